@@ -0,0 +1,291 @@
+//! `roc alloc-report`: adds up the allocations that a program built with `--profile-alloc` reported
+//! on stderr, per source location and per layout.
+
+use std::fmt;
+use std::io::BufRead;
+
+use roc_collections::MutMap;
+
+/// What a program built with `--profile-alloc` puts in front of every allocation it reports.
+const EVENT_PREFIX: &str = "roc-alloc ";
+
+/// A line of the `.alloc-sites` file written next to the app module while building.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct AllocSite {
+    location: String,
+    callee: String,
+    layout: String,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocTotals {
+    pub allocations: u64,
+    pub reallocations: u64,
+    pub bytes: u64,
+}
+
+impl AllocTotals {
+    fn add(&mut self, kind: EventKind, bytes: u64) {
+        match kind {
+            EventKind::Alloc => self.allocations += 1,
+            EventKind::Realloc => self.reallocations += 1,
+        }
+
+        self.bytes += bytes;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EventKind {
+    Alloc,
+    Realloc,
+}
+
+/// The allocations of one run of a program, from most to fewest bytes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AllocReport {
+    pub by_location: Vec<(String, AllocTotals)>,
+    pub by_layout: Vec<(String, AllocTotals)>,
+}
+
+/// Adds up the allocations in `log`, which is what the program wrote to stderr, using the sites
+/// file that was written when it was built. Lines the program wrote itself are skipped.
+pub fn alloc_report(sites: &str, log: impl BufRead) -> Result<AllocReport, String> {
+    let sites = parse_sites(sites)?;
+
+    let mut by_location: MutMap<String, AllocTotals> = MutMap::default();
+    let mut by_layout: MutMap<String, AllocTotals> = MutMap::default();
+
+    for (index, line) in log.lines().enumerate() {
+        let line = line.map_err(|err| format!("Could not read the allocations: {err}"))?;
+        let Some(event) = line.trim_end().strip_prefix(EVENT_PREFIX) else {
+            continue;
+        };
+
+        let (kind, site_id, bytes) = parse_event(event)
+            .ok_or_else(|| format!("Line {} is not an allocation: {line}", index + 1))?;
+
+        let (location, layout) = if site_id == 0 {
+            ("(outside of any call)".to_string(), "(unknown)".to_string())
+        } else {
+            let site = sites.get(&site_id).ok_or_else(|| {
+                format!(
+                    "Allocation site {site_id} is not in the sites file. Was the program rebuilt since?"
+                )
+            })?;
+
+            (
+                format!("{} {}", site.location, site.callee),
+                site.layout.clone(),
+            )
+        };
+
+        by_location.entry(location).or_default().add(kind, bytes);
+        by_layout.entry(layout).or_default().add(kind, bytes);
+    }
+
+    Ok(AllocReport {
+        by_location: sorted(by_location),
+        by_layout: sorted(by_layout),
+    })
+}
+
+fn parse_sites(sites: &str) -> Result<MutMap<u32, AllocSite>, String> {
+    sites
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(index, line)| {
+            parse_site(line).ok_or_else(|| {
+                format!(
+                    "Line {} of the sites file is not an allocation site: {line}",
+                    index + 1
+                )
+            })
+        })
+        .collect()
+}
+
+/// Parses `3\tmain.roc:4:5\tList.append\tList I64`.
+fn parse_site(line: &str) -> Option<(u32, AllocSite)> {
+    let mut fields = line.split('\t');
+
+    let id = fields.next()?.parse().ok()?;
+    let site = AllocSite {
+        location: fields.next()?.to_string(),
+        callee: fields.next()?.to_string(),
+        layout: fields.next()?.to_string(),
+    };
+
+    Some((id, site))
+}
+
+/// Parses `a 3 48`: an allocation of 48 bytes made by site 3.
+fn parse_event(event: &str) -> Option<(EventKind, u32, u64)> {
+    let mut fields = event.split(' ');
+
+    let kind = match fields.next()? {
+        "a" => EventKind::Alloc,
+        "r" => EventKind::Realloc,
+        _ => return None,
+    };
+    let site_id = fields.next()?.parse().ok()?;
+    let bytes = fields.next()?.parse().ok()?;
+
+    match fields.next() {
+        None => Some((kind, site_id, bytes)),
+        Some(_) => None,
+    }
+}
+
+fn sorted(totals: MutMap<String, AllocTotals>) -> Vec<(String, AllocTotals)> {
+    let mut totals: Vec<_> = totals.into_iter().collect();
+    totals.sort_by(|(name1, totals1), (name2, totals2)| {
+        (totals2.bytes, totals2.allocations, name1).cmp(&(
+            totals1.bytes,
+            totals1.allocations,
+            name2,
+        ))
+    });
+
+    totals
+}
+
+impl fmt::Display for AllocReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn table(
+            f: &mut fmt::Formatter<'_>,
+            title: &str,
+            rows: &[(String, AllocTotals)],
+        ) -> fmt::Result {
+            writeln!(
+                f,
+                "{:>12}  {:>8}  {:>8}  {title}",
+                "Bytes", "Allocs", "Reallocs"
+            )?;
+
+            for (name, totals) in rows {
+                writeln!(
+                    f,
+                    "{:>12}  {:>8}  {:>8}  {name}",
+                    totals.bytes, totals.allocations, totals.reallocations
+                )?;
+            }
+
+            Ok(())
+        }
+
+        if self.by_location.is_empty() {
+            return writeln!(f, "No allocations were reported.");
+        }
+
+        table(f, "Location", &self.by_location)?;
+        writeln!(f)?;
+        table(f, "Layout", &self.by_layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const SITES: &str = indoc!(
+        "
+        1\tmain.roc:3:5\tList.append\tList I64
+        2\tmain.roc:4:5\tStr.concat\tStr
+        3\tmain.roc:7:9\tList.append\tList I64
+        "
+    );
+
+    #[test]
+    fn adds_up_allocations_per_location_and_layout() {
+        let log = indoc!(
+            "
+            roc-alloc a 1 32
+            some output of the program
+            roc-alloc r 1 64
+            roc-alloc a 2 40
+            roc-alloc a 3 16
+            roc-alloc a 0 8
+            "
+        );
+
+        let report = alloc_report(SITES, log.as_bytes()).unwrap();
+
+        assert_eq!(
+            report.by_location,
+            vec![
+                (
+                    "main.roc:3:5 List.append".to_string(),
+                    AllocTotals {
+                        allocations: 1,
+                        reallocations: 1,
+                        bytes: 96
+                    }
+                ),
+                (
+                    "main.roc:4:5 Str.concat".to_string(),
+                    AllocTotals {
+                        allocations: 1,
+                        reallocations: 0,
+                        bytes: 40
+                    }
+                ),
+                (
+                    "main.roc:7:9 List.append".to_string(),
+                    AllocTotals {
+                        allocations: 1,
+                        reallocations: 0,
+                        bytes: 16
+                    }
+                ),
+                (
+                    "(outside of any call)".to_string(),
+                    AllocTotals {
+                        allocations: 1,
+                        reallocations: 0,
+                        bytes: 8
+                    }
+                ),
+            ]
+        );
+
+        assert_eq!(
+            report.by_layout,
+            vec![
+                (
+                    "List I64".to_string(),
+                    AllocTotals {
+                        allocations: 2,
+                        reallocations: 1,
+                        bytes: 112
+                    }
+                ),
+                (
+                    "Str".to_string(),
+                    AllocTotals {
+                        allocations: 1,
+                        reallocations: 0,
+                        bytes: 40
+                    }
+                ),
+                (
+                    "(unknown)".to_string(),
+                    AllocTotals {
+                        allocations: 1,
+                        reallocations: 0,
+                        bytes: 8
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_sites_of_another_build() {
+        let err = alloc_report(SITES, "roc-alloc a 4 32\n".as_bytes()).unwrap_err();
+
+        assert!(err.contains("site 4 is not in the sites file"), "{err}");
+    }
+}
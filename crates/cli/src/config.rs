@@ -0,0 +1,54 @@
+//! Reading project settings files like `roc-format.toml` and `roc-lint.toml`.
+
+use std::path::Path;
+
+/// The config for the file or directory at `path`: the nearest file called `file_name` in its
+/// directory or the directories above it, read with `parse`, or the default config if there
+/// isn't one.
+pub(crate) fn find_config<T: Default>(
+    path: &Path,
+    file_name: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<T, String> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+
+    for dir in path.ancestors() {
+        let config_path = dir.join(file_name);
+
+        if config_path.is_file() {
+            let src = std::fs::read_to_string(&config_path)
+                .map_err(|err| format!("Couldn't read {}: {err}", config_path.display()))?;
+
+            return parse(&src).map_err(|err| format!("Invalid {}: {err}", config_path.display()));
+        }
+    }
+
+    Ok(T::default())
+}
+
+/// Call `setting` with the key, value, and line number of each `key = value` line in `src`.
+///
+/// Only those lines (and comments) are supported, which is all the settings need.
+pub(crate) fn parse_settings(
+    src: &str,
+    mut setting: impl FnMut(&str, &str, usize) -> Result<(), String>,
+) -> Result<(), String> {
+    for (index, line) in src.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let line_number = index + 1;
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!(
+                "expected a `key = value` setting on line {line_number}"
+            ));
+        };
+
+        setting(key.trim(), value.trim(), line_number)?;
+    }
+
+    Ok(())
+}
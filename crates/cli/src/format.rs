@@ -9,8 +9,9 @@ use roc_can::expr::{DeclarationTag, Declarations, Expr};
 use roc_error_macros::{internal_error, user_error};
 use roc_fmt::def::fmt_defs;
 use roc_fmt::header::fmt_header;
+use roc_fmt::migrate::MigrationReport;
 use roc_fmt::Buf;
-use roc_fmt::MigrationFlags;
+use roc_fmt::{fmt_with_max_width, MigrationFlags};
 use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadedModule, LoadingProblem, Threading};
 use roc_module::symbol::{Interns, ModuleId};
 use roc_packaging::cache::{self, RocCacheDir};
@@ -19,16 +20,21 @@ use roc_parse::header::parse_module_defs;
 use roc_parse::normalize::Normalize;
 use roc_parse::{header, parser::SyntaxError, state::State};
 use roc_problem::can::RuntimeError;
-use roc_region::all::{LineColumn, LineInfo};
+use roc_region::all::{LineColumn, LineInfo, Loc, Position, Region};
 use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
 use roc_target::Target;
 use roc_types::subs::{Subs, Variable};
+use similar::{DiffTag, TextDiff};
+
+use crate::config::{find_config, parse_settings};
 
 #[derive(Copy, Clone, Debug)]
 pub enum FormatMode {
     WriteToFile,
     WriteToStdout,
     CheckOnly,
+    /// Like `CheckOnly`, but also print a unified diff of what formatting would change
+    Diff,
 }
 
 fn flatten_directories(files: std::vec::Vec<PathBuf>) -> std::vec::Vec<PathBuf> {
@@ -71,23 +77,90 @@ fn flatten_directories(files: std::vec::Vec<PathBuf>) -> std::vec::Vec<PathBuf>
     files
 }
 
+/// Project settings for `roc format`, from a `roc-format.toml` file like this one:
+///
+/// ```toml
+/// # Put applications, records, lists, and `|>` pipelines that would go past
+/// # this many columns on multiple lines.
+/// max_width = 100
+/// ```
+///
+/// Without a config file (or a setting in it), there is no max width, and only the
+/// things that were already on multiple lines stay that way.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormatConfig {
+    pub max_width: Option<u16>,
+}
+
+impl FormatConfig {
+    pub const FILE_NAME: &'static str = "roc-format.toml";
+
+    /// The config for the file or directory at `path`: the nearest `roc-format.toml` in
+    /// its directory or the directories above it, if there is one.
+    pub fn find(path: &Path) -> Result<Self, String> {
+        find_config(path, Self::FILE_NAME, Self::parse)
+    }
+
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+
+        parse_settings(src, |key, value, line_number| {
+            match key {
+            "max_width" => match value.parse::<u16>() {
+                Ok(max_width) if max_width > 0 => {
+                    config.max_width = Some(max_width);
+
+                    Ok(())
+                }
+                _ => Err(format!(
+                    "max_width must be a positive number of columns, but line {line_number} sets it to `{value}`"
+                )),
+            },
+            other => Err(format!("unknown setting `{other}` on line {line_number}")),
+        }
+        })?;
+
+        Ok(config)
+    }
+}
+
 fn is_roc_file(path: &Path) -> bool {
     matches!(path.extension().and_then(OsStr::to_str), Some("roc"))
 }
 
+/// Format the given files (and the .roc files in the given directories). With `opt_lines`,
+/// only those lines of each file are formatted; see [`format_src_lines`].
 pub fn format_files(
     files: std::vec::Vec<PathBuf>,
     mode: FormatMode,
     flags: MigrationFlags,
+    opt_lines: Option<&[Range<u32>]>,
 ) -> Result<(), String> {
     let arena = Bump::new();
     let mut files_to_reformat = Vec::new(); // to track which files failed `roc format --check`
 
     for file in flatten_directories(files) {
         let src = std::fs::read_to_string(&file).unwrap();
+        let config = FormatConfig::find(&file)?;
+
+        let formatted = match opt_lines {
+            Some(lines) => format_src_lines_with_report(&arena, &src, lines, flags, config),
+            None => format_src_with_report(&arena, &src, flags, config),
+        };
+
+        match formatted {
+            Ok((buf, report)) => {
+                // Only say what was migrated once it's been written out.
+                let print_summary = || {
+                    if flags.at_least_one_active() {
+                        let path = file.display().to_string();
+
+                        if let Some(summary) = migration_summary(&path, &buf, &report) {
+                            eprintln!("{summary}");
+                        }
+                    }
+                };
 
-        match format_src(&arena, &src, flags) {
-            Ok(buf) => {
                 match mode {
                     FormatMode::CheckOnly => {
                         // If a file fails `format --check`, add it to the file
@@ -96,12 +169,22 @@ pub fn format_files(
                             files_to_reformat.push(file.display().to_string());
                         }
                     }
+                    FormatMode::Diff => {
+                        if buf.as_str() != src {
+                            let path = file.display().to_string();
+
+                            print!("{}", format_diff(&path, &src, &buf));
+                            files_to_reformat.push(path);
+                        }
+                    }
                     FormatMode::WriteToFile => {
                         // If all the checks above passed, actually write out the new file.
                         std::fs::write(&file, buf.as_str()).unwrap();
+                        print_summary();
                     }
                     FormatMode::WriteToStdout => {
-                        std::io::stdout().lock().write_all(buf.as_bytes()).unwrap()
+                        std::io::stdout().lock().write_all(buf.as_bytes()).unwrap();
+                        print_summary();
                     }
                 }
             }
@@ -171,12 +254,17 @@ pub fn format_files(
             },
         }
     }
-    // After processing all files, check if any files failed `format --check`
+    // After processing all files, check if any files failed `format --check` (or `--diff`)
     if !files_to_reformat.is_empty() {
         let file_list = files_to_reformat.join(", ");
+        let flag = match mode {
+            FormatMode::Diff => "--diff",
+            _ => "--check",
+        };
+
         return Err(format!(
-            "The following file(s) failed `roc format --check`:\n\t{}\nYou can fix this with `roc format filename.roc`.",
-            file_list
+            "The following file(s) failed `roc format {}`:\n\t{}\nYou can fix this with `roc format filename.roc`.",
+            flag, file_list
         ));
     }
     Ok(())
@@ -199,18 +287,41 @@ pub enum FormatProblem {
     },
 }
 
-pub fn format_src(arena: &Bump, src: &str, flags: MigrationFlags) -> Result<String, FormatProblem> {
+pub fn format_src(
+    arena: &Bump,
+    src: &str,
+    flags: MigrationFlags,
+    config: FormatConfig,
+) -> Result<String, FormatProblem> {
+    format_src_with_report(arena, src, flags, config).map(|(formatted_src, _)| formatted_src)
+}
+
+/// [`format_src`], along with what it migrated (and what it couldn't), by where each of
+/// them is in the formatted code
+fn format_src_with_report(
+    arena: &Bump,
+    src: &str,
+    flags: MigrationFlags,
+    config: FormatConfig,
+) -> Result<(String, MigrationReport), FormatProblem> {
+    let src = arena.alloc_str(src);
     let ast = arena.alloc(parse_all(arena, src).unwrap_or_else(|e| {
         user_error!("Unexpected parse failure when parsing this formatting:\n\n{src}\n\nParse error was:\n\n{:#?}\n\n", e)
     }));
-    let mut buf = Buf::new_in(arena, flags);
-    fmt_all(&mut buf, ast);
-
-    let reparsed_ast = match arena.alloc(parse_all(arena, buf.as_str())) {
-        Ok(ast) => ast,
-        Err(e) => {
+    let formatted = fmt_with_max_width(
+        arena,
+        src,
+        &&*ast,
+        flags,
+        config.max_width,
+        |buf, ast| fmt_all(buf, ast),
+        |formatted_src| parse_all(arena, formatted_src).map(|ast| &*arena.alloc(ast)),
+    );
+    let (formatted_src, reparsed_ast, report) = match formatted {
+        Ok(formatted) => formatted,
+        Err((formatted_src, e)) => {
             return Err(FormatProblem::ParsingFailed {
-                formatted_src: buf.as_str().to_string(),
+                formatted_src: formatted_src.to_string(),
                 parse_err: format!("{:?}", e),
             });
         }
@@ -228,28 +339,235 @@ pub fn format_src(arena: &Bump, src: &str, flags: MigrationFlags) -> Result<Stri
         && format!("{ast_normalized:?}") != format!("{reparsed_ast_normalized:?}")
     {
         return Err(FormatProblem::ReformattingChangedAst {
-            formatted_src: buf.as_str().to_string(),
+            formatted_src: formatted_src.to_string(),
             ast_before: format!("{ast_normalized:#?}\n"),
             ast_after: format!("{reparsed_ast_normalized:#?}\n"),
         });
     }
 
     // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted
-    let mut reformatted_buf = Buf::new_in(arena, flags);
-
-    fmt_all(&mut reformatted_buf, reparsed_ast);
+    let reformatted_src = fmt_all_with_config(arena, reparsed_ast, flags, config);
 
-    if buf.as_str() != reformatted_buf.as_str() {
+    if formatted_src != reformatted_src {
         return Err(FormatProblem::ReformattingUnstable {
-            formatted_src: buf.as_str().to_string(),
-            reformatted_src: reformatted_buf.as_str().to_string(),
+            formatted_src: formatted_src.to_string(),
+            reformatted_src: reformatted_src.to_string(),
         });
     }
 
-    Ok(buf.as_str().to_string())
+    Ok((formatted_src.to_string(), report))
+}
+
+fn fmt_all_with_config<'a>(
+    arena: &'a Bump,
+    ast: &'a FullAst,
+    flags: MigrationFlags,
+    config: FormatConfig,
+) -> &'a str {
+    let mut buf = Buf::new_in(arena, flags);
+    buf.set_max_width(config.max_width);
+    fmt_all(&mut buf, ast);
+
+    buf.into_bump_str()
+}
+
+/// Format only the given lines of `src` (0-based, with exclusive ends), leaving the rest of it
+/// as it is. A byte range can be formatted by passing the lines it's on.
+///
+/// Formatting part of a def (like some of the lines of an indented block) could change what
+/// it means, so each range is widened to cover every top-level def it touches.
+pub fn format_src_lines(
+    arena: &Bump,
+    src: &str,
+    lines: &[Range<u32>],
+    flags: MigrationFlags,
+    config: FormatConfig,
+) -> Result<String, FormatProblem> {
+    format_src_lines_with_report(arena, src, lines, flags, config)
+        .map(|(formatted_src, _)| formatted_src)
+}
+
+/// [`format_src_lines`], along with what it migrated (and what it couldn't) in those lines, by
+/// where each of them is in the formatted code
+fn format_src_lines_with_report(
+    arena: &Bump,
+    src: &str,
+    lines: &[Range<u32>],
+    flags: MigrationFlags,
+    config: FormatConfig,
+) -> Result<(String, MigrationReport), FormatProblem> {
+    let (formatted_src, report) = format_src_with_report(arena, src, flags, config)?;
+
+    let ast = parse_all(arena, src).unwrap_or_else(|e| {
+        internal_error!("Parsing failed after formatting already parsed this:\n\n{e:?}")
+    });
+    let line_info = LineInfo::new(src);
+    let def_lines: Vec<Range<u32>> = ast
+        .defs
+        .regions
+        .iter()
+        .map(|region| {
+            let region = line_info.convert_region(*region);
+
+            region.start.line..region.end.line + 1
+        })
+        .collect();
+    let lines: Vec<Range<u32>> = lines
+        .iter()
+        .map(|range| {
+            def_lines
+                .iter()
+                .filter(|def_range| lines_overlap(def_range, range))
+                .fold(range.clone(), |widened, def_range| {
+                    widened.start.min(def_range.start)..widened.end.max(def_range.end)
+                })
+        })
+        .collect();
+
+    let diff = TextDiff::from_lines(src, formatted_src.as_str());
+    let mut partially_formatted = String::with_capacity(formatted_src.len());
+    // The parts of the formatted code that were kept, and where they were put
+    let mut kept: Vec<(Range<usize>, usize)> = Vec::new();
+    let mut formatted_offset = 0;
+
+    for op in diff.ops() {
+        let old_range = op.old_range();
+        let old_lines = old_range.start as u32..old_range.end as u32;
+        let in_range = lines.iter().any(|range| lines_overlap(range, &old_lines));
+        let new_slices = &diff.new_slices()[op.new_range()];
+        let new_len: usize = new_slices.iter().map(|line| line.len()).sum();
+
+        if op.tag() != DiffTag::Equal && in_range {
+            kept.push((
+                formatted_offset..formatted_offset + new_len,
+                partially_formatted.len(),
+            ));
+            partially_formatted.extend(new_slices.iter().copied());
+        } else {
+            partially_formatted.extend(diff.old_slices()[old_range].iter().copied());
+        }
+
+        formatted_offset += new_len;
+    }
+
+    // The changes the formatter makes to one def can depend on the ones it makes to another
+    // (like the blank lines between them), so keeping only some of them might not give the
+    // same program back. If that happens, format everything rather than change its meaning.
+    let same_program = match parse_all(arena, arena.alloc_str(&partially_formatted)) {
+        Ok(partial_ast) => {
+            // Migrations change the tree on purpose, so then all we can check is that it parses.
+            flags.at_least_one_active()
+                || format!("{:?}", ast.normalize(arena))
+                    == format!("{:?}", partial_ast.normalize(arena))
+        }
+        Err(_) => false,
+    };
+
+    if same_program {
+        // Only what's in the kept parts was actually migrated.
+        let moved = |region: Region| {
+            let offset = region.start().offset as usize;
+            let (range, kept_offset) = kept.iter().find(|(range, _)| range.contains(&offset))?;
+            let moved = offset - range.start + kept_offset;
+
+            Some(Region::from_pos(Position::new(moved as u32)))
+        };
+        let report = MigrationReport {
+            migrated: report
+                .migrated
+                .into_iter()
+                .filter_map(|loc| Some(Loc::at(moved(loc.region)?, loc.value)))
+                .collect(),
+            not_migrated: report
+                .not_migrated
+                .into_iter()
+                .filter_map(|loc| Some(Loc::at(moved(loc.region)?, loc.value)))
+                .collect(),
+        };
+
+        Ok((partially_formatted, report))
+    } else {
+        Ok((formatted_src, report))
+    }
+}
+
+/// Whether two ranges of lines overlap. An empty range (where lines would be inserted)
+/// overlaps the ranges it's inside or right next to.
+fn lines_overlap(a: &Range<u32>, b: &Range<u32>) -> bool {
+    if a.is_empty() || b.is_empty() {
+        a.start <= b.end && b.start <= a.end
+    } else {
+        a.start < b.end && b.start < a.end
+    }
+}
+
+/// A unified diff of the changes formatting would make to the file at `path`
+pub fn format_diff(path: &str, src: &str, formatted_src: &str) -> String {
+    TextDiff::from_lines(src, formatted_src)
+        .unified_diff()
+        .header(path, path)
+        .to_string()
+}
+
+/// Describe what formatting the file at `path` into `formatted_src` migrated, and what it
+/// couldn't, one line each (with line numbers in `formatted_src`), so large codebases can be
+/// migrated one file at a time and the rest fixed by hand. Returns `None` if there was nothing
+/// to migrate.
+pub fn migration_summary(
+    path: &str,
+    formatted_src: &str,
+    report: &MigrationReport,
+) -> Option<String> {
+    if report.migrated.is_empty() && report.not_migrated.is_empty() {
+        return None;
+    }
+
+    let line_info = LineInfo::new(formatted_src);
+    let line = |region: Region| line_info.convert_pos(region.start()).line + 1;
+    let things = |count: usize| match count {
+        1 => "1 thing".to_string(),
+        _ => format!("{count} things"),
+    };
+    let mut summary = String::new();
+
+    if !report.migrated.is_empty() {
+        summary.push_str(&format!(
+            "Migrated {} in {path}:\n",
+            things(report.migrated.len())
+        ));
+
+        for migration in report.migrated.iter() {
+            summary.push_str(&format!(
+                "    {path}:{}: {}\n",
+                line(migration.region),
+                migration.value
+            ));
+        }
+    }
+
+    if !report.not_migrated.is_empty() {
+        if !summary.is_empty() {
+            summary.push('\n');
+        }
+
+        summary.push_str(&format!(
+            "Couldn't migrate {} in {path}, so it needs to be changed by hand:\n",
+            things(report.not_migrated.len())
+        ));
+
+        for problem in report.not_migrated.iter() {
+            summary.push_str(&format!(
+                "    {path}:{}: {}\n",
+                line(problem.region),
+                problem.value
+            ));
+        }
+    }
+
+    Some(summary)
 }
 
-fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<FullAst<'a>, SyntaxError<'a>> {
+pub(crate) fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<FullAst<'a>, SyntaxError<'a>> {
     let (header, state) = header::parse_header(arena, State::new(src.as_bytes()))
         .map_err(|e| SyntaxError::Header(e.problem))?;
 
@@ -294,6 +612,7 @@ pub fn annotate_file(arena: &Bump, file: PathBuf) -> Result<(), AnnotationProble
         palette: DEFAULT_PALETTE,
         threading: Threading::AllAvailable,
         exec_mode: ExecutionMode::Check,
+        opt_level: None,
     };
 
     let mut loaded = roc_load::load_and_typecheck(
@@ -488,7 +807,7 @@ main =
             parens_and_commas: false,
         };
 
-        let result = format_files(vec![file_path.clone()], FormatMode::CheckOnly, flags);
+        let result = format_files(vec![file_path.clone()], FormatMode::CheckOnly, flags, None);
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
@@ -511,7 +830,7 @@ main =
             parens_and_commas: false,
         };
 
-        let result = format_files(vec![file1, file2], FormatMode::CheckOnly, flags);
+        let result = format_files(vec![file1, file2], FormatMode::CheckOnly, flags, None);
         assert!(result.is_err());
         let error_message = result.unwrap_err();
         assert!(error_message.contains("test1.roc") && error_message.contains("test2.roc"));
@@ -528,7 +847,7 @@ main =
             parens_and_commas: false,
         };
 
-        let result = format_files(vec![file_path], FormatMode::CheckOnly, flags);
+        let result = format_files(vec![file_path], FormatMode::CheckOnly, flags, None);
         assert!(result.is_ok());
 
         cleanup_temp_dir(dir);
@@ -549,6 +868,7 @@ main =
             vec![file_formatted, file1_unformated, file2_unformated],
             FormatMode::CheckOnly,
             flags,
+            None,
         );
         assert!(result.is_err());
         let error_message = result.unwrap_err();
@@ -558,6 +878,230 @@ main =
         cleanup_temp_dir(dir);
     }
 
+    #[test]
+    fn test_format_only_some_lines() {
+        let src = indoc! {r#"
+            app [main] { pf: platform "platform/main.roc" }
+
+            a =
+                    1
+
+            b =
+                    2
+        "#};
+        let flags = MigrationFlags {
+            snakify: false,
+            parens_and_commas: false,
+        };
+        let arena = Bump::new();
+
+        // Only line 4 is in the range, but that's in the middle of `a`, so all of `a` is formatted.
+        let formatted = format_src_lines(
+            &arena,
+            src,
+            std::slice::from_ref(&(3..4)),
+            flags,
+            FormatConfig::default(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            formatted,
+            indoc! {r#"
+                app [main] { pf: platform "platform/main.roc" }
+
+                a =
+                    1
+
+                b =
+                        2
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_format_diff() {
+        let flags = MigrationFlags {
+            snakify: false,
+            parens_and_commas: false,
+        };
+        let arena = Bump::new();
+        let formatted =
+            format_src(&arena, UNFORMATTED_ROC, flags, FormatConfig::default()).unwrap();
+        let diff = format_diff("test.roc", UNFORMATTED_ROC, &formatted);
+
+        assert!(diff.starts_with("--- test.roc\n+++ test.roc\n@@ "));
+        assert!(diff.contains("\n-        name = Stdin.line!\n"));
+        assert!(diff.contains("\n+    name = Stdin.line!\n"));
+    }
+
+    #[test]
+    fn test_format_config() {
+        assert_eq!(
+            FormatConfig::parse("# Wrap long lines\nmax_width = 80 # columns\n"),
+            Ok(FormatConfig {
+                max_width: Some(80)
+            })
+        );
+        assert_eq!(FormatConfig::parse(""), Ok(FormatConfig::default()));
+        assert!(FormatConfig::parse("max_width = wide").is_err());
+        assert!(FormatConfig::parse("indent = 2").is_err());
+    }
+
+    #[test]
+    fn test_format_with_max_width_from_config() {
+        let dir = tempdir().unwrap();
+        setup_test_file(dir.path(), FormatConfig::FILE_NAME, "max_width = 40\n");
+        let file_path = setup_test_file(
+            dir.path(),
+            "test1.roc",
+            indoc! {r#"
+                app [main] { pf: platform "platform/main.roc" }
+
+                main = [first_number, second_number, third_number]
+            "#}
+            .trim_end(),
+        );
+        let flags = MigrationFlags {
+            snakify: false,
+            parens_and_commas: false,
+        };
+
+        let result = format_files(
+            vec![file_path.clone()],
+            FormatMode::WriteToFile,
+            flags,
+            None,
+        );
+        assert!(result.is_ok());
+        assert_eq!(
+            read_to_string(&file_path).unwrap(),
+            indoc! {r#"
+                app [main] { pf: platform "platform/main.roc" }
+
+                main = [
+                    first_number,
+                    second_number,
+                    third_number,
+                ]
+            "#}
+        );
+
+        cleanup_temp_dir(dir);
+    }
+
+    fn summary(
+        src: &str,
+        lines: Option<&[Range<u32>]>,
+        flags: MigrationFlags,
+        config: FormatConfig,
+    ) -> Option<String> {
+        let arena = Bump::new();
+        let (formatted_src, report) = match lines {
+            Some(lines) => format_src_lines_with_report(&arena, src, lines, flags, config),
+            None => format_src_with_report(&arena, src, flags, config),
+        }
+        .unwrap();
+
+        migration_summary("main.roc", &formatted_src, &report)
+    }
+
+    #[test]
+    fn test_migration_summary() {
+        let src = indoc! {r#"
+            app [main] { pf: platform "platform/main.roc" }
+
+            toStr = \n -> "$(n)"
+
+            main : Task {} []
+            main = Task.ok {}
+        "#};
+        let flags = MigrationFlags {
+            snakify: true,
+            parens_and_commas: false,
+        };
+
+        assert_eq!(
+            summary(src, None, flags, FormatConfig::default()).as_deref(),
+            Some(indoc! {r#"
+                Migrated 3 things in main.roc:
+                    main.roc:3: `toStr` to `to_str`
+                    main.roc:3: `\x ->` lambda to `|x|`
+                    main.roc:3: `$(x)` interpolation to `${x}`
+
+                Couldn't migrate 2 things in main.roc, so it needs to be changed by hand:
+                    main.roc:5: the `Task` API is gone; use effectful functions (whose names end in `!`) instead
+                    main.roc:6: the `Task` API is gone; use effectful functions (whose names end in `!`) instead
+            "#})
+        );
+        assert_eq!(
+            summary(
+                "module [x]\n\nx = 1\n",
+                None,
+                flags,
+                FormatConfig::default()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_migration_summary_only_covers_formatted_lines() {
+        let src = indoc! {r#"
+            module [foo_bar, baz_qux]
+
+            fooBar = 1
+
+            bazQux = 2
+        "#};
+        let flags = MigrationFlags {
+            snakify: true,
+            parens_and_commas: false,
+        };
+
+        assert_eq!(
+            summary(
+                src,
+                Some(std::slice::from_ref(&(4..5))),
+                flags,
+                FormatConfig::default()
+            )
+            .as_deref(),
+            Some(indoc! {r#"
+                Migrated 1 thing in main.roc:
+                    main.roc:5: `bazQux` to `baz_qux`
+            "#})
+        );
+    }
+
+    #[test]
+    fn test_migration_summary_lines_past_max_width() {
+        let src = indoc! {r#"
+            module [main]
+
+            main = List.concat [firstName, secondName, thirdName] [fourthName]
+        "#};
+        let flags = MigrationFlags {
+            snakify: true,
+            parens_and_commas: true,
+        };
+        let config = FormatConfig {
+            max_width: Some(40),
+        };
+
+        assert_eq!(
+            summary(src, None, flags, config).as_deref(),
+            Some(indoc! {r#"
+                Migrated 5 things in main.roc:
+                    main.roc:3: `f x y` call to `f(x, y)`
+                    main.roc:5: `firstName` to `first_name`
+                    main.roc:6: `secondName` to `second_name`
+                    main.roc:7: `thirdName` to `third_name`
+                    main.roc:9: `fourthName` to `fourth_name`
+            "#})
+        );
+    }
+
     const HEADER: &str = indoc! {r#"
         interface Test
             exposes []
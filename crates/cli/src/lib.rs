@@ -11,7 +11,7 @@ use clap::{
 use roc_build::link::{LinkType, LinkingStrategy};
 use roc_build::program::{
     handle_error_module, handle_loading_problem, standard_load_config, BuildFileError,
-    BuildOrdering, BuiltFile, CodeGenBackend, CodeGenOptions, DEFAULT_ROC_FILENAME,
+    BuildOrdering, BuiltFile, CodeGenBackend, CodeGenOptions, Sanitizer, DEFAULT_ROC_FILENAME,
 };
 #[cfg(not(windows))]
 use roc_collections::MutMap;
@@ -24,13 +24,16 @@ use roc_module::symbol::ModuleId;
 use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
 use roc_packaging::tarball::Compression;
+use roc_reporting::cli::{warning_kind, WarningLevels};
+use roc_reporting::report::RenderTarget;
 #[cfg(not(windows))]
 use roc_reporting::report::ANSI_STYLE_CODES;
-use roc_target::{Architecture, Target};
+use roc_target::{Architecture, OperatingSystem, Target};
 use std::env;
 use std::ffi::{CString, OsStr, OsString};
 use std::io;
 use std::mem::ManuallyDrop;
+use std::ops::Range;
 use std::os::raw::{c_char, c_int};
 use std::path::{Path, PathBuf};
 use std::process;
@@ -41,11 +44,16 @@ use strum::IntoEnumIterator;
 #[cfg(not(target_os = "linux"))]
 use tempfile::TempDir;
 
+mod config;
 mod format;
 pub use format::{
-    annotate_file, annotation_edit, annotation_edits, format_files, format_src, AnnotationProblem,
-    FormatMode,
+    annotate_file, annotation_edit, annotation_edits, format_diff, format_files, format_src,
+    format_src_lines, AnnotationProblem, FormatConfig, FormatMode,
 };
+mod lint;
+pub use lint::{lint_file, LintConfig};
+mod alloc_report;
+pub use alloc_report::{alloc_report, AllocReport, AllocTotals};
 
 pub const CMD_BUILD: &str = "build";
 pub const CMD_RUN: &str = "run";
@@ -53,12 +61,15 @@ pub const CMD_DEV: &str = "dev";
 pub const CMD_REPL: &str = "repl";
 pub const CMD_DOCS: &str = "docs";
 pub const CMD_CHECK: &str = "check";
+pub const CMD_LINT: &str = "lint";
 pub const CMD_VERSION: &str = "version";
+pub const CMD_EXPLAIN: &str = "explain";
 pub const CMD_FORMAT: &str = "format";
 pub const CMD_FORMAT_ANNOTATE: &str = "annotate";
 pub const CMD_TEST: &str = "test";
 pub const CMD_GLUE: &str = "glue";
 pub const CMD_PREPROCESS_HOST: &str = "preprocess-host";
+pub const CMD_ALLOC_REPORT: &str = "alloc-report";
 
 pub const FLAG_EMIT_LLVM_IR: &str = "emit-llvm-ir";
 pub const FLAG_PROFILING: &str = "profiling";
@@ -74,26 +85,42 @@ pub const FLAG_TIME: &str = "time";
 pub const FLAG_VERBOSE: &str = "verbose";
 pub const FLAG_NO_COLOR: &str = "no-color";
 pub const FLAG_NO_HEADER: &str = "no-header";
+pub const FLAG_REPL_PLATFORM: &str = "platform";
 pub const FLAG_LINKER: &str = "linker";
 pub const FLAG_BUILD_HOST: &str = "build-host";
 pub const FLAG_SUPPRESS_BUILD_HOST_WARNING: &str = "suppress-build-host-warning";
 pub const FLAG_CHECK: &str = "check";
 pub const FLAG_STDIN: &str = "stdin";
 pub const FLAG_STDOUT: &str = "stdout";
+pub const FLAG_DIFF: &str = "diff";
+pub const FLAG_LINES: &str = "lines";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
 pub const FLAG_OUTPUT: &str = "output";
 pub const FLAG_FUZZ: &str = "fuzz";
+pub const FLAG_TRACE_CLONES: &str = "trace-clones";
+pub const FLAG_PROFILE_ALLOC: &str = "profile-alloc";
+pub const FLAG_CHECK_REFCOUNTS: &str = "check-refcounts";
+pub const FLAG_SANITIZE: &str = "sanitize";
 pub const FLAG_MAIN: &str = "main";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const GLUE_DIR: &str = "GLUE_DIR";
 pub const GLUE_SPEC: &str = "GLUE_SPEC";
 pub const DIRECTORY_OR_FILES: &str = "DIRECTORY_OR_FILES";
+pub const ERROR_CODE: &str = "ERROR_CODE";
+pub const ALLOC_SITES_FILE: &str = "ALLOC_SITES_FILE";
+pub const ALLOC_LOG_FILE: &str = "ALLOC_LOG_FILE";
 pub const ARGS_FOR_APP: &str = "ARGS_FOR_APP";
 pub const FLAG_PP_HOST: &str = "host";
 pub const FLAG_PP_PLATFORM: &str = "platform";
 pub const FLAG_PP_DYLIB: &str = "lib";
 pub const FLAG_MIGRATE: &str = "migrate";
 pub const FLAG_DOCS_ROOT: &str = "root-dir";
+pub const FLAG_DOCS_FORMAT: &str = "format";
+pub const FLAG_ERROR_FORMAT: &str = "error-format";
+pub const FLAG_DENY: &str = "deny";
+pub const FLAG_ALLOW: &str = "allow";
+pub const FLAG_WARNINGS_AS_ERRORS: &str = "warnings-as-errors";
+pub const FLAG_FIX: &str = "fix";
 
 pub const VERSION: &str = env!("ROC_VERSION");
 const DEFAULT_GENERATED_DOCS_DIR: &str = "generated-docs";
@@ -171,12 +198,71 @@ pub fn build_app() -> Command {
         .action(ArgAction::SetTrue)
         .required(false);
 
+    let flag_trace_clones = Arg::new(FLAG_TRACE_CLONES)
+        .long(FLAG_TRACE_CLONES)
+        .help("Report every list, string or dict that gets copied instead of updated in place because it was shared\n(Prints the updates that always copy while building, and logs the others through `dbg` when they copy at runtime.)")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_profile_alloc = Arg::new(FLAG_PROFILE_ALLOC)
+        .long(FLAG_PROFILE_ALLOC)
+        .help("Make the program report every allocation it makes on stderr, for `roc alloc-report` to add up per source location and per layout\n(Also writes a .alloc-sites file next to the .roc file, which the report needs.)")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_check_refcounts = Arg::new(FLAG_CHECK_REFCOUNTS)
+        .long(FLAG_CHECK_REFCOUNTS)
+        .help("Check refcounts while running, reporting values that are decremented after they were freed, used after they were freed, or never freed\n(Every problem names the call in user code that allocated the value. Makes the program slower and keeps freed memory around.)")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_sanitize = Arg::new(FLAG_SANITIZE)
+        .long(FLAG_SANITIZE)
+        .help("Instrument the Roc code with an LLVM sanitizer and link in its runtime, using clang instead of the surgical linker\n(Build the host with the matching -fsanitize=address, -fsanitize=undefined,local-bounds or -fsanitize=memory to also check it, and the boundary between the two.)")
+        .value_parser(Sanitizer::NAMES)
+        .required(false);
+
     let flag_main = Arg::new(FLAG_MAIN)
         .long(FLAG_MAIN)
         .help("The .roc file of the main app/package module to resolve dependencies from")
         .value_parser(value_parser!(PathBuf))
         .required(false);
 
+    let flag_error_format = Arg::new(FLAG_ERROR_FORMAT)
+        .long(FLAG_ERROR_FORMAT)
+        .help("How to print errors and warnings\n(`json` prints one JSON object per line, and `sarif` prints a SARIF 2.1.0 log, for CI and code review tools.)")
+        .value_parser(["human", "json", "sarif"])
+        .required(false)
+        .default_value("human");
+
+    let parse_warning_kind = |kind: &str| {
+        warning_kind(kind).ok_or_else(|| {
+            "expected a kind of warning, like `unused-import` or `E0204` (run `roc explain` to list them)".to_string()
+        })
+    };
+
+    let flag_deny = Arg::new(FLAG_DENY)
+        .long(FLAG_DENY)
+        .help("Report warnings of this kind as errors\n(Can be given more than once. Kinds are error codes like `E0204`, or titles like `unused-import`.)")
+        .value_name("WARNING_KIND")
+        .value_parser(parse_warning_kind)
+        .action(ArgAction::Append)
+        .required(false);
+
+    let flag_allow = Arg::new(FLAG_ALLOW)
+        .long(FLAG_ALLOW)
+        .help("Don't report warnings of this kind\n(Can be given more than once. To allow a kind of warning in just one def, put a `# roc:allow unused-import` comment above it.)")
+        .value_name("WARNING_KIND")
+        .value_parser(parse_warning_kind)
+        .action(ArgAction::Append)
+        .required(false);
+
+    let flag_warnings_as_errors = Arg::new(FLAG_WARNINGS_AS_ERRORS)
+        .long(FLAG_WARNINGS_AS_ERRORS)
+        .help("Report every warning that isn't allowed as an error")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
     let roc_file_to_run = Arg::new(ROC_FILE)
         .help("The .roc file of an app to run")
         .value_parser(value_parser!(PathBuf))
@@ -221,7 +307,15 @@ pub fn build_app() -> Command {
             .arg(flag_build_host.clone())
             .arg(flag_suppress_build_host_warning.clone())
             .arg(flag_fuzz.clone())
+            .arg(flag_trace_clones.clone())
+            .arg(flag_profile_alloc.clone())
+            .arg(flag_check_refcounts.clone())
+            .arg(flag_sanitize.clone())
             .arg(flag_wasm_stack_size_kb)
+            .arg(flag_error_format.clone())
+            .arg(flag_deny.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warnings_as_errors.clone())
             .arg(
                 Arg::new(FLAG_TARGET)
                     .long(FLAG_TARGET)
@@ -281,6 +375,11 @@ pub fn build_app() -> Command {
             .arg(flag_build_host.clone())
             .arg(flag_suppress_build_host_warning.clone())
             .arg(flag_fuzz.clone())
+            .arg(flag_check_refcounts.clone())
+            .arg(flag_error_format.clone())
+            .arg(flag_deny.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warnings_as_errors.clone())
             .arg(
                 Arg::new(FLAG_VERBOSE)
                     .long(FLAG_VERBOSE)
@@ -314,6 +413,13 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false)
             )
+            .arg(
+                Arg::new(FLAG_REPL_PLATFORM)
+                    .long(FLAG_REPL_PLATFORM)
+                    .help("Run effectful expressions on this platform (e.g. ./platform/main.roc)")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+            )
         )
         .subcommand(Command::new(CMD_RUN)
             .about("Run a .roc file even if it has build errors")
@@ -328,6 +434,10 @@ pub fn build_app() -> Command {
             .arg(flag_build_host.clone())
             .arg(flag_suppress_build_host_warning.clone())
             .arg(flag_fuzz.clone())
+            .arg(flag_trace_clones.clone())
+            .arg(flag_profile_alloc.clone())
+            .arg(flag_check_refcounts.clone())
+            .arg(flag_sanitize.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone().last(true))
         )
@@ -344,6 +454,10 @@ pub fn build_app() -> Command {
             .arg(flag_build_host.clone())
             .arg(flag_suppress_build_host_warning.clone())
             .arg(flag_fuzz.clone())
+            .arg(flag_trace_clones.clone())
+            .arg(flag_profile_alloc.clone())
+            .arg(flag_check_refcounts.clone())
+            .arg(flag_sanitize.clone())
             .arg(roc_file_to_run.clone())
             .arg(args_for_app.clone().last(true))
         )
@@ -362,10 +476,26 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_DIFF)
+                    .long(FLAG_DIFF)
+                    .help("Like --check, but also prints a unified diff of what formatting would change")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_LINES)
+                    .long(FLAG_LINES)
+                    .help("Only format these lines, like `--lines 3:10` for lines 3 through 10\n(Can be given more than once. The top-level definitions on those lines are formatted in full.)")
+                    .value_name("START:END")
+                    .value_parser(parse_line_range)
+                    .action(ArgAction::Append)
+                    .required(false),
+            )
             .arg(
                 Arg::new(FLAG_MIGRATE)
                     .long(FLAG_MIGRATE)
-                    .help("Will change syntax to match the latest preferred style. This can cause changes to variable names and more.")
+                    .help("Will change syntax to match the latest preferred style. This can cause changes to variable names and more. Once the files are written, prints a summary of what was changed, and what needs to be changed by hand.")
                     .action(ArgAction::SetTrue)
                     .required(false),
             )
@@ -397,11 +527,38 @@ pub fn build_app() -> Command {
         )
         .subcommand(Command::new(CMD_VERSION)
             .about(concatcp!("Print the Roc compiler’s version, which is currently ", VERSION)))
+        .subcommand(Command::new(CMD_EXPLAIN)
+            .about("Explain an error code, like the E0301 in `── TYPE MISMATCH [E0301]`, with examples")
+            .arg(
+                Arg::new(ERROR_CODE)
+                    .help("The error code to explain. Without one, all error codes are listed.")
+                    .required(false)
+            )
+        )
+        .subcommand(Command::new(CMD_ALLOC_REPORT)
+            .about("Add up the allocations of a program built with `--profile-alloc`, per source location and per layout")
+            .arg(
+                Arg::new(ALLOC_SITES_FILE)
+                    .help("The .alloc-sites file written while building the program")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(true)
+            )
+            .arg(
+                Arg::new(ALLOC_LOG_FILE)
+                    .help("A file with what the program wrote to stderr\n(Read from stdin if not given.)")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+            )
+        )
         .subcommand(Command::new(CMD_CHECK)
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_main.clone())
             .arg(flag_time.clone())
             .arg(flag_max_threads.clone())
+            .arg(flag_error_format.clone())
+            .arg(flag_deny.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warnings_as_errors.clone())
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file to check")
@@ -410,6 +567,29 @@ pub fn build_app() -> Command {
                     .default_value(DEFAULT_ROC_FILENAME),
             )
             )
+        .subcommand(Command::new(CMD_LINT)
+            .about("Check the code for problems like `roc check` does, and also for code that probably isn’t what was meant\n(Kinds of warnings can be denied or allowed for a whole project in a `roc-lint.toml` file.)")
+            .arg(flag_main.clone())
+            .arg(flag_max_threads.clone())
+            .arg(flag_error_format)
+            .arg(flag_deny)
+            .arg(flag_allow)
+            .arg(flag_warnings_as_errors)
+            .arg(
+                Arg::new(FLAG_FIX)
+                    .long(FLAG_FIX)
+                    .help("Apply the suggested fixes, and reformat the files they're in")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file to lint")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+            )
         .subcommand(
             Command::new(CMD_DOCS)
                 .about("Generate documentation for a Roc package")
@@ -427,6 +607,13 @@ pub fn build_app() -> Command {
                     .default_value(DEFAULT_ROC_FILENAME),
                 )
                 .arg(flag_docs_root_dir)
+                .arg(Arg::new(FLAG_DOCS_FORMAT)
+                    .long(FLAG_DOCS_FORMAT)
+                    .help("Generate HTML pages, or a single docs.json file with each module's exposed symbols, type signatures, doc comments, and source locations.")
+                    .value_parser(["html", "json"])
+                    .required(false)
+                    .default_value("html"),
+                )
         )
         .subcommand(Command::new(CMD_GLUE)
             .about("Generate glue code between a platform's Roc API and its host language")
@@ -499,10 +686,28 @@ pub fn build_app() -> Command {
         .arg(flag_build_host)
         .arg(flag_suppress_build_host_warning)
         .arg(flag_fuzz)
+        .arg(flag_trace_clones)
+        .arg(flag_profile_alloc)
+        .arg(flag_check_refcounts)
+        .arg(flag_sanitize)
         .arg(roc_file_to_run)
         .arg(args_for_app.trailing_var_arg(true))
 }
 
+/// Parses `--lines 3:10` (1-based, and including line 10) into lines 2..10
+fn parse_line_range(arg: &str) -> Result<Range<u32>, String> {
+    let invalid = || format!("`{arg}` isn't a line range like `3:10`");
+    let (start, end) = arg.split_once(':').ok_or_else(invalid)?;
+    let start: u32 = start.trim().parse().map_err(|_| invalid())?;
+    let end: u32 = end.trim().parse().map_err(|_| invalid())?;
+
+    if start == 0 || end < start {
+        return Err(invalid());
+    }
+
+    Ok(start - 1..end)
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum BuildConfig {
     BuildOnly,
@@ -524,6 +729,45 @@ fn opt_level_from_flags(matches: &ArgMatches) -> OptLevel {
     }
 }
 
+/// How `--error-format` says to print errors and warnings. Commands without that flag,
+/// like `roc run`, always print them for a person to read.
+pub fn render_target_from_flags(matches: &ArgMatches) -> RenderTarget {
+    match matches
+        .try_get_one::<String>(FLAG_ERROR_FORMAT)
+        .ok()
+        .flatten()
+        .map(String::as_str)
+    {
+        Some("json") => RenderTarget::Json,
+        Some("sarif") => RenderTarget::Sarif,
+        _ => RenderTarget::ColorTerminal,
+    }
+}
+
+/// How `--deny`, `--allow` and `--warnings-as-errors` say to report warnings. Commands without
+/// those flags, like `roc run`, report every warning as a warning.
+pub fn warning_levels_from_flags(matches: &ArgMatches) -> WarningLevels {
+    let kinds = |flag| {
+        matches
+            .try_get_many::<&'static str>(flag)
+            .ok()
+            .flatten()
+            .map(|kinds| kinds.copied().collect())
+            .unwrap_or_default()
+    };
+
+    WarningLevels {
+        deny: kinds(FLAG_DENY),
+        allow: kinds(FLAG_ALLOW),
+        warnings_as_errors: matches
+            .try_get_one::<bool>(FLAG_WARNINGS_AS_ERRORS)
+            .ok()
+            .flatten()
+            .copied()
+            .unwrap_or(false),
+    }
+}
+
 #[cfg(windows)]
 pub fn test(_matches: &ArgMatches, _target: Target) -> io::Result<i32> {
     todo!("running tests does not work on windows right now")
@@ -546,6 +790,8 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
     let start_time = Instant::now();
     let arena = Bump::new();
     let opt_level = opt_level_from_flags(matches);
+    let render = render_target_from_flags(matches);
+    let warning_levels = warning_levels_from_flags(matches);
 
     let threading = match matches.get_one::<usize>(FLAG_MAX_THREADS) {
         None => Threading::AllAvailable,
@@ -599,11 +845,11 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
         let load_config = LoadConfig {
             target,
             function_kind,
-            // TODO: expose this from CLI?
-            render: roc_reporting::report::RenderTarget::ColorTerminal,
+            render,
             palette: roc_reporting::report::DEFAULT_PALETTE,
             threading,
             exec_mode: ExecutionMode::Test,
+            opt_level: Some(opt_level),
         };
         let load_result = roc_load::load_and_monomorphize(
             arena,
@@ -619,10 +865,31 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
                 return handle_loading_problem(problem);
             }
             Err(LoadMonomorphizedError::ErrorModule(module)) => {
-                return handle_error_module(module, start_time.elapsed(), path.as_os_str(), false);
+                return handle_error_module(
+                    module,
+                    start_time.elapsed(),
+                    path.as_os_str(),
+                    false,
+                    render,
+                    &warning_levels,
+                );
             }
         };
-        let problems = report_problems_monomorphized(&mut loaded);
+        let problems = report_problems_monomorphized(&mut loaded, render, &warning_levels);
+
+        // Only warnings turned into errors by `--deny` or `--warnings-as-errors` get this far
+        if problems.errors > 0 {
+            if !render.is_machine_readable() {
+                problems.print_error_warning_count(start_time.elapsed());
+                println!(".");
+            }
+
+            return Ok(problems.exit_code());
+        }
+
+        if matches.get_flag(FLAG_CHECK_REFCOUNTS) {
+            roc_build::program::check_refcounts(arena, &mut loaded);
+        }
 
         let mut expectations = std::mem::take(&mut loaded.expectations);
 
@@ -641,11 +908,7 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
 
         // Print warnings before running tests.
         {
-            debug_assert_eq!(
-                problems.errors, 0,
-                "if there were errors, we would have already exited."
-            );
-            if problems.warnings > 0 {
+            if problems.warnings > 0 && !render.is_machine_readable() {
                 problems.print_error_warning_count(start_time.elapsed());
                 println!(".\n\nRunning tests…\n\n\x1B[36m{}\x1B[39m", "─".repeat(80));
             }
@@ -969,8 +1232,17 @@ pub fn build(
 
     let wasm_dev_backend = matches!(code_gen_backend, CodeGenBackend::Wasm);
 
+    let check_refcounts = matches.get_flag(FLAG_CHECK_REFCOUNTS);
+    let sanitizer = matches
+        .get_one::<String>(FLAG_SANITIZE)
+        .map(|name| Sanitizer::from_name(name).unwrap());
+
     let linking_strategy = if wasm_dev_backend {
         LinkingStrategy::Additive
+    } else if check_refcounts || sanitizer.is_some() {
+        // leaks are reported from an `atexit` handler, and sanitizers need their runtime, neither
+        // of which the surgical linker can link in
+        LinkingStrategy::Legacy
     } else {
         default_linking_strategy(matches, link_type, target)
     };
@@ -985,6 +1257,41 @@ pub fn build(
         user_error!("Cannot instrument binary for fuzzing while using a dev backend.");
     }
 
+    let trace_clones = matches.get_flag(FLAG_TRACE_CLONES);
+    if trace_clones && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot trace clones while using a dev backend.");
+    }
+
+    let profile_alloc = matches.get_flag(FLAG_PROFILE_ALLOC);
+    if profile_alloc && target == Target::Wasm32 {
+        user_error!("Cannot profile allocations when targeting wasm32.");
+    }
+
+    if check_refcounts && target == Target::Wasm32 {
+        user_error!("Cannot check refcounts when targeting wasm32.");
+    }
+
+    if check_refcounts && profile_alloc {
+        user_error!("Cannot profile allocations and check refcounts at the same time.");
+    }
+
+    if let Some(sanitizer) = sanitizer {
+        if !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+            user_error!("Cannot use a sanitizer while using a dev backend.");
+        }
+
+        if !matches!(
+            target.operating_system(),
+            OperatingSystem::Linux | OperatingSystem::Mac
+        ) {
+            user_error!("Sanitizers are only supported on Linux and macOS.");
+        }
+
+        if sanitizer == Sanitizer::Memory && target.operating_system() != OperatingSystem::Linux {
+            user_error!("The memory sanitizer is only supported on Linux.");
+        }
+    }
+
     let wasm_dev_stack_bytes: Option<u32> = matches
         .try_get_one::<u32>(FLAG_WASM_STACK_SIZE_KB)
         .ok()
@@ -1002,9 +1309,16 @@ pub fn build(
         emit_debug_info,
         emit_llvm_ir,
         fuzz,
+        trace_clones,
+        profile_alloc,
+        check_refcounts,
+        sanitizer,
     };
 
-    let load_config = standard_load_config(target, build_ordering, threading);
+    let render = render_target_from_flags(matches);
+    let warning_levels = warning_levels_from_flags(matches);
+    let mut load_config = standard_load_config(target, build_ordering, threading, opt_level);
+    load_config.render = render;
 
     let res_binary_path = roc_build::program::build_file(
         &arena,
@@ -1019,6 +1333,7 @@ pub fn build(
         wasm_dev_stack_bytes,
         roc_cache_dir,
         load_config,
+        &warning_levels,
         out_path,
         verbose,
     );
@@ -1043,6 +1358,10 @@ pub fn build(
                     // since the process is about to exit anyway.
                     // std::mem::forget(arena);
 
+                    if render.is_machine_readable() {
+                        return Ok(problems.exit_code());
+                    }
+
                     problems.print_error_warning_count(total_time);
                     println!(" while successfully building:\n\n    {generated_filename}");
 
@@ -1098,10 +1417,14 @@ pub fn build(
                         // Return a nonzero exit code due to fatal problem
                         return Ok(problems.exit_code());
                     }
-                    debug_assert_eq!(
-                        problems.errors, 0,
-                        "if there are non-fatal errors, they should have been returned as an error variant"
-                    );
+                    // Non-fatal errors are returned as an error variant, so these can only be
+                    // warnings turned into errors by `--deny` or `--warnings-as-errors`.
+                    if problems.errors > 0 {
+                        problems.print_error_warning_count(total_time);
+                        println!(".");
+
+                        return Ok(problems.exit_code());
+                    }
 
                     if problems.warnings > 0 {
                         problems.print_error_warning_count(total_time);
@@ -1132,9 +1455,14 @@ pub fn build(
                 }
             }
         }
-        Err(BuildFileError::ErrorModule { module, total_time }) => {
-            handle_error_module(module, total_time, path.as_os_str(), true)
-        }
+        Err(BuildFileError::ErrorModule { module, total_time }) => handle_error_module(
+            module,
+            total_time,
+            path.as_os_str(),
+            true,
+            render,
+            &warning_levels,
+        ),
         Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
     }
 }
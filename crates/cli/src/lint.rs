@@ -0,0 +1,360 @@
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use bumpalo::Bump;
+use roc_collections::MutMap;
+use roc_fmt::MigrationFlags;
+use roc_lint::{apply_fixes, lint_module, ALL_LINTS};
+use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadedModule, LoadingProblem, Threading};
+use roc_module::symbol::ModuleId;
+use roc_packaging::cache::RocCacheDir;
+use roc_problem::lint::{Fix, LintProblem};
+use roc_reporting::cli::{report_problems_and_lints, warning_kind, Problems, WarningLevels};
+use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+use roc_target::Target;
+use similar::{DiffTag, TextDiff};
+
+use crate::config::{find_config, parse_settings};
+use crate::format::{format_src_lines, parse_all, FormatConfig};
+
+/// Project settings for `roc lint`, from a `roc-lint.toml` file like this one:
+///
+/// ```toml
+/// # Report these kinds of warnings as errors
+/// deny = ["leftover-dbg"]
+/// # Don't report these kinds of warnings
+/// allow = ["confusable-names", "E0502"]
+/// ```
+///
+/// Kinds are the same as for `--deny` and `--allow`, which take precedence over the config file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LintConfig {
+    pub deny: Vec<&'static str>,
+    pub allow: Vec<&'static str>,
+}
+
+impl LintConfig {
+    pub const FILE_NAME: &'static str = "roc-lint.toml";
+
+    /// The config for the file or directory at `path`: the nearest `roc-lint.toml` in its
+    /// directory or the directories above it, if there is one.
+    pub fn find(path: &Path) -> Result<Self, String> {
+        find_config(path, Self::FILE_NAME, Self::parse)
+    }
+
+    /// Settings are lists of kinds of warnings, like `deny = ["unused-import"]`.
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+
+        parse_settings(src, |key, value, line_number| {
+            let kinds = match key {
+                "deny" => &mut config.deny,
+                "allow" => &mut config.allow,
+                other => {
+                    return Err(format!(
+                        "unknown setting `{other}` on line {line_number}; the settings are `deny` and `allow`"
+                    ));
+                }
+            };
+
+            let Some(list) = value
+                .strip_prefix('[')
+                .and_then(|list| list.strip_suffix(']'))
+            else {
+                return Err(format!(
+                    "expected a list like `[\"unused-import\"]` on line {line_number}"
+                ));
+            };
+
+            for kind in list.split(',').map(|kind| kind.trim().trim_matches('"')) {
+                if kind.is_empty() {
+                    continue;
+                }
+
+                match warning_kind(kind) {
+                    Some(code) => kinds.push(code),
+                    None => {
+                        return Err(format!(
+                            "`{kind}` on line {line_number} isn't a kind of warning (run `roc explain` to list them)"
+                        ));
+                    }
+                }
+            }
+
+            Ok(())
+        })?;
+
+        Ok(config)
+    }
+
+    /// Combine this config with the warning levels from the command line, which take precedence
+    pub fn warning_levels(&self, flags: WarningLevels) -> WarningLevels {
+        let mut deny: Vec<&'static str> = self
+            .deny
+            .iter()
+            .copied()
+            .filter(|kind| !flags.allow.contains(kind))
+            .collect();
+        let mut allow: Vec<&'static str> = self
+            .allow
+            .iter()
+            .copied()
+            .filter(|kind| !flags.deny.contains(kind))
+            .collect();
+
+        deny.extend(flags.deny);
+        allow.extend(flags.allow);
+
+        WarningLevels {
+            deny,
+            allow,
+            warnings_as_errors: flags.warnings_as_errors,
+        }
+    }
+}
+
+/// Check a module and the project modules it imports, like `roc check`, and run every lint on
+/// them too. With `fix`, the fixes for the lint problems that were reported are applied, and the
+/// files they're in are reformatted.
+#[allow(clippy::too_many_arguments)]
+pub fn lint_file<'a>(
+    arena: &'a Bump,
+    roc_file_path: PathBuf,
+    opt_main_path: Option<PathBuf>,
+    roc_cache_dir: RocCacheDir<'_>,
+    threading: Threading,
+    render: RenderTarget,
+    warning_levels: &WarningLevels,
+    fix: bool,
+) -> Result<(Problems, Duration), LoadingProblem<'a>> {
+    let lint_start = Instant::now();
+
+    let load_config = LoadConfig {
+        target: Target::LinuxX64,
+        function_kind: FunctionKind::from_env(),
+        render,
+        palette: DEFAULT_PALETTE,
+        threading,
+        exec_mode: ExecutionMode::Check,
+        opt_level: None,
+    };
+
+    let project_dir = roc_file_path
+        .canonicalize()
+        .unwrap_or_else(|_| roc_file_path.clone())
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    let mut loaded = roc_load::load_and_typecheck(
+        arena,
+        roc_file_path,
+        opt_main_path,
+        roc_cache_dir,
+        load_config,
+    )?;
+
+    let mut lint_problems = lint_project_modules(&loaded, &project_dir);
+
+    let problems = report_problems_and_lints(
+        &loaded.sources,
+        &loaded.interns,
+        &mut loaded.can_problems,
+        &mut loaded.type_problems,
+        &mut lint_problems,
+        render,
+        warning_levels,
+    );
+
+    if fix {
+        for (module_id, module_problems) in lint_problems {
+            let Some((path, src)) = loaded.sources.get(&module_id) else {
+                continue;
+            };
+
+            let fixes: Vec<_> = module_problems
+                .iter()
+                .filter_map(LintProblem::fix)
+                .collect();
+
+            if fixes.is_empty() {
+                continue;
+            }
+
+            let config = FormatConfig::find(path).unwrap_or_default();
+
+            match fix_src(arena, src, fixes, config) {
+                Some(fixed_src) => {
+                    if let Err(err) = std::fs::write(path, fixed_src) {
+                        eprintln!("Couldn't write the fixes to {}: {err}", path.display());
+                    }
+                }
+                None => eprintln!(
+                    "Couldn't fix {}, because the fixed code doesn't parse. This is a bug in one of the lints!",
+                    path.display()
+                ),
+            }
+        }
+    }
+
+    Ok((problems, lint_start.elapsed()))
+}
+
+/// Apply the fixes to `src`, and format the lines they changed. Returns `None` if the fixed code
+/// doesn't parse, in which case it shouldn't be written.
+fn fix_src(arena: &Bump, src: &str, fixes: Vec<&Fix>, config: FormatConfig) -> Option<String> {
+    let fixed_src = apply_fixes(src, fixes);
+
+    if parse_all(arena, arena.alloc_str(&fixed_src)).is_err() {
+        return None;
+    }
+
+    let fixed_lines: Vec<Range<u32>> = TextDiff::from_lines(src, fixed_src.as_str())
+        .ops()
+        .iter()
+        .filter(|op| op.tag() != DiffTag::Equal)
+        .map(|op| op.new_range().start as u32..op.new_range().end as u32)
+        .collect();
+    let flags = MigrationFlags {
+        snakify: false,
+        parens_and_commas: false,
+    };
+
+    Some(format_src_lines(arena, &fixed_src, &fixed_lines, flags, config).unwrap_or(fixed_src))
+}
+
+/// Run every lint on the modules in the project directory, leaving out packages and builtins
+fn lint_project_modules(
+    loaded: &LoadedModule,
+    project_dir: &Path,
+) -> MutMap<ModuleId, Vec<LintProblem>> {
+    let mut lint_problems = MutMap::default();
+
+    for (module_id, (path, src)) in loaded.sources.iter() {
+        let path = path.canonicalize().unwrap_or_else(|_| path.clone());
+
+        if !path.starts_with(project_dir) {
+            continue;
+        }
+
+        let decls = match loaded.declarations_by_id.get(module_id) {
+            Some(decls) => decls,
+            None => match loaded.typechecked.get(module_id) {
+                Some(checked) => &checked.decls,
+                None => continue,
+            },
+        };
+
+        let problems = lint_module(*module_id, decls, src, &loaded.interns, ALL_LINTS);
+
+        if !problems.is_empty() {
+            lint_problems.insert(*module_id, problems);
+        }
+    }
+
+    lint_problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use roc_region::all::{Position, Region};
+
+    #[test]
+    fn parse_lint_config() {
+        let config = LintConfig::parse(indoc!(
+            r#"
+            # Report these kinds of warnings as errors
+            deny = ["leftover-dbg"]
+            allow = ["confusable-names", "E0204"]
+            "#
+        ))
+        .unwrap();
+
+        assert_eq!(
+            config,
+            LintConfig {
+                deny: vec!["E0505"],
+                allow: vec!["E0503", "E0204"],
+            }
+        );
+    }
+
+    #[test]
+    fn lint_config_rejects_unknown_kinds() {
+        let err = LintConfig::parse("allow = [\"not-a-warning\"]").unwrap_err();
+
+        assert!(err.contains("`not-a-warning` on line 1"), "{err}");
+    }
+
+    fn fix_at(src: &str, target: &str, replacement: &str) -> Fix {
+        let start = src.find(target).unwrap() as u32;
+
+        Fix {
+            region: Region::new(
+                Position::new(start),
+                Position::new(start + target.len() as u32),
+            ),
+            replacement: replacement.to_string(),
+        }
+    }
+
+    #[test]
+    fn fixes_only_format_the_lines_they_change() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r#"
+            module [a, b]
+
+            a = [1,2]
+
+            b = 3
+            "#
+        );
+        let fix = fix_at(src, "3", "[3,4]");
+
+        assert_eq!(
+            fix_src(&arena, src, vec![&fix], FormatConfig::default()).as_deref(),
+            Some(indoc!(
+                r#"
+                module [a, b]
+
+                a = [1,2]
+
+                b = [3, 4]
+                "#
+            ))
+        );
+    }
+
+    #[test]
+    fn fixes_that_dont_parse_are_not_applied() {
+        let arena = Bump::new();
+        let src = "module [b]\n\nb = 3\n";
+        let fix = fix_at(src, "3", "(");
+
+        assert_eq!(
+            fix_src(&arena, src, vec![&fix], FormatConfig::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn command_line_takes_precedence_over_lint_config() {
+        let config = LintConfig {
+            deny: vec!["E0505"],
+            allow: vec!["E0503"],
+        };
+
+        let warning_levels = config.warning_levels(WarningLevels {
+            deny: vec!["E0503"],
+            allow: vec!["E0505"],
+            warnings_as_errors: false,
+        });
+
+        assert_eq!(warning_levels.deny, vec!["E0503"]);
+        assert_eq!(warning_levels.allow, vec!["E0505"]);
+    }
+}
@@ -3,15 +3,18 @@ use bumpalo::Bump;
 use roc_build::link::LinkType;
 use roc_build::program::{check_file, CodeGenBackend};
 use roc_cli::{
-    annotate_file, build_app, default_linking_strategy, format_files, format_src, test,
-    AnnotationProblem, BuildConfig, FormatMode, CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_DOCS,
-    CMD_FORMAT, CMD_FORMAT_ANNOTATE, CMD_GLUE, CMD_PREPROCESS_HOST, CMD_REPL, CMD_RUN, CMD_TEST,
-    CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_DEV, FLAG_DOCS_ROOT, FLAG_LIB, FLAG_MAIN,
-    FLAG_MIGRATE, FLAG_NO_COLOR, FLAG_NO_HEADER, FLAG_NO_LINK, FLAG_OUTPUT, FLAG_PP_DYLIB,
-    FLAG_PP_HOST, FLAG_PP_PLATFORM, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, FLAG_VERBOSE,
-    GLUE_DIR, GLUE_SPEC, ROC_FILE, VERSION,
+    alloc_report, annotate_file, build_app, default_linking_strategy, format_diff, format_files,
+    format_src, format_src_lines, lint_file, render_target_from_flags, test,
+    warning_levels_from_flags, AnnotationProblem, BuildConfig, FormatConfig, FormatMode,
+    LintConfig, ALLOC_LOG_FILE, ALLOC_SITES_FILE, CMD_ALLOC_REPORT, CMD_BUILD, CMD_CHECK, CMD_DEV,
+    CMD_DOCS, CMD_EXPLAIN, CMD_FORMAT, CMD_FORMAT_ANNOTATE, CMD_GLUE, CMD_LINT,
+    CMD_PREPROCESS_HOST, CMD_REPL, CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, ERROR_CODE,
+    FLAG_CHECK, FLAG_DEV, FLAG_DIFF, FLAG_DOCS_FORMAT, FLAG_DOCS_ROOT, FLAG_FIX, FLAG_LIB,
+    FLAG_LINES, FLAG_MAIN, FLAG_MIGRATE, FLAG_NO_COLOR, FLAG_NO_HEADER, FLAG_NO_LINK, FLAG_OUTPUT,
+    FLAG_PP_DYLIB, FLAG_PP_HOST, FLAG_PP_PLATFORM, FLAG_REPL_PLATFORM, FLAG_STDIN, FLAG_STDOUT,
+    FLAG_TARGET, FLAG_TIME, FLAG_VERBOSE, GLUE_DIR, GLUE_SPEC, ROC_FILE, VERSION,
 };
-use roc_docs::generate_docs_html;
+use roc_docs::{generate_docs_html, generate_docs_json};
 use roc_error_macros::{internal_error, user_error};
 use roc_fmt::MigrationFlags;
 use roc_gen_dev::AssemblyBackendMode;
@@ -22,6 +25,7 @@ use roc_target::Target;
 use std::fs::{self, FileType};
 use std::io::BufRead;
 use std::io::{self, Read, Write};
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use target_lexicon::Triple;
@@ -225,6 +229,8 @@ fn main() -> io::Result<()> {
             };
 
             let opt_main_path = matches.get_one::<PathBuf>(FLAG_MAIN);
+            let render = render_target_from_flags(matches);
+            let warning_levels = warning_levels_from_flags(matches);
 
             match roc_file_path.extension().and_then(OsStr::to_str) {
                 Some("md") => {
@@ -263,10 +269,14 @@ fn main() -> io::Result<()> {
                             emit_timings,
                             RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
                             threading,
+                            render,
+                            &warning_levels,
                         ) {
                             Ok((problems, total_time)) => {
-                                problems.print_error_warning_count(total_time);
-                                println!(".\n");
+                                if !render.is_machine_readable() {
+                                    problems.print_error_warning_count(total_time);
+                                    println!(".\n");
+                                }
 
                                 exit_code = problems.exit_code();
                             }
@@ -296,10 +306,14 @@ fn main() -> io::Result<()> {
                         emit_timings,
                         RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
                         threading,
+                        render,
+                        &warning_levels,
                     ) {
                         Ok((problems, total_time)) => {
-                            problems.print_error_warning_count(total_time);
-                            println!(".\n");
+                            if !render.is_machine_readable() {
+                                problems.print_error_warning_count(total_time);
+                                println!(".\n");
+                            }
                             Ok(problems.exit_code())
                         }
 
@@ -315,11 +329,59 @@ fn main() -> io::Result<()> {
                 }
             }
         }
+        Some((CMD_LINT, matches)) => {
+            let arena = Bump::new();
+
+            let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+            let threading = match matches.get_one::<usize>(roc_cli::FLAG_MAX_THREADS) {
+                None => Threading::AllAvailable,
+                Some(0) => user_error!("cannot build with at most 0 threads"),
+                Some(1) => Threading::Single,
+                Some(n) => Threading::AtMost(*n),
+            };
+
+            let opt_main_path = matches.get_one::<PathBuf>(FLAG_MAIN);
+            let render = render_target_from_flags(matches);
+            let config = LintConfig::find(roc_file_path).unwrap_or_else(|err| {
+                eprintln!("{err}");
+                std::process::exit(1);
+            });
+            let warning_levels = config.warning_levels(warning_levels_from_flags(matches));
+
+            match lint_file(
+                &arena,
+                roc_file_path.to_owned(),
+                opt_main_path.cloned(),
+                RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
+                threading,
+                render,
+                &warning_levels,
+                matches.get_flag(FLAG_FIX),
+            ) {
+                Ok((problems, total_time)) => {
+                    if !render.is_machine_readable() {
+                        problems.print_error_warning_count(total_time);
+                        println!(".\n");
+                    }
+                    Ok(problems.exit_code())
+                }
+
+                Err(LoadingProblem::FormattedReport(report, _)) => {
+                    print!("{report}");
+
+                    Ok(1)
+                }
+                Err(other) => {
+                    panic!("lint_file failed with error:\n{other:?}");
+                }
+            }
+        }
         Some((CMD_REPL, matches)) => {
             let has_color = !matches.get_one::<bool>(FLAG_NO_COLOR).unwrap();
             let has_header = !matches.get_one::<bool>(FLAG_NO_HEADER).unwrap();
+            let opt_platform = matches.get_one::<PathBuf>(FLAG_REPL_PLATFORM).cloned();
 
-            Ok(roc_repl_cli::main(has_color, has_header))
+            Ok(roc_repl_cli::main(has_color, has_header, opt_platform))
         }
         Some((CMD_DOCS, matches)) => {
             let root_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
@@ -339,11 +401,17 @@ fn main() -> io::Result<()> {
                 }
             };
 
-            generate_docs_html(
-                root_path.to_owned(),
-                out_dir.as_ref(),
-                maybe_root_dir.clone(),
-            );
+            match matches
+                .get_one::<String>(FLAG_DOCS_FORMAT)
+                .map(AsRef::as_ref)
+            {
+                Some("json") => generate_docs_json(root_path.to_owned(), out_dir.as_ref()),
+                _ => generate_docs_html(
+                    root_path.to_owned(),
+                    out_dir.as_ref(),
+                    maybe_root_dir.clone(),
+                ),
+            }
 
             Ok(0)
         }
@@ -389,19 +457,24 @@ fn main() -> io::Result<()> {
             let migrate = matches.get_flag(FLAG_MIGRATE);
             let format_mode = if to_stdout {
                 FormatMode::WriteToStdout
+            } else if matches.get_flag(FLAG_DIFF) {
+                FormatMode::Diff
             } else {
                 match matches.get_flag(FLAG_CHECK) {
                     true => FormatMode::CheckOnly,
                     false => FormatMode::WriteToFile,
                 }
             };
+            let opt_lines = matches
+                .get_many::<Range<u32>>(FLAG_LINES)
+                .map(|ranges| ranges.cloned().collect::<Vec<_>>());
             let flags = MigrationFlags {
                 snakify: migrate,
                 parens_and_commas: migrate,
             };
 
             if from_stdin && matches!(format_mode, FormatMode::WriteToFile) {
-                eprintln!("When using the --stdin flag, either the --check or the --stdout flag must also be specified. (Otherwise, it's unclear what filename to write to!)");
+                eprintln!("When using the --stdin flag, either the --check, --diff or --stdout flag must also be specified. (Otherwise, it's unclear what filename to write to!)");
                 std::process::exit(1);
             }
 
@@ -451,17 +524,35 @@ fn main() -> io::Result<()> {
                     std::process::exit(1);
                 });
 
-                match format_src(&arena, src, flags) {
+                // There's no file to start looking for a config from, so use the current directory.
+                let config = FormatConfig::find(&std::env::current_dir()?).unwrap_or_else(|err| {
+                    eprintln!("{err}");
+                    std::process::exit(1);
+                });
+                let formatted = match &opt_lines {
+                    Some(lines) => format_src_lines(&arena, src, lines, flags, config),
+                    None => format_src(&arena, src, flags, config),
+                };
+
+                match formatted {
                     Ok(formatted_src) => {
                         match format_mode {
                             FormatMode::CheckOnly => {
-                                if src == formatted_src {
+                                if src != formatted_src {
                                     eprintln!("One or more files need to be reformatted.");
                                     1
                                 } else {
                                     0
                                 }
                             }
+                            FormatMode::Diff => {
+                                if src != formatted_src {
+                                    print!("{}", format_diff("<stdin>", src, &formatted_src));
+                                    1
+                                } else {
+                                    0
+                                }
+                            }
                             FormatMode::WriteToStdout => {
                                 std::io::stdout()
                                     .lock()
@@ -483,7 +574,7 @@ fn main() -> io::Result<()> {
                     }
                 }
             } else {
-                match format_files(roc_files, format_mode, flags) {
+                match format_files(roc_files, format_mode, flags, opt_lines.as_deref()) {
                     Ok(()) => 0,
                     Err(message) => {
                         eprintln!("{message}");
@@ -494,10 +585,67 @@ fn main() -> io::Result<()> {
 
             Ok(format_exit_code)
         }
+        Some((CMD_ALLOC_REPORT, matches)) => {
+            let sites_path = matches.get_one::<PathBuf>(ALLOC_SITES_FILE).unwrap();
+            let sites = fs::read_to_string(sites_path).unwrap_or_else(|err| {
+                user_error!("Could not read {}: {err}", sites_path.display())
+            });
+
+            let report = match matches.get_one::<PathBuf>(ALLOC_LOG_FILE) {
+                Some(log_path) => {
+                    let log = fs::File::open(log_path).unwrap_or_else(|err| {
+                        user_error!("Could not read {}: {err}", log_path.display())
+                    });
+
+                    alloc_report(&sites, io::BufReader::new(log))
+                }
+                None => alloc_report(&sites, io::stdin().lock()),
+            };
+
+            match report {
+                Ok(report) => {
+                    print!("{report}");
+                    Ok(0)
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    Ok(1)
+                }
+            }
+        }
         Some((CMD_VERSION, _)) => {
             println!("roc {}", VERSION);
             Ok(0)
         }
+        Some((CMD_EXPLAIN, matches)) => match matches.get_one::<String>(ERROR_CODE) {
+            Some(code) => match roc_reporting::error_code::lookup(code) {
+                Some(error_code) => {
+                    println!(
+                        "{}\n\n{}",
+                        roc_reporting::report::pretty_header(
+                            &roc_reporting::report::title_with_code(
+                                error_code.title,
+                                Some(error_code.code)
+                            )
+                        ),
+                        error_code.explanation
+                    );
+                    Ok(0)
+                }
+                None => {
+                    eprintln!(
+                        "`{code}` is not a Roc error code. Error codes look like E0301, and appear in the header of each error. Run `roc explain` to list them all."
+                    );
+                    Ok(1)
+                }
+            },
+            None => {
+                for error_code in roc_reporting::error_code::all() {
+                    println!("{}  {}", error_code.code, error_code.title);
+                }
+                Ok(0)
+            }
+        },
         _ => unreachable!(),
     }?;
 
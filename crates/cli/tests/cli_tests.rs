@@ -38,6 +38,11 @@ mod cli_tests {
     const SUPPRESS_BUILD_HOST_WARNING_FLAG: &str =
         concatcp!("--", roc_cli::FLAG_SUPPRESS_BUILD_HOST_WARNING);
     const CHECK_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK);
+    const SANITIZE_ADDRESS_FLAG: &str = concatcp!("--", roc_cli::FLAG_SANITIZE, "=", "address");
+    const SANITIZE_UNDEFINED_FLAG: &str = concatcp!("--", roc_cli::FLAG_SANITIZE, "=", "undefined");
+    const SANITIZE_MEMORY_FLAG: &str = concatcp!("--", roc_cli::FLAG_SANITIZE, "=", "memory");
+    const DIFF_FLAG: &str = concatcp!("--", roc_cli::FLAG_DIFF);
+    const STDIN_FLAG: &str = concatcp!("--", roc_cli::FLAG_STDIN);
     #[allow(dead_code)]
     const TARGET_FLAG: &str = concatcp!("--", roc_cli::FLAG_TARGET);
 
@@ -163,6 +168,35 @@ mod cli_tests {
         );
     }
 
+    #[test]
+    #[cfg_attr(windows, ignore = "sanitizers are only supported on Linux and macOS")]
+    fn address_sanitizer_catches_out_of_bounds_read() {
+        copy_zig_glue::initialize_zig_test_platforms();
+
+        let cli_build = ExecCli::new(
+            CMD_BUILD,
+            file_from_root(
+                "crates/cli/tests/test-projects/sanitizers",
+                "out_of_bounds.roc",
+            ),
+        )
+        .arg(BUILD_HOST_FLAG)
+        .arg(SUPPRESS_BUILD_HOST_WARNING_FLAG)
+        .arg(SANITIZE_ADDRESS_FLAG);
+
+        cli_build.run().assert_clean_success();
+
+        // the host hands Roc a list that's longer than its allocation
+        let executable_output = cli_build.run_executable(false, None, None);
+        executable_output.assert_nonzero_exit();
+        assert!(
+            executable_output
+                .stderr
+                .contains("AddressSanitizer: heap-buffer-overflow"),
+            "expected ASan to report the out of bounds read\n\n{executable_output}"
+        );
+    }
+
     #[test]
     #[cfg_attr(windows, ignore)]
     // tea = The Elm Architecture
@@ -527,6 +561,62 @@ mod cli_tests {
             );
         }
 
+        #[test]
+        #[cfg_attr(windows, ignore = "sanitizers are only supported on Linux and macOS")]
+        fn run_multi_dep_str_address_sanitizer() {
+            build_platform_host();
+
+            let cli_build = ExecCli::new(
+                CMD_BUILD,
+                file_from_root(
+                    "crates/cli/tests/test-projects/fixtures/multi-dep-str",
+                    "main.roc",
+                ),
+            )
+            .arg(SANITIZE_ADDRESS_FLAG);
+
+            // valgrind can't run programs that are instrumented with ASan
+            cli_build.check_build_and_run("I am Dep2.str2\n", false, None, None);
+        }
+
+        #[test]
+        #[cfg_attr(windows, ignore = "sanitizers are only supported on Linux and macOS")]
+        fn run_multi_dep_str_undefined_sanitizer() {
+            build_platform_host();
+
+            let cli_build = ExecCli::new(
+                CMD_BUILD,
+                file_from_root(
+                    "crates/cli/tests/test-projects/fixtures/multi-dep-str",
+                    "main.roc",
+                ),
+            )
+            .arg(SANITIZE_UNDEFINED_FLAG);
+
+            cli_build.check_build_and_run("I am Dep2.str2\n", ALLOW_VALGRIND, None, None);
+        }
+
+        #[test]
+        #[cfg_attr(
+            not(target_os = "linux"),
+            ignore = "the memory sanitizer is only supported on Linux"
+        )]
+        fn run_multi_dep_str_memory_sanitizer() {
+            build_platform_host();
+
+            let cli_build = ExecCli::new(
+                CMD_BUILD,
+                file_from_root(
+                    "crates/cli/tests/test-projects/fixtures/multi-dep-str",
+                    "main.roc",
+                ),
+            )
+            .arg(SANITIZE_MEMORY_FLAG);
+
+            // valgrind can't run programs that are instrumented with MSan
+            cli_build.check_build_and_run("I am Dep2.str2\n", false, None, None);
+        }
+
         #[test]
         #[cfg_attr(windows, ignore)]
         fn run_multi_dep_thunk() {
@@ -1436,6 +1526,57 @@ mod cli_tests {
         .assert_nonzero_exit();
     }
 
+    #[test]
+    fn format_diff_reformatting_needed() {
+        let out = ExecCli::new(
+            CMD_FORMAT,
+            file_from_root(
+                "crates/cli/tests/test-projects/fixtures/format",
+                "not_formatted.roc",
+            ),
+        )
+        .arg(DIFF_FLAG)
+        .run();
+
+        out.assert_nonzero_exit();
+        assert!(out
+            .stdout
+            .contains("\n-app [main]   { pf: \"platform/main.roc\" }\n"));
+    }
+
+    #[test]
+    fn format_stdin_check_good() {
+        let file = file_from_root(
+            "crates/cli/tests/test-projects/fixtures/format",
+            "formatted.roc",
+        );
+        let src = std::fs::read_to_string(&file).unwrap();
+
+        ExecCli::new(CMD_FORMAT, file)
+            .arg(STDIN_FLAG)
+            .arg(CHECK_FLAG)
+            .run_with_stdin(&src)
+            .assert_clean_success();
+    }
+
+    #[test]
+    fn format_stdin_check_reformatting_needed() {
+        let file = file_from_root(
+            "crates/cli/tests/test-projects/fixtures/format",
+            "not_formatted.roc",
+        );
+        let src = std::fs::read_to_string(&file).unwrap();
+        let out = ExecCli::new(CMD_FORMAT, file)
+            .arg(STDIN_FLAG)
+            .arg(CHECK_FLAG)
+            .run_with_stdin(&src);
+
+        out.assert_nonzero_exit();
+        assert!(out
+            .stderr
+            .contains("One or more files need to be reformatted."));
+    }
+
     #[test]
     fn format_check_folders() {
         // This fails, because "not_formatted.roc" is present in this folder
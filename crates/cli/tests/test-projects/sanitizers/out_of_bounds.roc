@@ -0,0 +1,5 @@
+app [main] { pf: platform "platform/main.roc" }
+
+# The host passes in a list that claims to be longer than its allocation.
+main : List U8 -> U64
+main = |bytes| List.walk(bytes, 0, |sum, byte| sum + Num.to_u64(byte))
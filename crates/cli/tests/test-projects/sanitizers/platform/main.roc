@@ -0,0 +1,9 @@
+platform "sanitizers"
+    requires {} { main : List U8 -> U64 }
+    exposes []
+    packages {}
+    imports []
+    provides [main_for_host]
+
+main_for_host : List U8 -> U64
+main_for_host = \bytes -> main(bytes)
@@ -47,14 +47,22 @@ impl ExecCli {
     }
 
     pub fn run(&self) -> CmdOut {
+        self.run_with_stdin_opt(None)
+    }
+
+    /// Run the CLI with `stdin` written to its stdin, like for `roc format --stdin`
+    pub fn run_with_stdin(&self, stdin: &str) -> CmdOut {
+        self.run_with_stdin_opt(Some(stdin))
+    }
+
+    fn run_with_stdin_opt(&self, stdin_opt: Option<&str>) -> CmdOut {
         let mut roc_cli_command = Command::new(path_to_roc_binary());
 
         roc_cli_command.arg(self.sub_command);
         roc_cli_command.arg(self.roc_file_path.clone());
         roc_cli_command.args(&self.args);
 
-        let app_stdin_opt = None;
-        run_command(roc_cli_command, app_stdin_opt)
+        run_command(roc_cli_command, stdin_opt)
     }
 
     pub fn full_check_build_and_run(
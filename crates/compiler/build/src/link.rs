@@ -1,5 +1,7 @@
+use crate::program::Sanitizer;
 use crate::target::arch_str;
 use libloading::{Error, Library};
+use object::{Object, ObjectSymbol};
 use roc_command_utils::{cargo, clang, rustup, zig};
 use roc_debug_flags;
 use roc_error_macros::internal_error;
@@ -42,6 +44,103 @@ pub fn link(
     }
 }
 
+/// Links like [link], but lets clang link in the runtime of `sanitizer`, which knows where those
+/// libraries are and which other libraries they need.
+///
+/// The host should be built with [Sanitizer::clang_flag] as well. Hosts that use the C++ standard
+/// library get the C++ parts of the runtime too.
+pub fn link_sanitized(
+    target: Target,
+    output_path: PathBuf,
+    input_paths: &[&str],
+    link_type: LinkType,
+    sanitizer: Sanitizer,
+) -> io::Result<(Child, PathBuf)> {
+    let output_path = match link_type {
+        LinkType::Executable => output_path,
+        LinkType::Dylib => {
+            let mut output_path = output_path;
+            output_path.set_extension(target.dynamic_library_file_ext());
+
+            output_path
+        }
+        LinkType::None => {
+            internal_error!("link_sanitized should not be called with link type of none")
+        }
+    };
+
+    let mut clang_cmd = clang();
+
+    clang_cmd
+        .arg(sanitizer.clang_flag())
+        .args(input_paths)
+        .args(extra_link_flags());
+
+    if input_paths.iter().any(|path| uses_cpp_runtime(path)) {
+        clang_cmd.arg("-fsanitize-link-c++-runtime");
+    }
+
+    if let LinkType::Dylib = link_type {
+        clang_cmd.arg("-shared");
+    }
+
+    if target.operating_system() == OperatingSystem::Linux {
+        clang_cmd.args(["-lm", "-lpthread", "-ldl", "-lrt", "-lutil"]);
+    }
+
+    clang_cmd.args(["-o", output_path.to_str().unwrap()]);
+
+    debug_print_command(&clang_cmd);
+
+    let child = clang_cmd.spawn()?;
+
+    Ok((child, output_path))
+}
+
+/// Whether the object or archive at `path` calls into the C++ standard library or its ABI, like
+/// `operator new` or `__cxa_throw`.
+fn uses_cpp_runtime(path: &str) -> bool {
+    // `operator new` and `delete`, `std::`, exceptions, and the guards of static locals. Rust's
+    // std also refers to `__cxa_thread_atexit_impl`, but that one is in libc.
+    const PREFIXES: [&str; 9] = [
+        "Znw",
+        "Zna",
+        "Zdl",
+        "Zda",
+        "ZSt",
+        "ZNSt",
+        "cxa_throw",
+        "cxa_guard_",
+        "gxx_personality",
+    ];
+
+    fn object_uses_cpp_runtime(data: &[u8]) -> bool {
+        let Ok(file) = object::File::parse(data) else {
+            return false;
+        };
+
+        file.symbols().any(|symbol| {
+            // Mach-O symbols have an extra leading underscore
+            let name = symbol.name().unwrap_or_default().trim_start_matches('_');
+
+            symbol.is_undefined() && PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+        })
+    }
+
+    let Ok(data) = std::fs::read(path) else {
+        return false;
+    };
+
+    match object::read::archive::ArchiveFile::parse(&*data) {
+        Ok(archive) => archive.members().any(|member| {
+            member
+                .and_then(|member| member.data(&*data))
+                .is_ok_and(object_uses_cpp_runtime)
+        }),
+        Err(_) => object_uses_cpp_runtime(&data),
+    }
+}
+
 // Attempts to find a file that is stored relative to the roc executable.
 // Since roc is built in target/debug/roc, we may need to drop that path to find the file.
 // This is used to avoid depending on the current working directory.
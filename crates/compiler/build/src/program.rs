@@ -1,8 +1,10 @@
-use crate::link::{link, preprocess_host_wasm32, rebuild_host, LinkType, LinkingStrategy};
+use crate::link::{
+    link, link_sanitized, preprocess_host_wasm32, rebuild_host, LinkType, LinkingStrategy,
+};
 use bumpalo::collections::CollectIn;
 use bumpalo::Bump;
 use inkwell::memory_buffer::MemoryBuffer;
-use roc_error_macros::internal_error;
+use roc_error_macros::{internal_error, user_error};
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::{module_from_builtins, LlvmBackendMode};
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
@@ -13,7 +15,7 @@ use roc_load::{
 use roc_mono::ir::{OptLevel, SingleEntryPoint};
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::{
-    cli::{report_problems, Problems},
+    cli::{report_problems, Problems, WarningLevels},
     report::{RenderTarget, DEFAULT_PALETTE},
 };
 use roc_target::{Architecture, Target};
@@ -37,21 +39,33 @@ pub struct CodeGenTiming {
     pub total: Duration,
 }
 
-pub fn report_problems_monomorphized(loaded: &mut MonomorphizedModule) -> Problems {
+pub fn report_problems_monomorphized(
+    loaded: &mut MonomorphizedModule,
+    render: RenderTarget,
+    warning_levels: &WarningLevels,
+) -> Problems {
     report_problems(
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        render,
+        warning_levels,
     )
 }
 
-pub fn report_problems_typechecked(loaded: &mut LoadedModule) -> Problems {
+pub fn report_problems_typechecked(
+    loaded: &mut LoadedModule,
+    render: RenderTarget,
+    warning_levels: &WarningLevels,
+) -> Problems {
     report_problems(
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        render,
+        warning_levels,
     )
 }
 
@@ -78,6 +92,61 @@ pub enum CodeGenBackend {
     Wasm,
 }
 
+/// The LLVM sanitizers that the Roc object can be instrumented with.
+///
+/// The zig builtins aren't instrumented, so MSan may report reads of memory that only they wrote.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sanitizer {
+    Address,
+    Undefined,
+    Memory,
+}
+
+impl Sanitizer {
+    pub const NAMES: [&'static str; 3] = ["address", "undefined", "memory"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "address" => Some(Sanitizer::Address),
+            "undefined" => Some(Sanitizer::Undefined),
+            "memory" => Some(Sanitizer::Memory),
+            _ => None,
+        }
+    }
+
+    /// The `-fsanitize` value that instruments C the way [Sanitizer::passes] instrument Roc, and
+    /// links in the runtime they need. The host should be built with it too.
+    ///
+    /// Most of UBSan is about C semantics that clang checks while it generates IR, and Roc already
+    /// checks its own arithmetic. What's left is `local-bounds`, which is LLVM's bounds-checking
+    /// pass, so that's what both sides get for `undefined`.
+    pub fn clang_flag(self) -> &'static str {
+        match self {
+            Sanitizer::Address => "-fsanitize=address",
+            Sanitizer::Undefined => "-fsanitize=undefined,local-bounds",
+            Sanitizer::Memory => "-fsanitize=memory",
+        }
+    }
+
+    /// The LLVM passes that instrument the Roc object.
+    fn passes(self) -> &'static [&'static str] {
+        match self {
+            Sanitizer::Address => &["asan"],
+            Sanitizer::Undefined => &["bounds-checking"],
+            Sanitizer::Memory => &["msan"],
+        }
+    }
+
+    /// The function attribute that the passes only instrument functions with.
+    fn function_attribute(self) -> Option<&'static str> {
+        match self {
+            Sanitizer::Address => Some("sanitize_address"),
+            Sanitizer::Undefined => None,
+            Sanitizer::Memory => Some("sanitize_memory"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct CodeGenOptions {
     pub backend: CodeGenBackend,
@@ -85,6 +154,14 @@ pub struct CodeGenOptions {
     pub emit_debug_info: bool,
     pub emit_llvm_ir: bool,
     pub fuzz: bool,
+    /// Report the builtins that copy a shared value instead of updating it in place.
+    pub trace_clones: bool,
+    /// Report every allocation along with the call in user code that made it.
+    pub profile_alloc: bool,
+    /// Report refcounts that are decremented too often or too little, and uses after free.
+    pub check_refcounts: bool,
+    /// Instrument the Roc object with an LLVM sanitizer, and link in its runtime.
+    pub sanitizer: Option<Sanitizer>,
 }
 
 type GenFromMono<'a> = (CodeObject, CodeGenTiming, ExpectMetadata<'a>);
@@ -92,7 +169,7 @@ type GenFromMono<'a> = (CodeObject, CodeGenTiming, ExpectMetadata<'a>);
 #[allow(clippy::too_many_arguments)]
 pub fn gen_from_mono_module<'a>(
     arena: &'a bumpalo::Bump,
-    loaded: MonomorphizedModule<'a>,
+    mut loaded: MonomorphizedModule<'a>,
     roc_file_path: &Path,
     target: Target,
     code_gen_options: CodeGenOptions,
@@ -105,6 +182,18 @@ pub fn gen_from_mono_module<'a>(
     let fuzz = code_gen_options.fuzz;
     let opt = code_gen_options.opt_level;
 
+    if code_gen_options.trace_clones {
+        trace_clones(arena, &mut loaded);
+    }
+
+    if code_gen_options.profile_alloc {
+        profile_alloc(arena, &mut loaded, roc_file_path);
+    }
+
+    if code_gen_options.check_refcounts {
+        check_refcounts(arena, &mut loaded);
+    }
+
     match code_gen_options.backend {
         CodeGenBackend::Wasm => {
             assert_ne!(
@@ -140,10 +229,141 @@ pub fn gen_from_mono_module<'a>(
             debug,
             emit_llvm_ir,
             fuzz,
+            code_gen_options.sanitizer,
         ),
     }
 }
 
+/// Prints the in-place updates that copy every time they run, and instruments all of them to
+/// `dbg` a message whenever they copy at runtime.
+fn trace_clones<'a>(arena: &'a Bump, loaded: &mut MonomorphizedModule<'a>) {
+    use roc_mono::in_place::SharedReason;
+
+    let shared = roc_mono::in_place::trace_clones(
+        arena,
+        &loaded.layout_interner,
+        loaded.module_id,
+        &mut loaded.interns,
+        &loaded.sources,
+        &loaded.call_sites,
+        &mut loaded.procedures,
+    );
+
+    if !shared.is_empty() {
+        eprintln!("These updates copy their argument every time, because it's shared:\n");
+
+        for (site, reason) in shared {
+            let reason = match reason {
+                SharedReason::UsedLater => "it's used again afterwards",
+                SharedReason::Borrowed => "it's borrowed",
+            };
+
+            eprintln!("    {} {} ({reason})", site.location, site.callee);
+        }
+
+        eprintln!();
+    }
+}
+
+/// Instruments the calls in user code to report every allocation they make along with their id,
+/// and writes the call that goes with each id to a `.alloc-sites` file next to the app module.
+fn profile_alloc<'a>(arena: &'a Bump, loaded: &mut MonomorphizedModule<'a>, roc_file_path: &Path) {
+    use roc_mono::alloc_profile::profile_allocations;
+    use roc_mono::layout::LayoutInterner;
+    use std::fmt::Write;
+
+    let alloc_sites = profile_allocations(
+        arena,
+        &loaded.layout_interner,
+        loaded.module_id,
+        &mut loaded.interns,
+        &loaded.sources,
+        &loaded.call_sites,
+        &mut loaded.procedures,
+    );
+
+    let mut table = String::new();
+    for (index, (site, layout)) in alloc_sites.iter().enumerate() {
+        writeln!(
+            table,
+            "{}\t{}\t{}\t{}",
+            index + 1,
+            site.location,
+            site.callee,
+            loaded.layout_interner.dbg(*layout)
+        )
+        .unwrap();
+    }
+
+    let sites_path = roc_file_path.with_extension("alloc-sites");
+    if let Err(err) = std::fs::write(&sites_path, table) {
+        user_error!(
+            "Could not write the allocation sites to {}: {err}",
+            sites_path.display()
+        );
+    }
+
+    eprintln!(
+        "The program reports its allocations on stderr. To see where it allocates, run\n\n    roc alloc-report {} <file with its stderr>\n",
+        sites_path.display()
+    );
+}
+
+/// Instruments the program to check refcounts at runtime, reporting the call in user code that
+/// made an allocation when something goes wrong with it.
+///
+/// The checks start in the procs the host calls. Tests are checked for leaks one by one, and
+/// programs once they exit.
+pub fn check_refcounts<'a>(arena: &'a Bump, loaded: &mut MonomorphizedModule<'a>) {
+    use roc_collections::all::MutSet;
+    use roc_mono::alloc_profile::profile_allocations;
+    use roc_mono::refcount_check::instrument_refcount_checks;
+
+    let alloc_sites = profile_allocations(
+        arena,
+        &loaded.layout_interner,
+        loaded.module_id,
+        &mut loaded.interns,
+        &loaded.sources,
+        &loaded.call_sites,
+        &mut loaded.procedures,
+    );
+
+    let (entry_points, per_call): (MutSet<_>, _) = match &loaded.entry_point {
+        EntryPoint::Executable {
+            exposed_to_host, ..
+        } => (
+            exposed_to_host
+                .iter()
+                .map(|(_, symbol, _)| *symbol)
+                .collect(),
+            false,
+        ),
+        EntryPoint::Test => (
+            loaded
+                .toplevel_expects
+                .values()
+                .flat_map(|expects| expects.pure.keys().copied())
+                .collect(),
+            true,
+        ),
+    };
+
+    instrument_refcount_checks(
+        arena,
+        loaded.module_id,
+        loaded
+            .interns
+            .all_ident_ids
+            .get_mut(&loaded.module_id)
+            .unwrap(),
+        &alloc_sites,
+        &entry_points,
+        per_call,
+        &mut loaded.procedures,
+    );
+}
+
 // TODO how should imported modules factor into this? What if those use builtins too?
 // TODO this should probably use more helper functions
 // TODO make this polymorphic in the llvm functions so it can be reused for another backend.
@@ -158,6 +378,7 @@ fn gen_from_mono_module_llvm<'a>(
     emit_debug_info: bool,
     emit_llvm_ir: bool,
     fuzz: bool,
+    sanitizer: Option<Sanitizer>,
 ) -> GenFromMono<'a> {
     use crate::target::{self, convert_opt_level};
     use inkwell::attributes::{Attribute, AttributeLoc};
@@ -259,6 +480,18 @@ fn gen_from_mono_module_llvm<'a>(
         &loaded.glue_layouts,
     );
 
+    if let Some(attribute) = sanitizer.and_then(Sanitizer::function_attribute) {
+        let kind_id = Attribute::get_named_enum_kind_id(attribute);
+        debug_assert!(kind_id > 0);
+        let enum_attr = context.create_enum_attribute(kind_id, 0);
+
+        for function in module.get_functions() {
+            if function.count_basic_blocks() > 0 {
+                function.add_attribute(AttributeLoc::Function, enum_attr);
+            }
+        }
+    }
+
     // We are now finished building the LLVM IR.
     let generate_final_ir = all_code_gen_start.elapsed();
     let code_gen_object_start = Instant::now();
@@ -266,7 +499,7 @@ fn gen_from_mono_module_llvm<'a>(
     crate::llvm_passes::optimize_llvm_ir(&env, target, opt_level, emit_debug_info, &app_ll_file);
 
     let gen_sanitizers = cfg!(feature = "sanitizers") && std::env::var("ROC_SANITIZERS").is_ok();
-    let memory_buffer = if fuzz || gen_sanitizers {
+    let memory_buffer = if fuzz || gen_sanitizers || sanitizer.is_some() {
         let dir = tempfile::tempdir().unwrap();
         let dir = dir.into_path();
 
@@ -293,6 +526,9 @@ fn gen_from_mono_module_llvm<'a>(
                 "-sanitizer-coverage-trace-compares",
             ]);
         }
+        if let Some(sanitizer) = sanitizer {
+            passes.extend_from_slice(sanitizer.passes());
+        }
         if gen_sanitizers {
             for sanitizer in std::env::var("ROC_SANITIZERS")
                 .unwrap()
@@ -653,10 +889,16 @@ pub fn handle_error_module(
     total_time: std::time::Duration,
     filename: &OsStr,
     print_run_anyway_hint: bool,
+    render: RenderTarget,
+    warning_levels: &WarningLevels,
 ) -> std::io::Result<i32> {
     debug_assert!(module.total_problems() > 0);
 
-    let problems = report_problems_typechecked(&mut module);
+    let problems = report_problems_typechecked(&mut module, render, warning_levels);
+
+    if render.is_machine_readable() {
+        return Ok(problems.exit_code());
+    }
 
     problems.print_error_warning_count(total_time);
 
@@ -694,6 +936,7 @@ pub fn standard_load_config(
     target: Target,
     order: BuildOrdering,
     threading: Threading,
+    opt_level: OptLevel,
 ) -> LoadConfig {
     let exec_mode = match order {
         BuildOrdering::BuildIfChecks => ExecutionMode::ExecutableIfCheck,
@@ -707,6 +950,7 @@ pub fn standard_load_config(
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode,
+        opt_level: Some(opt_level),
     }
 }
 
@@ -724,10 +968,12 @@ pub fn build_file<'a>(
     wasm_dev_stack_bytes: Option<u32>,
     roc_cache_dir: RocCacheDir<'_>,
     load_config: LoadConfig,
+    warning_levels: &WarningLevels,
     out_path: Option<&Path>,
     verbose: bool,
 ) -> Result<BuiltFile<'a>, BuildFileError<'a>> {
     let compilation_start = Instant::now();
+    let render = load_config.render;
 
     let loaded = roc_load::load_and_monomorphize(
         arena,
@@ -750,12 +996,66 @@ pub fn build_file<'a>(
         suppress_build_host_warning,
         wasm_dev_stack_bytes,
         loaded,
+        render,
+        warning_levels,
         compilation_start,
         out_path,
         verbose,
     )
 }
 
+/// Like [build_file], but for an app whose source is in memory rather than in a file,
+/// like the ones the REPL generates. Relative paths in the app are resolved from `src_dir`.
+#[allow(clippy::too_many_arguments)]
+pub fn build_str<'a>(
+    arena: &'a Bump,
+    target: Target,
+    app_module_path: PathBuf,
+    app_module_source: &'a str,
+    src_dir: PathBuf,
+    code_gen_options: CodeGenOptions,
+    link_type: LinkType,
+    linking_strategy: LinkingStrategy,
+    build_host: bool,
+    suppress_build_host_warning: bool,
+    roc_cache_dir: RocCacheDir<'_>,
+    load_config: LoadConfig,
+    out_path: Option<&Path>,
+) -> Result<BuiltFile<'a>, BuildFileError<'a>> {
+    let compilation_start = Instant::now();
+    let render = load_config.render;
+
+    let loaded = roc_load::load_and_monomorphize_from_str(
+        arena,
+        app_module_path.clone(),
+        app_module_source,
+        src_dir,
+        None,
+        roc_cache_dir,
+        load_config,
+    )
+    .map_err(|e| BuildFileError::from_mono_error(e, compilation_start))?;
+
+    build_loaded_file(
+        arena,
+        target,
+        app_module_path,
+        code_gen_options,
+        false,
+        link_type,
+        linking_strategy,
+        build_host,
+        suppress_build_host_warning,
+        None,
+        loaded,
+        render,
+        &WarningLevels::default(),
+        compilation_start,
+        out_path,
+        false,
+    )
+}
+
 #[derive(Debug, PartialEq, Eq)]
 /// Opt because of possible None value
 // Advice: do not try to wrap this in an Option, that would require cloning in build_loaded_file.
@@ -827,6 +1127,8 @@ fn build_loaded_file<'a>(
     suppress_build_host_warning: bool,
     wasm_dev_stack_bytes: Option<u32>,
     loaded: roc_load::MonomorphizedModule<'a>,
+    render: RenderTarget,
+    warning_levels: &WarningLevels,
     compilation_start: Instant,
     out_path: Option<&Path>,
     verbose: bool,
@@ -905,7 +1207,7 @@ fn build_loaded_file<'a>(
     // This only needs to be mutable for report_problems. This can't be done
     // inside a nested scope without causing a borrow error!
     let mut loaded = loaded;
-    let problems = report_problems_monomorphized(&mut loaded);
+    let problems = report_problems_monomorphized(&mut loaded, render, warning_levels);
     let loaded = loaded;
 
     let (roc_app_bytes, code_gen_timing, expect_metadata) = gen_from_mono_module(
@@ -1022,8 +1324,17 @@ fn build_loaded_file<'a>(
                 inputs.push(builtins_host_tempfile.path().to_str().unwrap());
             }
 
-            let (mut child, _) = link(target, output_exe_path.clone(), &inputs, link_type)
-                .map_err(|_| todo!("linker failed to spawn."))?;
+            let (mut child, _) = match code_gen_options.sanitizer {
+                Some(sanitizer) => link_sanitized(
+                    target,
+                    output_exe_path.clone(),
+                    &inputs,
+                    link_type,
+                    sanitizer,
+                ),
+                None => link(target, output_exe_path.clone(), &inputs, link_type),
+            }
+            .unwrap_or_else(|err| user_error!("Could not start the linker: {err}"));
 
             let exit_status = child
                 .wait()
@@ -1350,6 +1661,8 @@ pub fn check_file<'a>(
     emit_timings: bool,
     roc_cache_dir: RocCacheDir<'_>,
     threading: Threading,
+    render: RenderTarget,
+    warning_levels: &WarningLevels,
 ) -> Result<(Problems, Duration), LoadingProblem<'a>> {
     let compilation_start = Instant::now();
 
@@ -1362,11 +1675,11 @@ pub fn check_file<'a>(
     let load_config = LoadConfig {
         target,
         function_kind: FunctionKind::from_env(),
-        // TODO: expose this from CLI?
-        render: RenderTarget::ColorTerminal,
+        render,
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Check,
+        opt_level: None,
     };
     let mut loaded = roc_load::load_and_typecheck(
         arena,
@@ -1418,7 +1731,10 @@ pub fn check_file<'a>(
         println!("Finished checking in {} ms\n", compilation_end.as_millis(),);
     }
 
-    Ok((report_problems_typechecked(&mut loaded), compilation_end))
+    Ok((
+        report_problems_typechecked(&mut loaded, render, warning_levels),
+        compilation_end,
+    ))
 }
 
 pub fn build_str_test<'a>(
@@ -1435,6 +1751,10 @@ pub fn build_str_test<'a>(
         emit_debug_info: false,
         emit_llvm_ir: false,
         fuzz: false,
+        trace_clones: false,
+        profile_alloc: false,
+        check_refcounts: false,
+        sanitizer: None,
     };
 
     let emit_timings = false;
@@ -1446,9 +1766,15 @@ pub fn build_str_test<'a>(
     let build_ordering = BuildOrdering::AlwaysBuild;
     let threading = Threading::AtMost(2);
 
-    let load_config = standard_load_config(target, build_ordering, threading);
+    let load_config = standard_load_config(
+        target,
+        build_ordering,
+        threading,
+        code_gen_options.opt_level,
+    );
 
     let compilation_start = std::time::Instant::now();
+    let render = load_config.render;
 
     // Step 1: compile the app and generate the .o file
     let loaded = roc_load::load_and_monomorphize_from_str(
@@ -1477,6 +1803,8 @@ pub fn build_str_test<'a>(
         suppress_build_host_warning,
         wasm_dev_stack_bytes,
         loaded,
+        render,
+        &WarningLevels::default(),
         compilation_start,
         None,
         false,
@@ -215,6 +215,7 @@ comptime {
     exportStrFn(str.strWithAsciiLowercased, "with_ascii_lowercased");
     exportStrFn(str.strWithAsciiUppercased, "with_ascii_uppercased");
     exportStrFn(str.strCaselessAsciiEquals, "caseless_ascii_equals");
+    exportStrFn(str.strIsUnique, "is_unique");
 
     for (INTEGERS) |T| {
         str.exportFromInt(T, ROC_BUILTINS ++ "." ++ STR ++ ".from_int.");
@@ -241,6 +242,9 @@ comptime {
     exportUtilsFn(utils.decrefCheckNullC, "decref_check_null");
     exportUtilsFn(utils.allocateWithRefcountC, "allocate_with_refcount");
     exportUtilsFn(utils.dictPseudoSeed, "dict_pseudo_seed");
+    exportUtilsFn(utils.setAllocSite, "set_alloc_site");
+    exportUtilsFn(utils.checkRefcountsStart, "check_refcounts_start");
+    exportUtilsFn(utils.checkRefcountLeaks, "check_refcount_leaks");
 
     @export(panic_utils.panic, .{ .name = "roc_builtins.utils." ++ "panic", .linkage = .weak });
     @export(dbg_utils.dbg_impl, .{ .name = "roc_builtins.utils." ++ "dbg_impl", .linkage = .weak });
@@ -2725,6 +2725,12 @@ pub fn strAllocationPtr(
     return string.getAllocationPtr();
 }
 
+pub fn strIsUnique(
+    string: RocStr,
+) callconv(.C) bool {
+    return string.isUnique();
+}
+
 pub fn strReleaseExcessCapacity(
     string: RocStr,
 ) callconv(.C) RocStr {
@@ -1,5 +1,7 @@
 const std = @import("std");
 const builtin = @import("builtin");
+const RocStr = @import("str.zig").RocStr;
+const panic_help = @import("panic.zig").panic_help;
 
 const DEBUG_INCDEC = false;
 const DEBUG_TESTING_ALLOC = false;
@@ -145,7 +147,275 @@ fn testing_roc_panic(c_ptr: *anyopaque, tag_id: u32) callconv(.C) void {
     @panic("Roc panicked");
 }
 
+// When a program is built with `roc build --profile-alloc`, it tells us which call site it is in
+// before every call that may allocate, and we report every allocation to stderr as
+// `roc-alloc <a|r> <site> <bytes>`, for `roc alloc-report` to aggregate.
+// Site 0 stands for allocations made outside of any call the compiler recorded.
+var profiling_allocations = false;
+var alloc_site: u32 = 0;
+
+// Returns the previous site, so that it can be restored once the call returns.
+pub fn setAllocSite(site: u32) callconv(.C) u32 {
+    // when checking refcounts, the sites only name the allocations that go wrong
+    profiling_allocations = !checking_refcounts;
+
+    const previous = alloc_site;
+    alloc_site = site;
+    return previous;
+}
+
+fn reportAllocation(kind: u8, size: usize) void {
+    if (builtin.target.cpu.arch != .wasm32) {
+        const stderr = std.io.getStdErr().writer();
+        stderr.print("roc-alloc {c} {d} {d}\n", .{ kind, alloc_site, size }) catch {};
+    }
+}
+
+// When a program is built with `--check-refcounts`, every allocation is tracked along with the
+// site that made it (see `setAllocSite`). Freed allocations are kept around rather than handed
+// back to the host, so that a later incref or decref of them can be told apart from one of a
+// new allocation at the same address. That catches
+//
+// - decrements of an allocation that was already freed (its refcount already hit zero),
+// - other uses of an allocation after it was freed,
+// - allocations that are still live when a test finishes, or when the program exits.
+//
+// The tracking is not thread safe, so it's only meant for single-threaded programs and tests.
+var checking_refcounts = false;
+
+const TrackedState = enum(u8) {
+    live,
+    freed,
+    // freed by a realloc that moved it, so its memory belongs to the host again
+    moved,
+};
+
+const TrackedAllocation = struct {
+    // 0 for an empty slot
+    refcount_ptr: usize,
+    allocation: usize,
+    alignment: u32,
+    site: u32,
+    state: TrackedState,
+};
+
+const EMPTY_SLOT = TrackedAllocation{
+    .refcount_ptr = 0,
+    .allocation = 0,
+    .alignment = 0,
+    .site = 0,
+    .state = .live,
+};
+
+// An open addressing hash table keyed by the refcount pointer. Entries are never removed, only
+// marked as freed, until the next `checkRefcountsStart` of a test.
+var tracked: [*]TrackedAllocation = undefined;
+var tracked_capacity: usize = 0;
+var tracked_count: usize = 0;
+
+// The name of site `n` is on line `n - 1`.
+var site_names: []const u8 = "";
+var refcount_error: [1024]u8 = undefined;
+
+extern fn atexit(function: *const fn () callconv(.C) void) c_int;
+extern fn _exit(status: c_int) noreturn;
+
+// Starts checking refcounts, and returns whether they were already being checked.
+//
+// Tests pass `per_call`, to forget about the allocations of the test that ran before. Programs
+// check for leaks once, when they exit.
+pub fn checkRefcountsStart(names: RocStr, per_call: bool) callconv(.C) bool {
+    const already_checking = checking_refcounts;
+
+    if (!already_checking) {
+        // the names may be a small string that lives on the caller's stack
+        const bytes = names.asSlice();
+        const copy = @as([*]u8, @ptrCast(roc_alloc(@max(bytes.len, 1), 1) orelse unreachable));
+        @memcpy(copy[0..bytes.len], bytes);
+        site_names = copy[0..bytes.len];
+
+        checking_refcounts = true;
+        profiling_allocations = false;
+
+        if (!per_call and builtin.target.cpu.arch != .wasm32) {
+            _ = atexit(&reportLeaksAtExit);
+        }
+    }
+
+    if (per_call) {
+        resetTracked();
+    }
+
+    return already_checking;
+}
+
+// Returns true if every tracked allocation was freed, and panics otherwise.
+pub fn checkRefcountLeaks() callconv(.C) bool {
+    var leaked: usize = 0;
+    var first_site: u32 = 0;
+    for (tracked[0..tracked_capacity]) |entry| {
+        if (entry.refcount_ptr != 0 and entry.state == .live) {
+            if (leaked == 0) {
+                first_site = entry.site;
+            }
+            leaked += 1;
+        }
+    }
+
+    if (leaked == 0) {
+        return true;
+    }
+
+    // the leaked allocations should not be reported again by the next check
+    resetTracked();
+
+    const message = std.fmt.bufPrint(
+        &refcount_error,
+        "Refcount check failed: {d} allocation(s) were never freed, the first one made by {s}",
+        .{ leaked, siteName(first_site) },
+    ) catch &refcount_error;
+    panic_help(message, 0);
+}
+
+fn reportLeaksAtExit() callconv(.C) void {
+    const site_count = std.mem.count(u8, site_names, "\n") + 2;
+    const per_site = @as([*]usize, @ptrCast(@alignCast(roc_alloc(site_count * @sizeOf(usize), @alignOf(usize)) orelse unreachable)));
+    @memset(per_site[0..site_count], 0);
+
+    var leaked: usize = 0;
+    for (tracked[0..tracked_capacity]) |entry| {
+        if (entry.refcount_ptr != 0 and entry.state == .live) {
+            per_site[@min(entry.site, site_count - 1)] += 1;
+            leaked += 1;
+        }
+    }
+
+    if (leaked == 0) {
+        return;
+    }
+
+    const stderr = std.io.getStdErr().writer();
+    stderr.print("\nRefcount check failed: {d} allocation(s) were never freed:\n\n", .{leaked}) catch {};
+    for (per_site[0..site_count], 0..) |count, site| {
+        if (count != 0) {
+            stderr.print("    {d} made by {s}\n", .{ count, siteName(@intCast(site)) }) catch {};
+        }
+    }
+
+    // exiting again from an atexit handler is undefined, so leave without running the others
+    _exit(1);
+}
+
+fn siteName(site: u32) []const u8 {
+    if (site != 0) {
+        var lines = std.mem.splitScalar(u8, site_names, '\n');
+        var index: u32 = 1;
+        while (lines.next()) |line| : (index += 1) {
+            if (index == site) {
+                return line;
+            }
+        }
+    }
+
+    return "code outside of any recorded call";
+}
+
+fn reportRefcountError(comptime what: []const u8, entry: *const TrackedAllocation) noreturn {
+    const message = std.fmt.bufPrint(
+        &refcount_error,
+        "Refcount check failed: the allocation made by {s} was " ++ what ++ " after it was freed",
+        .{siteName(entry.site)},
+    ) catch &refcount_error;
+    panic_help(message, 0);
+}
+
+fn checkNotFreed(comptime what: []const u8, refcount_ptr: usize) void {
+    if (findTracked(refcount_ptr)) |entry| {
+        if (entry.state != .live) {
+            reportRefcountError(what, entry);
+        }
+    }
+}
+
+fn hashPtr(refcount_ptr: usize) usize {
+    const golden: usize = @truncate(0x9E3779B97F4A7C15);
+    return (refcount_ptr >> 3) *% golden;
+}
+
+// The slot of the allocation, or the empty slot where it would go.
+fn trackedSlot(refcount_ptr: usize) *TrackedAllocation {
+    const mask = tracked_capacity - 1;
+    var index = hashPtr(refcount_ptr) & mask;
+    while (true) : (index = (index + 1) & mask) {
+        const slot = &tracked[index];
+        if (slot.refcount_ptr == refcount_ptr or slot.refcount_ptr == 0) {
+            return slot;
+        }
+    }
+}
+
+fn findTracked(refcount_ptr: usize) ?*TrackedAllocation {
+    if (tracked_capacity == 0) return null;
+
+    const slot = trackedSlot(refcount_ptr);
+    return if (slot.refcount_ptr == 0) null else slot;
+}
+
+fn trackAllocation(refcount_ptr: usize, allocation: usize, alignment: u32, site: u32) void {
+    if (4 * (tracked_count + 1) > 3 * tracked_capacity) {
+        growTracked();
+    }
+
+    const slot = trackedSlot(refcount_ptr);
+    if (slot.refcount_ptr == 0) {
+        tracked_count += 1;
+    }
+
+    slot.* = TrackedAllocation{
+        .refcount_ptr = refcount_ptr,
+        .allocation = allocation,
+        .alignment = alignment,
+        .site = site,
+        .state = .live,
+    };
+}
+
+fn growTracked() void {
+    const old = tracked;
+    const old_capacity = tracked_capacity;
+
+    tracked_capacity = if (old_capacity == 0) 1024 else 2 * old_capacity;
+    const bytes = tracked_capacity * @sizeOf(TrackedAllocation);
+    tracked = @as([*]TrackedAllocation, @ptrCast(@alignCast(roc_alloc(bytes, @alignOf(TrackedAllocation)) orelse unreachable)));
+    @memset(tracked[0..tracked_capacity], EMPTY_SLOT);
+
+    if (old_capacity != 0) {
+        for (old[0..old_capacity]) |entry| {
+            if (entry.refcount_ptr != 0) {
+                trackedSlot(entry.refcount_ptr).* = entry;
+            }
+        }
+
+        roc_dealloc(@ptrCast(old), @alignOf(TrackedAllocation));
+    }
+}
+
+// Hands the freed allocations back to the host, and forgets about the live ones.
+fn resetTracked() void {
+    for (tracked[0..tracked_capacity]) |*entry| {
+        if (entry.refcount_ptr != 0 and entry.state == .freed) {
+            roc_dealloc(@ptrFromInt(entry.allocation), entry.alignment);
+        }
+        entry.* = EMPTY_SLOT;
+    }
+
+    tracked_count = 0;
+}
+
 pub fn alloc(size: usize, alignment: u32) ?[*]u8 {
+    if (profiling_allocations) {
+        reportAllocation('a', size);
+    }
     return @as(?[*]u8, @ptrCast(roc_alloc(size, alignment)));
 }
 
@@ -153,6 +423,9 @@ pub fn realloc(c_ptr: [*]u8, new_size: usize, old_size: usize, alignment: u32) [
     if (DEBUG_INCDEC and builtin.target.cpu.arch != .wasm32) {
         std.debug.print("- realloc {*}\n", .{c_ptr});
     }
+    if (profiling_allocations) {
+        reportAllocation('r', new_size);
+    }
     return @as([*]u8, @ptrCast(roc_realloc(c_ptr, new_size, old_size, alignment)));
 }
 
@@ -208,6 +481,10 @@ pub fn increfRcPtrC(ptr_to_refcount: *isize, amount: isize) callconv(.C) void {
         std.debug.print("| increment {*}: ", .{ptr_to_refcount});
     }
 
+    if (checking_refcounts) {
+        checkNotFreed("used", @intFromPtr(ptr_to_refcount));
+    }
+
     // Ensure that the refcount is not whole program lifetime.
     const refcount: isize = ptr_to_refcount.*;
     if (!rcConstant(refcount)) {
@@ -343,6 +620,18 @@ inline fn free_ptr_to_refcount(
     const extra_bytes = @max(required_space, alignment);
     const allocation_ptr = @as([*]u8, @ptrCast(refcount_ptr)) - (extra_bytes - @sizeOf(usize));
 
+    if (checking_refcounts) {
+        if (findTracked(@intFromPtr(refcount_ptr))) |entry| {
+            if (entry.state != .live) {
+                reportRefcountError("freed again", entry);
+            }
+
+            // keep the memory, so that later uses of it are caught
+            entry.state = .freed;
+            return;
+        }
+    }
+
     // NOTE: we don't even check whether the refcount is "infinity" here!
     dealloc(allocation_ptr, alignment);
 
@@ -362,6 +651,11 @@ inline fn decref_ptr_to_refcount(
         std.debug.print("| decrement {*}: ", .{refcount_ptr});
     }
 
+    // a freed refcount is zero, which would otherwise look like a constant
+    if (checking_refcounts) {
+        checkNotFreed("decremented", @intFromPtr(refcount_ptr));
+    }
+
     // Due to RC alignmen tmust take into acount pointer size.
     const ptr_width = @sizeOf(usize);
     const alignment = @max(ptr_width, element_alignment);
@@ -405,6 +699,10 @@ pub fn isUnique(
 
     const isizes: [*]isize = @as([*]isize, @ptrFromInt(masked_ptr));
 
+    if (checking_refcounts) {
+        checkNotFreed("used", @intFromPtr(isizes - 1));
+    }
+
     const refcount = (isizes - 1)[0];
 
     if (DEBUG_INCDEC and builtin.target.cpu.arch != .wasm32) {
@@ -522,6 +820,10 @@ pub fn allocateWithRefcount(
     const refcount_ptr = @as([*]usize, @ptrCast(@as([*]align(ptr_width) u8, @alignCast(data_ptr)) - ptr_width));
     refcount_ptr[0] = if (RC_TYPE == .none) REFCOUNT_MAX_ISIZE else 1;
 
+    if (checking_refcounts) {
+        trackAllocation(@intFromPtr(refcount_ptr), @intFromPtr(new_bytes), alignment, alloc_site);
+    }
+
     return data_ptr;
 }
 
@@ -555,6 +857,17 @@ pub fn unsafeReallocate(
     const new_allocation = realloc(old_allocation, new_width, old_width, alignment);
 
     const new_source = @as([*]u8, @ptrCast(new_allocation)) + extra_bytes;
+
+    if (checking_refcounts) {
+        var site = alloc_site;
+        if (findTracked(@intFromPtr(source_ptr - ptr_width))) |entry| {
+            site = entry.site;
+            entry.state = .moved;
+        }
+
+        trackAllocation(@intFromPtr(new_source - ptr_width), @intFromPtr(new_allocation), alignment, site);
+    }
+
     return new_source;
 }
 
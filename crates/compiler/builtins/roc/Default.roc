@@ -0,0 +1,66 @@
+module [
+    Default,
+    default,
+    default_num,
+    default_str,
+    default_list,
+    default_dict,
+    default_bool,
+]
+
+import Bool exposing [Bool]
+import Dict exposing [Dict]
+import List
+import Str
+import Num exposing [Num]
+
+## A type that has a default value, like `0` for numbers or `""` for strings.
+##
+## [Default] can be derived for records, tuples and tag unions whose contents
+## implement [Default], and is implemented by every number type, [Bool], [Str],
+## [List] and [Dict](../Dict#Dict).
+Default implements
+    ## Returns the default value of a type.
+    ##
+    ## **Note** that when `default` is derived by the Roc compiler, the default
+    ## value is built as follows:
+    ##
+    ## 1. Numbers default to `0`.
+    ## 2. [Bool] defaults to [Bool.false](../Bool#false).
+    ## 3. [Str], [List] and [Dict](../Dict#Dict) default to being empty.
+    ## 4. Records and tuples default to every field having its default value.
+    ## 5. Tag unions default to the tag with the fewest payloads, and of those,
+    ## the one whose name comes first alphabetically. Its payloads have their
+    ## default values.
+    ##
+    ## This makes it easy to build a big record where most fields have their
+    ## default value, by updating the fields that shouldn't:
+    ## ```roc
+    ## Config : { name : Str, retries : U8, verbose : Bool }
+    ##
+    ## default_config : Config
+    ## default_config = Default.default({})
+    ##
+    ## expect { default_config & retries: 3 } == { name: "", retries: 3, verbose: Bool.false }
+    ## ```
+    default : {} -> a where a implements Default
+
+## Returns `0`, which is the default value of every number type.
+default_num : {} -> Num *
+default_num = |{}| 0
+
+## Returns `""`.
+default_str : {} -> Str
+default_str = |{}| ""
+
+## Returns an empty list.
+default_list : {} -> List *
+default_list = |{}| []
+
+## Returns an empty dictionary.
+default_dict : {} -> Dict * *
+default_dict = |{}| Dict.empty({})
+
+## Returns [Bool.false](../Bool#false).
+default_bool : {} -> Bool
+default_bool = |{}| Bool.false
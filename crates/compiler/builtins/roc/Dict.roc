@@ -503,7 +503,12 @@ insert_helper = |buckets0, data0, bucket_index0, dist_and_fingerprint0, key, val
 ## ```
 remove : Dict k v, k -> Dict k v
 remove = |@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), key|
-    if !(List.is_empty(data)) then
+    if List.is_empty(buckets) and !(List.is_empty(data)) then
+        # This dict came from a host and has not been indexed yet (see `find`).
+        @Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts })
+        |> reserve(0)
+        |> remove(key)
+    else if !(List.is_empty(data)) then
         (bucket_index0, dist_and_fingerprint0) = next_while_less(buckets, key, shifts)
         (bucket_index1, dist_and_fingerprint1) = remove_helper(buckets, bucket_index0, dist_and_fingerprint0, data, key)
 
@@ -544,6 +549,16 @@ remove_helper = |buckets, bucket_index, dist_and_fingerprint, data, key|
 ## ```
 update : Dict k v, k, (Result v [Missing] -> Result v [Missing]) -> Dict k v
 update = |@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), key, alter|
+    if List.is_empty(buckets) and !(List.is_empty(data)) then
+        # This dict came from a host and has not been indexed yet (see `find`).
+        @Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts })
+        |> reserve(0)
+        |> update(key, alter)
+    else
+        update_indexed(@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), key, alter)
+
+update_indexed : Dict k v, k, (Result v [Missing] -> Result v [Missing]) -> Dict k v
+update_indexed = |@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), key, alter|
     { bucket_index, result } = find(@Dict({ buckets, data, max_bucket_capacity, max_load_factor, shifts }), key)
     when result is
         Ok(value) ->
@@ -762,12 +777,19 @@ find = |@Dict({ buckets, data, shifts }), key|
     dist_and_fingerprint = dist_and_fingerprint_from_hash(hash)
     bucket_index = bucket_index_from_hash(hash, shifts)
 
-    if !(List.is_empty(data)) then
+    if List.is_empty(data) then
+        { bucket_index, result: Err(KeyNotFound) }
+    else if List.is_empty(buckets) then
+        # Hosts can build a dict (e.g. with roc_std's RocDict) but can't reproduce our
+        # hash seed, so they only fill in `data`. Operations that need the buckets index
+        # such a dict first; lookups just scan the data.
+        when List.find_first(data, |(k, _)| k == key) is
+            Ok((_, v)) -> { bucket_index, result: Ok(v) }
+            Err(NotFound) -> { bucket_index, result: Err(KeyNotFound) }
+    else
         # TODO: this is true in the C++ code, confirm it in Roc as well.
         # unrolled loop. *Always* check a few directly, then enter the loop. This is faster.
         find_first_unroll(buckets, bucket_index, dist_and_fingerprint, data, key)
-    else
-        { bucket_index, result: Err(KeyNotFound) }
 
 find_manual_unrolls = 2
 
@@ -1217,6 +1239,40 @@ expect
     |> len
     |> Bool.is_eq(0)
 
+expect
+    # A dict as a host builds it: entries in `data`, but no buckets.
+    from_host_data = |data|
+        @Dict(
+            {
+                buckets: [],
+                data,
+                max_bucket_capacity: 0,
+                max_load_factor: default_max_load_factor,
+                shifts: calc_shifts_for_size(List.len(data), default_max_load_factor),
+            },
+        )
+
+    dict = from_host_data([("a", 1), ("b", 2), ("c", 3)])
+
+    changed =
+        dict
+        |> remove("a")
+        |> insert("d", 4)
+        |> update("b", |_| Ok(20))
+
+    grown =
+        from_host_data([("a", 1), ("b", 2)])
+        |> insert("c", 3)
+
+    (get(dict, "b") == Ok(2))
+    and (get(dict, "d") == Err(KeyNotFound))
+    and contains(dict, "c")
+    and (len(changed) == 3)
+    and (get(changed, "a") == Err(KeyNotFound))
+    and (get(changed, "b") == Ok(20))
+    and (get(changed, "d") == Ok(4))
+    and (grown == from_list([("a", 1), ("b", 2), ("c", 3)]))
+
 # All BadKey's hash to the same location.
 # This is needed to test some robinhood logic.
 BadKey := U64 implements [
@@ -0,0 +1,82 @@
+module [
+    Sort,
+    compare,
+    compare_bool,
+    compare_str,
+    compare_list,
+]
+
+import Bool exposing [Bool]
+import List
+import Str
+import Num exposing [U64]
+
+## A value that can be put in order.
+##
+## [Sort] can be derived for records, tuples and tag unions whose contents
+## implement [Sort], and is implemented by [Bool], [Str], lists of values that
+## implement [Sort], and every number type except [F32](../Num#F32) and
+## [F64](../Num#F64). Like [Eq](../Bool#Eq), floating-point numbers are left
+## out because `NaN` can't be put in order with other numbers.
+Sort implements
+    ## Returns `LT` if the first value comes before the second one, `GT` if it
+    ## comes after it, and `EQ` if neither comes first.
+    ##
+    ## **Note** that when `compare` is derived by the Roc compiler, values are
+    ## compared structurally. The rules for this are as follows:
+    ##
+    ## 1. Records are compared field by field, in the alphabetical order of
+    ## their field names. The first fields that aren't equal decide the order.
+    ## 2. Tuples are compared element by element, from the first one.
+    ## 3. Tags are compared by name, in alphabetical order. Tags with the same
+    ## name are compared by their payloads, from the first one.
+    ## 4. [Bool.false](../Bool#false) comes before [Bool.true](../Bool#true).
+    ## 5. [Str] values are compared by their UTF-8 bytes.
+    ## 6. [List] values are compared element by element. If one list runs out
+    ## of elements first, it comes first.
+    ##
+    ## Since `compare` returns the same tags as [Num.compare](../Num#compare),
+    ## it can be passed to [List.sort_with](../List#sort_with) directly:
+    ## ```roc
+    ## expect List.sort_with([B(2), A(3), B(1)], Sort.compare) == [A(3), B(1), B(2)]
+    ## ```
+    compare : a, a -> [LT, EQ, GT] where a implements Sort
+
+## Compares two [Bool]s, with [Bool.false](../Bool#false) coming first.
+compare_bool : Bool, Bool -> [LT, EQ, GT]
+compare_bool = |a, b|
+    if a == b then
+        EQ
+    else if a then
+        GT
+    else
+        LT
+
+## Compares two strings by their UTF-8 bytes.
+compare_str : Str, Str -> [LT, EQ, GT]
+compare_str = |a, b|
+    compare_list(Str.to_utf8(a), Str.to_utf8(b))
+
+## Compares two lists element by element. If one list is a prefix of the
+## other, it comes first.
+compare_list : List a, List a -> [LT, EQ, GT] where a implements Sort
+compare_list = |a, b|
+    compare_list_from(a, b, 0)
+
+compare_list_from : List a, List a, U64 -> [LT, EQ, GT] where a implements Sort
+compare_list_from = |a, b, index|
+    when List.get(a, index) is
+        Ok(x) ->
+            when List.get(b, index) is
+                Ok(y) ->
+                    when compare(x, y) is
+                        EQ -> compare_list_from(a, b, Num.add_wrap(index, 1))
+                        ordering -> ordering
+
+                Err(OutOfBounds) -> GT
+
+        Err(OutOfBounds) ->
+            if index < List.len(b) then
+                LT
+            else
+                EQ
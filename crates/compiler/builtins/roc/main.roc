@@ -11,4 +11,6 @@ package [
     Hash,
     Box,
     Inspect,
+    Sort,
+    Default,
 ] {}
@@ -362,6 +362,7 @@ pub const STR_RELEASE_EXCESS_CAPACITY: &str = "roc_builtins.str.release_excess_c
 pub const STR_WITH_ASCII_LOWERCASED: &str = "roc_builtins.str.with_ascii_lowercased";
 pub const STR_WITH_ASCII_UPPERCASED: &str = "roc_builtins.str.with_ascii_uppercased";
 pub const STR_CASELESS_ASCII_EQUALS: &str = "roc_builtins.str.caseless_ascii_equals";
+pub const STR_IS_UNIQUE: &str = "roc_builtins.str.is_unique";
 
 pub const LIST_MAP: &str = "roc_builtins.list.map";
 pub const LIST_MAP2: &str = "roc_builtins.list.map2";
@@ -433,6 +434,9 @@ pub const UTILS_FREE_DATA_PTR: &str = "roc_builtins.utils.free_data_ptr";
 pub const UTILS_IS_UNIQUE: &str = "roc_builtins.utils.is_unique";
 pub const UTILS_DECREF_CHECK_NULL: &str = "roc_builtins.utils.decref_check_null";
 pub const UTILS_DICT_PSEUDO_SEED: &str = "roc_builtins.utils.dict_pseudo_seed";
+pub const UTILS_SET_ALLOC_SITE: &str = "roc_builtins.utils.set_alloc_site";
+pub const UTILS_CHECK_REFCOUNTS_START: &str = "roc_builtins.utils.check_refcounts_start";
+pub const UTILS_CHECK_REFCOUNT_LEAKS: &str = "roc_builtins.utils.check_refcount_leaks";
 
 pub const UTILS_EXPECT_FAILED_START_SHARED_BUFFER: &str =
     "roc_builtins.utils.expect_failed_start_shared_buffer";
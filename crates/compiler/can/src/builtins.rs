@@ -91,6 +91,10 @@ macro_rules! map_symbol_to_lowlevel_and_arity {
                 LowLevel::RefCountIncDataPtr => unimplemented!(),
                 LowLevel::RefCountDecDataPtr=> unimplemented!(),
                 LowLevel::RefCountIsUnique => unimplemented!(),
+                LowLevel::StrIsUnique => unimplemented!(),
+                LowLevel::SetAllocSite => unimplemented!(),
+                LowLevel::RefCountCheckStart => unimplemented!(),
+                LowLevel::RefCountCheckLeaks => unimplemented!(),
                 LowLevel::ListIncref => unimplemented!(),
                 LowLevel::ListDecref => unimplemented!(),
 
@@ -3182,7 +3182,9 @@ fn to_pending_value_def<'a>(
         ModuleImport(module_import) => {
             let qualified_module_name: QualifiedModuleName = module_import.name.value.into();
             let module_name = qualified_module_name.module.clone();
-            let pq_module_name = qualified_module_name.into_pq_module_name(env.opt_shorthand);
+            let pq_module_name = env
+                .qualified_module_ids
+                .import_name(qualified_module_name, env.opt_shorthand);
 
             let module_id = env
                 .qualified_module_ids
@@ -217,6 +217,94 @@ fn is_eq<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
     )
 }
 
+fn compare<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
+    let alloc_pat = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
+    let alloc_expr = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
+
+    let payload1 = "#payload1";
+    let payload2 = "#payload2";
+
+    let opaque_ref = alloc_pat(ast::Pattern::OpaqueRef(at_opaque));
+    // \@Opaq payload1
+    let opaque1 = ast::Pattern::Apply(
+        opaque_ref,
+        &*env.arena.alloc([Loc::at(
+            DERIVED_REGION,
+            ast::Pattern::Identifier { ident: payload1 },
+        )]),
+    );
+    // \@Opaq payload2
+    let opaque2 = ast::Pattern::Apply(
+        opaque_ref,
+        &*env.arena.alloc([Loc::at(
+            DERIVED_REGION,
+            ast::Pattern::Identifier { ident: payload2 },
+        )]),
+    );
+
+    // Sort.compare(payload1, payload2), through an alias that still
+    // reaches the builtin if this module shadows `Sort`
+    let call_member = alloc_expr(ast::Expr::Apply(
+        alloc_expr(ast::Expr::Var {
+            module_name: "#Sort",
+            ident: "compare",
+        }),
+        &*env.arena.alloc([
+            &*alloc_expr(ast::Expr::Var {
+                module_name: "",
+                ident: payload1,
+            }),
+            &*alloc_expr(ast::Expr::Var {
+                module_name: "",
+                ident: payload2,
+            }),
+        ]),
+        CalledVia::Space,
+    ));
+
+    // \@Opaq payload1, @Opaq payload2 -> Sort.compare(payload1, payload2)
+    ast::Expr::Closure(
+        env.arena.alloc([
+            Loc::at(DERIVED_REGION, opaque1),
+            Loc::at(DERIVED_REGION, opaque2),
+        ]),
+        call_member,
+    )
+}
+
+fn default<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
+    let alloc_expr = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
+
+    // Default.default({}), through an alias that still
+    // reaches the builtin if this module shadows `Default`
+    let call_member = alloc_expr(ast::Expr::Apply(
+        alloc_expr(ast::Expr::Var {
+            module_name: "#Default",
+            ident: "default",
+        }),
+        &*env
+            .arena
+            .alloc([&*alloc_expr(ast::Expr::Record(Collection::empty()))]),
+        CalledVia::Space,
+    ));
+
+    // @Opaq(Default.default({}))
+    let wrap_opaque = alloc_expr(ast::Expr::Apply(
+        alloc_expr(ast::Expr::OpaqueRef(at_opaque)),
+        &*env.arena.alloc([&*call_member]),
+        CalledVia::Space,
+    ));
+
+    // \{} -> @Opaq(Default.default({}))
+    ast::Expr::Closure(
+        env.arena.alloc([Loc::at(
+            DERIVED_REGION,
+            ast::Pattern::RecordDestructure(Collection::empty()),
+        )]),
+        wrap_opaque,
+    )
+}
+
 fn to_inspector<'a>(env: &mut Env<'a>, at_opaque: &'a str) -> ast::Expr<'a> {
     // Inspect for opaques as a tag so it prints `@Opaque payload`.
     let alloc_pat = |it| env.arena.alloc(Loc::at(DERIVED_REGION, it));
@@ -330,6 +418,8 @@ pub(crate) fn synthesize_member_impl<'a>(
             format!("#{opaque_name}_to_inspector"),
             to_inspector(env, at_opaque),
         ),
+        Symbol::SORT_COMPARE => (format!("#{opaque_name}_compare"), compare(env, at_opaque)),
+        Symbol::DEFAULT_DEFAULT => (format!("#{opaque_name}_default"), default(env, at_opaque)),
         other => internal_error!("{:?} is not a derivable ability member!", other),
     };
 
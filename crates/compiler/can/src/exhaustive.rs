@@ -255,12 +255,77 @@ fn index_var(
     }
 }
 
+/// The names of all fields of the record type `var`, in sorted order, or [None] if `var` is not
+/// a record with known fields.
+fn record_field_names(subs: &Subs, mut var: Variable) -> Option<Vec<Lowercase>> {
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Content::Alias(_, _, inner, AliasKind::Structural) => var = *inner,
+            Content::Structure(FlatType::Record(fields, ext)) => {
+                let mut names: Vec<Lowercase> = fields
+                    .unsorted_iterator(subs, *ext)
+                    .ok()?
+                    .map(|(name, _)| name.clone())
+                    .collect();
+                names.sort();
+
+                return Some(names);
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// A record pattern only mentions the fields it destructures, so two record patterns matching
+/// the same record may have different fields. Rewrite the pattern to mention every field of the
+/// record, in the same order, so that the rows of a match line up and missing patterns can be
+/// reported with all of their fields.
+fn expand_record_ctor(
+    union: Union,
+    patterns: Vec<SketchedPattern>,
+    all_fields: Vec<Lowercase>,
+) -> (Union, Vec<SketchedPattern>) {
+    let given_fields = match union.render_as {
+        RenderAs::Record(fields) => fields,
+        _ => internal_error!("only record constructors can be expanded"),
+    };
+
+    let mut given: VecMap<Lowercase, SketchedPattern> =
+        given_fields.into_iter().zip(patterns).collect();
+
+    let patterns = all_fields
+        .iter()
+        .map(|field| match given.remove(field) {
+            Some((_, pattern)) => pattern,
+            None => SketchedPattern::Anything,
+        })
+        .collect();
+
+    let union = Union {
+        alternatives: vec![Ctor {
+            name: CtorName::Tag(TagName("#Record".into())),
+            tag_id: TagId(0),
+            arity: all_fields.len(),
+        }],
+        render_as: RenderAs::Record(all_fields),
+    };
+
+    (union, patterns)
+}
+
 impl SketchedPattern {
     fn reify(self, subs: &Subs, real_var: Variable) -> Result<Pattern, TypeError> {
         match self {
             Self::Anything => Ok(Pattern::Anything),
             Self::Literal(lit) => Ok(Pattern::Literal(lit)),
             Self::KnownCtor(union, tag_id, patterns) => {
+                let (union, patterns) = match record_field_names(subs, real_var) {
+                    Some(all_fields) if matches!(union.render_as, RenderAs::Record(..)) => {
+                        expand_record_ctor(union, patterns, all_fields)
+                    }
+                    _ => (union, patterns),
+                };
+
                 let index_ctor = IndexCtor::of_union(&union, tag_id);
                 let arg_vars = index_var(subs, real_var, index_ctor, &union.render_as)?;
 
@@ -443,7 +508,7 @@ fn sketch_pattern(pattern: &crate::pattern::Pattern) -> SketchedPattern {
         }
 
         // Treat this like a literal so we mark it as non-exhaustive
-        MalformedPattern(..) => SP::Literal(Literal::Byte(1)),
+        MalformedPattern(..) => SP::Literal(Literal::Malformed),
 
         Underscore
         | Identifier(_)
@@ -684,6 +684,11 @@ impl ScopedIdentIds {
     }
 }
 
+/// Derived impls refer to builtins that a module can shadow by these names,
+/// which can't be written in source.
+pub(crate) const SHADOWABLE_BUILTIN_ALIASES: [(ModuleId, &str); 2] =
+    [(ModuleId::SORT, "#Sort"), (ModuleId::DEFAULT, "#Default")];
+
 #[derive(Debug, Clone)]
 pub struct ScopeModules {
     /// The ids of all modules in scope
@@ -712,6 +717,13 @@ impl ScopeModules {
             names.push(module_name.clone());
         }
 
+        for (module_id, alias) in SHADOWABLE_BUILTIN_ALIASES {
+            ids.push(module_id);
+            names.push(alias.into());
+            sources.push(ScopeModuleSource::Builtin);
+            params.push(None);
+        }
+
         if !home_id.is_builtin() {
             ids.push(home_id);
             names.push(home_name);
@@ -728,9 +740,10 @@ impl ScopeModules {
     }
 
     pub fn lookup(&self, module_name: &ModuleName) -> Option<ModuleLookup> {
+        // Search from the end, so a module that shadows a builtin wins
         self.names
             .iter()
-            .position(|name| name == module_name)
+            .rposition(|name| name == module_name)
             .map(|index| ModuleLookup {
                 id: self.ids[index],
                 params: self.params[index],
@@ -748,7 +761,11 @@ impl ScopeModules {
     }
 
     pub fn available_names(&self) -> impl Iterator<Item = &ModuleName> {
-        self.names.iter()
+        self.names.iter().filter(|name| {
+            !SHADOWABLE_BUILTIN_ALIASES
+                .iter()
+                .any(|(_, alias)| name.as_str() == *alias)
+        })
     }
 
     pub fn insert(
@@ -758,12 +775,17 @@ impl ScopeModules {
         params: Option<(Variable, Symbol)>,
         region: Region,
     ) -> Result<(), ScopeModuleSource> {
-        if let Some(index) = self.names.iter().position(|name| name == &module_name) {
+        if let Some(index) = self.names.iter().rposition(|name| name == &module_name) {
             if self.ids[index] == module_id {
                 return Ok(());
             }
 
-            return Err(self.sources[index]);
+            let is_shadowable = matches!(self.sources[index], ScopeModuleSource::Builtin)
+                && ModuleId::shadowable_builtin(module_name.as_str()) == Some(self.ids[index]);
+
+            if !is_shadowable {
+                return Err(self.sources[index]);
+            }
         }
 
         self.ids.push(module_id);
@@ -0,0 +1,148 @@
+//! Completion of names and record fields that doesn't depend on any editor. The language server
+//! and the REPL each find the candidates in scope their own way, and share the rest: which
+//! characters make up the word being completed, how a record field path like `a.b.c` is split and
+//! walked, and how the candidates' types are printed.
+use roc_module::symbol::{Interns, ModuleId};
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
+use roc_types::subs::{Content, FlatType, Subs, Variable};
+
+/// Whether `ch` can be part of a word being completed, like `List.map!` or `person.first_name`
+pub fn is_completion_char(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, '_' | '.' | '!')
+}
+
+/// Where the word being completed, which ends at `pos` in `src`, starts. A `pos` in the middle
+/// of a character (which an editor can send us) has no word before it.
+pub fn word_start(src: &str, pos: usize) -> usize {
+    let Some(before) = src.get(..pos) else {
+        return pos;
+    };
+
+    before
+        .char_indices()
+        .rev()
+        .take_while(|(_, ch)| is_completion_char(*ch))
+        .last()
+        .map_or(pos, |(index, _)| index)
+}
+
+/// Prints the type of `var` for showing next to a completion. Printing names the type's
+/// variables in `subs`, so that's rolled back afterwards to leave `subs` as it was.
+pub fn format_var_type(
+    var: Variable,
+    subs: &mut Subs,
+    module_id: ModuleId,
+    interns: &Interns,
+) -> String {
+    let snapshot = subs.snapshot();
+    let type_str = name_and_print_var(var, subs, module_id, interns, DebugPrint::NOTHING);
+    subs.rollback_to(snapshot);
+
+    type_str
+}
+
+/// A record field completion, split into its parts.
+/// E.g. `a.b.c.d` is `{ variable_name: "a", middle_fields: ["b", "c"], field: "d" }`
+#[derive(Debug, PartialEq, Eq)]
+pub struct FieldPath<'a> {
+    /// The name of the variable holding the outermost record
+    pub variable_name: &'a str,
+    pub middle_fields: Vec<&'a str>,
+    /// The (partial) name of the field being completed
+    pub field: &'a str,
+}
+
+impl<'a> FieldPath<'a> {
+    /// Splits a word like `a.b.c.d`, returning `None` if it has no `.` to complete after.
+    pub fn parse(word: &'a str) -> Option<Self> {
+        let (path, field) = word.rsplit_once('.')?;
+        let mut parts = path.split('.');
+        let variable_name = parts.next()?;
+
+        Some(Self {
+            variable_name,
+            middle_fields: parts.collect(),
+            field,
+        })
+    }
+
+    /// The fields whose names start with `self.field`, in the record reached from `var` (the
+    /// type of `self.variable_name`) by way of the middle fields.
+    ///
+    /// If the document isn't fully type checked, we won't know the type of `c` in `a.b.c.d`
+    /// directly. Instead we get the type of `b` within `a`'s type, and then of `c` within `b`'s.
+    pub fn completions(&self, var: Variable, subs: &Subs) -> Vec<(String, Variable)> {
+        let mut var = var;
+
+        for middle_field in &self.middle_fields {
+            match find_record_fields(var, subs)
+                .into_iter()
+                .find(|(name, _)| name == middle_field)
+            {
+                Some((_, field_var)) => var = field_var,
+                None => return Vec::new(),
+            }
+        }
+
+        find_record_fields(var, subs)
+            .into_iter()
+            .filter(|(name, _)| name.starts_with(self.field))
+            .collect()
+    }
+}
+
+/// The names and types of the fields of a record (or the elements of a tuple), looking through
+/// aliases. Anything else has no fields, so this returns an empty list for it.
+pub fn find_record_fields(var: Variable, subs: &Subs) -> Vec<(String, Variable)> {
+    match subs.get_content_without_compacting(var) {
+        Content::Structure(FlatType::Record(fields, ext)) => fields
+            .unsorted_iterator(subs, *ext)
+            .map(|fields| {
+                fields
+                    .map(|(name, field)| (name.to_string(), field.into_inner()))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        Content::Structure(FlatType::Tuple(elems, ext)) => elems
+            .unsorted_iterator(subs, *ext)
+            .map(|elems| elems.map(|(index, var)| (index.to_string(), var)).collect())
+            .unwrap_or_default(),
+        Content::Alias(_, _, real_var, _) => find_record_fields(*real_var, subs),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_path() {
+        assert_eq!(
+            FieldPath::parse("a.b.c.d"),
+            Some(FieldPath {
+                variable_name: "a",
+                middle_fields: vec!["b", "c"],
+                field: "d",
+            })
+        );
+        assert_eq!(
+            FieldPath::parse("person."),
+            Some(FieldPath {
+                variable_name: "person",
+                middle_fields: Vec::new(),
+                field: "",
+            })
+        );
+        assert_eq!(FieldPath::parse("person"), None);
+    }
+
+    #[test]
+    fn word_starts() {
+        assert_eq!(word_start("x = List.ma", 11), 4);
+        assert_eq!(word_start("f(person.first_na", 17), 2);
+        assert_eq!(word_start("Stdout.line!", 12), 0);
+        assert_eq!(word_start("x = ", 4), 4);
+        assert_eq!(word_start("  ç", 3), 3);
+    }
+}
@@ -111,6 +111,14 @@ flags! {
     /// Writes a pretty-printed mono IR to stderr after function specialization.
     ROC_PRINT_IR_AFTER_SPECIALIZATION
 
+    /// Writes a pretty-printed mono IR to stderr after top-level constants have been
+    /// evaluated at compile time.
+    ROC_PRINT_IR_AFTER_CONST_EVAL
+
+    /// Writes a pretty-printed mono IR to stderr after the backend-independent
+    /// optimizations (inlining, constant folding and so on).
+    ROC_PRINT_IR_AFTER_OPTIMIZE
+
     /// Writes a pretty-printed mono IR to stderr after insertion of reset/reuse
     /// instructions.
     ROC_PRINT_IR_AFTER_RESET_REUSE
@@ -0,0 +1,306 @@
+//! Derivers for the `Default` ability.
+
+use std::iter::once;
+
+use roc_can::{
+    expr::{AnnotatedMark, ClosureData, Expr, Field, Recursive},
+    pattern::Pattern,
+};
+use roc_collections::SendMap;
+use roc_derive_key::default::FlatDefaultKey;
+use roc_module::{
+    called_via::CalledVia,
+    ident::{Lowercase, TagName},
+    symbol::Symbol,
+};
+use roc_region::all::{Loc, Region};
+use roc_types::{
+    subs::{
+        Content, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields, SubsSlice, TagExt,
+        TupleElems, UnionLambdas, UnionTags, Variable,
+    },
+    types::RecordField,
+};
+
+use crate::{
+    synth_var,
+    util::{Env, ExtensionKind},
+    DerivedBody,
+};
+
+pub(crate) fn derive_default(
+    env: &mut Env<'_>,
+    key: FlatDefaultKey,
+    def_symbol: Symbol,
+) -> DerivedBody {
+    let (body_type, body) = match key {
+        FlatDefaultKey::Record(fields) => default_record(env, def_symbol, fields),
+        FlatDefaultKey::Tuple(arity) => default_tuple(env, def_symbol, arity),
+        FlatDefaultKey::TagUnion(tags) => default_tag_union(env, def_symbol, tags),
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::DEFAULT_DEFAULT);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+fn default_record(env: &mut Env<'_>, fn_name: Symbol, fields: Vec<Lowercase>) -> (Variable, Expr) {
+    // Suppose rcd = { f1, ..., fn }.
+    // Build a generalized type t_rcd = { f1: t1, ..., fn: tn }, with fresh t1, ..., tn,
+    // so that we can re-use the derived impl for many records of the same fields.
+    let (record_var, record_fields) = {
+        let flex_fields = fields
+            .into_iter()
+            .map(|name| {
+                (
+                    name,
+                    RecordField::Required(env.subs.fresh_unnamed_flex_var()),
+                )
+            })
+            .collect::<Vec<(Lowercase, _)>>();
+        let fields = RecordFields::insert_into_subs(env.subs, flex_fields);
+        let record_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+        );
+
+        (record_var, fields)
+    };
+
+    // Now, the default for this record is
+    //
+    // default_rcd : {} -> { f1: t1, ..., fn: tn }
+    // default_rcd = \{} ->
+    //   { f1: Default.default {}, ..., fn: Default.default {} }
+    let fields: SendMap<Lowercase, Field> = record_fields
+        .iter_all()
+        .map(|(field_name, field_var, _)| {
+            let field_name = env.subs[field_name].clone();
+            let field_var = env.subs[field_var];
+
+            let field = Field {
+                var: field_var,
+                region: Region::zero(),
+                loc_expr: Box::new(Loc::at_zero(call_default(env, field_var))),
+            };
+
+            (field_name, field)
+        })
+        .collect();
+
+    let body = if fields.is_empty() {
+        Expr::EmptyRecord
+    } else {
+        Expr::Record { record_var, fields }
+    };
+
+    build_outer_derived_closure(env, fn_name, (record_var, body))
+}
+
+fn default_tuple(env: &mut Env<'_>, fn_name: Symbol, arity: u32) -> (Variable, Expr) {
+    // Suppose tup = (v1, ..., vn).
+    // Build a generalized type t_tup = (t1, ..., tn), with fresh t1, ..., tn,
+    // so that we can re-use the derived impl for many tuples of the same arity.
+    let (tuple_var, tuple_elems) = {
+        // TODO: avoid an allocation here by pre-allocating the indices and variables `TupleElems`
+        // will be instantiated with.
+        let flex_elems: Vec<_> = (0..arity)
+            .map(|i| (i as usize, env.subs.fresh_unnamed_flex_var()))
+            .collect();
+        let elems = TupleElems::insert_into_subs(env.subs, flex_elems);
+        let tuple_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Tuple(elems, Variable::EMPTY_TUPLE)),
+        );
+
+        (tuple_var, elems)
+    };
+
+    // Now, the default for this tuple is
+    //
+    // default_tup : {} -> (t1, ..., tn)
+    // default_tup = \{} ->
+    //   (Default.default {}, ..., Default.default {})
+    let elems = tuple_elems
+        .iter_all()
+        .map(|(_, elem_var)| {
+            let elem_var = env.subs[elem_var];
+
+            (
+                elem_var,
+                Box::new(Loc::at_zero(call_default(env, elem_var))),
+            )
+        })
+        .collect();
+
+    build_outer_derived_closure(env, fn_name, (tuple_var, Expr::Tuple { tuple_var, elems }))
+}
+
+fn default_tag_union(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    tags: Vec<(TagName, u16)>,
+) -> (Variable, Expr) {
+    // Suppose tags = [ A p11 .. p1n, ..., Q pq1 .. pqm ]
+    // Build a generalized type t_tags = [ A t11 .. t1n, ..., Q tq1 .. tqm ],
+    // with fresh t1, ..., tqm, so that we can re-use the derived impl for many
+    // unions of the same tags and payloads.
+    let (union_var, union_tags) = {
+        let flex_tag_labels = tags
+            .into_iter()
+            .map(|(label, arity)| {
+                let variables_slice = env.subs.reserve_into_vars(arity.into());
+                for var_index in variables_slice {
+                    env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+                }
+                (label, variables_slice)
+            })
+            .collect::<Vec<_>>();
+        let union_tags = UnionTags::insert_slices_into_subs(env.subs, flex_tag_labels);
+        let tag_union_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::TagUnion(
+                union_tags,
+                TagExt::Any(Variable::EMPTY_TAG_UNION),
+            )),
+        );
+
+        (tag_union_var, union_tags)
+    };
+
+    // Now, the default for this tag union is its tag with the fewest payloads, and of those, the
+    // first one in alphabetical order. Picking the fewest payloads means a recursive union like
+    // `[Nil, Cons a t]` defaults to `Nil`, rather than building values forever. Say that's P:
+    //
+    // default_union : {} -> [ A t11 .. t1n, ..., Q tq1 .. tqm ]
+    // default_union = \{} ->
+    //   P (Default.default {}) .. (Default.default {})
+    let (tag_name, payload_vars) = union_tags
+        .iter_all()
+        .map(|(tag, payloads)| (tag, env.subs[payloads]))
+        .min_by_key(|(_, payloads)| payloads.len())
+        .map(|(tag, payloads)| {
+            (
+                env.subs[tag].clone(),
+                env.subs.get_subs_slice(payloads).to_vec(),
+            )
+        })
+        .expect("a tag union with no tags has no default");
+
+    let arguments = payload_vars
+        .into_iter()
+        .map(|var| (var, Loc::at_zero(call_default(env, var))))
+        .collect();
+
+    let tag = Expr::Tag {
+        tag_union_var: union_var,
+        ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+        name: tag_name,
+        arguments,
+    };
+
+    build_outer_derived_closure(env, fn_name, (union_var, tag))
+}
+
+/// `Default.default {}`
+fn call_default(env: &mut Env<'_>, val_var: Variable) -> Expr {
+    // build `Default.default` function type
+    //
+    // {} -[uls]-> val where val implements Default
+    let exposed_default_fn_var = env.import_builtin_symbol_var(Symbol::DEFAULT_DEFAULT);
+
+    // {} -[clos]-> (typeof val)
+    let this_arguments_slice = env.subs.insert_into_vars([Variable::EMPTY_RECORD]);
+    let this_default_clos_var = env.subs.fresh_unnamed_flex_var();
+    let this_default_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_arguments_slice,
+            this_default_clos_var,
+            val_var,
+            Variable::PURE,
+        )),
+    );
+
+    //   {} -[uls]->  val where val implements Default
+    // ~ {} -[clos]-> (typeof val)
+    env.unify(exposed_default_fn_var, this_default_fn_var);
+
+    // Default.default : {} -[clos]-> (typeof val) where (typeof val) implements Default
+    let default_fn_head = Expr::AbilityMember(Symbol::DEFAULT_DEFAULT, None, this_default_fn_var);
+    let default_fn_data = Box::new((
+        this_default_fn_var,
+        Loc::at_zero(default_fn_head),
+        this_default_clos_var,
+        val_var,
+        Variable::PURE,
+    ));
+
+    let default_arguments = vec![(Variable::EMPTY_RECORD, Loc::at_zero(Expr::EmptyRecord))];
+
+    Expr::Call(default_fn_data, default_arguments, CalledVia::Space)
+}
+
+fn build_outer_derived_closure(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    body: (Variable, Expr),
+) -> (Variable, Expr) {
+    let (body_var, body_expr) = body;
+
+    let (fn_var, fn_clos_var) = {
+        // Create fn_var for ambient capture; we fix it up below.
+        let fn_var = synth_var(env.subs, Content::Error);
+
+        // -[fn_name]->
+        let fn_captures = vec![];
+        let fn_name_labels = UnionLambdas::insert_into_subs(env.subs, once((fn_name, fn_captures)));
+        let fn_clos_var = synth_var(
+            env.subs,
+            Content::LambdaSet(LambdaSet {
+                solved: fn_name_labels,
+                recursion_var: OptVariable::NONE,
+                unspecialized: SubsSlice::default(),
+                ambient_function: fn_var,
+            }),
+        );
+
+        // {} -[fn_name]-> body_var
+        let args_slice = env.subs.insert_into_vars([Variable::EMPTY_RECORD]);
+        env.subs.set_content(
+            fn_var,
+            Content::Structure(FlatType::Func(
+                args_slice,
+                fn_clos_var,
+                body_var,
+                Variable::PURE,
+            )),
+        );
+
+        (fn_var, fn_clos_var)
+    };
+
+    let clos_expr = Expr::Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: body_var,
+        fx_type: Variable::PURE,
+        early_returns: vec![],
+        name: fn_name,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![(
+            Variable::EMPTY_RECORD,
+            AnnotatedMark::known_exhaustive(),
+            Loc::at_zero(Pattern::Underscore),
+        )],
+        loc_body: Box::new(Loc::at_zero(body_expr)),
+    });
+
+    (fn_var, clos_expr)
+}
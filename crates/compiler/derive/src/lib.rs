@@ -18,9 +18,11 @@ use roc_types::subs::{
 use util::Env;
 
 mod decoding;
+mod default;
 mod encoding;
 mod hash;
 mod inspect;
+mod sort;
 mod util;
 
 pub(crate) const DERIVED_SYNTH: ModuleId = ModuleId::DERIVED_SYNTH;
@@ -83,6 +85,10 @@ fn build_derived_body(
         DeriveKey::ToInspector(to_inspector_key) => {
             inspect::derive_to_inspector(&mut env, to_inspector_key, derived_symbol)
         }
+        DeriveKey::Sort(sort_key) => sort::derive_compare(&mut env, sort_key, derived_symbol),
+        DeriveKey::Default(default_key) => {
+            default::derive_default(&mut env, default_key, derived_symbol)
+        }
     };
 
     let def = Def {
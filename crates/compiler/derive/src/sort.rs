@@ -0,0 +1,578 @@
+//! Derivers for the `Sort` ability.
+
+use std::iter::once;
+
+use roc_can::{
+    expr::{AnnotatedMark, ClosureData, Expr, Recursive, WhenBranch, WhenBranchPattern},
+    pattern::Pattern,
+};
+use roc_derive_key::sort::FlatSortKey;
+use roc_module::{
+    called_via::CalledVia,
+    ident::{Lowercase, TagName},
+    symbol::Symbol,
+};
+use roc_region::all::{Loc, Region};
+use roc_types::{
+    subs::{
+        Content, ExhaustiveMark, FlatType, GetSubsSlice, LambdaSet, OptVariable, RecordFields,
+        RedundantMark, SubsSlice, TagExt, TupleElems, UnionLambdas, UnionTags, Variable,
+    },
+    types::RecordField,
+};
+
+use crate::{
+    synth_var,
+    util::{Env, ExtensionKind},
+    DerivedBody,
+};
+
+pub(crate) fn derive_compare(
+    env: &mut Env<'_>,
+    key: FlatSortKey,
+    def_symbol: Symbol,
+) -> DerivedBody {
+    let (body_type, body) = match key {
+        FlatSortKey::Record(fields) => compare_record(env, def_symbol, fields),
+        FlatSortKey::Tuple(arity) => compare_tuple(env, def_symbol, arity),
+        FlatSortKey::TagUnion(tags) => compare_tag_union(env, def_symbol, tags),
+    };
+
+    let specialization_lambda_sets =
+        env.get_specialization_lambda_sets(body_type, Symbol::SORT_COMPARE);
+
+    DerivedBody {
+        body,
+        body_type,
+        specialization_lambda_sets,
+    }
+}
+
+/// One pair of values to compare with `Sort.compare`, and their type.
+type Comparison = (Variable, Expr, Expr);
+
+fn compare_record(env: &mut Env<'_>, fn_name: Symbol, fields: Vec<Lowercase>) -> (Variable, Expr) {
+    // Suppose rcd = { f1, ..., fn }.
+    // Build a generalized type t_rcd = { f1: t1, ..., fn: tn }, with fresh t1, ..., tn,
+    // so that we can re-use the derived impl for many records of the same fields.
+    let (record_var, record_fields) = {
+        let flex_fields = fields
+            .into_iter()
+            .map(|name| {
+                (
+                    name,
+                    RecordField::Required(env.subs.fresh_unnamed_flex_var()),
+                )
+            })
+            .collect::<Vec<(Lowercase, _)>>();
+        let fields = RecordFields::insert_into_subs(env.subs, flex_fields);
+        let record_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Record(fields, Variable::EMPTY_RECORD)),
+        );
+
+        (record_var, fields)
+    };
+
+    // Now, a comparison for this record is
+    //
+    // compare_rcd : { f1: t1, ..., fn: tn }, { f1: t1, ..., fn: tn } -> [LT, EQ, GT]
+    // compare_rcd = \a, b ->
+    //   when Sort.compare a.f1 b.f1 is
+    //     EQ ->
+    //       ...
+    //         Sort.compare a.fn b.fn
+    //     ordering -> ordering
+    //
+    // The fields are in alphabetical order, which is the only order the type knows about.
+    let a_sym = env.new_symbol("a");
+    let b_sym = env.new_symbol("b");
+    let ordering_var = ordering_var(env);
+
+    let comparisons: Vec<Comparison> = record_fields
+        .iter_all()
+        .map(|(field_name, field_var, _)| {
+            let field_name = env.subs[field_name].clone();
+            let field_var = env.subs[field_var];
+
+            let mut field_access = |record_sym| Expr::RecordAccess {
+                record_var,
+                field_var,
+                ext_var: env.subs.fresh_unnamed_flex_var(),
+                loc_expr: Box::new(Loc::at_zero(Expr::Var(
+                    record_sym,
+                    env.subs.fresh_unnamed_flex_var(),
+                ))),
+                field: field_name.clone(),
+            };
+
+            (field_var, field_access(a_sym), field_access(b_sym))
+        })
+        .collect();
+
+    let body = compare_in_order(env, ordering_var, comparisons);
+
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (record_var, Pattern::Identifier(a_sym)),
+        (record_var, Pattern::Identifier(b_sym)),
+        (ordering_var, body),
+    )
+}
+
+fn compare_tuple(env: &mut Env<'_>, fn_name: Symbol, arity: u32) -> (Variable, Expr) {
+    // Suppose tup = (v1, ..., vn).
+    // Build a generalized type t_tup = (t1, ..., tn), with fresh t1, ..., tn,
+    // so that we can re-use the derived impl for many tuples of the same arity.
+    let (tuple_var, tuple_elems) = {
+        // TODO: avoid an allocation here by pre-allocating the indices and variables `TupleElems`
+        // will be instantiated with.
+        let flex_elems: Vec<_> = (0..arity)
+            .map(|i| (i as usize, env.subs.fresh_unnamed_flex_var()))
+            .collect();
+        let elems = TupleElems::insert_into_subs(env.subs, flex_elems);
+        let tuple_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::Tuple(elems, Variable::EMPTY_TUPLE)),
+        );
+
+        (tuple_var, elems)
+    };
+
+    // Now, a comparison for this tuple is
+    //
+    // compare_tup : (t1, ..., tn), (t1, ..., tn) -> [LT, EQ, GT]
+    // compare_tup = \a, b ->
+    //   when Sort.compare a.0 b.0 is
+    //     EQ ->
+    //       ...
+    //         Sort.compare a.n b.n
+    //     ordering -> ordering
+    let a_sym = env.new_symbol("a");
+    let b_sym = env.new_symbol("b");
+    let ordering_var = ordering_var(env);
+
+    let comparisons: Vec<Comparison> = tuple_elems
+        .iter_all()
+        .map(|(elem_idx, elem_var)| {
+            let index = env.subs[elem_idx];
+            let elem_var = env.subs[elem_var];
+
+            let mut elem_access = |tuple_sym| Expr::TupleAccess {
+                tuple_var,
+                elem_var,
+                ext_var: env.subs.fresh_unnamed_flex_var(),
+                loc_expr: Box::new(Loc::at_zero(Expr::Var(
+                    tuple_sym,
+                    env.subs.fresh_unnamed_flex_var(),
+                ))),
+                index,
+            };
+
+            (elem_var, elem_access(a_sym), elem_access(b_sym))
+        })
+        .collect();
+
+    let body = compare_in_order(env, ordering_var, comparisons);
+
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (tuple_var, Pattern::Identifier(a_sym)),
+        (tuple_var, Pattern::Identifier(b_sym)),
+        (ordering_var, body),
+    )
+}
+
+fn compare_tag_union(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    tags: Vec<(TagName, u16)>,
+) -> (Variable, Expr) {
+    // Suppose tags = [ A p11 .. p1n, ..., Q pq1 .. pqm ]
+    // Build a generalized type t_tags = [ A t11 .. t1n, ..., Q tq1 .. tqm ],
+    // with fresh t1, ..., tqm, so that we can re-use the derived impl for many
+    // unions of the same tags and payloads.
+    let (union_var, union_tags) = {
+        let flex_tag_labels = tags
+            .into_iter()
+            .map(|(label, arity)| {
+                let variables_slice = env.subs.reserve_into_vars(arity.into());
+                for var_index in variables_slice {
+                    env.subs[var_index] = env.subs.fresh_unnamed_flex_var();
+                }
+                (label, variables_slice)
+            })
+            .collect::<Vec<_>>();
+        let union_tags = UnionTags::insert_slices_into_subs(env.subs, flex_tag_labels);
+        let tag_union_var = synth_var(
+            env.subs,
+            Content::Structure(FlatType::TagUnion(
+                union_tags,
+                TagExt::Any(Variable::EMPTY_TAG_UNION),
+            )),
+        );
+
+        (tag_union_var, union_tags)
+    };
+
+    // Now, a comparison for this tag union is
+    //
+    // compare_union : [ A t11 .. t1n, ..., Q tq1 .. tqm ], [ A t11 .. t1n, ..., Q tq1 .. tqm ] -> [LT, EQ, GT]
+    // compare_union = \a, b ->
+    //   when a is
+    //     A x11 .. x1n ->
+    //       when b is
+    //         A y11 .. y1n ->
+    //           when Sort.compare x11 y11 is
+    //             EQ -> ... Sort.compare x1n y1n
+    //             ordering -> ordering
+    //         B _ .. _ | ... | Q _ .. _ -> LT
+    //     ...
+    //     Q xq1 .. xqm ->
+    //       when b is
+    //         A _ .. _ | ... | P _ .. _ -> GT
+    //         Q yq1 .. yqm -> ...
+    //
+    // The tags are in alphabetical order, which is the only order the type knows about.
+    let a_sym = env.new_symbol("a");
+    let b_sym = env.new_symbol("b");
+    let ordering_var = ordering_var(env);
+
+    let tags: Vec<(TagName, Vec<Variable>)> = union_tags
+        .iter_all()
+        .map(|(tag, payloads)| {
+            let tag_name = env.subs[tag].clone();
+            let payload_vars = env.subs.get_subs_slice(env.subs[payloads]).to_vec();
+
+            (tag_name, payload_vars)
+        })
+        .collect();
+
+    let tag_pattern = |tag_name: &TagName, arguments: Vec<(Variable, Loc<Pattern>)>| {
+        Loc::at_zero(Pattern::AppliedTag {
+            whole_var: union_var,
+            tag_name: tag_name.clone(),
+            ext_var: Variable::EMPTY_TAG_UNION,
+            arguments,
+        })
+    };
+
+    // `B _ .. _ | C _ .. _ -> ordering` for the tags that come before or after the one `a` has
+    let other_tags_branch = |env: &mut Env<'_>, others: &[(TagName, Vec<Variable>)], ordering| {
+        let patterns = others
+            .iter()
+            .map(|(tag_name, payload_vars)| WhenBranchPattern {
+                pattern: tag_pattern(
+                    tag_name,
+                    payload_vars
+                        .iter()
+                        .map(|var| (*var, Loc::at_zero(Pattern::Underscore)))
+                        .collect(),
+                ),
+                degenerate: false,
+            })
+            .collect();
+
+        WhenBranch {
+            patterns,
+            value: Loc::at_zero(ordering_tag(env, ordering_var, ordering)),
+            guard: None,
+            redundant: RedundantMark::known_non_redundant(),
+        }
+    };
+
+    let branches = tags
+        .iter()
+        .enumerate()
+        .map(|(index, (tag_name, payload_vars))| {
+            // x11 .. x1n, and y11 .. y1n
+            let a_payload_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(payload_vars.len())
+                .collect();
+            let b_payload_syms: Vec<_> = std::iter::repeat_with(|| env.unique_symbol())
+                .take(payload_vars.len())
+                .collect();
+
+            let bind_payloads = |syms: &[Symbol]| {
+                (payload_vars.iter())
+                    .zip(syms)
+                    .map(|(var, sym)| (*var, Loc::at_zero(Pattern::Identifier(*sym))))
+                    .collect()
+            };
+
+            let comparisons = (payload_vars.iter())
+                .zip(a_payload_syms.iter().zip(b_payload_syms.iter()))
+                .map(|(var, (x, y))| (*var, Expr::Var(*x, *var), Expr::Var(*y, *var)))
+                .collect();
+
+            // A y11 .. y1n -> ...
+            let same_tag_branch = WhenBranch {
+                patterns: vec![WhenBranchPattern {
+                    pattern: tag_pattern(tag_name, bind_payloads(&b_payload_syms)),
+                    degenerate: false,
+                }],
+                value: Loc::at_zero(compare_in_order(env, ordering_var, comparisons)),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            };
+
+            let (before, after) = (&tags[..index], &tags[index + 1..]);
+            let mut inner_branches = Vec::with_capacity(3);
+            if !before.is_empty() {
+                inner_branches.push(other_tags_branch(env, before, "GT"));
+            }
+            inner_branches.push(same_tag_branch);
+            if !after.is_empty() {
+                inner_branches.push(other_tags_branch(env, after, "LT"));
+            }
+
+            // when b is ...
+            let inner_when = Expr::When {
+                loc_cond: Box::new(Loc::at_zero(Expr::Var(b_sym, union_var))),
+                cond_var: union_var,
+                expr_var: ordering_var,
+                region: Region::zero(),
+                branches: inner_branches,
+                branches_cond_var: union_var,
+                exhaustive: ExhaustiveMark::known_exhaustive(),
+            };
+
+            // A x11 .. x1n -> when b is ...
+            WhenBranch {
+                patterns: vec![WhenBranchPattern {
+                    pattern: tag_pattern(tag_name, bind_payloads(&a_payload_syms)),
+                    degenerate: false,
+                }],
+                value: Loc::at_zero(inner_when),
+                guard: None,
+                redundant: RedundantMark::known_non_redundant(),
+            }
+        })
+        .collect();
+
+    // when a is ...
+    let when_expr = Expr::When {
+        loc_cond: Box::new(Loc::at_zero(Expr::Var(a_sym, union_var))),
+        cond_var: union_var,
+        expr_var: ordering_var,
+        region: Region::zero(),
+        branches,
+        branches_cond_var: union_var,
+        exhaustive: ExhaustiveMark::known_exhaustive(),
+    };
+
+    build_outer_derived_closure(
+        env,
+        fn_name,
+        (union_var, Pattern::Identifier(a_sym)),
+        (union_var, Pattern::Identifier(b_sym)),
+        (ordering_var, when_expr),
+    )
+}
+
+/// `[EQ, GT, LT]`, the type `Sort.compare` returns
+fn ordering_var(env: &mut Env<'_>) -> Variable {
+    let tags = UnionTags::insert_into_subs::<_, Vec<Variable>>(
+        env.subs,
+        [
+            ("EQ".into(), vec![]),
+            ("GT".into(), vec![]),
+            ("LT".into(), vec![]),
+        ],
+    );
+
+    synth_var(
+        env.subs,
+        Content::Structure(FlatType::TagUnion(
+            tags,
+            TagExt::Any(Variable::EMPTY_TAG_UNION),
+        )),
+    )
+}
+
+/// `EQ`, `GT` or `LT`
+fn ordering_tag(env: &mut Env<'_>, ordering_var: Variable, name: &str) -> Expr {
+    Expr::Tag {
+        tag_union_var: ordering_var,
+        ext_var: env.new_ext_var(ExtensionKind::TagUnion),
+        name: name.into(),
+        arguments: vec![],
+    }
+}
+
+/// Compare each pair of values in turn, until one of them isn't `EQ`:
+///
+/// when Sort.compare a1 b1 is
+///   EQ ->
+///     ...
+///       Sort.compare an bn
+///   ordering -> ordering
+fn compare_in_order(
+    env: &mut Env<'_>,
+    ordering_var: Variable,
+    comparisons: Vec<Comparison>,
+) -> Expr {
+    let mut comparisons = comparisons.into_iter().rev();
+
+    let Some(last) = comparisons.next() else {
+        return ordering_tag(env, ordering_var, "EQ");
+    };
+
+    let last_ordering = call_sort_compare(env, ordering_var, last);
+
+    comparisons.fold(last_ordering, |rest, comparison| {
+        let ordering = call_sort_compare(env, ordering_var, comparison);
+        let ordering_sym = env.new_symbol("ordering");
+
+        let eq_branch = WhenBranch {
+            patterns: vec![WhenBranchPattern {
+                pattern: Loc::at_zero(Pattern::AppliedTag {
+                    whole_var: ordering_var,
+                    tag_name: "EQ".into(),
+                    ext_var: Variable::EMPTY_TAG_UNION,
+                    arguments: vec![],
+                }),
+                degenerate: false,
+            }],
+            value: Loc::at_zero(rest),
+            guard: None,
+            redundant: RedundantMark::known_non_redundant(),
+        };
+
+        let other_branch = WhenBranch {
+            patterns: vec![WhenBranchPattern {
+                pattern: Loc::at_zero(Pattern::Identifier(ordering_sym)),
+                degenerate: false,
+            }],
+            value: Loc::at_zero(Expr::Var(ordering_sym, ordering_var)),
+            guard: None,
+            redundant: RedundantMark::known_non_redundant(),
+        };
+
+        Expr::When {
+            loc_cond: Box::new(Loc::at_zero(ordering)),
+            cond_var: ordering_var,
+            expr_var: ordering_var,
+            region: Region::zero(),
+            branches: vec![eq_branch, other_branch],
+            branches_cond_var: ordering_var,
+            exhaustive: ExhaustiveMark::known_exhaustive(),
+        }
+    })
+}
+
+/// `Sort.compare a b`
+fn call_sort_compare(env: &mut Env<'_>, ordering_var: Variable, comparison: Comparison) -> Expr {
+    let (val_var, a_expr, b_expr) = comparison;
+
+    // build `Sort.compare` function type
+    //
+    // val, val -[uls]-> [LT, EQ, GT] where val implements Sort
+    let exposed_compare_fn_var = env.import_builtin_symbol_var(Symbol::SORT_COMPARE);
+
+    // (typeof val), (typeof val) -[clos]-> [EQ, GT, LT]
+    let this_arguments_slice = env.subs.insert_into_vars([val_var, val_var]);
+    let this_compare_clos_var = env.subs.fresh_unnamed_flex_var();
+    let this_compare_fn_var = synth_var(
+        env.subs,
+        Content::Structure(FlatType::Func(
+            this_arguments_slice,
+            this_compare_clos_var,
+            ordering_var,
+            Variable::PURE,
+        )),
+    );
+
+    //   val,          val          -[uls]->  [LT, EQ, GT] where val implements Sort
+    // ~ (typeof val), (typeof val) -[clos]-> [EQ, GT, LT]
+    env.unify(exposed_compare_fn_var, this_compare_fn_var);
+
+    // Sort.compare : (typeof val), (typeof val) -[clos]-> [EQ, GT, LT] where (typeof val) implements Sort
+    let compare_fn_head = Expr::AbilityMember(Symbol::SORT_COMPARE, None, this_compare_fn_var);
+    let compare_fn_data = Box::new((
+        this_compare_fn_var,
+        Loc::at_zero(compare_fn_head),
+        this_compare_clos_var,
+        ordering_var,
+        Variable::PURE,
+    ));
+
+    let compare_arguments = vec![
+        (val_var, Loc::at_zero(a_expr)),
+        (val_var, Loc::at_zero(b_expr)),
+    ];
+
+    Expr::Call(compare_fn_data, compare_arguments, CalledVia::Space)
+}
+
+fn build_outer_derived_closure(
+    env: &mut Env<'_>,
+    fn_name: Symbol,
+    a: (Variable, Pattern),
+    b: (Variable, Pattern),
+    body: (Variable, Expr),
+) -> (Variable, Expr) {
+    let (a_var, a_pattern) = a;
+    let (b_var, b_pattern) = b;
+    let (body_var, body_expr) = body;
+
+    let (fn_var, fn_clos_var) = {
+        // Create fn_var for ambient capture; we fix it up below.
+        let fn_var = synth_var(env.subs, Content::Error);
+
+        // -[fn_name]->
+        let fn_captures = vec![];
+        let fn_name_labels = UnionLambdas::insert_into_subs(env.subs, once((fn_name, fn_captures)));
+        let fn_clos_var = synth_var(
+            env.subs,
+            Content::LambdaSet(LambdaSet {
+                solved: fn_name_labels,
+                recursion_var: OptVariable::NONE,
+                unspecialized: SubsSlice::default(),
+                ambient_function: fn_var,
+            }),
+        );
+
+        // a_var, b_var -[fn_name]-> body_var
+        let args_slice = env.subs.insert_into_vars([a_var, b_var]);
+        env.subs.set_content(
+            fn_var,
+            Content::Structure(FlatType::Func(
+                args_slice,
+                fn_clos_var,
+                body_var,
+                Variable::PURE,
+            )),
+        );
+
+        (fn_var, fn_clos_var)
+    };
+
+    let clos_expr = Expr::Closure(ClosureData {
+        function_type: fn_var,
+        closure_type: fn_clos_var,
+        return_type: body_var,
+        fx_type: Variable::PURE,
+        early_returns: vec![],
+        name: fn_name,
+        captured_symbols: vec![],
+        recursive: Recursive::NotRecursive,
+        arguments: vec![
+            (
+                a_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(a_pattern),
+            ),
+            (
+                b_var,
+                AnnotatedMark::known_exhaustive(),
+                Loc::at_zero(b_pattern),
+            ),
+        ],
+        loc_body: Box::new(Loc::at_zero(body_expr)),
+    });
+
+    (fn_var, clos_expr)
+}
@@ -0,0 +1,187 @@
+use roc_module::{
+    ident::{Lowercase, TagName},
+    symbol::Symbol,
+};
+use roc_types::subs::{Content, FlatType, GetSubsSlice, Subs, Variable};
+
+use crate::{
+    util::{check_derivable_ext_var, debug_name_record, debug_name_tag, debug_name_tuple},
+    DeriveError,
+};
+
+#[derive(Hash)]
+pub enum FlatDefault {
+    // `default` is always of form `{} -> a` where `a` is opaque, so all immediates must have
+    // exactly one lambda set!
+    SingleLambdaSetImmediate(Symbol),
+    Key(FlatDefaultKey),
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+pub enum FlatDefaultKey {
+    // Unfortunate that we must allocate here, c'est la vie
+    Record(Vec<Lowercase>),
+    Tuple(u32),
+    TagUnion(Vec<(TagName, u16)>),
+}
+
+impl FlatDefaultKey {
+    pub(crate) fn debug_name(&self) -> String {
+        match self {
+            FlatDefaultKey::Record(fields) => debug_name_record(fields),
+            FlatDefaultKey::Tuple(arity) => debug_name_tuple(*arity),
+            FlatDefaultKey::TagUnion(tags) => debug_name_tag(tags),
+        }
+    }
+}
+
+impl FlatDefault {
+    pub(crate) fn from_var(subs: &Subs, var: Variable) -> Result<FlatDefault, DeriveError> {
+        use DeriveError::*;
+        use FlatDefault::*;
+        match *subs.get_content_without_compacting(var) {
+            Content::Structure(flat_type) => match flat_type {
+                FlatType::Apply(sym, _) => match sym {
+                    Symbol::LIST_LIST => Ok(SingleLambdaSetImmediate(Symbol::DEFAULT_LIST)),
+                    Symbol::DICT_DICT => Ok(SingleLambdaSetImmediate(Symbol::DEFAULT_DICT)),
+                    Symbol::STR_STR => Ok(SingleLambdaSetImmediate(Symbol::DEFAULT_STR)),
+                    _ => Err(Underivable),
+                },
+                FlatType::Record(fields, ext) => {
+                    let (fields_iter, ext) = fields.unsorted_iterator_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext, |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyRecord))
+                    })?;
+
+                    let mut field_names = Vec::with_capacity(fields.len());
+                    for (field_name, record_field) in fields_iter {
+                        if record_field.is_optional() {
+                            // Can't derive a concrete default for optional fields, since those
+                            // are compile-time-polymorphic
+                            return Err(Underivable);
+                        }
+                        field_names.push(field_name.clone());
+                    }
+
+                    field_names.sort();
+
+                    Ok(Key(FlatDefaultKey::Record(field_names)))
+                }
+                FlatType::Tuple(elems, ext) => {
+                    let (elems_iter, ext) = elems.sorted_iterator_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext, |_| false)?;
+
+                    Ok(Key(FlatDefaultKey::Tuple(elems_iter.count() as _)))
+                }
+                FlatType::TagUnion(tags, ext) | FlatType::RecursiveTagUnion(_, tags, ext) => {
+                    // The recursion var doesn't matter, because the derived implementation will only
+                    // look on the surface of the tag union type, and more over the payloads of the
+                    // arguments will be left generic for the monomorphizer to fill in with the
+                    // appropriate type. That is,
+                    //   [ A t1, B t1 t2 ]
+                    // and
+                    //   [ A t1, B t1 t2 ] as R
+                    // look the same on the surface, because `R` is only somewhere inside of the
+                    // `t`-prefixed payload types.
+                    let (tags_iter, ext) = tags.unsorted_tags_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext.var(), |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyTagUnion))
+                    })?;
+
+                    let mut tag_names_and_payload_sizes: Vec<_> = tags_iter
+                        .tags
+                        .into_iter()
+                        .map(|(name, payload_slice)| {
+                            let payload_size = payload_slice.len();
+                            (name.clone(), payload_size as _)
+                        })
+                        .collect();
+
+                    tag_names_and_payload_sizes.sort_by(|(t1, _), (t2, _)| t1.cmp(t2));
+
+                    if tag_names_and_payload_sizes.is_empty() {
+                        // There's no value to use as the default.
+                        return Err(Underivable);
+                    }
+
+                    Ok(Key(FlatDefaultKey::TagUnion(tag_names_and_payload_sizes)))
+                }
+                FlatType::FunctionOrTagUnion(names_index, _, _) => {
+                    Ok(Key(FlatDefaultKey::TagUnion(
+                        subs.get_subs_slice(names_index)
+                            .iter()
+                            .map(|t| (t.clone(), 0))
+                            .collect(),
+                    )))
+                }
+                FlatType::EmptyRecord => Ok(Key(FlatDefaultKey::Record(vec![]))),
+                // There's no value to use as the default.
+                FlatType::EmptyTagUnion => Err(Underivable),
+                //
+                FlatType::Func(..) | FlatType::EffectfulFunc => Err(Underivable),
+            },
+            Content::Alias(sym, _, real_var, _) => match builtin_symbol_to_default_lambda(sym) {
+                Some(lambda) => Ok(lambda),
+                // NB: I believe it is okay to unwrap opaques here because derivers are only used
+                // by the backend, and the backend treats opaques like structural aliases.
+                None => Self::from_var(subs, real_var),
+            },
+            Content::RangedNumber(_) => {
+                // Every number defaults to `0`, so it doesn't matter which one this becomes.
+                Ok(SingleLambdaSetImmediate(Symbol::DEFAULT_NUM))
+            }
+            //
+            Content::RecursionVar { structure, .. } => Self::from_var(subs, structure),
+            //
+            Content::Error => Err(Underivable),
+            Content::FlexVar(_)
+            | Content::RigidVar(_)
+            | Content::FlexAbleVar(_, _)
+            | Content::RigidAbleVar(_, _) => Err(UnboundVar),
+            Content::LambdaSet(_) | Content::ErasedLambda => Err(Underivable),
+            Content::Pure | Content::Effectful => Err(Underivable),
+        }
+    }
+
+    pub fn from_builtin_symbol(symbol: Symbol) -> Result<FlatDefault, DeriveError> {
+        builtin_symbol_to_default_lambda(symbol).ok_or(DeriveError::Underivable)
+    }
+}
+
+const fn builtin_symbol_to_default_lambda(symbol: Symbol) -> Option<FlatDefault> {
+    use FlatDefault::*;
+    match symbol {
+        Symbol::BOOL_BOOL => Some(SingleLambdaSetImmediate(Symbol::DEFAULT_BOOL)),
+        Symbol::DICT_DICT => Some(SingleLambdaSetImmediate(Symbol::DEFAULT_DICT)),
+        Symbol::NUM_U8
+        | Symbol::NUM_UNSIGNED8
+        | Symbol::NUM_U16
+        | Symbol::NUM_UNSIGNED16
+        | Symbol::NUM_U32
+        | Symbol::NUM_UNSIGNED32
+        | Symbol::NUM_U64
+        | Symbol::NUM_UNSIGNED64
+        | Symbol::NUM_U128
+        | Symbol::NUM_UNSIGNED128
+        | Symbol::NUM_I8
+        | Symbol::NUM_SIGNED8
+        | Symbol::NUM_I16
+        | Symbol::NUM_SIGNED16
+        | Symbol::NUM_I32
+        | Symbol::NUM_SIGNED32
+        | Symbol::NUM_I64
+        | Symbol::NUM_SIGNED64
+        | Symbol::NUM_I128
+        | Symbol::NUM_SIGNED128
+        | Symbol::NUM_F32
+        | Symbol::NUM_BINARY32
+        | Symbol::NUM_F64
+        | Symbol::NUM_BINARY64
+        | Symbol::NUM_DEC
+        | Symbol::NUM_DECIMAL => Some(SingleLambdaSetImmediate(Symbol::DEFAULT_NUM)),
+        _ => None,
+    }
+}
@@ -14,18 +14,22 @@
 //! [`DeriveKey`].
 
 pub mod decoding;
+pub mod default;
 pub mod encoding;
 pub mod hash;
 pub mod inspect;
+pub mod sort;
 mod util;
 
 use decoding::{FlatDecodable, FlatDecodableKey};
+use default::{FlatDefault, FlatDefaultKey};
 use encoding::{FlatEncodable, FlatEncodableKey};
 use hash::{FlatHash, FlatHashKey};
 
 use inspect::{FlatInspectable, FlatInspectableKey};
 use roc_module::symbol::Symbol;
 use roc_types::subs::{Subs, Variable};
+use sort::{FlatSort, FlatSortKey};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum DeriveError {
@@ -43,6 +47,8 @@ pub enum DeriveKey {
     Decoder(FlatDecodableKey),
     Hash(FlatHashKey),
     ToInspector(FlatInspectableKey),
+    Sort(FlatSortKey),
+    Default(FlatDefaultKey),
 }
 
 impl DeriveKey {
@@ -52,6 +58,8 @@ impl DeriveKey {
             DeriveKey::Decoder(key) => format!("decoder_{}", key.debug_name()),
             DeriveKey::Hash(key) => format!("hash_{}", key.debug_name()),
             DeriveKey::ToInspector(key) => format!("to_inspector_{}", key.debug_name()),
+            DeriveKey::Sort(key) => format!("compare_{}", key.debug_name()),
+            DeriveKey::Default(key) => format!("default_{}", key.debug_name()),
         }
     }
 }
@@ -82,6 +90,8 @@ pub enum DeriveBuiltin {
     Hash,
     IsEq,
     ToInspector,
+    Sort,
+    Default,
 }
 
 impl TryFrom<Symbol> for DeriveBuiltin {
@@ -94,6 +104,8 @@ impl TryFrom<Symbol> for DeriveBuiltin {
             Symbol::HASH_HASH => Ok(DeriveBuiltin::Hash),
             Symbol::BOOL_IS_EQ => Ok(DeriveBuiltin::IsEq),
             Symbol::INSPECT_TO_INSPECTOR => Ok(DeriveBuiltin::ToInspector),
+            Symbol::SORT_COMPARE => Ok(DeriveBuiltin::Sort),
+            Symbol::DEFAULT_DEFAULT => Ok(DeriveBuiltin::Default),
             _ => Err(value),
         }
     }
@@ -131,6 +143,18 @@ impl Derived {
                 FlatInspectable::Immediate(imm) => Ok(Derived::Immediate(imm)),
                 FlatInspectable::Key(repr) => Ok(Derived::Key(DeriveKey::ToInspector(repr))),
             },
+            DeriveBuiltin::Sort => match sort::FlatSort::from_var(subs, var)? {
+                FlatSort::SingleLambdaSetImmediate(imm) => {
+                    Ok(Derived::SingleLambdaSetImmediate(imm))
+                }
+                FlatSort::Key(repr) => Ok(Derived::Key(DeriveKey::Sort(repr))),
+            },
+            DeriveBuiltin::Default => match default::FlatDefault::from_var(subs, var)? {
+                FlatDefault::SingleLambdaSetImmediate(imm) => {
+                    Ok(Derived::SingleLambdaSetImmediate(imm))
+                }
+                FlatDefault::Key(repr) => Ok(Derived::Key(DeriveKey::Default(repr))),
+            },
         }
     }
 
@@ -167,6 +191,18 @@ impl Derived {
                     FlatInspectable::Key(repr) => Ok(Derived::Key(DeriveKey::ToInspector(repr))),
                 }
             }
+            DeriveBuiltin::Sort => match sort::FlatSort::from_builtin_symbol(symbol)? {
+                FlatSort::SingleLambdaSetImmediate(imm) => {
+                    Ok(Derived::SingleLambdaSetImmediate(imm))
+                }
+                FlatSort::Key(repr) => Ok(Derived::Key(DeriveKey::Sort(repr))),
+            },
+            DeriveBuiltin::Default => match default::FlatDefault::from_builtin_symbol(symbol)? {
+                FlatDefault::SingleLambdaSetImmediate(imm) => {
+                    Ok(Derived::SingleLambdaSetImmediate(imm))
+                }
+                FlatDefault::Key(repr) => Ok(Derived::Key(DeriveKey::Default(repr))),
+            },
         }
     }
 }
@@ -0,0 +1,187 @@
+use roc_module::{
+    ident::{Lowercase, TagName},
+    symbol::Symbol,
+};
+use roc_types::subs::{Content, FlatType, GetSubsSlice, Subs, Variable};
+
+use crate::{
+    util::{check_derivable_ext_var, debug_name_record, debug_name_tag, debug_name_tuple},
+    DeriveError,
+};
+
+#[derive(Hash)]
+pub enum FlatSort {
+    // `compare` is always of form `a, a -> [LT, EQ, GT]` where `a` is opaque, so all immediates
+    // must have exactly one lambda set!
+    SingleLambdaSetImmediate(Symbol),
+    Key(FlatSortKey),
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone)]
+pub enum FlatSortKey {
+    // Unfortunate that we must allocate here, c'est la vie
+    Record(Vec<Lowercase>),
+    Tuple(u32),
+    TagUnion(Vec<(TagName, u16)>),
+}
+
+impl FlatSortKey {
+    pub(crate) fn debug_name(&self) -> String {
+        match self {
+            FlatSortKey::Record(fields) => debug_name_record(fields),
+            FlatSortKey::Tuple(arity) => debug_name_tuple(*arity),
+            FlatSortKey::TagUnion(tags) => debug_name_tag(tags),
+        }
+    }
+}
+
+impl FlatSort {
+    pub(crate) fn from_var(subs: &Subs, var: Variable) -> Result<FlatSort, DeriveError> {
+        use DeriveError::*;
+        use FlatSort::*;
+        match *subs.get_content_without_compacting(var) {
+            Content::Structure(flat_type) => match flat_type {
+                FlatType::Apply(sym, _) => match sym {
+                    Symbol::LIST_LIST => Ok(SingleLambdaSetImmediate(Symbol::SORT_COMPARE_LIST)),
+                    Symbol::STR_STR => Ok(SingleLambdaSetImmediate(Symbol::SORT_COMPARE_STR)),
+                    _ => Err(Underivable),
+                },
+                FlatType::Record(fields, ext) => {
+                    let (fields_iter, ext) = fields.unsorted_iterator_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext, |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyRecord))
+                    })?;
+
+                    let mut field_names = Vec::with_capacity(fields.len());
+                    for (field_name, record_field) in fields_iter {
+                        if record_field.is_optional() {
+                            // Can't derive a concrete comparison for optional fields, since those
+                            // are compile-time-polymorphic
+                            return Err(Underivable);
+                        }
+                        field_names.push(field_name.clone());
+                    }
+
+                    field_names.sort();
+
+                    Ok(Key(FlatSortKey::Record(field_names)))
+                }
+                FlatType::Tuple(elems, ext) => {
+                    let (elems_iter, ext) = elems.sorted_iterator_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext, |_| false)?;
+
+                    Ok(Key(FlatSortKey::Tuple(elems_iter.count() as _)))
+                }
+                FlatType::TagUnion(tags, ext) | FlatType::RecursiveTagUnion(_, tags, ext) => {
+                    // The recursion var doesn't matter, because the derived implementation will only
+                    // look on the surface of the tag union type, and more over the payloads of the
+                    // arguments will be left generic for the monomorphizer to fill in with the
+                    // appropriate type. That is,
+                    //   [ A t1, B t1 t2 ]
+                    // and
+                    //   [ A t1, B t1 t2 ] as R
+                    // look the same on the surface, because `R` is only somewhere inside of the
+                    // `t`-prefixed payload types.
+                    let (tags_iter, ext) = tags.unsorted_tags_and_ext(subs, ext);
+
+                    check_derivable_ext_var(subs, ext.var(), |ext| {
+                        matches!(ext, Content::Structure(FlatType::EmptyTagUnion))
+                    })?;
+
+                    let mut tag_names_and_payload_sizes: Vec<_> = tags_iter
+                        .tags
+                        .into_iter()
+                        .map(|(name, payload_slice)| {
+                            let payload_size = payload_slice.len();
+                            (name.clone(), payload_size as _)
+                        })
+                        .collect();
+
+                    tag_names_and_payload_sizes.sort_by(|(t1, _), (t2, _)| t1.cmp(t2));
+
+                    Ok(Key(FlatSortKey::TagUnion(tag_names_and_payload_sizes)))
+                }
+                FlatType::FunctionOrTagUnion(names_index, _, _) => Ok(Key(FlatSortKey::TagUnion(
+                    subs.get_subs_slice(names_index)
+                        .iter()
+                        .map(|t| (t.clone(), 0))
+                        .collect(),
+                ))),
+                FlatType::EmptyRecord => Ok(Key(FlatSortKey::Record(vec![]))),
+                FlatType::EmptyTagUnion => Ok(Key(FlatSortKey::TagUnion(vec![]))),
+                //
+                FlatType::Func(..) | FlatType::EffectfulFunc => Err(Underivable),
+            },
+            Content::Alias(sym, _, real_var, _) => match builtin_symbol_to_sort_lambda(sym) {
+                Some(lambda) => Ok(lambda),
+                // NB: I believe it is okay to unwrap opaques here because derivers are only used
+                // by the backend, and the backend treats opaques like structural aliases.
+                None => Self::from_var(subs, real_var),
+            },
+            Content::RangedNumber(range) => {
+                // Find the integer we're going to compile to, that'll tell us what lambda we
+                // should resolve to.
+                //
+                // Note that at this point, we don't need to update the underlying type variable.
+                // That's because
+                //
+                //   - If the type variable always had a ground constructor after solving, we would
+                //     have already refined the ranged number during obligation checking.
+                //
+                //   - If the type variable was generalized, then this branch is only reached
+                //     during monomorphization, at which point we always choose a default layout
+                //     for ranged numbers, without concern for reification to a ground type.
+                let chosen_width = range.default_compilation_width();
+                let lambda = builtin_symbol_to_sort_lambda(chosen_width.symbol()).unwrap();
+                Ok(lambda)
+            }
+            //
+            Content::RecursionVar { structure, .. } => Self::from_var(subs, structure),
+            //
+            Content::Error => Err(Underivable),
+            Content::FlexVar(_)
+            | Content::RigidVar(_)
+            | Content::FlexAbleVar(_, _)
+            | Content::RigidAbleVar(_, _) => Err(UnboundVar),
+            Content::LambdaSet(_) | Content::ErasedLambda => Err(Underivable),
+            Content::Pure | Content::Effectful => Err(Underivable),
+        }
+    }
+
+    pub fn from_builtin_symbol(symbol: Symbol) -> Result<FlatSort, DeriveError> {
+        builtin_symbol_to_sort_lambda(symbol).ok_or(DeriveError::Underivable)
+    }
+}
+
+const fn builtin_symbol_to_sort_lambda(symbol: Symbol) -> Option<FlatSort> {
+    use FlatSort::*;
+    match symbol {
+        Symbol::BOOL_BOOL => Some(SingleLambdaSetImmediate(Symbol::SORT_COMPARE_BOOL)),
+        // Floats can't be put in a total order, so they don't implement `Sort`.
+        Symbol::NUM_U8
+        | Symbol::NUM_UNSIGNED8
+        | Symbol::NUM_U16
+        | Symbol::NUM_UNSIGNED16
+        | Symbol::NUM_U32
+        | Symbol::NUM_UNSIGNED32
+        | Symbol::NUM_U64
+        | Symbol::NUM_UNSIGNED64
+        | Symbol::NUM_U128
+        | Symbol::NUM_UNSIGNED128
+        | Symbol::NUM_I8
+        | Symbol::NUM_SIGNED8
+        | Symbol::NUM_I16
+        | Symbol::NUM_SIGNED16
+        | Symbol::NUM_I32
+        | Symbol::NUM_SIGNED32
+        | Symbol::NUM_I64
+        | Symbol::NUM_SIGNED64
+        | Symbol::NUM_I128
+        | Symbol::NUM_SIGNED128
+        | Symbol::NUM_DEC
+        | Symbol::NUM_DECIMAL => Some(SingleLambdaSetImmediate(Symbol::NUM_COMPARE)),
+        _ => None,
+    }
+}
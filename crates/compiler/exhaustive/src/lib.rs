@@ -132,6 +132,61 @@ pub enum Literal {
     Float(u64),
     Decimal([u8; 16]),
     Str(Box<str>),
+    /// A pattern that could not be canonicalized. It is treated like a literal so that it never
+    /// makes a match exhaustive, but it has no concrete counterexample.
+    Malformed,
+}
+
+impl Literal {
+    /// Picks a literal of the same kind as `self` that is not in `seen`, to use as a concrete
+    /// counterexample when a match over literals is not exhaustive.
+    fn unseen_sibling(&self, seen: &[&Literal]) -> Option<Literal> {
+        // There are more candidates than seen literals, so one of them must be unseen.
+        let candidates = 0..=seen.len();
+
+        match self {
+            Literal::Int(_) => candidates
+                .map(|n| Literal::Int((n as i128).to_ne_bytes()))
+                .find(|lit| !seen.contains(&lit)),
+            Literal::U128(_) => candidates
+                .map(|n| Literal::U128((n as u128).to_ne_bytes()))
+                .find(|lit| !seen.contains(&lit)),
+            Literal::Byte(_) => candidates
+                .filter_map(|n| u8::try_from(n).ok())
+                .map(Literal::Byte)
+                .find(|lit| !seen.contains(&lit)),
+            Literal::Float(_) => candidates
+                .map(|n| Literal::Float((n as f64).to_bits()))
+                .find(|lit| !seen.contains(&lit)),
+            Literal::Decimal(_) => candidates
+                .map(|n| Literal::Decimal((n as i128 * DECIMAL_ONE).to_ne_bytes()))
+                .find(|lit| !seen.contains(&lit)),
+            Literal::Str(_) => candidates
+                .map(|n| Literal::Str(str_candidate(n).into()))
+                .find(|lit| !seen.contains(&lit)),
+            Literal::Bit(_) => [false, true]
+                .map(Literal::Bit)
+                .into_iter()
+                .find(|lit| !seen.contains(&lit)),
+            Literal::Malformed => None,
+        }
+    }
+}
+
+/// The representation of `1` in a `Dec`, which stores its value scaled by 10^18.
+const DECIMAL_ONE: i128 = 1_000_000_000_000_000_000;
+
+/// The `n`th string in the sequence "", "a", "b", .., "z", "aa", "ab", ..
+fn str_candidate(mut n: usize) -> String {
+    let mut chars = Vec::new();
+
+    while n > 0 {
+        n -= 1;
+        chars.push((b'a' + (n % 26) as u8) as char);
+        n /= 26;
+    }
+
+    chars.into_iter().rev().collect()
 }
 
 /// Error
@@ -227,8 +282,12 @@ fn is_exhaustive(matrix: &RefPatternMatrix, n: usize) -> PatternMatrix {
                 .collect();
             let mut rest = is_exhaustive(&new_matrix, n - 1);
 
+            // Only rows starting with `_` survive the specialization above, so if the first
+            // column matches literals, any literal not among them is a concrete counterexample.
+            let missing = missing_literal(matrix).unwrap_or(Anything);
+
             for row in rest.iter_mut() {
-                row.push(Anything);
+                row.push(missing.clone());
             }
 
             rest
@@ -305,6 +364,20 @@ fn is_exhaustive(matrix: &RefPatternMatrix, n: usize) -> PatternMatrix {
     }
 }
 
+fn missing_literal(matrix: &RefPatternMatrix) -> Option<Pattern> {
+    let seen: Vec<&Literal> = matrix
+        .iter()
+        .filter_map(|row| match row.last() {
+            Some(Literal(lit)) => Some(lit),
+            _ => None,
+        })
+        .collect();
+
+    let first = seen.first()?;
+
+    first.unseen_sibling(&seen).map(Literal)
+}
+
 fn is_missing<T>(union: Union, ctors: &MutMap<TagId, T>, ctor: &Ctor) -> Option<Pattern> {
     let Ctor { arity, tag_id, .. } = ctor;
 
@@ -416,6 +489,17 @@ pub fn is_useful(mut old_matrix: PatternMatrix, mut vector: Row) -> bool {
                         }
                     }
 
+                    Anything
+                        if old_matrix
+                            .iter()
+                            .any(|row| matches!(row.last(), Some(List(..)))) =>
+                    {
+                        // In a column of lists, `_` covers exactly the same values as `[..]`.
+                        // Checking it as a list lets the rows' list patterns cover it together,
+                        // as `[]` and `[_, ..]` do.
+                        vector.push(List(ListArity::ANY, vec![]));
+                    }
+
                     Anything => {
                         // check if all alternatives appear in matrix
                         match is_complete(&old_matrix) {
@@ -698,9 +782,14 @@ fn collect_ctors(matrix: &RefPatternMatrix) -> CollectedCtors {
         return CollectedCtors::NonExhaustiveAny;
     }
 
-    let first_row = &matrix[0];
+    // Rows starting with `_` match every constructor, so the constructors of the column are
+    // decided by the first row that starts with something more specific.
+    let first_specific = matrix
+        .iter()
+        .filter_map(|row| row.last())
+        .find(|pattern| !matches!(pattern, Anything));
 
-    if let Some(ctor) = first_row.last() {
+    if let Some(ctor) = first_specific {
         match ctor {
             Anything => CollectedCtors::NonExhaustiveAny,
             Pattern::Literal(_) => CollectedCtors::NonExhaustiveAny,
@@ -1,6 +1,7 @@
 use crate::{
     collection::{fmt_collection, Braces},
     expr::{expr_lift_spaces, expr_lift_spaces_after, expr_prec, merge_spaces_conservative},
+    migrate::MigrateError,
     node::{
         parens_around_node, DelimitedItem, Item, Node, NodeInfo, NodeSequenceBuilder, Nodify, Prec,
         Sp,
@@ -424,16 +425,7 @@ fn assigned_field_value_to_node<'a, 'b>(
 where
     'a: 'b,
 {
-    let field_name = if flags.snakify {
-        let mut buf = Buf::new_in(arena, flags);
-        buf.indent(0); // Take out of beginning of line
-        snakify_camel_ident(&mut buf, name);
-        let s: &str = arena.alloc_str(buf.as_str());
-        s
-    } else {
-        name
-    };
-    let first = Node::Literal(field_name);
+    let first = Node::Ident(name);
 
     let mut b = NodeSequenceBuilder::new(arena, first, 2, false);
 
@@ -1082,16 +1074,7 @@ impl<'a> Nodify<'a> for TypeVar<'a> {
                 inner
             }
             TypeVar::Identifier(text) => {
-                let var_name = if flags.snakify {
-                    let mut buf = Buf::new_in(arena, flags);
-                    buf.indent(0); // Take out of beginning of line
-                    snakify_camel_ident(&mut buf, text);
-                    let s: &str = arena.alloc_str(buf.as_str());
-                    s
-                } else {
-                    text
-                };
-                let item = NodeInfo::item(Node::Literal(var_name));
+                let item = NodeInfo::item(Node::Ident(text));
 
                 if *text == "implements" {
                     parens_around_node(arena, item, false)
@@ -1120,10 +1103,15 @@ impl<'a> Nodify<'a> for TypeAnnotation<'a> {
     {
         match self {
             TypeAnnotation::Apply(module, func, args) => {
-                let first = if module.is_empty() {
-                    Node::Literal(func)
+                let name = if module.is_empty() {
+                    *func
+                } else {
+                    arena.alloc_str(&format!("{}.{}", module, func))
+                };
+                let first = if *func == "Task" && (module.is_empty() || *module == "Task") {
+                    Node::NotMigrated(name, MigrateError::TaskNotSupported)
                 } else {
-                    Node::Literal(arena.alloc_str(&format!("{}.{}", module, func)))
+                    Node::Literal(name)
                 };
 
                 NodeInfo::apply(
@@ -1237,16 +1225,7 @@ impl<'a> Nodify<'a> for TypeAnnotation<'a> {
                 }
             }
             TypeAnnotation::BoundVariable(text) => {
-                let var_name = if flags.snakify {
-                    let mut buf = Buf::new_in(arena, flags);
-                    buf.indent(0); // Take out of beginning of line
-                    snakify_camel_ident(&mut buf, text);
-                    let s: &str = arena.alloc_str(buf.as_str());
-                    s
-                } else {
-                    text
-                };
-                let item = NodeInfo::item(Node::Literal(var_name));
+                let item = NodeInfo::item(Node::Ident(text));
 
                 if *text == "implements" {
                     parens_around_node(arena, item, false)
@@ -1377,19 +1356,11 @@ fn filter_newlines<'a, 'b: 'a>(
 }
 
 impl<'a> Nodify<'a> for &'a str {
-    fn to_node<'b>(&'a self, arena: &'b Bump, flags: MigrationFlags) -> NodeInfo<'b>
+    fn to_node<'b>(&'a self, _arena: &'b Bump, _flags: MigrationFlags) -> NodeInfo<'b>
     where
         'a: 'b,
     {
-        if flags.snakify {
-            let mut buf = Buf::new_in(arena, flags);
-            buf.indent(0); // Take out of beginning of line
-            snakify_camel_ident(&mut buf, self);
-            let s: &str = arena.alloc_str(buf.as_str());
-            NodeInfo::item(Node::Literal(s))
-        } else {
-            NodeInfo::item(Node::Literal(self))
-        }
+        NodeInfo::item(Node::Ident(self))
     }
 }
 
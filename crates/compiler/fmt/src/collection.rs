@@ -46,7 +46,11 @@ pub fn fmt_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable + std::fmt::D
     let start = braces.start();
     let end = braces.end();
 
-    if is_collection_multiline(&items) {
+    if is_collection_multiline(&items)
+        || !buf.fits_on_line(indent, |buf| {
+            fmt_collection_single_line(buf, indent, braces, &items)
+        })
+    {
         let braces_indent = indent;
         let item_indent = braces_indent + INDENT;
         if newline == Newlines::Yes {
@@ -142,29 +146,39 @@ pub fn fmt_collection<'a, 'buf, T: ExtractSpaces<'a> + Formattable + std::fmt::D
 
         buf.ensure_ends_with_newline();
         buf.indent(braces_indent);
+        buf.push(end);
     } else {
         // is_multiline == false
         // there is no comment to add
-        buf.indent(indent);
-        buf.push(start);
-        let mut iter = items.iter().enumerate().peekable();
-        while let Some((index, item)) = iter.next() {
-            if braces == Braces::Curly || index != 0 {
-                buf.spaces(1);
-            }
+        fmt_collection_single_line(buf, indent, braces, &items);
+    }
+}
 
-            item.format(buf, indent);
-            if iter.peek().is_some() {
-                buf.push(',');
-            }
+fn fmt_collection_single_line<T: Formattable>(
+    buf: &mut Buf<'_>,
+    indent: u16,
+    braces: Braces,
+    items: &Collection<'_, T>,
+) {
+    buf.indent(indent);
+    buf.push(braces.start());
+    let mut iter = items.iter().enumerate().peekable();
+    while let Some((index, item)) = iter.next() {
+        if braces == Braces::Curly || index != 0 {
+            buf.spaces(1);
         }
 
-        if !items.is_empty() && braces == Braces::Curly {
-            buf.spaces(1);
+        item.format(buf, indent);
+        if iter.peek().is_some() {
+            buf.push(',');
         }
     }
 
-    buf.push(end);
+    if !items.is_empty() && braces == Braces::Curly {
+        buf.spaces(1);
+    }
+
+    buf.push(braces.end());
 }
 
 fn has_comments(spaces: &[CommentOrNewline<'_>]) -> bool {
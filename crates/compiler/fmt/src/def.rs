@@ -934,6 +934,12 @@ fn fmt_expect<'a>(buf: &mut Buf, condition: &'a Loc<Expr<'a>>, is_multiline: boo
     buf.indent(indent);
     buf.push_str("expect");
 
+    let is_multiline = is_multiline
+        || !buf.fits_on_line(indent, |buf| {
+            buf.spaces(1);
+            condition.format(buf, indent);
+        });
+
     let return_indent = if is_multiline {
         buf.newline();
         indent + INDENT
@@ -1011,7 +1017,14 @@ pub fn fmt_body<'a>(buf: &mut Buf, pattern: &'a Pattern<'a>, body: &'a Expr<'a>,
         .item
         .maybe_before(buf.text.bump(), body_lifted.before);
 
-    if body.is_multiline() {
+    // A body that doesn't fit gets formatted like one that's already multiline, so that a binop
+    // chain that's about to get split up starts on its own line.
+    let body_fits = buf.fits_on_line(indent, |buf| {
+        buf.spaces(1);
+        body.format_with_options(buf, Parens::NotNeeded, Newlines::Yes, indent);
+    });
+
+    if body.is_multiline() || !body_fits {
         match body {
             Expr::SpaceBefore(sub_def, spaces) => {
                 let should_outdent = match sub_def {
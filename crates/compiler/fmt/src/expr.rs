@@ -1,6 +1,7 @@
 use crate::annotation::{except_last, is_collection_multiline, Formattable, Newlines, Parens};
 use crate::collection::{fmt_collection, Braces};
 use crate::def::{fmt_defs, starts_with_block_string_literal, valdef_lift_spaces_before};
+use crate::migrate::{MigrateError, Migration};
 use crate::node::Prec;
 use crate::pattern::{
     fmt_pattern, pattern_lift_spaces, snakify_camel_ident, starts_with_inline_comment,
@@ -69,6 +70,9 @@ fn format_expr_only(
         }
         Expr::Var { module_name, ident } => {
             buf.indent(indent);
+            if *module_name == "Task" {
+                buf.not_migrated(MigrateError::TaskNotSupported);
+            }
             if !module_name.is_empty() {
                 buf.push_str(module_name);
                 buf.push('.');
@@ -128,6 +132,7 @@ fn format_expr_only(
             loc_args,
             _,
         ) if buf.flags().parens_and_commas => {
+            buf.migrated(Migration::ParensAndCommas);
             let arena = buf.text.bump();
             let apply = arena.alloc(Expr::PncApply(
                 arena.alloc(Loc {
@@ -145,6 +150,7 @@ fn format_expr_only(
             );
         }
         Expr::Apply(loc_expr, loc_args, _) if buf.flags().parens_and_commas => {
+            buf.migrated(Migration::ParensAndCommas);
             fmt_pnc_apply(loc_expr, &Collection::with_items(loc_args), indent, buf);
         }
         Expr::Apply(loc_expr, loc_args, _) => {
@@ -397,13 +403,16 @@ fn format_expr_only(
         }
         Expr::MalformedIdent(str, _) => {
             buf.indent(indent);
+            buf.not_migrated(MigrateError::MalformedIdentNotSupported);
             if buf.flags().snakify {
                 snakify_camel_ident(buf, str);
             } else {
                 buf.push_str(str);
             }
         }
-        Expr::PrecedenceConflict { .. } => {}
+        Expr::PrecedenceConflict { .. } => {
+            buf.not_migrated(MigrateError::PrecedenceConflictNotSupported);
+        }
         Expr::EmptyRecordBuilder { .. } => {}
         Expr::SingleFieldRecordBuilder { .. } => {}
         Expr::OptionalFieldInRecordBuilder(_, _) => {}
@@ -750,7 +759,8 @@ fn fmt_apply(
                     a.is_multiline()
                         && (!a.extract_spaces().before.is_empty() || !is_outdentable(&a.value))
                 })
-                .unwrap_or_default());
+                .unwrap_or_default()
+            || !buf.fits_on_line(indent, |buf| fmt_apply(loc_expr, loc_args, indent, buf)));
 
     let arg_indent = if needs_indent {
         indent + INDENT
@@ -964,6 +974,9 @@ fn format_str_segment(seg: &StrSegment, buf: &mut Buf) {
             buf.push(escaped.to_parsed_char());
         }
         Interpolated(loc_expr) => {
+            if buf.src_before_ends_with(loc_expr.region, "$(") {
+                buf.migrated(Migration::Interpolation);
+            }
             buf.push_str("${");
             // e.g. {name} in "Hi, ${name}!"
             let min_indent = buf.cur_line_indent() + INDENT;
@@ -1526,7 +1539,13 @@ fn fmt_binops<'a>(
     indent: u16,
 ) {
     let is_multiline = loc_right_side.value.is_multiline()
-        || lefts.iter().any(|(expr, _)| expr.value.is_multiline());
+        || lefts.iter().any(|(expr, _)| expr.value.is_multiline())
+        || (lefts
+            .iter()
+            .any(|(_, loc_binop)| loc_binop.value == BinOp::Pizza)
+            && !buf.fits_on_line(indent, |buf| {
+                fmt_binops(buf, lefts, loc_right_side, indent)
+            }));
 
     for (loc_left_side, loc_binop) in lefts {
         let binop = loc_binop.value;
@@ -2032,6 +2051,11 @@ fn fmt_closure<'a>(
     use self::Expr::*;
 
     buf.indent(indent);
+    if let Some(first) = loc_patterns.first() {
+        if buf.src_before_ends_with(first.region, "\\") {
+            buf.migrated(Migration::Lambda);
+        }
+    }
     buf.push('|');
 
     let arguments_are_multiline = loc_patterns
@@ -2086,9 +2110,12 @@ fn fmt_closure<'a>(
     buf.push_str("|");
     buf.spaces(1);
 
-    let is_multiline = loc_ret.value.is_multiline();
+    let is_multiline = loc_ret.value.is_multiline()
+        || !buf.fits_on_line(indent, |buf| {
+            loc_ret.format_with_options(buf, Parens::NotNeeded, Newlines::Yes, indent)
+        });
 
-    // If the body is multiline, go down a line and indent.
+    // If the body is multiline (or too long to fit), go down a line and indent.
     let body_indent = if is_multiline {
         arrow_line_indent + INDENT
     } else {
@@ -2125,6 +2152,24 @@ fn fmt_closure<'a>(
     }
 }
 
+fn fmt_record_fields_single_line<Field: Formattable>(
+    buf: &mut Buf<'_>,
+    loc_fields: &[Loc<Field>],
+    indent: u16,
+) {
+    buf.spaces(1);
+    let mut iter = loc_fields.iter().peekable();
+    while let Some(field) = iter.next() {
+        field.format_with_options(buf, Parens::NotNeeded, Newlines::No, indent);
+
+        if iter.peek().is_some() {
+            buf.push_str(",");
+            buf.spaces(1);
+        }
+    }
+    buf.spaces(1);
+}
+
 enum RecordPrefix<'a> {
     Update(&'a Loc<Expr<'a>>),
     Mapper(&'a Loc<Expr<'a>>),
@@ -2171,7 +2216,11 @@ fn fmt_record_like<'a, 'b: 'a, Field, ToSpacesAround>(
         }
 
         let is_multiline = loc_fields.iter().any(|loc_field| loc_field.is_multiline())
-            || !final_comments.is_empty();
+            || !final_comments.is_empty()
+            || !buf.fits_on_line(indent, |buf| {
+                fmt_record_fields_single_line(buf, loc_fields, indent);
+                buf.push('}');
+            });
 
         if is_multiline {
             let field_indent = indent + INDENT;
@@ -2232,21 +2281,10 @@ fn fmt_record_like<'a, 'b: 'a, Field, ToSpacesAround>(
             buf.ensure_ends_with_newline();
         } else {
             // is_multiline == false
-            buf.spaces(1);
-            let field_indent = indent;
-            let mut iter = loc_fields.iter().peekable();
-            while let Some(field) = iter.next() {
-                field.format_with_options(buf, Parens::NotNeeded, Newlines::No, field_indent);
-
-                if iter.peek().is_some() {
-                    buf.push_str(",");
-                    buf.spaces(1);
-                }
-            }
-            buf.spaces(1);
             // if we are here, that means that `final_comments` is empty, thus we don't have
             // to add a comment. Anyway, it is not possible to have a single line record with
             // a comment in it.
+            fmt_record_fields_single_line(buf, loc_fields, indent);
         };
 
         // closes the initial bracket
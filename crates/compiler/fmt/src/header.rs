@@ -17,6 +17,10 @@ use roc_parse::ident::UppercaseIdent;
 use roc_region::all::Loc;
 
 pub fn fmt_header<'a>(buf: &mut Buf<'_>, header: &'a SpacesBefore<'a, Header<'a>>) {
+    // Headers keep their layout regardless of the max width; some of their parts (like the
+    // `requires` of a platform) can't be split up the way the same syntax in an expression can.
+    let max_width = buf.max_width.take();
+
     fmt_comments_only(buf, header.before.iter(), NewlineAt::Bottom, 0);
     match &header.item {
         Header::Module(header) => {
@@ -35,6 +39,8 @@ pub fn fmt_header<'a>(buf: &mut Buf<'_>, header: &'a SpacesBefore<'a, Header<'a>
             fmt_hosted_header(buf, header);
         }
     }
+
+    buf.max_width = max_width;
 }
 
 macro_rules! keywords {
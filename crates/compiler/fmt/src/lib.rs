@@ -13,6 +13,8 @@ pub mod pattern;
 pub mod spaces;
 
 use bumpalo::{collections::String, Bump};
+use migrate::{MigrateError, Migration, MigrationReport};
+use roc_region::all::{Loc, Position, Region};
 
 #[derive(Debug)]
 pub struct Buf<'a> {
@@ -22,6 +24,9 @@ pub struct Buf<'a> {
     beginning_of_line: bool,
     line_indent: u16,
     flags: MigrationFlags,
+    max_width: Option<u16>,
+    src: &'a str,
+    migrations: MigrationReport,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -36,6 +41,57 @@ impl MigrationFlags {
     }
 }
 
+/// How many times [`fmt_with_max_width`] reformats its output to let the max width settle down
+const MAX_WIDTH_PASSES: usize = 8;
+
+/// Format `ast` (parsed from `src`) with `fmt`, returning the formatted code, `parse` of it,
+/// and what formatting migrated. If the formatted code doesn't parse, returns it with the error.
+///
+/// Splitting up something that went past the max width can change how the code around it
+/// is laid out the next time it's formatted, so with a max width, this keeps reformatting
+/// until that settles down. Every pass only puts more things on multiple lines, so it doesn't
+/// take many.
+pub fn fmt_with_max_width<'a, T, E>(
+    arena: &'a Bump,
+    src: &'a str,
+    ast: &T,
+    flags: MigrationFlags,
+    max_width: Option<u16>,
+    fmt: impl Fn(&mut Buf<'a>, &T),
+    parse: impl Fn(&'a str) -> Result<T, E>,
+) -> Result<(&'a str, T, MigrationReport), (&'a str, E)> {
+    let fmt_once = |src: &'a str, ast: &T| {
+        let mut buf = Buf::new_in(arena, flags);
+        buf.set_src(src);
+        buf.set_max_width(max_width);
+        fmt(&mut buf, ast);
+
+        let report = buf.take_migration_report();
+
+        (buf.into_bump_str(), report)
+    };
+
+    let (mut formatted_src, mut report) = fmt_once(src, ast);
+    let mut reparsed = parse(formatted_src).map_err(|err| (formatted_src, err))?;
+
+    if max_width.is_some() {
+        for _ in 0..MAX_WIDTH_PASSES {
+            // Everything gets migrated in the first pass, so all that changes after it is where.
+            let (next_src, _) = fmt_once(formatted_src, &reparsed);
+
+            if next_src == formatted_src {
+                break;
+            }
+
+            report = report.moved(formatted_src, next_src);
+            formatted_src = next_src;
+            reparsed = parse(formatted_src).map_err(|err| (formatted_src, err))?;
+        }
+    }
+
+    Ok((formatted_src, reparsed, report))
+}
+
 impl<'a> Buf<'a> {
     pub fn new_in(arena: &'a Bump, flags: MigrationFlags) -> Buf<'a> {
         Buf {
@@ -45,6 +101,9 @@ impl<'a> Buf<'a> {
             newlines_to_flush: 0,
             beginning_of_line: true,
             flags,
+            max_width: None,
+            src: "",
+            migrations: MigrationReport::default(),
         }
     }
 
@@ -52,6 +111,94 @@ impl<'a> Buf<'a> {
         self.flags
     }
 
+    /// With a max width, applications, records, lists (and other collections) and `|>` pipelines
+    /// that would go past it get laid out on multiple lines instead. Since that changes how the
+    /// code around them gets laid out the next time it's formatted, use [`fmt_with_max_width`]
+    /// to reformat until the output stops changing.
+    pub fn set_max_width(&mut self, max_width: Option<u16>) {
+        self.max_width = max_width;
+    }
+
+    /// Whether what `fmt` writes (which must all be on one line) fits before the max width,
+    /// when it starts where the buffer is now (or at `indent`, at the beginning of a line).
+    /// Without a max width, everything fits.
+    pub fn fits_on_line(&self, indent: u16, fmt: impl FnOnce(&mut Buf<'a>)) -> bool {
+        let Some(max_width) = self.max_width else {
+            return true;
+        };
+
+        let mut scratch = Buf {
+            text: String::new_in(self.text.bump()),
+            spaces_to_flush: 0,
+            newlines_to_flush: 0,
+            beginning_of_line: false,
+            line_indent: indent,
+            flags: self.flags,
+            max_width: None,
+            src: self.src,
+            migrations: MigrationReport::default(),
+        };
+
+        fmt(&mut scratch);
+
+        let width = scratch.text.lines().next().unwrap_or_default().chars().count();
+
+        self.cur_column(indent) + width <= max_width as usize
+    }
+
+    /// The code being formatted. Some deprecated syntax (like `\\x ->` lambdas) parses the same
+    /// as what replaced it, so without it, rewriting that syntax can't be told apart from
+    /// formatting code that already used the new syntax.
+    pub fn set_src(&mut self, src: &'a str) {
+        self.src = src;
+    }
+
+    /// Whether the code being formatted has `syntax` right before `region`, ignoring spaces
+    pub fn src_before_ends_with(&self, region: Region, syntax: &str) -> bool {
+        self.src
+            .get(..region.start().offset as usize)
+            .is_some_and(|before| before.trim_end().ends_with(syntax))
+    }
+
+    /// Note that some deprecated syntax is being rewritten, where the next thing written goes
+    pub fn migrated(&mut self, migration: Migration) {
+        let region = self.next_region();
+
+        self.migrations.migrated.push(Loc::at(region, migration));
+    }
+
+    /// Note that some code can't be migrated, so it's being written as it is
+    pub fn not_migrated(&mut self, error: MigrateError) {
+        let region = self.next_region();
+
+        self.migrations.not_migrated.push(Loc::at(region, error));
+    }
+
+    /// Everything that was noted as migrated (or not) so far, in the order it was written,
+    /// with where each of them starts in the formatted code.
+    pub fn take_migration_report(&mut self) -> MigrationReport {
+        std::mem::take(&mut self.migrations)
+    }
+
+    /// Where the next thing written will start, once the spaces before it are flushed
+    fn next_region(&self) -> Region {
+        let offset = self.text.len() + self.newlines_to_flush + self.spaces_to_flush;
+
+        Region::from_pos(Position::new(offset as u32))
+    }
+
+    fn cur_column(&self, indent: u16) -> usize {
+        if self.beginning_of_line {
+            indent as usize
+        } else if self.newlines_to_flush > 0 {
+            self.spaces_to_flush
+        } else {
+            let line_start = self.text.rfind('\n').map_or(0, |index| index + 1);
+
+            self.text[line_start..].chars().count() + self.spaces_to_flush
+        }
+    }
+
     pub fn as_str(&'a self) -> &'a str {
         self.text.as_str()
     }
@@ -13,7 +13,7 @@ use roc_parse::{
     },
     ident::Accessor,
 };
-use roc_region::all::Loc;
+use roc_region::all::{Loc, Position, Region};
 
 use crate::{
     annotation::{is_collection_multiline, Parens},
@@ -1043,7 +1043,7 @@ impl Fmt for TypeDef<'_> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MigrateError {
     AbilitiesNotSupported,
     MalformedIdentNotSupported,
@@ -1051,6 +1051,29 @@ pub enum MigrateError {
     MalformedPatternIdentNotSupported,
     MalformedPatternAsExprNotSupported,
     PrecedenceConflictNotSupported,
+    /// Code using the old `Task` API has to be rewritten to use effectful functions by hand,
+    /// because whether it still works depends on types the formatter doesn't know about.
+    TaskNotSupported,
+}
+
+impl std::fmt::Display for MigrateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            MigrateError::AbilitiesNotSupported => "abilities can't be migrated automatically",
+            MigrateError::MalformedIdentNotSupported => "this name doesn't parse",
+            MigrateError::MalformedPatternNotSupported
+            | MigrateError::MalformedPatternIdentNotSupported
+            | MigrateError::MalformedPatternAsExprNotSupported => "this pattern doesn't parse",
+            MigrateError::PrecedenceConflictNotSupported => {
+                "these operators need parentheses to say which goes first"
+            }
+            MigrateError::TaskNotSupported => {
+                "the `Task` API is gone; use effectful functions (whose names end in `!`) instead"
+            }
+        };
+
+        f.write_str(message)
+    }
 }
 
 impl Fmt for ValueDef<'_> {
@@ -1399,3 +1422,229 @@ pub fn fmt_defs(buf: &mut Buf<'_>, defs: &Defs<'_>) -> Result<(), MigrateError>
     }
     Ok(())
 }
+
+/// Deprecated syntax that formatting with migrations turns into the current syntax
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Migration {
+    /// `\\x -> ...` to `|x| ...`
+    Lambda,
+    /// `"$(x)"` to `"${x}"`
+    Interpolation,
+    /// `f x y` to `f(x, y)`
+    ParensAndCommas,
+    /// A camelCase name to snake_case, like `fooBar` to `foo_bar`
+    SnakeCase { from: String, to: String },
+}
+
+impl std::fmt::Display for Migration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Migration::Lambda => write!(f, "`\\x ->` lambda to `|x|`"),
+            Migration::Interpolation => write!(f, "`$(x)` interpolation to `${{x}}`"),
+            Migration::ParensAndCommas => write!(f, "`f x y` call to `f(x, y)`"),
+            Migration::SnakeCase { from, to } => write!(f, "`{from}` to `{to}`"),
+        }
+    }
+}
+
+/// What formatting with migrations rewrote, and what it found but couldn't rewrite, with where
+/// each of them starts in the formatted code. The formatter notes these as it writes them
+/// (see [`Buf::migrated`]).
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    pub migrated: Vec<Loc<Migration>>,
+    pub not_migrated: Vec<Loc<MigrateError>>,
+}
+
+impl MigrationReport {
+    /// Where everything in the report is in `to`, after reformatting `from` changed only how
+    /// it's laid out (like going past the max width does). That never changes the names and
+    /// literals or their order, so each is found again by the letters and digits before it.
+    pub fn moved(self, from: &str, to: &str) -> MigrationReport {
+        let alphanumerics = |text: &str| -> Vec<usize> {
+            text.char_indices()
+                .filter(|(_, c)| c.is_ascii_alphanumeric())
+                .map(|(index, _)| index)
+                .collect()
+        };
+        let from_alphanumerics = alphanumerics(from);
+        let to_alphanumerics = alphanumerics(to);
+        let move_region = |region: Region| {
+            let offset = region.start().offset as usize;
+            let count = from_alphanumerics.partition_point(|&index| index < offset);
+            let moved = to_alphanumerics.get(count).copied().unwrap_or(to.len());
+
+            Region::from_pos(Position::new(moved as u32))
+        };
+
+        MigrationReport {
+            migrated: self
+                .migrated
+                .into_iter()
+                .map(|loc| Loc::at(move_region(loc.region), loc.value))
+                .collect(),
+            not_migrated: self
+                .not_migrated
+                .into_iter()
+                .map(|loc| Loc::at(move_region(loc.region), loc.value))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod report_test {
+    use bumpalo::Bump;
+    use roc_parse::{ast::Defs, header::parse_module_defs, state::State};
+
+    use roc_region::all::{Loc, Position, Region};
+
+    use super::{MigrateError, Migration, MigrationReport};
+    use crate::{def::fmt_defs, Buf, MigrationFlags};
+
+    /// What was noted, by the rest of the formatted line it starts on
+    type Found<T> = Vec<(String, T)>;
+
+    const NO_FLAGS: MigrationFlags = MigrationFlags {
+        snakify: false,
+        parens_and_commas: false,
+    };
+
+    fn report(src: &str, flags: MigrationFlags) -> (Found<Migration>, Found<MigrateError>) {
+        let arena = Bump::new();
+        let src = arena.alloc_str(src);
+        let defs = parse_module_defs(&arena, State::new(src.as_bytes()), Defs::default())
+            .expect("test source should parse");
+        let mut buf = Buf::new_in(&arena, flags);
+
+        buf.set_src(src);
+        fmt_defs(&mut buf, &defs, 0);
+
+        let report = buf.take_migration_report();
+        let formatted = buf.into_bump_str();
+        let rest_of_line = |offset: u32| {
+            let rest = &formatted[offset as usize..];
+
+            rest.lines().next().unwrap_or_default().to_string()
+        };
+
+        (
+            report
+                .migrated
+                .into_iter()
+                .map(|loc| (rest_of_line(loc.region.start().offset), loc.value))
+                .collect(),
+            report
+                .not_migrated
+                .into_iter()
+                .map(|loc| (rest_of_line(loc.region.start().offset), loc.value))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn reports_lambdas_and_interpolation_without_flags() {
+        let src = "f = \\x -> \"hi $(x)\"\ng = |y| \"hi ${y}\"\n";
+        let (migrated, not_migrated) = report(src, NO_FLAGS);
+
+        assert_eq!(
+            migrated,
+            vec![
+                ("|x| \"hi ${x}\"".to_string(), Migration::Lambda),
+                ("${x}\"".to_string(), Migration::Interpolation)
+            ]
+        );
+        assert!(not_migrated.is_empty());
+    }
+
+    #[test]
+    fn reports_renames_and_calls() {
+        let src = "fooBar = List.map xs toStr\n";
+        let flags = MigrationFlags {
+            snakify: true,
+            parens_and_commas: true,
+        };
+        let (migrated, _) = report(src, flags);
+
+        assert_eq!(
+            migrated,
+            vec![
+                (
+                    "foo_bar = List.map(xs, to_str)".to_string(),
+                    Migration::SnakeCase {
+                        from: "fooBar".to_string(),
+                        to: "foo_bar".to_string()
+                    }
+                ),
+                (
+                    "List.map(xs, to_str)".to_string(),
+                    Migration::ParensAndCommas
+                ),
+                (
+                    "to_str)".to_string(),
+                    Migration::SnakeCase {
+                        from: "toStr".to_string(),
+                        to: "to_str".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_task_as_not_migrated() {
+        let src = "main : Task {} []\nmain = Task.ok {}\n";
+        let (_, not_migrated) = report(src, NO_FLAGS);
+
+        assert_eq!(
+            not_migrated,
+            vec![
+                ("Task {} []".to_string(), MigrateError::TaskNotSupported),
+                ("Task.ok {}".to_string(), MigrateError::TaskNotSupported)
+            ]
+        );
+    }
+
+    #[test]
+    fn moved_reports_follow_the_code_they_are_about() {
+        let report = MigrationReport {
+            migrated: vec![Loc::at(
+                Region::from_pos(Position::new(4)),
+                Migration::Lambda,
+            )],
+            not_migrated: vec![Loc::at(
+                Region::from_pos(Position::new(13)),
+                MigrateError::TaskNotSupported,
+            )],
+        };
+        let moved = report.moved(
+            "f = |x| g(x, Task.ok)",
+            "f = |x|\n    g(\n        x,\n        Task.ok,\n    )",
+        );
+
+        assert_eq!(
+            moved,
+            MigrationReport {
+                migrated: vec![Loc::at(
+                    Region::from_pos(Position::new(5)),
+                    Migration::Lambda
+                )],
+                not_migrated: vec![Loc::at(
+                    Region::from_pos(Position::new(34)),
+                    MigrateError::TaskNotSupported,
+                )],
+            }
+        );
+    }
+
+    #[test]
+    fn reports_nothing_for_already_migrated_code() {
+        let src = "foo_bar = |x| List.map(x, to_str)\n";
+        let flags = MigrationFlags {
+            snakify: true,
+            parens_and_commas: true,
+        };
+
+        assert_eq!(report(src, flags), (vec![], vec![]));
+    }
+}
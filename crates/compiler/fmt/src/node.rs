@@ -5,6 +5,8 @@ use crate::{
     annotation::{Formattable, Newlines, Parens},
     collection::Braces,
     expr::merge_spaces_conservative,
+    migrate::MigrateError,
+    pattern::snakify_camel_ident,
     spaces::{fmt_spaces, fmt_spaces_no_blank_lines, INDENT},
     Buf, MigrationFlags,
 };
@@ -74,6 +76,10 @@ impl<'a> From<&'a [CommentOrNewline<'a>]> for Sp<'a> {
 #[derive(Copy, Clone, Debug)]
 pub enum Node<'a> {
     Literal(&'a str),
+    /// A lowercase name, which formatting with migrations turns into snake_case
+    Ident(&'a str),
+    /// Something formatting with migrations can't rewrite, so it's written as it is
+    NotMigrated(&'a str, MigrateError),
     Sequence {
         first: &'a Node<'a>,
         extra_indent_for_rest: bool,
@@ -359,7 +365,7 @@ impl<'a> Formattable for Node<'a> {
                 first,
                 rest,
             } => first.is_multiline() || rest.iter().any(|item| item.is_multiline()),
-            Node::Literal(_) => false,
+            Node::Literal(_) | Node::Ident(_) | Node::NotMigrated(..) => false,
             Node::TypeAnnotation(type_annotation) => type_annotation.is_multiline(),
             Node::Pattern(pat) => pat.is_multiline(),
             Node::Expr(expr) => expr.is_multiline(),
@@ -456,6 +462,15 @@ impl<'a> Formattable for Node<'a> {
                 buf.indent(indent);
                 buf.push_str(text);
             }
+            Node::Ident(name) => {
+                buf.indent(indent);
+                snakify_camel_ident(buf, name);
+            }
+            Node::NotMigrated(text, error) => {
+                buf.indent(indent);
+                buf.not_migrated(*error);
+                buf.push_str(text);
+            }
             Node::TypeAnnotation(type_annotation) => {
                 type_annotation.format_with_options(buf, parens, newlines, indent);
             }
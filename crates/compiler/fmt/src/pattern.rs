@@ -2,6 +2,7 @@ use crate::annotation::{Formattable, Newlines, Parens};
 use crate::expr::{
     expr_is_multiline, expr_lift_spaces_after, fmt_str_literal, format_sq_literal, is_str_multiline,
 };
+use crate::migrate::{MigrateError, Migration};
 use crate::node::{Node, NodeInfo, NodeSequenceBuilder, Prec, Sp};
 use crate::spaces::{fmt_comments_only, fmt_spaces, NewlineAt, INDENT};
 use crate::Buf;
@@ -446,12 +447,19 @@ fn fmt_pattern_only(
         }
 
         // Malformed
-        Pattern::Malformed(string) | Pattern::MalformedIdent(string, _) => {
+        Pattern::Malformed(string) => {
             buf.indent(indent);
+            buf.not_migrated(MigrateError::MalformedPatternNotSupported);
+            buf.push_str(string);
+        }
+        Pattern::MalformedIdent(string, _) => {
+            buf.indent(indent);
+            buf.not_migrated(MigrateError::MalformedPatternIdentNotSupported);
             buf.push_str(string);
         }
         Pattern::MalformedExpr(expr) => {
             buf.indent(indent);
+            buf.not_migrated(MigrateError::MalformedPatternAsExprNotSupported);
             expr.format(buf, indent);
         }
         Pattern::QualifiedIdentifier { module_name, ident } => {
@@ -479,6 +487,11 @@ pub fn pattern_fmt_apply(
 ) {
     let use_commas_and_parens = is_pnc || buf.flags().parens_and_commas;
     buf.indent(indent);
+
+    if !is_pnc && use_commas_and_parens && !args.is_empty() {
+        buf.migrated(Migration::ParensAndCommas);
+    }
+
     // Sometimes, an Apply pattern needs parens around it.
     // In particular when an Apply's argument is itself an Apply (> 0) arguments
     let parens = !args.is_empty()
@@ -831,6 +844,7 @@ pub fn snakify_camel_ident(buf: &mut Buf, string: &str) {
         buf.push_str(string);
         return;
     }
+    let mut snake = std::string::String::with_capacity(string.len());
     let mut index = 0;
     let len = chars.len();
 
@@ -857,11 +871,20 @@ pub fn snakify_camel_ident(buf: &mut Buf, string: &str) {
         };
         // those are boundary transitions - should push _ and curr
         if boundary {
-            buf.push('_');
+            snake.push('_');
         }
-        buf.push(c.to_ascii_lowercase());
+        snake.push(c.to_ascii_lowercase());
         index += 1;
     }
+
+    if snake != string {
+        buf.migrated(Migration::SnakeCase {
+            from: string.to_string(),
+            to: snake.clone(),
+        });
+    }
+
+    buf.push_str(&snake);
 }
 
 #[cfg(test)]
@@ -1052,15 +1052,15 @@ trait Backend<'a> {
                 debug_assert_eq!(
                     2,
                     args.len(),
-                    "NumAdd: expected to have exactly two argument"
+                    "NumAddWrap: expected to have exactly two argument"
                 );
-                debug_assert_eq!(
-                    arg_layouts[0], arg_layouts[1],
-                    "NumAdd: expected all arguments of to have the same layout"
+                debug_assert!(
+                    self.interner().eq_repr(arg_layouts[0], arg_layouts[1]),
+                    "NumAddWrap: expected all arguments to have the same representation"
                 );
-                debug_assert_eq!(
-                    arg_layouts[0], *ret_layout,
-                    "NumAdd: expected to have the same argument and return layout"
+                debug_assert!(
+                    self.interner().eq_repr(arg_layouts[0], *ret_layout),
+                    "NumAddWrap: expected the arguments and return value to have the same representation"
                 );
                 self.build_num_add_wrap(sym, &args[0], &args[1], ret_layout)
             }
@@ -1225,13 +1225,13 @@ trait Backend<'a> {
                     args.len(),
                     "NumSubWrap: expected to have exactly two argument"
                 );
-                debug_assert_eq!(
-                    arg_layouts[0], arg_layouts[1],
-                    "NumSubWrap: expected all arguments of to have the same layout"
+                debug_assert!(
+                    self.interner().eq_repr(arg_layouts[0], arg_layouts[1]),
+                    "NumSubWrap: expected all arguments to have the same representation"
                 );
-                debug_assert_eq!(
-                    arg_layouts[0], *ret_layout,
-                    "NumSubWrap: expected to have the same argument and return layout"
+                debug_assert!(
+                    self.interner().eq_repr(arg_layouts[0], *ret_layout),
+                    "NumSubWrap: expected the arguments and return value to have the same representation"
                 );
                 self.build_num_sub_wrap(sym, &args[0], &args[1], ret_layout)
             }
@@ -1765,6 +1765,20 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::ListIsUnique => self.build_fn_call(
+                sym,
+                bitcode::LIST_IS_UNIQUE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::StrIsUnique => self.build_fn_call(
+                sym,
+                bitcode::STR_IS_UNIQUE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::PtrCast => {
                 debug_assert_eq!(
                     1,
@@ -1869,6 +1883,27 @@ trait Backend<'a> {
                 arg_layouts,
                 ret_layout,
             ),
+            LowLevel::SetAllocSite => self.build_fn_call(
+                sym,
+                bitcode::UTILS_SET_ALLOC_SITE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::RefCountCheckStart => self.build_fn_call(
+                sym,
+                bitcode::UTILS_CHECK_REFCOUNTS_START.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+            LowLevel::RefCountCheckLeaks => self.build_fn_call(
+                sym,
+                bitcode::UTILS_CHECK_REFCOUNT_LEAKS.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
             LowLevel::NumToStr => {
                 let arg_layout = arg_layouts[0];
                 let intrinsic = match self.interner().get_repr(arg_layout) {
@@ -843,6 +843,18 @@ pub(crate) fn run_low_level<'a, 'ctx>(
                 bitcode::LIST_IS_UNIQUE,
             )
         }
+        StrIsUnique => {
+            // Str.isUnique : Str -> Bool
+            arguments!(string);
+
+            call_str_bitcode_fn(
+                env,
+                &[string],
+                &[],
+                BitcodeReturns::Basic,
+                bitcode::STR_IS_UNIQUE,
+            )
+        }
         ListClone => {
             // List.clone : List a -> List a
             arguments_with_layouts!((list, list_layout));
@@ -1410,6 +1422,28 @@ pub(crate) fn run_low_level<'a, 'ctx>(
 
             call_bitcode_fn(env, &[], bitcode::UTILS_DICT_PSEUDO_SEED)
         }
+        SetAllocSite => {
+            // only inserted when profiling allocations
+            arguments!(site);
+
+            call_bitcode_fn(env, &[site], bitcode::UTILS_SET_ALLOC_SITE)
+        }
+        RefCountCheckStart => {
+            // only inserted when checking refcounts
+            arguments!(site_names, per_call);
+
+            call_str_bitcode_fn(
+                env,
+                &[site_names],
+                &[per_call],
+                BitcodeReturns::Basic,
+                bitcode::UTILS_CHECK_REFCOUNTS_START,
+            )
+        }
+        RefCountCheckLeaks => {
+            // only inserted when checking refcounts
+            call_bitcode_fn(env, &[], bitcode::UTILS_CHECK_REFCOUNT_LEAKS)
+        }
 
         ListIncref | ListDecref | SetJmp | LongJmp | SetLongJmpBuffer => {
             unreachable!("only inserted in dev backend codegen")
@@ -282,6 +282,7 @@ impl<'a> LowLevelCall<'a> {
             ListGetCapacity => self.load_args_and_call_zig(backend, bitcode::LIST_CAPACITY),
 
             ListIsUnique => self.load_args_and_call_zig(backend, bitcode::LIST_IS_UNIQUE),
+            StrIsUnique => self.load_args_and_call_zig(backend, bitcode::STR_IS_UNIQUE),
 
             ListClone => {
                 let input_list: Symbol = self.arguments[0];
@@ -2243,6 +2244,13 @@ impl<'a> LowLevelCall<'a> {
                 StoredValue::StackMemory { .. } => { /* do nothing */ }
             },
             DictPseudoSeed => self.load_args_and_call_zig(backend, bitcode::UTILS_DICT_PSEUDO_SEED),
+            SetAllocSite => self.load_args_and_call_zig(backend, bitcode::UTILS_SET_ALLOC_SITE),
+            RefCountCheckStart => {
+                self.load_args_and_call_zig(backend, bitcode::UTILS_CHECK_REFCOUNTS_START)
+            }
+            RefCountCheckLeaks => {
+                self.load_args_and_call_zig(backend, bitcode::UTILS_CHECK_REFCOUNT_LEAKS)
+            }
 
             SetJmp | LongJmp | SetLongJmpBuffer => {
                 unreachable!("only inserted in dev backend codegen")
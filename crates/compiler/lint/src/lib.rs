@@ -0,0 +1,154 @@
+//! Lints for `roc lint`: checks for code that compiles and runs fine, but probably isn't what was
+//! meant, or is more complicated than it needs to be.
+//!
+//! Each lint implements [Lint], and is run on every declaration and expression in a module as
+//! [lint_module] walks its canonical AST. The problems they find are [LintProblem]s, which
+//! `roc_reporting` turns into reports, and may come with a [Fix] that `roc lint --fix` applies.
+mod lints;
+
+use roc_can::expr::{Declarations, Expr};
+use roc_can::traverse::{walk_decl, walk_expr, DeclarationInfo, Visitor};
+use roc_module::symbol::{Interns, ModuleId};
+use roc_problem::lint::{Fix, LintProblem};
+use roc_region::all::Region;
+use roc_types::subs::Variable;
+
+pub use lints::{ConfusableNames, CrashOnListGet, LeftoverDbg, ManualTry, RedundantWhenBranch};
+
+/// Every lint, in the order their problems are reported
+pub const ALL_LINTS: &[&dyn Lint] = &[
+    &RedundantWhenBranch,
+    &CrashOnListGet,
+    &ConfusableNames,
+    &ManualTry,
+    &LeftoverDbg,
+];
+
+pub trait Lint {
+    /// Check a top-level declaration, before any of the expressions in it
+    fn check_decl(
+        &self,
+        _cx: &LintContext<'_>,
+        _decl: &DeclarationInfo<'_>,
+        _problems: &mut Vec<LintProblem>,
+    ) {
+    }
+
+    /// Check an expression, before any of the expressions in it
+    fn check_expr(
+        &self,
+        _cx: &LintContext<'_>,
+        _expr: &Expr,
+        _region: Region,
+        _problems: &mut Vec<LintProblem>,
+    ) {
+    }
+}
+
+pub struct LintContext<'a> {
+    pub home: ModuleId,
+    pub interns: &'a Interns,
+    pub src: &'a str,
+    /// Whether we're in an `expect`, which only runs in tests
+    pub in_expect: bool,
+}
+
+impl<'a> LintContext<'a> {
+    /// The source code in this region
+    pub fn src_at(&self, region: Region) -> &'a str {
+        &self.src[region.start().offset as usize..region.end().offset as usize]
+    }
+}
+
+/// Run these lints on a module, returning the problems they found
+pub fn lint_module(
+    home: ModuleId,
+    decls: &Declarations,
+    src: &str,
+    interns: &Interns,
+    lints: &[&dyn Lint],
+) -> Vec<LintProblem> {
+    let mut visitor = LintVisitor {
+        cx: LintContext {
+            home,
+            interns,
+            src,
+            in_expect: false,
+        },
+        lints,
+        problems: Vec::new(),
+    };
+
+    visitor.visit_decls(decls);
+
+    visitor.problems
+}
+
+struct LintVisitor<'a> {
+    cx: LintContext<'a>,
+    lints: &'a [&'a dyn Lint],
+    problems: Vec<LintProblem>,
+}
+
+impl Visitor for LintVisitor<'_> {
+    fn visit_decl(&mut self, decl: DeclarationInfo<'_>) {
+        for lint in self.lints {
+            lint.check_decl(&self.cx, &decl, &mut self.problems);
+        }
+
+        self.cx.in_expect = matches!(decl, DeclarationInfo::Expectation { .. });
+        walk_decl(self, decl);
+        self.cx.in_expect = false;
+    }
+
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        for lint in self.lints {
+            lint.check_expr(&self.cx, expr, region, &mut self.problems);
+        }
+
+        match expr {
+            Expr::Expect {
+                loc_condition,
+                loc_continuation,
+                lookups_in_cond: _,
+            } => {
+                let in_expect = std::mem::replace(&mut self.cx.in_expect, true);
+                self.visit_expr(&loc_condition.value, loc_condition.region, Variable::BOOL);
+                self.cx.in_expect = in_expect;
+
+                self.visit_expr(
+                    &loc_continuation.value,
+                    loc_continuation.region,
+                    Variable::NULL,
+                );
+            }
+            _ => walk_expr(self, expr, var),
+        }
+    }
+}
+
+/// Apply fixes to a module's source code. Fixes that overlap one that starts earlier are
+/// skipped; running `roc lint --fix` again will apply them if they're still needed.
+pub fn apply_fixes<'a>(src: &str, fixes: impl IntoIterator<Item = &'a Fix>) -> String {
+    let mut fixes: Vec<&Fix> = fixes.into_iter().collect();
+    fixes.sort_by_key(|fix| (fix.region.start(), fix.region.end()));
+
+    let mut fixed = String::with_capacity(src.len());
+    let mut progress = 0;
+
+    for fix in fixes {
+        let start = fix.region.start().offset as usize;
+
+        if start < progress {
+            continue;
+        }
+
+        fixed.push_str(&src[progress..start]);
+        fixed.push_str(&fix.replacement);
+        progress = fix.region.end().offset as usize;
+    }
+
+    fixed.push_str(&src[progress..]);
+
+    fixed
+}
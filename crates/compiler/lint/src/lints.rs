@@ -0,0 +1,426 @@
+use roc_can::expr::{Expr, WhenBranch};
+use roc_can::pattern::{DestructType, Pattern, RecordDestruct};
+use roc_can::traverse::{
+    symbols_introduced_from_pattern, walk_decl, walk_pattern, walk_record_destruct,
+    DeclarationInfo, Visitor,
+};
+use roc_module::symbol::Symbol;
+use roc_problem::lint::{Fix, LintProblem};
+use roc_region::all::{Loc, Position, Region};
+use roc_types::subs::Variable;
+
+use crate::{Lint, LintContext};
+
+/// A `when` branch whose body is the same as the branch right before it
+pub struct RedundantWhenBranch;
+
+impl Lint for RedundantWhenBranch {
+    fn check_expr(
+        &self,
+        cx: &LintContext<'_>,
+        expr: &Expr,
+        _region: Region,
+        problems: &mut Vec<LintProblem>,
+    ) {
+        let Expr::When { branches, .. } = expr else {
+            return;
+        };
+
+        for pair in branches.windows(2) {
+            let [previous, branch] = pair else {
+                continue;
+            };
+
+            let (Some(previous_region), Some(region)) =
+                (branch_region(previous), branch_region(branch))
+            else {
+                continue;
+            };
+
+            if previous.guard.is_some()
+                || branch.guard.is_some()
+                || cx.src_at(previous.value.region).trim() != cx.src_at(branch.value.region).trim()
+            {
+                continue;
+            }
+
+            // When the patterns bind names, the bodies could refer to different values with the
+            // same name, so combining the patterns might not be what was meant.
+            let binds_names = previous
+                .patterns
+                .iter()
+                .chain(&branch.patterns)
+                .any(|pattern| {
+                    symbols_introduced_from_pattern(&pattern.pattern)
+                        .next()
+                        .is_some()
+                });
+
+            let fix = (!binds_names).then(|| {
+                let patterns: Vec<&str> = previous
+                    .patterns
+                    .iter()
+                    .chain(&branch.patterns)
+                    .map(|pattern| cx.src_at(pattern.pattern.region))
+                    .collect();
+
+                Fix {
+                    region: Region::span_across(
+                        &previous.patterns[0].pattern.region,
+                        &branch.patterns[branch.patterns.len() - 1].pattern.region,
+                    ),
+                    replacement: patterns.join(" | "),
+                }
+            });
+
+            problems.push(LintProblem::RedundantWhenBranch {
+                region,
+                previous_branch: previous_region,
+                fix,
+            });
+        }
+    }
+}
+
+/// From a branch's first pattern to the end of its body, if it was written out in the source
+fn branch_region(branch: &WhenBranch) -> Option<Region> {
+    let first_pattern = branch.patterns.first()?;
+
+    if first_pattern.pattern.region.is_empty() || branch.value.region.is_empty() {
+        return None;
+    }
+
+    Some(Region::span_across(
+        &first_pattern.pattern.region,
+        &branch.value.region,
+    ))
+}
+
+/// A `when List.get ... is` with a branch that crashes
+pub struct CrashOnListGet;
+
+impl Lint for CrashOnListGet {
+    fn check_expr(
+        &self,
+        _cx: &LintContext<'_>,
+        expr: &Expr,
+        region: Region,
+        problems: &mut Vec<LintProblem>,
+    ) {
+        let Expr::When {
+            loc_cond, branches, ..
+        } = expr
+        else {
+            return;
+        };
+
+        let Expr::Call(function, _, _) = &loc_cond.value else {
+            return;
+        };
+
+        if !matches!(function.1.value, Expr::Var(Symbol::LIST_GET, _)) {
+            return;
+        }
+
+        if let Some(branch) = branches
+            .iter()
+            .find(|branch| matches!(branch.value.value, Expr::Crash { .. }))
+        {
+            problems.push(LintProblem::CrashOnListGet {
+                region,
+                crash: branch.value.region,
+            });
+        }
+    }
+}
+
+/// Names bound in the same declaration that only differ by underscores or a number at the end
+pub struct ConfusableNames;
+
+impl Lint for ConfusableNames {
+    fn check_decl(
+        &self,
+        cx: &LintContext<'_>,
+        decl: &DeclarationInfo<'_>,
+        problems: &mut Vec<LintProblem>,
+    ) {
+        let mut collector = NameCollector { names: Vec::new() };
+        walk_decl(&mut collector, decl.clone());
+
+        let mut names: Vec<(&str, Loc<Symbol>)> = Vec::with_capacity(collector.names.len());
+
+        for loc_symbol in collector.names {
+            let name = loc_symbol.value.as_str(cx.interns);
+
+            // Skip names the compiler made up, and ones we've already seen
+            if name.starts_with(|c: char| c.is_ascii_lowercase())
+                && names.iter().all(|(seen, _)| *seen != name)
+            {
+                names.push((name, loc_symbol));
+            }
+        }
+
+        names.sort_by_key(|(_, loc_symbol)| loc_symbol.region);
+
+        for (index, (name, confusable)) in names.iter().enumerate() {
+            if let Some((_, original)) = names[..index]
+                .iter()
+                .find(|(other, _)| are_confusable(other, name))
+            {
+                problems.push(LintProblem::ConfusableNames {
+                    original: *original,
+                    confusable: *confusable,
+                });
+            }
+        }
+    }
+}
+
+/// Whether two different names only differ by underscores or a number at the end, like `user`
+/// and `user2`. Numbered names like `x1` and `x2` are usually numbered on purpose, though.
+fn are_confusable(a: &str, b: &str) -> bool {
+    fn split_number(name: &str) -> (&str, &str) {
+        let without_number = name.trim_end_matches(|c: char| c.is_ascii_digit());
+
+        (without_number, &name[without_number.len()..])
+    }
+
+    let (a_name, a_number) = split_number(a);
+    let (b_name, b_number) = split_number(b);
+
+    if !a_number.is_empty() && !b_number.is_empty() && a_number != b_number {
+        return false;
+    }
+
+    a != b && a_name.replace('_', "") == b_name.replace('_', "")
+}
+
+struct NameCollector {
+    names: Vec<Loc<Symbol>>,
+}
+
+impl Visitor for NameCollector {
+    fn visit_pattern(&mut self, pattern: &Pattern, region: Region, _opt_var: Option<Variable>) {
+        match pattern {
+            Pattern::Identifier(symbol) | Pattern::Shadowed(_, _, symbol) => {
+                self.names.push(Loc::at(region, *symbol));
+            }
+            _ => walk_pattern(self, pattern),
+        }
+    }
+
+    fn visit_record_destruct(&mut self, destruct: &RecordDestruct, region: Region) {
+        // A field with a pattern, like `{ name: { first } }`, doesn't bind the field's name
+        if !matches!(destruct.typ, DestructType::Guard(..)) {
+            self.names.push(Loc::at(region, destruct.symbol));
+        }
+
+        walk_record_destruct(self, destruct);
+    }
+}
+
+/// A `when` that unwraps an `Ok` and returns an `Err`, which is what `?` does
+pub struct ManualTry;
+
+impl Lint for ManualTry {
+    fn check_expr(
+        &self,
+        cx: &LintContext<'_>,
+        expr: &Expr,
+        region: Region,
+        problems: &mut Vec<LintProblem>,
+    ) {
+        let Expr::When {
+            loc_cond, branches, ..
+        } = expr
+        else {
+            return;
+        };
+
+        let [first, second] = branches.as_slice() else {
+            return;
+        };
+
+        let is_manual_try = (unwraps_ok(first) && returns_err(second))
+            || (returns_err(first) && unwraps_ok(second));
+
+        if !is_manual_try || loc_cond.region.is_empty() {
+            return;
+        }
+
+        let cond = cx.src_at(loc_cond.region).trim();
+        let replacement = if has_top_level_whitespace(cond) {
+            format!("({cond})?")
+        } else {
+            format!("{cond}?")
+        };
+
+        problems.push(LintProblem::ManualTry {
+            region,
+            fix: Fix {
+                region,
+                replacement,
+            },
+        });
+    }
+}
+
+/// The tag a branch matches and the name it binds its one payload to, like `Ok(x)`
+fn single_tag_pattern(branch: &WhenBranch) -> Option<(&str, Symbol)> {
+    let [pattern] = branch.patterns.as_slice() else {
+        return None;
+    };
+
+    if branch.guard.is_some() {
+        return None;
+    }
+
+    match &pattern.pattern.value {
+        Pattern::AppliedTag {
+            tag_name,
+            arguments,
+            ..
+        } => match arguments.as_slice() {
+            [(
+                _,
+                Loc {
+                    value: Pattern::Identifier(symbol),
+                    ..
+                },
+            )] => Some((tag_name.0.as_str(), *symbol)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `Ok(x) -> x`
+fn unwraps_ok(branch: &WhenBranch) -> bool {
+    match single_tag_pattern(branch) {
+        Some(("Ok", symbol)) => matches!(branch.value.value, Expr::Var(var, _) if var == symbol),
+        _ => false,
+    }
+}
+
+/// `Err(e) -> return Err(e)`
+fn returns_err(branch: &WhenBranch) -> bool {
+    let Some(("Err", symbol)) = single_tag_pattern(branch) else {
+        return false;
+    };
+
+    let Expr::Return { return_value, .. } = &branch.value.value else {
+        return false;
+    };
+
+    match &return_value.value {
+        Expr::Tag {
+            name, arguments, ..
+        } if name.0.as_str() == "Err" => matches!(
+            arguments.as_slice(),
+            [(_, Loc { value: Expr::Var(var, _), .. })] if *var == symbol
+        ),
+        _ => false,
+    }
+}
+
+/// Whether `?` needs parentheses to apply to all of this expression, like `(List.get list 0)?`
+fn has_top_level_whitespace(src: &str) -> bool {
+    let mut depth = 0usize;
+    let mut in_string = false;
+
+    for c in src.chars() {
+        match c {
+            '"' => in_string = !in_string,
+            '(' | '[' | '{' if !in_string => depth += 1,
+            ')' | ']' | '}' if !in_string => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 && !in_string => return true,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// A `dbg` outside of an `expect`
+pub struct LeftoverDbg;
+
+impl Lint for LeftoverDbg {
+    fn check_expr(
+        &self,
+        cx: &LintContext<'_>,
+        expr: &Expr,
+        _region: Region,
+        problems: &mut Vec<LintProblem>,
+    ) {
+        let Expr::Dbg {
+            loc_message,
+            loc_continuation,
+            ..
+        } = expr
+        else {
+            return;
+        };
+
+        if cx.in_expect {
+            return;
+        }
+
+        // The message is the value being printed, so look for the `dbg` before it, like in
+        // `dbg x` or `dbg(x)`. It isn't there when it's used in a pipeline, like `x |> dbg`.
+        let value_start = loc_message.region.start().offset as usize;
+        let value_end = loc_message.region.end().offset as usize;
+        let before = cx.src[..value_start].trim_end();
+        let parenthesized = before.ends_with('(');
+        let before = before.strip_suffix('(').unwrap_or(before).trim_end();
+
+        let Some(dbg_start) = before
+            .strip_suffix("dbg")
+            .map(|before_dbg| before_dbg.len())
+        else {
+            problems.push(LintProblem::LeftoverDbg {
+                region: loc_message.region,
+                fix: None,
+            });
+
+            return;
+        };
+
+        let after = &cx.src[value_end..];
+        let dbg_end = match after.trim_start().strip_prefix(')') {
+            Some(rest) if parenthesized => cx.src.len() - rest.len(),
+            _ => value_end,
+        };
+
+        let region = Region::new(
+            Position::new(dbg_start as u32),
+            Position::new(dbg_end as u32),
+        );
+
+        // `dbg(x)` as an expression evaluates to `x`, so it's continued by `x` itself
+        let fix = if loc_continuation.region == loc_message.region {
+            Some(Fix {
+                region,
+                replacement: cx.src_at(loc_message.region).to_string(),
+            })
+        } else {
+            // Remove a `dbg` statement along with its line, if it has one to itself
+            let line_start = cx.src[..dbg_start].rfind('\n').map_or(0, |i| i + 1);
+            let line_end = cx.src[dbg_end..]
+                .find('\n')
+                .map_or(cx.src.len(), |i| dbg_end + i + 1);
+
+            let own_line = cx.src[line_start..dbg_start].trim().is_empty()
+                && cx.src[dbg_end..line_end].trim().is_empty();
+
+            own_line.then(|| Fix {
+                region: Region::new(
+                    Position::new(line_start as u32),
+                    Position::new(line_end as u32),
+                ),
+                replacement: String::new(),
+            })
+        };
+
+        problems.push(LintProblem::LeftoverDbg { region, fix });
+    }
+}
@@ -25,6 +25,8 @@ const MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::DECODE, "Decode.roc"),
     (ModuleId::HASH, "Hash.roc"),
     (ModuleId::INSPECT, "Inspect.roc"),
+    (ModuleId::SORT, "Sort.roc"),
+    (ModuleId::DEFAULT, "Default.roc"),
 ];
 
 fn main() {
@@ -100,6 +102,8 @@ fn write_types_for_module_real(module_id: ModuleId, filename: &str, output_path:
         &module.interns,
         &mut module.can_problems,
         &mut module.type_problems,
+        roc_reporting::report::RenderTarget::ColorTerminal,
+        &Default::default(),
     );
 
     if problems.errors + problems.warnings > 0 {
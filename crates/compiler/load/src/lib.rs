@@ -4,6 +4,7 @@ use bumpalo::Bump;
 use roc_can::module::{ExposedByModule, TypeState};
 use roc_collections::all::MutMap;
 use roc_module::symbol::ModuleId;
+use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::report::{Palette, RenderTarget};
 use roc_target::Target;
@@ -57,6 +58,7 @@ pub fn load_single_threaded<'a>(
     palette: Palette,
     roc_cache_dir: RocCacheDir<'_>,
     exec_mode: ExecutionMode,
+    opt_level: Option<OptLevel>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let cached_subs = read_cached_types();
     let exposed_types = ExposedByModule::default();
@@ -71,6 +73,7 @@ pub fn load_single_threaded<'a>(
         render,
         palette,
         exec_mode,
+        opt_level,
         roc_cache_dir,
     )
 }
@@ -208,6 +211,7 @@ pub fn load_and_typecheck_str<'a>(
         palette,
         roc_cache_dir,
         ExecutionMode::Check,
+        None,
     )? {
         Monomorphized(_) => unreachable!(""),
         TypeChecked(module) => Ok(module),
@@ -256,6 +260,8 @@ fn read_cached_types() -> MutMap<ModuleId, TypeState> {
     let mod_decode = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Decode.dat"));
     let mod_hash = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Hash.dat"));
     let mod_inspect = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Inspect.dat"));
+    let mod_sort = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Sort.dat"));
+    let mod_default = include_bytes_align_as!(u128, concat!(env!("OUT_DIR"), "/Default.dat"));
 
     let mut output = MutMap::default();
 
@@ -279,6 +285,8 @@ fn read_cached_types() -> MutMap<ModuleId, TypeState> {
 
         output.insert(ModuleId::HASH, deserialize_help(mod_hash));
         output.insert(ModuleId::INSPECT, deserialize_help(mod_inspect));
+        output.insert(ModuleId::SORT, deserialize_help(mod_sort));
+        output.insert(ModuleId::DEFAULT, deserialize_help(mod_default));
     }
 
     output
@@ -25,7 +25,7 @@ mod test_reporting {
         can_problem, parse_problem, type_problem, RenderTarget, Report, ANSI_STYLE_CODES,
         DEFAULT_PALETTE,
     };
-    use roc_reporting::report::{RocDocAllocator, RocDocBuilder};
+    use roc_reporting::report::{lint_problem, RocDocAllocator, RocDocBuilder};
     use roc_solve::FunctionKind;
     use roc_solve_problem::TypeError;
     use roc_test_utils_dir::TmpDir;
@@ -42,6 +42,7 @@ mod test_reporting {
     fn to_simple_report(doc: RocDocBuilder) -> Report {
         Report {
             title: "".to_string(),
+            code: None,
             doc,
             filename: filename_from_string(r"/code/proj/Main.roc"),
             severity: Severity::RuntimeError,
@@ -130,6 +131,7 @@ mod test_reporting {
                 threading: Threading::Single,
                 exec_mode: ExecutionMode::Check,
                 function_kind: FunctionKind::LambdaSet,
+                opt_level: None,
             };
             let result = roc_load::load_and_typecheck(
                 arena,
@@ -480,7 +482,7 @@ mod test_reporting {
         "
         ),
         @r"
-    ── NOT EXPOSED in /code/proj/Main.roc ──────────────────────────────────────────
+    ── NOT EXPOSED [E0218] in /code/proj/Main.roc ──────────────────────────────────
 
     The List module does not expose `isempty`:
 
@@ -507,7 +509,7 @@ mod test_reporting {
         "
         ),
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `y` is not used anywhere in your code.
 
@@ -532,7 +534,7 @@ mod test_reporting {
        "
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [E0217] in /code/proj/Main.roc ───────────────────────────────
 
     The `i` name is first defined here:
 
@@ -564,7 +566,7 @@ mod test_reporting {
        "
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [E0217] in /code/proj/Main.roc ───────────────────────────────
 
     The `Booly` name is first defined here:
 
@@ -596,7 +598,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     Using != and == together requires parentheses, to clarify how they
     should be grouped.
@@ -625,7 +627,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-        ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+        ── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────
 
         Nothing is named `bar` in this scope.
 
@@ -649,7 +651,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `true` in this scope.
 
@@ -658,9 +660,9 @@ mod test_reporting {
 
     Did you mean one of these?
 
-        Str
         Frac
         Num
+        Str
         U8
     "###
     );
@@ -681,7 +683,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     Using more than one == like this requires parentheses, to clarify how
     things should be grouped.
@@ -707,7 +709,7 @@ mod test_reporting {
          "#
         ),
         @r#"
-    ── UNUSED ARGUMENT in /code/proj/Main.roc ──────────────────────────────────────
+    ── UNUSED ARGUMENT [E0211] in /code/proj/Main.roc ──────────────────────────────
 
     `box` doesn't use `html_children`.
 
@@ -720,7 +722,7 @@ mod test_reporting {
     at the start of a variable name is a way of saying that the variable
     is not used.
 
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `y` is not used anywhere in your code.
 
@@ -807,7 +809,7 @@ mod test_reporting {
             ),
             indoc!(
                 r"
-                <cyan>── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────<reset>
+                <cyan>── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────<reset>
 
                 Nothing is named `the_admin` in this scope.
 
@@ -825,6 +827,470 @@ mod test_reporting {
         );
     }
 
+    fn structured_reports(
+        src: &str,
+        to_value: impl Fn(Report<'_>) -> serde_json::Value,
+    ) -> Vec<serde_json::Value> {
+        let arena = Bump::new();
+        let (type_problems, can_problems, home, interns) =
+            infer_expr_help(&arena, src).expect("parse error");
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let lines = LineInfo::new(src);
+        let filename = filename_from_string(r"/code/proj/Main.roc");
+        let alloc = RocDocAllocator::new(&src_lines, home, &interns);
+
+        let can_reports = can_problems
+            .into_iter()
+            .map(|problem| can_problem(&alloc, &lines, filename.clone(), problem));
+        let type_reports = type_problems
+            .into_iter()
+            .filter_map(|problem| type_problem(&alloc, &lines, filename.clone(), problem));
+
+        can_reports.chain(type_reports).map(to_value).collect()
+    }
+
+    const TYPO_SRC: &str = indoc!(
+        r#"
+            user_name = "Ann"
+
+            Str.concat user_nmae "!"
+        "#
+    );
+
+    #[test]
+    fn report_as_json() {
+        let reports = structured_reports(TYPO_SRC, |report| report.to_json());
+        let report = reports
+            .iter()
+            .find(|report| report["title"] == "UNRECOGNIZED NAME")
+            .unwrap();
+        let region = serde_json::json!({
+            "start": { "line": 3, "column": 12 },
+            "end": { "line": 3, "column": 21 },
+        });
+
+        assert_eq!(report["code"], "E0202");
+        assert_eq!(report["severity"], "error");
+        assert_eq!(report["file"], "/code/proj/Main.roc");
+        assert_eq!(report["region"], region);
+        assert_eq!(report["secondary_regions"], serde_json::json!([]));
+        assert!(report["message"].as_str().unwrap().starts_with(
+            "Nothing is named `user_nmae` in this scope.\n\n3│  Str.concat user_nmae"
+        ));
+        assert_eq!(
+            report["suggestions"][0],
+            serde_json::json!({ "region": region, "replacement": "user_name" })
+        );
+    }
+
+    #[test]
+    fn report_as_sarif() {
+        let log = Report::sarif_log(structured_reports(TYPO_SRC, |report| {
+            report.to_sarif_result()
+        }));
+        let result = log["runs"][0]["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|result| result["ruleId"] == "E0202")
+            .unwrap();
+        let region = serde_json::json!({
+            "startLine": 3,
+            "startColumn": 12,
+            "endLine": 3,
+            "endColumn": 21,
+        });
+
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(result["level"], "error");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"],
+            serde_json::json!({
+                "artifactLocation": { "uri": "/code/proj/Main.roc" },
+                "region": region,
+            })
+        );
+        assert_eq!(
+            result["fixes"][0]["artifactChanges"][0]["replacements"][0],
+            serde_json::json!({
+                "deletedRegion": region,
+                "insertedContent": { "text": "user_name" },
+            })
+        );
+    }
+
+    fn warning_counts(
+        subdir: &str,
+        src: &str,
+        warning_levels: &roc_reporting::cli::WarningLevels,
+    ) -> (usize, usize) {
+        let arena = Bump::new();
+        let (_, result) = run_load_and_infer(subdir, &arena, src);
+        let mut loaded = result.expect("load failed");
+
+        let problems = roc_reporting::cli::report_problems(
+            &loaded.sources,
+            &loaded.interns,
+            &mut loaded.can_problems,
+            &mut loaded.type_problems,
+            RenderTarget::Json,
+            warning_levels,
+        );
+
+        (problems.errors, problems.warnings)
+    }
+
+    const UNUSED_DEFS_SRC: &str = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            # roc:allow unused-definition
+            x =
+                1
+
+            y = 2
+
+            3
+        "#
+    );
+
+    #[test]
+    fn warning_levels() {
+        use roc_reporting::cli::{warning_kind, WarningLevels};
+
+        assert_eq!(warning_kind("unused-definition"), Some("E0203"));
+        assert_eq!(warning_kind("e0203"), Some("E0203"));
+        assert_eq!(warning_kind("unused definitions"), None);
+
+        let default = WarningLevels::default();
+        let deny = WarningLevels {
+            deny: vec!["E0203"],
+            ..Default::default()
+        };
+        let allow = WarningLevels {
+            allow: vec!["E0203"],
+            ..Default::default()
+        };
+        let warnings_as_errors = WarningLevels {
+            warnings_as_errors: true,
+            ..Default::default()
+        };
+
+        // `x` is allowed in the source, so only `y` is reported
+        assert_eq!(
+            warning_counts("warning_levels_default", UNUSED_DEFS_SRC, &default),
+            (0, 1)
+        );
+        assert_eq!(
+            warning_counts("warning_levels_deny", UNUSED_DEFS_SRC, &deny),
+            (1, 0)
+        );
+        assert_eq!(
+            warning_counts("warning_levels_allow", UNUSED_DEFS_SRC, &allow),
+            (0, 0)
+        );
+        assert_eq!(
+            warning_counts(
+                "warning_levels_as_errors",
+                UNUSED_DEFS_SRC,
+                &warnings_as_errors
+            ),
+            (1, 0)
+        );
+    }
+
+    /// Load a module and run every lint on it
+    fn lint_problems<'a>(
+        subdir: &str,
+        arena: &'a Bump,
+        src: &'a str,
+    ) -> (String, LoadedModule, Vec<roc_problem::lint::LintProblem>) {
+        let (module_src, result) = run_load_and_infer(subdir, arena, src);
+        let loaded = result.expect("load failed");
+        let home = loaded.module_id;
+
+        let decls = match loaded.declarations_by_id.get(&home) {
+            Some(decls) => decls,
+            None => &loaded.typechecked[&home].decls,
+        };
+
+        let problems = roc_lint::lint_module(
+            home,
+            decls,
+            &module_src,
+            &loaded.interns,
+            roc_lint::ALL_LINTS,
+        );
+
+        (module_src, loaded, problems)
+    }
+
+    fn __lint_report_as(test_name: &str, src: &str, check_render: impl FnOnce(&str)) {
+        use ven_pretty::DocAllocator;
+
+        let arena = Bump::new();
+        let (module_src, loaded, problems) = lint_problems(test_name, &arena, src);
+
+        let filename = filename_from_string(r"/code/proj/Main.roc");
+        let lines = LineInfo::new(&module_src);
+        let src_lines: Vec<&str> = module_src.split('\n').collect();
+        let alloc = RocDocAllocator::new(&src_lines, loaded.module_id, &loaded.interns);
+
+        let reports: Vec<_> = problems
+            .into_iter()
+            .map(|problem| lint_problem(&alloc, &lines, filename.clone(), problem))
+            .collect();
+        let has_reports = !reports.is_empty();
+
+        let doc = alloc
+            .stack(reports.into_iter().map(|v| v.pretty(&alloc)))
+            .append(if has_reports {
+                alloc.line()
+            } else {
+                alloc.nil()
+            });
+
+        let mut buf = String::new();
+        doc.1
+            .render_raw(70, &mut roc_reporting::report::CiWrite::new(&mut buf))
+            .expect("list_reports");
+
+        check_render(buf.as_str());
+    }
+
+    macro_rules! test_lint {
+        ($(#[$meta:meta])* $test_name:ident, $program:expr, @$output:literal) => {
+            #[test]
+            $(#[$meta])*
+            fn $test_name() {
+                __lint_report_as(std::stringify!($test_name), $program, |golden| insta::assert_snapshot!(golden, @$output))
+            }
+        };
+    }
+
+    /// The module's source after applying the fixes for every lint problem in it
+    fn lint_fixes(test_name: &str, src: &str) -> String {
+        let arena = Bump::new();
+        let (module_src, _, problems) = lint_problems(test_name, &arena, src);
+
+        roc_lint::apply_fixes(
+            &module_src,
+            problems
+                .iter()
+                .filter_map(roc_problem::lint::LintProblem::fix),
+        )
+    }
+
+    test_lint!(
+        lint_redundant_when_branch,
+        indoc!(
+            r#"
+            color = Red
+
+            when color is
+                Red -> "warm"
+                Orange -> "warm"
+                Blue -> "cool"
+            "#
+        ),
+        @r#"
+    ── REDUNDANT WHEN BRANCH [E0501] in /code/proj/Main.roc ────────────────────────
+
+    This branch does the same thing as the one before it:
+
+    7│          Red -> "warm"
+    8│          Orange -> "warm"
+                ^^^^^^^^^^^^^^^^
+
+    Their patterns can be combined with `|` instead:
+
+        Red | Orange
+    "#
+    );
+
+    test_lint!(
+        lint_crash_on_list_get,
+        indoc!(
+            r#"
+            when List.get([1, 2, 3], 0) is
+                Ok(first) -> first
+                Err(OutOfBounds) -> crash "empty list"
+            "#
+        ),
+        @r#"
+    ── CRASH ON LIST GET [E0502] in /code/proj/Main.roc ────────────────────────────
+
+    This `when` crashes if the index is out of bounds:
+
+    4│      when List.get([1, 2, 3], 0) is
+    5│          Ok(first) -> first
+    6│          Err(OutOfBounds) -> crash "empty list"
+                                    ^^^^^^^^^^^^^^^^^^
+
+    Crashing ends the whole program. Consider handling the `OutOfBounds`
+    error instead, by returning it with `?` or by using
+    `Result.with_default` to fall back to a default element.
+    "#
+    );
+
+    test_lint!(
+        lint_confusable_names,
+        indoc!(
+            r#"
+            user_name = "Sam"
+            username = "sam"
+            x1 = 1
+            x2 = 2
+
+            Str.concat(user_name, username) |> Str.count_utf8_bytes |> Num.add(x1 + x2)
+            "#
+        ),
+        @r#"
+    ── CONFUSABLE NAMES [E0503] in /code/proj/Main.roc ─────────────────────────────
+
+    The name `username` is easy to confuse with `user_name`, which is defined
+    nearby:
+
+    4│      user_name = "Sam"
+            ^^^^^^^^^
+
+    And here it is:
+
+    5│      username = "sam"
+            ^^^^^^^^
+
+    Names that only differ by underscores or a number at the end are easy
+    to mix up. Consider a name that says how the two values are different.
+    "#
+    );
+
+    test_lint!(
+        lint_manual_try,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            parse : Str -> Result U64 [InvalidNumStr]
+            parse = |str|
+                n =
+                    when Str.to_u64(str) is
+                        Ok(num) -> num
+                        Err(e) ->
+                            return Err(e)
+
+                Ok(n + 1)
+
+            main = parse("1")
+            "#
+        ),
+        @r#"
+    ── MANUAL TRY [E0504] in /code/proj/Main.roc ───────────────────────────────────
+
+    This `when` unwraps an `Ok` and returns an `Err` early, which is what
+    `?` does:
+
+    6│>          when Str.to_u64(str) is
+    7│>              Ok(num) -> num
+    8│>              Err(e) ->
+    9│>                  return Err(e)
+
+    It can be written like this instead:
+
+        Str.to_u64(str)?
+    "#
+    );
+
+    test_lint!(
+        lint_leftover_dbg,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                x = 1
+                dbg x
+
+                dbg(x) + 1
+
+            expect
+                dbg main
+                main == 2
+            "#
+        ),
+        @r#"
+    ── LEFTOVER DBG [E0505] in /code/proj/Main.roc ─────────────────────────────────
+
+    This `dbg` is outside of an `expect`:
+
+    5│      dbg x
+            ^^^^^
+
+    If you're done debugging, remove it.
+
+    ── LEFTOVER DBG [E0505] in /code/proj/Main.roc ─────────────────────────────────
+
+    This `dbg` is outside of an `expect`:
+
+    7│      dbg(x) + 1
+            ^^^^^^
+
+    If you're done debugging, replace it with the value itself:
+
+        x
+    "#
+    );
+
+    #[test]
+    fn lint_fixes_are_applied() {
+        let fixed = lint_fixes(
+            "lint_fixes_are_applied",
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                parse : Str -> Result U64 [InvalidNumStr]
+                parse = |str|
+                    n =
+                        when Str.to_u64(str) is
+                            Ok(num) -> num
+                            Err(e) ->
+                                return Err(e)
+
+                    dbg n
+
+                    when n is
+                        0 -> Ok(dbg(n))
+                        1 -> Ok(dbg(n))
+                        _ -> Ok(n)
+
+                main = parse("1")
+                "#
+            ),
+        );
+
+        assert_eq!(
+            fixed,
+            indoc!(
+                r#"
+                app "test" provides [main] to "./platform"
+
+                parse : Str -> Result U64 [InvalidNumStr]
+                parse = |str|
+                    n =
+                        Str.to_u64(str)?
+
+
+                    when n is
+                        0 | 1 -> Ok(n)
+                        _ -> Ok(n)
+
+                main = parse("1")
+                "#
+            )
+        );
+    }
+
     test_report!(
         if_condition_not_bool,
         indoc!(
@@ -833,7 +1299,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` condition needs to be a Bool:
 
@@ -859,7 +1325,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` guard condition needs to be a Bool:
 
@@ -884,7 +1350,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -911,7 +1377,7 @@ mod test_reporting {
              "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 3rd branch of this `if` does not match all the previous branches:
 
@@ -941,7 +1407,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 2nd branch of this `when` does not match all the previous branches:
 
@@ -979,7 +1445,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -1023,7 +1489,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This list contains elements with different types:
 
@@ -1050,7 +1516,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This list contains elements with different types:
 
@@ -1083,7 +1549,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     I cannot update the `.foo` field like this:
 
@@ -1113,7 +1579,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1138,7 +1604,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1164,7 +1630,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1177,7 +1643,7 @@ mod test_reporting {
 
         List ∞ -> *
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1204,7 +1670,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This returns something that's incompatible with the return type of the
     enclosing function:
@@ -1241,7 +1707,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This returns something that's incompatible with the return type of the
     enclosing function:
@@ -1276,7 +1742,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1302,7 +1768,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1340,7 +1806,7 @@ mod test_reporting {
         // against that extra variable, rather than possibly having to translate a `Type`
         // again.
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1353,7 +1819,7 @@ mod test_reporting {
 
         List ∞ -> List *
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1380,7 +1846,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1393,7 +1859,7 @@ mod test_reporting {
 
         List ∞ -> List *
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1421,7 +1887,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1455,7 +1921,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1492,7 +1958,7 @@ mod test_reporting {
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1529,7 +1995,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `then` branch of this `if` expression:
 
@@ -1563,7 +2029,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -1596,7 +2062,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -1628,7 +2094,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [E0315] in /code/proj/Main.roc ────────────────────────────────
 
     The `x` value is not a function, but it was given 1 argument:
 
@@ -1650,7 +2116,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [E0315] in /code/proj/Main.roc ────────────────────────────────
 
     The `f` function expects 1 argument, but it got 2 instead:
 
@@ -1672,7 +2138,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO FEW ARGS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── TOO FEW ARGS [E0316] in /code/proj/Main.roc ─────────────────────────────────
 
     The `f` function expects 2 arguments, but it got only 1:
 
@@ -1693,7 +2159,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -1722,7 +2188,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 2nd pattern in this `when` does not match the previous ones:
 
@@ -1748,7 +2214,7 @@ mod test_reporting {
              "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -1776,7 +2242,7 @@ mod test_reporting {
              "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -1805,7 +2271,7 @@ mod test_reporting {
              "
         ),
         @r"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `foo` in this scope.
 
@@ -1861,7 +2327,7 @@ mod test_reporting {
         ),
         // Just putting this here. We should probably handle or-patterns better
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 2nd pattern in this branch does not match the previous ones:
 
@@ -1889,7 +2355,7 @@ mod test_reporting {
         ),
         // Maybe this should specifically say the pattern doesn't work?
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -1917,7 +2383,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of this definition:
 
@@ -1948,7 +2414,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This integer pattern is malformed:
 
@@ -1969,7 +2435,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This float pattern is malformed:
 
@@ -1990,7 +2456,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This hex integer pattern is malformed:
 
@@ -2011,7 +2477,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This octal integer pattern is malformed:
 
@@ -2032,7 +2498,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This binary integer pattern is malformed:
 
@@ -2054,7 +2520,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -2104,7 +2570,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -2138,7 +2604,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2173,7 +2639,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2209,7 +2675,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `ok` in this scope.
 
@@ -2240,7 +2706,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `ok` is not used anywhere in your code.
 
@@ -2250,7 +2716,7 @@ mod test_reporting {
     If you didn't intend on using `ok` then remove it so future readers of
     your code don't wonder why it is there.
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2282,7 +2748,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+    ── CIRCULAR DEFINITION [E0216] in /code/proj/Main.roc ──────────────────────────
 
     `f` is defined directly in terms of itself:
 
@@ -2309,7 +2775,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+    ── CIRCULAR DEFINITION [E0216] in /code/proj/Main.roc ──────────────────────────
 
     The `foo` definition is causing a very tricky infinite loop:
 
@@ -2337,7 +2803,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2359,7 +2825,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2392,7 +2858,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `r` record doesn’t have a `foo` field:
 
@@ -2420,7 +2886,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2450,7 +2916,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -2475,7 +2941,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -2503,7 +2969,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -2531,7 +2997,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2565,7 +3031,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2608,7 +3074,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This pattern does not cover all the possibilities:
 
@@ -2623,7 +3089,7 @@ mod test_reporting {
     matching in function arguments, put a `when` in the function body to
     account for all possibilities.
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2660,7 +3126,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -2691,7 +3157,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2700,7 +3166,7 @@ mod test_reporting {
 
     Other possibilities include:
 
-        _
+        0
 
     I would have to crash if I saw one of those! Add branches for them!
     "
@@ -2718,7 +3184,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2746,7 +3212,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2775,7 +3241,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2804,7 +3270,7 @@ mod test_reporting {
         ),
         // Tip: Looks like a record field guard is not exhaustive. Learn more about record pattern matches at TODO.
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2813,7 +3279,7 @@ mod test_reporting {
 
     Other possibilities include:
 
-        { a }
+        { a: 0 }
 
     I would have to crash if I saw one of those! Add branches for them!
     "
@@ -2833,7 +3299,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2843,7 +3309,7 @@ mod test_reporting {
 
     Other possibilities include:
 
-        { a: Just _ }
+        { a: Just 0, b }
 
     I would have to crash if I saw one of those! Add branches for them!
     "
@@ -2859,7 +3325,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2869,7 +3335,157 @@ mod test_reporting {
 
     Other possibilities include:
 
-        Record (Just _) _
+        Record (Just 0) _
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "
+    );
+
+    test_report!(
+        patterns_int_not_exhaustive_concrete_literal,
+        indoc!(
+            r"
+            x : U8
+            x = 3
+
+            when x is
+                0 -> 0
+                1 -> 1
+                3 -> 3
+            "
+        ),
+        @r"
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+     7│>      when x is
+     8│>          0 -> 0
+     9│>          1 -> 1
+    10│>          3 -> 3
+
+    Other possibilities include:
+
+        2
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "
+    );
+
+    test_report!(
+        patterns_str_not_exhaustive_concrete_literal,
+        indoc!(
+            r#"
+            s : Str
+            s = "a"
+
+            when s is
+                "" -> 0
+                "a" -> 1
+            "#
+        ),
+        @r#"
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+    7│>      when s is
+    8│>          "" -> 0
+    9│>          "a" -> 1
+
+    Other possibilities include:
+
+        "b"
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "#
+    );
+
+    test_report!(
+        patterns_float_not_exhaustive_concrete_literal,
+        indoc!(
+            r"
+            x : F64
+            x = 1.5
+
+            when x is
+                0.0 -> 0
+                1.5 -> 1
+            "
+        ),
+        @r"
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+    7│>      when x is
+    8│>          0.0 -> 0
+    9│>          1.5 -> 1
+
+    Other possibilities include:
+
+        1.0
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "
+    );
+
+    test_report!(
+        patterns_tuple_of_strs_not_exhaustive,
+        indoc!(
+            r#"
+            t : (Str, Str)
+            t = ("a", "b")
+
+            when t is
+                ("a", _) -> 0
+                (_, "b") -> 1
+            "#
+        ),
+        @r#"
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+    7│>      when t is
+    8│>          ("a", _) -> 0
+    9│>          (_, "b") -> 1
+
+    Other possibilities include:
+
+        ( "", "" )
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "#
+    );
+
+    test_report!(
+        patterns_nested_record_with_different_fields_not_exhaustive,
+        indoc!(
+            r"
+            x : { a : Result (Result U8 U8) U8, b : List U8 }
+            x = { a: Err 1, b: [] }
+
+            when x is
+                { a: Ok (Ok _), b: [] } -> 0
+                { a: Err _ } -> 1
+                { a: Ok (Err _), b: [_, ..] } -> 2
+            "
+        ),
+        @r"
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+     7│>      when x is
+     8│>          { a: Ok (Ok _), b: [] } -> 0
+     9│>          { a: Err _ } -> 1
+    10│>          { a: Ok (Err _), b: [_, ..] } -> 2
+
+    Other possibilities include:
+
+        { a: Ok (Err _), b: [] }
+        { a: Ok (Ok _), b: [_, ..] }
 
     I would have to crash if I saw one of those! Add branches for them!
     "
@@ -2886,7 +3502,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
 
     The 2nd pattern is redundant:
 
@@ -2914,7 +3530,7 @@ mod test_reporting {
         ),
         // de-aliases the alias to give a better error message
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `f` has an unexpected type:
 
@@ -2952,7 +3568,7 @@ mod test_reporting {
         ),
         // should not report Bar as unused!
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [E0314] in /code/proj/Main.roc ─────────────────────────────────
 
     The `Foo` alias is self-recursive in an invalid way:
 
@@ -2978,7 +3594,7 @@ mod test_reporting {
         ),
         // should not report Bar as unused!
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [E0314] in /code/proj/Main.roc ─────────────────────────────────
 
     The `Foo` alias is self-recursive in an invalid way:
 
@@ -2998,7 +3614,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [E0213] in /code/proj/Main.roc ─────────────────────────
 
     This record defines the `.x` field twice!
 
@@ -3022,7 +3638,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [E0213] in /code/proj/Main.roc ─────────────────────────
 
     This record defines the `.x` field twice!
 
@@ -3050,7 +3666,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [E0213] in /code/proj/Main.roc ─────────────────────────
 
     This record defines the `.x` field twice!
 
@@ -3085,7 +3701,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [E0213] in /code/proj/Main.roc ─────────────────────────
 
     This record defines the `.x` field twice!
 
@@ -3118,7 +3734,7 @@ mod test_reporting {
             "#
         ),
         @r"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [E0213] in /code/proj/Main.roc ─────────────────────────
 
     This record type defines the `.foo` field twice!
 
@@ -3146,7 +3762,7 @@ mod test_reporting {
             "#
         ),
         @r"
-    ── DUPLICATE TAG NAME in /code/proj/Main.roc ───────────────────────────────────
+    ── DUPLICATE TAG NAME [E0214] in /code/proj/Main.roc ───────────────────────────
 
     This tag union type defines the `Foo` tag twice!
 
@@ -3175,7 +3791,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── NAMING PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── NAMING PROBLEM [E0201] in /code/proj/Main.roc ───────────────────────────────
 
     This annotation does not match the definition immediately following
     it:
@@ -3211,7 +3827,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This definition of `MyAlias` has an unexpected pattern:
 
@@ -3220,7 +3836,7 @@ mod test_reporting {
 
     Only type variables like `a` or `value` can occur in this position.
 
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `MyAlias` is not used anywhere in your code.
 
@@ -3243,7 +3859,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This definition of `Age` has an unexpected pattern:
 
@@ -3265,7 +3881,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY TYPE ARGUMENTS in /code/proj/Main.roc ──────────────────────────────
+    ── TOO MANY TYPE ARGUMENTS [E0261] in /code/proj/Main.roc ──────────────────────
 
     The `Num` opaque expects 1 type argument, but it got 2 instead:
 
@@ -3287,7 +3903,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY TYPE ARGUMENTS in /code/proj/Main.roc ──────────────────────────────
+    ── TOO MANY TYPE ARGUMENTS [E0261] in /code/proj/Main.roc ──────────────────────
 
     The `Num` opaque expects 1 type argument, but it got 2 instead:
 
@@ -3311,7 +3927,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO FEW TYPE ARGUMENTS in /code/proj/Main.roc ───────────────────────────────
+    ── TOO FEW TYPE ARGUMENTS [E0262] in /code/proj/Main.roc ───────────────────────
 
     The `Pair` alias expects 2 type arguments, but it got 1 instead:
 
@@ -3335,7 +3951,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY TYPE ARGUMENTS in /code/proj/Main.roc ──────────────────────────────
+    ── TOO MANY TYPE ARGUMENTS [E0261] in /code/proj/Main.roc ──────────────────────
 
     The `Pair` alias expects 2 type arguments, but it got 3 instead:
 
@@ -3358,7 +3974,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── UNUSED TYPE ALIAS PARAMETER in /code/proj/Main.roc ──────────────────────────
+    ── UNUSED TYPE ALIAS PARAMETER [E0207] in /code/proj/Main.roc ──────────────────
 
     The `a` type parameter is not used in the `Foo` alias definition:
 
@@ -3380,7 +3996,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── ARGUMENTS BEFORE EQUALS in tmp/elm_function_syntax/Test.roc ─────────────────
+    ── ARGUMENTS BEFORE EQUALS [E0103] in tmp/elm_function_syntax/Test.roc ─────────
 
     I am partway through parsing a definition, but I got stuck here:
 
@@ -3408,7 +4024,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -3457,7 +4073,7 @@ mod test_reporting {
         // TODO render tag unions across multiple lines
         // TODO do not show recursion var if the recursion var does not render on the surface of a type
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -3511,7 +4127,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal is too big:
 
@@ -3523,7 +4139,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal is too small:
 
@@ -3535,7 +4151,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal is too big:
 
@@ -3547,7 +4163,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal is too small:
 
@@ -3559,7 +4175,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -3588,7 +4204,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This float literal is too big:
 
@@ -3600,7 +4216,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This float literal is too small:
 
@@ -3633,7 +4249,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal contains an invalid digit:
 
@@ -3645,7 +4261,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This hex integer literal contains an invalid digit:
 
@@ -3657,7 +4273,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This octal integer literal contains an invalid digit:
 
@@ -3669,7 +4285,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This binary integer literal contains an invalid digit:
 
@@ -3699,7 +4315,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This hex integer literal contains no digits:
 
@@ -3711,7 +4327,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This octal integer literal contains no digits:
 
@@ -3723,7 +4339,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This binary integer literal contains no digits:
 
@@ -3747,7 +4363,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This float literal contains an invalid digit:
 
@@ -3778,7 +4394,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This expression cannot be updated:
 
@@ -3787,7 +4403,7 @@ mod test_reporting {
 
     Only variables can be updated with record update syntax.
 
-    ── MODULE NOT IMPORTED in /code/proj/Main.roc ──────────────────────────────────
+    ── MODULE NOT IMPORTED [E0219] in /code/proj/Main.roc ──────────────────────────
 
     The `Test` module is not imported:
 
@@ -3802,7 +4418,7 @@ mod test_reporting {
         Dict
         Hash
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This expression cannot be updated:
 
@@ -3821,7 +4437,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── MODULE NOT IMPORTED in /code/proj/Main.roc ──────────────────────────────────
+    ── MODULE NOT IMPORTED [E0219] in /code/proj/Main.roc ──────────────────────────
 
     The `Foo` module is not imported:
 
@@ -3846,7 +4462,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -3874,7 +4490,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `f` is weird:
 
@@ -3903,7 +4519,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of this definition:
 
@@ -3934,7 +4550,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `f` is weird:
 
@@ -3967,7 +4583,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -4000,7 +4616,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -4031,7 +4647,7 @@ mod test_reporting {
                 "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to this function has an unexpected type:
 
@@ -4065,7 +4681,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -4099,7 +4715,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -4127,7 +4743,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── BAD OPTIONAL VALUE in /code/proj/Main.roc ───────────────────────────────────
+    ── BAD OPTIONAL VALUE [E0272] in /code/proj/Main.roc ───────────────────────────
 
     This record uses an optional value for the `.y` field in an incorrect
     context!
@@ -4164,7 +4780,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
 
     The 3rd pattern is redundant:
 
@@ -4208,7 +4824,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── UNUSED ARGUMENT in /code/proj/Main.roc ──────────────────────────────────────
+    ── UNUSED ARGUMENT [E0211] in /code/proj/Main.roc ──────────────────────────────
 
     `f` doesn't use `foo`.
 
@@ -4230,7 +4846,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a qualified name here:
 
@@ -4251,7 +4867,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a qualified name here:
 
@@ -4271,7 +4887,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a record field access here:
 
@@ -4293,7 +4909,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/type_annotation_double_colon/Test.roc ───────────────
+    ── UNKNOWN OPERATOR [E0104] in tmp/type_annotation_double_colon/Test.roc ───────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -4326,7 +4942,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── IGNORED RESULT in /code/proj/Main.roc ───────────────────────────────────────
+    ── IGNORED RESULT [E0317] in /code/proj/Main.roc ───────────────────────────────
 
     The result of this expression is ignored:
 
@@ -4342,7 +4958,7 @@ mod test_reporting {
 
         _ = File.delete! "data.json"
 
-    ── LEFTOVER STATEMENT in /code/proj/Main.roc ───────────────────────────────────
+    ── LEFTOVER STATEMENT [E0311] in /code/proj/Main.roc ───────────────────────────
 
     This statement does not produce any effects:
 
@@ -4364,7 +4980,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TAG UNION TYPE in tmp/tag_union_open/Test.roc ────────────────────
+    ── UNFINISHED TAG UNION TYPE [E0155] in tmp/tag_union_open/Test.roc ────────────
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4386,7 +5002,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TAG UNION TYPE in tmp/tag_union_end/Test.roc ─────────────────────
+    ── UNFINISHED TAG UNION TYPE [E0155] in tmp/tag_union_end/Test.roc ─────────────
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4408,7 +5024,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── WEIRD TAG NAME in tmp/tag_union_lowercase_tag_name/Test.roc ─────────────────
+    ── WEIRD TAG NAME [E0156] in tmp/tag_union_lowercase_tag_name/Test.roc ─────────
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4429,7 +5045,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── WEIRD TAG NAME in tmp/tag_union_second_lowercase_tag_name/Test.roc ──────────
+    ── WEIRD TAG NAME [E0156] in tmp/tag_union_second_lowercase_tag_name/Test.roc ──
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4450,7 +5066,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_open/Test.roc ─────────────────────
+    ── UNFINISHED RECORD TYPE [E0152] in tmp/record_type_open/Test.roc ─────────────
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4473,7 +5089,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_open_indent/Test.roc ──────────────
+    ── UNFINISHED RECORD TYPE [E0152] in tmp/record_type_open_indent/Test.roc ──────
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4496,7 +5112,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_end/Test.roc ──────────────────────
+    ── UNFINISHED RECORD TYPE [E0152] in tmp/record_type_end/Test.roc ──────────────
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4518,7 +5134,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_keyword_field_name/Test.roc ───────
+    ── UNFINISHED RECORD TYPE [E0152] in ...ecord_type_keyword_field_name/Test.roc ─
 
     I just started parsing a record type, but I got stuck on this field
     name:
@@ -4540,7 +5156,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_missing_comma/Test.roc ────────────
+    ── UNFINISHED RECORD TYPE [E0152] in tmp/record_type_missing_comma/Test.roc ────
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4557,7 +5173,7 @@ mod test_reporting {
         record_type_tab,
         "f : { foo \t }",
         @r###"
-    ── TAB CHARACTER in tmp/record_type_tab/Test.roc ───────────────────────────────
+    ── TAB CHARACTER [E0179] in tmp/record_type_tab/Test.roc ───────────────────────
 
     I encountered a tab character:
 
@@ -4572,7 +5188,7 @@ mod test_reporting {
         comment_with_tab,
         "# comment with a \t char\n4",
         @r###"
-    ── TAB CHARACTER in tmp/comment_with_tab/Test.roc ──────────────────────────────
+    ── TAB CHARACTER [E0179] in tmp/comment_with_tab/Test.roc ──────────────────────
 
     I encountered a tab character:
 
@@ -4587,7 +5203,7 @@ mod test_reporting {
         comment_with_control_character,
         "# comment with a \x07 char\n",
         @r###"
-    ── ASCII CONTROL CHARACTER in tmp/comment_with_control_character/Test.roc ──────
+    ── ASCII CONTROL CHARACTER [E0180] in ...mment_with_control_character/Test.roc ─
 
     I encountered an ASCII control character:
 
@@ -4602,7 +5218,7 @@ mod test_reporting {
         record_type_carriage_return,
         "f : { \r foo }",
         @r"
-    ── MISPLACED CARRIAGE RETURN in tmp/record_type_carriage_return/Test.roc ───────
+    ── MISPLACED CARRIAGE RETURN [E0181] in ...ecord_type_carriage_return/Test.roc ─
 
     I encountered a stray carriage return (\r):
 
@@ -4622,7 +5238,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/type_in_parens_start/Test.roc ─────────────────
+    ── UNFINISHED PARENTHESES [E0130] in tmp/type_in_parens_start/Test.roc ─────────
 
     I am partway through parsing a type in parentheses, but I got stuck
     here:
@@ -4645,7 +5261,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/type_in_parens_end/Test.roc ───────────────────
+    ── UNFINISHED PARENTHESES [E0130] in tmp/type_in_parens_end/Test.roc ───────────
 
     I am partway through parsing a type in parentheses, but I got stuck
     here:
@@ -4670,7 +5286,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am confused by this type name:
 
@@ -4681,7 +5297,7 @@ mod test_reporting {
     qualified by a module name, like Bool or Http.Request.Request.
     "
     );
-    //                ── DOUBLE DOT ──────────────────────────────────────────────────────────────────
+    //                ── DOUBLE DOT [E0157] ──────────────────────────────────────────────────────────
     //
     //                I encountered two dots in a row:
     //
@@ -4700,7 +5316,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am confused by this type name:
 
@@ -4711,7 +5327,7 @@ mod test_reporting {
     qualified by a module name, like Bool or Http.Request.Request.
     "
     );
-    //                ── TRAILING DOT ────────────────────────────────────────────────────────────────
+    //                ── TRAILING DOT [E0158] ────────────────────────────────────────────────────────
     //
     //                I encountered a dot with nothing after it:
     //
@@ -4729,7 +5345,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TYPE in tmp/type_apply_stray_dot/Test.roc ────────────────────────
+    ── UNFINISHED TYPE [E0149] in tmp/type_apply_stray_dot/Test.roc ────────────────
 
     I just started parsing a type, but I got stuck here:
 
@@ -4750,7 +5366,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am confused by this type name:
 
@@ -4761,7 +5377,7 @@ mod test_reporting {
     qualified by a module name, like Bool or Http.Request.Request.
     "
     );
-    //                ── WEIRD QUALIFIED NAME ────────────────────────────────────────────────────────
+    //                ── WEIRD QUALIFIED NAME [E0159] ────────────────────────────────────────────────
     //
     //                I encountered a number at the start of a qualified name segment:
     //
@@ -4781,7 +5397,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am confused by this type name:
 
@@ -4806,7 +5422,7 @@ mod test_reporting {
     //         "
     //     ),
     //     @r#"
-    // ── MISSING FINAL EXPRESSION in tmp/def_missing_final_expression/Test.roc ───────
+    // ── MISSING FINAL EXPRESSION [E0107] in ...ef_missing_final_expression/Test.roc ─
 
     // I am partway through parsing a definition, but I got stuck here:
 
@@ -4834,7 +5450,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── INDENT ENDS AFTER EXPRESSION in tmp/expression_indentation_end/Test.roc ─────
+    ── INDENT ENDS AFTER EXPRESSION [E0109] in ...ression_indentation_end/Test.roc ─
 
     I am partway through parsing an expression, but I got stuck here:
 
@@ -4860,7 +5476,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED INLINE ALIAS in tmp/type_inline_alias/Test.roc ───────────────────
+    ── UNFINISHED INLINE ALIAS [E0150] in tmp/type_inline_alias/Test.roc ───────────
 
     I just started parsing an inline type alias, but I got stuck here:
 
@@ -4882,7 +5498,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── DOUBLE COMMA in tmp/type_double_comma/Test.roc ──────────────────────────────
+    ── DOUBLE COMMA [E0148] in tmp/type_double_comma/Test.roc ──────────────────────
 
     I just started parsing a function argument type, but I encountered two
     commas in a row:
@@ -4905,7 +5521,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TYPE in tmp/type_argument_no_arrow/Test.roc ──────────────────────
+    ── UNFINISHED TYPE [E0149] in tmp/type_argument_no_arrow/Test.roc ──────────────
 
     I am partway through parsing a type, but I got stuck here:
 
@@ -4928,7 +5544,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TYPE in tmp/type_argument_arrow_then_nothing/Test.roc ────────────
+    ── UNFINISHED TYPE [E0149] in tmp/type_argument_arrow_then_nothing/Test.roc ────
 
     I just started parsing a type, but I got stuck here:
 
@@ -4947,7 +5563,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_import/Test.roc ─────────────────────────
+    ── UNFINISHED IMPORT [E0137] in tmp/unfinished_import/Test.roc ─────────────────
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -4975,7 +5591,7 @@ mod test_reporting {
             import Menu { x = 4 }
             "
         ),@r###"
-    ── RECORD PARSE PROBLEM in tmp/weird_import_params_record/Test.roc ─────────────
+    ── RECORD PARSE PROBLEM [E0113] in tmp/weird_import_params_record/Test.roc ─────
 
     I am partway through parsing a record, but I got stuck here:
 
@@ -4993,7 +5609,7 @@ mod test_reporting {
             import Menu { my_params & echo: echo_fn }
             "
         ),@r"
-    ── RECORD UPDATE IN MODULE PARAMS in ...ecord_update_in_module_params/Test.roc ─
+    ── RECORD UPDATE IN MODULE PARAMS [E0133] in ...date_in_module_params/Test.roc ─
 
     I was partway through parsing module params, but I got stuck here:
 
@@ -5013,7 +5629,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_import_as_or_exposing/Test.roc ──────────
+    ── UNFINISHED IMPORT [E0137] in tmp/unfinished_import_as_or_exposing/Test.roc ──
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -5042,7 +5658,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_import_alias/Test.roc ───────────────────
+    ── UNFINISHED IMPORT [E0137] in tmp/unfinished_import_alias/Test.roc ───────────
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -5061,7 +5677,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── LOWERCASE ALIAS in tmp/lowercase_import_alias/Test.roc ──────────────────────
+    ── LOWERCASE ALIAS [E0135] in tmp/lowercase_import_alias/Test.roc ──────────────
 
     This import is using a lowercase alias:
 
@@ -5080,7 +5696,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_import_exposing/Test.roc ────────────────
+    ── UNFINISHED IMPORT [E0137] in tmp/unfinished_import_exposing/Test.roc ────────
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -5098,7 +5714,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── WEIRD EXPOSING in tmp/unfinished_import_exposing_name/Test.roc ──────────────
+    ── WEIRD EXPOSING [E0136] in tmp/unfinished_import_exposing_name/Test.roc ──────
 
     I'm partway through parsing an exposing list, but I got stuck here:
 
@@ -5118,7 +5734,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_ingested_file_name/Test.roc ─────────────
+    ── UNFINISHED IMPORT [E0137] in tmp/unfinished_ingested_file_name/Test.roc ─────
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -5139,7 +5755,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TYPE in tmp/ingested_file_import_ann_syntax_err/Test.roc ─────────
+    ── UNFINISHED TYPE [E0149] in tmp/ingested_file_import_ann_syntax_err/Test.roc ─
 
     I am partway through parsing a type, but I got stuck here:
 
@@ -5164,7 +5780,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `my_dict` definition:
 
@@ -5199,7 +5815,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `my_dict` definition:
 
@@ -5231,7 +5847,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── IF GUARD NO CONDITION in tmp/if_guard_without_condition/Test.roc ────────────
+    ── IF GUARD NO CONDITION [E0139] in tmp/if_guard_without_condition/Test.roc ────
 
     I just started parsing an if guard, but there is no guard condition:
 
@@ -5256,7 +5872,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED PATTERN in tmp/empty_or_pattern/Test.roc ─────────────────────────
+    ── UNFINISHED PATTERN [E0142] in tmp/empty_or_pattern/Test.roc ─────────────────
 
     I just started parsing a pattern, but I got stuck here:
 
@@ -5281,7 +5897,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── MISSING ARROW in tmp/pattern_binds_keyword/Test.roc ─────────────────────────
+    ── MISSING ARROW [E0116] in tmp/pattern_binds_keyword/Test.roc ─────────────────
 
     I am partway through parsing a `when` expression, but got stuck here:
 
@@ -5317,7 +5933,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED WHEN in tmp/when_missing_arrow/Test.roc ──────────────────────────
+    ── UNFINISHED WHEN [E0140] in tmp/when_missing_arrow/Test.roc ──────────────────
 
     I was partway through parsing a `when` expression, but I got stuck here:
 
@@ -5350,7 +5966,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED ARGUMENT LIST in tmp/lambda_double_comma/Test.roc ────────────────
+    ── UNFINISHED ARGUMENT LIST [E0117] in tmp/lambda_double_comma/Test.roc ────────
 
     I am partway through parsing a function argument list, but I got stuck
     at this comma:
@@ -5371,7 +5987,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED ARGUMENT LIST in tmp/lambda_leading_comma/Test.roc ───────────────
+    ── UNFINISHED ARGUMENT LIST [E0117] in tmp/lambda_leading_comma/Test.roc ───────
 
     I am partway through parsing a function argument list, but I got stuck
     at this comma:
@@ -5387,7 +6003,7 @@ mod test_reporting {
     // this should get better with time
     // TODO this formerly gave
     //
-    //                ── UNFINISHED WHEN ─────────────────────────────────────────────────────────────
+    //                ── UNFINISHED WHEN [E0140] ─────────────────────────────────────────────────────
     //
     //                I was partway through parsing a `when` expression, but I got stuck here:
     //
@@ -5418,7 +6034,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNKNOWN OPERATOR in tmp/when_outdented_branch/Test.roc ──────────────────────
+    ── UNKNOWN OPERATOR [E0104] in tmp/when_outdented_branch/Test.roc ──────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -5448,7 +6064,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNEXPECTED ARROW in tmp/when_over_indented_underscore/Test.roc ──────────────
+    ── UNEXPECTED ARROW [E0141] in tmp/when_over_indented_underscore/Test.roc ──────
 
     I am parsing a `when` expression right now, but this arrow is confusing
     me:
@@ -5486,7 +6102,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNEXPECTED ARROW in tmp/when_over_indented_int/Test.roc ─────────────────────
+    ── UNEXPECTED ARROW [E0141] in tmp/when_over_indented_int/Test.roc ─────────────
 
     I am parsing a `when` expression right now, but this arrow is confusing
     me:
@@ -5527,7 +6143,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED IF in tmp/if_outdented_then/Test.roc ─────────────────────────────
+    ── UNFINISHED IF [E0138] in tmp/if_outdented_then/Test.roc ─────────────────────
 
     I was partway through parsing an `if` expression, but I got stuck here:
 
@@ -5547,7 +6163,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED IF in tmp/if_missing_else/Test.roc ───────────────────────────────
+    ── UNFINISHED IF [E0138] in tmp/if_missing_else/Test.roc ───────────────────────
 
     I was partway through parsing an `if` expression, but I got stuck here:
 
@@ -5566,7 +6182,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED LIST in tmp/list_double_comma/Test.roc ───────────────────────────
+    ── UNFINISHED LIST [E0131] in tmp/list_double_comma/Test.roc ───────────────────
 
     I am partway through started parsing a list, but I got stuck here:
 
@@ -5586,7 +6202,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED LIST in tmp/list_without_end/Test.roc ────────────────────────────
+    ── UNFINISHED LIST [E0131] in tmp/list_without_end/Test.roc ────────────────────
 
     I am partway through started parsing a list, but I got stuck here:
 
@@ -5612,7 +6228,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── MISSING EXPRESSION in tmp/missing_return_expression/Test.roc ────────────────
+    ── MISSING EXPRESSION [E0106] in tmp/missing_return_expression/Test.roc ────────
 
     I am partway through parsing a return statement, but I got stuck here:
 
@@ -5632,7 +6248,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── MISSING EXPRESSION in tmp/return_as_def_name/Test.roc ───────────────────────
+    ── MISSING EXPRESSION [E0106] in tmp/return_as_def_name/Test.roc ───────────────
 
     I am partway through parsing a return statement, but I got stuck here:
 
@@ -5647,7 +6263,7 @@ mod test_reporting {
         return_space_problem,
         "return \t",
         @r###"
-    ── TAB CHARACTER in tmp/return_space_problem/Test.roc ──────────────────────────
+    ── TAB CHARACTER [E0179] in tmp/return_space_problem/Test.roc ──────────────────
 
     I encountered a tab character:
 
@@ -5666,7 +6282,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This float literal contains an invalid digit:
 
@@ -5684,7 +6300,7 @@ mod test_reporting {
         unicode_not_hex,
         r#""abc\u(zzzz)def""#,
         @r#"
-    ── WEIRD CODE POINT in tmp/unicode_not_hex/Test.roc ────────────────────────────
+    ── WEIRD CODE POINT [E0120] in tmp/unicode_not_hex/Test.roc ────────────────────
 
     I am partway through parsing a unicode code point, but I got stuck
     here:
@@ -5702,7 +6318,7 @@ mod test_reporting {
         unicode_too_large,
         r#""abc\u(110000)def""#,
         @r#"
-    ── INVALID UNICODE in /code/proj/Main.roc ──────────────────────────────────────
+    ── INVALID UNICODE [E0215] in /code/proj/Main.roc ──────────────────────────────
 
     This unicode code point is invalid:
 
@@ -5717,7 +6333,7 @@ mod test_reporting {
         weird_escape,
         r#""abc\qdef""#,
         @r#"
-    ── WEIRD ESCAPE in tmp/weird_escape/Test.roc ───────────────────────────────────
+    ── WEIRD ESCAPE [E0119] in tmp/weird_escape/Test.roc ───────────────────────────
 
     I was partway through parsing a  string literal, but I got stuck here:
 
@@ -5740,7 +6356,7 @@ mod test_reporting {
         single_quote_too_long,
         r"'abcdef'",
         @r#"
-    ── INVALID SCALAR in tmp/single_quote_too_long/Test.roc ────────────────────────
+    ── INVALID SCALAR [E0123] in tmp/single_quote_too_long/Test.roc ────────────────
 
     I am part way through parsing this scalar literal (character literal),
     but it's too long to fit in a U32 so it's not a valid scalar.
@@ -5757,7 +6373,7 @@ mod test_reporting {
         single_no_end,
         r#""there is no end"#,
         @r#"
-    ── ENDLESS STRING in tmp/single_no_end/Test.roc ────────────────────────────────
+    ── ENDLESS STRING [E0124] in tmp/single_no_end/Test.roc ────────────────────────
 
     I cannot find the end of this string:
 
@@ -5773,7 +6389,7 @@ mod test_reporting {
         multi_no_end,
         r#""""there is no end"#,
         @r#"
-    ── ENDLESS STRING in tmp/multi_no_end/Test.roc ─────────────────────────────────
+    ── ENDLESS STRING [E0124] in tmp/multi_no_end/Test.roc ─────────────────────────
 
     I cannot find the end of this block string:
 
@@ -5789,7 +6405,7 @@ mod test_reporting {
         multi_insufficient_indent,
         "    \"\"\"\n  testing\n    \"\"\"", // 4 space indent on the start, 2 space on the `testing` line
         @r#"
-    ── INSUFFICIENT INDENT IN MULTI-LINE STRING in ...insufficient_indent/Test.roc ─
+    ── INSUFFICIENT INDENT IN MULTI-LINE STRING [E0126] in ...ient_indent/Test.roc ─
 
     This multiline string is not sufficiently indented:
 
@@ -5811,7 +6427,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── INDENT ENDS AFTER EXPRESSION in ...expect_without_final_expression/Test.roc ─
+    ── INDENT ENDS AFTER EXPRESSION [E0109] in ...ithout_final_expression/Test.roc ─
 
     I am partway through parsing an expect statement, but I got stuck
     here:
@@ -5836,7 +6452,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── UNHANDLED PARSE ERROR in tmp/unhandled_parse_error/Test.roc ─────────────────
+    ── UNHANDLED PARSE ERROR [E0112] in tmp/unhandled_parse_error/Test.roc ─────────
 
     I got stuck while parsing this:
 
@@ -5867,7 +6483,7 @@ mod test_reporting {
             "#,
             ),
             @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -5893,7 +6509,7 @@ mod test_reporting {
                 $name,
                 &format!(r#"if Bool.true then "abc" else 1 {} 2"#, $op),
                 |golden| assert_eq!(golden, format!(
-r#"── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+r#"── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
 This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -5936,7 +6552,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `foo` record doesn’t have a `if` field:
 
@@ -5955,7 +6571,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── NOT EXPOSED in /code/proj/Main.roc ──────────────────────────────────────────
+    ── NOT EXPOSED [E0218] in /code/proj/Main.roc ──────────────────────────────────
 
     The Num module does not expose `if`:
 
@@ -5979,7 +6595,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a record field access here:
 
@@ -5998,7 +6614,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am very confused by this field access:
 
@@ -6017,7 +6633,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am very confused by this field access
 
@@ -6053,7 +6669,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/case_of/Test.roc ────────────────────────────────────
+    ── UNKNOWN OPERATOR [E0104] in tmp/case_of/Test.roc ────────────────────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -6086,7 +6702,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `bar` in this scope.
 
@@ -6111,7 +6727,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/invalid_operator/Test.roc ───────────────────────────
+    ── UNKNOWN OPERATOR [E0104] in tmp/invalid_operator/Test.roc ───────────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -6137,7 +6753,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/double_plus/Test.roc ────────────────────────────────
+    ── UNKNOWN OPERATOR [E0104] in tmp/double_plus/Test.roc ────────────────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -6164,7 +6780,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/inline_hastype/Test.roc ─────────────────────────────
+    ── UNKNOWN OPERATOR [E0104] in tmp/inline_hastype/Test.roc ─────────────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -6193,7 +6809,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── SYNTAX PROBLEM in tmp/wild_case_arrow/Test.roc ──────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in tmp/wild_case_arrow/Test.roc ──────────────────────
 
     I got stuck here:
 
@@ -6222,7 +6838,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r"
-                ── WEIRD PROVIDES in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD PROVIDES [E0170] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a provides list, but I got stuck here:
 
@@ -6257,7 +6873,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r"
-                ── WEIRD PROVIDES in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD PROVIDES [E0170] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6283,7 +6899,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD PROVIDES in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD PROVIDES [E0170] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6310,7 +6926,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD PROVIDES in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD PROVIDES [E0170] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6336,7 +6952,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD MODULE PARAMS in /code/proj/Main.roc ──────────────────────────────────
+                ── WEIRD MODULE PARAMS [E0171] in /code/proj/Main.roc ──────────────────────────
 
                 I am partway through parsing a module header, but I got stuck here:
 
@@ -6373,7 +6989,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── BAD REQUIRES in /code/proj/Main.roc ─────────────────────────────────────────
+                ── BAD REQUIRES [E0176] in /code/proj/Main.roc ─────────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6401,7 +7017,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r"
-                ── WEIRD IMPORTS in /code/proj/Main.roc ────────────────────────────────────────
+                ── WEIRD IMPORTS [E0173] in /code/proj/Main.roc ────────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6426,7 +7042,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r"
-                ── WEIRD EXPOSES in /code/proj/Main.roc ────────────────────────────────────────
+                ── WEIRD EXPOSES [E0172] in /code/proj/Main.roc ────────────────────────────────
 
                 I am partway through parsing an `exposes` list, but I got stuck here:
 
@@ -6451,7 +7067,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD EXPOSES in /code/proj/Main.roc ────────────────────────────────────────
+                ── WEIRD EXPOSES [E0172] in /code/proj/Main.roc ────────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6476,7 +7092,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD EXPOSES in /code/proj/Main.roc ────────────────────────────────────────
+                ── WEIRD EXPOSES [E0172] in /code/proj/Main.roc ────────────────────────────────
 
                 I am partway through parsing an `exposes` list, but I got stuck here:
 
@@ -6501,7 +7117,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD EXPOSES in /code/proj/Main.roc ────────────────────────────────────────
+                ── WEIRD EXPOSES [E0172] in /code/proj/Main.roc ────────────────────────────────
 
                 I am partway through parsing an `exposes` list, but I got stuck here:
 
@@ -6529,7 +7145,7 @@ All branches in an `if` must have the same type!
             ),
             indoc!(
                 r#"
-                ── WEIRD APP NAME in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD APP NAME [E0167] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a header, but got stuck here:
 
@@ -6553,7 +7169,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [E0315] in /code/proj/Main.roc ────────────────────────────────
 
     This value is not a function, but it was given 2 arguments:
 
@@ -6574,7 +7190,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [E0315] in /code/proj/Main.roc ────────────────────────────────
 
     This value is not a function, but it was given 2 arguments:
 
@@ -6596,7 +7212,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -6622,7 +7238,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/pattern_in_parens_open/Test.roc ───────────────
+    ── UNFINISHED PARENTHESES [E0130] in tmp/pattern_in_parens_open/Test.roc ───────
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6645,7 +7261,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/pattern_in_parens_end_comma/Test.roc ──────────
+    ── UNFINISHED PARENTHESES [E0130] in tmp/pattern_in_parens_end_comma/Test.roc ──
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6668,7 +7284,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/pattern_in_parens_end/Test.roc ────────────────
+    ── UNFINISHED PARENTHESES [E0130] in tmp/pattern_in_parens_end/Test.roc ────────
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6692,7 +7308,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── MISSING ARROW in tmp/unfinished_closure_pattern_in_parens/Test.roc ──────────
+    ── MISSING ARROW [E0116] in tmp/unfinished_closure_pattern_in_parens/Test.roc ──
 
     I am partway through parsing a function argument list, but I got stuck
     here:
@@ -6715,7 +7331,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/pattern_in_parens_indent_open/Test.roc ────────
+    ── UNFINISHED PARENTHESES [E0130] in ...pattern_in_parens_indent_open/Test.roc ─
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6740,7 +7356,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `expect` condition needs to be a Bool:
 
@@ -6767,7 +7383,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to * has an unexpected type:
 
@@ -6782,7 +7398,7 @@ All branches in an `if` must have the same type!
 
         Num *
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `mult` definition:
 
@@ -6811,7 +7427,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to * has an unexpected type:
 
@@ -6826,7 +7442,7 @@ All branches in an `if` must have the same type!
 
         Num a
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `mult` definition:
 
@@ -6861,7 +7477,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [E0217] in /code/proj/Main.roc ───────────────────────────────
 
     This alias has the same name as a builtin:
 
@@ -6871,7 +7487,7 @@ All branches in an `if` must have the same type!
     All builtin aliases are in scope by default, so I need this alias to
     have a different name!
 
-    ── TOO FEW TYPE ARGUMENTS in /code/proj/Main.roc ───────────────────────────────
+    ── TOO FEW TYPE ARGUMENTS [E0262] in /code/proj/Main.roc ───────────────────────
 
     The `Result` alias expects 2 type arguments, but it got 1 instead:
 
@@ -6899,7 +7515,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [E0217] in /code/proj/Main.roc ───────────────────────────────
 
     This alias has the same name as a builtin:
 
@@ -6909,7 +7525,7 @@ All branches in an `if` must have the same type!
     All builtin aliases are in scope by default, so I need this alias to
     have a different name!
 
-    ── TOO MANY TYPE ARGUMENTS in /code/proj/Main.roc ──────────────────────────────
+    ── TOO MANY TYPE ARGUMENTS [E0261] in /code/proj/Main.roc ──────────────────────
 
     The `Result` alias expects 2 type arguments, but it got 3 instead:
 
@@ -6931,7 +7547,7 @@ All branches in an `if` must have the same type!
         ),
         // TODO: We should tell the user that we inferred `_` as `a`
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -6965,7 +7581,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -6997,7 +7613,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -7037,7 +7653,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `inner` definition:
 
@@ -7066,7 +7682,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── NOT AN INLINE ALIAS in tmp/error_inline_alias_not_an_alias/Test.roc ─────────
+    ── NOT AN INLINE ALIAS [E0161] in tmp/error_inline_alias_not_an_alias/Test.roc ─
 
     The inline type after this `as` is not a type alias:
 
@@ -7086,7 +7702,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── QUALIFIED ALIAS NAME in tmp/error_inline_alias_qualified/Test.roc ───────────
+    ── QUALIFIED ALIAS NAME [E0162] in tmp/error_inline_alias_qualified/Test.roc ───
 
     This type alias has a qualified name:
 
@@ -7106,7 +7722,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE ARGUMENT NOT LOWERCASE in ..._inline_alias_argument_uppercase/Test.roc ─
+    ── TYPE ARGUMENT NOT LOWERCASE [E0163] in ...alias_argument_uppercase/Test.roc ─
 
     This alias type argument is not lowercase:
 
@@ -7130,7 +7746,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `is_empty` has an unexpected type:
 
@@ -7166,7 +7782,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `c` has an unexpected type:
 
@@ -7199,7 +7815,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [E0314] in /code/proj/Main.roc ─────────────────────────────────
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -7222,7 +7838,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [E0314] in /code/proj/Main.roc ─────────────────────────────────
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -7244,7 +7860,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [E0314] in /code/proj/Main.roc ─────────────────────────────────
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -7269,7 +7885,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `job` is weird:
 
@@ -7304,7 +7920,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `job` definition:
 
@@ -7335,7 +7951,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── NESTED DATATYPE in /code/proj/Main.roc ──────────────────────────────────────
+    ── NESTED DATATYPE [E0221] in /code/proj/Main.roc ──────────────────────────────
 
     `Nested` is a nested datatype. Here is one recursive usage of it:
 
@@ -7363,7 +7979,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── NESTED DATATYPE in /code/proj/Main.roc ──────────────────────────────────────
+    ── NESTED DATATYPE [E0221] in /code/proj/Main.roc ──────────────────────────────
 
     `Nested` is a nested datatype. Here is one recursive usage of it:
 
@@ -7411,7 +8027,7 @@ All branches in an `if` must have the same type!
 
                     let real = format!(indoc!(
                         r"
-                        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+                        ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
                         This 1st argument to `use` has an unexpected type:
 
@@ -7477,7 +8093,7 @@ All branches in an `if` must have the same type!
 
                     let real = format!(indoc!(
                         r"
-                        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+                        ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
                         The branches of this `when` expression don't match the condition:
 
@@ -7528,7 +8144,7 @@ All branches in an `if` must have the same type!
         ),
         // TODO: link to number suffixes
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal contains an invalid digit:
 
@@ -7551,7 +8167,7 @@ All branches in an `if` must have the same type!
         ),
         // TODO: link to number suffixes
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal contains an invalid digit:
 
@@ -7573,7 +8189,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── CONFLICTING NUMBER SUFFIX in /code/proj/Main.roc ────────────────────────────
+    ── CONFLICTING NUMBER SUFFIX [E0222] in /code/proj/Main.roc ────────────────────
 
     This number literal is an integer, but it has a float suffix:
 
@@ -7590,7 +8206,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── CONFLICTING NUMBER SUFFIX in /code/proj/Main.roc ────────────────────────────
+    ── CONFLICTING NUMBER SUFFIX [E0222] in /code/proj/Main.roc ────────────────────
 
     This number literal is a float, but it has an integer suffix:
 
@@ -7603,7 +8219,7 @@ All branches in an `if` must have the same type!
         u8_overflow,
         "256u8",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [E0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7619,7 +8235,7 @@ All branches in an `if` must have the same type!
         negative_u8,
         "-1u8",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [E0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7635,7 +8251,7 @@ All branches in an `if` must have the same type!
         u16_overflow,
         "65536u16",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [E0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7651,7 +8267,7 @@ All branches in an `if` must have the same type!
         negative_u16,
         "-1u16",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [E0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7667,7 +8283,7 @@ All branches in an `if` must have the same type!
         u32_overflow,
         "4_294_967_296u32",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [E0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7683,7 +8299,7 @@ All branches in an `if` must have the same type!
         negative_u32,
         "-1u32",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [E0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7699,7 +8315,7 @@ All branches in an `if` must have the same type!
         u64_overflow,
         "18_446_744_073_709_551_616u64",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [E0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7715,7 +8331,7 @@ All branches in an `if` must have the same type!
         negative_u64,
         "-1u64",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [E0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7731,7 +8347,7 @@ All branches in an `if` must have the same type!
         negative_u128,
         "-1u128",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [E0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7747,7 +8363,7 @@ All branches in an `if` must have the same type!
         i8_overflow,
         "128i8",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [E0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7763,7 +8379,7 @@ All branches in an `if` must have the same type!
         i8_underflow,
         "-129i8",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [E0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7779,7 +8395,7 @@ All branches in an `if` must have the same type!
         i16_overflow,
         "32768i16",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [E0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7795,7 +8411,7 @@ All branches in an `if` must have the same type!
         i16_underflow,
         "-32769i16",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [E0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7811,7 +8427,7 @@ All branches in an `if` must have the same type!
         i32_overflow,
         "2_147_483_648i32",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [E0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7827,7 +8443,7 @@ All branches in an `if` must have the same type!
         i32_underflow,
         "-2_147_483_649i32",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [E0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7843,7 +8459,7 @@ All branches in an `if` must have the same type!
         i64_overflow,
         "9_223_372_036_854_775_808i64",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [E0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7859,7 +8475,7 @@ All branches in an `if` must have the same type!
         i64_underflow,
         "-9_223_372_036_854_775_809i64",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [E0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7875,7 +8491,7 @@ All branches in an `if` must have the same type!
         i128_overflow,
         "170_141_183_460_469_231_731_687_303_715_884_105_728i128",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [E0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7897,7 +8513,7 @@ All branches in an `if` must have the same type!
         // TODO: this error message could be improved, e.g. something like "This argument can
         // be used as ... because of its literal value"
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7923,7 +8539,7 @@ All branches in an `if` must have the same type!
              "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7950,7 +8566,7 @@ All branches in an `if` must have the same type!
              "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7977,7 +8593,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -8008,7 +8624,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [E0314] in /code/proj/Main.roc ─────────────────────────────────
 
     The `R` alias is self-recursive in an invalid way:
 
@@ -8031,7 +8647,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [E0314] in /code/proj/Main.roc ─────────────────────────────────
 
     The `R` alias is self-recursive in an invalid way:
 
@@ -8055,7 +8671,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [E0314] in /code/proj/Main.roc ─────────────────────────────────
 
     The `Foo` alias is recursive in an invalid way:
 
@@ -8090,7 +8706,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [E0217] in /code/proj/Main.roc ───────────────────────────────
 
     This alias has the same name as a builtin:
 
@@ -8110,7 +8726,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
+    ── OPAQUE TYPE NOT DEFINED [E0225] in /code/proj/Main.roc ──────────────────────
 
     The opaque type Age referenced here is not defined:
 
@@ -8131,7 +8747,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
+    ── OPAQUE TYPE NOT DEFINED [E0225] in /code/proj/Main.roc ──────────────────────
 
     The opaque type Age referenced here is not defined:
 
@@ -8145,7 +8761,7 @@ All branches in an `if` must have the same type!
 
     Note: It looks like there are no opaque types declared in this scope yet!
 
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `Age` is not used anywhere in your code.
 
@@ -8168,7 +8784,7 @@ All branches in an `if` must have the same type!
         // and checking it during can. The reason the error appears is because it is parsed as
         // Apply(Error(OtherModule), [@Age, 21])
         @r"
-    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
+    ── OPAQUE TYPE NOT DEFINED [E0225] in /code/proj/Main.roc ──────────────────────
 
     The opaque type Age referenced here is not defined:
 
@@ -8177,7 +8793,7 @@ All branches in an `if` must have the same type!
 
     Note: It looks like there are no opaque types declared in this scope yet!
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a qualified name here:
 
@@ -8204,7 +8820,7 @@ All branches in an `if` must have the same type!
         // `@Age` can be linked to the declaration of `Age` inside `age`, and a suggestion to
         // raise that declaration to the outer scope.
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `Age` is not used anywhere in your code.
 
@@ -8214,7 +8830,7 @@ All branches in an `if` must have the same type!
     If you didn't intend on using `Age` then remove it so future readers of
     your code don't wonder why it is there.
 
-    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
+    ── OPAQUE TYPE NOT DEFINED [E0225] in /code/proj/Main.roc ──────────────────────
 
     The opaque type Age referenced here is not defined:
 
@@ -8235,7 +8851,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── MODULE NOT IMPORTED in /code/proj/Main.roc ──────────────────────────────────
+    ── MODULE NOT IMPORTED [E0219] in /code/proj/Main.roc ──────────────────────────
 
     The `Unimported` module is not imported:
 
@@ -8245,10 +8861,10 @@ All branches in an `if` must have the same type!
     Is there an import missing? Perhaps there is a typo. Did you mean one
     of these?
 
+        Sort
         Encode
         Inspect
         Dict
-        List
     "
     );
 
@@ -8267,7 +8883,7 @@ All branches in an `if` must have the same type!
         // TODO(opaques): error could be improved by saying that the opaque definition demands
         // that the argument be a U8, and linking to the definition!
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -8296,7 +8912,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -8326,7 +8942,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `v` definition:
 
@@ -8362,7 +8978,7 @@ All branches in an `if` must have the same type!
         // TODO(opaques): error could be improved by saying that the user-provided pattern
         // probably wants to change "Age" to "@Age"!
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `f` is weird:
 
@@ -8395,7 +9011,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 2nd pattern in this `when` does not match the previous ones:
 
@@ -8426,7 +9042,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -8464,7 +9080,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -8474,7 +9090,7 @@ All branches in an `if` must have the same type!
 
     Other possibilities include:
 
-        @F _
+        @F 0
 
     I would have to crash if I saw one of those! Add branches for them!
     "#
@@ -8497,7 +9113,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `y` has an unexpected type:
 
@@ -8530,7 +9146,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -8554,7 +9170,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── INVALID_EXTENSION_TYPE in /code/proj/Main.roc ───────────────────────────────
+    ── INVALID_EXTENSION_TYPE [E0229] in /code/proj/Main.roc ───────────────────────
 
     This record extension type is invalid:
 
@@ -8575,7 +9191,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── INVALID_EXTENSION_TYPE in /code/proj/Main.roc ───────────────────────────────
+    ── INVALID_EXTENSION_TYPE [E0229] in /code/proj/Main.roc ───────────────────────
 
     This tag union extension type is invalid:
 
@@ -8602,7 +9218,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `UnknownType` in this scope.
 
@@ -8616,7 +9232,7 @@ All branches in an `if` must have the same type!
         Unsigned16
         Unsigned64
 
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `UnknownType` in this scope.
 
@@ -8643,7 +9259,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── UNFINISHED ABILITY in tmp/ability_first_demand_not_indented_enough/Test.roc ─
+    ── UNFINISHED ABILITY [E0182] in ..._first_demand_not_indented_enough/Test.roc ─
 
     I was partway through parsing an ability definition, but I got stuck
     here:
@@ -8668,7 +9284,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-        ── UNFINISHED ABILITY in tmp/ability_demands_not_indented_with_first/Test.roc ──
+        ── UNFINISHED ABILITY [E0182] in ...y_demands_not_indented_with_first/Test.roc ─
 
         I was partway through parsing an ability definition, but I got stuck
         here:
@@ -8691,7 +9307,7 @@ All branches in an `if` must have the same type!
                 "
         ),
         @r"
-        ── UNFINISHED ABILITY in tmp/ability_demand_value_has_args/Test.roc ────────────
+        ── UNFINISHED ABILITY [E0182] in tmp/ability_demand_value_has_args/Test.roc ────
 
         I was partway through parsing an ability definition, but I got stuck
         here:
@@ -8715,7 +9331,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── UNFINISHED ABILITY in tmp/ability_non_signature_expression/Test.roc ─────────
+    ── UNFINISHED ABILITY [E0182] in tmp/ability_non_signature_expression/Test.roc ─
 
     I was partway through parsing an ability definition, but I got stuck
     here:
@@ -8738,7 +9354,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── WILDCARD NOT ALLOWED HERE in /code/proj/Main.roc ────────────────────────────
+    ── WILDCARD NOT ALLOWED HERE [E0209] in /code/proj/Main.roc ────────────────────
 
     The definition of `I` includes a wildcard (`*`) type variable:
 
@@ -8760,7 +9376,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── UNDERSCORE NOT ALLOWED HERE in /code/proj/Main.roc ──────────────────────────
+    ── UNDERSCORE NOT ALLOWED HERE [E0210] in /code/proj/Main.roc ──────────────────
 
     The definition of `I` includes an inferred (`_`) type:
 
@@ -8781,7 +9397,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── WILDCARD NOT ALLOWED HERE in /code/proj/Main.roc ────────────────────────────
+    ── WILDCARD NOT ALLOWED HERE [E0209] in /code/proj/Main.roc ────────────────────
 
     The definition of `I` includes a wildcard (`*`) type variable:
 
@@ -8803,7 +9419,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── WILDCARD NOT ALLOWED HERE in /code/proj/Main.roc ────────────────────────────
+    ── WILDCARD NOT ALLOWED HERE [E0209] in /code/proj/Main.roc ────────────────────
 
     The definition of `I` includes 2 wildcard (`*`) type variables. Here is
     one of them:
@@ -8826,7 +9442,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── UNDERSCORE NOT ALLOWED HERE in /code/proj/Main.roc ──────────────────────────
+    ── UNDERSCORE NOT ALLOWED HERE [E0210] in /code/proj/Main.roc ──────────────────
 
     The definition of `I` includes an inferred (`_`) type:
 
@@ -8847,7 +9463,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── UNDECLARED TYPE VARIABLE in /code/proj/Main.roc ─────────────────────────────
+    ── UNDECLARED TYPE VARIABLE [E0208] in /code/proj/Main.roc ─────────────────────
 
     The definition of `I` includes an undeclared type variable:
 
@@ -8872,7 +9488,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── ABILITY HAS TYPE VARIABLES in /code/proj/Main.roc ───────────────────────────
+    ── ABILITY HAS TYPE VARIABLES [E0230] in /code/proj/Main.roc ───────────────────
 
     The definition of the `MHash` ability includes type variables:
 
@@ -8882,7 +9498,7 @@ All branches in an `if` must have the same type!
     Abilities cannot depend on type variables, but their member values
     can!
 
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `MHash` is not used anywhere in your code.
 
@@ -8904,7 +9520,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── IMPLEMENTS CLAUSE IS NOT AN ABILITY in /code/proj/Main.roc ──────────────────
+    ── IMPLEMENTS CLAUSE IS NOT AN ABILITY [E0231] in /code/proj/Main.roc ──────────
 
     The type referenced in this "implements" clause is not an ability:
 
@@ -8923,7 +9539,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-        ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+        ── DUPLICATE NAME [E0217] in /code/proj/Main.roc ───────────────────────────────
 
         The `a` name is first defined here:
 
@@ -8952,7 +9568,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-        ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+        ── DUPLICATE NAME [E0217] in /code/proj/Main.roc ───────────────────────────────
 
         The `Ability` name is first defined here:
 
@@ -8979,7 +9595,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-        ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE in /code/proj/Main.roc ─────────────
+        ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE [E0233] in /code/proj/Main.roc ─────
 
         The definition of the ability member `ab` does not include an `implements`
         clause binding a type variable to the ability `Ability`:
@@ -8994,7 +9610,7 @@ All branches in an `if` must have the same type!
 
         Otherwise, the function does not need to be part of the ability!
 
-        ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+        ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
         `Ability` is not used anywhere in your code.
 
@@ -9016,7 +9632,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-        ── ABILITY MEMBER BINDS MULTIPLE VARIABLES in /code/proj/Main.roc ──────────────
+        ── ABILITY MEMBER BINDS MULTIPLE VARIABLES [E0234] in /code/proj/Main.roc ──────
 
         The definition of the ability member `eq` includes multiple variables
         bound to the `MEq`` ability:`
@@ -9044,7 +9660,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── ILLEGAL IMPLEMENTS CLAUSE in /code/proj/Main.roc ────────────────────────────
+    ── ILLEGAL IMPLEMENTS CLAUSE [E0232] in /code/proj/Main.roc ────────────────────
 
     An `implements` clause is not allowed here:
 
@@ -9054,7 +9670,7 @@ All branches in an `if` must have the same type!
     `implements` clauses can only be specified on the top-level type
     annotations.
 
-    ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE in /code/proj/Main.roc ─────────────
+    ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE [E0233] in /code/proj/Main.roc ─────
 
     The definition of the ability member `hash` does not include an
     `implements` clause binding a type variable to the ability `MHash`:
@@ -9085,7 +9701,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+        ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
         Something is off with this specialization of `hash`:
 
@@ -9118,7 +9734,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9144,7 +9760,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `hash` is not used anywhere in your code.
 
@@ -9173,7 +9789,7 @@ All branches in an `if` must have the same type!
         ),
         // TODO: the error message here could be seriously improved!
         @r"
-    ── OVERLOADED SPECIALIZATION in /code/proj/Main.roc ────────────────────────────
+    ── OVERLOADED SPECIALIZATION [E0258] in /code/proj/Main.roc ────────────────────
 
     This ability member specialization is already claimed to specialize
     another opaque type:
@@ -9186,7 +9802,7 @@ All branches in an `if` must have the same type!
     Ability specializations can only provide implementations for one
     opaque type, since all opaque types are different!
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This specialization of `hash` is overly general:
 
@@ -9226,7 +9842,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── OVERLOADED SPECIALIZATION in /code/proj/Main.roc ────────────────────────────
+    ── OVERLOADED SPECIALIZATION [E0258] in /code/proj/Main.roc ────────────────────
 
     This ability member specialization is already claimed to specialize
     another opaque type:
@@ -9257,7 +9873,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with this specialization of `eq`:
 
@@ -9292,7 +9908,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `hash` definition:
 
@@ -9333,7 +9949,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9346,7 +9962,7 @@ All branches in an `if` must have the same type!
 
     Only builtin abilities can have generated implementations!
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9371,7 +9987,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-        ── ABILITY NOT ON TOP-LEVEL in /code/proj/Main.roc ─────────────────────────────
+        ── ABILITY NOT ON TOP-LEVEL [E0235] in /code/proj/Main.roc ─────────────────────
 
         This ability definition is not on the top-level of a module:
 
@@ -9399,7 +10015,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `hashable` definition:
 
@@ -9445,7 +10061,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── ABILITY USED AS TYPE in /code/proj/Main.roc ─────────────────────────────────
+    ── ABILITY USED AS TYPE [E0237] in /code/proj/Main.roc ─────────────────────────
 
     You are attempting to use the ability `MHash` as a type directly:
 
@@ -9459,7 +10075,7 @@ All branches in an `if` must have the same type!
 
         a implements MHash
 
-    ── ABILITY USED AS TYPE in /code/proj/Main.roc ─────────────────────────────────
+    ── ABILITY USED AS TYPE [E0237] in /code/proj/Main.roc ─────────────────────────
 
     You are attempting to use the ability `MHash` as a type directly:
 
@@ -9489,7 +10105,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -9542,7 +10158,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── WEIRD IMPORTS in tmp/imports_missing_comma/Test.roc ─────────────────────────
+        ── WEIRD IMPORTS [E0173] in tmp/imports_missing_comma/Test.roc ─────────────────
 
         I am partway through parsing a imports list, but I got stuck here:
 
@@ -9567,7 +10183,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+        ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
         This `when` does not cover all the possibilities:
 
@@ -9605,22 +10221,21 @@ All branches in an `if` must have the same type!
             r#"
             app "test" provides [main] to "./platform"
 
-            Default implements default : {} -> a where a implements Default
+            Zero implements zero : {} -> a where a implements Zero
 
             main =
-                A := {} implements [Default {default}]
-                default = \{} -> @A {}
-                default {}
+                A := {} implements [Zero {zero}]
+                zero = \{} -> @A {}
+                zero {}
             "#
         ),
         @r"
-        ── SPECIALIZATION NOT ON TOP-LEVEL in /code/proj/Main.roc ──────────────────────
+        ── SPECIALIZATION NOT ON TOP-LEVEL [E0236] in /code/proj/Main.roc ──────────────
 
-        This specialization of the `default` ability member is in a nested
-        scope:
+        This specialization of the `zero` ability member is in a nested scope:
 
-        7│      default = \{} -> @A {}
-                ^^^^^^^
+        7│      zero = \{} -> @A {}
+                ^^^^
 
         Specializations can only be defined on the top-level of a module.
         "
@@ -9639,7 +10254,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to == has an unexpected type:
 
@@ -9675,7 +10290,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `remove` has an unexpected type:
 
@@ -9696,7 +10311,7 @@ All branches in an `if` must have the same type!
     change the type annotation to be more specific? Maybe change the code
     to be more general?
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `new`:
 
@@ -9709,7 +10324,7 @@ All branches in an `if` must have the same type!
 
         { set : Set ∞ }
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [E0318] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `goal`:
 
@@ -9739,7 +10354,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-        ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+        ── CIRCULAR DEFINITION [E0216] in /code/proj/Main.roc ──────────────────────────
 
         The `t1` definition is causing a very tricky infinite loop:
 
@@ -9767,7 +10382,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9795,7 +10410,7 @@ All branches in an `if` must have the same type!
         // TODO: this error message is quite unfortunate. We should remove the duplication, and
         // also support regions that point to things in other modules. See also https://github.com/roc-lang/roc/issues/3056.
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9832,7 +10447,7 @@ All branches in an `if` must have the same type!
                 "#
         ),
         @r"
-            ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+            ── CIRCULAR DEFINITION [E0216] in /code/proj/Main.roc ──────────────────────────
 
             The `t1` definition is causing a very tricky infinite loop:
 
@@ -9862,7 +10477,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── IMPLEMENTATION NOT FOUND in /code/proj/Main.roc ─────────────────────────────
+    ── IMPLEMENTATION NOT FOUND [E0239] in /code/proj/Main.roc ─────────────────────
 
     An implementation of `eq` could not be found in this scope:
 
@@ -9873,7 +10488,7 @@ All branches in an `if` must have the same type!
     another variable that implements this ability member, like
     { eq: myeq }
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9900,7 +10515,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `a_m_eq` in this scope.
 
@@ -9914,7 +10529,7 @@ All branches in an `if` must have the same type!
         Eq
         Num
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9941,7 +10556,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── OPTIONAL ABILITY IMPLEMENTATION in /code/proj/Main.roc ──────────────────────
+    ── OPTIONAL ABILITY IMPLEMENTATION [E0242] in /code/proj/Main.roc ──────────────
 
     Ability implementations cannot be optional:
 
@@ -9952,7 +10567,7 @@ All branches in an `if` must have the same type!
 
 
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9979,7 +10594,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── OPTIONAL ABILITY IMPLEMENTATION in /code/proj/Main.roc ──────────────────────
+    ── OPTIONAL ABILITY IMPLEMENTATION [E0242] in /code/proj/Main.roc ──────────────
 
     Ability implementations cannot be optional:
 
@@ -9992,7 +10607,7 @@ All branches in an `if` must have the same type!
     record of implementations. For example,    implements [Encoding] will
     attempt to derive `Encoding`
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `Encoding` ability:
 
@@ -10017,7 +10632,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── QUALIFIED ABILITY IMPLEMENTATION in /code/proj/Main.roc ─────────────────────
+    ── QUALIFIED ABILITY IMPLEMENTATION [E0243] in /code/proj/Main.roc ─────────────
 
     This ability implementation is qualified:
 
@@ -10027,7 +10642,7 @@ All branches in an `if` must have the same type!
     Custom implementations must be defined in the local scope, and
     unqualified.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -10052,7 +10667,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── ABILITY IMPLEMENTATION NOT IDENTIFIER in /code/proj/Main.roc ────────────────
+    ── ABILITY IMPLEMENTATION NOT IDENTIFIER [E0244] in /code/proj/Main.roc ────────
 
     This ability implementation is not an identifier:
 
@@ -10064,7 +10679,7 @@ All branches in an `if` must have the same type!
 
     Tip: consider defining this expression as a variable.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -10091,7 +10706,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── DUPLICATE IMPLEMENTATION in /code/proj/Main.roc ─────────────────────────────
+    ── DUPLICATE IMPLEMENTATION [E0245] in /code/proj/Main.roc ─────────────────────
 
     This ability member implementation is duplicate:
 
@@ -10119,7 +10734,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── NOT AN ABILITY in /code/proj/Main.roc ───────────────────────────────────────
+    ── NOT AN ABILITY [E0241] in /code/proj/Main.roc ───────────────────────────────
 
     This identifier is not an ability in scope:
 
@@ -10142,7 +10757,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── ILLEGAL DERIVE in /code/proj/Main.roc ───────────────────────────────────────
+    ── ILLEGAL DERIVE [E0238] in /code/proj/Main.roc ───────────────────────────────
 
     This ability cannot be derived:
 
@@ -10151,7 +10766,8 @@ All branches in an `if` must have the same type!
 
     Only builtin abilities can be derived.
 
-    Note: The builtin abilities are `Encoding`, `Decoding`, `Hash`, `Eq`, `Inspect`
+    Note: The builtin abilities are `Encoding`, `Decoding`, `Hash`, `Eq`, `Inspect`,
+    `Sort`, `Default`
     "
     );
 
@@ -10165,7 +10781,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Encoding` ability for `A`:
 
@@ -10190,7 +10806,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Encoding` ability for `A`:
 
@@ -10242,7 +10858,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [E0217] in /code/proj/Main.roc ───────────────────────────────
 
     The `main` name is first defined here:
 
@@ -10257,7 +10873,7 @@ All branches in an `if` must have the same type!
     Since these variables have the same name, it's easy to use the wrong
     one by accident. Give one of them a new name.
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10291,7 +10907,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+        ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
         Something is off with the body of the `with_open` definition:
 
@@ -10327,7 +10943,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This returns something that's incompatible with the return type of the
     enclosing function:
@@ -10375,7 +10991,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `foo` has an unexpected type:
 
@@ -10420,7 +11036,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to == has an unexpected type:
 
@@ -10445,7 +11061,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to == has an unexpected type:
 
@@ -10482,7 +11098,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the 2nd branch of this `when` expression:
 
@@ -10518,7 +11134,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `map` has an unexpected type:
 
@@ -10545,7 +11161,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-        ── NAME NOT BOUND IN ALL PATTERNS in /code/proj/Main.roc ───────────────────────
+        ── NAME NOT BOUND IN ALL PATTERNS [E0256] in /code/proj/Main.roc ───────────────
 
         `x` is not bound in all patterns of this `when` branch
 
@@ -10556,7 +11172,7 @@ All branches in an `if` must have the same type!
         of the branch. Otherwise, the program would crash when it tries to use
         an identifier that wasn't bound!
 
-        ── NAME NOT BOUND IN ALL PATTERNS in /code/proj/Main.roc ───────────────────────
+        ── NAME NOT BOUND IN ALL PATTERNS [E0256] in /code/proj/Main.roc ───────────────
 
         `y` is not bound in all patterns of this `when` branch
 
@@ -10567,7 +11183,7 @@ All branches in an `if` must have the same type!
         of the branch. Otherwise, the program would crash when it tries to use
         an identifier that wasn't bound!
 
-        ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+        ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
         `y` is not used in this `when` branch.
 
@@ -10588,7 +11204,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-        ── NAME NOT BOUND IN ALL PATTERNS in /code/proj/Main.roc ───────────────────────
+        ── NAME NOT BOUND IN ALL PATTERNS [E0256] in /code/proj/Main.roc ───────────────
 
         `x` is not bound in all patterns of this `when` branch
 
@@ -10611,7 +11227,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-        ── NAME NOT BOUND IN ALL PATTERNS in /code/proj/Main.roc ───────────────────────
+        ── NAME NOT BOUND IN ALL PATTERNS [E0256] in /code/proj/Main.roc ───────────────
 
         `rest` is not bound in all patterns of this `when` branch
 
@@ -10634,7 +11250,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -10660,7 +11276,7 @@ All branches in an `if` must have the same type!
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r"── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+                r"── UNRECOGNIZED NAME [E0202] in /code/proj/Main.roc ────────────────────────────
 
                 Nothing is named `foo` in this scope.
 
@@ -10697,7 +11313,7 @@ All branches in an `if` must have the same type!
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r"── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+                r"── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
                 An underscore is being used as a variable here:
 
@@ -10723,7 +11339,7 @@ All branches in an `if` must have the same type!
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r"── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+                r"── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
                 This variable's name starts with an underscore:
 
@@ -10756,7 +11372,7 @@ All branches in an `if` must have the same type!
             golden,
             indoc!(
                 r"
-                ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+                ── SYNTAX PROBLEM [E0108] in /code/proj/Main.roc ───────────────────────────────
 
                 This variable's name starts with an underscore:
 
@@ -10782,7 +11398,7 @@ All branches in an `if` must have the same type!
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r"── NAMING PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+                r"── NAMING PROBLEM [E0201] in /code/proj/Main.roc ───────────────────────────────
 
                 I am trying to parse an identifier here:
 
@@ -10806,7 +11422,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── EMPTY RECORD BUILDER in /code/proj/Main.roc ─────────────────────────────────
+    ── EMPTY RECORD BUILDER [E0275] in /code/proj/Main.roc ─────────────────────────
 
     This record builder has no fields:
 
@@ -10827,7 +11443,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── NOT ENOUGH FIELDS IN RECORD BUILDER in /code/proj/Main.roc ──────────────────
+    ── NOT ENOUGH FIELDS IN RECORD BUILDER [E0276] in /code/proj/Main.roc ──────────
 
     This record builder only has one field:
 
@@ -10850,7 +11466,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── OPTIONAL FIELD IN RECORD BUILDER in /code/proj/Main.roc ─────────────────────
+    ── OPTIONAL FIELD IN RECORD BUILDER [E0277] in /code/proj/Main.roc ─────────────
 
     Optional fields are not allowed to be used in record builders.
 
@@ -10887,7 +11503,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [E0315] in /code/proj/Main.roc ────────────────────────────────
 
     The `xyz` value is not a function, but it was given 3 arguments:
 
@@ -10915,7 +11531,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This assignment doesn't introduce any new variables:
 
@@ -10926,7 +11542,7 @@ All branches in an `if` must have the same type!
     affect the program's behavior. If you don't need to use the value on
     the right-hand side, consider removing the assignment.
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This assignment doesn't introduce any new variables:
 
@@ -10937,7 +11553,7 @@ All branches in an `if` must have the same type!
     affect the program's behavior. If you don't need to use the value on
     the right-hand side, consider removing the assignment.
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This assignment doesn't introduce any new variables:
 
@@ -10948,7 +11564,7 @@ All branches in an `if` must have the same type!
     affect the program's behavior. If you don't need to use the value on
     the right-hand side, consider removing the assignment.
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This assignment doesn't introduce any new variables:
 
@@ -10977,7 +11593,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10989,7 +11605,7 @@ All branches in an `if` must have the same type!
     allowed at the top-level, assignments that don't introduce variables
     cannot affect a program's behavior
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -11001,7 +11617,7 @@ All branches in an `if` must have the same type!
     allowed at the top-level, assignments that don't introduce variables
     cannot affect a program's behavior
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -11013,7 +11629,7 @@ All branches in an `if` must have the same type!
     allowed at the top-level, assignments that don't introduce variables
     cannot affect a program's behavior
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -11041,7 +11657,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `hash` is not used anywhere in your code.
 
@@ -11068,7 +11684,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── WRONG SPECIALIZATION TYPE in /code/proj/Main.roc ────────────────────────────
+    ── WRONG SPECIALIZATION TYPE [E0303] in /code/proj/Main.roc ────────────────────
 
     This specialization of `hash` is not for the expected type:
 
@@ -11091,7 +11707,7 @@ All branches in an `if` must have the same type!
                 "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -11121,7 +11737,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [E0314] in /code/proj/Main.roc ─────────────────────────────────
 
     The `Recursive` opaque is self-recursive in an invalid way:
 
@@ -11143,7 +11759,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Decoding` ability for `A`:
 
@@ -11168,7 +11784,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Decoding` ability for `A`:
 
@@ -11225,7 +11841,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11258,7 +11874,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11288,7 +11904,7 @@ All branches in an `if` must have the same type!
             "
         ),
     @r#"
-    ── UNUSED ARGUMENT in /code/proj/Main.roc ──────────────────────────────────────
+    ── UNUSED ARGUMENT [E0211] in /code/proj/Main.roc ──────────────────────────────
 
     This function doesn't use `x`.
 
@@ -11320,7 +11936,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -11353,7 +11969,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -11390,7 +12006,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `then` branch of this `if` expression:
 
@@ -11422,7 +12038,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [E0203] in /code/proj/Main.roc ────────────────────────────
 
     `foo` is not used in this `when` branch.
 
@@ -11490,7 +12106,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11520,7 +12136,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11586,7 +12202,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -11614,7 +12230,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -11641,7 +12257,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNMATCHABLE PATTERN in /code/proj/Main.roc ──────────────────────────────────
+    ── UNMATCHABLE PATTERN [E0321] in /code/proj/Main.roc ──────────────────────────
 
     The 2nd pattern will never be matched:
 
@@ -11668,7 +12284,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNMATCHABLE PATTERN in /code/proj/Main.roc ──────────────────────────────────
+    ── UNMATCHABLE PATTERN [E0321] in /code/proj/Main.roc ──────────────────────────
 
     The 2nd pattern will never be matched:
 
@@ -11680,7 +12296,7 @@ All branches in an `if` must have the same type!
     It's impossible to create a value of this shape, so this pattern can
     be safely removed!
 
-    ── UNMATCHABLE PATTERN in /code/proj/Main.roc ──────────────────────────────────
+    ── UNMATCHABLE PATTERN [E0321] in /code/proj/Main.roc ──────────────────────────
 
     The 3rd pattern will never be matched:
 
@@ -11705,7 +12321,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [E0217] in /code/proj/Main.roc ───────────────────────────────
 
     This opaque type has the same name as a builtin:
 
@@ -11729,7 +12345,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r###"
-    ── UNUSED IMPORT in /code/proj/Main.roc ────────────────────────────────────────
+    ── UNUSED IMPORT [E0204] in /code/proj/Main.roc ────────────────────────────────
 
     List is imported but not used.
 
@@ -11782,7 +12398,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNRECOGNIZED PACKAGE in tmp/unknown_shorthand_no_deps/Test.roc ──────────────
+    ── UNRECOGNIZED PACKAGE [E0423] in tmp/unknown_shorthand_no_deps/Test.roc ──────
 
     This module is trying to import from `foo`:
 
@@ -11807,7 +12423,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNRECOGNIZED PACKAGE in tmp/unknown_shorthand_in_app/Test.roc ───────────────
+    ── UNRECOGNIZED PACKAGE [E0423] in tmp/unknown_shorthand_in_app/Test.roc ───────
 
     This module is trying to import from `foo`:
 
@@ -11834,7 +12450,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    [1;36m── FILE NOT FOUND in tmp/import_qualified_builtin/../../tests/Bool.roc ─────────[0m
+    [1;36m── FILE NOT FOUND [E0414] in tmp/import_qualified_builtin/../../tests/Bool.roc ─[0m
 
     I am looking for this file, but it's not there:
 
@@ -11856,7 +12472,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+    ── CIRCULAR DEFINITION [E0216] in /code/proj/Main.roc ──────────────────────────
 
     `main` is defined directly in terms of itself:
 
@@ -11878,7 +12494,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` condition needs to be a Bool:
 
@@ -11904,7 +12520,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` condition needs to be a Bool:
 
@@ -11932,7 +12548,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Hash` ability for `A`:
 
@@ -11957,7 +12573,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Hash` ability for `A`:
 
@@ -12040,7 +12656,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12067,7 +12683,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12113,7 +12729,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12149,7 +12765,7 @@ All branches in an `if` must have the same type!
             "
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `shift_right_zf_by` has an unexpected type:
 
@@ -12164,7 +12780,7 @@ All branches in an `if` must have the same type!
 
         U8
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `shift_right_by` has an unexpected type:
 
@@ -12179,7 +12795,7 @@ All branches in an `if` must have the same type!
 
         U8
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `shift_left_by` has an unexpected type:
 
@@ -12207,7 +12823,7 @@ All branches in an `if` must have the same type!
             "
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `contains` has an unexpected type:
 
@@ -12234,7 +12850,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -12259,7 +12875,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -12289,7 +12905,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -12315,7 +12931,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -12331,6 +12947,56 @@ All branches in an `if` must have the same type!
     "
     );
 
+    test_report!(
+        derive_sort_for_f64,
+        indoc!(
+            r#"
+             app "test" provides [A] to "./platform"
+
+             A := F64 implements [Sort]
+             "#
+        ),
+        @r"
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
+
+    I can't derive an implementation of the `Sort` ability for `A`:
+
+    3│  A := F64 implements [Sort]
+                             ^^^^
+
+    Note: I can't derive `Sort.compare` for floating-point types. That's
+    because Roc's floating-point numbers cannot be put in a total order -
+    in Roc, `NaN` is neither less than, greater than, nor equal to any
+    number. If a type can't be put in a total order, it cannot support the
+    `Sort` ability!
+
+    Tip: You can define a custom implementation of `Sort` for `A`.
+    "
+    );
+
+    test_report!(
+        derive_default_for_function,
+        indoc!(
+            r#"
+             app "test" provides [A] to "./platform"
+
+             A := U8 -> U8 implements [Default]
+             "#
+        ),
+        @r"
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
+
+    I can't derive an implementation of the `Default` ability for `A`:
+
+    3│  A := U8 -> U8 implements [Default]
+                                  ^^^^^^^
+
+    Note: `Default` cannot be generated for functions.
+
+    Tip: You can define a custom implementation of `Default` for `A`.
+    "
+    );
+
     test_report!(
         derive_eq_for_non_eq_opaque,
         indoc!(
@@ -12343,7 +13009,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -12426,7 +13092,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12453,7 +13119,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12482,7 +13148,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12505,7 +13171,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12545,7 +13211,7 @@ All branches in an `if` must have the same type!
              "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12583,7 +13249,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── NOT EXPOSED in /code/proj/Main.roc ──────────────────────────────────────────
+    ── NOT EXPOSED [E0218] in /code/proj/Main.roc ──────────────────────────────────
 
     The Bool module does not expose `structural_eq`:
 
@@ -12597,7 +13263,7 @@ All branches in an `if` must have the same type!
         Bool.false
         Bool.is_eq
 
-    ── NOT EXPOSED in /code/proj/Main.roc ──────────────────────────────────────────
+    ── NOT EXPOSED [E0218] in /code/proj/Main.roc ──────────────────────────────────
 
     The Bool module does not expose `structural_not_eq`:
 
@@ -12625,7 +13291,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12650,7 +13316,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `foo` is weird:
 
@@ -12679,7 +13345,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Hash` ability for `F`:
 
@@ -12690,7 +13356,7 @@ All branches in an `if` must have the same type!
 
     Tip: You can define a custom implementation of `Hash` for `F`.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `F`:
 
@@ -12701,7 +13367,7 @@ All branches in an `if` must have the same type!
 
     Tip: You can define a custom implementation of `Eq` for `F`.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [E0247] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Encoding` ability for `F`:
 
@@ -12724,7 +13390,7 @@ All branches in an `if` must have the same type!
             "
         ),
     @r"
-    ── DUPLICATE BOUND ABILITY in /code/proj/Main.roc ──────────────────────────────
+    ── DUPLICATE BOUND ABILITY [E0255] in /code/proj/Main.roc ──────────────────────
 
     I already saw that this type variable is bound to the `Hash` ability
     once before:
@@ -12750,7 +13416,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `g` has an unexpected type:
 
@@ -12787,7 +13453,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `g` has an unexpected type:
 
@@ -12825,7 +13491,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `g` has an unexpected type:
 
@@ -12858,7 +13524,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNFINISHED LIST PATTERN in tmp/list_pattern_not_terminated/Test.roc ─────────
+    ── UNFINISHED LIST PATTERN [E0145] in tmp/list_pattern_not_terminated/Test.roc ─
 
     I am partway through parsing a list pattern, but I got stuck here:
 
@@ -12879,7 +13545,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── INCORRECT REST PATTERN in tmp/list_pattern_weird_rest_pattern/Test.roc ──────
+    ── INCORRECT REST PATTERN [E0146] in ...st_pattern_weird_rest_pattern/Test.roc ─
 
     It looks like you may trying to write a list rest pattern, but it's
     not the form I expect:
@@ -12901,7 +13567,7 @@ All branches in an `if` must have the same type!
             "
         ),
     @r"
-    ── UNNECESSARY WILDCARD in /code/proj/Main.roc ─────────────────────────────────
+    ── UNNECESSARY WILDCARD [E0259] in /code/proj/Main.roc ─────────────────────────
 
     This type annotation has a wildcard type variable (`*`) that isn't
     needed.
@@ -12928,7 +13594,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── MULTIPLE LIST REST PATTERNS in /code/proj/Main.roc ──────────────────────────
+    ── MULTIPLE LIST REST PATTERNS [E0260] in /code/proj/Main.roc ──────────────────
 
     This list pattern match has multiple rest patterns:
 
@@ -12938,7 +13604,7 @@ All branches in an `if` must have the same type!
     I only support compiling list patterns with one .. pattern! Can you
     remove this additional one?
 
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -12962,7 +13628,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── MULTIPLE LIST REST PATTERNS in /code/proj/Main.roc ──────────────────────────
+    ── MULTIPLE LIST REST PATTERNS [E0260] in /code/proj/Main.roc ──────────────────
 
     This list pattern match has multiple rest patterns:
 
@@ -12972,7 +13638,7 @@ All branches in an `if` must have the same type!
     I only support compiling list patterns with one .. pattern! Can you
     remove this additional one?
 
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -12996,7 +13662,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This list element doesn't match the types of other elements in the
     pattern:
@@ -13023,7 +13689,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -13056,7 +13722,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13096,7 +13762,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13140,7 +13806,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
 
     The 2nd pattern is redundant:
 
@@ -13206,7 +13872,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13233,7 +13899,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13276,7 +13942,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13303,7 +13969,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13352,7 +14018,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13383,7 +14049,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13415,7 +14081,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13464,7 +14130,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13498,7 +14164,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
 
     The 3rd pattern is redundant:
 
@@ -13526,7 +14192,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
 
     The 3rd pattern is redundant:
 
@@ -13554,7 +14220,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
 
     The 3rd pattern is redundant:
 
@@ -13582,7 +14248,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
 
     The 2nd pattern is redundant:
 
@@ -13596,6 +14262,154 @@ All branches in an `if` must have the same type!
     "#
     );
 
+    test_report!(
+        list_match_redundant_head_and_tail_slice,
+        indoc!(
+            r#"
+            f : List [A, B] -> Str
+            f = \l ->
+                when l is
+                    [A, .., _] -> ""
+                    [B, ..] -> ""
+                    [_, .., A] -> ""
+                    _ -> ""
+
+            f
+            "#
+        ),
+        @r#"
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
+
+    The 3rd pattern is redundant:
+
+     6│           when l is
+     7│               [A, .., _] -> ""
+     8│               [B, ..] -> ""
+     9│>              [_, .., A] -> ""
+    10│               _ -> ""
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "#
+    );
+
+    test_report!(
+        list_match_redundant_literal_head_and_tail,
+        indoc!(
+            r#"
+            l : List U8
+
+            when l is
+                [1, .., 2] -> ""
+                [1, _, .., 2] -> ""
+                _ -> ""
+            "#
+        ),
+        @r#"
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
+
+    The 2nd pattern is redundant:
+
+    6│       when l is
+    7│           [1, .., 2] -> ""
+    8│>          [1, _, .., 2] -> ""
+    9│           _ -> ""
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "#
+    );
+
+    test_report!(
+        list_match_redundant_wildcard_after_exhaustive_lists,
+        indoc!(
+            r#"
+            l : List [A]
+
+            when l is
+                [] -> ""
+                [_, ..] -> ""
+                _ -> ""
+            "#
+        ),
+        @r#"
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
+
+    The 3rd pattern is redundant:
+
+    6│      when l is
+    7│          [] -> ""
+    8│          [_, ..] -> ""
+    9│          _ -> ""
+                ^
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "#
+    );
+
+    test_report!(
+        list_match_redundant_wildcard_in_tuple,
+        indoc!(
+            r#"
+            f : (List U8, [A, B]) -> Str
+            f = \t ->
+                when t is
+                    ([], _) -> ""
+                    ([_, ..], A) -> ""
+                    (_, B) -> ""
+                    (_, _) -> ""
+
+            f
+            "#
+        ),
+        @r#"
+    ── REDUNDANT PATTERN [E0320] in /code/proj/Main.roc ────────────────────────────
+
+    The 4th pattern is redundant:
+
+     6│          when t is
+     7│              ([], _) -> ""
+     8│              ([_, ..], A) -> ""
+     9│              (_, B) -> ""
+    10│              (_, _) -> ""
+                     ^^^^^^
+
+    Any value of this shape will be handled by a previous pattern, so this
+    one should be removed.
+    "#
+    );
+
+    test_report!(
+        list_match_non_exhaustive_str_tail,
+        indoc!(
+            r#"
+            l : List Str
+
+            when l is
+                [] -> ""
+                [_] -> ""
+                ["a", .., "b"] -> ""
+            "#
+        ),
+        @r#"
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+    6│>      when l is
+    7│>          [] -> ""
+    8│>          [_] -> ""
+    9│>          ["a", .., "b"] -> ""
+
+    Other possibilities include:
+
+        [_, .., ""]
+
+    I would have to crash if I saw one of those! Add branches for them!
+    "#
+    );
+
     test_no_problem!(
         list_match_with_guard,
         indoc!(
@@ -13621,7 +14435,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13652,7 +14466,7 @@ All branches in an `if` must have the same type!
             "
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This value passed to `crash` is not a string:
 
@@ -13677,7 +14491,7 @@ All branches in an `if` must have the same type!
             "
         ),
     @r"
-    ── UNAPPLIED CRASH in /code/proj/Main.roc ──────────────────────────────────────
+    ── UNAPPLIED CRASH [E0263] in /code/proj/Main.roc ──────────────────────────────
 
     This `crash` doesn't have a message given to it:
 
@@ -13698,7 +14512,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── OVERAPPLIED CRASH in /code/proj/Main.roc ────────────────────────────────────
+    ── OVERAPPLIED CRASH [E0264] in /code/proj/Main.roc ────────────────────────────
 
     This `crash` has too many values given to it:
 
@@ -13734,7 +14548,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13776,7 +14590,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── DEFINITION ONLY USED IN RECURSION in /code/proj/Main.roc ────────────────────
+    ── DEFINITION ONLY USED IN RECURSION [E0253] in /code/proj/Main.roc ────────────
 
     This definition is only used in recursion with itself:
 
@@ -13809,7 +14623,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── DEFINITIONS ONLY USED IN RECURSION in /code/proj/Main.roc ───────────────────
+    ── DEFINITIONS ONLY USED IN RECURSION [E0254] in /code/proj/Main.roc ───────────
 
     These 2 definitions are only used in mutual recursion with themselves:
 
@@ -13846,7 +14660,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── DEFINITION ONLY USED IN RECURSION in /code/proj/Main.roc ────────────────────
+    ── DEFINITION ONLY USED IN RECURSION [E0253] in /code/proj/Main.roc ────────────
 
     This definition is only used in recursion with itself:
 
@@ -13882,7 +14696,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── DEFINITIONS ONLY USED IN RECURSION in /code/proj/Main.roc ───────────────────
+    ── DEFINITIONS ONLY USED IN RECURSION [E0254] in /code/proj/Main.roc ───────────
 
     These 2 definitions are only used in mutual recursion with themselves:
 
@@ -13922,7 +14736,7 @@ All branches in an `if` must have the same type!
             {one, str}
         "#),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `concat` has an unexpected type:
 
@@ -13953,7 +14767,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -13990,7 +14804,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -14026,7 +14840,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `main` definition:
 
@@ -14064,7 +14878,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `main` definition:
 
@@ -14132,7 +14946,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -14172,7 +14986,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -14212,7 +15026,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [E0319] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -14260,7 +15074,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [E0315] in /code/proj/Main.roc ────────────────────────────────
 
     The `parser` value is an opaque type, so it cannot be called with an
     argument:
@@ -14284,7 +15098,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -14315,7 +15129,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -14349,7 +15163,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -14383,7 +15197,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -14411,7 +15225,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TOO FEW ARGS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── TOO FEW ARGS [E0316] in /code/proj/Main.roc ─────────────────────────────────
 
     The `sub` function expects 2 arguments, but it got only 1:
 
@@ -14431,7 +15245,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TOO FEW ARGS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── TOO FEW ARGS [E0316] in /code/proj/Main.roc ─────────────────────────────────
 
     The `sub` function expects 2 arguments, but it got only 1:
 
@@ -14451,7 +15265,7 @@ All branches in an `if` must have the same type!
             "
         ),
     @r"
-    ── UNAPPLIED DBG in /code/proj/Main.roc ────────────────────────────────────────
+    ── UNAPPLIED DBG [E0265] in /code/proj/Main.roc ────────────────────────────────
 
     This `dbg` doesn't have a value given to it:
 
@@ -14462,7 +15276,7 @@ All branches in an `if` must have the same type!
     can't be used as a value that's passed around, like functions can be -
     it must be applied immediately!
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -14487,7 +15301,7 @@ All branches in an `if` must have the same type!
             "#
         ),
     @r#"
-    ── OVERAPPLIED DBG in /code/proj/Main.roc ──────────────────────────────────────
+    ── OVERAPPLIED DBG [E0266] in /code/proj/Main.roc ──────────────────────────────
 
     This `dbg` has too many values given to it:
 
@@ -14496,7 +15310,7 @@ All branches in an `if` must have the same type!
 
     `dbg` must be given exactly one value to print.
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -14521,7 +15335,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This record doesn’t have a `abcde` field:
 
@@ -14540,7 +15354,7 @@ All branches in an `if` must have the same type!
               "#
         ),
         @r###"
-      ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+      ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
       This expression is used in an unexpected way:
 
@@ -14568,7 +15382,7 @@ All branches in an `if` must have the same type!
               "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -14600,7 +15414,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-        ── RETURN OUTSIDE OF FUNCTION in /code/proj/Main.roc ───────────────────────────
+        ── RETURN OUTSIDE OF FUNCTION [E0268] in /code/proj/Main.roc ───────────────────
 
         This `return` doesn't belong to a function:
 
@@ -14628,7 +15442,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-        ── UNREACHABLE CODE in /code/proj/Main.roc ─────────────────────────────────────
+        ── UNREACHABLE CODE [E0270] in /code/proj/Main.roc ─────────────────────────────
 
         This code won't run because it follows a `return` statement:
 
@@ -14655,7 +15469,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-        ── UNNECESSARY RETURN in /code/proj/Main.roc ───────────────────────────────────
+        ── UNNECESSARY RETURN [E0271] in /code/proj/Main.roc ───────────────────────────
 
         This `return` keyword is redundant:
 
@@ -14681,7 +15495,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This returns something that's incompatible with the return type of the
     enclosing function:
@@ -14729,7 +15543,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-        ── IGNORED RESULT in /code/proj/Main.roc ───────────────────────────────────────
+        ── IGNORED RESULT [E0317] in /code/proj/Main.roc ───────────────────────────────
         
         The result of this expression is ignored:
         
@@ -14775,7 +15589,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── IGNORED RESULT in /code/proj/Main.roc ───────────────────────────────────────
+    ── IGNORED RESULT [E0317] in /code/proj/Main.roc ───────────────────────────────
 
     The result of this expression is ignored:
 
@@ -14809,7 +15623,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This returns something that's incompatible with the return type of the
     enclosing function:
@@ -14852,7 +15666,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-        ── IGNORED RESULT in /code/proj/Main.roc ───────────────────────────────────────
+        ── IGNORED RESULT [E0317] in /code/proj/Main.roc ───────────────────────────────
         
         The result of this expression is ignored:
         
@@ -14905,7 +15719,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── IGNORED RESULT in /code/proj/Main.roc ───────────────────────────────────────
+    ── IGNORED RESULT [E0317] in /code/proj/Main.roc ───────────────────────────────
 
     The result of this expression is ignored:
 
@@ -14942,7 +15756,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── IGNORED RESULT in /code/proj/Main.roc ───────────────────────────────────────
+    ── IGNORED RESULT [E0317] in /code/proj/Main.roc ───────────────────────────────
 
     The result of this call to `Num.to_str` is ignored:
 
@@ -14958,7 +15772,7 @@ All branches in an `if` must have the same type!
 
         _ = File.delete! "data.json"
 
-    ── LEFTOVER STATEMENT in /code/proj/Main.roc ───────────────────────────────────
+    ── LEFTOVER STATEMENT [E0311] in /code/proj/Main.roc ───────────────────────────
 
     This statement does not produce any effects:
 
@@ -14989,7 +15803,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [E0257] in /code/proj/Main.roc ───────────────────────
 
     This assignment doesn't introduce any new variables:
 
@@ -15017,7 +15831,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+        ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
         
         Something is off with the body of the `my_function` definition:
         
@@ -15071,7 +15885,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── INVALID TRY TARGET in /code/proj/Main.roc ───────────────────────────────────
+    ── INVALID TRY TARGET [E0312] in /code/proj/Main.roc ───────────────────────────
 
     This expression cannot be used as a `try` target:
 
@@ -15100,7 +15914,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── INVALID TRY TARGET in /code/proj/Main.roc ───────────────────────────────────
+    ── INVALID TRY TARGET [E0312] in /code/proj/Main.roc ───────────────────────────
 
     This expression cannot be tried with the `?` operator:
 
@@ -15130,7 +15944,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This returns something that's incompatible with the return type of the
     enclosing function:
@@ -15169,7 +15983,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to this function has an unexpected type:
 
@@ -15205,7 +16019,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to |> has an unexpected type:
 
@@ -15240,7 +16054,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to this function has an unexpected type:
 
@@ -15274,7 +16088,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── LEFTOVER STATEMENT in /code/proj/Main.roc ───────────────────────────────────
+    ── LEFTOVER STATEMENT [E0311] in /code/proj/Main.roc ───────────────────────────
 
     This statement does not produce any effects:
 
@@ -15307,7 +16121,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── EFFECT IN PURE FUNCTION in /code/proj/Main.roc ──────────────────────────────
+    ── EFFECT IN PURE FUNCTION [E0309] in /code/proj/Main.roc ──────────────────────
 
     This call to `Effect.get_line!` might produce an effect:
 
@@ -15344,7 +16158,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── EFFECT IN PURE FUNCTION in /code/proj/Main.roc ──────────────────────────────
+    ── EFFECT IN PURE FUNCTION [E0309] in /code/proj/Main.roc ──────────────────────
 
     This call to `Effect.put_line!` might produce an effect:
 
@@ -15379,7 +16193,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     This function is effectful, but its name does not indicate so:
 
@@ -15408,7 +16222,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── IGNORED RESULT in /code/proj/Main.roc ───────────────────────────────────────
+    ── IGNORED RESULT [E0317] in /code/proj/Main.roc ───────────────────────────────
 
     The result of this call to `Effect.get_line!` is ignored:
 
@@ -15440,7 +16254,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── IGNORED RESULT in /code/proj/Main.roc ───────────────────────────────────────
+    ── IGNORED RESULT [E0317] in /code/proj/Main.roc ───────────────────────────────
 
     The result of this expression is ignored:
 
@@ -15454,7 +16268,7 @@ All branches in an `if` must have the same type!
 
     Hint: Did you forget to call the function?
 
-    ── LEFTOVER STATEMENT in /code/proj/Main.roc ───────────────────────────────────
+    ── LEFTOVER STATEMENT [E0311] in /code/proj/Main.roc ───────────────────────────
 
     This statement does not produce any effects:
 
@@ -15484,7 +16298,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNNECESSARY EXCLAMATION in /code/proj/Main.roc ──────────────────────────────
+    ── UNNECESSARY EXCLAMATION [E0249] in /code/proj/Main.roc ──────────────────────
 
     This function is pure, but its name suggests otherwise:
 
@@ -15514,7 +16328,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── EFFECT IN TOP-LEVEL in /code/proj/Main.roc ──────────────────────────────────
+    ── EFFECT IN TOP-LEVEL [E0310] in /code/proj/Main.roc ──────────────────────────
 
     This call to `Effect.put_line!` might produce an effect:
 
@@ -15550,7 +16364,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     This function is effectful, but its name does not indicate so:
 
@@ -15581,7 +16395,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     This field's value is an effectful function, but its name does not
     indicate so:
@@ -15609,7 +16423,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     The type of this record field is an effectful function, but its name
     does not indicate so:
@@ -15637,7 +16451,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── UNNECESSARY EXCLAMATION in /code/proj/Main.roc ──────────────────────────────
+    ── UNNECESSARY EXCLAMATION [E0249] in /code/proj/Main.roc ──────────────────────
 
     The type of this record field is a pure function, but its name
     suggests otherwise:
@@ -15673,7 +16487,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     This function is effectful, but its name does not indicate so:
 
@@ -15710,7 +16524,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r"
-    ── UNNECESSARY EXCLAMATION in /code/proj/Main.roc ──────────────────────────────
+    ── UNNECESSARY EXCLAMATION [E0249] in /code/proj/Main.roc ──────────────────────
 
     This function is pure, but its name suggests otherwise:
 
@@ -15739,7 +16553,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     This function is effectful, but its name does not indicate so:
 
@@ -15752,7 +16566,7 @@ All branches in an `if` must have the same type!
 
     This will help readers identify it as a source of effects.
 
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     This function is effectful, but its name does not indicate so:
 
@@ -15782,7 +16596,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNNECESSARY EXCLAMATION in /code/proj/Main.roc ──────────────────────────────
+    ── UNNECESSARY EXCLAMATION [E0249] in /code/proj/Main.roc ──────────────────────
 
     This function is pure, but its name suggests otherwise:
 
@@ -15811,7 +16625,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     This function is effectful, but its name does not indicate so:
 
@@ -15824,7 +16638,7 @@ All branches in an `if` must have the same type!
 
     This will help readers identify it as a source of effects.
 
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     This function is effectful, but its name does not indicate so:
 
@@ -15854,7 +16668,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNNECESSARY EXCLAMATION in /code/proj/Main.roc ──────────────────────────────
+    ── UNNECESSARY EXCLAMATION [E0249] in /code/proj/Main.roc ──────────────────────
 
     This function is pure, but its name suggests otherwise:
 
@@ -15884,7 +16698,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── MISSING EXCLAMATION in /code/proj/Main.roc ──────────────────────────────────
+    ── MISSING EXCLAMATION [E0248] in /code/proj/Main.roc ──────────────────────────
 
     This function is effectful, but its name does not indicate so:
 
@@ -15916,7 +16730,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNNECESSARY EXCLAMATION in /code/proj/Main.roc ──────────────────────────────
+    ── UNNECESSARY EXCLAMATION [E0249] in /code/proj/Main.roc ──────────────────────
 
     This function is pure, but its name suggests otherwise:
 
@@ -15944,7 +16758,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `pure_higher_order` has an unexpected type:
 
@@ -15977,7 +16791,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `pure_higher_order` has an unexpected type:
 
@@ -16005,7 +16819,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [E0301] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `v` definition:
 
@@ -16040,7 +16854,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── TYPE VARIABLE IS NOT GENERIC in /code/proj/Main.roc ─────────────────────────
+    ── TYPE VARIABLE IS NOT GENERIC [E0313] in /code/proj/Main.roc ─────────────────
 
     This type variable has a single type:
 
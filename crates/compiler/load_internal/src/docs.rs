@@ -1,12 +1,17 @@
 use crate::docs::DocEntry::DetachedDoc;
 use crate::docs::TypeAnnotation::{Apply, BoundVariable, Function, NoTypeAnn, Record, TagUnion};
+use bumpalo::Bump;
 use roc_can::scope::Scope;
-use roc_collections::VecSet;
+use roc_collections::{MutMap, MutSet, VecSet};
 use roc_module::ident::ModuleName;
 use roc_module::symbol::{IdentIds, ModuleId, ModuleIds, Symbol};
 use roc_parse::ast::{self, ExtractSpaces, TypeHeader, TypeVar};
 use roc_parse::ast::{AssignedField, FunctionArrow};
 use roc_parse::ast::{CommentOrNewline, TypeDef, ValueDef};
+use roc_parse::expr::parse_repl_defs_and_optional_expr;
+use roc_parse::state::State;
+use roc_region::all::{Loc, Position, Region};
+use std::cell::RefCell;
 
 // Documentation generation requirements
 
@@ -43,6 +48,8 @@ pub struct DocDef {
     pub type_vars: Vec<String>,
     pub type_annotation: TypeAnnotation,
     pub docs: Option<String>,
+    /// Where the def appears in its module's source
+    pub region: Region,
 }
 
 #[derive(Debug, Clone)]
@@ -209,6 +216,7 @@ fn generate_entry_docs(
         scratchpad.extend(spaces_before);
 
         let docs = comments_or_new_lines_to_docs(&scratchpad);
+        let region = defs.regions[index];
 
         match either_index.split() {
             Err(value_index) => match &defs.value_defs[value_index.index()] {
@@ -223,6 +231,7 @@ fn generate_entry_docs(
                                 type_annotation: type_to_docs(false, loc_ann.value),
                                 type_vars: Vec::new(),
                                 docs,
+                                region,
                             };
                             doc_entries.push(DocEntry::DocDef(doc_def));
                         }
@@ -243,6 +252,7 @@ fn generate_entry_docs(
                                 type_vars: Vec::new(),
                                 symbol: Symbol::new(home, ident_id),
                                 docs,
+                                region,
                             };
                             doc_entries.push(DocEntry::DocDef(doc_def));
                         }
@@ -259,6 +269,7 @@ fn generate_entry_docs(
                                 type_vars: Vec::new(),
                                 symbol: Symbol::new(home, ident_id),
                                 docs,
+                                region,
                             };
                             doc_entries.push(DocEntry::DocDef(doc_def));
                         }
@@ -297,6 +308,7 @@ fn generate_entry_docs(
                                 type_vars: Vec::new(),
                                 symbol: Symbol::new(home, ident_id),
                                 docs,
+                                region,
                             };
                             doc_entries.push(DocEntry::DocDef(doc_def));
                         }
@@ -335,6 +347,7 @@ fn generate_entry_docs(
                         type_vars,
                         docs,
                         symbol: Symbol::new(home, ident_id),
+                        region,
                     };
                     doc_entries.push(DocEntry::DocDef(doc_def));
                 }
@@ -358,6 +371,7 @@ fn generate_entry_docs(
                         type_vars,
                         docs,
                         symbol: Symbol::new(home, ident_id),
+                        region,
                     };
                     doc_entries.push(DocEntry::DocDef(doc_def));
                 }
@@ -398,6 +412,7 @@ fn generate_entry_docs(
                         symbol: Symbol::new(home, ident_id),
                         type_vars,
                         docs,
+                        region,
                     };
                     doc_entries.push(DocEntry::DocDef(doc_def));
                 }
@@ -769,3 +784,629 @@ fn comments_or_new_lines_to_docs<'a>(
         Some(docs)
     }
 }
+
+/// A fenced Roc code block in a `##` doc comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocExample {
+    /// The code, without the `##` at the start of each line
+    pub code: String,
+    /// From the start of the block's first line of code to the end of its last
+    pub region: Region,
+    /// Where each line of code is in the module's source, after its `##`
+    lines: Vec<(usize, usize)>,
+}
+
+/// Finds the fenced code blocks in a module's doc comments that are meant to be Roc code,
+/// meaning blocks tagged `roc`, which aren't marked `unchecked` or `repl`. Blocks with no
+/// language are left alone, since they're as likely to be shell commands or output.
+pub fn doc_examples(src: &str) -> Vec<DocExample> {
+    let mut examples = Vec::new();
+    // The lines of the code block we're currently in, if any, and whether it's Roc code
+    let mut open_block: Option<(bool, Vec<(usize, usize)>)> = None;
+    let mut line_start = 0;
+
+    for line in src.split_inclusive('\n') {
+        let line_end = line_start + line.trim_end_matches(['\n', '\r']).len();
+        let content_start = doc_comment_content_start(line).map(|start| line_start + start);
+
+        line_start += line.len();
+
+        let Some(content_start) = content_start else {
+            // The doc comment ended before the code block was closed; leave it be.
+            open_block = None;
+            continue;
+        };
+
+        let content = &src[content_start..line_end];
+
+        match content.trim_start().strip_prefix("```") {
+            Some(info) => match open_block.take() {
+                None => open_block = Some((is_roc_code_block(info), Vec::new())),
+                Some((is_roc, lines)) => {
+                    if let (true, Some(&(first_start, _)), Some(&(_, last_end))) =
+                        (is_roc, lines.first(), lines.last())
+                    {
+                        let code = lines
+                            .iter()
+                            .map(|&(start, end)| &src[start..end])
+                            .collect::<Vec<_>>()
+                            .join("\n");
+
+                        examples.push(DocExample {
+                            code,
+                            region: Region::new(
+                                Position::new(first_start as u32),
+                                Position::new(last_end as u32),
+                            ),
+                            lines,
+                        });
+                    }
+                }
+            },
+            None => {
+                if let Some((_, lines)) = open_block.as_mut() {
+                    lines.push((content_start, line_end));
+                }
+            }
+        }
+    }
+
+    examples
+}
+
+/// Where the text of a doc comment line starts, after its `##` and the optional space following it
+fn doc_comment_content_start(line: &str) -> Option<usize> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    let after_hashes = line[indent..].strip_prefix("##")?;
+
+    if after_hashes.starts_with('#') {
+        return None;
+    }
+
+    Some(indent + 2 + usize::from(after_hashes.starts_with(' ')))
+}
+
+fn is_roc_code_block(info: &str) -> bool {
+    let mut words = info
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|word| !word.is_empty());
+
+    words.next() == Some("roc") && words.all(|word| word != "unchecked" && word != "repl")
+}
+
+/// Turns each doc example into a top-level `expect`, so `roc test` typechecks the example in the
+/// context of its module and runs the `expect`s in it.
+///
+/// Examples are parsed from a copy of the module's source with everything but the example's
+/// code blanked out, so every region in them points into the doc comment they came from.
+/// Statements and a final expression, which are usually there to show what an expression
+/// evaluates to, are typechecked but their values are ignored. The example's defs are renamed
+/// where they'd shadow the module's top-level defs.
+///
+/// Returns the regions of the examples that don't parse, which are left out, so that they can be
+/// reported without failing the rest of the module.
+pub fn add_doc_example_expects<'a>(
+    arena: &'a Bump,
+    src: &str,
+    defs: &mut ast::Defs<'a>,
+) -> Vec<Region> {
+    let examples = doc_examples(src);
+    let mut invalid_examples = Vec::new();
+
+    if examples.is_empty() {
+        return invalid_examples;
+    }
+
+    let top_level = ExampleRenamer::new(arena, MutMap::default());
+    for value_def in defs.value_defs.iter() {
+        match value_def {
+            ValueDef::Annotation(pattern, _) => {
+                top_level.pattern(arena.alloc(pattern.value));
+            }
+            ValueDef::Body(pattern, _)
+            | ValueDef::AnnotatedBody {
+                body_pattern: pattern,
+                ..
+            } => {
+                top_level.pattern(&pattern.value);
+            }
+            _ => {}
+        }
+    }
+    let top_level_names: MutSet<&str> = top_level.bound.into_inner().into_iter().collect();
+
+    for example in examples {
+        let mut blanked = String::with_capacity(example.region.end().offset as usize);
+
+        for &(start, end) in example.lines.iter() {
+            blanked.extend(
+                src.as_bytes()[blanked.len()..start]
+                    .iter()
+                    .map(|&byte| match byte {
+                        b'\n' | b'\r' => byte as char,
+                        _ => ' ',
+                    }),
+            );
+            blanked.push_str(&src[start..end]);
+        }
+
+        let blanked = arena.alloc_str(&blanked);
+
+        let (example_defs, final_expr) =
+            match parse_repl_defs_and_optional_expr(arena, State::new(blanked.as_bytes())) {
+                Ok((_, parsed, _)) => parsed,
+                Err(_) => {
+                    invalid_examples.push(example.region);
+                    continue;
+                }
+            };
+
+        let mut body_defs = ast::Defs::default();
+        let mut ignored = bumpalo::collections::Vec::new_in(arena);
+
+        for (index, def) in example_defs.defs().enumerate() {
+            let region = example_defs.regions[index];
+
+            match def {
+                Ok(type_def) => body_defs.push_type_def(*type_def, region, &[], &[]),
+                Err(ValueDef::Stmt(expr)) => ignored.push(*expr),
+                Err(value_def) => body_defs.push_value_def(*value_def, region, &[], &[]),
+            }
+        }
+
+        if let Some(expr) = final_expr {
+            ignored.push(&*arena.alloc(expr));
+        }
+
+        // The inline `expect`s are what get checked, so the example itself always passes. Its
+        // statements and final expression still get typechecked, as arguments to `|_| Bool.true`.
+        let end = Region::from_pos(example.region.end());
+        let mut passed = ast::Expr::Var {
+            module_name: "Bool",
+            ident: "true",
+        };
+
+        if !ignored.is_empty() {
+            let params = arena.alloc_slice_fill_iter(
+                ignored
+                    .iter()
+                    .map(|expr| Loc::at(expr.region, ast::Pattern::Underscore(""))),
+            );
+            let ignore = ast::Expr::Closure(params, arena.alloc(Loc::at(end, passed)));
+            let ignore = ast::Expr::ParensAround(arena.alloc(ignore));
+
+            passed = ast::Expr::PncApply(
+                arena.alloc(Loc::at(end, ignore)),
+                ast::Collection::with_items(ignored.into_bump_slice()),
+            );
+        }
+
+        let body_defs: &'a ast::Defs<'a> = arena.alloc(body_defs);
+        let example_expr: &'a ast::Expr<'a> = if body_defs.is_empty() {
+            arena.alloc(passed)
+        } else {
+            arena.alloc(ast::Expr::Defs(
+                body_defs,
+                arena.alloc(Loc::at(end, passed)),
+            ))
+        };
+
+        let example_names = ExampleRenamer::new(arena, MutMap::default());
+        example_names.defs(body_defs);
+        let example_names = example_names.bound.into_inner();
+
+        let mut renames = MutMap::default();
+        for &name in example_names.iter() {
+            if top_level_names.contains(name) && !renames.contains_key(name) {
+                let mut renamed = format!("{name}_example");
+                let mut suffix = 1;
+                while top_level_names.contains(renamed.as_str())
+                    || example_names.contains(&renamed.as_str())
+                {
+                    suffix += 1;
+                    renamed = format!("{name}_example{suffix}");
+                }
+
+                renames.insert(name, &*arena.alloc_str(&renamed));
+            }
+        }
+
+        let example_expr = if renames.is_empty() {
+            *example_expr
+        } else {
+            ExampleRenamer::new(arena, renames).expr(example_expr)
+        };
+        let condition = arena.alloc(Loc::at(example.region, example_expr));
+
+        defs.push_value_def(
+            ValueDef::Expect {
+                condition,
+                preceding_comment: Region::from_pos(example.region.start()),
+            },
+            example.region,
+            &[],
+            &[],
+        );
+    }
+
+    invalid_examples
+}
+
+/// Renames the identifiers in a doc example, so that the names it defines can't shadow the
+/// top-level defs of the module it's in. With no renames, it just collects the names it binds.
+struct ExampleRenamer<'a> {
+    arena: &'a Bump,
+    renames: MutMap<&'a str, &'a str>,
+    bound: RefCell<Vec<&'a str>>,
+}
+
+impl<'a> ExampleRenamer<'a> {
+    fn new(arena: &'a Bump, renames: MutMap<&'a str, &'a str>) -> Self {
+        Self {
+            arena,
+            renames,
+            bound: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn ident(&self, ident: &'a str) -> &'a str {
+        self.renames.get(ident).copied().unwrap_or(ident)
+    }
+
+    fn bind(&self, ident: &'a str) -> &'a str {
+        self.bound.borrow_mut().push(ident);
+
+        self.ident(ident)
+    }
+
+    fn defs(&self, defs: &'a ast::Defs<'a>) -> ast::Defs<'a> {
+        let mut renamed = defs.clone();
+        renamed.value_defs = defs
+            .value_defs
+            .iter()
+            .map(|value_def| self.value_def(value_def))
+            .collect();
+
+        renamed
+    }
+
+    fn value_def(&self, value_def: &'a ValueDef<'a>) -> ValueDef<'a> {
+        match *value_def {
+            ValueDef::Annotation(ref pattern, ann) => {
+                ValueDef::Annotation(Loc::at(pattern.region, self.pattern(&pattern.value)), ann)
+            }
+            ValueDef::Body(pattern, expr) => {
+                ValueDef::Body(self.loc_pattern(pattern), self.loc_expr(expr))
+            }
+            ValueDef::AnnotatedBody {
+                ann_pattern,
+                ann_type,
+                lines_between,
+                body_pattern,
+                body_expr,
+            } => ValueDef::AnnotatedBody {
+                ann_pattern: self.loc_pattern(ann_pattern),
+                ann_type,
+                lines_between,
+                body_pattern: self.loc_pattern(body_pattern),
+                body_expr: self.loc_expr(body_expr),
+            },
+            ValueDef::Dbg {
+                condition,
+                preceding_comment,
+            } => ValueDef::Dbg {
+                condition: self.loc_expr(condition),
+                preceding_comment,
+            },
+            ValueDef::Expect {
+                condition,
+                preceding_comment,
+            } => ValueDef::Expect {
+                condition: self.loc_expr(condition),
+                preceding_comment,
+            },
+            ValueDef::Stmt(expr) => ValueDef::Stmt(self.loc_expr(expr)),
+            ValueDef::ModuleImport(_)
+            | ValueDef::IngestedFileImport(_)
+            | ValueDef::StmtAfterExpr => *value_def,
+        }
+    }
+
+    fn loc_expr(&self, loc_expr: &'a Loc<ast::Expr<'a>>) -> &'a Loc<ast::Expr<'a>> {
+        self.arena
+            .alloc(Loc::at(loc_expr.region, self.expr(&loc_expr.value)))
+    }
+
+    fn loc_exprs(&self, loc_exprs: &'a [&'a Loc<ast::Expr<'a>>]) -> &'a [&'a Loc<ast::Expr<'a>>] {
+        self.arena
+            .alloc_slice_fill_iter(loc_exprs.iter().map(|loc_expr| self.loc_expr(loc_expr)))
+    }
+
+    fn expr(&self, expr: &'a ast::Expr<'a>) -> ast::Expr<'a> {
+        use ast::Expr::*;
+
+        let arena = self.arena;
+
+        match *expr {
+            Var {
+                module_name: "",
+                ident,
+            } => Var {
+                module_name: "",
+                ident: self.ident(ident),
+            },
+            Str(literal) => Str(self.str_literal(literal)),
+            RecordAccess(inner, field) => RecordAccess(arena.alloc(self.expr(inner)), field),
+            TupleAccess(inner, index) => TupleAccess(arena.alloc(self.expr(inner)), index),
+            TrySuffix(inner) => TrySuffix(arena.alloc(self.expr(inner))),
+            List(items) => List(items.map_items(arena, |item| self.loc_expr(item))),
+            RecordUpdate { update, fields } => RecordUpdate {
+                update: self.loc_expr(update),
+                fields: self.fields(fields),
+            },
+            Record(fields) => Record(self.fields(fields)),
+            Tuple(items) => Tuple(items.map_items(arena, |item| self.loc_expr(item))),
+            RecordBuilder { mapper, fields } => RecordBuilder {
+                mapper: self.loc_expr(mapper),
+                fields: self.fields(fields),
+            },
+            Closure(patterns, body) => Closure(self.patterns(patterns), self.loc_expr(body)),
+            Defs(defs, continuation) => {
+                Defs(arena.alloc(self.defs(defs)), self.loc_expr(continuation))
+            }
+            DbgStmt {
+                first,
+                extra_args,
+                continuation,
+                pnc_style,
+            } => DbgStmt {
+                first: self.loc_expr(first),
+                extra_args: self.loc_exprs(extra_args),
+                continuation: self.loc_expr(continuation),
+                pnc_style,
+            },
+            LowLevelTry(inner, kind) => LowLevelTry(self.loc_expr(inner), kind),
+            Apply(function, args, called_via) => {
+                Apply(self.loc_expr(function), self.loc_exprs(args), called_via)
+            }
+            PncApply(function, args) => PncApply(
+                self.loc_expr(function),
+                args.map_items(arena, |arg| self.loc_expr(arg)),
+            ),
+            BinOps(operands, last) => BinOps(
+                arena.alloc_slice_fill_iter(operands.iter().map(|(operand, op)| {
+                    (Loc::at(operand.region, self.expr(&operand.value)), *op)
+                })),
+                self.loc_expr(last),
+            ),
+            UnaryOp(operand, op) => UnaryOp(self.loc_expr(operand), op),
+            If {
+                if_thens,
+                final_else,
+                indented_else,
+            } => If {
+                if_thens: arena.alloc_slice_fill_iter(if_thens.iter().map(|(cond, then)| {
+                    (
+                        Loc::at(cond.region, self.expr(&cond.value)),
+                        Loc::at(then.region, self.expr(&then.value)),
+                    )
+                })),
+                final_else: self.loc_expr(final_else),
+                indented_else,
+            },
+            When(cond, branches) => When(
+                self.loc_expr(cond),
+                arena.alloc_slice_fill_iter(branches.iter().map(|branch| {
+                    &*arena.alloc(ast::WhenBranch {
+                        patterns: self.patterns(branch.patterns),
+                        value: Loc::at(branch.value.region, self.expr(&branch.value.value)),
+                        guard: branch
+                            .guard
+                            .as_ref()
+                            .map(|guard| Loc::at(guard.region, self.expr(&guard.value))),
+                    })
+                })),
+            ),
+            Return(value, after) => Return(
+                self.loc_expr(value),
+                after.map(|after| self.loc_expr(after)),
+            ),
+            SpaceBefore(inner, spaces) => SpaceBefore(arena.alloc(self.expr(inner)), spaces),
+            SpaceAfter(inner, spaces) => SpaceAfter(arena.alloc(self.expr(inner)), spaces),
+            ParensAround(inner) => ParensAround(arena.alloc(self.expr(inner))),
+            LowLevelDbg(info, message, continuation) => {
+                LowLevelDbg(info, self.loc_expr(message), self.loc_expr(continuation))
+            }
+            EmptyRecordBuilder(inner) => EmptyRecordBuilder(self.loc_expr(inner)),
+            SingleFieldRecordBuilder(inner) => SingleFieldRecordBuilder(self.loc_expr(inner)),
+            OptionalFieldInRecordBuilder(label, inner) => {
+                OptionalFieldInRecordBuilder(label, self.loc_expr(inner))
+            }
+            Var { .. }
+            | Float(_)
+            | Num(_)
+            | NonBase10Int { .. }
+            | SingleQuote(_)
+            | AccessorFunction(_)
+            | RecordUpdater(_)
+            | Underscore(_)
+            | Crash
+            | Tag(_)
+            | OpaqueRef(_)
+            | Dbg
+            | Try
+            | MalformedIdent(_, _)
+            | PrecedenceConflict(_) => *expr,
+        }
+    }
+
+    fn str_literal(&self, literal: ast::StrLiteral<'a>) -> ast::StrLiteral<'a> {
+        match literal {
+            ast::StrLiteral::PlainLine(_) => literal,
+            ast::StrLiteral::Line(segments) => ast::StrLiteral::Line(self.str_segments(segments)),
+            ast::StrLiteral::Block(lines) => ast::StrLiteral::Block(
+                self.arena
+                    .alloc_slice_fill_iter(lines.iter().map(|line| self.str_segments(line))),
+            ),
+        }
+    }
+
+    fn str_segments(&self, segments: &'a [ast::StrSegment<'a>]) -> &'a [ast::StrSegment<'a>] {
+        self.arena
+            .alloc_slice_fill_iter(segments.iter().map(|segment| match segment {
+                ast::StrSegment::Interpolated(expr) => ast::StrSegment::Interpolated(Loc::at(
+                    expr.region,
+                    self.arena.alloc(self.expr(expr.value)),
+                )),
+                _ => *segment,
+            }))
+    }
+
+    fn fields(
+        &self,
+        fields: ast::Collection<'a, Loc<AssignedField<'a, ast::Expr<'a>>>>,
+    ) -> ast::Collection<'a, Loc<AssignedField<'a, ast::Expr<'a>>>> {
+        fields.map_items(self.arena, |field| {
+            Loc::at(field.region, self.field(&field.value))
+        })
+    }
+
+    fn field(
+        &self,
+        field: &'a AssignedField<'a, ast::Expr<'a>>,
+    ) -> AssignedField<'a, ast::Expr<'a>> {
+        match *field {
+            AssignedField::RequiredValue(label, spaces, value) => {
+                AssignedField::RequiredValue(label, spaces, self.loc_expr(value))
+            }
+            AssignedField::OptionalValue(label, spaces, value) => {
+                AssignedField::OptionalValue(label, spaces, self.loc_expr(value))
+            }
+            AssignedField::IgnoredValue(label, spaces, value) => {
+                AssignedField::IgnoredValue(label, spaces, self.loc_expr(value))
+            }
+            // `{ x }` is short for `{ x: x }`, and only the value gets renamed
+            AssignedField::LabelOnly(label) => match self.renames.get(label.value) {
+                Some(&ident) => AssignedField::RequiredValue(
+                    label,
+                    &[],
+                    self.arena.alloc(Loc::at(
+                        label.region,
+                        ast::Expr::Var {
+                            module_name: "",
+                            ident,
+                        },
+                    )),
+                ),
+                None => *field,
+            },
+            AssignedField::SpaceBefore(inner, spaces) => {
+                AssignedField::SpaceBefore(self.arena.alloc(self.field(inner)), spaces)
+            }
+            AssignedField::SpaceAfter(inner, spaces) => {
+                AssignedField::SpaceAfter(self.arena.alloc(self.field(inner)), spaces)
+            }
+        }
+    }
+
+    fn loc_pattern(&self, pattern: &'a Loc<ast::Pattern<'a>>) -> &'a Loc<ast::Pattern<'a>> {
+        self.arena
+            .alloc(Loc::at(pattern.region, self.pattern(&pattern.value)))
+    }
+
+    fn patterns(&self, patterns: &'a [Loc<ast::Pattern<'a>>]) -> &'a [Loc<ast::Pattern<'a>>] {
+        self.arena.alloc_slice_fill_iter(
+            patterns
+                .iter()
+                .map(|pattern| Loc::at(pattern.region, self.pattern(&pattern.value))),
+        )
+    }
+
+    fn pattern_collection(
+        &self,
+        patterns: ast::Collection<'a, Loc<ast::Pattern<'a>>>,
+    ) -> ast::Collection<'a, Loc<ast::Pattern<'a>>> {
+        patterns.map_items(self.arena, |pattern| {
+            Loc::at(pattern.region, self.pattern(&pattern.value))
+        })
+    }
+
+    fn pattern_as(&self, pattern_as: ast::PatternAs<'a>) -> ast::PatternAs<'a> {
+        ast::PatternAs {
+            spaces_before: pattern_as.spaces_before,
+            identifier: Loc::at(
+                pattern_as.identifier.region,
+                self.bind(pattern_as.identifier.value),
+            ),
+        }
+    }
+
+    fn pattern(&self, pattern: &'a ast::Pattern<'a>) -> ast::Pattern<'a> {
+        use ast::Pattern::*;
+
+        let arena = self.arena;
+
+        match *pattern {
+            Identifier { ident } => Identifier {
+                ident: self.bind(ident),
+            },
+            Apply(tag, args) => Apply(tag, self.patterns(args)),
+            PncApply(tag, args) => PncApply(tag, self.pattern_collection(args)),
+            RecordDestructure(fields) => RecordDestructure(fields.map_items(arena, |field| {
+                Loc::at(field.region, self.field_pattern(field.region, &field.value))
+            })),
+            RequiredField(label, inner) => RequiredField(label, self.loc_pattern(inner)),
+            // the label is also the name it binds, so this can't be renamed
+            OptionalField(label, default) => OptionalField(label, self.loc_expr(default)),
+            Tuple(items) => Tuple(self.pattern_collection(items)),
+            List(items) => List(self.pattern_collection(items)),
+            ListRest(Some((spaces, pattern_as))) => {
+                ListRest(Some((spaces, self.pattern_as(pattern_as))))
+            }
+            As(inner, pattern_as) => As(self.loc_pattern(inner), self.pattern_as(pattern_as)),
+            SpaceBefore(inner, spaces) => SpaceBefore(arena.alloc(self.pattern(inner)), spaces),
+            SpaceAfter(inner, spaces) => SpaceAfter(arena.alloc(self.pattern(inner)), spaces),
+            MalformedExpr(inner) => MalformedExpr(arena.alloc(self.expr(inner))),
+            QualifiedIdentifier { .. }
+            | Tag(_)
+            | OpaqueRef(_)
+            | NumLiteral(_)
+            | NonBase10Literal { .. }
+            | FloatLiteral(_)
+            | StrLiteral(_)
+            | Underscore(_)
+            | SingleQuote(_)
+            | ListRest(None)
+            | Malformed(_)
+            | MalformedIdent(_, _) => *pattern,
+        }
+    }
+
+    /// A field of a record destructure, where `{ x }` is short for `{ x: x }`
+    fn field_pattern(&self, region: Region, pattern: &'a ast::Pattern<'a>) -> ast::Pattern<'a> {
+        match *pattern {
+            ast::Pattern::Identifier { ident } => {
+                let renamed = self.bind(ident);
+
+                if renamed == ident {
+                    *pattern
+                } else {
+                    ast::Pattern::RequiredField(
+                        ident,
+                        self.arena
+                            .alloc(Loc::at(region, ast::Pattern::Identifier { ident: renamed })),
+                    )
+                }
+            }
+            ast::Pattern::SpaceBefore(inner, spaces) => ast::Pattern::SpaceBefore(
+                self.arena.alloc(self.field_pattern(region, inner)),
+                spaces,
+            ),
+            ast::Pattern::SpaceAfter(inner, spaces) => ast::Pattern::SpaceAfter(
+                self.arena.alloc(self.field_pattern(region, inner)),
+                spaces,
+            ),
+            _ => self.pattern(pattern),
+        }
+    }
+}
@@ -29,9 +29,9 @@ use roc_constrain::module::constrain_module;
 use roc_debug_flags::dbg_do;
 #[cfg(debug_assertions)]
 use roc_debug_flags::{
-    ROC_CHECK_MONO_IR, ROC_PRINT_IR_AFTER_DROP_SPECIALIZATION, ROC_PRINT_IR_AFTER_REFCOUNT,
-    ROC_PRINT_IR_AFTER_RESET_REUSE, ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_IR_AFTER_TRMC,
-    ROC_PRINT_LOAD_LOG,
+    ROC_CHECK_MONO_IR, ROC_PRINT_IR_AFTER_CONST_EVAL, ROC_PRINT_IR_AFTER_DROP_SPECIALIZATION,
+    ROC_PRINT_IR_AFTER_OPTIMIZE, ROC_PRINT_IR_AFTER_REFCOUNT, ROC_PRINT_IR_AFTER_RESET_REUSE,
+    ROC_PRINT_IR_AFTER_SPECIALIZATION, ROC_PRINT_IR_AFTER_TRMC, ROC_PRINT_LOAD_LOG,
 };
 use roc_derive::SharedDerivedModule;
 use roc_error_macros::internal_error;
@@ -41,9 +41,10 @@ use roc_module::symbol::{
     IdentIds, IdentIdsByModule, Interns, ModuleId, ModuleIds, PQModuleName, PackageModuleIds,
     PackageQualified, Symbol,
 };
+use roc_mono::in_place::CallSites;
 use roc_mono::ir::{
-    CapturedSymbols, ExternalSpecializations, GlueLayouts, HostExposedLambdaSets, PartialProc,
-    Proc, ProcLayout, Procs, ProcsBase, UpdateModeIds, UsageTrackingMap,
+    CapturedSymbols, ExternalSpecializations, GlueLayouts, HostExposedLambdaSets, OptLevel,
+    PartialProc, Proc, ProcLayout, Procs, ProcsBase, UpdateModeIds, UsageTrackingMap,
 };
 use roc_mono::layout::{
     GlobalLayoutInterner, LambdaName, Layout, LayoutCache, LayoutProblem, Niche, STLayoutInterner,
@@ -116,6 +117,9 @@ pub struct LoadConfig {
     pub threading: Threading,
     pub exec_mode: ExecutionMode,
     pub function_kind: FunctionKind,
+    /// How much to optimize the mono IR before it's handed to a backend. `None` leaves the IR
+    /// exactly as it was specialized.
+    pub opt_level: Option<OptLevel>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -185,6 +189,7 @@ fn start_phase<'a>(
                         let module_name = dep_name.clone();
 
                         BuildTask::LoadModule {
+                            module_id,
                             module_name,
                             // Provide mutexes of ModuleIds and IdentIds by module,
                             // so other modules can populate them as they load.
@@ -199,12 +204,21 @@ fn start_phase<'a>(
                 // parse the file
                 let header = state.module_cache.headers.remove(&module_id).unwrap();
 
+                // `roc test` also checks the examples in the doc comments of the package under test
+                let include_doc_examples = matches!(state.exec_mode, ExecutionMode::Test) && {
+                    let modules = state.arc_modules.lock();
+                    modules
+                        .package_eq(module_id, state.root_id)
+                        .expect("root or this module is not yet known - that's a bug!")
+                };
+
                 BuildTask::Parse {
                     header,
                     arc_shorthands: Arc::clone(&state.arc_shorthands),
                     module_ids: Arc::clone(&state.arc_modules),
                     ident_ids_by_module: Arc::clone(&state.ident_ids_by_module),
                     root_type: state.root_type.clone(),
+                    include_doc_examples,
                 }
             }
             Phase::SoloCanonicalize => {
@@ -631,6 +645,7 @@ enum Msg<'a> {
         module_timing: ModuleTiming,
         toplevel_expects: ToplevelExpects,
         expectations: Option<Expectations>,
+        constants: MutMap<Symbol, Region>,
     },
     MadeSpecializations {
         module_id: ModuleId,
@@ -640,6 +655,7 @@ enum Msg<'a> {
         procs_base: ProcsBase<'a>,
         procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
         host_exposed_lambda_sets: HostExposedLambdaSets<'a>,
+        call_sites: CallSites,
         update_mode_ids: UpdateModeIds,
         module_timing: ModuleTiming,
         subs: Subs,
@@ -740,6 +756,7 @@ struct State<'a> {
     pub dependencies: Dependencies<'a>,
     pub procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
     pub host_exposed_lambda_sets: HostExposedLambdaSets<'a>,
+    pub call_sites: CallSites,
     pub toplevel_expects: MutMap<ModuleId, ToplevelExpects>,
     pub exposed_to_host: ExposedToHost,
 
@@ -770,6 +787,7 @@ struct State<'a> {
     pub render: RenderTarget,
     pub palette: Palette,
     pub exec_mode: ExecutionMode,
+    pub opt_level: Option<OptLevel>,
 
     /// All abilities across all modules.
     pub world_abilities: WorldAbilities,
@@ -805,6 +823,7 @@ impl<'a> State<'a> {
         palette: Palette,
         number_of_workers: usize,
         exec_mode: ExecutionMode,
+        opt_level: Option<OptLevel>,
     ) -> Self {
         let cache_dir = roc_packaging::cache::roc_cache_packages_dir();
         let dependencies = Dependencies::new(exec_mode.goal_phase());
@@ -824,6 +843,7 @@ impl<'a> State<'a> {
             dependencies,
             procedures: MutMap::default(),
             host_exposed_lambda_sets: std::vec::Vec::new(),
+            call_sites: CallSites::default(),
             toplevel_expects: MutMap::default(),
             exposed_to_host: ExposedToHost::default(),
             exposed_modules: &[],
@@ -841,6 +861,7 @@ impl<'a> State<'a> {
             render,
             palette,
             exec_mode,
+            opt_level,
             make_specializations_pass: MakeSpecializationsPass::Pass(1),
             world_abilities: Default::default(),
             layout_interner: GlobalLayoutInterner::with_capacity(128, target),
@@ -897,6 +918,7 @@ impl std::fmt::Display for ModuleTiming {
 #[derive(Debug)]
 enum BuildTask<'a> {
     LoadModule {
+        module_id: ModuleId,
         module_name: PQModuleName<'a>,
         module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
         shorthands: Arc<Mutex<MutMap<&'a str, ShorthandPath>>>,
@@ -908,6 +930,7 @@ enum BuildTask<'a> {
         module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
         ident_ids_by_module: SharedIdentIdsByModule,
         root_type: RootType,
+        include_doc_examples: bool,
     },
     SoloCanonicalize {
         parsed: ParsedModule<'a>,
@@ -1144,6 +1167,7 @@ pub fn load_and_typecheck_str<'a>(
         threading,
         exec_mode: ExecutionMode::Check,
         function_kind,
+        opt_level: None,
     };
 
     match load(
@@ -1563,6 +1587,7 @@ pub fn load<'a>(
             load_config.render,
             load_config.palette,
             load_config.exec_mode,
+            load_config.opt_level,
             roc_cache_dir,
         ),
         Threads::Many(threads) => load_multi_threaded(
@@ -1576,6 +1601,7 @@ pub fn load<'a>(
             load_config.palette,
             threads,
             load_config.exec_mode,
+            load_config.opt_level,
             roc_cache_dir,
         ),
     }
@@ -1592,6 +1618,7 @@ pub fn load_single_threaded<'a>(
     render: RenderTarget,
     palette: Palette,
     exec_mode: ExecutionMode,
+    opt_level: Option<OptLevel>,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -1630,6 +1657,7 @@ pub fn load_single_threaded<'a>(
         palette,
         number_of_workers,
         exec_mode,
+        opt_level,
     );
 
     // We'll add tasks to this, and then worker threads will take tasks from it.
@@ -1973,6 +2001,7 @@ fn load_multi_threaded<'a>(
     palette: Palette,
     available_threads: usize,
     exec_mode: ExecutionMode,
+    opt_level: Option<OptLevel>,
     roc_cache_dir: RocCacheDir<'_>,
 ) -> Result<LoadResult<'a>, LoadingProblem<'a>> {
     let LoadStart {
@@ -2026,6 +2055,7 @@ fn load_multi_threaded<'a>(
         palette,
         num_workers,
         exec_mode,
+        opt_level,
     );
 
     // an arena for every worker, stored in an arena-allocated bumpalo vec to make the lifetimes work
@@ -2389,6 +2419,8 @@ fn update<'a>(
                 extend_module_with_builtin_import(parsed, ModuleId::DECODE);
                 extend_module_with_builtin_import(parsed, ModuleId::HASH);
                 extend_module_with_builtin_import(parsed, ModuleId::INSPECT);
+                extend_module_with_builtin_import(parsed, ModuleId::SORT);
+                extend_module_with_builtin_import(parsed, ModuleId::DEFAULT);
             }
             state
                 .module_cache
@@ -2719,6 +2751,7 @@ fn update<'a>(
             module_timing,
             toplevel_expects,
             expectations,
+            constants,
         } => {
             log!("found specializations for {:?}", module_id);
 
@@ -2735,6 +2768,8 @@ fn update<'a>(
                 .or_default()
                 .extend(procs_base.module_thunks.iter().copied());
 
+            state.module_cache.top_level_constants.extend(constants);
+
             let found_specializations_module = FoundSpecializationsModule {
                 ident_ids,
                 layout_cache,
@@ -2765,6 +2800,7 @@ fn update<'a>(
             procs_base,
             procedures,
             host_exposed_lambda_sets,
+            call_sites,
             external_specializations_requested,
             module_timing,
             layout_cache,
@@ -2785,6 +2821,7 @@ fn update<'a>(
             state
                 .host_exposed_lambda_sets
                 .extend(host_exposed_lambda_sets);
+            state.call_sites.extend(call_sites);
             state.module_cache.late_specializations.insert(
                 module_id,
                 LateSpecializationsModule {
@@ -2900,6 +2937,62 @@ fn update<'a>(
                     debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_SPECIALIZATION);
                     debug_check_ir!(state, arena, layout_interner, ROC_CHECK_MONO_IR);
 
+                    // Every build reports constants that crash, but folding the others into the
+                    // IR only pays off in optimized builds
+                    let fold = matches!(
+                        state.opt_level,
+                        Some(OptLevel::Normal | OptLevel::Size | OptLevel::Optimize)
+                    );
+                    let constants = state
+                        .module_cache
+                        .top_level_constants
+                        .keys()
+                        .copied()
+                        .collect();
+                    let crashes = roc_mono::const_eval::fold_top_level_constants(
+                        arena,
+                        &mut layout_interner,
+                        &mut state.constrained_ident_ids,
+                        &constants,
+                        &mut state.procedures,
+                        fold,
+                    );
+
+                    for crash in crashes {
+                        let problem = roc_problem::can::Problem::ConstantCrashed {
+                            symbol: crash.symbol,
+                            region: state.module_cache.top_level_constants[&crash.symbol],
+                            message: crash.message,
+                        };
+
+                        state
+                            .module_cache
+                            .can_problems
+                            .entry(crash.symbol.module_id())
+                            .or_default()
+                            .push(problem);
+                    }
+
+                    debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_CONST_EVAL);
+
+                    if let Some(opt_level) = state.opt_level {
+                        let ident_ids = state.constrained_ident_ids.get_mut(&module_id).unwrap();
+
+                        roc_mono::optimize::optimize_procs(
+                            arena,
+                            &layout_interner,
+                            module_id,
+                            ident_ids,
+                            &mut update_mode_ids,
+                            &mut state.call_sites,
+                            opt_level,
+                            &mut state.procedures,
+                        );
+
+                        debug_print_ir!(state, &layout_interner, ROC_PRINT_IR_AFTER_OPTIMIZE);
+                        debug_check_ir!(state, arena, layout_interner, ROC_CHECK_MONO_IR);
+                    }
+
                     let ident_ids = state.constrained_ident_ids.get_mut(&module_id).unwrap();
 
                     roc_mono::tail_recursion::apply_trmc(
@@ -3287,6 +3380,7 @@ fn finish_specialization<'a>(
         toplevel_expects,
         procedures,
         host_exposed_lambda_sets,
+        call_sites,
         module_cache,
         platform_data,
         ..
@@ -3323,6 +3417,7 @@ fn finish_specialization<'a>(
         layout_interner,
         procedures,
         host_exposed_lambda_sets,
+        call_sites,
         entry_point,
         sources,
         timings: state.timings,
@@ -3677,6 +3772,7 @@ fn load_builtin_module<'a>(
 fn load_module<'a>(
     arena: &'a Bump,
     src_dir: &Path,
+    module_id: ModuleId,
     module_name: PQModuleName<'a>,
     module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
     arc_shorthands: Arc<Mutex<MutMap<&'a str, ShorthandPath>>>,
@@ -3694,11 +3790,12 @@ fn load_module<'a>(
     module_timing.read_roc_file = Default::default();
     module_timing.parse_header = parse_header_duration;
 
+    // A package's own module may shadow the name of a builtin, so builtins are matched by id.
     macro_rules! load_builtins {
         ($($name:literal, $module_id:path)*) => {
-            match module_name.unqualified().map(|name| name.as_str()) {
+            match module_id {
             $(
-                Some($name) => {
+                $module_id => {
                     let (module_id, msg) = load_builtin_module(
                         arena,
                         module_ids,
@@ -3727,6 +3824,8 @@ fn load_module<'a>(
         "Decode", ModuleId::DECODE
         "Hash", ModuleId::HASH
         "Inspect", ModuleId::INSPECT
+        "Sort", ModuleId::SORT
+        "Default", ModuleId::DEFAULT
     }
 
     let (filename, opt_shorthand) = module_name_to_path(src_dir, &module_name, arc_shorthands);
@@ -4358,7 +4457,14 @@ fn build_header<'a>(
             Some(shorthand) => PQModuleName::Qualified(shorthand, declared_name),
             None => PQModuleName::Unqualified(declared_name),
         };
-        let home = module_ids.get_or_insert(&name);
+        let home = match header_type {
+            // A package's own module may shadow the name of a builtin,
+            // so builtins are only looked up among the builtins.
+            HeaderType::Builtin { .. } => module_ids
+                .get_builtin_id(name.as_inner())
+                .unwrap_or_else(|| internal_error!("Unknown builtin module {:?}", name)),
+            _ => module_ids.get_or_insert(&name),
+        };
 
         (name, home)
     };
@@ -5184,6 +5290,7 @@ fn canonicalize_and_constrain<'a>(
         symbols_from_requires,
         opt_shorthand,
         exposed_ident_ids,
+        invalid_doc_examples,
         ..
     } = parsed;
 
@@ -5242,6 +5349,12 @@ fn canonicalize_and_constrain<'a>(
         solo_can_output.module_params,
     );
 
+    module_output.problems.extend(
+        invalid_doc_examples
+            .into_iter()
+            .map(roc_problem::can::Problem::InvalidDocExample),
+    );
+
     let mut types = Types::new();
 
     // _after has an underscore because it's unused in --release builds
@@ -5351,6 +5464,8 @@ fn canonicalize_and_constrain<'a>(
                         | ModuleId::SET
                         | ModuleId::HASH
                         | ModuleId::INSPECT
+                        | ModuleId::SORT
+                        | ModuleId::DEFAULT
                 );
 
                 if !name.is_builtin() || should_include_builtin {
@@ -5396,11 +5511,13 @@ fn canonicalize_and_constrain<'a>(
 
 fn parse<'a>(
     arena: &'a Bump,
+    src_dir: &Path,
     header: ModuleHeader<'a>,
     arc_shorthands: Arc<Mutex<MutMap<&'a str, ShorthandPath>>>,
     module_ids: Arc<Mutex<PackageModuleIds<'a>>>,
     ident_ids_by_module: SharedIdentIdsByModule,
     root_type: RootType,
+    include_doc_examples: bool,
 ) -> Result<Msg<'a>, LoadingProblem<'a>> {
     let mut module_timing = header.module_timing;
     let parse_start = Instant::now();
@@ -5410,7 +5527,7 @@ fn parse<'a>(
     let header_import_defs =
         roc_parse::ast::Header::header_imports_to_defs(arena, header.header_imports);
 
-    let parsed_defs = match parse_module_defs(arena, parse_state.clone(), header_import_defs) {
+    let mut parsed_defs = match parse_module_defs(arena, parse_state.clone(), header_import_defs) {
         Ok(success) => success,
         Err(fail) => {
             return Err(LoadingProblem::ParsingFailed(
@@ -5424,6 +5541,12 @@ fn parse<'a>(
     // we'd have bailed out before now.
     let src = unsafe { from_utf8_unchecked(source) };
 
+    let invalid_doc_examples = if include_doc_examples {
+        crate::docs::add_doc_example_expects(arena, src, &mut parsed_defs)
+    } else {
+        Vec::new()
+    };
+
     // Record the parse end time once, to avoid checking the time a second time
     // immediately afterward (for the beginning of canonicalization).
     let parse_end = Instant::now();
@@ -5495,19 +5618,43 @@ fn parse<'a>(
 
     let mut exposed: Vec<Symbol> = Vec::with_capacity(num_exposes);
 
+    // A module in this package with the same name as a shadowable builtin takes its place.
+    let shadowed_builtins: Vec<ModuleId> = imported
+        .iter()
+        .filter(|(qualified_module_name, _)| qualified_module_name.opt_package.is_none())
+        .filter_map(|(qualified_module_name, _)| {
+            let builtin = ModuleId::shadowable_builtin(qualified_module_name.module.as_str())?;
+            let own_module_name = match header.opt_shorthand {
+                Some(shorthand) => {
+                    PQModuleName::Qualified(shorthand, qualified_module_name.module.clone())
+                }
+                None => PQModuleName::Unqualified(qualified_module_name.module.clone()),
+            };
+            let (filename, _) =
+                module_name_to_path(src_dir, &own_module_name, Arc::clone(&arc_shorthands));
+
+            filename.is_file().then_some(builtin)
+        })
+        .collect();
+
     // Make sure the module_ids has ModuleIds for all our deps,
     // then record those ModuleIds in can_module_ids for later.
     {
         // Lock just long enough to perform the minimal operations necessary.
         let mut module_ids = module_ids.lock();
 
+        for builtin in shadowed_builtins {
+            module_ids.shadow_builtin(header.opt_shorthand, builtin);
+        }
+
         // For each of our imports, add an entry to deps_by_name
         //
         // e.g. for `import pf.Foo exposing [bar]`, add `Foo` to deps_by_name
         //
         // Also build a list of imported_values_to_expose (like `bar` above.)
         for (qualified_module_name, region) in imported.into_iter() {
-            let pq_module_name = qualified_module_name.into_pq_module_name(header.opt_shorthand);
+            let pq_module_name =
+                module_ids.import_name(qualified_module_name, header.opt_shorthand);
             let module_id = module_ids.get_or_insert(&pq_module_name);
 
             available_modules.insert(module_id, region);
@@ -5692,6 +5839,7 @@ fn parse<'a>(
         header_type,
         header_comments: header_docs,
         opt_shorthand: header.opt_shorthand,
+        invalid_doc_examples,
     };
 
     Ok(Msg::Parsed(parsed))
@@ -5758,6 +5906,7 @@ fn make_specializations<'a>(
     );
 
     let external_specializations_requested = procs.externals_we_need.clone();
+    let call_sites = std::mem::take(&mut procs.call_sites);
     let (procedures, host_exposed_lambda_sets, restored_procs_base) =
         procs.get_specialized_procs_without_rc();
 
@@ -5776,6 +5925,7 @@ fn make_specializations<'a>(
         procs_base: restored_procs_base,
         procedures,
         host_exposed_lambda_sets,
+        call_sites,
         update_mode_ids,
         subs,
         expectations,
@@ -5804,6 +5954,7 @@ fn build_pending_specializations<'a>(
     let find_specializations_start = Instant::now();
 
     let mut module_thunks = bumpalo::collections::Vec::new_in(arena);
+    let mut constants = MutMap::default();
     let mut toplevel_expects = ToplevelExpects::default();
 
     let mut procs_base = ProcsBase {
@@ -5914,6 +6065,12 @@ fn build_pending_specializations<'a>(
                         // mark this symbols as a top-level thunk before any other work on the procs
                         module_thunks.push(symbol);
 
+                        if !is_host_exposed {
+                            let def_region = declarations.symbols[index].region;
+                            constants
+                                .insert(symbol, Region::span_across(&def_region, &body.region));
+                        }
+
                         let proc = PartialProc {
                             annotation: expr_var,
                             // This is a 0-arity thunk, so it has no arguments.
@@ -6014,6 +6171,13 @@ fn build_pending_specializations<'a>(
                 // mark this symbols as a top-level thunk before any other work on the procs
                 module_thunks.push(symbol);
 
+                if !is_host_exposed {
+                    constants.insert(
+                        symbol,
+                        Region::span_across(&loc_pattern.region, &body.region),
+                    );
+                }
+
                 // If this is an exposed symbol, we need to
                 // register it as such. Otherwise, since it
                 // never gets called by Roc code, it will never
@@ -6159,6 +6323,7 @@ fn build_pending_specializations<'a>(
         module_timing,
         toplevel_expects,
         expectations,
+        constants,
     }
 }
 
@@ -6311,6 +6476,7 @@ fn run_task<'a>(
 
     let msg_result = match task {
         LoadModule {
+            module_id,
             module_name,
             module_ids,
             shorthands,
@@ -6318,6 +6484,7 @@ fn run_task<'a>(
         } => load_module(
             arena,
             src_dir,
+            module_id,
             module_name,
             module_ids,
             shorthands,
@@ -6331,13 +6498,16 @@ fn run_task<'a>(
             module_ids,
             ident_ids_by_module,
             root_type,
+            include_doc_examples,
         } => parse(
             arena,
+            src_dir,
             header,
             arc_shorthands,
             module_ids,
             ident_ids_by_module,
             root_type,
+            include_doc_examples,
         ),
         SoloCanonicalize { parsed } => {
             let module_id = parsed.module_id;
@@ -6557,6 +6727,7 @@ fn to_import_cycle_report(
         filename,
         doc,
         title: "IMPORT CYCLE".to_string(),
+        code: Some("E0418"),
         severity: Severity::RuntimeError,
     };
 
@@ -6608,6 +6779,7 @@ fn to_incorrect_module_name_report<'a>(
         filename,
         doc,
         title: "INCORRECT MODULE NAME".to_string(),
+        code: Some("E0419"),
         severity,
     };
 
@@ -6656,6 +6828,7 @@ fn to_no_platform_package_report(
         filename,
         doc,
         title: "UNSPECIFIED PLATFORM".to_string(),
+        code: Some("E0420"),
         severity,
     };
 
@@ -6700,6 +6873,7 @@ fn to_multiple_platform_packages_report(
         filename,
         doc,
         title: "MULTIPLE PLATFORMS".to_string(),
+        code: Some("E0421"),
         severity,
     };
 
@@ -6807,6 +6981,7 @@ fn to_unrecognized_package_shorthand_report(
         filename,
         doc,
         title: "UNRECOGNIZED PACKAGE".to_string(),
+        code: Some("E0423"),
         severity,
     };
 
@@ -6890,6 +7065,7 @@ fn report_cannot_run(
                     filename,
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: Some("E0422"),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -6905,6 +7081,7 @@ fn report_cannot_run(
                     filename,
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: Some("E0422"),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -6920,6 +7097,7 @@ fn report_cannot_run(
                     filename,
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: Some("E0422"),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -6935,6 +7113,7 @@ fn report_cannot_run(
                     filename,
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    code: Some("E0422"),
                     severity: Severity::RuntimeError,
                 }
             }
@@ -25,4 +25,6 @@ pub const BUILTIN_MODULES: &[(ModuleId, &str)] = &[
     (ModuleId::DECODE, "Decode"),
     (ModuleId::HASH, "Hash"),
     (ModuleId::INSPECT, "Inspect"),
+    (ModuleId::SORT, "Sort"),
+    (ModuleId::DEFAULT, "Default"),
 ];
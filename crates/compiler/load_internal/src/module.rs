@@ -11,6 +11,7 @@ use roc_module::ident::Ident;
 use roc_module::symbol::{
     IdentIds, IdentIdsByModule, Interns, ModuleId, PQModuleName, PackageQualified, Symbol,
 };
+use roc_mono::in_place::CallSites;
 use roc_mono::ir::{GlueLayouts, HostExposedLambdaSets, LambdaSetId, Proc, ProcLayout, ProcsBase};
 use roc_mono::layout::{LayoutCache, STLayoutInterner};
 use roc_parse::ast::{CommentOrNewline, Defs, TypeAnnotation};
@@ -179,6 +180,8 @@ pub struct MonomorphizedModule<'a> {
     pub type_problems: MutMap<ModuleId, Vec<TypeError>>,
     pub procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
     pub host_exposed_lambda_sets: HostExposedLambdaSets<'a>,
+    /// Where user code makes calls, see [roc_mono::in_place] and [roc_mono::alloc_profile].
+    pub call_sites: CallSites,
     pub toplevel_expects: MutMap<ModuleId, ToplevelExpects>,
     pub entry_point: EntryPoint<'a>,
     pub exposed_to_host: ExposedToHost,
@@ -207,6 +210,8 @@ pub struct ParsedModule<'a> {
     pub initial_scope: MutMap<Ident, (Symbol, Region)>,
     pub exposes: Vec<Symbol>,
     pub opt_shorthand: Option<&'a str>,
+    /// The doc examples that didn't parse, when doc examples are being checked
+    pub invalid_doc_examples: Vec<Region>,
 }
 
 #[derive(Debug)]
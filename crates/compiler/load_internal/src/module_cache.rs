@@ -42,6 +42,8 @@ pub(crate) struct ModuleCache<'a> {
     pub(crate) exposes: MutMap<ModuleId, Vec<(Symbol, Variable)>>,
     pub(crate) exposed_imports: MutMap<ModuleId, MutMap<Symbol, Region>>,
     pub(crate) top_level_thunks: MutMap<ModuleId, MutSet<Symbol>>,
+    /// Top-level thunks that may be evaluated at compile time, with the region of their def
+    pub(crate) top_level_constants: MutMap<Symbol, Region>,
     pub(crate) documentation: VecMap<ModuleId, ModuleDocumentation>,
     pub(crate) can_problems: MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     pub(crate) type_problems: MutMap<ModuleId, Vec<TypeError>>,
@@ -110,6 +112,8 @@ impl Default for ModuleCache<'_> {
             DECODE,
             HASH,
             INSPECT,
+            SORT,
+            DEFAULT,
         }
 
         Self {
@@ -130,6 +134,7 @@ impl Default for ModuleCache<'_> {
             exposed_imports: Default::default(),
             exposes: Default::default(),
             top_level_thunks: Default::default(),
+            top_level_constants: Default::default(),
             documentation: Default::default(),
             can_problems: Default::default(),
             type_problems: Default::default(),
@@ -16,6 +16,7 @@ mod helpers;
 
 use crate::helpers::fixtures_dir;
 use bumpalo::Bump;
+use roc_can::expr::DeclarationTag;
 use roc_can::module::ExposedByModule;
 use roc_load_internal::docs::DocDef;
 use roc_load_internal::file::{
@@ -63,6 +64,7 @@ fn load_and_typecheck(
         palette: DEFAULT_PALETTE,
         threading: Threading::Single,
         exec_mode: ExecutionMode::Check,
+        opt_level: None,
     };
 
     match roc_load_internal::file::load(
@@ -78,6 +80,35 @@ fn load_and_typecheck(
     }
 }
 
+fn load_for_test(arena: &Bump, filename: PathBuf) -> Result<LoadResult, LoadingProblem> {
+    let load_start = LoadStart::from_path(
+        arena,
+        filename,
+        None,
+        RenderTarget::Generic,
+        RocCacheDir::Disallowed,
+        DEFAULT_PALETTE,
+    )?;
+    let load_config = LoadConfig {
+        target: TARGET,
+        function_kind: FunctionKind::LambdaSet,
+        render: RenderTarget::Generic,
+        palette: DEFAULT_PALETTE,
+        threading: Threading::Single,
+        exec_mode: ExecutionMode::Test,
+        opt_level: None,
+    };
+
+    roc_load_internal::file::load(
+        arena,
+        load_start,
+        Default::default(),
+        Default::default(), // these tests will re-compile the builtins
+        RocCacheDir::Disallowed,
+        load_config,
+    )
+}
+
 const TARGET: Target = Target::LinuxX64;
 
 // HELPERS
@@ -754,7 +785,7 @@ fn parse_problem() {
             report,
             indoc!(
                 "
-                    ── UNFINISHED LIST in tmp/parse_problem/Main.roc ───────────────────────────────
+                    ── UNFINISHED LIST [E0131] in tmp/parse_problem/Main.roc ───────────────────────
 
                     I am partway through started parsing a list, but I got stuck here:
 
@@ -919,7 +950,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
         err,
         indoc!(
             r"
-                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE in ...d_outside_defining_module/Main.roc ─
+                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE [E0226] in ...e_defining_module/Main.roc ─
 
                 The unwrapped opaque type Age referenced here:
 
@@ -933,7 +964,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
 
                 Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
 
-                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE in ...d_outside_defining_module/Main.roc ─
+                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE [E0226] in ...e_defining_module/Main.roc ─
 
                 The unwrapped opaque type Age referenced here:
 
@@ -947,7 +978,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
 
                 Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
 
-                ── UNUSED IMPORT in ...aque_wrapped_unwrapped_outside_defining_module/Main.roc ─
+                ── UNUSED IMPORT [E0204] in ...pped_unwrapped_outside_defining_module/Main.roc ─
 
                 Age is imported but not used.
 
@@ -1034,7 +1065,7 @@ fn unused_imports() {
         err,
         indoc!(
             r"
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [E0204] in tmp/unused_imports/Main.roc ────────────────────────
 
             Dep2 is imported but not used.
 
@@ -1043,7 +1074,7 @@ fn unused_imports() {
 
             Since Dep2 isn't used, you don't need to import it.
 
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [E0204] in tmp/unused_imports/Main.roc ────────────────────────
 
             Dep2 is imported but not used.
 
@@ -1052,7 +1083,7 @@ fn unused_imports() {
 
             Since Dep2 isn't used, you don't need to import it.
 
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [E0204] in tmp/unused_imports/Main.roc ────────────────────────
 
             Dep2 is imported but not used.
 
@@ -1061,7 +1092,7 @@ fn unused_imports() {
 
             Since Dep2 isn't used, you don't need to import it.
 
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [E0204] in tmp/unused_imports/Main.roc ────────────────────────
 
             Dep1 is imported but not used.
 
@@ -1070,7 +1101,7 @@ fn unused_imports() {
 
             Since Dep1 isn't used, you don't need to import it.
 
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [E0204] in tmp/unused_imports/Main.roc ────────────────────────
 
             `Dep3.Three` is not used in this module.
 
@@ -1136,7 +1167,7 @@ fn explicit_builtin_import() {
         err,
         indoc!(
             r"
-            ── EXPLICIT BUILTIN IMPORT in tmp/explicit_builtin_import/Main.roc ─────────────
+            ── EXPLICIT BUILTIN IMPORT [E0206] in tmp/explicit_builtin_import/Main.roc ─────
 
             The builtin Bool was imported here:
 
@@ -1171,7 +1202,7 @@ fn explicit_builtin_import_empty_exposing() {
         err,
         indoc!(
             r"
-            ── EXPLICIT BUILTIN IMPORT in tmp/empty_exposing_builtin_import/Main.roc ───────
+            ── EXPLICIT BUILTIN IMPORT [E0206] in ...mpty_exposing_builtin_import/Main.roc ─
 
             The builtin Bool was imported here:
 
@@ -1210,7 +1241,7 @@ fn explicit_builtin_type_import() {
         err,
         indoc!(
             r"
-            ── EXPLICIT BUILTIN IMPORT in tmp/explicit_builtin_type_import/Main.roc ────────
+            ── EXPLICIT BUILTIN IMPORT [E0206] in ...explicit_builtin_type_import/Main.roc ─
 
             `Dict.Dict` was imported here:
 
@@ -1260,7 +1291,7 @@ fn import_shadows_symbol() {
         err,
         indoc!(
             r"
-            ── DUPLICATE NAME in tmp/import_shadows_symbol/Main.roc ────────────────────────
+            ── DUPLICATE NAME [E0217] in tmp/import_shadows_symbol/Main.roc ────────────────
 
             This import exposes `One.one`:
 
@@ -1274,7 +1305,7 @@ fn import_shadows_symbol() {
 
             You can rename it, or use the qualified name: `One.one`
 
-            ── UNUSED IMPORT in tmp/import_shadows_symbol/Main.roc ─────────────────────────
+            ── UNUSED IMPORT [E0204] in tmp/import_shadows_symbol/Main.roc ─────────────────
 
             One is imported but not used.
 
@@ -1308,7 +1339,7 @@ fn ingested_file_import_shadows_symbol() {
         err,
         indoc!(
             r#"
-            ── DUPLICATE NAME in tmp/ingested_import_shadows_symbol/Main.roc ───────────────
+            ── DUPLICATE NAME [E0217] in tmp/ingested_import_shadows_symbol/Main.roc ───────
 
             The `name` name is first defined here:
 
@@ -1401,7 +1432,7 @@ fn duplicate_alias() {
         err,
         indoc!(
             r"
-            ── IMPORT NAME CONFLICT in tmp/duplicate_alias/Main.roc ────────────────────────
+            ── IMPORT NAME CONFLICT [E0205] in tmp/duplicate_alias/Main.roc ────────────────
 
             Two was imported as D:
 
@@ -1466,7 +1497,7 @@ fn alias_using_module_name() {
         err,
         indoc!(
             r"
-            ── IMPORT NAME CONFLICT in tmp/alias_using_module_name/Main.roc ────────────────
+            ── IMPORT NAME CONFLICT [E0205] in tmp/alias_using_module_name/Main.roc ────────
 
             Two was imported as One:
 
@@ -1521,7 +1552,7 @@ fn alias_using_builtin_name() {
         err,
         indoc!(
             r"
-            ── IMPORT NAME CONFLICT in tmp/alias_using_builtin_name/Main.roc ───────────────
+            ── IMPORT NAME CONFLICT [E0205] in tmp/alias_using_builtin_name/Main.roc ───────
 
             BoolExtra was imported as Bool:
 
@@ -1539,6 +1570,109 @@ fn alias_using_builtin_name() {
     )
 }
 
+#[test]
+fn user_module_shadows_builtin_sort() {
+    let modules = vec![
+        (
+            "Sort.roc",
+            indoc!(
+                r"
+                module [by_length]
+
+                by_length : Str, Str -> Bool
+                by_length = |a, b| Str.count_utf8_bytes(a) < Str.count_utf8_bytes(b)
+                "
+            ),
+        ),
+        (
+            "Main.roc",
+            indoc!(
+                r#"
+                module [shorter, priority]
+
+                import Sort
+
+                Priority := [Low, High] implements [Sort]
+
+                shorter = Sort.by_length("a", "bb")
+
+                priority = @Priority(High)
+                "#
+            ),
+        ),
+    ];
+
+    let loaded_module = multiple_modules("user_module_shadows_builtin_sort", modules).unwrap();
+
+    expect_types(
+        loaded_module,
+        hashmap! {
+            "shorter" => "Bool",
+            "priority" => "Priority",
+            "#Priority_compare" => "Priority, Priority -> [EQ, GT, LT]",
+        },
+    );
+}
+
+#[test]
+fn app_module_shadows_builtin_default() {
+    let modules = vec![
+        (
+            "platform/main.roc",
+            indoc!(
+                r#"
+                platform "testplatform"
+                    requires {} { main : Str }
+                    exposes []
+                    packages {}
+                    imports []
+                    provides [main_for_host]
+
+                main_for_host : Str
+                main_for_host = main
+                "#
+            ),
+        ),
+        (
+            "Default.roc",
+            indoc!(
+                r#"
+                module [greeting]
+
+                greeting : Str
+                greeting = "Hello"
+                "#
+            ),
+        ),
+        (
+            "main.roc",
+            indoc!(
+                r#"
+                app "test"
+                    packages { pf: "platform/main.roc" }
+                    provides [main] to pf
+
+                import Default
+
+                Level := [Off, On] implements [Default]
+
+                level : Level
+                level = @Level(On)
+
+                main =
+                    when level is
+                        @Level(On) -> Default.greeting
+                        @Level(Off) -> ""
+                "#
+            ),
+        ),
+    ];
+
+    // `Default.greeting` only exists in the app's own module
+    let result = multiple_modules("app_module_shadows_builtin_default", modules);
+    assert!(result.is_ok(), "should check");
+}
+
 #[test]
 fn cannot_use_original_name_if_imported_with_alias() {
     let modules = vec![
@@ -1663,7 +1797,7 @@ fn module_params_typecheck_fail() {
         result,
         indoc!(
             r#"
-            ── MODULE PARAMS MISMATCH in tmp/module_params_typecheck_fail/Main.roc ─────────
+            ── MODULE PARAMS MISMATCH [E0308] in tmp/module_params_typecheck_fail/Main.roc ─
 
             Something is off with the params provided by this import:
 
@@ -1714,7 +1848,7 @@ fn module_params_missing_fields() {
         result,
         indoc!(
             r#"
-            ── MODULE PARAMS MISMATCH in tmp/module_params_missing_fields/Main.roc ─────────
+            ── MODULE PARAMS MISMATCH [E0308] in tmp/module_params_missing_fields/Main.roc ─
 
             Something is off with the params provided by this import:
 
@@ -1767,7 +1901,7 @@ fn module_params_extra_fields() {
         result,
         indoc!(
             r#"
-            ── MODULE PARAMS MISMATCH in tmp/module_params_extra_fields/Main.roc ───────────
+            ── MODULE PARAMS MISMATCH [E0308] in tmp/module_params_extra_fields/Main.roc ───
 
             Something is off with the params provided by this import:
 
@@ -1820,7 +1954,7 @@ fn module_params_unexpected() {
         err,
         indoc!(
             r#"
-            ── UNEXPECTED MODULE PARAMS in tmp/module_params_unexpected/Main.roc ───────────
+            ── UNEXPECTED MODULE PARAMS [E0306] in tmp/module_params_unexpected/Main.roc ───
 
             This import specifies module params:
 
@@ -1866,7 +2000,7 @@ fn module_params_missing() {
         err,
         indoc!(
             r#"
-            ── MISSING MODULE PARAMS in tmp/module_params_missing/Main.roc ─────────────────
+            ── MISSING MODULE PARAMS [E0307] in tmp/module_params_missing/Main.roc ─────────
 
             This import specifies no module params:
 
@@ -1928,7 +2062,7 @@ fn issue_2863_module_type_does_not_exist() {
                 report,
                 indoc!(
                     "
-                        ── UNRECOGNIZED NAME in tmp/issue_2863_module_type_does_not_exist/main.roc ─────
+                        ── UNRECOGNIZED NAME [E0202] in ...ue_2863_module_type_does_not_exist/main.roc ─
 
                         Nothing is named `DoesNotExist` in this scope.
 
@@ -1938,9 +2072,9 @@ fn issue_2863_module_type_does_not_exist() {
                         Did you mean one of these?
 
                             Decoding
-                            Result
                             Dict
-                            DecodeError
+                            Result
+                            Default
                         "
                       )
                 )
@@ -2006,7 +2140,7 @@ fn module_cyclic_import_itself() {
         err,
         indoc!(
             r"
-            ── IMPORT CYCLE in tmp/module_cyclic_import_itself/Age.roc ─────────────────────
+            ── IMPORT CYCLE [E0418] in tmp/module_cyclic_import_itself/Age.roc ─────────────
 
             I can't compile Age because it depends on itself through the following
             chain of module imports:
@@ -2055,7 +2189,7 @@ fn module_cyclic_import_transitive() {
         err,
         indoc!(
             r"
-            ── IMPORT CYCLE in tmp/module_cyclic_import_transitive/Age.roc ─────────────────
+            ── IMPORT CYCLE [E0418] in tmp/module_cyclic_import_transitive/Age.roc ─────────
 
             I can't compile Age because it depends on itself through the following
             chain of module imports:
@@ -2090,7 +2224,7 @@ fn non_roc_file_extension() {
 
     let expected = indoc!(
         r"
-        ── NOT A ROC FILE in tmp/non_roc_file_extension/main.md ────────────────────────
+        ── NOT A ROC FILE [E0416] in tmp/non_roc_file_extension/main.md ────────────────
 
         I expected a file with extension `.roc` or without extension.
         Instead I received a file with extension `.md`."
@@ -2120,7 +2254,7 @@ fn roc_file_no_extension() {
 
     let expected = indoc!(
         r"
-        ── NOT A ROC FILE in tmp/roc_file_no_extension/main ────────────────────────────
+        ── NOT A ROC FILE [E0416] in tmp/roc_file_no_extension/main ────────────────────
 
         I expected a file with either:
         - extension `.roc`
@@ -2183,3 +2317,275 @@ fn roc_package_depends_on_other_package() {
 
     assert!(result.is_ok());
 }
+
+#[test]
+fn doc_examples() {
+    let src = indoc!(
+        r#"
+        module [add_one]
+
+        ## Adds one.
+        ##
+        ## ```roc
+        ## two = add_one(1)
+        ## expect two == 2
+        ## ```
+        ##
+        ## ```roc,unchecked
+        ## add_one(Ok(1))
+        ## ```
+        ##
+        ## ```sh
+        ## roc test
+        ## ```
+        ##
+        ## ```
+        ## add_one(1) # 2
+        ## ```
+        add_one = |n| n + 1
+
+        ## ```
+        ## expect add_one(1) > 1
+        "#
+    );
+
+    let examples = roc_load_internal::docs::doc_examples(src);
+
+    assert_eq!(
+        examples
+            .iter()
+            .map(|example| example.code.as_str())
+            .collect::<Vec<_>>(),
+        vec!["two = add_one(1)\nexpect two == 2"]
+    );
+
+    let region = examples[0].region;
+    assert_eq!(
+        &src[region.start().offset as usize..region.end().offset as usize],
+        "two = add_one(1)\n## expect two == 2"
+    );
+}
+
+#[test]
+fn doc_example_expects_run_in_test_mode() {
+    let arena = Bump::new();
+    let dir = TmpDir::new("tmp/doc_example_expects_run_in_test_mode");
+    let file_path = dir.path().join("Main.roc");
+    let src = indoc!(
+        r#"
+        module [add_one]
+
+        ## ```roc
+        ## two = add_one(1)
+        ## expect two == 2
+        ## expect add_one(two) == 3
+        ## ```
+        ##
+        ## ```roc
+        ## add_one(1) # evaluates to 2
+        ## ```
+        add_one = |n| n + 1
+
+        expect add_one(0) == 1
+        "#
+    );
+    std::fs::write(&file_path, src).unwrap();
+
+    let module = match load_for_test(&arena, file_path).unwrap() {
+        LoadResult::Monomorphized(module) => module,
+        LoadResult::TypeChecked(_) => unreachable!("expected the doc examples to typecheck"),
+    };
+    let home = module.module_id;
+
+    assert!(module.can_problems[&home].is_empty());
+    assert!(module.type_problems[&home].is_empty());
+
+    let mut expect_sources: Vec<_> = module.toplevel_expects[&home]
+        .pure
+        .values()
+        .map(|region| &src[region.start().offset as usize..region.end().offset as usize])
+        .collect();
+    expect_sources.sort();
+
+    assert_eq!(
+        expect_sources,
+        vec![
+            "add_one(1) # evaluates to 2",
+            "expect add_one(0) == 1",
+            "two = add_one(1)\n## expect two == 2\n## expect add_one(two) == 3",
+        ]
+    );
+}
+
+#[test]
+fn doc_example_defs_dont_shadow_top_level_defs() {
+    let arena = Bump::new();
+    let dir = TmpDir::new("tmp/doc_example_defs_dont_shadow_top_level_defs");
+    let file_path = dir.path().join("Main.roc");
+    let src = indoc!(
+        r#"
+        module [add_one, two, one]
+
+        ## ```roc
+        ## two = add_one(1)
+        ## (one, three) = (two - 1, add_one(two))
+        ## expect two == 2
+        ## expect one + three == 4
+        ## ```
+        add_one = |n| n + 1
+
+        two = 2
+
+        one = 1
+        "#
+    );
+    std::fs::write(&file_path, src).unwrap();
+
+    let module = match load_for_test(&arena, file_path).unwrap() {
+        LoadResult::Monomorphized(module) => module,
+        LoadResult::TypeChecked(_) => unreachable!("expected the doc example to typecheck"),
+    };
+    let home = module.module_id;
+
+    assert!(module.can_problems[&home].is_empty());
+    assert!(module.type_problems[&home].is_empty());
+    assert_eq!(module.toplevel_expects[&home].pure.len(), 1);
+}
+
+#[test]
+fn doc_example_type_error() {
+    let arena = Bump::new();
+    let dir = TmpDir::new("tmp/doc_example_type_error");
+    let file_path = dir.path().join("Main.roc");
+    let src = indoc!(
+        r#"
+        module [add_one]
+
+        ## ```roc
+        ## expect add_one("one") == 2
+        ## ```
+        add_one = |n| n + 1
+        "#
+    );
+    std::fs::write(&file_path, src).unwrap();
+
+    // Type errors stop `roc test` before it builds anything.
+    let mut module = match load_for_test(&arena, file_path.clone()).unwrap() {
+        LoadResult::TypeChecked(module) => module,
+        LoadResult::Monomorphized(_) => unreachable!("expected a type error"),
+    };
+    let home = module.module_id;
+    let err = format_type_problems(
+        module.type_problems.remove(&home).unwrap(),
+        home,
+        &module.interns,
+        file_path,
+        src,
+    );
+
+    assert_eq!(
+        err,
+        indoc!(
+            r#"
+            ── TYPE MISMATCH [E0301] in tmp/doc_example_type_error/Main.roc ────────────────
+
+            This 1st argument to `add_one` has an unexpected type:
+
+            4│  ## expect add_one("one") == 2
+                                  ^^^^^
+
+            The argument is a string of type:
+
+                Str
+
+            But `add_one` needs its 1st argument to be:
+
+                Num *
+            "#
+        ),
+        "\n{}",
+        err
+    );
+}
+
+#[test]
+fn doc_example_syntax_error() {
+    let arena = Bump::new();
+    let dir = TmpDir::new("tmp/doc_example_syntax_error");
+    let file_path = dir.path().join("Main.roc");
+    let src = indoc!(
+        r#"
+        module [add_one]
+
+        ## ```roc
+        ## expect add_one(1) ==
+        ## ```
+        ##
+        ## ```roc
+        ## expect add_one(1) == 2
+        ## ```
+        add_one = |n| n + 1
+        "#
+    );
+    std::fs::write(&file_path, src).unwrap();
+
+    // Like other errors, the invalid example stops `roc test` before it builds anything.
+    let module = match load_for_test(&arena, file_path).unwrap() {
+        LoadResult::TypeChecked(module) => module,
+        LoadResult::Monomorphized(_) => unreachable!("expected the invalid example to be reported"),
+    };
+    let home = module.module_id;
+
+    match module.can_problems[&home].as_slice() {
+        [Problem::InvalidDocExample(region)] => assert_eq!(
+            &src[region.start().offset as usize..region.end().offset as usize],
+            "expect add_one(1) =="
+        ),
+        problems => panic!("expected one invalid doc example, got {problems:?}"),
+    }
+
+    // The example that parses still gets checked.
+    assert!(module.type_problems[&home].is_empty());
+    assert_eq!(
+        module.declarations_by_id[&home]
+            .declarations
+            .iter()
+            .filter(|tag| matches!(tag, DeclarationTag::Expectation))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn constant_crash_reported_without_optimizations() {
+    let arena = Bump::new();
+    let dir = TmpDir::new("tmp/constant_crash_reported_without_optimizations");
+    let file_path = dir.path().join("Main.roc");
+    let src = indoc!(
+        r#"
+        module [max_items]
+
+        max_items : U8
+        max_items = Num.max_u8 + 1
+
+        expect max_items > 0
+        "#
+    );
+    std::fs::write(&file_path, src).unwrap();
+
+    let module = match load_for_test(&arena, file_path).unwrap() {
+        LoadResult::Monomorphized(module) => module,
+        LoadResult::TypeChecked(_) => unreachable!("expected the module to typecheck"),
+    };
+    let home = module.module_id;
+
+    match module.can_problems[&home].as_slice() {
+        [Problem::ConstantCrashed {
+            symbol, message, ..
+        }] => {
+            assert_eq!(symbol.as_str(&module.interns), "max_items");
+            assert_eq!(message, "Integer addition overflowed!");
+        }
+        problems => panic!("expected one crashed constant, got {problems:?}"),
+    }
+}
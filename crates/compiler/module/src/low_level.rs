@@ -30,6 +30,7 @@ pub enum LowLevel {
     StrWithAsciiLowercased,
     StrWithAsciiUppercased,
     StrCaselessAsciiEquals,
+    StrIsUnique,
     ListLenUsize,
     ListLenU64,
     ListWithCapacity,
@@ -128,6 +129,9 @@ pub enum LowLevel {
     UnboxExpr,
     Unreachable,
     DictPseudoSeed,
+    SetAllocSite,
+    RefCountCheckStart,
+    RefCountCheckLeaks,
     SetJmp,
     LongJmp,
     SetLongJmpBuffer,
@@ -231,6 +235,10 @@ macro_rules! map_symbol_to_lowlevel {
                 LowLevel::RefCountIncDataPtr => unimplemented!(),
                 LowLevel::RefCountDecDataPtr=> unimplemented!(),
                 LowLevel::RefCountIsUnique => unimplemented!(),
+                LowLevel::StrIsUnique => unimplemented!(),
+                LowLevel::SetAllocSite => unimplemented!(),
+                LowLevel::RefCountCheckStart => unimplemented!(),
+                LowLevel::RefCountCheckLeaks => unimplemented!(),
                 LowLevel::ListIncref => unimplemented!(),
                 LowLevel::ListDecref => unimplemented!(),
 
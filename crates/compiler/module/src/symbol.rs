@@ -1,4 +1,4 @@
-use crate::ident::{Ident, IdentSuffix, Lowercase, ModuleName};
+use crate::ident::{Ident, IdentSuffix, Lowercase, ModuleName, QualifiedModuleName};
 use crate::module_err::{ModuleError, ModuleResult};
 use roc_collections::{SmallStringInterner, VecMap};
 use roc_error_macros::internal_error;
@@ -56,6 +56,8 @@ pub const DERIVABLE_ABILITIES: &[(Symbol, &[Symbol])] = &[
         Symbol::INSPECT_INSPECT_ABILITY,
         &[Symbol::INSPECT_TO_INSPECTOR],
     ),
+    (Symbol::SORT_SORT_ABILITY, &[Symbol::SORT_COMPARE]),
+    (Symbol::DEFAULT_DEFAULT_ABILITY, &[Symbol::DEFAULT_DEFAULT]),
 ];
 
 /// In Debug builds only, Symbol has a name() method that lets
@@ -407,6 +409,17 @@ impl ModuleId {
     pub fn is_automatically_imported(self) -> bool {
         self.is_builtin()
     }
+
+    /// The builtin module with this name, if a package's own module of the same name
+    /// takes its place. These builtins were added after programs could already have
+    /// modules with their names, so unlike the others, they don't reserve the name.
+    pub fn shadowable_builtin(module_name: &str) -> Option<ModuleId> {
+        match module_name {
+            ModuleName::SORT => Some(ModuleId::SORT),
+            ModuleName::DEFAULT => Some(ModuleId::DEFAULT),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Debug for ModuleId {
@@ -492,6 +505,9 @@ impl<'a, T> PackageQualified<'a, T> {
 #[derive(Debug, Clone)]
 pub struct PackageModuleIds<'a> {
     by_id: Vec<PQModuleName<'a>>,
+    /// Shadowable builtins that a package has its own module for, e.g. `(None, ModuleId::SORT)`
+    /// when the home package has a `Sort.roc`
+    shadowed_builtins: Vec<(Option<&'a str>, ModuleId)>,
 }
 
 impl<'a> PackageModuleIds<'a> {
@@ -510,6 +526,47 @@ impl<'a> PackageModuleIds<'a> {
         module_id
     }
 
+    /// Record that the package with this shorthand (or the home package, for [None]) has its
+    /// own module with the same name as a shadowable builtin, which its imports then refer to.
+    pub fn shadow_builtin(&mut self, opt_shorthand: Option<&'a str>, builtin: ModuleId) {
+        debug_assert!(builtin.is_builtin());
+
+        if !self.is_shadowed(opt_shorthand, builtin) {
+            self.shadowed_builtins.push((opt_shorthand, builtin));
+        }
+    }
+
+    fn is_shadowed(&self, opt_shorthand: Option<&'a str>, builtin: ModuleId) -> bool {
+        self.shadowed_builtins.contains(&(opt_shorthand, builtin))
+    }
+
+    /// Like [QualifiedModuleName::into_pq_module_name], except that a package importing a
+    /// builtin it shadows gets its own module instead.
+    pub fn import_name(
+        &self,
+        name: QualifiedModuleName<'a>,
+        opt_shorthand: Option<&'a str>,
+    ) -> PQModuleName<'a> {
+        let is_shadowed = name.opt_package.is_none()
+            && ModuleId::shadowable_builtin(name.module.as_str())
+                .is_some_and(|builtin| self.is_shadowed(opt_shorthand, builtin));
+
+        match opt_shorthand {
+            Some(shorthand) if is_shadowed => PQModuleName::Qualified(shorthand, name.module),
+            None if is_shadowed => PQModuleName::Unqualified(name.module),
+            _ => name.into_pq_module_name(opt_shorthand),
+        }
+    }
+
+    /// Get the id of a builtin module, even if the home package shadows its name.
+    pub fn get_builtin_id(&self, module_name: &ModuleName) -> Option<ModuleId> {
+        self.by_id
+            .iter()
+            .position(|name| name == &PQModuleName::Unqualified(module_name.clone()))
+            .map(ModuleId::from_zero_indexed)
+            .filter(|module_id| module_id.is_builtin())
+    }
+
     pub fn into_module_ids(self) -> ModuleIds {
         let by_id: Vec<ModuleName> = self
             .by_id
@@ -543,8 +600,11 @@ impl<'a> PackageModuleIds<'a> {
 
     pub fn get_id(&self, module_name: &PQModuleName<'a>) -> Option<ModuleId> {
         for (index, name) in self.by_id.iter().enumerate() {
-            if name == module_name {
-                return Some(ModuleId::from_zero_indexed(index));
+            let module_id = ModuleId::from_zero_indexed(index);
+
+            // When the home package shadows a builtin, the name refers to its own module
+            if name == module_name && !self.is_shadowed(None, module_id) {
+                return Some(module_id);
             }
         }
 
@@ -1042,7 +1102,10 @@ macro_rules! define_builtins {
                     insert_both(ModuleId::$module_const, $module_name);
                 )+
 
-                PackageModuleIds { by_id }
+                PackageModuleIds {
+                    by_id,
+                    shadowed_builtins: Vec::new(),
+                }
             }
         }
 
@@ -1727,6 +1790,22 @@ define_builtins! {
         32 INSPECT_TO_INSPECTOR: "to_inspector"
         33 INSPECT_TO_STR: "to_str"
     }
+    15 SORT: "Sort" => {
+        0 SORT_SORT_ABILITY: "Sort" exposed_type=true
+        1 SORT_COMPARE: "compare"
+        2 SORT_COMPARE_BOOL: "compare_bool"
+        3 SORT_COMPARE_STR: "compare_str"
+        4 SORT_COMPARE_LIST: "compare_list"
+    }
+    16 DEFAULT: "Default" => {
+        0 DEFAULT_DEFAULT_ABILITY: "Default" exposed_type=true
+        1 DEFAULT_DEFAULT: "default"
+        2 DEFAULT_NUM: "default_num"
+        3 DEFAULT_STR: "default_str"
+        4 DEFAULT_LIST: "default_list"
+        5 DEFAULT_DICT: "default_dict"
+        6 DEFAULT_BOOL: "default_bool"
+    }
 
-    num_modules: 15 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
+    num_modules: 17 // Keep this count up to date by hand! (TODO: see the mut_map! macro for how we could determine this count correctly in the macro)
 }
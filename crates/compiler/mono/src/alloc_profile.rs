@@ -0,0 +1,313 @@
+//! Attributing heap allocations to the calls in user code that make them.
+//!
+//! Hosts only see `roc_alloc` being called, which says nothing about where in the Roc program the
+//! memory is needed. When profiling allocations, [instrument_allocations] gives every call in user
+//! code an id, and makes the program tell the allocation wrapper in the builtins which call it is
+//! in before making it:
+//!
+//! ```text
+//! let y = List.append x v;
+//! ...
+//! ```
+//!
+//! becomes
+//!
+//! ```text
+//! let id = 3u32;
+//! let previous = lowlevel SetAllocSite id;
+//! let y = List.append x v;
+//! let restored = lowlevel SetAllocSite previous;
+//! ...
+//! ```
+//!
+//! The wrapper then reports every allocation together with the id of the innermost call it was
+//! made in. Allocations that user code makes without calling anything, like list literals, count
+//! towards the call of the function they are in. Together with the [AllocSite]s, that is enough to
+//! add up the allocations per source location and per layout.
+//!
+//! [profile_allocations] does the instrumenting and names the sites, for
+//! `roc build --profile-alloc` and the mono tests.
+//!
+//! Restoring the previous site after a call means that calls in tail position aren't tail calls
+//! anymore, so a profiled program may use more stack than usual.
+
+use std::path::PathBuf;
+
+use bumpalo::Bump;
+use roc_collections::{MutMap, MutSet};
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, Interns, ModuleId, Symbol};
+
+use crate::in_place::{CallSite, CallSites, NamedSite, SiteNames};
+use crate::ir::{Call, CallType, Expr, Literal, Proc, ProcLayout, Stmt, UpdateModeId};
+use crate::layout::{InLayout, Layout, LayoutInterner, STLayoutInterner};
+use crate::optimize::for_each_stmt;
+
+/// A call in user code that may allocate, and the layout of the value it returns. Specializations
+/// of the same call get an [AllocSite] per layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AllocSite<'a> {
+    pub site: CallSite,
+    pub layout: InLayout<'a>,
+}
+
+/// Instruments the procs with [instrument_allocations], and names the allocation sites for showing
+/// them to users. The site at index `i` has id `i + 1`.
+pub fn profile_allocations<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    home: ModuleId,
+    interns: &mut Interns,
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    call_sites: &CallSites,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) -> Vec<(NamedSite, InLayout<'a>)> {
+    let alloc_sites = instrument_allocations(
+        arena,
+        interner,
+        home,
+        interns.all_ident_ids.get_mut(&home).unwrap(),
+        call_sites,
+        procs,
+    );
+
+    let mut names = SiteNames::new(interns, sources, call_sites);
+
+    alloc_sites
+        .iter()
+        .map(|alloc_site| (names.name(&alloc_site.site), alloc_site.layout))
+        .collect()
+}
+
+/// Makes every recorded call in user code set the allocation site before it's made, and restore
+/// the previous one after it returns.
+///
+/// Returns the allocation sites; the site at index `i` has id `i + 1`, and id `0` stands for
+/// allocations made outside of any of them.
+pub fn instrument_allocations<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    call_sites: &CallSites,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) -> Vec<AllocSite<'a>> {
+    let mut seen = MutSet::default();
+    for proc in procs.values() {
+        for_each_stmt(&proc.body, |stmt| {
+            if let Some(alloc_site) = alloc_site(interner, call_sites, stmt) {
+                seen.insert(alloc_site);
+            }
+        });
+    }
+
+    // the ids end up in the report, so they should not depend on the order of the procs
+    let mut alloc_sites: Vec<_> = seen.into_iter().collect();
+    alloc_sites.sort_by_cached_key(|alloc_site| {
+        let region = alloc_site.site.region;
+
+        (
+            region.start(),
+            region.end(),
+            alloc_site.site.callee,
+            interner.dbg(alloc_site.layout),
+        )
+    });
+
+    let ids: MutMap<AllocSite, u32> = alloc_sites
+        .iter()
+        .enumerate()
+        .map(|(index, alloc_site)| (*alloc_site, index as u32 + 1))
+        .collect();
+
+    let mut instrumenter = Instrumenter {
+        arena,
+        interner,
+        home,
+        ident_ids,
+        call_sites,
+        ids: &ids,
+    };
+
+    for proc in procs.values_mut() {
+        proc.body = instrumenter.instrument_stmt(&proc.body).clone();
+    }
+
+    alloc_sites
+}
+
+/// Calls to builtins that return no heap data, like `Num.add` or `List.len`, don't allocate, so
+/// those are left alone.
+fn alloc_site<'a>(
+    interner: &STLayoutInterner<'a>,
+    call_sites: &CallSites,
+    stmt: &Stmt<'a>,
+) -> Option<AllocSite<'a>> {
+    let Stmt::Let(symbol, Expr::Call(_), layout, _) = stmt else {
+        return None;
+    };
+
+    let site = *call_sites.get(symbol)?;
+    if site.callee.module_id().is_builtin() && !interner.contains_refcounted(*layout) {
+        return None;
+    }
+
+    Some(AllocSite {
+        site,
+        layout: *layout,
+    })
+}
+
+struct Instrumenter<'a, 'r> {
+    arena: &'a Bump,
+    interner: &'r STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &'r mut IdentIds,
+    call_sites: &'r CallSites,
+    ids: &'r MutMap<AllocSite<'a>, u32>,
+}
+
+impl<'a, 'r> Instrumenter<'a, 'r> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn instrument_stmt(&mut self, stmt: &Stmt<'a>) -> &'a Stmt<'a> {
+        let arena = self.arena;
+
+        // chains of lets can get very long, so we walk those in a loop rather than recursively
+        let mut chain = std::vec::Vec::new();
+        let mut stmt = stmt;
+        while let Stmt::Let(_, _, _, continuation) | Stmt::Refcounting(_, continuation) = stmt {
+            chain.push(stmt);
+            stmt = continuation;
+        }
+
+        let tail = match stmt {
+            Stmt::Let(..) | Stmt::Refcounting(..) => unreachable!("handled above"),
+            Stmt::Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let branches = bumpalo::collections::Vec::from_iter_in(
+                    branches.iter().map(|(label, info, branch)| {
+                        (*label, info.clone(), self.instrument_stmt(branch).clone())
+                    }),
+                    arena,
+                );
+
+                Stmt::Switch {
+                    cond_symbol: *cond_symbol,
+                    cond_layout: *cond_layout,
+                    branches: branches.into_bump_slice(),
+                    default_branch: (
+                        default_branch.0.clone(),
+                        self.instrument_stmt(default_branch.1),
+                    ),
+                    ret_layout: *ret_layout,
+                }
+            }
+            Stmt::Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => Stmt::Expect {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder: self.instrument_stmt(remainder),
+            },
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                variable,
+                remainder,
+            } => Stmt::Dbg {
+                source_location,
+                source,
+                symbol: *symbol,
+                variable: *variable,
+                remainder: self.instrument_stmt(remainder),
+            },
+            Stmt::Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => Stmt::Join {
+                id: *id,
+                parameters,
+                body: self.instrument_stmt(body),
+                remainder: self.instrument_stmt(remainder),
+            },
+            Stmt::Ret(_) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => stmt.clone(),
+        };
+
+        let mut result: &'a Stmt<'a> = arena.alloc(tail);
+
+        for stmt in chain.into_iter().rev() {
+            result = match stmt {
+                Stmt::Let(symbol, expr, layout, _) => {
+                    let id = alloc_site(self.interner, self.call_sites, stmt)
+                        .map(|alloc_site| self.ids[&alloc_site]);
+
+                    match id {
+                        Some(id) => {
+                            let previous = self.unique_symbol();
+                            let restored = self.unique_symbol();
+                            let restore = self.set_alloc_site(restored, previous, result);
+                            let call =
+                                arena.alloc(Stmt::Let(*symbol, expr.clone(), *layout, restore));
+
+                            let id_symbol = self.unique_symbol();
+                            let set = self.set_alloc_site(previous, id_symbol, call);
+
+                            arena.alloc(Stmt::Let(
+                                id_symbol,
+                                Expr::Literal(Literal::Int((id as i128).to_ne_bytes())),
+                                Layout::U32,
+                                set,
+                            ))
+                        }
+                        None => arena.alloc(Stmt::Let(*symbol, expr.clone(), *layout, result)),
+                    }
+                }
+                Stmt::Refcounting(modify_rc, _) => {
+                    arena.alloc(Stmt::Refcounting(*modify_rc, result))
+                }
+                _ => unreachable!("only lets and refcounting are chained"),
+            };
+        }
+
+        result
+    }
+
+    /// `let previous = lowlevel SetAllocSite site; continuation`
+    fn set_alloc_site(
+        &self,
+        previous: Symbol,
+        site: Symbol,
+        continuation: &'a Stmt<'a>,
+    ) -> &'a Stmt<'a> {
+        let arena = self.arena;
+
+        let set = Expr::Call(Call {
+            call_type: CallType::LowLevel {
+                op: LowLevel::SetAllocSite,
+                update_mode: UpdateModeId::BACKEND_DUMMY,
+            },
+            arguments: arena.alloc([site]),
+        });
+
+        arena.alloc(Stmt::Let(previous, set, Layout::U32, continuation))
+    }
+}
@@ -0,0 +1,1156 @@
+//! Compile-time evaluation of top-level constants.
+//!
+//! A top-level value like `table = List.map(List.range(...), f)` is compiled to a 0-arity thunk
+//! that computes the value whenever it's used. When everything a thunk does can be done at compile
+//! time (arithmetic, building records, tags, strings and lists, calling other Roc functions), we
+//! interpret it here and replace its body with the value it computes. The backends emit strings
+//! and lists of literals as static data, so a folded constant costs next to nothing at runtime.
+//!
+//! Anything we can't do at compile time (effects, pointers, erased functions, lowlevels we don't
+//! know the exact runtime behavior of) leaves the thunk alone. A constant that crashes is reported
+//! to the caller, because it would crash every program that uses it.
+//!
+//! Dicts and sets can't be folded: their buckets depend on a hash seed that's only known at
+//! runtime, so any constant that builds one stays a thunk.
+//!
+//! This is a small interpreter for mono IR rather than a run of the dev backend. The backends
+//! depend on this crate, and running their output needs the builtins compiled by zig, which the
+//! compiler itself doesn't link. Constants are evaluated at every opt level so crashes are always
+//! reported, but only optimized builds replace the thunks with the folded values.
+#![allow(clippy::unnecessary_cast)]
+
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_builtins::bitcode::{FloatWidth, IntWidth};
+use roc_collections::{MutMap, MutSet};
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, IdentIdsByModule, ModuleId, Symbol};
+
+use crate::ir::{
+    CallType, Expr, JoinPointId, ListLiteralElement, Literal, Param, Proc, ProcLayout, Stmt,
+};
+use crate::layout::{
+    Builtin, InLayout, Layout, LayoutInterner, LayoutRepr, STLayoutInterner, TagIdIntType,
+};
+
+/// How many statements and list elements evaluating one constant may take before we give up and
+/// leave it to be computed at runtime.
+const FUEL: usize = 1 << 21;
+
+/// How deep calls may nest while evaluating a constant.
+const MAX_CALL_DEPTH: usize = 256;
+
+/// How many values a folded constant may consist of. Bigger constants stay thunks, so the IR
+/// doesn't explode.
+const MAX_FOLDED_SIZE: usize = 1 << 16;
+
+const DEC_ONE: i128 = 1_000_000_000_000_000_000;
+
+/// A top-level constant that crashed while it was evaluated at compile time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstantCrash {
+    pub symbol: Symbol,
+    pub message: String,
+}
+
+/// Evaluate the top-level constants in `thunks` that don't depend on anything only known at
+/// runtime, and report the ones that crash. With `fold` set, the bodies of the others are replaced
+/// with the values they compute.
+pub fn fold_top_level_constants<'a>(
+    arena: &'a Bump,
+    interner: &mut STLayoutInterner<'a>,
+    ident_ids: &mut IdentIdsByModule,
+    thunks: &MutSet<Symbol>,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    fold: bool,
+) -> std::vec::Vec<ConstantCrash> {
+    let mut keys: std::vec::Vec<_> = procs
+        .iter()
+        .filter(|((symbol, layout), proc)| {
+            thunks.contains(symbol) && layout.arguments.is_empty() && !is_folded(&proc.body)
+        })
+        .map(|(key, _)| *key)
+        .collect();
+
+    // sort for deterministic symbol numbering in the folded bodies
+    keys.sort_by_key(|(symbol, layout)| (*symbol, layout.result));
+
+    let mut folded = std::vec::Vec::new();
+    let mut crashes: std::vec::Vec<ConstantCrash> = std::vec::Vec::new();
+
+    let mut interpreter = Interpreter {
+        interner,
+        procs,
+        thunks,
+        evaluated: MutMap::default(),
+        thunk_stack: std::vec::Vec::new(),
+        fuel: FUEL,
+        depth: 0,
+    };
+
+    for key in keys {
+        interpreter.fuel = FUEL;
+
+        match interpreter.eval_thunk(key) {
+            Ok(value) if fold => folded.push((key, value)),
+            Ok(_) => {}
+            Err(Stop::Crash { thunk, message }) if thunk == key.0 => {
+                // report each constant once, not once per specialization
+                if !crashes.iter().any(|crash| crash.symbol == thunk) {
+                    crashes.push(ConstantCrash {
+                        symbol: thunk,
+                        message,
+                    });
+                }
+            }
+            Err(_) => {}
+        }
+    }
+
+    for (key, value) in folded {
+        let proc = procs.get_mut(&key).unwrap();
+        let home = key.0.module_id();
+
+        let mut emitter = Emitter {
+            arena,
+            interner,
+            home,
+            ident_ids: ident_ids.get_or_insert(home),
+            lets: std::vec::Vec::new(),
+            size: 0,
+        };
+
+        if let Some(symbol) = emitter.emit(&value, proc.ret_layout) {
+            proc.body = emitter.into_body(symbol);
+        }
+    }
+
+    crashes
+}
+
+/// How many steps folding a single lowlevel may take. Folding `Str.repeat("ab", 1_000_000)` into a
+/// literal would only bloat the binary.
+const LOWLEVEL_FUEL: usize = 1 << 10;
+
+/// Evaluates the lowlevel `op` on literal arguments, if that can be done at compile time without
+/// crashing. The IR optimizer uses this to fold constant arithmetic.
+pub(crate) fn fold_lowlevel<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    op: LowLevel,
+    arguments: &[(Literal<'a>, InLayout<'a>)],
+    layout: InLayout<'a>,
+) -> Option<Literal<'a>> {
+    let procs = MutMap::default();
+    let thunks = MutSet::default();
+
+    let mut interpreter = Interpreter {
+        interner,
+        procs: &procs,
+        thunks: &thunks,
+        evaluated: MutMap::default(),
+        thunk_stack: std::vec::Vec::new(),
+        fuel: LOWLEVEL_FUEL,
+        depth: 0,
+    };
+
+    let arguments = arguments
+        .iter()
+        .map(|(literal, layout)| interpreter.literal(literal, *layout))
+        .collect::<Result<std::vec::Vec<_>, _>>()
+        .ok()?;
+
+    let value = interpreter.eval_lowlevel(op, &arguments, layout).ok()?;
+
+    to_literal(arena, interner, &value, layout)
+}
+
+/// Does this body just build a value out of literals?
+fn is_folded(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Let(_, expr, _, continuation) => {
+            matches!(
+                expr,
+                Expr::Literal(_)
+                    | Expr::Struct(_)
+                    | Expr::Tag { .. }
+                    | Expr::Array { .. }
+                    | Expr::EmptyArray
+            ) && is_folded(continuation)
+        }
+        Stmt::Ret(_) => true,
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    /// The bits of the integer, sign-extended if the width is signed
+    Int(u128, IntWidth),
+    Float(f64, FloatWidth),
+    Dec(i128),
+    Bool(bool),
+    Str(Rc<str>),
+    List(Rc<std::vec::Vec<Value>>),
+    Struct(Rc<[Value]>),
+    Tag(TagIdIntType, Rc<[Value]>),
+}
+
+#[derive(Debug, Clone)]
+enum Stop {
+    /// Evaluating needs something that is only known at runtime, or takes too long. The constant
+    /// stays a thunk.
+    GiveUp,
+    /// The constant `thunk` crashed while it was being evaluated.
+    Crash { thunk: Symbol, message: String },
+}
+
+/// Binds `$T` to the Rust integer type of an [IntWidth] in `$body`.
+macro_rules! with_int_type {
+    ($width:expr, $T:ident, $body:block) => {
+        match $width {
+            IntWidth::U8 => {
+                type $T = u8;
+                $body
+            }
+            IntWidth::U16 => {
+                type $T = u16;
+                $body
+            }
+            IntWidth::U32 => {
+                type $T = u32;
+                $body
+            }
+            IntWidth::U64 => {
+                type $T = u64;
+                $body
+            }
+            IntWidth::U128 => {
+                type $T = u128;
+                $body
+            }
+            IntWidth::I8 => {
+                type $T = i8;
+                $body
+            }
+            IntWidth::I16 => {
+                type $T = i16;
+                $body
+            }
+            IntWidth::I32 => {
+                type $T = i32;
+                $body
+            }
+            IntWidth::I64 => {
+                type $T = i64;
+                $body
+            }
+            IntWidth::I128 => {
+                type $T = i128;
+                $body
+            }
+        }
+    };
+}
+
+/// Truncates (and sign-extends) `bits` to the given width.
+fn int(bits: u128, width: IntWidth) -> Value {
+    with_int_type!(width, T, { Value::Int(bits as T as i128 as u128, width) })
+}
+
+fn float(value: f64, width: FloatWidth) -> Value {
+    match width {
+        FloatWidth::F32 => Value::Float(value as f32 as f64, width),
+        FloatWidth::F64 => Value::Float(value, width),
+    }
+}
+
+fn float_to_int(value: f64, width: IntWidth) -> Option<Value> {
+    let bits = width.stack_size() as i32 * 8;
+
+    let (min, max) = if width.is_signed() {
+        (-(2f64.powi(bits - 1)), 2f64.powi(bits - 1))
+    } else {
+        (0.0, 2f64.powi(bits))
+    };
+
+    if value >= min && value < max {
+        Some(with_int_type!(width, T, {
+            Value::Int(value as T as i128 as u128, width)
+        }))
+    } else {
+        None
+    }
+}
+
+#[derive(Default)]
+struct Frame<'a, 'r> {
+    values: MutMap<Symbol, Value>,
+    join_points: MutMap<JoinPointId, (&'r [Param<'a>], &'r Stmt<'a>)>,
+}
+
+impl<'a, 'r> Frame<'a, 'r> {
+    fn get(&self, symbol: Symbol) -> Result<&Value, Stop> {
+        self.values.get(&symbol).ok_or(Stop::GiveUp)
+    }
+
+    fn get_all(&self, symbols: &[Symbol]) -> Result<std::vec::Vec<Value>, Stop> {
+        symbols
+            .iter()
+            .map(|symbol| self.get(*symbol).cloned())
+            .collect()
+    }
+}
+
+struct Interpreter<'a, 'r> {
+    interner: &'r STLayoutInterner<'a>,
+    procs: &'r MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    thunks: &'r MutSet<Symbol>,
+    evaluated: MutMap<(Symbol, ProcLayout<'a>), Result<Value, Stop>>,
+    /// The constants being evaluated, innermost last
+    thunk_stack: std::vec::Vec<Symbol>,
+    fuel: usize,
+    depth: usize,
+}
+
+impl<'a, 'r> Interpreter<'a, 'r> {
+    fn burn(&mut self, amount: usize) -> Result<(), Stop> {
+        self.fuel = self.fuel.checked_sub(amount).ok_or(Stop::GiveUp)?;
+
+        Ok(())
+    }
+
+    fn crash(&self, message: &str) -> Stop {
+        match self.thunk_stack.last() {
+            Some(thunk) => Stop::Crash {
+                thunk: *thunk,
+                message: message.to_string(),
+            },
+            // outside of a constant, a crash just means the operation has to happen at runtime
+            None => Stop::GiveUp,
+        }
+    }
+
+    fn repr(&self, layout: InLayout<'a>) -> LayoutRepr<'a> {
+        self.interner
+            .get_repr(Layout::runtime_representation_in(layout, self.interner))
+    }
+
+    fn eval_thunk(&mut self, key: (Symbol, ProcLayout<'a>)) -> Result<Value, Stop> {
+        if let Some(result) = self.evaluated.get(&key) {
+            return result.clone();
+        }
+
+        self.thunk_stack.push(key.0);
+        let result = self.call_proc(key, std::vec::Vec::new());
+        self.thunk_stack.pop();
+
+        self.evaluated.insert(key, result.clone());
+
+        result
+    }
+
+    fn call_proc(
+        &mut self,
+        key: (Symbol, ProcLayout<'a>),
+        arguments: std::vec::Vec<Value>,
+    ) -> Result<Value, Stop> {
+        let procs = self.procs;
+        let proc = procs.get(&key).ok_or(Stop::GiveUp)?;
+
+        if proc.is_erased || self.depth >= MAX_CALL_DEPTH {
+            return Err(Stop::GiveUp);
+        }
+
+        let mut frame = Frame::default();
+        for ((_, symbol), value) in proc.args.iter().zip(arguments) {
+            frame.values.insert(*symbol, value);
+        }
+
+        self.depth += 1;
+        let result = self.eval_stmt(&mut frame, &proc.body);
+        self.depth -= 1;
+
+        result
+    }
+
+    fn eval_stmt(
+        &mut self,
+        frame: &mut Frame<'a, 'r>,
+        mut stmt: &'r Stmt<'a>,
+    ) -> Result<Value, Stop> {
+        loop {
+            self.burn(1)?;
+
+            match stmt {
+                Stmt::Let(symbol, expr, layout, continuation) => {
+                    let value = self.eval_expr(frame, expr, *layout)?;
+                    frame.values.insert(*symbol, value);
+
+                    stmt = *continuation;
+                }
+                Stmt::Switch {
+                    cond_symbol,
+                    branches,
+                    default_branch,
+                    ..
+                } => {
+                    let cond = match frame.get(*cond_symbol)? {
+                        Value::Int(bits, _) => *bits as u64,
+                        Value::Bool(b) => *b as u64,
+                        _ => return Err(Stop::GiveUp),
+                    };
+
+                    stmt = match branches.iter().find(|(id, _, _)| *id == cond) {
+                        Some((_, _, branch)) => branch,
+                        None => default_branch.1,
+                    };
+                }
+                Stmt::Ret(symbol) => return frame.get(*symbol).cloned(),
+                Stmt::Refcounting(_, continuation) => stmt = *continuation,
+                Stmt::Join {
+                    id,
+                    parameters,
+                    body,
+                    remainder,
+                } => {
+                    frame.join_points.insert(*id, (*parameters, *body));
+
+                    stmt = *remainder;
+                }
+                Stmt::Jump(id, arguments) => {
+                    let (parameters, body) = *frame.join_points.get(id).ok_or(Stop::GiveUp)?;
+                    let values = frame.get_all(arguments)?;
+
+                    for (parameter, value) in parameters.iter().zip(values) {
+                        frame.values.insert(parameter.symbol, value);
+                    }
+
+                    stmt = body;
+                }
+                Stmt::Crash(symbol, _) => {
+                    return match frame.get(*symbol)? {
+                        Value::Str(message) => Err(self.crash(message)),
+                        _ => Err(Stop::GiveUp),
+                    };
+                }
+                Stmt::Expect { .. } | Stmt::Dbg { .. } => return Err(Stop::GiveUp),
+            }
+        }
+    }
+
+    fn eval_expr(
+        &mut self,
+        frame: &Frame<'a, 'r>,
+        expr: &'r Expr<'a>,
+        layout: InLayout<'a>,
+    ) -> Result<Value, Stop> {
+        match expr {
+            Expr::Literal(literal) => self.literal(literal, layout),
+            Expr::Call(call) => {
+                let arguments = frame.get_all(call.arguments)?;
+
+                match call.call_type.clone().replace_lowlevel_wrapper() {
+                    CallType::ByName {
+                        name,
+                        ret_layout,
+                        arg_layouts,
+                        ..
+                    } => {
+                        let key = (
+                            name.name(),
+                            ProcLayout {
+                                arguments: arg_layouts,
+                                result: ret_layout,
+                                niche: name.niche(),
+                            },
+                        );
+
+                        if arguments.is_empty() && self.thunks.contains(&key.0) {
+                            self.eval_thunk(key)
+                        } else {
+                            self.call_proc(key, arguments)
+                        }
+                    }
+                    CallType::LowLevel { op, .. } => self.eval_lowlevel(op, &arguments, layout),
+                    CallType::ByPointer { .. }
+                    | CallType::Foreign { .. }
+                    | CallType::HigherOrder(_) => Err(Stop::GiveUp),
+                }
+            }
+            Expr::Tag {
+                tag_id, arguments, ..
+            } => Ok(Value::Tag(*tag_id, frame.get_all(arguments)?.into())),
+            Expr::Struct(fields) => Ok(Value::Struct(frame.get_all(fields)?.into())),
+            Expr::StructAtIndex {
+                index, structure, ..
+            } => match frame.get(*structure)? {
+                Value::Struct(fields) => fields.get(*index as usize).cloned().ok_or(Stop::GiveUp),
+                _ => Err(Stop::GiveUp),
+            },
+            Expr::GetTagId { structure, .. } => match frame.get(*structure)? {
+                Value::Tag(tag_id, _) => self.int_with_layout(*tag_id as u128, layout),
+                _ => Err(Stop::GiveUp),
+            },
+            Expr::UnionAtIndex {
+                structure,
+                tag_id,
+                index,
+                ..
+            } => match frame.get(*structure)? {
+                Value::Tag(actual, fields) if actual == tag_id => {
+                    fields.get(*index as usize).cloned().ok_or(Stop::GiveUp)
+                }
+                _ => Err(Stop::GiveUp),
+            },
+            Expr::Array { elem_layout, elems } => {
+                self.burn(elems.len())?;
+
+                let mut list = std::vec::Vec::with_capacity(elems.len());
+                for elem in elems.iter() {
+                    list.push(match elem {
+                        ListLiteralElement::Literal(literal) => {
+                            self.literal(literal, *elem_layout)?
+                        }
+                        ListLiteralElement::Symbol(symbol) => frame.get(*symbol)?.clone(),
+                    });
+                }
+
+                Ok(Value::List(Rc::new(list)))
+            }
+            Expr::EmptyArray => Ok(Value::List(Rc::default())),
+            Expr::NullPointer
+            | Expr::GetElementPointer { .. }
+            | Expr::ErasedMake { .. }
+            | Expr::ErasedLoad { .. }
+            | Expr::FunctionPointer { .. }
+            | Expr::Alloca { .. }
+            | Expr::Reset { .. }
+            | Expr::ResetRef { .. } => Err(Stop::GiveUp),
+        }
+    }
+
+    fn literal(&self, literal: &Literal, layout: InLayout<'a>) -> Result<Value, Stop> {
+        use Builtin::*;
+
+        let value = match (literal, self.repr(layout)) {
+            (Literal::Int(bytes), LayoutRepr::Builtin(Int(width))) => {
+                int(i128::from_ne_bytes(*bytes) as u128, width)
+            }
+            (Literal::U128(bytes), LayoutRepr::Builtin(Int(width))) => {
+                int(u128::from_ne_bytes(*bytes), width)
+            }
+            (Literal::Byte(byte), LayoutRepr::Builtin(Int(width))) => int(*byte as u128, width),
+            (Literal::Float(value), LayoutRepr::Builtin(Float(width))) => float(*value, width),
+            (Literal::Decimal(bytes), LayoutRepr::Builtin(Decimal)) => {
+                Value::Dec(i128::from_ne_bytes(*bytes))
+            }
+            (Literal::Bool(b), LayoutRepr::Builtin(Bool)) => Value::Bool(*b),
+            (Literal::Str(s), LayoutRepr::Builtin(Str)) => Value::Str((*s).into()),
+            _ => return Err(Stop::GiveUp),
+        };
+
+        Ok(value)
+    }
+
+    fn int_with_layout(&self, bits: u128, layout: InLayout<'a>) -> Result<Value, Stop> {
+        match self.repr(layout) {
+            LayoutRepr::Builtin(Builtin::Int(width)) => Ok(int(bits, width)),
+            LayoutRepr::Builtin(Builtin::Bool) => Ok(Value::Bool(bits != 0)),
+            _ => Err(Stop::GiveUp),
+        }
+    }
+
+    fn eval_lowlevel(
+        &mut self,
+        op: LowLevel,
+        arguments: &[Value],
+        layout: InLayout<'a>,
+    ) -> Result<Value, Stop> {
+        use LowLevel::*;
+        use Value::*;
+
+        let value = match (op, arguments) {
+            (Eq, [a, b]) => Bool(a == b),
+            (NotEq, [a, b]) => Bool(a != b),
+            (Not, [Bool(b)]) => Bool(!b),
+
+            (StrConcat, [Str(a), Str(b)]) => {
+                self.burn(a.len() + b.len())?;
+                Str(format!("{a}{b}").into())
+            }
+            (StrJoinWith, [List(strings), Str(separator)]) => {
+                let strings = strings
+                    .iter()
+                    .map(as_str)
+                    .collect::<Result<std::vec::Vec<_>, _>>()?;
+                let joined = strings.join(separator);
+                self.burn(joined.len())?;
+
+                Str(joined.into())
+            }
+            (StrIsEmpty, [Str(s)]) => Bool(s.is_empty()),
+            (StrStartsWith, [Str(s), Str(prefix)]) => Bool(s.starts_with(&**prefix)),
+            (StrEndsWith, [Str(s), Str(suffix)]) => Bool(s.ends_with(&**suffix)),
+            (StrSplitOn, [Str(s), Str(delimiter)]) if !delimiter.is_empty() => {
+                self.burn(s.len())?;
+                List(Rc::new(
+                    s.split(&**delimiter).map(|part| Str(part.into())).collect(),
+                ))
+            }
+            (StrCountUtf8Bytes, [Str(s)]) => Int(s.len() as u128, IntWidth::U64),
+            (StrToUtf8, [Str(s)]) => {
+                self.burn(s.len())?;
+                List(Rc::new(
+                    s.bytes()
+                        .map(|byte| Int(byte as u128, IntWidth::U8))
+                        .collect(),
+                ))
+            }
+            (StrRepeat, [Str(s), count]) => {
+                let count = as_index(count)?;
+                self.burn(s.len().saturating_mul(count))?;
+                Str(s.repeat(count).into())
+            }
+            (StrGetUnsafe, [Str(s), index]) => {
+                let byte = s.as_bytes().get(as_index(index)?).ok_or(Stop::GiveUp)?;
+                Int(*byte as u128, IntWidth::U8)
+            }
+            (StrSubstringUnsafe, [Str(s), start, length]) => {
+                let start = as_index(start)?;
+                let end = start.checked_add(as_index(length)?).ok_or(Stop::GiveUp)?;
+                Str(s.get(start..end).ok_or(Stop::GiveUp)?.into())
+            }
+            (StrWithCapacity, [_]) => Str("".into()),
+            (StrReserve, [s @ Str(_), _]) | (StrReleaseExcessCapacity, [s @ Str(_)]) => s.clone(),
+            (StrWithAsciiLowercased, [Str(s)]) => Str(s.to_ascii_lowercase().into()),
+            (StrWithAsciiUppercased, [Str(s)]) => Str(s.to_ascii_uppercase().into()),
+            (StrCaselessAsciiEquals, [Str(a), Str(b)]) => Bool(a.eq_ignore_ascii_case(b)),
+
+            (ListLenU64, [List(list)]) => Int(list.len() as u128, IntWidth::U64),
+            (ListLenUsize, [List(list)]) => self.int_with_layout(list.len() as u128, layout)?,
+            (ListWithCapacity, [_]) => List(Rc::default()),
+            (ListReserve, [list @ List(_), _])
+            | (ListReleaseExcessCapacity, [list @ List(_)])
+            | (ListClone, [list @ List(_)]) => list.clone(),
+            (ListAppendUnsafe, [List(list), elem]) => {
+                self.burn(list.len())?;
+
+                let mut list = list.as_ref().clone();
+                list.push(elem.clone());
+                List(Rc::new(list))
+            }
+            (ListPrepend, [List(list), elem]) => {
+                self.burn(list.len())?;
+
+                let mut list = list.as_ref().clone();
+                list.insert(0, elem.clone());
+                List(Rc::new(list))
+            }
+            (ListGetUnsafe, [List(list), index]) => {
+                list.get(as_index(index)?).cloned().ok_or(Stop::GiveUp)?
+            }
+            (ListReplaceUnsafe, [List(list), index, elem]) => {
+                let index = as_index(index)?;
+                if index >= list.len() {
+                    return Err(Stop::GiveUp);
+                }
+
+                self.burn(list.len())?;
+
+                let mut list = list.as_ref().clone();
+                let old = std::mem::replace(&mut list[index], elem.clone());
+                let list = List(Rc::new(list));
+
+                // the record is `{ list, value }`, sorted by alignment and then by name
+                let fields = match self.repr(layout) {
+                    LayoutRepr::Struct([first, _]) => match self.repr(*first) {
+                        LayoutRepr::Builtin(Builtin::List(_)) => [list, old],
+                        _ => [old, list],
+                    },
+                    _ => return Err(Stop::GiveUp),
+                };
+
+                Struct(Rc::new(fields))
+            }
+            (ListConcat, [List(a), List(b)]) => {
+                self.burn(a.len() + b.len())?;
+                List(Rc::new(a.iter().chain(b.iter()).cloned().collect()))
+            }
+            (ListSublist, [List(list), start, length]) => {
+                let start = as_index(start)?.min(list.len());
+                let end = start.saturating_add(as_index(length)?).min(list.len());
+                self.burn(end - start)?;
+
+                List(Rc::new(list[start..end].to_vec()))
+            }
+            (ListDropAt, [List(list), index]) => {
+                let index = as_index(index)?;
+                self.burn(list.len())?;
+
+                let mut list = list.as_ref().clone();
+                if index < list.len() {
+                    list.remove(index);
+                }
+                List(Rc::new(list))
+            }
+            (ListSwap, [List(list), i, j]) => {
+                let (i, j) = (as_index(i)?, as_index(j)?);
+                self.burn(list.len())?;
+
+                let mut list = list.as_ref().clone();
+                if i < list.len() && j < list.len() {
+                    list.swap(i, j);
+                }
+                List(Rc::new(list))
+            }
+            (ListConcatUtf8, [List(list), Str(s)]) => {
+                self.burn(list.len() + s.len())?;
+
+                let mut list = list.as_ref().clone();
+                list.extend(s.bytes().map(|byte| Int(byte as u128, IntWidth::U8)));
+                List(Rc::new(list))
+            }
+
+            // The seed is an address only known at runtime. A dict hashed with any other seed
+            // would look up its keys in the wrong buckets.
+            (DictPseudoSeed, _) => return Err(Stop::GiveUp),
+
+            _ => self.eval_num(op, arguments, layout)?,
+        };
+
+        Ok(value)
+    }
+
+    fn eval_num(
+        &mut self,
+        op: LowLevel,
+        arguments: &[Value],
+        layout: InLayout<'a>,
+    ) -> Result<Value, Stop> {
+        use LowLevel::*;
+        use Value::*;
+
+        let value = match (op, arguments) {
+            (
+                NumAdd | NumAddWrap | NumAddSaturated | NumSub | NumSubWrap | NumSubSaturated
+                | NumMul | NumMulWrap | NumMulSaturated | NumBitwiseAnd | NumBitwiseXor
+                | NumBitwiseOr,
+                [Int(a, width), Int(b, _)],
+            ) => with_int_type!(*width, T, {
+                let (x, y) = (*a as T, *b as T);
+
+                let result = match op {
+                    NumAdd => x
+                        .checked_add(y)
+                        .ok_or_else(|| self.crash("Integer addition overflowed!"))?,
+                    NumSub => x
+                        .checked_sub(y)
+                        .ok_or_else(|| self.crash("Integer subtraction overflowed!"))?,
+                    NumMul => x
+                        .checked_mul(y)
+                        .ok_or_else(|| self.crash("Integer multiplication overflowed!"))?,
+                    NumAddWrap => x.wrapping_add(y),
+                    NumSubWrap => x.wrapping_sub(y),
+                    NumMulWrap => x.wrapping_mul(y),
+                    NumAddSaturated => x.saturating_add(y),
+                    NumSubSaturated => x.saturating_sub(y),
+                    NumMulSaturated => x.saturating_mul(y),
+                    NumBitwiseAnd => x & y,
+                    NumBitwiseXor => x ^ y,
+                    _ => x | y,
+                };
+
+                Int(result as i128 as u128, *width)
+            }),
+            (NumAddChecked | NumSubChecked | NumMulChecked, [Int(a, width), Int(b, _)]) => {
+                with_int_type!(*width, T, {
+                    let (x, y) = (*a as T, *b as T);
+
+                    let (result, overflowed) = match op {
+                        NumAddChecked => x.overflowing_add(y),
+                        NumSubChecked => x.overflowing_sub(y),
+                        _ => x.overflowing_mul(y),
+                    };
+
+                    // the record is `{ b : Bool, a : Num a }`, and the number is never less
+                    // aligned than the Bool
+                    Struct(Rc::new([
+                        Int(result as i128 as u128, *width),
+                        Bool(overflowed),
+                    ]))
+                })
+            }
+            (NumAdd | NumSub | NumMul | NumDivFrac, [Float(a, width), Float(b, _)]) => {
+                let result = match op {
+                    NumAdd => a + b,
+                    NumSub => a - b,
+                    NumMul => a * b,
+                    _ => a / b,
+                };
+
+                float(result, *width)
+            }
+            (NumAdd, [Dec(a), Dec(b)]) => Dec(a
+                .checked_add(*b)
+                .ok_or_else(|| self.crash("Decimal addition overflowed!"))?),
+            (NumSub, [Dec(a), Dec(b)]) => Dec(a
+                .checked_sub(*b)
+                .ok_or_else(|| self.crash("Decimal subtraction overflowed!"))?),
+
+            (NumGt | NumGte | NumLt | NumLte, [a, b]) => {
+                let ordering = compare(a, b)?;
+
+                Bool(match op {
+                    NumGt => ordering == Some(Ordering::Greater),
+                    NumGte => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+                    NumLt => ordering == Some(Ordering::Less),
+                    _ => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+                })
+            }
+            (NumCompare, [a, b]) => {
+                // [EQ, GT, LT], in tag id order
+                let tag_id = match compare(a, b)?.ok_or(Stop::GiveUp)? {
+                    Ordering::Equal => 0,
+                    Ordering::Greater => 1,
+                    Ordering::Less => 2,
+                };
+
+                self.int_with_layout(tag_id, layout)?
+            }
+
+            (NumDivTruncUnchecked | NumRemUnchecked, [Int(a, width), Int(b, _)]) => {
+                with_int_type!(*width, T, {
+                    let (x, y) = (*a as T, *b as T);
+
+                    let result = match op {
+                        NumDivTruncUnchecked => x.checked_div(y),
+                        _ => x.checked_rem(y),
+                    };
+
+                    Int(result.ok_or(Stop::GiveUp)? as i128 as u128, *width)
+                })
+            }
+            (NumDivCeilUnchecked, [Int(a, width), Int(b, _)]) => {
+                // the bits are sign-extended, so this tells whether the quotient is positive
+                let same_sign = !width.is_signed() || ((*a as i128) < 0) == ((*b as i128) < 0);
+
+                with_int_type!(*width, T, {
+                    let (x, y) = (*a as T, *b as T);
+
+                    let quotient = x.checked_div(y).ok_or(Stop::GiveUp)?;
+                    let result = if x % y != 0 && same_sign {
+                        quotient + 1
+                    } else {
+                        quotient
+                    };
+
+                    Int(result as i128 as u128, *width)
+                })
+            }
+            (NumIsMultipleOf, [Int(a, width), Int(b, _)]) => with_int_type!(*width, T, {
+                let (x, y) = (*a as T, *b as T);
+
+                if y == 0 {
+                    Bool(x == 0)
+                } else {
+                    // only `MIN % -1` can overflow, and `MIN` is a multiple of -1
+                    Bool(x.checked_rem(y).map_or(true, |remainder| remainder == 0))
+                }
+            }),
+            (NumPowInt, [Int(a, width), Int(b, _)]) => {
+                // negative exponents are sign-extended, so they don't fit either
+                let exponent = u32::try_from(*b).map_err(|_| Stop::GiveUp)?;
+
+                with_int_type!(*width, T, {
+                    let result = (*a as T)
+                        .checked_pow(exponent)
+                        .ok_or_else(|| self.crash("Integer raised to power overflowed!"))?;
+
+                    Int(result as i128 as u128, *width)
+                })
+            }
+            (NumShiftLeftBy | NumShiftRightBy | NumShiftRightZfBy, [Int(a, width), Int(by, _)]) => {
+                let bits = width.stack_size() * 8;
+                // shifting by the whole width or more is left to the runtime
+                let by = u32::try_from(*by)
+                    .ok()
+                    .filter(|by| *by < bits)
+                    .ok_or(Stop::GiveUp)?;
+
+                let mask = u128::MAX >> (128 - bits);
+                let value = a & mask;
+
+                let result = match op {
+                    NumShiftLeftBy => value << by,
+                    NumShiftRightZfBy => value >> by,
+                    _ if (value >> (bits - 1)) & 1 == 1 => (value >> by) | (mask & !(mask >> by)),
+                    _ => value >> by,
+                };
+
+                int(result, *width)
+            }
+            (
+                NumCountLeadingZeroBits | NumCountTrailingZeroBits | NumCountOneBits,
+                [Int(a, width)],
+            ) => {
+                let count = with_int_type!(*width, T, {
+                    let x = *a as T;
+
+                    match op {
+                        NumCountLeadingZeroBits => x.leading_zeros(),
+                        NumCountTrailingZeroBits => x.trailing_zeros(),
+                        _ => x.count_ones(),
+                    }
+                });
+
+                self.int_with_layout(count as u128, layout)?
+            }
+
+            (NumAbs, [int @ Int(_, width)]) if !width.is_signed() => int.clone(),
+            (NumNeg | NumAbs, [Int(a, width)]) if width.is_signed() => {
+                let x = *a as i128;
+                if op == NumAbs && x >= 0 {
+                    return Ok(Int(*a, *width));
+                }
+
+                // negating the minimum value overflows
+                match x.checked_neg() {
+                    Some(negated)
+                        if int(negated as u128, *width) == Int(negated as u128, *width) =>
+                    {
+                        Int(negated as u128, *width)
+                    }
+                    _ if op == NumNeg => {
+                        return Err(self.crash(
+                            "Integer negation overflowed because its argument is the minimum value",
+                        ))
+                    }
+                    _ => {
+                        return Err(self.crash(
+                            "Integer absolute overflowed because its argument is the minimum value",
+                        ))
+                    }
+                }
+            }
+            (NumNeg, [Float(a, width)]) => Float(-a, *width),
+            (NumAbs, [Float(a, width)]) => Float(a.abs(), *width),
+            (NumNeg, [Dec(a)]) => Dec(a.checked_neg().ok_or(Stop::GiveUp)?),
+            (NumAbs, [Dec(a)]) => Dec(a.checked_abs().ok_or(Stop::GiveUp)?),
+
+            (NumIntCast, [Int(a, _)]) => self.int_with_layout(*a, layout)?,
+            (NumToFrac | NumToFloatCast, [Int(a, width)]) => {
+                let signed = width.is_signed();
+
+                match self.repr(layout) {
+                    LayoutRepr::Builtin(Builtin::Float(FloatWidth::F32)) if signed => {
+                        Float(*a as i128 as f32 as f64, FloatWidth::F32)
+                    }
+                    LayoutRepr::Builtin(Builtin::Float(FloatWidth::F32)) => {
+                        Float(*a as f32 as f64, FloatWidth::F32)
+                    }
+                    LayoutRepr::Builtin(Builtin::Float(FloatWidth::F64)) if signed => {
+                        Float(*a as i128 as f64, FloatWidth::F64)
+                    }
+                    LayoutRepr::Builtin(Builtin::Float(FloatWidth::F64)) => {
+                        Float(*a as f64, FloatWidth::F64)
+                    }
+                    LayoutRepr::Builtin(Builtin::Decimal) => {
+                        let whole = if signed {
+                            *a as i128
+                        } else {
+                            i128::try_from(*a).map_err(|_| Stop::GiveUp)?
+                        };
+
+                        Dec(whole.checked_mul(DEC_ONE).ok_or(Stop::GiveUp)?)
+                    }
+                    _ => return Err(Stop::GiveUp),
+                }
+            }
+            (NumToFrac | NumToFloatCast, [Float(a, _)]) => match self.repr(layout) {
+                LayoutRepr::Builtin(Builtin::Float(width)) => float(*a, width),
+                _ => return Err(Stop::GiveUp),
+            },
+            (NumRound | NumFloor | NumCeiling, [Float(a, _)]) => {
+                let rounded = match op {
+                    NumRound => a.round(),
+                    NumFloor => a.floor(),
+                    _ => a.ceil(),
+                };
+
+                match self.repr(layout) {
+                    LayoutRepr::Builtin(Builtin::Int(width)) => {
+                        float_to_int(rounded, width).ok_or(Stop::GiveUp)?
+                    }
+                    _ => return Err(Stop::GiveUp),
+                }
+            }
+            (NumIsNan, [Float(a, _)]) => Bool(a.is_nan()),
+            (NumIsInfinite, [Float(a, _)]) => Bool(a.is_infinite()),
+            (NumIsFinite, [Float(a, _)]) => Bool(a.is_finite()),
+
+            (NumWithoutDecimalPoint, [Dec(a)]) => Int(*a as u128, IntWidth::I128),
+            (NumWithDecimalPoint, [Int(a, _)]) => Dec(*a as i128),
+
+            (NumToStr, [Int(a, width)]) => {
+                with_int_type!(*width, T, { Str((*a as T).to_string().into()) })
+            }
+
+            _ => return Err(Stop::GiveUp),
+        };
+
+        Ok(value)
+    }
+}
+
+fn as_str(value: &Value) -> Result<&str, Stop> {
+    match value {
+        Value::Str(s) => Ok(s),
+        _ => Err(Stop::GiveUp),
+    }
+}
+
+fn as_index(value: &Value) -> Result<usize, Stop> {
+    match value {
+        Value::Int(bits, width) if !width.is_signed() => {
+            usize::try_from(*bits).map_err(|_| Stop::GiveUp)
+        }
+        _ => Err(Stop::GiveUp),
+    }
+}
+
+fn compare(a: &Value, b: &Value) -> Result<Option<Ordering>, Stop> {
+    match (a, b) {
+        (Value::Int(a, width), Value::Int(b, _)) => Ok(with_int_type!(*width, T, {
+            Some((*a as T).cmp(&(*b as T)))
+        })),
+        (Value::Float(a, _), Value::Float(b, _)) => Ok(a.partial_cmp(b)),
+        (Value::Dec(a), Value::Dec(b)) => Ok(Some(a.cmp(b))),
+        _ => Err(Stop::GiveUp),
+    }
+}
+
+/// The literal for a scalar or string value, if the layout has one.
+fn to_literal<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    value: &Value,
+    layout: InLayout<'a>,
+) -> Option<Literal<'a>> {
+    let literal = match (value, interner.get_repr(layout)) {
+        (Value::Int(bits, IntWidth::U128), LayoutRepr::Builtin(Builtin::Int(_))) => {
+            Literal::U128(bits.to_ne_bytes())
+        }
+        (Value::Int(bits, _), LayoutRepr::Builtin(Builtin::Int(_))) => {
+            Literal::Int((*bits as i128).to_ne_bytes())
+        }
+        (Value::Float(value, _), LayoutRepr::Builtin(Builtin::Float(_))) => Literal::Float(*value),
+        (Value::Dec(value), LayoutRepr::Builtin(Builtin::Decimal)) => {
+            Literal::Decimal(value.to_ne_bytes())
+        }
+        (Value::Bool(b), LayoutRepr::Builtin(Builtin::Bool)) => Literal::Bool(*b),
+        (Value::Str(s), LayoutRepr::Builtin(Builtin::Str)) => Literal::Str(arena.alloc_str(s)),
+        _ => return None,
+    };
+
+    Some(literal)
+}
+
+/// Turns an evaluated constant back into IR.
+struct Emitter<'a, 'i> {
+    arena: &'a Bump,
+    interner: &'i mut STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &'i mut IdentIds,
+    lets: std::vec::Vec<(Symbol, Expr<'a>, InLayout<'a>)>,
+    size: usize,
+}
+
+impl<'a, 'i> Emitter<'a, 'i> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn into_body(self, symbol: Symbol) -> Stmt<'a> {
+        let mut stmt = Stmt::Ret(symbol);
+
+        for (symbol, expr, layout) in self.lets.into_iter().rev() {
+            stmt = Stmt::Let(symbol, expr, layout, self.arena.alloc(stmt));
+        }
+
+        stmt
+    }
+
+    fn emit(&mut self, value: &Value, layout: InLayout<'a>) -> Option<Symbol> {
+        self.size += 1;
+        if self.size > MAX_FOLDED_SIZE {
+            return None;
+        }
+
+        let expr = match (value, self.interner.get_repr(layout)) {
+            (_, LayoutRepr::LambdaSet(lambda_set)) => {
+                self.size -= 1;
+                return self.emit(value, lambda_set.runtime_representation());
+            }
+            (Value::List(elems), LayoutRepr::Builtin(Builtin::List(_))) if elems.is_empty() => {
+                Expr::EmptyArray
+            }
+            (Value::List(elems), LayoutRepr::Builtin(Builtin::List(elem_layout))) => {
+                self.size += elems.len();
+                if self.size > MAX_FOLDED_SIZE {
+                    return None;
+                }
+
+                let mut list_elems = Vec::with_capacity_in(elems.len(), self.arena);
+                for elem in elems.iter() {
+                    list_elems.push(
+                        match to_literal(self.arena, self.interner, elem, elem_layout) {
+                            Some(literal) => ListLiteralElement::Literal(literal),
+                            None => ListLiteralElement::Symbol(self.emit(elem, elem_layout)?),
+                        },
+                    );
+                }
+
+                Expr::Array {
+                    elem_layout,
+                    elems: list_elems.into_bump_slice(),
+                }
+            }
+            (Value::Struct(fields), LayoutRepr::Struct(field_layouts))
+                if fields.len() == field_layouts.len() =>
+            {
+                let mut symbols = Vec::with_capacity_in(fields.len(), self.arena);
+                for (field, field_layout) in fields.iter().zip(field_layouts.iter()) {
+                    symbols.push(self.emit(field, *field_layout)?);
+                }
+
+                Expr::Struct(symbols.into_bump_slice())
+            }
+            (Value::Tag(tag_id, fields), LayoutRepr::Union(union_layout)) => {
+                let mut symbols = Vec::with_capacity_in(fields.len(), self.arena);
+                for (index, field) in fields.iter().enumerate() {
+                    let field_layout = union_layout.layout_at(self.interner, *tag_id, index);
+                    symbols.push(self.emit(field, field_layout)?);
+                }
+
+                Expr::Tag {
+                    tag_layout: union_layout,
+                    tag_id: *tag_id,
+                    arguments: symbols.into_bump_slice(),
+                    reuse: None,
+                }
+            }
+            _ => Expr::Literal(to_literal(self.arena, self.interner, value, layout)?),
+        };
+
+        let symbol = self.unique_symbol();
+        self.lets.push((symbol, expr, layout));
+
+        Some(symbol)
+    }
+}
@@ -1608,6 +1608,8 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         | NumF64FromParts => RC::NoRc,
         NumWithoutDecimalPoint | NumWithDecimalPoint => RC::NoRc,
         DictPseudoSeed => RC::NoRc,
+        SetAllocSite => RC::NoRc,
+        RefCountCheckStart | RefCountCheckLeaks => RC::NoRc,
         StrStartsWith | StrEndsWith => RC::NoRc,
         StrFromUtf8 => RC::Rc,
         StrFromUtf8Lossy => RC::Rc,
@@ -1616,7 +1618,7 @@ fn low_level_no_rc(lowlevel: &LowLevel) -> RC {
         StrFromInt | StrFromFloat => RC::NoRc,
         Hash => RC::NoRc,
 
-        ListIsUnique => RC::Rc,
+        ListIsUnique | StrIsUnique => RC::Rc,
         ListClone => RC::Rc,
 
         BoxExpr | UnboxExpr => {
@@ -0,0 +1,610 @@
+//! Diagnostics for in-place updates.
+//!
+//! Builtins like `List.set`, `Str.concat` and `Dict.insert` update their argument in place when
+//! nothing else refers to it, and copy it otherwise. Whether a copy happens can't be seen in the
+//! program, so an accidental one can make a loop quadratic without anybody noticing. To find
+//! those, this module
+//!
+//! - lists the calls for which reference counting had to increment the updated value first, so
+//!   the call copies whenever it runs ([shared_updates]),
+//! - and instruments calls to report, through `dbg`, every copy that happens at runtime
+//!   ([instrument_copies]).
+//!
+//! [trace_clones] does both, for `roc build --trace-clones` and the mono tests.
+//!
+//! The IR does not keep track of regions, so both work on the [CallSites] recorded during
+//! specialization. Those only cover calls in user code: a `Dict.insert` is reported where the user
+//! called it, not where it calls `List.set` internally.
+//!
+//! Both passes expect reference counting to have been inserted already.
+
+use std::path::PathBuf;
+
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_collections::MutMap;
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, Interns, ModuleId, Symbol};
+use roc_region::all::{LineInfo, Region};
+use roc_types::subs::Variable;
+
+use crate::ir::{
+    Call, CallType, Expr, JoinPointId, Literal, ModifyRc, Proc, ProcLayout, Stmt, UpdateModeId,
+};
+use crate::layout::{Builtin, InLayout, Layout, LayoutInterner, LayoutRepr, STLayoutInterner};
+use crate::optimize::{for_each_stmt, stmt_uses};
+
+/// A call by name in user code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CallSite {
+    /// The function that was called, e.g. `List.set`.
+    pub callee: Symbol,
+    /// The module the call is in.
+    pub module_id: ModuleId,
+    pub region: Region,
+}
+
+/// The call sites in user code, keyed by the symbol the result of the call is assigned to. When a
+/// call gets inlined, the calls in the inlined body that have no call site of their own take over
+/// its call site.
+pub type CallSites = MutMap<Symbol, CallSite>;
+
+/// Whether this builtin updates its first argument in place if it's unique, and copies it if not.
+pub fn updates_in_place(symbol: Symbol) -> bool {
+    matches!(
+        symbol,
+        Symbol::LIST_SET
+            | Symbol::LIST_REPLACE
+            | Symbol::LIST_UPDATE
+            | Symbol::LIST_APPEND
+            | Symbol::LIST_PREPEND
+            | Symbol::LIST_CONCAT
+            | Symbol::LIST_SWAP
+            | Symbol::LIST_DROP_AT
+            | Symbol::LIST_RESERVE
+            | Symbol::STR_CONCAT
+            | Symbol::STR_RESERVE
+            | Symbol::DICT_INSERT
+            | Symbol::DICT_REMOVE
+            | Symbol::DICT_UPDATE
+            | Symbol::SET_INSERT
+            | Symbol::SET_REMOVE
+    )
+}
+
+/// The lowlevels the builtins in [updates_in_place] bottom out in. They only show up in user code
+/// once a builtin has been inlined.
+fn lowlevel_updates_in_place(op: LowLevel) -> bool {
+    use LowLevel::*;
+
+    matches!(
+        op,
+        ListReplaceUnsafe
+            | ListAppendUnsafe
+            | ListPrepend
+            | ListConcat
+            | ListSwap
+            | ListDropAt
+            | ListReserve
+            | StrConcat
+            | StrReserve
+    )
+}
+
+/// If this is a recorded in-place update, returns its call site and the symbol that is updated.
+fn in_place_update(
+    call_sites: &CallSites,
+    symbol: Symbol,
+    expr: &Expr,
+) -> Option<(CallSite, Symbol)> {
+    let Expr::Call(Call {
+        call_type,
+        arguments,
+    }) = expr
+    else {
+        return None;
+    };
+
+    let updates = match call_type {
+        CallType::ByName { name, .. } => updates_in_place(name.name()),
+        CallType::LowLevel { op, .. } => lowlevel_updates_in_place(*op),
+        _ => false,
+    };
+
+    if !updates {
+        return None;
+    }
+
+    let site = call_sites
+        .get(&symbol)
+        .filter(|site| updates_in_place(site.callee))?;
+
+    Some((*site, *arguments.first()?))
+}
+
+/// Why an in-place update can't happen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SharedReason {
+    /// The value is used again after the update, e.g. later on or in the next iteration of a loop.
+    UsedLater,
+    /// The value is borrowed, e.g. it's a parameter that the caller keeps using.
+    Borrowed,
+}
+
+/// A call that copies the value it updates every time it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SharedUpdate {
+    pub site: CallSite,
+    pub reason: SharedReason,
+}
+
+/// Finds the updates that reference counting could not prove unique: their argument is
+/// incremented right before the call, so the builtin always gets a shared value. The result has
+/// one entry per call site, sorted by where they are in their module.
+pub fn shared_updates<'a>(
+    procs: &MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    call_sites: &CallSites,
+) -> std::vec::Vec<SharedUpdate> {
+    let mut found = std::vec::Vec::new();
+
+    for proc in procs.values() {
+        for_each_stmt(&proc.body, |stmt| {
+            // an update of a shared value looks like
+            //
+            // inc x;
+            // let y = List.set x i v;
+            let mut incremented = std::vec::Vec::new();
+            let mut stmt = stmt;
+            while let Stmt::Refcounting(ModifyRc::Inc(symbol, _), continuation) = stmt {
+                incremented.push(*symbol);
+                stmt = continuation;
+            }
+
+            if incremented.is_empty() {
+                return;
+            }
+
+            if let Stmt::Let(symbol, expr, _, _) = stmt {
+                if let Some((site, argument)) = in_place_update(call_sites, *symbol, expr) {
+                    if incremented.contains(&argument) {
+                        // the update itself is one use
+                        let reason = if count_uses(&proc.body, argument) > 1 {
+                            SharedReason::UsedLater
+                        } else {
+                            SharedReason::Borrowed
+                        };
+
+                        // a call site shows up once for every specialization of its function
+                        if !found
+                            .iter()
+                            .any(|update: &SharedUpdate| update.site == site)
+                        {
+                            found.push(SharedUpdate { site, reason });
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    found.sort_by_key(|update| (update.site.region.start(), update.site.callee));
+
+    found
+}
+
+/// How often the statement uses the symbol, not counting changes to its reference count.
+fn count_uses(stmt: &Stmt, symbol: Symbol) -> usize {
+    let mut uses = 0;
+
+    for_each_stmt(stmt, |stmt| {
+        if !matches!(stmt, Stmt::Refcounting(..)) {
+            stmt_uses(stmt, |used| uses += (used == symbol) as usize);
+        }
+    });
+
+    uses
+}
+
+/// A call site as we show it to users
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedSite {
+    /// Where the call is, like `main.roc:12:9`
+    pub location: String,
+    /// The function it calls, like `List.set`
+    pub callee: String,
+}
+
+/// Turns call sites into the text we show users, like `main.roc:12:9` and `List.set`.
+pub struct SiteNames<'s> {
+    sources: &'s MutMap<ModuleId, (PathBuf, Box<str>)>,
+    line_infos: MutMap<ModuleId, LineInfo>,
+    /// Looked up front, so that we don't hold on to the interns while the instrumentation makes
+    /// new symbols.
+    callees: MutMap<Symbol, String>,
+}
+
+impl<'s> SiteNames<'s> {
+    pub fn new(
+        interns: &Interns,
+        sources: &'s MutMap<ModuleId, (PathBuf, Box<str>)>,
+        call_sites: &CallSites,
+    ) -> Self {
+        let callees = call_sites
+            .values()
+            .map(|site| {
+                let callee = site.callee;
+                let name = format!(
+                    "{}.{}",
+                    callee.module_string(interns),
+                    callee.as_str(interns)
+                );
+
+                (callee, name)
+            })
+            .collect();
+
+        Self {
+            sources,
+            line_infos: MutMap::default(),
+            callees,
+        }
+    }
+
+    pub fn location(&mut self, site: &CallSite) -> String {
+        let Some((path, src)) = self.sources.get(&site.module_id) else {
+            return format!("{:?}", site.region);
+        };
+
+        let line_info = self
+            .line_infos
+            .entry(site.module_id)
+            .or_insert_with(|| LineInfo::new(src));
+        let start = line_info.convert_pos(site.region.start());
+
+        format!(
+            "{}:{}:{}",
+            path.to_string_lossy(),
+            start.line + 1,
+            start.column + 1
+        )
+    }
+
+    pub fn callee(&self, site: &CallSite) -> &str {
+        &self.callees[&site.callee]
+    }
+
+    pub fn name(&mut self, site: &CallSite) -> NamedSite {
+        NamedSite {
+            location: self.location(site),
+            callee: self.callee(site).to_string(),
+        }
+    }
+}
+
+/// Finds the [shared_updates] and instruments the procs with [instrument_copies]. Returns the
+/// updates that copy every time, named for showing them to users.
+pub fn trace_clones<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    home: ModuleId,
+    interns: &mut Interns,
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    call_sites: &CallSites,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) -> std::vec::Vec<(NamedSite, SharedReason)> {
+    let mut names = SiteNames::new(interns, sources, call_sites);
+
+    let shared = shared_updates(procs, call_sites)
+        .into_iter()
+        .map(|update| (names.name(&update.site), update.reason))
+        .collect();
+
+    instrument_copies(
+        arena,
+        interner,
+        home,
+        interns.all_ident_ids.get_mut(&home).unwrap(),
+        call_sites,
+        &mut names,
+        procs,
+    );
+
+    shared
+}
+
+/// Makes every recorded in-place update check whether its argument is unique right before the
+/// call, and `dbg` a message naming the call site if it isn't.
+///
+/// ```text
+/// inc x;
+/// let y = List.set x i v;
+/// ...
+/// ```
+///
+/// becomes
+///
+/// ```text
+/// inc x;
+/// joinpoint j =
+///     let y = List.set x i v;
+///     ...
+/// in
+/// let u = lowlevel ListIsUnique x;
+/// if u then jump j else (dbg "main.roc:3:5" "List.set" "copied a shared value"; jump j)
+/// ```
+pub fn instrument_copies<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    call_sites: &CallSites,
+    names: &mut SiteNames,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) {
+    if call_sites.is_empty() {
+        return;
+    }
+
+    for proc in procs.values_mut() {
+        let mut instrumenter = Instrumenter {
+            arena,
+            interner,
+            home,
+            ident_ids,
+            call_sites,
+            names,
+            layouts: proc
+                .args
+                .iter()
+                .map(|(layout, symbol)| (*symbol, *layout))
+                .collect(),
+            ret_layout: proc.ret_layout,
+        };
+
+        proc.body = instrumenter.instrument_stmt(&proc.body).clone();
+    }
+}
+
+struct Instrumenter<'a, 'r, 's> {
+    arena: &'a Bump,
+    interner: &'r STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &'r mut IdentIds,
+    call_sites: &'r CallSites,
+    names: &'r mut SiteNames<'s>,
+    layouts: MutMap<Symbol, InLayout<'a>>,
+    ret_layout: InLayout<'a>,
+}
+
+impl<'a, 'r, 's> Instrumenter<'a, 'r, 's> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn instrument_stmt(&mut self, stmt: &Stmt<'a>) -> &'a Stmt<'a> {
+        let arena = self.arena;
+
+        // chains of lets can get very long, so we walk those in a loop rather than recursively
+        let mut chain = std::vec::Vec::new();
+        let mut stmt = stmt;
+        loop {
+            match stmt {
+                Stmt::Let(symbol, _, layout, continuation) => {
+                    self.layouts.insert(*symbol, *layout);
+                    chain.push(stmt);
+                    stmt = continuation;
+                }
+                Stmt::Refcounting(_, continuation) => {
+                    chain.push(stmt);
+                    stmt = continuation;
+                }
+                _ => break,
+            }
+        }
+
+        let tail = match stmt {
+            Stmt::Let(..) | Stmt::Refcounting(..) => unreachable!("handled above"),
+            Stmt::Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let branches = Vec::from_iter_in(
+                    branches.iter().map(|(label, info, branch)| {
+                        (*label, info.clone(), self.instrument_stmt(branch).clone())
+                    }),
+                    arena,
+                );
+
+                Stmt::Switch {
+                    cond_symbol: *cond_symbol,
+                    cond_layout: *cond_layout,
+                    branches: branches.into_bump_slice(),
+                    default_branch: (
+                        default_branch.0.clone(),
+                        self.instrument_stmt(default_branch.1),
+                    ),
+                    ret_layout: *ret_layout,
+                }
+            }
+            Stmt::Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => Stmt::Expect {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder: self.instrument_stmt(remainder),
+            },
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                variable,
+                remainder,
+            } => Stmt::Dbg {
+                source_location,
+                source,
+                symbol: *symbol,
+                variable: *variable,
+                remainder: self.instrument_stmt(remainder),
+            },
+            Stmt::Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => {
+                for param in parameters.iter() {
+                    self.layouts.insert(param.symbol, param.layout);
+                }
+
+                Stmt::Join {
+                    id: *id,
+                    parameters,
+                    body: self.instrument_stmt(body),
+                    remainder: self.instrument_stmt(remainder),
+                }
+            }
+            Stmt::Ret(_) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => stmt.clone(),
+        };
+
+        let mut result: &'a Stmt<'a> = arena.alloc(tail);
+
+        for stmt in chain.into_iter().rev() {
+            result = match stmt {
+                Stmt::Let(symbol, expr, layout, _) => {
+                    let call = arena.alloc(Stmt::Let(*symbol, expr.clone(), *layout, result));
+
+                    match in_place_update(self.call_sites, *symbol, expr) {
+                        Some((site, argument)) => self.check_copy(site, argument, call),
+                        None => call,
+                    }
+                }
+                Stmt::Refcounting(modify_rc, _) => {
+                    arena.alloc(Stmt::Refcounting(*modify_rc, result))
+                }
+                _ => unreachable!("only lets and refcounting are chained"),
+            };
+        }
+
+        result
+    }
+
+    /// Puts a uniqueness check of the argument in front of the call.
+    fn check_copy(&mut self, site: CallSite, argument: Symbol, call: &'a Stmt<'a>) -> &'a Stmt<'a> {
+        let arena = self.arena;
+
+        let Some(layout) = self.layouts.get(&argument) else {
+            return call;
+        };
+
+        let Some((path, op)) = shared_part(self.interner, *layout) else {
+            return call;
+        };
+
+        let id = JoinPointId(self.unique_symbol());
+        let jump = arena.alloc(Stmt::Jump(id, &[]));
+
+        let message = self.unique_symbol();
+        let copied = Stmt::Let(
+            message,
+            Expr::Literal(Literal::Str("copied a shared value")),
+            Layout::STR,
+            arena.alloc(Stmt::Dbg {
+                source_location: arena.alloc_str(&self.names.location(&site)),
+                source: arena.alloc_str(self.names.callee(&site)),
+                symbol: message,
+                variable: Variable::STR,
+                remainder: jump,
+            }),
+        );
+
+        let is_unique = self.unique_symbol();
+        let switch = Stmt::if_then_else(
+            arena,
+            is_unique,
+            self.ret_layout,
+            jump.clone(),
+            arena.alloc(copied),
+        );
+
+        let mut projections = std::vec::Vec::with_capacity(path.len());
+        let mut value = argument;
+        for (index, field_layouts) in path {
+            let field = self.unique_symbol();
+            let expr = Expr::StructAtIndex {
+                index,
+                field_layouts,
+                structure: value,
+            };
+
+            projections.push((field, expr, field_layouts[index as usize]));
+            value = field;
+        }
+
+        let check = Expr::Call(Call {
+            call_type: CallType::LowLevel {
+                op,
+                update_mode: UpdateModeId::BACKEND_DUMMY,
+            },
+            arguments: arena.alloc([value]),
+        });
+
+        let mut result: &'a Stmt<'a> = arena.alloc(Stmt::Let(
+            is_unique,
+            check,
+            Layout::BOOL,
+            arena.alloc(switch),
+        ));
+
+        for (field, expr, field_layout) in projections.into_iter().rev() {
+            result = arena.alloc(Stmt::Let(field, expr, field_layout, result));
+        }
+
+        arena.alloc(Stmt::Join {
+            id,
+            parameters: &[],
+            body: call,
+            remainder: result,
+        })
+    }
+}
+
+/// The struct fields to go through to get to a value: their indices, and the field layouts of the
+/// structs they're in.
+type FieldPath<'a> = std::vec::Vec<(u64, &'a [InLayout<'a>])>;
+
+/// Where the reference count that decides whether a value of this layout gets copied lives: a
+/// list or string, or the first list or string in a struct, like the record behind a `Dict`. All
+/// of those are shared whenever the struct is. Returns the path of struct fields that leads there,
+/// and the lowlevel that checks it.
+fn shared_part<'a>(
+    interner: &STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+) -> Option<(FieldPath<'a>, LowLevel)> {
+    match interner.get_repr(layout) {
+        LayoutRepr::Builtin(Builtin::List(_)) => Some((vec![], LowLevel::ListIsUnique)),
+        LayoutRepr::Builtin(Builtin::Str) => Some((vec![], LowLevel::StrIsUnique)),
+        LayoutRepr::Struct(field_layouts) => {
+            field_layouts
+                .iter()
+                .enumerate()
+                .find_map(|(index, field_layout)| {
+                    let (mut path, op) = shared_part(interner, *field_layout)?;
+                    path.insert(0, (index as u64, field_layouts));
+
+                    Some((path, op))
+                })
+        }
+        _ => None,
+    }
+}
@@ -1232,6 +1232,9 @@ pub(crate) fn lowlevel_borrow_signature(op: LowLevel) -> &'static [Ownership] {
     match op {
         Unreachable => &[IRRELEVANT],
         DictPseudoSeed => &[IRRELEVANT],
+        SetAllocSite => &[IRRELEVANT],
+        RefCountCheckStart => &[BORROWED, IRRELEVANT],
+        RefCountCheckLeaks => &[],
         ListLenU64 | ListLenUsize | StrIsEmpty | StrCountUtf8Bytes | ListGetCapacity => &[BORROWED],
         ListWithCapacity | StrWithCapacity => &[IRRELEVANT],
         ListReplaceUnsafe => &[OWNED, IRRELEVANT, IRRELEVANT],
@@ -1311,7 +1314,7 @@ pub(crate) fn lowlevel_borrow_signature(op: LowLevel) -> &'static [Ownership] {
         StrFromInt | StrFromFloat => &[IRRELEVANT],
         Hash => &[BORROWED, IRRELEVANT],
 
-        ListIsUnique => &[BORROWED],
+        ListIsUnique | StrIsUnique => &[BORROWED],
         ListClone => &[OWNED],
 
         BoxExpr | UnboxExpr => {
@@ -1,5 +1,6 @@
 #![allow(clippy::manual_map)]
 
+use crate::in_place::{CallSite, CallSites};
 use crate::ir::erased::{build_erased_function, ResolvedErasedLambda};
 use crate::ir::literal::{make_num_literal, IntOrFloatValue};
 use crate::layout::{
@@ -924,6 +925,9 @@ pub struct Procs<'a> {
     pub imported_module_thunks: &'a [Symbol],
     pub module_thunks: &'a [Symbol],
     pub host_exposed_symbols: &'a [Symbol],
+
+    /// Where user code makes calls, for [crate::in_place] and [crate::alloc_profile].
+    pub call_sites: CallSites,
 }
 
 impl<'a> Procs<'a> {
@@ -942,6 +946,8 @@ impl<'a> Procs<'a> {
             imported_module_thunks: &[],
             module_thunks: &[],
             host_exposed_symbols: &[],
+
+            call_sites: CallSites::default(),
         }
     }
 
@@ -1728,7 +1734,7 @@ impl<'a> Call<'a> {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CallSpecId {
     id: u32,
 }
@@ -1738,6 +1744,11 @@ impl CallSpecId {
         self.id.to_ne_bytes()
     }
 
+    /// The id after this one; used to hand out fresh ids after specialization
+    pub(crate) fn next(self) -> Self {
+        Self { id: self.id + 1 }
+    }
+
     /// Dummy value for generating refcount helper procs in the backends
     /// This happens *after* specialization so it's safe
     pub const BACKEND_DUMMY: Self = Self { id: 0 };
@@ -4621,7 +4632,31 @@ pub fn with_hole<'a>(
 
         EmptyRecord => let_empty_struct(assigned, hole),
 
-        Expect { .. } => unreachable!("I think this is unreachable"),
+        Expect {
+            loc_condition,
+            loc_continuation,
+            lookups_in_cond,
+        } => {
+            // e.g. an `expect` in a block inside a top-level `expect`
+            let rest = with_hole(
+                env,
+                loc_continuation.value,
+                variable,
+                procs,
+                layout_cache,
+                assigned,
+                hole,
+            );
+
+            compile_expect(
+                env,
+                procs,
+                layout_cache,
+                *loc_condition,
+                lookups_in_cond,
+                rest,
+            )
+        }
         Dbg {
             source_location,
             source,
@@ -5450,6 +5485,16 @@ pub fn with_hole<'a>(
 
             match loc_expr.value {
                 roc_can::expr::Expr::Var(proc_name, _) if is_known(proc_name) => {
+                    if !env.home.is_builtin() {
+                        let site = CallSite {
+                            callee: proc_name,
+                            module_id: env.home,
+                            region: loc_expr.region,
+                        };
+
+                        procs.call_sites.insert(assigned, site);
+                    }
+
                     // a call by a known name
                     call_by_name(
                         env,
@@ -6444,6 +6489,76 @@ where
     result
 }
 
+/// Stores the payload of a niche-filled tag in the byte that represents the whole union.
+#[allow(clippy::too_many_arguments)]
+fn niche_filled_tag<'a>(
+    env: &mut Env<'a, '_>,
+    layout_cache: &LayoutCache<'a>,
+    niche_layout: InLayout<'a>,
+    start: u8,
+    payload: Symbol,
+    payload_layout: InLayout<'a>,
+    assigned: Symbol,
+    hole: &'a Stmt<'a>,
+) -> Stmt<'a> {
+    let arena = env.arena;
+
+    match layout_cache.get_repr(payload_layout) {
+        LayoutRepr::Builtin(Builtin::Bool) => {
+            // there is no arithmetic on bools, so branch to pick the value instead
+            let id = JoinPointId(env.unique_symbol());
+
+            let mut jump_with = |value| {
+                let symbol = env.unique_symbol();
+                let jump = arena.alloc(Stmt::Jump(id, arena.alloc([symbol])));
+
+                Stmt::Let(
+                    symbol,
+                    Expr::Literal(Literal::Byte(value)),
+                    niche_layout,
+                    jump,
+                )
+            };
+
+            let pass = jump_with(start + 1);
+            let fail = jump_with(start);
+
+            let switch = cond(env, payload, payload_layout, pass, fail, niche_layout);
+
+            let param = Param {
+                symbol: assigned,
+                layout: niche_layout,
+            };
+
+            Stmt::Join {
+                id,
+                parameters: arena.alloc([param]),
+                body: hole,
+                remainder: arena.alloc(switch),
+            }
+        }
+        _ => {
+            let offset = env.unique_symbol();
+            let add = Expr::Call(Call {
+                call_type: CallType::LowLevel {
+                    op: LowLevel::NumAddWrap,
+                    update_mode: env.next_update_mode_id(),
+                },
+                arguments: arena.alloc([payload, offset]),
+            });
+
+            let stmt = Stmt::Let(assigned, add, niche_layout, hole);
+
+            Stmt::Let(
+                offset,
+                Expr::Literal(Literal::Byte(start)),
+                payload_layout,
+                arena.alloc(stmt),
+            )
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn convert_tag_union<'a>(
     env: &mut Env<'a, '_>,
@@ -6510,6 +6625,48 @@ fn convert_tag_union<'a>(
                 None => runtime_error(env, "tag must be in its own type"),
             }
         }
+        NicheFilled { tag_names, niche } => {
+            let opt_tag_id = tag_names
+                .iter()
+                .position(|key| key.expect_tag_ref() == &tag_name);
+
+            let tag_id = match opt_tag_id {
+                Some(tag_id) => tag_id as TagIdIntType,
+                None => return runtime_error(env, "tag must be in its own type"),
+            };
+
+            let niche_layout = return_on_layout_error!(
+                env,
+                layout_cache.from_var(env.arena, variant_var, env.subs),
+                "NicheFilled"
+            );
+
+            match niche.payload(tag_id) {
+                None => Stmt::Let(
+                    assigned,
+                    Expr::Literal(Literal::Byte(niche.start(tag_id))),
+                    niche_layout,
+                    hole,
+                ),
+                Some(payload_layout) => {
+                    let field_symbols_temp = sorted_field_symbols(env, procs, layout_cache, args);
+
+                    let stmt = niche_filled_tag(
+                        env,
+                        layout_cache,
+                        niche_layout,
+                        niche.start(tag_id),
+                        field_symbols_temp[0].1,
+                        payload_layout,
+                        assigned,
+                        hole,
+                    );
+
+                    let iter = field_symbols_temp.into_iter().map(|(_, _, data)| data);
+                    assign_to_symbols(env, procs, layout_cache, iter, stmt)
+                }
+            }
+        }
 
         Newtype {
             arguments: field_layouts,
@@ -7110,67 +7267,15 @@ pub fn from_can<'a>(
             lookups_in_cond,
         } => {
             let rest = from_can(env, variable, loc_continuation.value, procs, layout_cache);
-            let cond_symbol = env.unique_symbol();
-
-            let mut lookups = Vec::with_capacity_in(lookups_in_cond.len(), env.arena);
-            let mut lookup_variables = Vec::with_capacity_in(lookups_in_cond.len(), env.arena);
-            let mut specialized_variables = Vec::with_capacity_in(lookups_in_cond.len(), env.arena);
 
-            for ExpectLookup {
-                symbol,
-                var,
-                ability_info,
-            } in lookups_in_cond.iter().copied()
-            {
-                let symbol = match ability_info {
-                    Some(specialization_id) => late_resolve_ability_specialization(
-                        env,
-                        symbol,
-                        Some(specialization_id),
-                        var,
-                    ),
-                    None => symbol,
-                };
-
-                let expectation_subs = env
-                    .expectation_subs
-                    .as_deref_mut()
-                    .expect("if expects are compiled, their subs should be available");
-                let spec_var = expectation_subs.fresh_unnamed_flex_var();
-
-                if !env.subs.is_function(var) {
-                    // Exclude functions from lookups
-                    lookups.push(symbol);
-                    lookup_variables.push(var);
-                    specialized_variables.push(spec_var);
-                }
-            }
-
-            let specialized_variables = specialized_variables.into_bump_slice();
-
-            let mut stmt = Stmt::Expect {
-                condition: cond_symbol,
-                region: loc_condition.region,
-                lookups: lookups.into_bump_slice(),
-                variables: specialized_variables,
-                remainder: env.arena.alloc(rest),
-            };
-
-            stmt = with_hole(
+            compile_expect(
                 env,
-                loc_condition.value,
-                Variable::BOOL,
                 procs,
                 layout_cache,
-                cond_symbol,
-                env.arena.alloc(stmt),
-            );
-
-            // Now that the condition has been specialized, export the specialized types of our
-            // lookups into the expectation subs.
-            store_specialized_expectation_lookups(env, lookup_variables, specialized_variables);
-
-            stmt
+                *loc_condition,
+                lookups_in_cond,
+                rest,
+            )
         }
 
         Dbg {
@@ -7231,6 +7336,74 @@ pub fn from_can<'a>(
     }
 }
 
+fn compile_expect<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    loc_condition: Loc<roc_can::expr::Expr>,
+    lookups_in_cond: std::vec::Vec<ExpectLookup>,
+    rest: Stmt<'a>,
+) -> Stmt<'a> {
+    let cond_symbol = env.unique_symbol();
+
+    let mut lookups = Vec::with_capacity_in(lookups_in_cond.len(), env.arena);
+    let mut lookup_variables = Vec::with_capacity_in(lookups_in_cond.len(), env.arena);
+    let mut specialized_variables = Vec::with_capacity_in(lookups_in_cond.len(), env.arena);
+
+    for ExpectLookup {
+        symbol,
+        var,
+        ability_info,
+    } in lookups_in_cond.iter().copied()
+    {
+        let symbol = match ability_info {
+            Some(specialization_id) => {
+                late_resolve_ability_specialization(env, symbol, Some(specialization_id), var)
+            }
+            None => symbol,
+        };
+
+        let expectation_subs = env
+            .expectation_subs
+            .as_deref_mut()
+            .expect("if expects are compiled, their subs should be available");
+        let spec_var = expectation_subs.fresh_unnamed_flex_var();
+
+        if !env.subs.is_function(var) {
+            // Exclude functions from lookups
+            lookups.push(symbol);
+            lookup_variables.push(var);
+            specialized_variables.push(spec_var);
+        }
+    }
+
+    let specialized_variables = specialized_variables.into_bump_slice();
+
+    let mut stmt = Stmt::Expect {
+        condition: cond_symbol,
+        region: loc_condition.region,
+        lookups: lookups.into_bump_slice(),
+        variables: specialized_variables,
+        remainder: env.arena.alloc(rest),
+    };
+
+    stmt = with_hole(
+        env,
+        loc_condition.value,
+        Variable::BOOL,
+        procs,
+        layout_cache,
+        cond_symbol,
+        env.arena.alloc(stmt),
+    );
+
+    // Now that the condition has been specialized, export the specialized types of our
+    // lookups into the expectation subs.
+    store_specialized_expectation_lookups(env, lookup_variables, specialized_variables);
+
+    stmt
+}
+
 fn store_specialized_expectation_lookups(
     env: &mut Env,
     lookup_variables: impl IntoIterator<Item = Variable>,
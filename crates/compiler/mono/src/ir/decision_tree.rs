@@ -1,11 +1,13 @@
-use super::pattern::{build_list_index_probe, store_pattern, DestructType, ListIndex, Pattern};
+use super::pattern::{
+    build_list_index_probe, niche_payload_stores, store_pattern, DestructType, ListIndex, Pattern,
+};
 use crate::ir::{
     substitute_in_exprs_many, BranchInfo, Call, CallType, CompiledGuardStmt, Env, Expr,
     GuardStmtSpec, JoinPointId, Literal, Param, Procs, Stmt,
 };
 use crate::layout::{
-    Builtin, InLayout, Layout, LayoutCache, LayoutInterner, LayoutRepr, TLLayoutInterner,
-    TagIdIntType, UnionLayout,
+    Builtin, InLayout, Layout, LayoutCache, LayoutInterner, LayoutRepr, NicheUnion,
+    TLLayoutInterner, TagIdIntType, UnionLayout,
 };
 use roc_builtins::bitcode::{FloatWidth, IntWidth};
 use roc_collections::all::{MutMap, MutSet};
@@ -117,6 +119,11 @@ enum Test<'a> {
         tag_id: TagIdIntType,
         num_alts: usize,
     },
+    IsNiche {
+        tag_id: TagIdIntType,
+        niche: NicheUnion<'a>,
+        num_alts: usize,
+    },
     IsListLen {
         bound: ListLenBound,
         len: u64,
@@ -136,6 +143,8 @@ impl<'a> Test<'a> {
             Test::IsStr(_) => false,
             Test::IsBit(_) => true,
             Test::IsByte { .. } => true,
+            // a tag owns a range of values, so needs more than an equality check
+            Test::IsNiche { .. } => false,
             Test::IsListLen { bound, .. } => match bound {
                 ListLenBound::Exact => true,
                 ListLenBound::AtLeast => false,
@@ -186,6 +195,13 @@ impl<'a> Hash for Test<'a> {
                 state.write_u8(7);
                 (len, bound).hash(state);
             }
+            IsNiche {
+                tag_id, num_alts, ..
+            } => {
+                state.write_u8(8);
+                tag_id.hash(state);
+                num_alts.hash(state);
+            }
         }
     }
 }
@@ -363,6 +379,7 @@ fn tests_are_complete_help(last_test: &Test, number_of_tests: usize) -> bool {
     match last_test {
         Test::IsCtor { union, .. } => number_of_tests == union.alternatives.len(),
         Test::IsByte { num_alts, .. } => number_of_tests == *num_alts,
+        Test::IsNiche { num_alts, .. } => number_of_tests == *num_alts,
         Test::IsBit(_) => number_of_tests == 2,
         Test::IsInt(_, _) => false,
         Test::IsFloat(_, _) => false,
@@ -632,6 +649,17 @@ fn test_for_pattern<'a>(pattern: &Pattern<'a>) -> Option<Test<'a>> {
             arguments: arguments.to_vec(),
         },
 
+        NicheTag {
+            tag_id,
+            niche,
+            union,
+            ..
+        } => IsNiche {
+            tag_id: *tag_id,
+            niche: *niche,
+            num_alts: union.alternatives.len(),
+        },
+
         List {
             arity,
             list_layout: _,
@@ -1076,6 +1104,28 @@ fn to_relevant_branch_help<'a>(
                 _ => None,
             }
         }
+        NicheTag {
+            tag_id, arguments, ..
+        } => match test {
+            IsNiche {
+                tag_id: test_id, ..
+            } if tag_id == *test_id => {
+                let sub_positions = arguments.into_iter().map(|(pattern, _)| {
+                    let mut new_path = path.to_vec();
+                    new_path.push(PathInstruction::TagIndex { index: 0, tag_id });
+                    (new_path, pattern)
+                });
+                start.extend(sub_positions);
+                start.extend(end);
+
+                Some(Branch {
+                    goal: branch.goal,
+                    guard: branch.guard.clone(),
+                    patterns: start,
+                })
+            }
+            _ => None,
+        },
         Voided { .. } => internal_error!("unreachable"),
         StrLiteral(string) => match test {
             IsStr(test_str) if string == *test_str => {
@@ -1216,6 +1266,7 @@ fn needs_tests(pattern: &Pattern) -> bool {
         | RecordDestructure(..)
         | TupleDestructure(..)
         | AppliedTag { .. }
+        | NicheTag { .. }
         | OpaqueUnwrap { .. }
         | BitLiteral { .. }
         | EnumLiteral { .. }
@@ -1575,6 +1626,28 @@ fn path_to_expr_help<'a>(
             PathInstruction::TagIndex { index, tag_id } => {
                 let index = *index;
 
+                if let Some(niche) = layout_interner.get_semantic(layout).niche_union() {
+                    debug_assert_eq!(index, 0);
+
+                    let payload_layout = niche.payload(*tag_id).unwrap();
+                    let payload = env.unique_symbol();
+
+                    stores.extend(niche_payload_stores(
+                        env,
+                        layout_interner,
+                        symbol,
+                        layout,
+                        niche.start(*tag_id),
+                        payload,
+                        payload_layout,
+                    ));
+
+                    symbol = payload;
+                    layout = payload_layout;
+
+                    continue;
+                }
+
                 match layout_interner.chase_recursive(layout) {
                     LayoutRepr::Union(union_layout) => {
                         let inner_expr = Expr::UnionAtIndex {
@@ -1744,6 +1817,46 @@ fn test_to_comparison<'a>(
             (stores, (lhs_symbol, Comparator::Eq, rhs_symbol), None)
         }
 
+        Test::IsNiche { tag_id, niche, .. } => {
+            let start = niche.start(tag_id);
+
+            match niche.number_of_values(tag_id) {
+                1 => {
+                    let lhs = Expr::Literal(Literal::Byte(start));
+                    let lhs_symbol = env.unique_symbol();
+                    stores.push((lhs_symbol, test_layout, lhs));
+
+                    (stores, (lhs_symbol, Comparator::Eq, rhs_symbol), None)
+                }
+                number_of_values => {
+                    // values below `start` wrap around to the top when it is subtracted, so a
+                    // single comparison checks that the value is in `start..start + number_of_values`
+                    let start_symbol = env.unique_symbol();
+                    let offset_symbol = env.unique_symbol();
+                    let last_symbol = env.unique_symbol();
+
+                    let offset = Expr::Call(Call {
+                        call_type: CallType::LowLevel {
+                            op: LowLevel::NumSubWrap,
+                            update_mode: env.next_update_mode_id(),
+                        },
+                        arguments: env.arena.alloc([rhs_symbol, start_symbol]),
+                    });
+                    let last = Expr::Literal(Literal::Byte((number_of_values - 1) as u8));
+
+                    stores.push((
+                        start_symbol,
+                        test_layout,
+                        Expr::Literal(Literal::Byte(start)),
+                    ));
+                    stores.push((offset_symbol, test_layout, offset));
+                    stores.push((last_symbol, test_layout, last));
+
+                    (stores, (last_symbol, Comparator::Geq, offset_symbol), None)
+                }
+            }
+        }
+
         Test::IsBit(test_bit) => {
             let lhs = Expr::Literal(Literal::Bool(test_bit));
             let lhs_symbol = env.unique_symbol();
@@ -2256,6 +2369,7 @@ fn decide_to_branching<'a>(
                     Test::IsBit(v) => v as u64,
                     Test::IsByte { tag_id, .. } => tag_id as u64,
                     Test::IsCtor { tag_id, .. } => tag_id as u64,
+                    Test::IsNiche { .. } => unreachable!("niche-filled tags cannot be switched on"),
                     Test::IsListLen { len, bound } => match bound {
                         ListLenBound::Exact => len as _,
                         ListLenBound::AtLeast => {
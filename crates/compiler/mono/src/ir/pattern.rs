@@ -1,7 +1,7 @@
 use crate::ir::{substitute_in_exprs, Env, Expr, Procs, Stmt};
 use crate::layout::{
     self, Builtin, InLayout, Layout, LayoutCache, LayoutInterner, LayoutProblem, LayoutRepr,
-    TagIdIntType, UnionLayout, WrappedVariant,
+    NicheUnion, TagIdIntType, UnionLayout, WrappedVariant,
 };
 use bumpalo::collections::Vec;
 use roc_builtins::bitcode::{FloatWidth, IntWidth};
@@ -52,6 +52,14 @@ pub enum Pattern<'a> {
         layout: UnionLayout<'a>,
         union: roc_exhaustive::Union,
     },
+    NicheTag {
+        tag_name: TagName,
+        tag_id: TagIdIntType,
+        arguments: Vec<'a, (Pattern<'a>, InLayout<'a>)>,
+        layout: InLayout<'a>,
+        niche: NicheUnion<'a>,
+        union: roc_exhaustive::Union,
+    },
     Voided {
         tag_name: TagName,
     },
@@ -104,7 +112,7 @@ impl<'a> Pattern<'a> {
                     stack.extend(arguments.iter().map(|(t, _)| t))
                 }
                 Pattern::Voided { .. } => return true,
-                Pattern::AppliedTag { arguments, .. } => {
+                Pattern::AppliedTag { arguments, .. } | Pattern::NicheTag { arguments, .. } => {
                     stack.extend(arguments.iter().map(|(t, _)| t))
                 }
                 Pattern::OpaqueUnwrap { argument, .. } => stack.push(&argument.0),
@@ -175,7 +183,9 @@ impl<'r, 'a> Iterator for PatternBindingIter<'r, 'a> {
                         *self = Stack(stack);
                         self.next()
                     }
-                    NewtypeDestructure { arguments, .. } | AppliedTag { arguments, .. } => {
+                    NewtypeDestructure { arguments, .. }
+                    | AppliedTag { arguments, .. }
+                    | NicheTag { arguments, .. } => {
                         let stack = arguments.iter().map(|(p, l)| (Pat(p), *l)).rev().collect();
                         *self = Stack(stack);
                         self.next()
@@ -234,7 +244,9 @@ impl<'r, 'a> Iterator for PatternBindingIter<'r, 'a> {
                                     .map(|destruct| (Pat(&destruct.pat), destruct.layout))
                                     .rev(),
                             ),
-                            NewtypeDestructure { arguments, .. } | AppliedTag { arguments, .. } => {
+                            NewtypeDestructure { arguments, .. }
+                            | AppliedTag { arguments, .. }
+                            | NicheTag { arguments, .. } => {
                                 stack.extend(arguments.iter().map(|(p, l)| (Pat(p), *l)).rev())
                             }
                             OpaqueUnwrap { argument, .. } => {
@@ -475,6 +487,57 @@ fn from_can_pattern_help<'a>(
                         union,
                     }
                 }
+                NicheFilled { tag_names, niche } => {
+                    let tag_id = tag_names
+                        .iter()
+                        .position(|key| tag_name == key.expect_tag_ref())
+                        .expect("tag must be in its own type")
+                        as TagIdIntType;
+
+                    let mut ctors = std::vec::Vec::with_capacity(tag_names.len());
+                    for (i, tag_name) in tag_names.into_iter().enumerate() {
+                        ctors.push(Ctor {
+                            tag_id: TagId(i as _),
+                            name: CtorName::Tag(tag_name.expect_tag()),
+                            arity: niche.payload(i as _).is_some() as usize,
+                        })
+                    }
+
+                    let union = roc_exhaustive::Union {
+                        render_as: RenderAs::Tag,
+                        alternatives: ctors,
+                    };
+
+                    let layout = match layout_cache.from_var(env.arena, *whole_var, env.subs) {
+                        Ok(layout) => layout,
+                        Err(_) => internal_error!("niche-filled union without a layout"),
+                    };
+
+                    let mut mono_args = Vec::with_capacity_in(arguments.len(), env.arena);
+                    let it = arguments.iter().zip(niche.payload(tag_id));
+
+                    for ((_, loc_pat), payload_layout) in it {
+                        mono_args.push((
+                            from_can_pattern_help(
+                                env,
+                                procs,
+                                layout_cache,
+                                &loc_pat.value,
+                                assignments,
+                            )?,
+                            payload_layout,
+                        ));
+                    }
+
+                    Pattern::NicheTag {
+                        tag_name: tag_name.clone(),
+                        tag_id,
+                        arguments: mono_args,
+                        layout,
+                        niche,
+                        union,
+                    }
+                }
                 Newtype {
                     arguments: field_layouts,
                     ..
@@ -1251,6 +1314,25 @@ fn store_pattern_help<'a>(
                 stmt,
             );
         }
+        NicheTag {
+            arguments,
+            layout,
+            niche,
+            tag_id,
+            ..
+        } => {
+            return store_niche_pattern(
+                env,
+                procs,
+                layout_cache,
+                outer_symbol,
+                *layout,
+                *niche,
+                arguments,
+                *tag_id,
+                stmt,
+            );
+        }
 
         List {
             arity,
@@ -1699,6 +1781,104 @@ fn store_tag_pattern<'a>(
     }
 }
 
+/// Reads the payload of a niche-filled tag back out of the byte that stores it.
+///
+/// A bool payload is stored as the tag's first value plus the bool, so it is recovered with a
+/// comparison; byte payloads are recovered by subtracting the tag's first value.
+pub(crate) fn niche_payload_stores<'a, I>(
+    env: &mut Env<'a, '_>,
+    interner: &I,
+    structure: Symbol,
+    layout: InLayout<'a>,
+    start: u8,
+    payload: Symbol,
+    payload_layout: InLayout<'a>,
+) -> [(Symbol, InLayout<'a>, Expr<'a>); 2]
+where
+    I: LayoutInterner<'a>,
+{
+    let (op, operand) = match interner.get_repr(payload_layout) {
+        LayoutRepr::Builtin(Builtin::Bool) => (LowLevel::Eq, start + 1),
+        _ => (LowLevel::NumSubWrap, start),
+    };
+
+    let operand_symbol = env.unique_symbol();
+    let load = Expr::Call(Call {
+        call_type: CallType::LowLevel {
+            op,
+            update_mode: env.next_update_mode_id(),
+        },
+        arguments: env.arena.alloc([structure, operand_symbol]),
+    });
+
+    [
+        (
+            operand_symbol,
+            layout,
+            Expr::Literal(Literal::Byte(operand)),
+        ),
+        (payload, payload_layout, load),
+    ]
+}
+
+#[allow(clippy::too_many_arguments)]
+fn store_niche_pattern<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    structure: Symbol,
+    layout: InLayout<'a>,
+    niche: NicheUnion<'a>,
+    arguments: &[(Pattern<'a>, InLayout<'a>)],
+    tag_id: TagIdIntType,
+    stmt: Stmt<'a>,
+) -> StorePattern<'a> {
+    use Pattern::*;
+
+    let (argument, arg_layout) = match arguments {
+        [] => return StorePattern::NotProductive(stmt),
+        [argument] => argument,
+        _ => internal_error!("niche-filled tags have at most one payload"),
+    };
+
+    let (payload, mut stmt) = match argument {
+        // store immediately in the given symbol
+        Identifier(symbol) => (*symbol, stmt),
+        Underscore
+        | IntLiteral(_, _)
+        | FloatLiteral(_, _)
+        | DecimalLiteral(_)
+        | EnumLiteral { .. }
+        | BitLiteral { .. }
+        | StrLiteral(_) => return StorePattern::NotProductive(stmt),
+        _ => {
+            // store the payload in a symbol, and continue matching on it
+            let symbol = env.unique_symbol();
+
+            match store_pattern_help(env, procs, layout_cache, argument, symbol, stmt) {
+                StorePattern::Productive(new) => (symbol, new),
+                StorePattern::NotProductive(new) => return StorePattern::NotProductive(new),
+            }
+        }
+    };
+
+    let stores = niche_payload_stores(
+        env,
+        &layout_cache.interner,
+        structure,
+        layout,
+        niche.start(tag_id),
+        payload,
+        *arg_layout,
+    );
+
+    for (symbol, layout, expr) in stores.into_iter().rev() {
+        stmt = Stmt::Let(symbol, expr, layout, env.arena.alloc(stmt));
+    }
+
+    StorePattern::Productive(stmt)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn store_newtype_pattern<'a>(
     env: &mut Env<'a, '_>,
@@ -3605,6 +3605,10 @@ pub enum UnionVariant<'a> {
         ffalse: TagOrClosure,
     },
     ByteUnion(Vec<'a, TagOrClosure>),
+    NicheFilled {
+        tag_names: Vec<'a, TagOrClosure>,
+        niche: NicheUnion<'a>,
+    },
     Newtype {
         tag_name: TagOrClosure,
         arguments: Vec<'a, InLayout<'a>>,
@@ -3719,6 +3723,126 @@ impl<'a> WrappedVariant<'a> {
     }
 }
 
+/// A non-recursive tag union that stores its tag in the values its payloads leave unused.
+///
+/// In sorted tag order, every tag owns a consecutive range of byte values: a tag without a payload
+/// owns a single value, a tag with a payload owns one value per value of that payload. So
+/// `[None, Some Bool]` is a byte where `None` is 0, `Some Bool.false` is 1 and `Some Bool.true` is 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NicheUnion<'a> {
+    /// The first value of every tag, followed by the total number of values.
+    starts: &'a [u16],
+    /// The payload of every tag, if it has one.
+    payloads: &'a [Option<InLayout<'a>>],
+}
+
+impl<'a> NicheUnion<'a> {
+    pub fn number_of_tags(&self) -> usize {
+        self.payloads.len()
+    }
+
+    /// The first value owned by this tag.
+    pub fn start(&self, tag_id: TagIdIntType) -> u8 {
+        self.starts[tag_id as usize] as u8
+    }
+
+    /// The number of values owned by this tag.
+    pub fn number_of_values(&self, tag_id: TagIdIntType) -> u16 {
+        self.starts[tag_id as usize + 1] - self.starts[tag_id as usize]
+    }
+
+    pub fn total_values(&self) -> u16 {
+        self.starts[self.payloads.len()]
+    }
+
+    pub fn payload(&self, tag_id: TagIdIntType) -> Option<InLayout<'a>> {
+        self.payloads[tag_id as usize]
+    }
+
+    /// The tag that owns this value.
+    pub fn tag_id_of(&self, value: u8) -> TagIdIntType {
+        let ends = &self.starts[1..];
+
+        ends.iter()
+            .position(|end| (value as u16) < *end)
+            .unwrap_or_else(|| internal_error!("value {value} is not part of niche union {self:?}"))
+            as TagIdIntType
+    }
+
+    /// The number of distinct values of a layout that can be a niche-filled payload.
+    pub fn byte_values<I>(interner: &I, layout: InLayout<'a>) -> Option<u16>
+    where
+        I: LayoutInterner<'a>,
+    {
+        match interner.get_repr(layout) {
+            LayoutRepr::Builtin(Builtin::Bool) => Some(2),
+            LayoutRepr::Builtin(Builtin::Int(IntWidth::U8)) => {
+                let semantic = interner.get_semantic(layout);
+
+                match semantic.niche_union() {
+                    Some(niche) => Some(niche.total_values()),
+                    None => semantic.tag_names().map(|tags| tags.len() as u16),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the niche-filled representation of a non-recursive union, if it has one.
+    ///
+    /// Only unions where every tag has at most one payload, and every payload is itself stored in
+    /// a byte with values to spare, qualify.
+    ///
+    /// Payloads with other niches aren't used. For example, `[None, Some (Box U64)]` could store
+    /// `None` as a null pointer, like recursive unions already do with `NullableUnwrapped`. That
+    /// needs every backend to read and write the tag through the payload's own layout rather than
+    /// a byte, so such unions keep their discriminant for now.
+    fn from_sorted_tags<I>(
+        arena: &'a Bump,
+        interner: &I,
+        sorted_tags: &[(TagOrClosure, &'a [InLayout<'a>])],
+    ) -> Option<Self>
+    where
+        I: LayoutInterner<'a>,
+    {
+        let mut starts = Vec::with_capacity_in(sorted_tags.len() + 1, arena);
+        let mut payloads = Vec::with_capacity_in(sorted_tags.len(), arena);
+        let mut next_start: u16 = 0;
+
+        for (label, arguments) in sorted_tags {
+            if let TagOrClosure::Closure(_) = label {
+                return None;
+            }
+
+            let (payload, values) = match arguments {
+                [] => (None, 1),
+                [payload] => (Some(*payload), Self::byte_values(interner, *payload)?),
+                _ => return None,
+            };
+
+            starts.push(next_start);
+            payloads.push(payload);
+            next_start += values;
+
+            if next_start > 256 {
+                return None;
+            }
+        }
+
+        starts.push(next_start);
+
+        if payloads.iter().all(Option::is_none) {
+            // plain enumerations are stored as a bool or byte already
+            return None;
+        }
+
+        Some(NicheUnion {
+            starts: starts.into_bump_slice(),
+            payloads: payloads.into_bump_slice(),
+        })
+    }
+}
+
 pub fn union_sorted_tags<'a>(
     env: &mut Env<'a, '_>,
     var: Variable,
@@ -3979,6 +4103,10 @@ where
                     Cacheable(UnionVariant::ByteUnion(tag_names), cache_criteria)
                 }
                 _ => {
+                    if let Some(variant) = niche_filled_variant(env, &answer) {
+                        return Cacheable(variant, cache_criteria);
+                    }
+
                     let variant = WrappedVariant::NonRecursive {
                         sorted_tag_layouts: answer,
                     };
@@ -3990,6 +4118,19 @@ where
     }
 }
 
+/// Stores a non-recursive union in a single byte, if its payloads leave enough values unused.
+///
+/// Layouts and the IR classify unions separately, so both must go through here to agree.
+fn niche_filled_variant<'a>(
+    env: &Env<'a, '_>,
+    sorted_tags: &[(TagOrClosure, &'a [InLayout<'a>])],
+) -> Option<UnionVariant<'a>> {
+    let niche = NicheUnion::from_sorted_tags(env.arena, &env.cache.interner, sorted_tags)?;
+    let tag_names = Vec::from_iter_in(sorted_tags.iter().map(|(tag, _)| tag.clone()), env.arena);
+
+    Some(UnionVariant::NicheFilled { tag_names, niche })
+}
+
 pub fn union_sorted_tags_pub<'a, L>(
     env: &mut Env<'a, '_>,
     tags_vec: std::vec::Vec<(L, std::vec::Vec<Variable>)>,
@@ -4217,6 +4358,12 @@ where
                     Cacheable(UnionVariant::ByteUnion(tag_names), cache_criteria)
                 }
                 _ => {
+                    if !is_recursive {
+                        if let Some(variant) = niche_filled_variant(env, &answer) {
+                            return Cacheable(variant, cache_criteria);
+                        }
+                    }
+
                     let variant = if let Some((nullable_id, nullable_name)) = nullable {
                         if answer.len() == 1 {
                             let (other_name, other_arguments) = answer.drain(..).next().unwrap();
@@ -4318,6 +4465,18 @@ where
         ByteUnion(_) => env
             .cache
             .put_in(Layout::new(LayoutRepr::U8.direct(), compute_semantic())),
+        NicheFilled { tag_names, niche } => {
+            let arena = env.arena;
+            let tags = arena.alloc_slice_fill_iter(
+                tag_names
+                    .iter()
+                    .map(|tag| &*arena.alloc_str(tag.expect_tag_ref().0.as_str())),
+            );
+            let semantic = SemanticRepr::niche_filled(arena, tags, niche);
+
+            env.cache
+                .put_in(Layout::new(LayoutRepr::U8.direct(), semantic))
+        }
         Newtype {
             arguments: field_layouts,
             ..
@@ -1,7 +1,10 @@
 //! Semantic representations of memory layouts for the purposes of specialization.
 
+use bumpalo::Bump;
 use roc_module::symbol::Symbol;
 
+use super::NicheUnion;
+
 /// A semantic representation of a memory layout.
 /// Semantic representations describe the shape of a type a [Layout][super::Layout] is generated
 /// for. Semantic representations disambiguate types that have the same runtime memory layout, but
@@ -27,6 +30,8 @@ enum Inner<'a> {
     Record(SemaRecord<'a>),
     Tuple(SemaTuple),
     TagUnion(SemaTagUnion<'a>),
+    // boxed to keep layouts small
+    NicheUnion(&'a SemaNicheUnion<'a>),
     Lambdas(SemaLambdas<'a>),
 }
 
@@ -59,9 +64,36 @@ impl<'a> SemanticRepr<'a> {
         Self(Inner::TagUnion(SemaTagUnion { tags }))
     }
 
+    pub(super) fn niche_filled(
+        arena: &'a Bump,
+        tags: &'a [&'a str],
+        niche: NicheUnion<'a>,
+    ) -> Self {
+        Self(Inner::NicheUnion(
+            arena.alloc(SemaNicheUnion { tags, niche }),
+        ))
+    }
+
     pub(super) fn lambdas(lambdas: &'a [Symbol]) -> Self {
         Self(Inner::Lambdas(SemaLambdas { lambdas }))
     }
+
+    /// The sorted tag names, if this is a tag union.
+    pub fn tag_names(&self) -> Option<&'a [&'a str]> {
+        match self.0 {
+            Inner::TagUnion(SemaTagUnion { tags }) => Some(tags),
+            Inner::NicheUnion(SemaNicheUnion { tags, .. }) => Some(tags),
+            _ => None,
+        }
+    }
+
+    /// How the tags are packed, if this is a niche-filled tag union.
+    pub fn niche_union(&self) -> Option<NicheUnion<'a>> {
+        match self.0 {
+            Inner::NicheUnion(SemaNicheUnion { niche, .. }) => Some(*niche),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -79,6 +111,12 @@ struct SemaTagUnion<'a> {
     tags: &'a [&'a str],
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct SemaNicheUnion<'a> {
+    tags: &'a [&'a str],
+    niche: NicheUnion<'a>,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 struct SemaLambdas<'a> {
     lambdas: &'a [Symbol],
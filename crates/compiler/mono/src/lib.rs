@@ -9,13 +9,18 @@
 // Not a useful lint for us
 #![allow(clippy::too_many_arguments)]
 
+pub mod alloc_profile;
 pub mod borrow;
 pub mod code_gen_help;
+pub mod const_eval;
 pub mod drop_specialization;
+pub mod in_place;
 pub mod inc_dec;
 pub mod ir;
 pub mod layout;
 pub mod low_level;
+pub mod optimize;
+pub mod refcount_check;
 pub mod reset_reuse;
 pub mod tail_recursion;
 
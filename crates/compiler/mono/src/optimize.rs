@@ -0,0 +1,1274 @@
+//! Backend-independent optimizations of the mono IR.
+//!
+//! LLVM does plenty of optimizing on its own, but the dev and wasm backends generate code for the
+//! IR pretty much as it is. So before reference counting is inserted, we
+//!
+//! - inline calls to small procs,
+//! - fold lowlevel operations whose arguments are all literals,
+//! - resolve a `when` on a value whose constructor is known, e.g. a tag we just built,
+//! - and remove join points that nothing jumps to and values that nothing uses.
+//!
+//! How much gets inlined depends on the [OptLevel].
+
+use bumpalo::collections::Vec;
+use bumpalo::Bump;
+use roc_collections::MutMap;
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+
+use crate::const_eval::fold_lowlevel;
+use crate::in_place::{CallSite, CallSites};
+use crate::ir::{
+    BranchInfo, Call, CallSpecId, CallType, Expr, JoinPointId, ListLiteralElement, Literal,
+    ModifyRc, OptLevel, Param, Proc, ProcLayout, ReuseToken, SelfRecursive, Stmt, UpdateModeId,
+    UpdateModeIds,
+};
+use crate::layout::{
+    Builtin, InLayout, LayoutInterner, LayoutRepr, STLayoutInterner, TagIdIntType,
+};
+
+/// How many rounds of inlining we do. A round inlines callees as they were before the round, so
+/// a wrapper around a wrapper needs two.
+const INLINE_ROUNDS: usize = 2;
+
+/// The biggest proc body, counted in statements, that gets inlined.
+fn inline_threshold(opt_level: OptLevel) -> usize {
+    match opt_level {
+        OptLevel::Size => 4,
+        OptLevel::Development => 8,
+        OptLevel::Normal => 16,
+        OptLevel::Optimize => 32,
+    }
+}
+
+pub fn optimize_procs<'a>(
+    arena: &'a Bump,
+    interner: &STLayoutInterner<'a>,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    update_mode_ids: &mut UpdateModeIds,
+    call_sites: &mut CallSites,
+    opt_level: OptLevel,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) {
+    let mut keys: std::vec::Vec<_> = procs.keys().copied().collect();
+
+    // sort for deterministic symbol numbering in the inlined bodies
+    keys.sort_by_key(|(symbol, layout)| (*symbol, layout.arguments, layout.result));
+
+    for proc in procs.values_mut() {
+        proc.body = simplify(arena, interner, &proc.body);
+    }
+
+    let mut next_call_spec_id = procs
+        .values()
+        .flat_map(|proc| call_spec_ids(&proc.body))
+        .max()
+        .unwrap_or(CallSpecId::BACKEND_DUMMY)
+        .next();
+
+    let threshold = inline_threshold(opt_level);
+
+    for _ in 0..INLINE_ROUNDS {
+        let candidates: MutMap<_, _> = procs
+            .iter()
+            .filter(|(_, proc)| is_inline_candidate(proc, threshold))
+            .map(|(key, proc)| (*key, proc.clone()))
+            .collect();
+
+        if candidates.is_empty() {
+            break;
+        }
+
+        for key in keys.iter() {
+            let proc = procs.get_mut(key).unwrap();
+
+            let mut inliner = Inliner {
+                arena,
+                home,
+                ident_ids,
+                update_mode_ids,
+                call_sites,
+                next_call_spec_id: &mut next_call_spec_id,
+                candidates: &candidates,
+                caller: *key,
+                inlined_any: false,
+            };
+
+            let body = inliner.inline_stmt(&proc.body);
+
+            if inliner.inlined_any {
+                proc.body = simplify(arena, interner, body);
+            }
+        }
+    }
+}
+
+fn is_inline_candidate(proc: &Proc, threshold: usize) -> bool {
+    if proc.is_erased || !matches!(proc.is_self_recursive, SelfRecursive::NotSelfRecursive) {
+        return false;
+    }
+
+    let mut size = 0;
+    let mut has_debug_statements = false;
+
+    for_each_stmt(&proc.body, |stmt| {
+        size += 1;
+        has_debug_statements |= matches!(stmt, Stmt::Expect { .. } | Stmt::Dbg { .. });
+    });
+
+    // `expect` and `dbg` refer to the type variables of their module, so they stay where they are
+    size <= threshold && !has_debug_statements
+}
+
+fn simplify<'a>(arena: &'a Bump, interner: &STLayoutInterner<'a>, body: &Stmt<'a>) -> Stmt<'a> {
+    let mut jump_counts = MutMap::default();
+    for_each_stmt(body, |stmt| {
+        if let Stmt::Jump(id, _) = stmt {
+            *jump_counts.entry(*id).or_insert(0) += 1;
+        }
+    });
+
+    let mut simplifier = Simplifier {
+        arena,
+        interner,
+        renames: MutMap::default(),
+        known: MutMap::default(),
+        jump_counts,
+        single_jump_joins: MutMap::default(),
+    };
+
+    let body = simplifier.simplify_stmt(body);
+
+    let mut uses = MutMap::default();
+    for_each_stmt(&body, |stmt| {
+        stmt_uses(stmt, |symbol| *uses.entry(symbol).or_insert(0) += 1)
+    });
+
+    let mut jump_counts = MutMap::default();
+    for_each_stmt(&body, |stmt| {
+        if let Stmt::Jump(id, _) = stmt {
+            *jump_counts.entry(*id).or_insert(0) += 1;
+        }
+    });
+
+    let mut eliminator = DeadCodeEliminator {
+        arena,
+        uses,
+        jump_counts,
+    };
+
+    eliminator.eliminate_stmt(&body)
+}
+
+/// Calls `f` on the statement and all statements nested in it.
+pub(crate) fn for_each_stmt<'s, 'a>(stmt: &'s Stmt<'a>, mut f: impl FnMut(&'s Stmt<'a>)) {
+    let mut stack = vec![stmt];
+
+    while let Some(stmt) = stack.pop() {
+        f(stmt);
+
+        match stmt {
+            Stmt::Let(_, _, _, continuation) => stack.push(continuation),
+            Stmt::Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                stack.push(default_branch.1);
+            }
+            Stmt::Refcounting(_, continuation) => stack.push(continuation),
+            Stmt::Expect { remainder, .. } | Stmt::Dbg { remainder, .. } => stack.push(remainder),
+            Stmt::Join {
+                body, remainder, ..
+            } => {
+                stack.push(body);
+                stack.push(remainder);
+            }
+            Stmt::Ret(_) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => {}
+        }
+    }
+}
+
+fn call_spec_ids(stmt: &Stmt) -> std::vec::Vec<CallSpecId> {
+    let mut ids = std::vec::Vec::new();
+
+    for_each_stmt(stmt, |stmt| {
+        if let Stmt::Let(_, Expr::Call(call), _, _) = stmt {
+            match &call.call_type {
+                CallType::ByName {
+                    specialization_id, ..
+                } => ids.push(*specialization_id),
+                CallType::HigherOrder(higher_order) => {
+                    ids.push(higher_order.passed_function.specialization_id)
+                }
+                _ => {}
+            }
+        }
+    });
+
+    ids
+}
+
+/// Calls `f` on the symbols a statement itself uses, not counting nested statements.
+pub(crate) fn stmt_uses(stmt: &Stmt, mut f: impl FnMut(Symbol)) {
+    match stmt {
+        Stmt::Let(_, expr, _, _) => expr_uses(expr, f),
+        Stmt::Switch {
+            cond_symbol,
+            branches,
+            default_branch,
+            ..
+        } => {
+            f(*cond_symbol);
+
+            let infos = branches.iter().map(|(_, info, _)| info);
+            for info in infos.chain(std::iter::once(&default_branch.0)) {
+                match info {
+                    BranchInfo::None => {}
+                    BranchInfo::Constructor { scrutinee, .. }
+                    | BranchInfo::List { scrutinee, .. }
+                    | BranchInfo::Unique { scrutinee, .. } => f(*scrutinee),
+                }
+            }
+        }
+        Stmt::Ret(symbol) | Stmt::Crash(symbol, _) => f(*symbol),
+        Stmt::Refcounting(modify_rc, _) => f(modify_rc.get_symbol()),
+        Stmt::Expect {
+            condition, lookups, ..
+        } => {
+            f(*condition);
+            lookups.iter().copied().for_each(f);
+        }
+        Stmt::Dbg { symbol, .. } => f(*symbol),
+        Stmt::Join { .. } => {}
+        Stmt::Jump(_, arguments) => arguments.iter().copied().for_each(f),
+    }
+}
+
+fn expr_uses(expr: &Expr, mut f: impl FnMut(Symbol)) {
+    match expr {
+        Expr::Call(call) => {
+            match &call.call_type {
+                CallType::ByPointer { pointer, .. } => f(*pointer),
+                CallType::HigherOrder(higher_order) => {
+                    f(higher_order.passed_function.captured_environment)
+                }
+                CallType::ByName { .. } | CallType::Foreign { .. } | CallType::LowLevel { .. } => {}
+            }
+
+            call.arguments.iter().copied().for_each(f);
+        }
+        Expr::Tag {
+            arguments, reuse, ..
+        } => {
+            arguments.iter().copied().for_each(&mut f);
+
+            if let Some(token) = reuse {
+                f(token.symbol);
+            }
+        }
+        Expr::Struct(fields) => fields.iter().copied().for_each(f),
+        Expr::StructAtIndex { structure, .. }
+        | Expr::GetTagId { structure, .. }
+        | Expr::UnionAtIndex { structure, .. }
+        | Expr::GetElementPointer { structure, .. } => f(*structure),
+        Expr::Array { elems, .. } => elems
+            .iter()
+            .filter_map(ListLiteralElement::to_symbol)
+            .for_each(f),
+        Expr::ErasedMake { value, callee } => {
+            if let Some(value) = value {
+                f(*value);
+            }
+
+            f(*callee);
+        }
+        Expr::ErasedLoad { symbol, .. }
+        | Expr::Reset { symbol, .. }
+        | Expr::ResetRef { symbol, .. } => f(*symbol),
+        Expr::Alloca { initializer, .. } => {
+            if let Some(initializer) = initializer {
+                f(*initializer);
+            }
+        }
+        Expr::Literal(_) | Expr::NullPointer | Expr::EmptyArray | Expr::FunctionPointer { .. } => {}
+    }
+}
+
+fn rename(renames: &MutMap<Symbol, Symbol>, symbol: Symbol) -> Symbol {
+    *renames.get(&symbol).unwrap_or(&symbol)
+}
+
+fn rename_all<'a>(
+    arena: &'a Bump,
+    renames: &MutMap<Symbol, Symbol>,
+    symbols: &[Symbol],
+) -> &'a [Symbol] {
+    arena.alloc_slice_fill_iter(symbols.iter().map(|symbol| rename(renames, *symbol)))
+}
+
+/// Rebuilds `expr` with every symbol it uses replaced according to `renames`.
+fn rename_expr<'a>(arena: &'a Bump, renames: &MutMap<Symbol, Symbol>, expr: &Expr<'a>) -> Expr<'a> {
+    let rename = |symbol: Symbol| rename(renames, symbol);
+
+    match expr {
+        Expr::Call(call) => {
+            let call_type = match &call.call_type {
+                CallType::ByPointer {
+                    pointer,
+                    ret_layout,
+                    arg_layouts,
+                } => CallType::ByPointer {
+                    pointer: rename(*pointer),
+                    ret_layout: *ret_layout,
+                    arg_layouts,
+                },
+                CallType::HigherOrder(higher_order) => {
+                    let mut higher_order = (*higher_order).clone();
+
+                    let crate::low_level::HigherOrder::ListSortWith { xs } = &mut higher_order.op;
+                    *xs = rename(*xs);
+
+                    let passed_function = &mut higher_order.passed_function;
+                    passed_function.captured_environment =
+                        rename(passed_function.captured_environment);
+
+                    CallType::HigherOrder(arena.alloc(higher_order))
+                }
+                call_type => call_type.clone(),
+            };
+
+            Expr::Call(Call {
+                call_type,
+                arguments: rename_all(arena, renames, call.arguments),
+            })
+        }
+        Expr::Tag {
+            tag_layout,
+            tag_id,
+            arguments,
+            reuse,
+        } => Expr::Tag {
+            tag_layout: *tag_layout,
+            tag_id: *tag_id,
+            arguments: rename_all(arena, renames, arguments),
+            reuse: reuse.map(|token| ReuseToken {
+                symbol: rename(token.symbol),
+                ..token
+            }),
+        },
+        Expr::Struct(fields) => Expr::Struct(rename_all(arena, renames, fields)),
+        Expr::StructAtIndex {
+            index,
+            field_layouts,
+            structure,
+        } => Expr::StructAtIndex {
+            index: *index,
+            field_layouts,
+            structure: rename(*structure),
+        },
+        Expr::GetTagId {
+            structure,
+            union_layout,
+        } => Expr::GetTagId {
+            structure: rename(*structure),
+            union_layout: *union_layout,
+        },
+        Expr::UnionAtIndex {
+            structure,
+            tag_id,
+            union_layout,
+            index,
+        } => Expr::UnionAtIndex {
+            structure: rename(*structure),
+            tag_id: *tag_id,
+            union_layout: *union_layout,
+            index: *index,
+        },
+        Expr::GetElementPointer {
+            structure,
+            union_layout,
+            indices,
+        } => Expr::GetElementPointer {
+            structure: rename(*structure),
+            union_layout: *union_layout,
+            indices,
+        },
+        Expr::Array { elem_layout, elems } => Expr::Array {
+            elem_layout: *elem_layout,
+            elems: arena.alloc_slice_fill_iter(elems.iter().map(|elem| match elem {
+                ListLiteralElement::Symbol(symbol) => ListLiteralElement::Symbol(rename(*symbol)),
+                ListLiteralElement::Literal(literal) => ListLiteralElement::Literal(*literal),
+            })),
+        },
+        Expr::ErasedMake { value, callee } => Expr::ErasedMake {
+            value: value.map(rename),
+            callee: rename(*callee),
+        },
+        Expr::ErasedLoad { symbol, field } => Expr::ErasedLoad {
+            symbol: rename(*symbol),
+            field: *field,
+        },
+        Expr::Alloca {
+            element_layout,
+            initializer,
+        } => Expr::Alloca {
+            element_layout: *element_layout,
+            initializer: initializer.map(rename),
+        },
+        Expr::Reset {
+            symbol,
+            update_mode,
+        } => Expr::Reset {
+            symbol: rename(*symbol),
+            update_mode: *update_mode,
+        },
+        Expr::ResetRef {
+            symbol,
+            update_mode,
+        } => Expr::ResetRef {
+            symbol: rename(*symbol),
+            update_mode: *update_mode,
+        },
+        Expr::Literal(_) | Expr::NullPointer | Expr::EmptyArray | Expr::FunctionPointer { .. } => {
+            expr.clone()
+        }
+    }
+}
+
+fn rename_branch_info<'a>(
+    renames: &MutMap<Symbol, Symbol>,
+    info: &BranchInfo<'a>,
+) -> BranchInfo<'a> {
+    match info {
+        BranchInfo::None => BranchInfo::None,
+        BranchInfo::Constructor {
+            scrutinee,
+            layout,
+            tag_id,
+        } => BranchInfo::Constructor {
+            scrutinee: rename(renames, *scrutinee),
+            layout: *layout,
+            tag_id: *tag_id,
+        },
+        BranchInfo::List { scrutinee, len } => BranchInfo::List {
+            scrutinee: rename(renames, *scrutinee),
+            len: *len,
+        },
+        BranchInfo::Unique { scrutinee, unique } => BranchInfo::Unique {
+            scrutinee: rename(renames, *scrutinee),
+            unique: *unique,
+        },
+    }
+}
+
+fn rename_modify_rc(renames: &MutMap<Symbol, Symbol>, modify_rc: ModifyRc) -> ModifyRc {
+    match modify_rc {
+        ModifyRc::Inc(symbol, amount) => ModifyRc::Inc(rename(renames, symbol), amount),
+        ModifyRc::Dec(symbol) => ModifyRc::Dec(rename(renames, symbol)),
+        ModifyRc::DecRef(symbol) => ModifyRc::DecRef(rename(renames, symbol)),
+        ModifyRc::Free(symbol) => ModifyRc::Free(rename(renames, symbol)),
+    }
+}
+
+struct Inliner<'a, 'r> {
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &'r mut IdentIds,
+    update_mode_ids: &'r mut UpdateModeIds,
+    call_sites: &'r mut CallSites,
+    next_call_spec_id: &'r mut CallSpecId,
+    candidates: &'r MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    caller: (Symbol, ProcLayout<'a>),
+    inlined_any: bool,
+}
+
+impl<'a, 'r> Inliner<'a, 'r> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn callee(&self, expr: &Expr<'a>) -> Option<&'r Proc<'a>> {
+        let Expr::Call(Call {
+            call_type:
+                CallType::ByName {
+                    name,
+                    ret_layout,
+                    arg_layouts,
+                    ..
+                },
+            ..
+        }) = expr
+        else {
+            return None;
+        };
+
+        let key = (
+            name.name(),
+            ProcLayout {
+                arguments: arg_layouts,
+                result: *ret_layout,
+                niche: name.niche(),
+            },
+        );
+
+        if key == self.caller {
+            return None;
+        }
+
+        self.candidates.get(&key)
+    }
+
+    fn inline_stmt(&mut self, stmt: &Stmt<'a>) -> &'a Stmt<'a> {
+        let arena = self.arena;
+
+        // chains of lets can get very long, so we walk those in a loop rather than recursively
+        let mut lets = std::vec::Vec::new();
+        let mut stmt = stmt;
+        while let Stmt::Let(symbol, expr, layout, continuation) = stmt {
+            lets.push((*symbol, expr, *layout));
+            stmt = continuation;
+        }
+
+        let tail = match stmt {
+            Stmt::Let(..) => unreachable!("lets are handled above"),
+            Stmt::Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let branches = Vec::from_iter_in(
+                    branches.iter().map(|(label, info, branch)| {
+                        (*label, info.clone(), self.inline_stmt(branch).clone())
+                    }),
+                    arena,
+                );
+
+                Stmt::Switch {
+                    cond_symbol: *cond_symbol,
+                    cond_layout: *cond_layout,
+                    branches: branches.into_bump_slice(),
+                    default_branch: (default_branch.0.clone(), self.inline_stmt(default_branch.1)),
+                    ret_layout: *ret_layout,
+                }
+            }
+            Stmt::Refcounting(modify_rc, continuation) => {
+                Stmt::Refcounting(*modify_rc, self.inline_stmt(continuation))
+            }
+            Stmt::Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => Stmt::Expect {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder: self.inline_stmt(remainder),
+            },
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                variable,
+                remainder,
+            } => Stmt::Dbg {
+                source_location,
+                source,
+                symbol: *symbol,
+                variable: *variable,
+                remainder: self.inline_stmt(remainder),
+            },
+            Stmt::Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => Stmt::Join {
+                id: *id,
+                parameters,
+                body: self.inline_stmt(body),
+                remainder: self.inline_stmt(remainder),
+            },
+            Stmt::Ret(_) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => stmt.clone(),
+        };
+
+        let mut result: &'a Stmt<'a> = arena.alloc(tail);
+
+        for (symbol, expr, layout) in lets.into_iter().rev() {
+            let stmt = match (self.callee(expr), expr) {
+                (Some(callee), Expr::Call(call)) => {
+                    self.inlined_any = true;
+
+                    // let x = f args; continuation
+                    //
+                    // becomes
+                    //
+                    // join j x = continuation in <body of f>
+                    //
+                    // where every `ret v` in the body of f is now a `jump j v`
+                    let id = JoinPointId(self.unique_symbol());
+
+                    let call_site = self.call_sites.get(&symbol).copied();
+
+                    let mut copier = Copier {
+                        inliner: self,
+                        renames: MutMap::default(),
+                        join_renames: MutMap::default(),
+                        return_to: id,
+                        call_site,
+                    };
+
+                    for ((_, parameter), argument) in callee.args.iter().zip(call.arguments) {
+                        copier.renames.insert(*parameter, *argument);
+                    }
+
+                    let remainder = copier.copy_stmt(&callee.body);
+
+                    Stmt::Join {
+                        id,
+                        parameters: arena.alloc([Param { symbol, layout }]),
+                        body: result,
+                        remainder,
+                    }
+                }
+                _ => Stmt::Let(symbol, expr.clone(), layout, result),
+            };
+
+            result = arena.alloc(stmt);
+        }
+
+        result
+    }
+}
+
+/// Copies the body of an inlined proc, giving everything it binds a fresh name. Symbols and ids
+/// must be unique within a proc, and the same callee may be inlined many times.
+struct Copier<'i, 'a, 'r> {
+    inliner: &'i mut Inliner<'a, 'r>,
+    renames: MutMap<Symbol, Symbol>,
+    join_renames: MutMap<JoinPointId, JoinPointId>,
+    return_to: JoinPointId,
+    /// Where the inlined call was made, if it's an in-place update we keep track of.
+    call_site: Option<CallSite>,
+}
+
+impl<'i, 'a, 'r> Copier<'i, 'a, 'r> {
+    fn fresh(&mut self, symbol: Symbol) -> Symbol {
+        let fresh = self.inliner.unique_symbol();
+        self.renames.insert(symbol, fresh);
+
+        fresh
+    }
+
+    fn fresh_call_spec_id(&mut self) -> CallSpecId {
+        let id = *self.inliner.next_call_spec_id;
+        *self.inliner.next_call_spec_id = id.next();
+
+        id
+    }
+
+    fn fresh_update_mode(&mut self, update_mode: UpdateModeId) -> UpdateModeId {
+        if update_mode == UpdateModeId::BACKEND_DUMMY {
+            update_mode
+        } else {
+            self.inliner.update_mode_ids.next_id()
+        }
+    }
+
+    fn copy_expr(&mut self, expr: &Expr<'a>) -> Expr<'a> {
+        let mut expr = rename_expr(self.inliner.arena, &self.renames, expr);
+
+        match &mut expr {
+            Expr::Call(call) => match &mut call.call_type {
+                CallType::ByName {
+                    specialization_id, ..
+                } => *specialization_id = self.fresh_call_spec_id(),
+                CallType::LowLevel { update_mode, .. } => {
+                    *update_mode = self.fresh_update_mode(*update_mode)
+                }
+                CallType::HigherOrder(higher_order) => {
+                    let mut fresh = (*higher_order).clone();
+                    fresh.update_mode = self.fresh_update_mode(fresh.update_mode);
+                    fresh.passed_function.specialization_id = self.fresh_call_spec_id();
+
+                    *higher_order = self.inliner.arena.alloc(fresh);
+                }
+                CallType::ByPointer { .. } | CallType::Foreign { .. } => {}
+            },
+            Expr::Tag {
+                reuse: Some(token), ..
+            } => token.update_mode = self.fresh_update_mode(token.update_mode),
+            Expr::Reset { update_mode, .. } | Expr::ResetRef { update_mode, .. } => {
+                *update_mode = self.fresh_update_mode(*update_mode)
+            }
+            _ => {}
+        }
+
+        expr
+    }
+
+    fn copy_stmt(&mut self, stmt: &Stmt<'a>) -> &'a Stmt<'a> {
+        let arena = self.inliner.arena;
+
+        let stmt = match stmt {
+            Stmt::Let(symbol, expr, layout, continuation) => {
+                // a call in the inlined body keeps its own call site, or else takes over the
+                // site of the inlined call
+                let call_site = match expr {
+                    Expr::Call(_) => self
+                        .inliner
+                        .call_sites
+                        .get(symbol)
+                        .or(self.call_site.as_ref()),
+                    _ => None,
+                }
+                .copied();
+
+                let expr = self.copy_expr(expr);
+                let symbol = self.fresh(*symbol);
+
+                if let Some(call_site) = call_site {
+                    self.inliner.call_sites.insert(symbol, call_site);
+                }
+
+                Stmt::Let(symbol, expr, *layout, self.copy_stmt(continuation))
+            }
+            Stmt::Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let branches = Vec::from_iter_in(
+                    branches.iter().map(|(label, info, branch)| {
+                        let info = rename_branch_info(&self.renames, info);
+
+                        (*label, info, self.copy_stmt(branch).clone())
+                    }),
+                    arena,
+                );
+
+                Stmt::Switch {
+                    cond_symbol: rename(&self.renames, *cond_symbol),
+                    cond_layout: *cond_layout,
+                    branches: branches.into_bump_slice(),
+                    default_branch: (
+                        rename_branch_info(&self.renames, &default_branch.0),
+                        self.copy_stmt(default_branch.1),
+                    ),
+                    ret_layout: *ret_layout,
+                }
+            }
+            Stmt::Ret(symbol) => Stmt::Jump(
+                self.return_to,
+                arena.alloc([rename(&self.renames, *symbol)]),
+            ),
+            Stmt::Refcounting(modify_rc, continuation) => {
+                let modify_rc = rename_modify_rc(&self.renames, *modify_rc);
+
+                Stmt::Refcounting(modify_rc, self.copy_stmt(continuation))
+            }
+            Stmt::Expect { .. } | Stmt::Dbg { .. } => {
+                unreachable!("procs with `expect` or `dbg` are not inlined")
+            }
+            Stmt::Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => {
+                let fresh_id = JoinPointId(self.inliner.unique_symbol());
+                self.join_renames.insert(*id, fresh_id);
+
+                let parameters =
+                    arena.alloc_slice_fill_iter(parameters.iter().map(|param| Param {
+                        symbol: self.fresh(param.symbol),
+                        layout: param.layout,
+                    }));
+
+                Stmt::Join {
+                    id: fresh_id,
+                    parameters,
+                    body: self.copy_stmt(body),
+                    remainder: self.copy_stmt(remainder),
+                }
+            }
+            Stmt::Jump(id, arguments) => Stmt::Jump(
+                self.join_renames[id],
+                rename_all(arena, &self.renames, arguments),
+            ),
+            Stmt::Crash(symbol, tag) => Stmt::Crash(rename(&self.renames, *symbol), *tag),
+        };
+
+        arena.alloc(stmt)
+    }
+}
+
+/// What we know about the value of a symbol
+#[derive(Clone, Copy)]
+enum Known<'a> {
+    Literal(Literal<'a>, InLayout<'a>),
+    Struct(&'a [Symbol]),
+    Tag(TagIdIntType, &'a [Symbol]),
+}
+
+struct Simplifier<'a, 'r> {
+    arena: &'a Bump,
+    interner: &'r STLayoutInterner<'a>,
+    /// Symbols that are replaced by another symbol holding the same value
+    renames: MutMap<Symbol, Symbol>,
+    known: MutMap<Symbol, Known<'a>>,
+    jump_counts: MutMap<JoinPointId, usize>,
+    /// Join points with a single jump to them. Their body is put where the jump is.
+    single_jump_joins: MutMap<JoinPointId, (&'a [Param<'a>], &'a Stmt<'a>)>,
+}
+
+impl<'a, 'r> Simplifier<'a, 'r> {
+    fn literal(&self, symbol: Symbol) -> Option<(Literal<'a>, InLayout<'a>)> {
+        match self.known.get(&symbol) {
+            Some(Known::Literal(literal, layout)) => Some((*literal, *layout)),
+            _ => None,
+        }
+    }
+
+    /// The literal for a tag id of the given layout
+    fn tag_id_literal(&self, tag_id: TagIdIntType, layout: InLayout<'a>) -> Option<Literal<'a>> {
+        match self.interner.get_repr(layout) {
+            LayoutRepr::Builtin(Builtin::Bool) => Some(Literal::Bool(tag_id != 0)),
+            LayoutRepr::Builtin(Builtin::Int(_)) => {
+                Some(Literal::Int((tag_id as i128).to_ne_bytes()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Evaluates `expr` as far as we can at compile time. Returns either the symbol that already
+    /// holds the value of the expression, or the simplified expression.
+    fn simplify_expr(&self, expr: Expr<'a>, layout: InLayout<'a>) -> Result<Symbol, Expr<'a>> {
+        match &expr {
+            Expr::StructAtIndex {
+                index, structure, ..
+            } => {
+                if let Some(Known::Struct(fields)) = self.known.get(structure) {
+                    return Ok(fields[*index as usize]);
+                }
+            }
+            Expr::UnionAtIndex {
+                structure,
+                tag_id,
+                index,
+                ..
+            } => {
+                if let Some(Known::Tag(known_tag_id, arguments)) = self.known.get(structure) {
+                    if known_tag_id == tag_id {
+                        return Ok(arguments[*index as usize]);
+                    }
+                }
+            }
+            Expr::GetTagId { structure, .. } => {
+                if let Some(Known::Tag(tag_id, _)) = self.known.get(structure) {
+                    if let Some(literal) = self.tag_id_literal(*tag_id, layout) {
+                        return Err(Expr::Literal(literal));
+                    }
+                }
+            }
+            Expr::Call(call) => {
+                if let CallType::LowLevel { op, .. } =
+                    call.call_type.clone().replace_lowlevel_wrapper()
+                {
+                    let arguments: Option<std::vec::Vec<_>> = call
+                        .arguments
+                        .iter()
+                        .map(|arg| self.literal(*arg))
+                        .collect();
+
+                    if let Some(arguments) = arguments {
+                        if let Some(literal) =
+                            fold_lowlevel(self.arena, self.interner, op, &arguments, layout)
+                        {
+                            return Err(Expr::Literal(literal));
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Err(expr)
+    }
+
+    /// The branch a switch on a known value takes
+    fn known_branch<'s>(
+        &self,
+        cond_symbol: Symbol,
+        branches: &'s [(u64, BranchInfo<'a>, Stmt<'a>)],
+        default_branch: &'s Stmt<'a>,
+    ) -> Option<&'s Stmt<'a>> {
+        let value = match self.literal(cond_symbol)?.0 {
+            Literal::Bool(b) => b as u64,
+            Literal::Byte(byte) => byte as u64,
+            Literal::Int(bytes) => u64::try_from(i128::from_ne_bytes(bytes)).ok()?,
+            Literal::U128(bytes) => u64::try_from(u128::from_ne_bytes(bytes)).ok()?,
+            Literal::Float(_) | Literal::Decimal(_) | Literal::Str(_) => return None,
+        };
+
+        let branch = branches
+            .iter()
+            .find(|(label, _, _)| *label == value)
+            .map(|(_, _, branch)| branch);
+
+        Some(branch.unwrap_or(default_branch))
+    }
+
+    /// Mono reuses symbol names in sibling branches, so what we learn in one branch must not leak
+    /// into the next.
+    fn simplify_scoped(&mut self, stmt: &Stmt<'a>) -> Stmt<'a> {
+        let renames = self.renames.clone();
+        let known = self.known.clone();
+
+        let stmt = self.simplify_stmt(stmt);
+
+        self.renames = renames;
+        self.known = known;
+
+        stmt
+    }
+
+    fn simplify_stmt(&mut self, stmt: &Stmt<'a>) -> Stmt<'a> {
+        let arena = self.arena;
+
+        // Chains of lets can get very long, so we walk those in a loop rather than recursively.
+        // Statements that are resolved at compile time just continue the loop.
+        let mut lets = std::vec::Vec::new();
+        let mut stmt = stmt;
+
+        let tail = loop {
+            match stmt {
+                Stmt::Let(symbol, expr, layout, continuation) => {
+                    let expr = rename_expr(arena, &self.renames, expr);
+
+                    match self.simplify_expr(expr, *layout) {
+                        Ok(existing) => {
+                            self.renames.insert(*symbol, existing);
+                        }
+                        Err(expr) => {
+                            let known = match &expr {
+                                Expr::Literal(literal) => Some(Known::Literal(*literal, *layout)),
+                                Expr::Struct(fields) => Some(Known::Struct(fields)),
+                                Expr::Tag {
+                                    tag_id,
+                                    arguments,
+                                    reuse: None,
+                                    ..
+                                } => Some(Known::Tag(*tag_id, arguments)),
+                                _ => None,
+                            };
+
+                            if let Some(known) = known {
+                                self.known.insert(*symbol, known);
+                            }
+
+                            lets.push((*symbol, expr, *layout));
+                        }
+                    }
+
+                    stmt = continuation;
+                }
+                Stmt::Switch {
+                    cond_symbol,
+                    cond_layout,
+                    branches,
+                    default_branch,
+                    ret_layout,
+                } => {
+                    let cond_symbol = rename(&self.renames, *cond_symbol);
+
+                    if let Some(branch) = self.known_branch(cond_symbol, branches, default_branch.1)
+                    {
+                        stmt = branch;
+                        continue;
+                    }
+
+                    let branches = Vec::from_iter_in(
+                        branches.iter().map(|(label, info, branch)| {
+                            let info = rename_branch_info(&self.renames, info);
+
+                            (*label, info, self.simplify_scoped(branch))
+                        }),
+                        arena,
+                    );
+
+                    let default_branch = (
+                        rename_branch_info(&self.renames, &default_branch.0),
+                        &*arena.alloc(self.simplify_scoped(default_branch.1)),
+                    );
+
+                    break Stmt::Switch {
+                        cond_symbol,
+                        cond_layout: *cond_layout,
+                        branches: branches.into_bump_slice(),
+                        default_branch,
+                        ret_layout: *ret_layout,
+                    };
+                }
+                Stmt::Ret(symbol) => break Stmt::Ret(rename(&self.renames, *symbol)),
+                Stmt::Refcounting(modify_rc, continuation) => {
+                    let modify_rc = rename_modify_rc(&self.renames, *modify_rc);
+
+                    break Stmt::Refcounting(
+                        modify_rc,
+                        arena.alloc(self.simplify_stmt(continuation)),
+                    );
+                }
+                Stmt::Expect {
+                    condition,
+                    region,
+                    lookups,
+                    variables,
+                    remainder,
+                } => {
+                    break Stmt::Expect {
+                        condition: rename(&self.renames, *condition),
+                        region: *region,
+                        lookups: rename_all(arena, &self.renames, lookups),
+                        variables,
+                        remainder: arena.alloc(self.simplify_stmt(remainder)),
+                    }
+                }
+                Stmt::Dbg {
+                    source_location,
+                    source,
+                    symbol,
+                    variable,
+                    remainder,
+                } => {
+                    break Stmt::Dbg {
+                        source_location,
+                        source,
+                        symbol: rename(&self.renames, *symbol),
+                        variable: *variable,
+                        remainder: arena.alloc(self.simplify_stmt(remainder)),
+                    }
+                }
+                Stmt::Join {
+                    id,
+                    parameters,
+                    body,
+                    remainder,
+                } => match self.jump_counts.get(id).copied().unwrap_or(0) {
+                    0 => stmt = remainder,
+                    1 if !jumps_to(body, *id) => {
+                        self.single_jump_joins.insert(*id, (parameters, body));
+
+                        stmt = remainder;
+                    }
+                    _ => {
+                        let body = self.simplify_scoped(body);
+                        let remainder = self.simplify_stmt(remainder);
+
+                        break Stmt::Join {
+                            id: *id,
+                            parameters,
+                            body: arena.alloc(body),
+                            remainder: arena.alloc(remainder),
+                        };
+                    }
+                },
+                Stmt::Jump(id, arguments) => {
+                    let arguments = rename_all(arena, &self.renames, arguments);
+
+                    match self.single_jump_joins.remove(id) {
+                        Some((parameters, body)) => {
+                            for (param, argument) in parameters.iter().zip(arguments) {
+                                self.renames.insert(param.symbol, *argument);
+                            }
+
+                            stmt = body;
+                        }
+                        None => break Stmt::Jump(*id, arguments),
+                    }
+                }
+                Stmt::Crash(symbol, tag) => {
+                    break Stmt::Crash(rename(&self.renames, *symbol), *tag)
+                }
+            }
+        };
+
+        lets.into_iter()
+            .rev()
+            .fold(tail, |continuation, (symbol, expr, layout)| {
+                Stmt::Let(symbol, expr, layout, arena.alloc(continuation))
+            })
+    }
+}
+
+fn jumps_to(stmt: &Stmt, id: JoinPointId) -> bool {
+    let mut found = false;
+
+    for_each_stmt(stmt, |stmt| {
+        found |= matches!(stmt, Stmt::Jump(target, _) if *target == id);
+    });
+
+    found
+}
+
+/// Removes values that nothing uses and join points that nothing jumps to.
+struct DeadCodeEliminator<'a> {
+    arena: &'a Bump,
+    uses: MutMap<Symbol, usize>,
+    jump_counts: MutMap<JoinPointId, usize>,
+}
+
+impl<'a> DeadCodeEliminator<'a> {
+    fn is_unused(&self, symbol: Symbol) -> bool {
+        self.uses.get(&symbol).copied().unwrap_or(0) == 0
+    }
+
+    fn eliminate_stmt(&mut self, stmt: &Stmt<'a>) -> Stmt<'a> {
+        let arena = self.arena;
+
+        // chains of lets can get very long, so we walk those in a loop rather than recursively
+        let mut lets = std::vec::Vec::new();
+        let mut stmt = stmt;
+        while let Stmt::Let(symbol, expr, layout, continuation) = stmt {
+            lets.push((*symbol, expr, *layout));
+            stmt = continuation;
+        }
+
+        let tail = match stmt {
+            Stmt::Let(..) => unreachable!("lets are handled above"),
+            Stmt::Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let branches = Vec::from_iter_in(
+                    branches.iter().map(|(label, info, branch)| {
+                        (*label, info.clone(), self.eliminate_stmt(branch))
+                    }),
+                    arena,
+                );
+
+                let default_branch = (
+                    default_branch.0.clone(),
+                    &*arena.alloc(self.eliminate_stmt(default_branch.1)),
+                );
+
+                Stmt::Switch {
+                    cond_symbol: *cond_symbol,
+                    cond_layout: *cond_layout,
+                    branches: branches.into_bump_slice(),
+                    default_branch,
+                    ret_layout: *ret_layout,
+                }
+            }
+            Stmt::Refcounting(modify_rc, continuation) => {
+                Stmt::Refcounting(*modify_rc, arena.alloc(self.eliminate_stmt(continuation)))
+            }
+            Stmt::Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => Stmt::Expect {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder: arena.alloc(self.eliminate_stmt(remainder)),
+            },
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                variable,
+                remainder,
+            } => Stmt::Dbg {
+                source_location,
+                source,
+                symbol: *symbol,
+                variable: *variable,
+                remainder: arena.alloc(self.eliminate_stmt(remainder)),
+            },
+            Stmt::Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => {
+                let remainder = self.eliminate_stmt(remainder);
+
+                let mut jumps_from_body = 0;
+                for_each_stmt(body, |stmt| {
+                    if matches!(stmt, Stmt::Jump(target, _) if target == id) {
+                        jumps_from_body += 1;
+                    }
+                });
+
+                if self.jump_counts.get(id).copied().unwrap_or(0) == jumps_from_body {
+                    remainder
+                } else {
+                    Stmt::Join {
+                        id: *id,
+                        parameters,
+                        body: arena.alloc(self.eliminate_stmt(body)),
+                        remainder: arena.alloc(remainder),
+                    }
+                }
+            }
+            Stmt::Ret(_) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => stmt.clone(),
+        };
+
+        // the innermost lets go first, so they stop using values we can then remove
+        lets.into_iter()
+            .rev()
+            .fold(tail, |continuation, (symbol, expr, layout)| {
+                if self.is_unused(symbol) && can_be_removed(expr) {
+                    expr_uses(expr, |used| {
+                        if let Some(count) = self.uses.get_mut(&used) {
+                            *count -= 1;
+                        }
+                    });
+
+                    continuation
+                } else {
+                    Stmt::Let(symbol, expr.clone(), layout, arena.alloc(continuation))
+                }
+            })
+    }
+}
+
+/// Can this expression be removed when nothing uses its value? Calls stay, because they may crash.
+fn can_be_removed(expr: &Expr) -> bool {
+    match expr {
+        Expr::Literal(_)
+        | Expr::Struct(_)
+        | Expr::StructAtIndex { .. }
+        | Expr::GetTagId { .. }
+        | Expr::UnionAtIndex { .. }
+        | Expr::GetElementPointer { .. }
+        | Expr::Array { .. }
+        | Expr::EmptyArray
+        | Expr::NullPointer
+        | Expr::FunctionPointer { .. } => true,
+        Expr::Tag { reuse, .. } => reuse.is_none(),
+        Expr::Call(_)
+        | Expr::ErasedMake { .. }
+        | Expr::ErasedLoad { .. }
+        | Expr::Alloca { .. }
+        | Expr::Reset { .. }
+        | Expr::ResetRef { .. } => false,
+    }
+}
@@ -0,0 +1,255 @@
+//! Checking refcounts at runtime.
+//!
+//! Bugs in refcounting (in the compiler, in a builtin, or in a host) usually show up far away from
+//! where they happen, if at all: a value that is decremented too often is freed while it is still
+//! in use, and one that is decremented too little is never freed. When checking refcounts, the
+//! builtins track every allocation along with the [AllocSite](crate::alloc_profile::AllocSite)
+//! that made it, and report
+//!
+//! - decrements of an allocation that was already freed,
+//! - other uses of an allocation after it was freed,
+//! - allocations that are never freed.
+//!
+//! [instrument_refcount_checks] makes the entry points turn the tracking on:
+//!
+//! ```text
+//! let names = "main.roc:3:5 (List.append)\n...";
+//! let per_call = false;
+//! let already_checking = lowlevel RefCountCheckStart names per_call;
+//! ...
+//! ```
+//!
+//! Programs report leaks when they exit. Tests are run one after the other in the same process,
+//! so every test starts with a clean slate, and checks for leaks before it returns:
+//!
+//! ```text
+//! let no_leaks = lowlevel RefCountCheckLeaks;
+//! ret result;
+//! ```
+
+use bumpalo::collections::String;
+use bumpalo::Bump;
+use roc_collections::{MutMap, MutSet};
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+
+use crate::in_place::NamedSite;
+use crate::ir::{Call, CallType, Expr, Literal, Proc, ProcLayout, Stmt, UpdateModeId};
+use crate::layout::{InLayout, Layout};
+
+/// Makes the `entry_points` start checking refcounts, naming the allocation sites from
+/// [profile_allocations](crate::alloc_profile::profile_allocations) in the reports.
+///
+/// With `per_call`, every call of an entry point forgets about the allocations made before it,
+/// and panics if anything it allocated is still live when it returns. That only makes sense for
+/// entry points that return no heap data, like tests.
+pub fn instrument_refcount_checks<'a>(
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &mut IdentIds,
+    alloc_sites: &[(NamedSite, InLayout<'a>)],
+    entry_points: &MutSet<Symbol>,
+    per_call: bool,
+    procs: &mut MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) {
+    // the name of the site with id `n` goes on line `n - 1`
+    let mut site_names = String::new_in(arena);
+    for (site, _) in alloc_sites {
+        site_names.push_str(&site.location);
+        site_names.push(' ');
+        site_names.push_str(&site.callee);
+        site_names.push('\n');
+    }
+    let site_names = site_names.into_bump_str();
+
+    let mut instrumenter = Instrumenter {
+        arena,
+        home,
+        ident_ids,
+    };
+
+    for ((symbol, _), proc) in procs.iter_mut() {
+        if !entry_points.contains(symbol) {
+            continue;
+        }
+
+        let body = if per_call {
+            instrumenter.check_leaks_before_returns(&proc.body)
+        } else {
+            arena.alloc(proc.body.clone())
+        };
+
+        proc.body = instrumenter.start_checking(site_names, per_call, body);
+    }
+}
+
+struct Instrumenter<'a, 'r> {
+    arena: &'a Bump,
+    home: ModuleId,
+    ident_ids: &'r mut IdentIds,
+}
+
+impl<'a, 'r> Instrumenter<'a, 'r> {
+    fn unique_symbol(&mut self) -> Symbol {
+        let ident_id = self.ident_ids.gen_unique();
+
+        Symbol::new(self.home, ident_id)
+    }
+
+    fn low_level(op: LowLevel, arguments: &'a [Symbol]) -> Expr<'a> {
+        Expr::Call(Call {
+            call_type: CallType::LowLevel {
+                op,
+                update_mode: UpdateModeId::BACKEND_DUMMY,
+            },
+            arguments,
+        })
+    }
+
+    /// Makes `body` start with `lowlevel RefCountCheckStart names per_call`.
+    fn start_checking(
+        &mut self,
+        site_names: &'a str,
+        per_call: bool,
+        body: &'a Stmt<'a>,
+    ) -> Stmt<'a> {
+        let arena = self.arena;
+
+        let names = self.unique_symbol();
+        let per_call_symbol = self.unique_symbol();
+        let already_checking = self.unique_symbol();
+
+        let start = Stmt::Let(
+            already_checking,
+            Self::low_level(
+                LowLevel::RefCountCheckStart,
+                arena.alloc([names, per_call_symbol]),
+            ),
+            Layout::BOOL,
+            body,
+        );
+
+        let per_call_literal = Stmt::Let(
+            per_call_symbol,
+            Expr::Literal(Literal::Bool(per_call)),
+            Layout::BOOL,
+            arena.alloc(start),
+        );
+
+        Stmt::Let(
+            names,
+            Expr::Literal(Literal::Str(site_names)),
+            Layout::STR,
+            arena.alloc(per_call_literal),
+        )
+    }
+
+    fn check_leaks_before_returns(&mut self, stmt: &Stmt<'a>) -> &'a Stmt<'a> {
+        let arena = self.arena;
+
+        // chains of lets can get very long, so we walk those in a loop rather than recursively
+        let mut chain = std::vec::Vec::new();
+        let mut stmt = stmt;
+        while let Stmt::Let(_, _, _, continuation) | Stmt::Refcounting(_, continuation) = stmt {
+            chain.push(stmt);
+            stmt = continuation;
+        }
+
+        let tail = match stmt {
+            Stmt::Let(..) | Stmt::Refcounting(..) => unreachable!("handled above"),
+            Stmt::Ret(_) => {
+                let no_leaks = self.unique_symbol();
+
+                Stmt::Let(
+                    no_leaks,
+                    Self::low_level(LowLevel::RefCountCheckLeaks, &[]),
+                    Layout::BOOL,
+                    arena.alloc(stmt.clone()),
+                )
+            }
+            Stmt::Switch {
+                cond_symbol,
+                cond_layout,
+                branches,
+                default_branch,
+                ret_layout,
+            } => {
+                let branches = bumpalo::collections::Vec::from_iter_in(
+                    branches.iter().map(|(label, info, branch)| {
+                        (
+                            *label,
+                            info.clone(),
+                            self.check_leaks_before_returns(branch).clone(),
+                        )
+                    }),
+                    arena,
+                );
+
+                Stmt::Switch {
+                    cond_symbol: *cond_symbol,
+                    cond_layout: *cond_layout,
+                    branches: branches.into_bump_slice(),
+                    default_branch: (
+                        default_branch.0.clone(),
+                        self.check_leaks_before_returns(default_branch.1),
+                    ),
+                    ret_layout: *ret_layout,
+                }
+            }
+            Stmt::Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => Stmt::Expect {
+                condition: *condition,
+                region: *region,
+                lookups,
+                variables,
+                remainder: self.check_leaks_before_returns(remainder),
+            },
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                variable,
+                remainder,
+            } => Stmt::Dbg {
+                source_location,
+                source,
+                symbol: *symbol,
+                variable: *variable,
+                remainder: self.check_leaks_before_returns(remainder),
+            },
+            Stmt::Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => Stmt::Join {
+                id: *id,
+                parameters,
+                body: self.check_leaks_before_returns(body),
+                remainder: self.check_leaks_before_returns(remainder),
+            },
+            Stmt::Jump(_, _) | Stmt::Crash(_, _) => stmt.clone(),
+        };
+
+        let mut result: &'a Stmt<'a> = arena.alloc(tail);
+
+        for stmt in chain.into_iter().rev() {
+            result = match stmt {
+                Stmt::Let(symbol, expr, layout, _) => {
+                    arena.alloc(Stmt::Let(*symbol, expr.clone(), *layout, result))
+                }
+                Stmt::Refcounting(modify_rc, _) => {
+                    arena.alloc(Stmt::Refcounting(*modify_rc, result))
+                }
+                _ => unreachable!("only lets and refcounting are chained"),
+            };
+        }
+
+        result
+    }
+}
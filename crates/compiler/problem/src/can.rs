@@ -264,6 +264,12 @@ pub enum Problem {
         record_region: Region,
     },
     InterpolatedStringNotAllowed(Region),
+    ConstantCrashed {
+        symbol: Symbol,
+        region: Region,
+        message: String,
+    },
+    InvalidDocExample(Region),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -282,6 +288,8 @@ impl Problem {
             Problem::UnusedImport(_, _) => Warning,
             Problem::UnusedModuleImport(_, _) => Warning,
             Problem::InterpolatedStringNotAllowed(_) => RuntimeError,
+            Problem::ConstantCrashed { .. } => RuntimeError,
+            Problem::InvalidDocExample(_) => RuntimeError,
             Problem::ImportNameConflict { .. } => RuntimeError,
             Problem::ExplicitBuiltinImport(_, _) => Warning,
             Problem::ExplicitBuiltinTypeImport(_, _) => Warning,
@@ -379,6 +387,8 @@ impl Problem {
             }
             | Problem::ExplicitBuiltinImport(_, region)
             | Problem::InterpolatedStringNotAllowed(region)
+            | Problem::ConstantCrashed { region, .. }
+            | Problem::InvalidDocExample(region)
             | Problem::ExplicitBuiltinTypeImport(_, region)
             | Problem::ImportShadowsSymbol { region, .. }
             | Problem::UnusedArgument(_, _, _, region)
@@ -0,0 +1,62 @@
+use roc_module::symbol::Symbol;
+use roc_region::all::{Loc, Region};
+
+use crate::Severity;
+
+/// A problem found by `roc lint`. The code compiles and runs fine, so these aren't reported by
+/// `roc check` or `roc build`, but it's probably not what was meant, or more complicated than it
+/// needs to be.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LintProblem {
+    /// A `when` branch whose body is the same as the branch right before it
+    RedundantWhenBranch {
+        region: Region,
+        previous_branch: Region,
+        fix: Option<Fix>,
+    },
+    /// A `when List.get ... is` with a branch that crashes
+    CrashOnListGet { region: Region, crash: Region },
+    /// Names bound in the same definition that only differ by underscores or a number at the end,
+    /// like `user` and `user2`
+    ConfusableNames {
+        original: Loc<Symbol>,
+        confusable: Loc<Symbol>,
+    },
+    /// A `when` that unwraps an `Ok` and returns an `Err`, which is what `?` does
+    ManualTry { region: Region, fix: Fix },
+    /// A `dbg` outside of an `expect`
+    LeftoverDbg { region: Region, fix: Option<Fix> },
+}
+
+/// A change to the source code that resolves a [LintProblem], applied by `roc lint --fix`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fix {
+    pub region: Region,
+    pub replacement: String,
+}
+
+impl LintProblem {
+    pub fn severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    pub fn region(&self) -> Region {
+        match self {
+            LintProblem::RedundantWhenBranch { region, .. }
+            | LintProblem::CrashOnListGet { region, .. }
+            | LintProblem::ManualTry { region, .. }
+            | LintProblem::LeftoverDbg { region, .. } => *region,
+            LintProblem::ConfusableNames { confusable, .. } => confusable.region,
+        }
+    }
+
+    pub fn fix(&self) -> Option<&Fix> {
+        match self {
+            LintProblem::RedundantWhenBranch { fix, .. } | LintProblem::LeftoverDbg { fix, .. } => {
+                fix.as_ref()
+            }
+            LintProblem::ManualTry { fix, .. } => Some(fix),
+            LintProblem::CrashOnListGet { .. } | LintProblem::ConfusableNames { .. } => None,
+        }
+    }
+}
@@ -10,7 +10,8 @@ use roc_error_macros::internal_error;
 use roc_module::symbol::{ModuleId, Symbol};
 use roc_region::all::{Loc, Region};
 use roc_solve_problem::{
-    NotDerivableContext, NotDerivableEq, TypeError, UnderivableReason, Unfulfilled,
+    NotDerivableContext, NotDerivableEq, NotDerivableSort, TypeError, UnderivableReason,
+    Unfulfilled,
 };
 use roc_solve_schema::UnificationMode;
 use roc_types::num::NumericRange;
@@ -310,6 +311,17 @@ impl ObligationCache {
                 var,
             )),
 
+            Symbol::SORT_SORT_ABILITY => {
+                Some(DeriveSort::is_derivable(self, abilities_store, subs, var))
+            }
+
+            Symbol::DEFAULT_DEFAULT_ABILITY => Some(DeriveDefault::is_derivable(
+                self,
+                abilities_store,
+                subs,
+                var,
+            )),
+
             _ => None,
         };
 
@@ -392,6 +404,8 @@ impl ObligationCache {
             DeriveEq::ABILITY => DeriveEq::is_derivable_builtin_opaque(opaque),
             DeriveHash::ABILITY => DeriveHash::is_derivable_builtin_opaque(opaque),
             DeriveInspect::ABILITY => DeriveInspect::is_derivable_builtin_opaque(opaque),
+            DeriveSort::ABILITY => DeriveSort::is_derivable_builtin_opaque(opaque),
+            DeriveDefault::ABILITY => DeriveDefault::is_derivable_builtin_opaque(opaque),
             _ => false,
         };
 
@@ -1388,6 +1402,250 @@ impl DerivableVisitor for DeriveEq {
     }
 }
 
+struct DeriveSort;
+impl DerivableVisitor for DeriveSort {
+    const ABILITY: Symbol = Symbol::SORT_SORT_ABILITY;
+    const ABILITY_SLICE: SubsSlice<Symbol> = Subs::AB_SORT;
+
+    #[inline(always)]
+    fn is_derivable_builtin_opaque(symbol: Symbol) -> bool {
+        is_builtin_fixed_int_alias(symbol)
+            || is_builtin_dec_alias(symbol)
+            || is_builtin_bool_alias(symbol)
+    }
+
+    #[inline(always)]
+    fn visit_recursion(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_apply(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        // Dicts and sets have no order to compare their elements in.
+        if matches!(symbol, Symbol::LIST_LIST | Symbol::STR_STR) {
+            Ok(Descend(true))
+        } else {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            })
+        }
+    }
+
+    #[inline(always)]
+    fn visit_record(
+        subs: &Subs,
+        var: Variable,
+        fields: RecordFields,
+    ) -> Result<Descend, NotDerivable> {
+        for (field_name, _, field) in fields.iter_all() {
+            if subs[field].is_optional() {
+                return Err(NotDerivable {
+                    var,
+                    context: NotDerivableContext::DecodeOptionalRecordField(
+                        subs[field_name].clone(),
+                    ),
+                });
+            }
+        }
+
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tuple(
+        _subs: &Subs,
+        _var: Variable,
+        _elems: TupleElems,
+    ) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_recursive_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_function_or_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_empty_record(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_empty_tag_union(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_alias(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if is_builtin_float_alias(symbol) {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::Sort(NotDerivableSort::FloatingPoint),
+            })
+        } else if is_builtin_number_alias(symbol) {
+            Ok(Descend(false))
+        } else {
+            Ok(Descend(true))
+        }
+    }
+
+    fn visit_floating_point_content(
+        var: Variable,
+        subs: &mut Subs,
+        content_var: Variable,
+    ) -> Result<Descend, NotDerivable> {
+        use roc_unify::unify::unify;
+
+        // Of the floating-point types, only Dec can be put in a total order.
+        // TODO(checkmate): pass checkmate through
+        let unified = unify(
+            &mut with_checkmate!({
+                on => UEnv::new(subs, None),
+                off => UEnv::new(subs),
+            }),
+            content_var,
+            Variable::DECIMAL,
+            UnificationMode::EQ,
+            Polarity::Pos,
+        );
+        match unified {
+            roc_unify::unify::Unified::Success { .. } => Ok(Descend(false)),
+            roc_unify::unify::Unified::Failure(..) => Err(NotDerivable {
+                var,
+                context: NotDerivableContext::Sort(NotDerivableSort::FloatingPoint),
+            }),
+        }
+    }
+
+    #[inline(always)]
+    fn visit_ranged_number(_var: Variable, _range: NumericRange) -> Result<(), NotDerivable> {
+        // Like with `is_eq`, ranged numbers are always possibly ints.
+        Ok(())
+    }
+}
+
+struct DeriveDefault;
+impl DerivableVisitor for DeriveDefault {
+    const ABILITY: Symbol = Symbol::DEFAULT_DEFAULT_ABILITY;
+    const ABILITY_SLICE: SubsSlice<Symbol> = Subs::AB_DEFAULT;
+
+    #[inline(always)]
+    fn is_derivable_builtin_opaque(symbol: Symbol) -> bool {
+        is_builtin_number_alias(symbol)
+            || is_builtin_bool_alias(symbol)
+            || matches!(symbol, Symbol::DICT_DICT)
+    }
+
+    #[inline(always)]
+    fn visit_recursion(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_apply(var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        // These all default to being empty, so their elements don't need a default.
+        if matches!(
+            symbol,
+            Symbol::LIST_LIST | Symbol::DICT_DICT | Symbol::STR_STR
+        ) {
+            Ok(Descend(false))
+        } else {
+            Err(NotDerivable {
+                var,
+                context: NotDerivableContext::NoContext,
+            })
+        }
+    }
+
+    #[inline(always)]
+    fn visit_record(
+        subs: &Subs,
+        var: Variable,
+        fields: RecordFields,
+    ) -> Result<Descend, NotDerivable> {
+        for (field_name, _, field) in fields.iter_all() {
+            if subs[field].is_optional() {
+                return Err(NotDerivable {
+                    var,
+                    context: NotDerivableContext::DecodeOptionalRecordField(
+                        subs[field_name].clone(),
+                    ),
+                });
+            }
+        }
+
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tuple(
+        _subs: &Subs,
+        _var: Variable,
+        _elems: TupleElems,
+    ) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_recursive_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_function_or_tag_union(_var: Variable) -> Result<Descend, NotDerivable> {
+        Ok(Descend(true))
+    }
+
+    #[inline(always)]
+    fn visit_empty_record(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_empty_tag_union(_var: Variable) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_alias(_var: Variable, symbol: Symbol) -> Result<Descend, NotDerivable> {
+        if is_builtin_number_alias(symbol) {
+            Ok(Descend(false))
+        } else {
+            Ok(Descend(true))
+        }
+    }
+
+    #[inline(always)]
+    fn visit_ranged_number(_var: Variable, _range: NumericRange) -> Result<(), NotDerivable> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn visit_floating_point_content(
+        _var: Variable,
+        _subs: &mut Subs,
+        _content_var: Variable,
+    ) -> Result<Descend, NotDerivable> {
+        Ok(Descend(false))
+    }
+}
+
 /// Determines what type implements an ability member of a specialized signature, given the
 /// [MustImplementAbility] constraints of the signature.
 pub fn type_implementing_specialization(
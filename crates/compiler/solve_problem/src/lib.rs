@@ -161,9 +161,15 @@ pub enum NotDerivableContext {
     Opaque(Symbol),
     DecodeOptionalRecordField(Lowercase),
     Eq(NotDerivableEq),
+    Sort(NotDerivableSort),
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub enum NotDerivableEq {
     FloatingPoint,
 }
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum NotDerivableSort {
+    FloatingPoint,
+}
@@ -0,0 +1,204 @@
+#![cfg(test)]
+// Even with #[allow(non_snake_case)] on individual idents, rust-analyzer issues diagnostics.
+// See https://github.com/rust-lang/rust-analyzer/issues/6541.
+// For the `v!` macro we use uppercase variables when constructing tag unions.
+#![allow(non_snake_case)]
+
+use crate::{
+    test_key_eq, test_key_neq,
+    util::{check_derivable, check_single_lset_immediate, check_underivable, derive_test},
+    v,
+};
+use insta::assert_snapshot;
+use roc_module::symbol::Symbol;
+use roc_types::subs::Variable;
+
+use roc_derive_key::{default::FlatDefaultKey, DeriveBuiltin::Default, DeriveError, DeriveKey};
+
+test_key_eq! {
+    Default,
+
+    same_record:
+        v!({ a: v!(U8), }), v!({ a: v!(U8), })
+    same_record_fields_diff_types:
+        v!({ a: v!(U8), }), v!({ a: v!(STR), })
+    same_record_fields_any_order:
+        v!({ a: v!(U8), b: v!(U8), c: v!(U8), }),
+        v!({ c: v!(U8), a: v!(U8), b: v!(U8), })
+    explicit_empty_record_and_implicit_empty_record:
+        v!(EMPTY_RECORD), v!({})
+
+    same_tuple:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16),))
+    same_tuple_fields_diff_types:
+        v!((v!(U8), v!(U16),)), v!((v!(U32), v!(U64),))
+
+    same_tag_union:
+        v!([ A v!(U8) v!(STR), B v!(STR) ]), v!([ A v!(U8) v!(STR), B v!(STR) ])
+    same_tag_union_tags_diff_types:
+        v!([ A v!(U8) v!(U8), B v!(U8) ]), v!([ A v!(STR) v!(STR), B v!(STR) ])
+    same_tag_union_tags_any_order:
+        v!([ A v!(U8) v!(U8), B v!(U8), C ]), v!([ C, B v!(STR), A v!(STR) v!(STR) ])
+
+    same_recursive_tag_union:
+        v!([ Nil, Cons v!(^lst)] as lst), v!([ Nil, Cons v!(^lst)] as lst)
+    same_tag_union_and_recursive_tag_union_fields:
+        v!([ Nil, Cons v!(STR)]), v!([ Nil, Cons v!(^lst)] as lst)
+}
+
+test_key_neq! {
+    Default,
+
+    different_record_fields:
+        v!({ a: v!(U8), }), v!({ b: v!(U8), })
+    record_empty_vs_nonempty:
+        v!(EMPTY_RECORD), v!({ a: v!(U8), })
+
+    different_tuple_arities:
+        v!((v!(U8), v!(U16),)), v!((v!(U8), v!(U16), v!(U32),))
+
+    different_tag_union_tags:
+        v!([ A v!(U8) ]), v!([ B v!(U8) ])
+    different_recursive_tag_union_tags:
+        v!([ Nil, Cons v!(^lst) ] as lst), v!([ Nil, Next v!(^lst) ] as lst)
+}
+
+#[test]
+fn immediates() {
+    check_single_lset_immediate(Default, v!(U8), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(U16), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(U32), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(U64), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(U128), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(I8), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(I16), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(I32), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(I64), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(I128), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(F32), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(F64), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(DEC), Symbol::DEFAULT_NUM);
+    check_single_lset_immediate(Default, v!(STR), Symbol::DEFAULT_STR);
+    check_single_lset_immediate(Default, v!(Symbol::LIST_LIST v!(U8)), Symbol::DEFAULT_LIST);
+    check_single_lset_immediate(
+        Default,
+        v!(Symbol::DICT_DICT v!(STR) v!(U8)),
+        Symbol::DEFAULT_DICT,
+    );
+}
+
+#[test]
+fn optional_record_field_derive_error() {
+    check_underivable(Default, v!({ ?a: v!(U8), }), DeriveError::Underivable);
+}
+
+#[test]
+fn empty_tag_union_derive_error() {
+    check_underivable(Default, v!(EMPTY_TAG_UNION), DeriveError::Underivable);
+}
+
+#[test]
+fn derivable_record_ext_flex_var() {
+    check_derivable(
+        Default,
+        v!({ a: v!(STR), }* ),
+        DeriveKey::Default(FlatDefaultKey::Record(vec!["a".into()])),
+    );
+}
+
+#[test]
+fn derivable_record_with_record_ext() {
+    check_derivable(
+        Default,
+        v!({ b: v!(STR), }{ a: v!(STR), } ),
+        DeriveKey::Default(FlatDefaultKey::Record(vec!["a".into(), "b".into()])),
+    );
+}
+
+#[test]
+fn derivable_tag_ext_flex_var() {
+    check_derivable(
+        Default,
+        v!([ A v!(STR) ]* ),
+        DeriveKey::Default(FlatDefaultKey::TagUnion(vec![("A".into(), 1)])),
+    );
+}
+
+#[test]
+fn empty_record() {
+    derive_test(Default, v!(EMPTY_RECORD), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for {}
+        # {} -[[default_{}(0)]]-> {}
+        # {} -[[default_{}(0)]]-> {}
+        # Specialization lambda sets:
+        #   @<1>: [[default_{}(0)]]
+        #Derived.default_{} = \_ -> {}
+        "###
+        )
+    })
+}
+
+#[test]
+fn two_field_record() {
+    derive_test(Default, v!({ a: v!(U8), b: v!(STR), }), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for { a : U8, b : Str }
+        # {} -[[default_{a,b}(0)]]-> { a : a, b : a1 } where a implements Default, a1 implements Default
+        # {} -[[default_{a,b}(0)]]-> { a : a, b : a1 } where a implements Default, a1 implements Default
+        # Specialization lambda sets:
+        #   @<1>: [[default_{a,b}(0)]]
+        #Derived.default_{a,b} = \_ -> { b: default {}, a: default {} }
+        "###
+        )
+    })
+}
+
+#[test]
+fn three_element_tuple() {
+    derive_test(Default, v!((v!(U8), v!(STR), v!(U16),)), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for ( U8, Str, U16 )*
+        # {} -[[default_(arity:3)(0)]]-> ( a, a1, a2 )* where a implements Default, a1 implements Default, a2 implements Default
+        # {} -[[default_(arity:3)(0)]]-> ( a, a1, a2 )* where a implements Default, a1 implements Default, a2 implements Default
+        # Specialization lambda sets:
+        #   @<1>: [[default_(arity:3)(0)]]
+        #Derived.default_(arity:3) = \_ -> ( default {}, default {}, default {} )
+        "###
+        )
+    })
+}
+
+#[test]
+fn tag_three_labels() {
+    derive_test(
+        Default,
+        v!([B v!(U8) v!(STR), C v!(U16), D v!(STR)]),
+        |golden| {
+            assert_snapshot!(golden, @r###"
+            # derived for [B U8 Str, C U16, D Str]
+            # {} -[[default_[B 2,C 1,D 1](0)]]-> [B * *, C a, D *] where a implements Default
+            # {} -[[default_[B 2,C 1,D 1](0)]]-> [B * *, C a, D *] where a implements Default
+            # Specialization lambda sets:
+            #   @<1>: [[default_[B 2,C 1,D 1](0)]]
+            #Derived.default_[B 2,C 1,D 1] = \_ -> C (default {})
+            "###
+            )
+        },
+    )
+}
+
+#[test]
+fn recursive_tag_union() {
+    derive_test(Default, v!([Cons v!(U8) v!(^lst), Nil ] as lst), |golden| {
+        assert_snapshot!(golden, @r###"
+        # derived for [Cons U8 $rec, Nil] as $rec
+        # {} -[[default_[Cons 2,Nil 0](0)]]-> [Cons * *, Nil]
+        # {} -[[default_[Cons 2,Nil 0](0)]]-> [Cons * *, Nil]
+        # Specialization lambda sets:
+        #   @<1>: [[default_[Cons 2,Nil 0](0)]]
+        #Derived.default_[Cons 2,Nil 0] = \_ -> Nil
+        "###
+        )
+    })
+}
@@ -2327,3 +2327,52 @@ fn recursive_tag_id_in_allocation_eq() {
         bool
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn niche_filled_option_bool_is_a_byte() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            x : [None, Some Bool]
+            x = Some Bool.true
+
+            main = x
+            "#
+        ),
+        2,
+        u8
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn niche_filled_nested_enum() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            Light : [Red, Yellow, Green]
+
+            Signal : [Off, Steady Light, Blinking Light]
+
+            describe : Signal -> U8
+            describe = \signal ->
+                when signal is
+                    Off -> 0
+                    Steady Red -> 1
+                    Steady _ -> 2
+                    Blinking light -> if light == Yellow then 3 else 4
+
+            main =
+                [Off, Steady Red, Steady Green, Blinking Yellow, Blinking Red]
+                |> List.map describe
+            "#
+        ),
+        RocList::from_slice(&[0, 1, 2, 3, 4]),
+        RocList<u8>
+    );
+}
@@ -2,6 +2,7 @@ use libloading::Library;
 use roc_build::link::{link, LinkType};
 use roc_load::{EntryPoint, ExecutionMode, LoadConfig, Threading};
 use roc_mono::ir::CrashTag;
+use roc_mono::ir::OptLevel;
 use roc_mono::ir::SingleEntryPoint;
 use roc_packaging::cache::RocCacheDir;
 use roc_region::all::LineInfo;
@@ -62,6 +63,7 @@ pub fn helper(
         threading: Threading::Single,
         exec_mode: ExecutionMode::Executable,
         function_kind: FunctionKind::LambdaSet,
+        opt_level: Some(OptLevel::Development),
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -20,6 +20,7 @@ use roc_load::LoadMonomorphizedError;
 use roc_load::Threading;
 use roc_module::symbol::Interns;
 use roc_module::symbol::Symbol;
+use roc_mono::ir::OptLevel;
 use roc_mono::ir::Proc;
 use roc_mono::ir::ProcLayout;
 use roc_mono::layout::STLayoutInterner;
@@ -124,7 +125,30 @@ fn promote_expr_to_module(src: &str) -> String {
     buffer
 }
 
-fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: bool, no_check: bool) {
+/// How to compile a mono test, as given to `#[mono_test]`
+struct MonoTestOptions {
+    /// `exec` or `test`
+    mode: &'static str,
+    /// An [OptLevel], or empty to show the IR exactly as it was specialized
+    opt_level: &'static str,
+    allow_type_errors: bool,
+    no_check: bool,
+    trace_clones: bool,
+    profile_alloc: bool,
+    check_refcounts: bool,
+}
+
+fn compiles_to_ir(test_name: &str, src: &str, options: MonoTestOptions) {
+    let MonoTestOptions {
+        mode,
+        opt_level,
+        allow_type_errors,
+        no_check,
+        trace_clones,
+        profile_alloc,
+        check_refcounts,
+    } = options;
+
     use roc_packaging::cache::RocCacheDir;
     use std::path::PathBuf;
 
@@ -134,6 +158,16 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: boo
         _ => panic!("Invalid test_mono exec mode {mode}"),
     };
 
+    // by default, tests show the IR exactly as it was specialized
+    let opt_level = match opt_level {
+        "" => None,
+        "development" => Some(OptLevel::Development),
+        "normal" => Some(OptLevel::Normal),
+        "size" => Some(OptLevel::Size),
+        "optimize" => Some(OptLevel::Optimize),
+        _ => panic!("Invalid test_mono opt level {opt_level}"),
+    };
+
     let arena = &Bump::new();
 
     let filename = PathBuf::from("Test.roc");
@@ -158,6 +192,7 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: boo
         render: roc_reporting::report::RenderTarget::Generic,
         palette: roc_reporting::report::DEFAULT_PALETTE,
         exec_mode,
+        opt_level,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -184,10 +219,13 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: boo
     use roc_load::MonomorphizedModule;
     let MonomorphizedModule {
         module_id: home,
-        procedures,
+        mut procedures,
         exposed_to_host,
         mut layout_interner,
-        interns,
+        mut interns,
+        call_sites,
+        sources,
+        toplevel_expects,
         ..
     } = loaded;
 
@@ -204,11 +242,98 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: boo
 
     let main_fn_symbol = exposed_to_host.top_level_values.keys().copied().next();
 
+    // the updates that always copy go on top, followed by the instrumented IR
+    let mut header = String::new();
+    if trace_clones {
+        let shared = roc_mono::in_place::trace_clones(
+            arena,
+            &layout_interner,
+            home,
+            &mut interns,
+            &sources,
+            &call_sites,
+            &mut procedures,
+        );
+
+        for (site, reason) in shared {
+            header.push_str(&format!(
+                "# {} {}: {reason:?}\n",
+                site.location, site.callee
+            ));
+        }
+    }
+
+    // followed by the allocation sites
+    if profile_alloc {
+        use roc_mono::layout::LayoutInterner;
+
+        let alloc_sites = roc_mono::alloc_profile::profile_allocations(
+            arena,
+            &layout_interner,
+            home,
+            &mut interns,
+            &sources,
+            &call_sites,
+            &mut procedures,
+        );
+
+        for (index, (site, layout)) in alloc_sites.iter().enumerate() {
+            header.push_str(&format!(
+                "# site {} {} {}: {}\n",
+                index + 1,
+                site.location,
+                site.callee,
+                layout_interner.dbg(*layout)
+            ));
+        }
+    }
+
+    // and the instrumented entry points, which name the sites in a string literal
+    if check_refcounts {
+        use roc_mono::refcount_check::instrument_refcount_checks;
+
+        let alloc_sites = roc_mono::alloc_profile::profile_allocations(
+            arena,
+            &layout_interner,
+            home,
+            &mut interns,
+            &sources,
+            &call_sites,
+            &mut procedures,
+        );
+
+        let per_call = matches!(exec_mode, ExecutionMode::Test);
+        let entry_points = if per_call {
+            toplevel_expects
+                .values()
+                .flat_map(|expects| expects.pure.keys().copied())
+                .collect()
+        } else {
+            main_fn_symbol.into_iter().collect()
+        };
+
+        instrument_refcount_checks(
+            arena,
+            home,
+            interns.all_ident_ids.get_mut(&home).unwrap(),
+            &alloc_sites,
+            &entry_points,
+            per_call,
+            &mut procedures,
+        );
+    }
+
     if !no_check {
         check_procedures(arena, &interns, &mut layout_interner, &procedures);
     }
 
-    verify_procedures(test_name, layout_interner, procedures, main_fn_symbol);
+    verify_procedures(
+        test_name,
+        &header,
+        layout_interner,
+        procedures,
+        main_fn_symbol,
+    );
 }
 
 fn check_procedures<'a>(
@@ -228,6 +353,7 @@ fn check_procedures<'a>(
 
 fn verify_procedures<'a>(
     test_name: &str,
+    header: &str,
     interner: STLayoutInterner<'a>,
     procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
     opt_main_fn_symbol: Option<Symbol>,
@@ -251,7 +377,7 @@ fn verify_procedures<'a>(
         procs_string.push(main_fn);
     }
 
-    let result = procs_string.join("\n");
+    let result = format!("{header}{}", procs_string.join("\n"));
 
     let path = format!("generated/{test_name}.txt");
     std::fs::create_dir_all("generated").unwrap();
@@ -3711,3 +3837,298 @@ fn return_annotated() {
         "#
     )
 }
+
+#[mono_test]
+fn sort_derived_record_with_sort_with() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main =
+            List.sort_with [{ name: "b", age: 2u8 }, { name: "a", age: 1u8 }] Sort.compare
+        "#
+    )
+}
+
+#[mono_test]
+fn sort_derived_opaque_tag_union() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Priority := [Low, High U8] implements [Sort]
+
+        main =
+            when Sort.compare (@Priority (High 1)) (@Priority Low) is
+                GT -> 1u8
+                _ -> 0u8
+        "#
+    )
+}
+
+#[mono_test]
+fn default_derived_record_update() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Config : { name : Str, retries : U8, verbose : Bool, tags : List Str }
+
+        main =
+            config : Config
+            config = Default.default {}
+
+            { config & retries: 3 }
+        "#
+    )
+}
+
+#[mono_test]
+fn default_derived_opaque_tag_union() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Level := [Off, On (F64, Dec)] implements [Default]
+
+        main =
+            when Default.default {} is
+                @Level Off -> 1u8
+                @Level (On _) -> 0u8
+        "#
+    )
+}
+
+#[mono_test(opt_level = "normal")]
+fn const_eval_arithmetic_and_strings() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        seconds_per_day : U64
+        seconds_per_day = 24 * 60 * 60
+
+        greeting = Str.concat "Hello, " (Num.to_str seconds_per_day)
+
+        main = greeting
+        "#
+    )
+}
+
+#[mono_test(opt_level = "normal")]
+fn const_eval_lookup_table() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        squares : List U16
+        squares = List.map (List.range { start: At 0, end: Before 8 }) \n -> n * n
+
+        main = List.get squares 3
+        "#
+    )
+}
+
+#[mono_test(opt_level = "normal")]
+fn const_eval_record_of_tags() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        defaults = {
+            name: Str.repeat "ab" 2,
+            level: if List.len [1, 2, 3] > 2 then High 3u8 else Low,
+        }
+
+        main = defaults
+        "#
+    )
+}
+
+#[mono_test(opt_level = "normal")]
+fn const_eval_skips_dict_from_list() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        codes : Dict Str U8
+        codes = Dict.from_list [("a", 1), ("b", 2)]
+
+        main = Dict.get codes "b"
+        "#
+    )
+}
+
+#[mono_test(opt_level = "normal")]
+fn const_eval_keeps_crashing_constant() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        max_items : U8
+        max_items = Num.max_u8 + 1
+
+        main = max_items
+        "#
+    )
+}
+
+#[mono_test(opt_level = "development")]
+fn optimize_inlines_small_procs() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        add_one : I64 -> I64
+        add_one = \x -> x + 1
+
+        main = \n -> add_one (add_one n)
+        "#
+    )
+}
+
+#[mono_test(opt_level = "development")]
+fn optimize_folds_constant_arithmetic() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = \n ->
+            width : I64
+            width = 6 * 7
+
+            n + width - 2
+        "#
+    )
+}
+
+#[mono_test(opt_level = "normal")]
+fn optimize_when_on_known_constructor() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Shape : [Circle I64, Square I64]
+
+        area : Shape -> I64
+        area = \shape ->
+            when shape is
+                Circle r -> 3 * r * r
+                Square s -> s * s
+
+        main = \n -> area (Square n)
+        "#
+    )
+}
+
+#[mono_test]
+fn niche_filled_option_bool() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        flip : [None, Some Bool] -> [None, Some Bool]
+        flip = \x ->
+            when x is
+                None -> None
+                Some b -> Some (Bool.not b)
+
+        main = \b -> flip (Some b)
+        "#
+    )
+}
+
+#[mono_test]
+fn niche_filled_nested_enum() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        Light : [Red, Yellow, Green]
+
+        Signal : [Off, Steady Light, Blinking Light]
+
+        describe : Signal -> Str
+        describe = \signal ->
+            when signal is
+                Off -> "off"
+                Steady Red -> "stop"
+                Steady _ -> "go"
+                Blinking light -> if light == Yellow then "caution" else "blinking"
+
+        main = \light -> describe (Blinking light)
+        "#
+    )
+}
+
+#[mono_test(trace_clones = "true")]
+fn trace_clones_of_shared_values() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        keep_both : List U64 -> U64
+        keep_both = \list ->
+            updated = List.set list 0 10
+            List.len list + List.len updated
+
+        greet : Str -> Str
+        greet = \name ->
+            greeting = Str.concat name "!"
+            if Str.is_empty name then "" else greeting
+
+        main = \n ->
+            unique = List.append [n] n
+            keep_both unique + Str.count_utf8_bytes (greet "hello")
+        "#
+    )
+}
+
+#[mono_test(trace_clones = "true", opt_level = "normal")]
+fn trace_clones_through_inlined_builtins() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main = \n ->
+            list = List.repeat 0 n
+            updated = List.set list 0 n
+            List.len list + List.len updated
+        "#
+    )
+}
+
+#[mono_test(profile_alloc = "true")]
+fn profile_alloc_call_sites() {
+    indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        digits : U64 -> List U64
+        digits = \n ->
+            if n < 10 then [n] else List.append (digits (n // 10)) (n % 10)
+
+        main = \n ->
+            list = digits n
+            strs = List.map list Num.to_str
+            List.len list + List.len strs
+        "#
+    )
+}
+
+#[mono_test(mode = "test", check_refcounts = "true")]
+fn check_refcounts_in_expects() {
+    indoc!(
+        r#"
+        interface Test exposes [] imports []
+
+        digits : U64 -> List U64
+        digits = \n ->
+            if n < 10 then [n] else List.append (digits (n // 10)) (n % 10)
+
+        expect
+            list = digits 1234
+            List.len list == 4
+        "#
+    )
+}
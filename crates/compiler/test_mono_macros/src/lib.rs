@@ -9,7 +9,11 @@ pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
     let mut no_check = false;
     let mut allow_type_errors = false;
     let mut mode = "exec".to_owned();
+    let mut opt_level = String::new();
     let mut large_stack = false;
+    let mut trace_clones = false;
+    let mut profile_alloc = false;
+    let mut check_refcounts = false;
     for arg in syn::parse_macro_input!(args as syn::AttributeArgs) {
         use syn::{Lit, Meta, MetaNameValue, NestedMeta};
         if let NestedMeta::Meta(Meta::NameValue(MetaNameValue {
@@ -21,6 +25,9 @@ pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
             if path.is_ident("mode") {
                 mode = s.value();
             }
+            if path.is_ident("opt_level") {
+                opt_level = s.value();
+            }
             if path.is_ident("no_check") {
                 no_check = true;
             }
@@ -30,6 +37,15 @@ pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
             if path.is_ident("large_stack") {
                 large_stack = true;
             }
+            if path.is_ident("trace_clones") {
+                trace_clones = true;
+            }
+            if path.is_ident("profile_alloc") {
+                profile_alloc = true;
+            }
+            if path.is_ident("check_refcounts") {
+                check_refcounts = true;
+            }
         }
     }
 
@@ -48,10 +64,20 @@ pub fn mono_test(args: TokenStream, item: TokenStream) -> TokenStream {
         #[test]
         #(#attributes)*
         #visibility fn #name(#args) {
+            let options = MonoTestOptions {
+                mode: #mode,
+                opt_level: #opt_level,
+                allow_type_errors: #allow_type_errors,
+                no_check: #no_check,
+                trace_clones: #trace_clones,
+                profile_alloc: #profile_alloc,
+                check_refcounts: #check_refcounts,
+            };
+
             if #large_stack {
-                with_larger_debug_stack(|| compiles_to_ir(#name_str, #body, &#mode, #allow_type_errors, #no_check));
+                with_larger_debug_stack(|| compiles_to_ir(#name_str, #body, options));
             } else {
-                compiles_to_ir(#name_str, #body, &#mode, #allow_type_errors, #no_check);
+                compiles_to_ir(#name_str, #body, options);
             }
         }
     };
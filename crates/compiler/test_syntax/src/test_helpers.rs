@@ -8,7 +8,7 @@ use roc_can_solo::env::SoloEnv;
 use roc_can_solo::scope::SoloScope;
 use roc_error_macros::set_panic_not_exit;
 use roc_fmt::migrate::{MigrateError, Suffix};
-use roc_fmt::{annotation::Formattable, header::fmt_header, MigrationFlags};
+use roc_fmt::{annotation::Formattable, fmt_with_max_width, header::fmt_header, MigrationFlags};
 use roc_module::ident::QualifiedModuleName;
 use roc_module::symbol::{IdentIds, Interns, ModuleIds, PackageModuleIds, Symbol};
 use roc_parse::ast::ValueDef;
@@ -111,32 +111,67 @@ pub enum Output<'a> {
 
 impl<'a> Output<'a> {
     pub fn format(&self, flags: MigrationFlags) -> InputOwned {
+        self.format_with_max_width(flags, None)
+    }
+
+    /// Format, reformatting the result until it stops changing if there's a max width
+    /// (see `roc_fmt::fmt_with_max_width`).
+    pub fn format_with_max_width(
+        &self,
+        flags: MigrationFlags,
+        max_width: Option<u16>,
+    ) -> InputOwned {
         let arena = Bump::new();
-        let mut buf = Buf::new_in(&arena, flags);
+        let kind = match self {
+            Output::Header(_) => InputKind::Header,
+            Output::ModuleDefs(_) => InputKind::ModuleDefs,
+            Output::Expr(_) => InputKind::Expr,
+            Output::Full(_) => InputKind::Full,
+            Output::Pattern(_) => InputKind::Pattern,
+        };
+        let formatted = fmt_with_max_width(
+            &arena,
+            "",
+            self,
+            flags,
+            max_width,
+            |buf, output| output.format_into(buf),
+            |src| kind.with_text(src).parse_in(&arena),
+        );
+        // If it doesn't parse, callers find that out (and say so) when they reparse it.
+        let formatted_src = match formatted {
+            Ok((formatted_src, _, _)) | Err((formatted_src, _)) => formatted_src.to_string(),
+        };
+
+        match kind {
+            InputKind::Header => InputOwned::Header(formatted_src),
+            InputKind::ModuleDefs => InputOwned::ModuleDefs(formatted_src),
+            InputKind::Expr => InputOwned::Expr(formatted_src),
+            InputKind::Full => InputOwned::Full(formatted_src),
+            InputKind::Pattern => InputOwned::Pattern(formatted_src),
+        }
+    }
+
+    fn format_into(&self, buf: &mut Buf) {
         match self {
             Output::Header(header) => {
-                fmt_header(&mut buf, header);
+                fmt_header(buf, header);
                 buf.fmt_end_of_file();
-                InputOwned::Header(buf.as_str().to_string())
             }
             Output::ModuleDefs(defs) => {
-                defs.format(&mut buf, 0);
+                defs.format(buf, 0);
                 buf.fmt_end_of_file();
-                InputOwned::ModuleDefs(buf.as_str().to_string())
             }
             Output::Expr(expr) => {
-                expr.format(&mut buf, 0);
-                InputOwned::Expr(buf.as_str().to_string())
+                expr.format(buf, 0);
             }
             Output::Full(full) => {
-                fmt_header(&mut buf, &full.header);
-                full.defs.format(&mut buf, 0);
+                fmt_header(buf, &full.header);
+                full.defs.format(buf, 0);
                 buf.fmt_end_of_file();
-                InputOwned::Full(buf.as_str().to_string())
             }
             Output::Pattern(patt) => {
-                patt.format(&mut buf, 0);
-                InputOwned::Pattern(buf.as_str().to_string())
+                patt.format(buf, 0);
             }
         }
     }
@@ -432,6 +467,41 @@ impl<'a> Input<'a> {
         check_idempotency: bool,
         canonicalize_mode: Option<bool>,
         flags: MigrationFlags,
+    ) {
+        self.check_invariants_with_options(
+            handle_formatted_output,
+            check_idempotency,
+            canonicalize_mode,
+            flags,
+            None,
+        );
+    }
+
+    /// Like `check_invariants`, but formatting with a max width, and always checking idempotency.
+    pub fn check_invariants_with_max_width(
+        &self,
+        handle_formatted_output: impl Fn(Input),
+        max_width: u16,
+    ) {
+        self.check_invariants_with_options(
+            handle_formatted_output,
+            true,
+            None,
+            MigrationFlags {
+                snakify: false,
+                parens_and_commas: false,
+            },
+            Some(max_width),
+        );
+    }
+
+    fn check_invariants_with_options(
+        &self,
+        handle_formatted_output: impl Fn(Input),
+        check_idempotency: bool,
+        canonicalize_mode: Option<bool>,
+        flags: MigrationFlags,
+        max_width: Option<u16>,
     ) {
         let arena = Bump::new();
 
@@ -439,7 +509,7 @@ impl<'a> Input<'a> {
             panic!("Unexpected parse failure when parsing this for formatting:\n\n{}\n\nParse error was:\n\n{:#?}\n\n", self.as_str(), err);
         });
 
-        let output = actual.format(flags);
+        let output = actual.format_with_max_width(flags, max_width);
 
         handle_formatted_output(output.as_ref());
 
@@ -487,7 +557,7 @@ impl<'a> Input<'a> {
 
         // Now verify that the resultant formatting is _idempotent_ - i.e. that it doesn't change again if re-formatted
         if check_idempotency {
-            let reformatted = reparsed_ast.format(flags);
+            let reformatted = reparsed_ast.format_with_max_width(flags, max_width);
 
             if output != reformatted {
                 pretty_compare_string(
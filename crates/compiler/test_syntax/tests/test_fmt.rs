@@ -38,6 +38,11 @@ mod test_fmt {
         )
     }
 
+    fn expr_formats_to_with_max_width(input: &str, expected: &str, max_width: u16) {
+        Input::Expr(input.trim())
+            .check_invariants_with_max_width(check_formatting(expected.trim()), max_width)
+    }
+
     fn expr_formats_same(input: &str) {
         Input::Expr(input.trim()).check_invariants(
             check_formatting(input.trim()),
@@ -5658,6 +5663,163 @@ mod test_fmt {
         );
     }
 
+    // MAX WIDTH
+
+    #[test]
+    fn max_width_keeps_short_lines() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r"
+                x = [1, 2, 3]
+                f x { a: 1 }
+                "
+            ),
+            indoc!(
+                r"
+                x = [1, 2, 3]
+                f x { a: 1 }
+                "
+            ),
+            20,
+        );
+    }
+
+    #[test]
+    fn max_width_wraps_long_list() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r"
+                numbers = [first_number, second_number, third_number]
+                numbers
+                "
+            ),
+            indoc!(
+                r"
+                numbers = [
+                    first_number,
+                    second_number,
+                    third_number,
+                ]
+                numbers
+                "
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_wraps_long_record() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r#"
+                user = { name: "Sam", email: "sam@example.com", age: 42 }
+                user
+                "#
+            ),
+            indoc!(
+                r#"
+                user = {
+                    name: "Sam",
+                    email: "sam@example.com",
+                    age: 42,
+                }
+                user
+                "#
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_wraps_long_apply() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r"
+                result = combine first_argument second_argument third_argument
+                result
+                "
+            ),
+            indoc!(
+                r"
+                result = combine
+                    first_argument
+                    second_argument
+                    third_argument
+                result
+                "
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_wraps_long_pnc_apply() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r"
+                result = combine(first_argument, second_argument, third_argument)
+                result
+                "
+            ),
+            indoc!(
+                r"
+                result = combine(
+                    first_argument,
+                    second_argument,
+                    third_argument,
+                )
+                result
+                "
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_wraps_long_pipeline() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r"
+                result = numbers |> List.map(double) |> List.keep_if(is_even) |> List.sum
+                result
+                "
+            ),
+            indoc!(
+                r"
+                result =
+                    numbers
+                    |> List.map(double)
+                    |> List.keep_if(is_even)
+                    |> List.sum
+                result
+                "
+            ),
+            40,
+        );
+    }
+
+    #[test]
+    fn max_width_wraps_outer_before_inner() {
+        expr_formats_to_with_max_width(
+            indoc!(
+                r"
+                x = [[first_number, second_number], [third_number, fourth_number]]
+                x
+                "
+            ),
+            indoc!(
+                r"
+                x = [
+                    [first_number, second_number],
+                    [third_number, fourth_number],
+                ]
+                x
+                "
+            ),
+            40,
+        );
+    }
+
     // MODULES
 
     #[test]
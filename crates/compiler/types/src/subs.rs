@@ -1554,6 +1554,10 @@ impl Subs {
     pub const AB_EQ: SubsSlice<Symbol>              = SubsSlice::new(4, 1);
     #[rustfmt::skip]
     pub const AB_INSPECT: SubsSlice<Symbol>         = SubsSlice::new(5, 1);
+    #[rustfmt::skip]
+    pub const AB_SORT: SubsSlice<Symbol>            = SubsSlice::new(6, 1);
+    #[rustfmt::skip]
+    pub const AB_DEFAULT: SubsSlice<Symbol>         = SubsSlice::new(7, 1);
     // END INIT-SymbolSubsSlice
 
     pub fn new() -> Self {
@@ -1583,6 +1587,8 @@ impl Subs {
         symbol_names.push(Symbol::HASH_HASH_ABILITY);
         symbol_names.push(Symbol::BOOL_EQ);
         symbol_names.push(Symbol::INSPECT_INSPECT_ABILITY);
+        symbol_names.push(Symbol::SORT_SORT_ABILITY);
+        symbol_names.push(Symbol::DEFAULT_DEFAULT_ABILITY);
         // END INIT-SymbolSubsSlice
 
         // IFTTT INIT-VariableSubsSlice
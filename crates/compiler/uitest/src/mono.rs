@@ -49,6 +49,7 @@ pub(crate) fn write_compiled_ir<'a>(
         render: roc_reporting::report::RenderTarget::Generic,
         palette: roc_reporting::report::DEFAULT_PALETTE,
         exec_mode,
+        opt_level: None,
     };
     let loaded = roc_load::load_and_monomorphize_from_str(
         arena,
@@ -32,6 +32,7 @@ pub fn copy_zig_glue() {
         workspace_dir.join("crates/cli/tests/test-projects/test-platform-effects-zig/glue"),
         workspace_dir.join("crates/cli/tests/test-projects/test-platform-simple-zig/glue"),
         workspace_dir.join("crates/cli/tests/test-projects/multiple_exposed/platform/glue"),
+        workspace_dir.join("crates/cli/tests/test-projects/sanitizers/platform/glue"),
         workspace_dir.join("crates/cli/tests/test-projects/tui/platform/glue"),
         workspace_dir.join("crates/cli/tests/platform-switching/zig-platform/glue"),
     ];
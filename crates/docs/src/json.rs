@@ -0,0 +1,140 @@
+//! The data behind the generated docs, as JSON. This is what `roc docs --format json` writes,
+//! for tools like documentation portals and doc coverage checks.
+use crate::type_annotation_to_html;
+use roc_load::docs::{DocEntry, ModuleDocumentation, TypeAnnotation};
+use roc_load::LoadedModule;
+use roc_module::symbol::ModuleId;
+use roc_region::all::{LineInfo, Region};
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(Serialize)]
+pub struct PackageDocs {
+    pub package_name: String,
+    pub modules: Vec<ModuleDocs>,
+}
+
+#[derive(Serialize)]
+pub struct ModuleDocs {
+    pub name: String,
+    pub path: Option<PathBuf>,
+    /// The doc comment at the top of the module
+    pub docs: Option<String>,
+    /// Every exposed symbol, whether or not it has a doc comment
+    pub entries: Vec<EntryDocs>,
+}
+
+#[derive(Serialize)]
+pub struct EntryDocs {
+    pub name: String,
+    pub kind: EntryKind,
+    pub type_vars: Vec<String>,
+    /// The type annotation as it's written in the source, or `null` if the def has none
+    pub type_annotation: Option<String>,
+    pub docs: Option<String>,
+    pub location: Option<SourceLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryKind {
+    Value,
+    Type,
+    Ability,
+}
+
+/// 1-based lines and columns, with an exclusive end
+#[derive(Serialize)]
+pub struct SourceLocation {
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+}
+
+pub fn package_docs(
+    package_name: &str,
+    exposed_module_docs: &[(ModuleId, ModuleDocumentation)],
+    loaded_module: &LoadedModule,
+) -> PackageDocs {
+    let modules = exposed_module_docs
+        .iter()
+        .map(|(module_id, module)| {
+            let source = loaded_module.sources.get(module_id);
+            let line_info = source.map(|(_, src)| LineInfo::new(src));
+
+            let docs = module.entries.iter().find_map(|entry| match entry {
+                DocEntry::ModuleDoc(docs) => Some(docs.clone()),
+                _ => None,
+            });
+
+            let entries = module
+                .entries
+                .iter()
+                .filter_map(|entry| match entry {
+                    DocEntry::DocDef(doc_def)
+                        if module.exposed_symbols.contains(&doc_def.symbol) =>
+                    {
+                        Some(doc_def)
+                    }
+                    _ => None,
+                })
+                .map(|doc_def| {
+                    let type_annotation = match &doc_def.type_annotation {
+                        TypeAnnotation::NoTypeAnn => None,
+                        type_ann => {
+                            let mut buf = String::new();
+                            type_annotation_to_html(0, &mut buf, type_ann, false);
+
+                            Some(buf)
+                        }
+                    };
+
+                    EntryDocs {
+                        name: doc_def.name.clone(),
+                        kind: entry_kind(&doc_def.name, &doc_def.type_annotation),
+                        type_vars: doc_def.type_vars.clone(),
+                        type_annotation,
+                        docs: doc_def.docs.clone(),
+                        location: line_info
+                            .as_ref()
+                            .map(|line_info| source_location(line_info, doc_def.region)),
+                    }
+                })
+                .collect();
+
+            ModuleDocs {
+                name: module.name.clone(),
+                path: source.map(|(path, _)| path.clone()),
+                docs,
+                entries,
+            }
+        })
+        .collect();
+
+    PackageDocs {
+        package_name: package_name.to_string(),
+        modules,
+    }
+}
+
+fn entry_kind(name: &str, type_ann: &TypeAnnotation) -> EntryKind {
+    if let TypeAnnotation::Ability { .. } = type_ann {
+        EntryKind::Ability
+    } else if name.starts_with(char::is_uppercase) {
+        EntryKind::Type
+    } else {
+        EntryKind::Value
+    }
+}
+
+fn source_location(line_info: &LineInfo, region: Region) -> SourceLocation {
+    let region = line_info.convert_region(region);
+
+    SourceLocation {
+        start_line: region.start.line + 1,
+        start_column: region.start.column + 1,
+        end_line: region.end.line + 1,
+        end_column: region.end.column + 1,
+    }
+}
@@ -20,6 +20,8 @@ use roc_region::all::Region;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+mod json;
+
 const LINK_SVG: &str = include_str!("./static/link.svg");
 
 pub fn generate_docs_html(root_file: PathBuf, build_dir: &Path, maybe_root_dir: Option<String>) {
@@ -206,6 +208,53 @@ pub fn generate_docs_html(root_file: PathBuf, build_dir: &Path, maybe_root_dir:
     println!("🎉 Docs generated in {}", build_dir.display());
 }
 
+/// Writes the same docs `generate_docs_html` renders to `<build_dir>/docs.json`, for tools that
+/// want the data rather than the pages.
+pub fn generate_docs_json(root_file: PathBuf, build_dir: &Path) {
+    let contents = docs_json(root_file);
+
+    fs::create_dir_all(build_dir).expect("TODO gracefully handle being unable to create build dir");
+
+    let path = build_dir.join("docs.json");
+
+    fs::write(&path, contents).unwrap_or_else(|error| {
+        panic!(
+            "Attempted to write {} but failed with this error: {}",
+            path.display(),
+            error
+        )
+    });
+
+    println!("🎉 Docs generated in {}", path.display());
+}
+
+/// The contents of the `docs.json` that `generate_docs_json` writes
+pub fn docs_json(root_file: PathBuf) -> String {
+    let package_name = package_name(&root_file);
+    let mut loaded_module = load_module_for_docs(root_file);
+    let exposed_module_docs = get_exposed_module_docs(&mut loaded_module);
+
+    let package_docs = json::package_docs(&package_name, &exposed_module_docs, &loaded_module);
+
+    serde_json::to_string_pretty(&package_docs).expect("docs should always serialize to JSON")
+}
+
+/// Packages don't declare a name in their header, so they're named after the directory their
+/// main .roc file is in, like `json` for `json/main.roc`. Packages and platforms often keep
+/// their main file in a `package/` or `platform/` directory, like `roc-json/package/main.roc`,
+/// so the directory above that names them instead.
+fn package_name(root_file: &Path) -> String {
+    let root_file = fs::canonicalize(root_file).unwrap_or_else(|_| root_file.to_path_buf());
+
+    root_file
+        .ancestors()
+        .skip(1)
+        .filter_map(Path::file_name)
+        .map(|name| name.to_string_lossy())
+        .find(|name| name != "package" && name != "platform")
+        .map_or_else(|| "Documentation".to_string(), |name| name.into_owned())
+}
+
 /// Gives only the module docs for modules that are exposed by the platform or package.
 fn get_exposed_module_docs(
     loaded_module: &mut LoadedModule,
@@ -681,6 +730,7 @@ pub fn load_module_for_docs(filename: PathBuf) -> LoadedModule {
         palette: roc_reporting::report::DEFAULT_PALETTE,
         threading: Threading::AllAvailable,
         exec_mode: ExecutionMode::Check,
+        opt_level: None,
     };
     match roc_load::load_and_typecheck(
         &arena,
@@ -1355,15 +1405,12 @@ fn markdown_to_html(
             Event::End(CodeBlock(_)) => {
                 match in_code_block {
                     Some(code_str) => {
-                        if code_str.contains("unchecked") {
-                            // TODO HANDLE UNCHECKED
-                        }
-
+                        // `roc test` checks the Roc code blocks, except `unchecked` and `repl`
+                        // ones (see roc_load::docs::doc_examples), so they're rendered the same.
                         if code_str.contains("repl") {
                             // TODO HANDLE REPL
                         }
 
-                        // TODO HANDLE CHECKING BY DEFAULT
                         let highlighted_html = roc_highlight::highlight_roc_code(&to_highlight);
                         docs_parser.push(Event::Html(CowStr::from(highlighted_html)));
                     }
@@ -1454,6 +1501,7 @@ fn report_markdown_link_problem(
             filename,
             doc,
             title: "INVALID DOCS LINK".to_string(),
+            code: Some("E0426"),
             severity: Severity::Warning,
         }
     };
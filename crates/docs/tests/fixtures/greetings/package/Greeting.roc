@@ -0,0 +1,19 @@
+## Ways to greet people.
+module [Greeting, hello, shout]
+
+## How formal a greeting is
+Greeting : [Hello, Hi]
+
+## Greets someone by name.
+##
+## ```roc
+## expect hello("Ada") == "Hello, Ada!"
+## ```
+hello : Str -> Str
+hello = |name| "Hello, ${name}!"
+
+shout = |greeting| Str.concat(greeting, "!")
+
+private = "not exposed"
+
+expect private != ""
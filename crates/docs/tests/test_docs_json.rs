@@ -0,0 +1,25 @@
+use std::path::{Path, PathBuf};
+
+fn fixture_dir(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(name)
+}
+
+#[test]
+fn package_docs_json() {
+    let package_dir = fixture_dir("greetings").join("package");
+    let json = roc_docs::docs_json(package_dir.join("main.roc"));
+
+    // The modules' paths are absolute, so make them relative to the package to compare them.
+    let mut docs: serde_json::Value = serde_json::from_str(&json).unwrap();
+    for module in docs["modules"].as_array_mut().unwrap() {
+        let path = PathBuf::from(module["path"].as_str().unwrap());
+        let path = path.strip_prefix(&package_dir).unwrap_or(&path);
+
+        module["path"] = path.to_string_lossy().replace('\\', "/").into();
+    }
+
+    insta::assert_snapshot!(serde_json::to_string_pretty(&docs).unwrap());
+}
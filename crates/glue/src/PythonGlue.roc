@@ -0,0 +1,634 @@
+app [make_glue] { pf: platform "../platform/main.roc" }
+
+import pf.Types exposing [Types]
+import pf.Shape exposing [Shape]
+import pf.File exposing [File]
+import pf.TypeId exposing [TypeId, type_id_to_u64]
+import "../static/roc_std.py" as roc_std_py : Str
+
+make_glue : List Types -> Result (List File) Str
+make_glue = \types_by_arch ->
+    # Python hosts can't run on wasm32, so there's no point in generating bindings for it.
+    native_types =
+        List.drop_if(types_by_arch, \types -> (Types.target(types)).architecture == Wasm32)
+
+    init_file_content =
+        List.walk(native_types, file_header, \content, types ->
+            arch = (Types.target(types)).architecture
+            arch_str = arch_name(arch)
+            machines =
+                machine_names(arch)
+                |> List.map(\machine -> "\"${machine}\"")
+                |> Str.join_with(", ")
+
+            Str.concat(
+                content,
+                """
+                if _machine in (${machines},):
+                    from .${arch_str} import *
+
+                """,
+            ))
+
+    List.walk_try(native_types, {}, \{}, types -> reject_recursive_unions(types))
+    |> Result.map_ok(\{} ->
+        native_types
+        |> List.map(convert_types_to_file)
+        |> List.append({ name: "roc_app/__init__.py", content: Str.concat(init_file_content, init_file_footer) })
+        |> List.concat(static_files))
+
+## Recursive tag unions are stored behind (possibly tagged) pointers, which
+## ctypes can't describe, so we refuse them instead of generating bindings
+## that hosts would have to decode by hand.
+reject_recursive_unions : Types -> Result {} Str
+reject_recursive_unions = \types ->
+    Types.walk_shapes(types, Ok({}), \state, shape, _id ->
+        when shape is
+            TagUnion(Recursive({ name }))
+            | TagUnion(NullableWrapped({ name }))
+            | TagUnion(NullableUnwrapped({ name }))
+            | TagUnion(NonNullableUnwrapped({ name })) ->
+                Result.try(state, \{} -> Err("PythonGlue does not support recursive tag unions like `${name}` yet."))
+
+            _ ->
+                state)
+
+## These are always included, and don't depend on the specifics of the app.
+static_files : List File
+static_files = [
+    { name: "roc_app/roc_std.py", content: roc_std_py },
+]
+
+convert_types_to_file : Types -> File
+convert_types_to_file = \types ->
+    # ctypes needs a struct's field types to be complete before the struct itself
+    # can be declared, so we emit declarations in dependency order.
+    content =
+        declaration_order(types)
+        |> List.walk(arch_file_header, \buf, id -> generate_declaration(buf, types, id))
+        |> generate_entry_points(types)
+
+    arch = (Types.target(types)).architecture
+    arch_str = arch_name(arch)
+
+    {
+        name: "roc_app/${arch_str}.py",
+        content,
+    }
+
+generate_declaration : Str, Types, TypeId -> Str
+generate_declaration = \buf, types, id ->
+    when Types.shape(types, id) is
+        Struct({ name, fields }) ->
+            generate_struct(buf, types, name, struct_field_list(fields))
+
+        TagUnionPayload({ name, fields }) ->
+            # Tag union payloads have numbered fields, so we prefix them
+            # with an "f" because Python doesn't allow attributes to be numbers.
+            renamed_fields =
+                List.map(struct_field_list(fields), \{ name: field_name, id: field_id } -> { name: "f${field_name}", id: field_id })
+
+            generate_struct(buf, types, name, renamed_fields)
+
+        TagUnion(Enumeration({ name, tags })) ->
+            generate_enumeration(buf, name, tags)
+
+        TagUnion(NonRecursive({ name, tags })) ->
+            if List.is_empty(tags) then
+                buf
+            else
+                generate_non_recursive_tag_union(buf, types, name, tags)
+
+        TagUnion(SingleTagStruct({ name, tag_name, payload })) ->
+            fields =
+                when payload is
+                    HasNoClosure(payload_fields) ->
+                        List.map_with_index(payload_fields, \{ id: field_id }, index ->
+                            index_str = Num.to_str(index)
+
+                            { name: "f${index_str}", id: field_id })
+
+                    HasClosure(payload_fields) ->
+                        List.map(payload_fields, \{ name: field_name, id: field_id } -> { name: field_name, id: field_id })
+
+            buf
+            |> generate_struct(types, name, fields)
+            |> Str.concat("# `${name}` is the Roc tag union `[${tag_name} ...]`.\n\n")
+
+        TagUnion(Recursive(_))
+        | TagUnion(NullableWrapped(_))
+        | TagUnion(NullableUnwrapped(_))
+        | TagUnion(NonNullableUnwrapped(_)) ->
+            # make_glue has already rejected these, in reject_recursive_unions
+            crash("PythonGlue does not support recursive tag unions yet.")
+
+        Function(_) | RecursivePointer(_) ->
+            # Functions are called through their entry points, and recursive pointers
+            # point to a type that has already been declared.
+            buf
+
+        Unit
+        | Unsized
+        | EmptyTagUnion
+        | Num(_)
+        | Bool
+        | RocResult(_, _)
+        | RocStr
+        | RocDict(_, _)
+        | RocSet(_)
+        | RocList(_)
+        | RocBox(_) ->
+            # These types are provided by roc_std.py
+            buf
+
+struct_field_list = \struct_fields ->
+    when struct_fields is
+        HasNoClosure(fields) -> List.map(fields, \{ name, id } -> { name, id })
+        HasClosure(fields) -> List.map(fields, \{ name, id } -> { name, id })
+
+generate_struct : Str, Types, Str, List { name : Str, id : TypeId } -> Str
+generate_struct = \buf, types, name, fields ->
+    escaped_name = escape_kw(name)
+
+    refcounted_fields =
+        fields
+        |> List.keep_if(\{ id } -> contains_refcounted(types, Types.shape(types, id)))
+        |> List.map(\{ name: field_name } -> escape_kw(field_name))
+
+    field_decls =
+        fields
+        |> List.map(\{ name: field_name, id } ->
+            escaped_field_name = escape_kw(field_name)
+            type_str = type_name(types, id)
+
+            "${indent}${indent}(\"${escaped_field_name}\", ${type_str}),\n")
+        |> Str.join_with("")
+
+    buf
+    |> Str.concat("class ${escaped_name}(ctypes.Structure):\n")
+    |> Str.concat("${indent}_fields_ = [\n${field_decls}${indent}]\n\n")
+    |> generate_refcount_methods(refcounted_fields, "self.")
+    |> Str.concat("\n")
+
+generate_refcount_methods : Str, List Str, Str -> Str
+generate_refcount_methods = \buf, field_names, prefix ->
+    if List.is_empty(field_names) then
+        buf
+    else
+        incs = List.map(field_names, \field_name -> "${indent}${indent}${prefix}${field_name}.inc()\n") |> Str.join_with("")
+        decs = List.map(field_names, \field_name -> "${indent}${indent}${prefix}${field_name}.dec()\n") |> Str.join_with("")
+
+        buf
+        |> Str.concat("${indent}def inc(self):\n${incs}\n")
+        |> Str.concat("${indent}def dec(self):\n${decs}\n")
+
+generate_enumeration : Str, Str, List Str -> Str
+generate_enumeration = \buf, name, tags ->
+    escaped_name = escape_kw(name)
+
+    variants =
+        List.map_with_index(tags, generate_enum_variant)
+        |> Str.join_with("")
+
+    Str.concat(buf, "class ${escaped_name}(enum.IntEnum):\n${variants}\n\n")
+
+generate_enum_variant : Str, U64 -> Str
+generate_enum_variant = \tag_name, index ->
+    escaped_tag_name = escape_kw(tag_name)
+    index_str = Num.to_str(index)
+
+    "${indent}${escaped_tag_name} = ${index_str}\n"
+
+generate_non_recursive_tag_union : Str, Types, Str, List { name : Str, payload : [Some TypeId, None] } -> Str
+generate_non_recursive_tag_union = \buf, types, name, tags ->
+    escaped_name = escape_kw(name)
+    discriminant_name = "${escaped_name}_Discriminant"
+    payload_name = "${escaped_name}_Payload"
+
+    payload_tags =
+        List.keep_oks(tags, \{ name: tag_name, payload } ->
+            when payload is
+                Some(id) -> Ok({ name: tag_name, id })
+                None -> Err(NoPayload))
+
+    payload_fields =
+        payload_tags
+        |> List.map(\{ name: tag_name, id } ->
+            type_str = type_name(types, id)
+
+            "${indent}${indent}(\"${tag_name}\", ${type_str}),\n")
+        |> Str.join_with("")
+
+    discriminant_variants =
+        List.map_with_index(tags, \{ name: tag_name }, index -> generate_enum_variant(tag_name, index))
+        |> Str.join_with("")
+
+    constructors =
+        tags
+        |> List.map_with_index(\{ name: tag_name, payload }, index ->
+            index_str = Num.to_str(index)
+            escaped_tag_name = escape_kw(tag_name)
+
+            when payload is
+                Some(_) ->
+                    """
+                        @classmethod
+                        def ${escaped_tag_name}(cls, payload):
+                            answer = cls()
+                            answer.payload.${tag_name} = payload
+                            answer.discriminant = ${index_str}
+
+                            return answer
+
+
+                    """
+
+                None ->
+                    """
+                        @classmethod
+                        def ${escaped_tag_name}(cls):
+                            answer = cls()
+                            answer.discriminant = ${index_str}
+
+                            return answer
+
+
+                    """)
+        |> Str.join_with("")
+
+    refcounted_payload_tags =
+        payload_tags
+        |> List.keep_if(\{ id } -> contains_refcounted(types, Types.shape(types, id)))
+        |> List.map(.name)
+
+    refcount_methods =
+        if List.is_empty(refcounted_payload_tags) then
+            ""
+        else
+            incs = generate_payload_refcount_calls(refcounted_payload_tags, discriminant_name, "inc")
+            decs = generate_payload_refcount_calls(refcounted_payload_tags, discriminant_name, "dec")
+
+            "${indent}def inc(self):\n${incs}\n${indent}def dec(self):\n${decs}\n"
+
+    discriminant_type = type_name_for_discriminant(List.len(tags))
+
+    """
+    ${buf}class ${discriminant_name}(enum.IntEnum):
+    ${discriminant_variants}
+
+    class ${payload_name}(ctypes.Union):
+        _fields_ = [
+    ${payload_fields}    ]
+
+
+    class ${escaped_name}(ctypes.Structure):
+        _fields_ = [
+            ("payload", ${payload_name}),
+            ("discriminant", ${discriminant_type}),
+        ]
+
+        # Returns which variant this tag union holds. Note that this never includes a payload!
+        def tag(self):
+            return ${discriminant_name}(self.discriminant)
+
+    ${constructors}${refcount_methods}
+
+    """
+
+generate_payload_refcount_calls : List Str, Str, Str -> Str
+generate_payload_refcount_calls = \tag_names, discriminant_name, method ->
+    tag_names
+    |> List.map(\tag_name ->
+        escaped_tag_name = escape_kw(tag_name)
+
+        "${indent}${indent}if self.discriminant == ${discriminant_name}.${escaped_tag_name}:\n${indent}${indent}${indent}self.payload.${tag_name}.${method}()\n")
+    |> Str.join_with("")
+
+type_name_for_discriminant : U64 -> Str
+type_name_for_discriminant = \tag_count ->
+    if tag_count <= 256 then
+        "ctypes.c_uint8"
+    else
+        "ctypes.c_uint16"
+
+generate_entry_points : Str, Types -> Str
+generate_entry_points = \buf, types ->
+    List.walk(Types.entry_points(types), buf, \accum, T(name, id) -> generate_entry_point(accum, types, name, id))
+
+generate_entry_point : Str, Types, Str, TypeId -> Str
+generate_entry_point = \buf, types, name, id ->
+    (args, ret) =
+        when Types.shape(types, id) is
+            Function(roc_fn) -> (roc_fn.args, roc_fn.ret)
+            _ -> ([], id)
+
+    when Types.shape(types, ret) is
+        Function(_) ->
+            # TODO: support entry points that return closures.
+            Str.concat(buf, "# TODO: `${name}` returns a function, which PythonGlue does not support yet.\n\n")
+
+        _ ->
+            params =
+                to_arg_str(args, types, \_arg_id, _shape, index ->
+                    index_str = Num.to_str(index)
+
+                    "arg${index_str}")
+
+            call_args =
+                to_arg_str(args, types, \_arg_id, shape, index ->
+                    index_str = Num.to_str(index)
+
+                    if is_passed_by_value(shape) then
+                        "arg${index_str}"
+                    else
+                        "ctypes.byref(arg${index_str})")
+
+            ret_type = type_name(types, ret)
+            param_comma = if Str.is_empty(params) then "" else ", "
+            call_comma = if Str.is_empty(call_args) then "" else ", "
+
+            """
+            ${buf}# Calls the Roc entry point `${name}`, given the `ctypes.CDLL` of a Roc application.
+            def ${name}(lib${param_comma}${params}):
+                roc_fn = lib.roc__${name}_1_exposed_generic
+                roc_fn.restype = None
+
+                ret = ${ret_type}()
+                roc_fn(ctypes.byref(ret)${call_comma}${call_args})
+
+                return ret
+
+
+            """
+
+## Returns type IDs so that each type comes after every type it contains by value.
+declaration_order : Types -> List TypeId
+declaration_order = \types ->
+    Types.walk_shapes(types, { visited: Set.empty({}), order: [] }, \state, _shape, id -> visit(state, types, id))
+    |> .order
+
+visit : { visited : Set U64, order : List TypeId }, Types, TypeId -> { visited : Set U64, order : List TypeId }
+visit = \state, types, id ->
+    key = type_id_to_u64(id)
+
+    if Set.contains(state.visited, key) then
+        state
+    else
+        marked = { state & visited: Set.insert(state.visited, key) }
+        after_deps = List.walk(direct_dependencies(Types.shape(types, id)), marked, \accum, dep -> visit(accum, types, dep))
+
+        { after_deps & order: List.append(after_deps.order, id) }
+
+direct_dependencies : Shape -> List TypeId
+direct_dependencies = \shape ->
+    when shape is
+        Struct({ fields }) ->
+            struct_field_list(fields) |> List.map(.id)
+
+        TagUnionPayload({ fields }) ->
+            struct_field_list(fields) |> List.map(.id)
+
+        TagUnion(NonRecursive({ tags })) ->
+            List.keep_oks(tags, \{ payload } ->
+                when payload is
+                    Some(id) -> Ok(id)
+                    None -> Err(NoPayload))
+
+        TagUnion(SingleTagStruct({ payload: HasNoClosure(fields) })) ->
+            List.map(fields, .id)
+
+        TagUnion(SingleTagStruct({ payload: HasClosure(fields) })) ->
+            List.map(fields, .id)
+
+        RocList(elem) -> [elem]
+        RocBox(elem) -> [elem]
+        RocSet(elem) -> [elem]
+        RocDict(key, value) -> [key, value]
+        RocResult(ok, err) -> [ok, err]
+        _ ->
+            # Nothing else contains types that need to be declared first.
+            []
+
+type_name : Types, TypeId -> Str
+type_name = \types, id ->
+    when Types.shape(types, id) is
+        Unit -> "RocUnit"
+        Unsized -> "RocList(ctypes.c_uint8)"
+        EmptyTagUnion -> "RocUnit"
+        RocStr -> "RocStr"
+        Bool -> "ctypes.c_bool"
+        Num(U8) -> "ctypes.c_uint8"
+        Num(U16) -> "ctypes.c_uint16"
+        Num(U32) -> "ctypes.c_uint32"
+        Num(U64) -> "ctypes.c_uint64"
+        Num(U128) -> "RocU128"
+        Num(I8) -> "ctypes.c_int8"
+        Num(I16) -> "ctypes.c_int16"
+        Num(I32) -> "ctypes.c_int32"
+        Num(I64) -> "ctypes.c_int64"
+        Num(I128) -> "RocI128"
+        Num(F32) -> "ctypes.c_float"
+        Num(F64) -> "ctypes.c_double"
+        Num(Dec) -> "RocDec"
+        RocDict(_key, _value) ->
+            crash("RocDict is not yet supported in python")
+
+        RocSet(_elem) ->
+            crash("RocSet is not yet supported in python")
+
+        RocList(elem) ->
+            elem_name = type_name(types, elem)
+
+            "RocList(${elem_name})"
+
+        RocBox(elem) ->
+            elem_name = type_name(types, elem)
+
+            "RocBox(${elem_name})"
+
+        RocResult(ok, err) ->
+            ok_name = type_name(types, ok)
+            err_name = type_name(types, err)
+
+            "RocResult(${ok_name}, ${err_name})"
+
+        RecursivePointer(content) ->
+            type_name(types, content)
+
+        TagUnion(Enumeration({ tags })) ->
+            # Enumerations are stored as plain integers; the generated `enum.IntEnum`
+            # can be used to give them names.
+            type_name_for_discriminant(List.len(tags))
+
+        Struct({ name }) -> escape_kw(name)
+        TagUnionPayload({ name }) -> escape_kw(name)
+        TagUnion(NonRecursive({ name })) -> escape_kw(name)
+        TagUnion(Recursive({ name })) -> escape_kw(name)
+        TagUnion(NullableWrapped({ name })) -> escape_kw(name)
+        TagUnion(NullableUnwrapped({ name })) -> escape_kw(name)
+        TagUnion(NonNullableUnwrapped({ name })) -> escape_kw(name)
+        TagUnion(SingleTagStruct({ name })) -> escape_kw(name)
+        Function(_) -> "ctypes.c_void_p"
+
+## Whether a value of this shape needs its refcount managed by the host.
+contains_refcounted : Types, Shape -> Bool
+contains_refcounted = \types, shape ->
+    when shape is
+        RocStr | RocList(_) | RocBox(_) | RocResult(_, _) -> Bool.true
+        Struct({ fields }) ->
+            struct_field_list(fields)
+            |> List.any(\{ id } -> contains_refcounted(types, Types.shape(types, id)))
+
+        TagUnionPayload({ fields }) ->
+            struct_field_list(fields)
+            |> List.any(\{ id } -> contains_refcounted(types, Types.shape(types, id)))
+
+        TagUnion(NonRecursive({ tags })) ->
+            List.any(tags, \{ payload } ->
+                when payload is
+                    Some(id) -> contains_refcounted(types, Types.shape(types, id))
+                    None -> Bool.false)
+
+        TagUnion(SingleTagStruct({ payload: HasNoClosure(fields) })) ->
+            List.any(fields, \{ id } -> contains_refcounted(types, Types.shape(types, id)))
+
+        TagUnion(SingleTagStruct({ payload: HasClosure(fields) })) ->
+            List.any(fields, \{ id } -> contains_refcounted(types, Types.shape(types, id)))
+
+        RecursivePointer(content) -> contains_refcounted(types, Types.shape(types, content))
+        _ -> Bool.false
+
+## Numbers, booleans and enumerations are passed to Roc by value; everything else by reference.
+is_passed_by_value : Shape -> Bool
+is_passed_by_value = \shape ->
+    when shape is
+        Bool | TagUnion(Enumeration(_)) -> Bool.true
+        Num(I128) | Num(U128) | Num(Dec) -> Bool.false
+        Num(_) -> Bool.true
+        _ -> Bool.false
+
+arch_name = \arch ->
+    when arch is
+        Aarch32 ->
+            "arm"
+
+        Aarch64 ->
+            "aarch64"
+
+        Wasm32 ->
+            "wasm32"
+
+        X86x32 ->
+            "x86"
+
+        X86x64 ->
+            "x86_64"
+
+## The values `platform.machine()` returns on each architecture.
+machine_names = \arch ->
+    when arch is
+        Aarch32 -> ["arm", "armv7l", "armv7"]
+        Aarch64 -> ["aarch64", "arm64"]
+        Wasm32 -> ["wasm32"]
+        X86x32 -> ["x86", "i386", "i686"]
+        X86x64 -> ["x86_64", "amd64"]
+
+file_header =
+    """
+    # ⚠️ GENERATED CODE ⚠️ - this entire file was generated by the `roc glue` CLI command
+
+    import platform
+
+    _machine = platform.machine().lower()
+
+
+    """
+
+init_file_footer =
+    """
+    del platform, _machine
+
+    """
+
+arch_file_header =
+    """
+    # ⚠️ GENERATED CODE ⚠️ - this entire file was generated by the `roc glue` CLI command
+
+    import ctypes
+    import enum
+
+    from .roc_std import *
+
+
+    """
+
+indent = "    "
+
+reserved_keywords = Set.from_list([
+    "False",
+    "None",
+    "True",
+    "and",
+    "as",
+    "assert",
+    "async",
+    "await",
+    "break",
+    "class",
+    "continue",
+    "def",
+    "del",
+    "elif",
+    "else",
+    "except",
+    "finally",
+    "for",
+    "from",
+    "global",
+    "if",
+    "import",
+    "in",
+    "is",
+    "lambda",
+    "nonlocal",
+    "not",
+    "or",
+    "pass",
+    "raise",
+    "return",
+    "try",
+    "while",
+    "with",
+    "yield",
+])
+
+escape_kw = \input ->
+    # Python has no raw identifiers, so we append an underscore (PEP 8's convention).
+    if Set.contains(reserved_keywords, input) then
+        "${input}_"
+    else
+        input
+
+is_unit : Shape -> Bool
+is_unit = \shape ->
+    when shape is
+        Unit -> Bool.true
+        _ -> Bool.false
+
+to_arg_str : List TypeId, Types, (TypeId, Shape, U64 -> Str) -> Str
+to_arg_str = \args, types, fmt ->
+    List.walk_with_index(args, "", \state, arg_id, index ->
+        shape = Types.shape(types, arg_id)
+
+        # Drop `{}` args; nothing will get passed anyway.
+        if is_unit(shape) then
+            state
+        else
+            arg_str = fmt(arg_id, shape, index)
+
+            if Str.is_empty(state) then
+                arg_str # Don't prepend a comma if this is the first one
+            else
+                state
+                |> Str.concat(", ")
+                |> Str.concat(arg_str))
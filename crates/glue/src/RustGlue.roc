@@ -8,7 +8,9 @@ import "../static/Cargo.toml" as roc_app_cargo_toml : Str
 import "../../roc_std/Cargo.toml" as roc_std_cargo_toml : Str
 import "../../roc_std/src/lib.rs" as roc_std_lib : Str
 import "../../roc_std/src/roc_box.rs" as roc_std_box : Str
+import "../../roc_std/src/roc_dict.rs" as roc_std_dict : Str
 import "../../roc_std/src/roc_list.rs" as roc_std_list : Str
+import "../../roc_std/src/roc_set.rs" as roc_std_set : Str
 import "../../roc_std/src/roc_str.rs" as roc_std_str : Str
 import "../../roc_std/src/storage.rs" as roc_std_storage : Str
 
@@ -43,7 +45,9 @@ static_files = [
     { name: "roc_std/Cargo.toml", content: roc_std_cargo_toml },
     { name: "roc_std/src/lib.rs", content: roc_std_lib },
     { name: "roc_std/src/roc_box.rs", content: roc_std_box },
+    { name: "roc_std/src/roc_dict.rs", content: roc_std_dict },
     { name: "roc_std/src/roc_list.rs", content: roc_std_list },
+    { name: "roc_std/src/roc_set.rs", content: roc_std_set },
     { name: "roc_std/src/roc_str.rs", content: roc_std_str },
     { name: "roc_std/src/storage.rs", content: roc_std_storage },
 ]
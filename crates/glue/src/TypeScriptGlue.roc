@@ -0,0 +1,712 @@
+app [make_glue] { pf: platform "../platform/main.roc" }
+
+import pf.Types exposing [Types]
+import pf.Shape exposing [Shape]
+import pf.File exposing [File]
+import pf.TypeId exposing [TypeId]
+import "../static/roc_std.ts" as roc_std_ts : Str
+
+make_glue : List Types -> Result (List File) Str
+make_glue = \types_by_arch ->
+    # JS and TypeScript hosts only ever talk to Roc through the wasm32 target,
+    # so that's the only architecture whose layouts matter here.
+    when List.find_first(types_by_arch, \types -> (Types.target(types)).architecture == Wasm32) is
+        Ok(types) ->
+            reject_recursive_unions(types)
+            |> Result.map_ok(\{} -> [
+                { name: "roc_app.ts", content: convert_types_to_content(types) },
+                { name: "roc_std.ts", content: roc_std_ts },
+            ])
+
+        Err(NotFound) ->
+            Err("TypeScriptGlue needs the wasm32 layouts of the platform's types, but `roc glue` did not provide them.")
+
+## Only recursive unions with a single non-null tag can be read and written so far,
+## so we refuse the others instead of handing hosts a raw pointer.
+reject_recursive_unions : Types -> Result {} Str
+reject_recursive_unions = \types ->
+    Types.walk_shapes(types, Ok({}), \state, shape, _id ->
+        when shape is
+            TagUnion(Recursive({ name })) | TagUnion(NullableWrapped({ name })) ->
+                Result.try(state, \{} -> Err("TypeScriptGlue does not support recursive tag unions like `${name}` yet."))
+
+            _ ->
+                state)
+
+convert_types_to_content : Types -> Str
+convert_types_to_content = \types ->
+    Types.walk_shapes(types, file_header, \buf, type, id ->
+        when type is
+            Struct({ name, fields }) ->
+                generate_struct(buf, types, id, name, struct_field_list(fields))
+
+            TagUnionPayload({ name, fields }) ->
+                # Tag union payloads have numbered fields, so we prefix them
+                # with an "f" to make them read like the other glue targets.
+                renamed_fields =
+                    List.map(struct_field_list(fields), \{ name: field_name, id: field_id } -> { name: "f${field_name}", id: field_id })
+
+                generate_struct(buf, types, id, name, renamed_fields)
+
+            TagUnion(Enumeration({ name, tags, size })) ->
+                generate_enumeration(buf, name, tags, size)
+
+            TagUnion(NonRecursive({ name, tags, discriminant_size, discriminant_offset })) ->
+                if List.is_empty(tags) then
+                    buf
+                else
+                    generate_non_recursive_tag_union(buf, types, name, tags, discriminant_size, discriminant_offset)
+
+            TagUnion(SingleTagStruct({ name, payload })) ->
+                fields =
+                    when payload is
+                        HasNoClosure(payload_fields) ->
+                            List.map_with_index(payload_fields, \{ id: field_id }, index ->
+                                index_str = Num.to_str(index)
+
+                                { name: "f${index_str}", id: field_id })
+
+                        HasClosure(payload_fields) ->
+                            List.map(payload_fields, \{ name: field_name, id: field_id } -> { name: field_name, id: field_id })
+
+                generate_struct(buf, types, id, name, fields)
+
+            TagUnion(NullableUnwrapped({ name, null_tag, non_null_tag, non_null_payload })) ->
+                generate_nullable_unwrapped(buf, types, name, null_tag, non_null_tag, non_null_payload)
+
+            TagUnion(NonNullableUnwrapped({ name, tag_name, payload })) ->
+                generate_non_nullable_unwrapped(buf, types, name, tag_name, payload)
+
+            TagUnion(Recursive(_)) | TagUnion(NullableWrapped(_)) ->
+                # make_glue has already rejected these, in reject_recursive_unions
+                crash("TypeScriptGlue does not support recursive tag unions with more than one non-null tag yet.")
+
+            Function(_) | RecursivePointer(_) ->
+                # Functions are called through their entry points, and recursive pointers
+                # point to a type that has already been declared.
+                buf
+
+            Unit
+            | Unsized
+            | EmptyTagUnion
+            | Num(_)
+            | Bool
+            | RocResult(_, _)
+            | RocStr
+            | RocDict(_, _)
+            | RocSet(_)
+            | RocList(_)
+            | RocBox(_) ->
+                # These map to builtin TypeScript types, or to helpers in roc_std.ts
+                buf)
+    |> generate_entry_points(types)
+
+struct_field_list = \struct_fields ->
+    when struct_fields is
+        HasNoClosure(fields) -> List.map(fields, \{ name, id } -> { name, id })
+        HasClosure(fields) -> List.map(fields, \{ name, id } -> { name, id })
+
+## Fields are laid out in the order `roc glue` lists them, like a C struct.
+with_offsets : Types, List { name : Str, id : TypeId } -> List { name : Str, id : TypeId, offset : U32 }
+with_offsets = \types, fields ->
+    List.walk(fields, { end: 0, answer: [] }, \{ end, answer }, { name, id } ->
+        offset = next_multiple_of(end, Types.alignment(types, id))
+
+        { end: offset + Types.size(types, id), answer: List.append(answer, { name, id, offset }) })
+    |> .answer
+
+generate_struct : Str, Types, TypeId, Str, List { name : Str, id : TypeId } -> Str
+generate_struct = \buf, types, id, name, fields ->
+    laid_out = with_offsets(types, fields)
+    size_str = Types.size(types, id) |> Num.to_str
+
+    field_decls =
+        laid_out
+        |> List.map(\{ name: field_name, id: field_id } ->
+            type_str = type_name(types, field_id)
+
+            "${indent}${field_name}: ${type_str};\n")
+        |> Str.join_with("")
+
+    field_reads =
+        laid_out
+        |> List.map(\{ name: field_name, id: field_id, offset } ->
+            field_ptr = offset_ptr("ptr", offset)
+            read = read_expr(types, field_id, field_ptr)
+
+            "${indent}${indent}${field_name}: ${read},\n")
+        |> Str.join_with("")
+
+    field_writes =
+        laid_out
+        |> List.keep_oks(\{ name: field_name, id: field_id, offset } ->
+            field_ptr = offset_ptr("ptr", offset)
+
+            write_stmt(types, field_id, field_ptr, "value.${field_name}")
+            |> Result.map_ok(\write -> "${indent}${write};\n"))
+        |> Str.join_with("")
+
+    """
+    ${buf}export const ${name}_SIZE = ${size_str};
+
+    export interface ${name} {
+    ${field_decls}}
+
+    export function read${name}(mem: RocMemory, ptr: number): ${name} {
+      return {
+    ${field_reads}  };
+    }
+
+    export function write${name}(mem: RocMemory, ptr: number, value: ${name}): void {
+    ${field_writes}}
+
+
+    """
+
+generate_enumeration : Str, Str, List Str, U32 -> Str
+generate_enumeration = \buf, name, tags, size ->
+    variants =
+        List.map_with_index(tags, \tag_name, index ->
+            index_str = Num.to_str(index)
+
+            "${indent}${tag_name}: ${index_str},\n")
+        |> Str.join_with("")
+
+    read = read_discriminant("ptr", size)
+    write = write_discriminant("ptr", size, "value")
+
+    """
+    ${buf}export const ${name} = {
+    ${variants}} as const;
+
+    export type ${name} = (typeof ${name})[keyof typeof ${name}];
+
+    export function read${name}(mem: RocMemory, ptr: number): ${name} {
+      return ${read} as ${name};
+    }
+
+    export function write${name}(mem: RocMemory, ptr: number, value: ${name}): void {
+      ${write};
+    }
+
+
+    """
+
+generate_non_recursive_tag_union : Str, Types, Str, List { name : Str, payload : [Some TypeId, None] }, U32, U32 -> Str
+generate_non_recursive_tag_union = \buf, types, name, tags, discriminant_size, discriminant_offset ->
+    discriminant_ptr = offset_ptr("ptr", discriminant_offset)
+
+    variants =
+        tags
+        |> List.map(\{ name: tag_name, payload } ->
+            when payload is
+                Some(payload_id) ->
+                    payload_type = type_name(types, payload_id)
+
+                    "${indent}| { tag: \"${tag_name}\"; payload: ${payload_type} }"
+
+                None ->
+                    "${indent}| { tag: \"${tag_name}\" }")
+        |> Str.join_with("\n")
+
+    read_cases =
+        tags
+        |> List.map_with_index(\{ name: tag_name, payload }, index ->
+            index_str = Num.to_str(index)
+
+            when payload is
+                Some(payload_id) ->
+                    read = read_expr(types, payload_id, "ptr")
+
+                    "${indent}${indent}case ${index_str}:\n${indent}${indent}${indent}return { tag: \"${tag_name}\", payload: ${read} };\n"
+
+                None ->
+                    "${indent}${indent}case ${index_str}:\n${indent}${indent}${indent}return { tag: \"${tag_name}\" };\n")
+        |> Str.join_with("")
+
+    write_cases =
+        tags
+        |> List.map_with_index(\{ name: tag_name, payload }, index ->
+            index_str = Num.to_str(index)
+            write_tag = write_discriminant(discriminant_ptr, discriminant_size, index_str)
+
+            write_payload =
+                when payload is
+                    Some(payload_id) ->
+                        when write_stmt(types, payload_id, "ptr", "value.payload") is
+                            Ok(write) -> "${indent}${indent}${indent}${write};\n"
+                            Err(NothingToWrite) -> ""
+
+                    None ->
+                        ""
+
+            "${indent}${indent}case \"${tag_name}\":\n${write_payload}${indent}${indent}${indent}${write_tag};\n${indent}${indent}${indent}break;\n")
+        |> Str.join_with("")
+
+    read_tag = read_discriminant(discriminant_ptr, discriminant_size)
+
+    """
+    ${buf}export type ${name} =
+    ${variants};
+
+    export function read${name}(mem: RocMemory, ptr: number): ${name} {
+      switch (${read_tag}) {
+    ${read_cases}    default:
+          throw new Error("Invalid discriminant for ${name}");
+      }
+    }
+
+    export function write${name}(mem: RocMemory, ptr: number, value: ${name}): void {
+      switch (value.tag) {
+    ${write_cases}  }
+    }
+
+
+    """
+
+generate_nullable_unwrapped : Str, Types, Str, Str, Str, TypeId -> Str
+generate_nullable_unwrapped = \buf, types, name, null_tag, non_null_tag, non_null_payload ->
+    payload_type = type_name(types, non_null_payload)
+    read_payload = read_expr(types, non_null_payload, "payloadPtr")
+    write_payload = write_heap_payload(types, non_null_payload, "value.payload")
+
+    """
+    ${buf}export type ${name} =
+      | { tag: "${null_tag}" }
+      | { tag: "${non_null_tag}"; payload: ${payload_type} };
+
+    export function read${name}(mem: RocMemory, ptr: number): ${name} {
+      const payloadPtr = mem.view.getUint32(ptr, true);
+
+      if (payloadPtr === 0) {
+        return { tag: "${null_tag}" };
+      }
+
+      return { tag: "${non_null_tag}", payload: ${read_payload} };
+    }
+
+    export function write${name}(mem: RocMemory, ptr: number, value: ${name}): void {
+      if (value.tag === "${null_tag}") {
+        mem.view.setUint32(ptr, 0, true);
+      } else {
+    ${write_payload}  }
+    }
+
+
+    """
+
+generate_non_nullable_unwrapped : Str, Types, Str, Str, TypeId -> Str
+generate_non_nullable_unwrapped = \buf, types, name, tag_name, payload ->
+    payload_type = type_name(types, payload)
+    read_payload = read_expr(types, payload, "payloadPtr")
+    write_payload = write_heap_payload(types, payload, "value.payload")
+
+    """
+    ${buf}export type ${name} = { tag: "${tag_name}"; payload: ${payload_type} };
+
+    export function read${name}(mem: RocMemory, ptr: number): ${name} {
+      const payloadPtr = mem.view.getUint32(ptr, true);
+
+      return { tag: "${tag_name}", payload: ${read_payload} };
+    }
+
+    export function write${name}(mem: RocMemory, ptr: number, value: ${name}): void {
+      {
+    ${write_payload}  }
+    }
+
+
+    """
+
+## Allocates a refcounted heap cell for a recursive tag union's payload,
+## and stores a pointer to it at `ptr`.
+write_heap_payload : Types, TypeId, Str -> Str
+write_heap_payload = \types, payload, value ->
+    size_str = Types.size(types, payload) |> Num.to_str
+    align_str = Types.alignment(types, payload) |> Num.to_str
+
+    write =
+        when write_stmt(types, payload, "payloadPtr", value) is
+            Ok(stmt) -> "${indent}${indent}${stmt};\n"
+            Err(NothingToWrite) -> ""
+
+    "${indent}${indent}const payloadPtr = mem.allocRefcounted(${size_str}, ${align_str});\n${write}${indent}${indent}mem.view.setUint32(ptr, payloadPtr, true);\n"
+
+generate_entry_points : Str, Types -> Str
+generate_entry_points = \buf, types ->
+    List.walk(Types.entry_points(types), buf, \accum, T(name, id) -> generate_entry_point(accum, types, name, id))
+
+generate_entry_point : Str, Types, Str, TypeId -> Str
+generate_entry_point = \buf, types, name, id ->
+    (args, ret) =
+        when Types.shape(types, id) is
+            Function(roc_fn) -> (roc_fn.args, roc_fn.ret)
+            _ -> ([], id)
+
+    when Types.shape(types, ret) is
+        Function(_) ->
+            # TODO: support entry points that return closures.
+            Str.concat(buf, "// TODO: `${name}` returns a function, which TypeScriptGlue does not support yet.\n\n")
+
+        _ ->
+            params =
+                to_arg_str(args, types, \arg_id, _shape, index ->
+                    index_str = Num.to_str(index)
+                    type_str = type_name(types, arg_id)
+
+                    "arg${index_str}: ${type_str}")
+
+            # Arguments that don't fit in a wasm value are written to memory and passed by pointer.
+            write_args =
+                List.map_with_index(args, \arg_id, index ->
+                    index_str = Num.to_str(index)
+                    shape = Types.shape(types, arg_id)
+
+                    if is_unit(shape) || is_passed_by_value(shape) then
+                        ""
+                    else
+                        size_str = Types.size(types, arg_id) |> Num.to_str
+                        align_str = Types.alignment(types, arg_id) |> Num.to_str
+                        write =
+                            when write_stmt(types, arg_id, "arg${index_str}Ptr", "arg${index_str}") is
+                                Ok(stmt) -> "${indent}${stmt};\n"
+                                Err(NothingToWrite) -> ""
+
+                        "${indent}const arg${index_str}Ptr = mem.allocator.alloc(${size_str}, ${align_str});\n${write}")
+                |> Str.join_with("")
+
+            call_args =
+                to_arg_str(args, types, \_arg_id, shape, index ->
+                    index_str = Num.to_str(index)
+
+                    when shape is
+                        Bool -> "arg${index_str} ? 1 : 0"
+                        _ if is_passed_by_value(shape) -> "arg${index_str}"
+                        _ -> "arg${index_str}Ptr")
+
+            free_args =
+                List.map_with_index(args, \arg_id, index ->
+                    index_str = Num.to_str(index)
+                    shape = Types.shape(types, arg_id)
+
+                    if is_unit(shape) || is_passed_by_value(shape) then
+                        ""
+                    else
+                        align_str = Types.alignment(types, arg_id) |> Num.to_str
+
+                        "${indent}mem.allocator.dealloc(arg${index_str}Ptr, ${align_str});\n")
+                |> Str.join_with("")
+
+            ret_type = type_name(types, ret)
+            ret_size_str = Types.size(types, ret) |> Num.to_str
+            ret_align_str = Types.alignment(types, ret) |> Num.to_str
+            read_ret = read_expr(types, ret, "retPtr")
+            call_comma = if Str.is_empty(call_args) then "" else ", "
+            param_comma = if Str.is_empty(params) then "" else ", "
+
+            """
+            ${buf}/**
+             * Calls the Roc entry point `${name}`, given the exports and memory of a Roc wasm module.
+             *
+             * Heap-allocated parts of the returned value are copied into JS values;
+             * the Roc allocations themselves are not released.
+             */
+            export function ${name}(exports: WebAssembly.Exports, mem: RocMemory${param_comma}${params}): ${ret_type} {
+              const rocFn = exports["roc__${name}_1_exposed_generic"] as (...args: (number | bigint)[]) => void;
+              const retPtr = mem.allocator.alloc(${ret_size_str}, ${ret_align_str});
+            ${write_args}
+              rocFn(retPtr${call_comma}${call_args});
+
+              const ret = ${read_ret};
+            ${free_args}  mem.allocator.dealloc(retPtr, ${ret_align_str});
+
+              return ret;
+            }
+
+
+            """
+
+## A TypeScript expression that reads a value of the given type from memory at `ptr`.
+read_expr : Types, TypeId, Str -> Str
+read_expr = \types, id, ptr ->
+    when Types.shape(types, id) is
+        Unit -> "{}"
+        Unsized -> ptr
+        EmptyTagUnion -> "(() => { throw new Error(\"Cannot read a value of an empty tag union\"); })()"
+        RocStr -> "mem.readStr(${ptr})"
+        Bool -> "mem.readBool(${ptr})"
+        Num(U8) -> "mem.view.getUint8(${ptr})"
+        Num(U16) -> "mem.view.getUint16(${ptr}, true)"
+        Num(U32) -> "mem.view.getUint32(${ptr}, true)"
+        Num(U64) -> "mem.view.getBigUint64(${ptr}, true)"
+        Num(U128) -> "mem.readU128(${ptr})"
+        Num(I8) -> "mem.view.getInt8(${ptr})"
+        Num(I16) -> "mem.view.getInt16(${ptr}, true)"
+        Num(I32) -> "mem.view.getInt32(${ptr}, true)"
+        Num(I64) -> "mem.view.getBigInt64(${ptr}, true)"
+        Num(I128) -> "mem.readI128(${ptr})"
+        Num(F32) -> "mem.view.getFloat32(${ptr}, true)"
+        Num(F64) -> "mem.view.getFloat64(${ptr}, true)"
+        Num(Dec) -> "mem.readDec(${ptr})"
+        RocDict(_key, _value) ->
+            crash("RocDict is not yet supported in TypeScript")
+
+        RocSet(_elem) ->
+            crash("RocSet is not yet supported in TypeScript")
+
+        RocList(elem) ->
+            elem_size_str = Types.size(types, elem) |> Num.to_str
+            read_elem = read_expr(types, elem, "p")
+
+            "mem.readList(${ptr}, ${elem_size_str}, (p) => ${read_elem})"
+
+        RocBox(elem) ->
+            read_elem = read_expr(types, elem, "p")
+
+            "mem.readBox(${ptr}, (p) => ${read_elem})"
+
+        RocResult(ok, err) ->
+            tag_offset_str = result_tag_offset(types, ok, err) |> Num.to_str
+            read_ok = read_expr(types, ok, "p")
+            read_err = read_expr(types, err, "p")
+
+            "mem.readResult(${ptr}, ${tag_offset_str}, (p) => ${read_ok}, (p) => ${read_err})"
+
+        RecursivePointer(content) ->
+            read_expr(types, content, ptr)
+
+        Function(_) ->
+            # Closures are opaque to the host; hand back a pointer to the captured data.
+            ptr
+
+        _ ->
+            name = type_name(types, id)
+
+            "read${name}(mem, ${ptr})"
+
+## A TypeScript statement that writes `value` to memory at `ptr`, if the type has any bytes to write.
+write_stmt : Types, TypeId, Str, Str -> Result Str [NothingToWrite]
+write_stmt = \types, id, ptr, value ->
+    when Types.shape(types, id) is
+        Unit | EmptyTagUnion | Unsized | Function(_) -> Err(NothingToWrite)
+        RocStr -> Ok("mem.writeStr(${ptr}, ${value})")
+        Bool -> Ok("mem.writeBool(${ptr}, ${value})")
+        Num(U8) -> Ok("mem.view.setUint8(${ptr}, ${value})")
+        Num(U16) -> Ok("mem.view.setUint16(${ptr}, ${value}, true)")
+        Num(U32) -> Ok("mem.view.setUint32(${ptr}, ${value}, true)")
+        Num(U64) -> Ok("mem.view.setBigUint64(${ptr}, ${value}, true)")
+        Num(U128) -> Ok("mem.writeU128(${ptr}, ${value})")
+        Num(I8) -> Ok("mem.view.setInt8(${ptr}, ${value})")
+        Num(I16) -> Ok("mem.view.setInt16(${ptr}, ${value}, true)")
+        Num(I32) -> Ok("mem.view.setInt32(${ptr}, ${value}, true)")
+        Num(I64) -> Ok("mem.view.setBigInt64(${ptr}, ${value}, true)")
+        Num(I128) -> Ok("mem.writeI128(${ptr}, ${value})")
+        Num(F32) -> Ok("mem.view.setFloat32(${ptr}, ${value}, true)")
+        Num(F64) -> Ok("mem.view.setFloat64(${ptr}, ${value}, true)")
+        Num(Dec) -> Ok("mem.writeDec(${ptr}, ${value})")
+        RocDict(_key, _value) ->
+            crash("RocDict is not yet supported in TypeScript")
+
+        RocSet(_elem) ->
+            crash("RocSet is not yet supported in TypeScript")
+
+        RocList(elem) ->
+            elem_size_str = Types.size(types, elem) |> Num.to_str
+            elem_align_str = Types.alignment(types, elem) |> Num.to_str
+            elem_is_refcounted = if contains_refcounted(types, Types.shape(types, elem)) then "true" else "false"
+            write_elem =
+                when write_stmt(types, elem, "p", "v") is
+                    Ok(stmt) -> stmt
+                    Err(NothingToWrite) -> "{}"
+
+            Ok("mem.writeList(${ptr}, ${value}, ${elem_size_str}, ${elem_align_str}, ${elem_is_refcounted}, (p, v) => ${write_elem})")
+
+        RocBox(elem) ->
+            elem_size_str = Types.size(types, elem) |> Num.to_str
+            elem_align_str = Types.alignment(types, elem) |> Num.to_str
+            write_elem =
+                when write_stmt(types, elem, "p", "v") is
+                    Ok(stmt) -> stmt
+                    Err(NothingToWrite) -> "{}"
+
+            Ok("mem.writeBox(${ptr}, ${value}, ${elem_size_str}, ${elem_align_str}, (p, v) => ${write_elem})")
+
+        RocResult(ok, err) ->
+            tag_offset_str = result_tag_offset(types, ok, err) |> Num.to_str
+            write_ok =
+                when write_stmt(types, ok, "p", "v") is
+                    Ok(stmt) -> stmt
+                    Err(NothingToWrite) -> "{}"
+            write_err =
+                when write_stmt(types, err, "p", "v") is
+                    Ok(stmt) -> stmt
+                    Err(NothingToWrite) -> "{}"
+
+            Ok("mem.writeResult(${ptr}, ${tag_offset_str}, ${value}, (p, v) => ${write_ok}, (p, v) => ${write_err})")
+
+        RecursivePointer(content) ->
+            write_stmt(types, content, ptr, value)
+
+        _ ->
+            name = type_name(types, id)
+
+            Ok("write${name}(mem, ${ptr}, ${value})")
+
+## `RocResult` stores its tag right after a payload big enough for either variant.
+result_tag_offset : Types, TypeId, TypeId -> U32
+result_tag_offset = \types, ok, err ->
+    payload_size = Num.max(Types.size(types, ok), Types.size(types, err))
+    payload_align = Num.max(Types.alignment(types, ok), Types.alignment(types, err))
+
+    next_multiple_of(payload_size, payload_align)
+
+read_discriminant : Str, U32 -> Str
+read_discriminant = \ptr, size ->
+    when size is
+        1 -> "mem.view.getUint8(${ptr})"
+        2 -> "mem.view.getUint16(${ptr}, true)"
+        _ -> "mem.view.getUint32(${ptr}, true)"
+
+write_discriminant : Str, U32, Str -> Str
+write_discriminant = \ptr, size, value ->
+    when size is
+        1 -> "mem.view.setUint8(${ptr}, ${value})"
+        2 -> "mem.view.setUint16(${ptr}, ${value}, true)"
+        _ -> "mem.view.setUint32(${ptr}, ${value}, true)"
+
+offset_ptr : Str, U32 -> Str
+offset_ptr = \ptr, offset ->
+    if offset == 0 then
+        ptr
+    else
+        offset_str = Num.to_str(offset)
+
+        "${ptr} + ${offset_str}"
+
+type_name : Types, TypeId -> Str
+type_name = \types, id ->
+    when Types.shape(types, id) is
+        Unit -> "{}"
+        Unsized -> "number"
+        EmptyTagUnion -> "never"
+        RocStr -> "string"
+        Bool -> "boolean"
+        Num(U8) | Num(U16) | Num(U32) | Num(I8) | Num(I16) | Num(I32) | Num(F32) | Num(F64) -> "number"
+        Num(U64) | Num(U128) | Num(I64) | Num(I128) | Num(Dec) -> "bigint"
+        RocDict(_key, _value) ->
+            crash("RocDict is not yet supported in TypeScript")
+
+        RocSet(_elem) ->
+            crash("RocSet is not yet supported in TypeScript")
+
+        RocList(elem) ->
+            elem_name = type_name(types, elem)
+
+            "Array<${elem_name}>"
+
+        RocBox(elem) ->
+            type_name(types, elem)
+
+        RocResult(ok, err) ->
+            ok_name = type_name(types, ok)
+            err_name = type_name(types, err)
+
+            "RocResult<${ok_name}, ${err_name}>"
+
+        RecursivePointer(content) ->
+            type_name(types, content)
+
+        Struct({ name }) -> name
+        TagUnionPayload({ name }) -> name
+        TagUnion(NonRecursive({ name })) -> name
+        TagUnion(Recursive({ name })) -> name
+        TagUnion(Enumeration({ name })) -> name
+        TagUnion(NullableWrapped({ name })) -> name
+        TagUnion(NullableUnwrapped({ name })) -> name
+        TagUnion(NonNullableUnwrapped({ name })) -> name
+        TagUnion(SingleTagStruct({ name })) -> name
+        Function(_) -> "number"
+
+## Whether a value of this shape lives on (or points into) the Roc heap.
+contains_refcounted : Types, Shape -> Bool
+contains_refcounted = \types, shape ->
+    when shape is
+        RocStr | RocList(_) | RocBox(_) -> Bool.true
+        TagUnion(Recursive(_)) | TagUnion(NullableWrapped(_)) | TagUnion(NullableUnwrapped(_)) | TagUnion(NonNullableUnwrapped(_)) -> Bool.true
+        RocResult(ok, err) ->
+            contains_refcounted(types, Types.shape(types, ok)) || contains_refcounted(types, Types.shape(types, err))
+
+        Struct({ fields }) ->
+            struct_field_list(fields)
+            |> List.any(\{ id } -> contains_refcounted(types, Types.shape(types, id)))
+
+        TagUnionPayload({ fields }) ->
+            struct_field_list(fields)
+            |> List.any(\{ id } -> contains_refcounted(types, Types.shape(types, id)))
+
+        TagUnion(NonRecursive({ tags })) ->
+            List.any(tags, \{ payload } ->
+                when payload is
+                    Some(id) -> contains_refcounted(types, Types.shape(types, id))
+                    None -> Bool.false)
+
+        TagUnion(SingleTagStruct({ payload: HasNoClosure(fields) })) ->
+            List.any(fields, \{ id } -> contains_refcounted(types, Types.shape(types, id)))
+
+        TagUnion(SingleTagStruct({ payload: HasClosure(fields) })) ->
+            List.any(fields, \{ id } -> contains_refcounted(types, Types.shape(types, id)))
+
+        RecursivePointer(_) -> Bool.true
+        _ -> Bool.false
+
+## Values that fit in a single wasm value are passed to Roc directly; everything else by pointer.
+is_passed_by_value : Shape -> Bool
+is_passed_by_value = \shape ->
+    when shape is
+        Bool | TagUnion(Enumeration(_)) -> Bool.true
+        Num(I128) | Num(U128) | Num(Dec) -> Bool.false
+        Num(_) -> Bool.true
+        _ -> Bool.false
+
+file_header =
+    """
+    // ⚠️ GENERATED CODE ⚠️ - this entire file was generated by the `roc glue` CLI command
+
+    import { RocMemory, type RocResult } from "./roc_std.ts";
+
+
+    """
+
+indent = "  "
+
+next_multiple_of = \lhs, rhs ->
+    if rhs == 0 then
+        lhs
+    else
+        when lhs % rhs is
+            0 -> lhs
+            r -> lhs + (rhs - r)
+
+is_unit : Shape -> Bool
+is_unit = \shape ->
+    when shape is
+        Unit -> Bool.true
+        _ -> Bool.false
+
+to_arg_str : List TypeId, Types, (TypeId, Shape, U64 -> Str) -> Str
+to_arg_str = \args, types, fmt ->
+    List.walk_with_index(args, "", \state, arg_id, index ->
+        shape = Types.shape(types, arg_id)
+
+        # Drop `{}` args; nothing will get passed anyway.
+        if is_unit(shape) then
+            state
+        else
+            arg_str = fmt(arg_id, shape, index)
+
+            if Str.is_empty(state) then
+                arg_str # Don't prepend a comma if this is the first one
+            else
+                state
+                |> Str.concat(", ")
+                |> Str.concat(arg_str))
@@ -61,12 +61,17 @@ pub fn generate(
                 emit_debug_info: false,
                 emit_llvm_ir: false,
                 fuzz: false,
+                trace_clones: false,
+                profile_alloc: false,
+                check_refcounts: false,
+                sanitizer: None,
             };
 
             let load_config = standard_load_config(
                 target,
                 BuildOrdering::BuildIfChecks,
                 Threading::AllAvailable,
+                code_gen_options.opt_level,
             );
 
             let arena = ManuallyDrop::new(Bump::new());
@@ -91,6 +96,7 @@ pub fn generate(
                     None,
                     RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
                     load_config,
+                    &Default::default(),
                     Some(dylib_dir.path()),
                     false,
                 ),
@@ -188,9 +194,14 @@ pub fn generate(
 
                     Ok(0)
                 }
-                Err(BuildFileError::ErrorModule { module, total_time }) => {
-                    handle_error_module(module, total_time, spec_path.as_os_str(), true)
-                }
+                Err(BuildFileError::ErrorModule { module, total_time }) => handle_error_module(
+                    module,
+                    total_time,
+                    spec_path.as_os_str(),
+                    true,
+                    RenderTarget::ColorTerminal,
+                    &Default::default(),
+                ),
                 Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
             };
 
@@ -430,6 +441,7 @@ pub fn load_types(
             palette: DEFAULT_PALETTE,
             threading,
             exec_mode: ExecutionMode::Check,
+            opt_level: None,
         },
     )
     .unwrap_or_else(|problem| match problem {
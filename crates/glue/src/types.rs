@@ -2057,6 +2057,23 @@ fn tag_union_type_from_layout<'a>(
                 }
             }
         }
+        LayoutRepr::Builtin(Builtin::Int(U8))
+            if env
+                .layout_cache
+                .interner
+                .get_semantic(layout)
+                .niche_union()
+                .is_some() =>
+        {
+            // A niche-filled union is a single byte, so hosts see it as an enumeration of
+            // every value it can hold, e.g. `[None, Some Bool]` has `None`, `Some_false` and
+            // `Some_true`.
+            RocTagUnion::Enumeration {
+                name: name.clone(),
+                tags: byte_value_names(&env.layout_cache.interner, layout),
+                size: 1,
+            }
+        }
         LayoutRepr::Builtin(Builtin::Int(int_width)) => {
             add_int_enumeration(union_tags, subs, &name, int_width)
         }
@@ -2160,6 +2177,28 @@ fn add_int_enumeration(
     }
 }
 
+/// Names the values of a type stored in a single byte, in the order of the byte values.
+fn byte_value_names<'a>(interner: &TLLayoutInterner<'a>, layout: InLayout<'a>) -> Vec<String> {
+    let semantic = interner.get_semantic(layout);
+
+    match (semantic.tag_names(), semantic.niche_union()) {
+        (Some(tags), Some(niche)) => tags
+            .iter()
+            .enumerate()
+            .flat_map(|(tag_id, tag)| match niche.payload(tag_id as _) {
+                None => vec![tag.to_string()],
+                Some(payload) => byte_value_names(interner, payload)
+                    .into_iter()
+                    .map(|value| format!("{tag}_{value}"))
+                    .collect(),
+            })
+            .collect(),
+        (Some(tags), None) => tags.iter().map(|tag| tag.to_string()).collect(),
+        // a plain Bool
+        (None, _) => vec!["false".to_string(), "true".to_string()],
+    }
+}
+
 fn union_tags_to_types<'a>(
     name: &str,
     union_tags: &UnionLabels<impl UnionTag>,
@@ -0,0 +1,421 @@
+# This file is copied into the output directory by `roc glue` when using PythonGlue.roc.
+#
+# ctypes representations of Roc's builtin data structures.
+#
+# Roc values that live on the heap are preceded by a refcount. Hosts are expected to
+# implement `roc_alloc`, `roc_realloc` and `roc_dealloc` with the C allocator
+# (`malloc`, `realloc` and `free`), so that memory allocated here can be freed by
+# the Roc application and vice versa.
+
+import ctypes
+import ctypes.util
+
+_libc = ctypes.CDLL(ctypes.util.find_library("c"))
+_libc.malloc.restype = ctypes.c_void_p
+_libc.malloc.argtypes = [ctypes.c_size_t]
+_libc.free.restype = None
+_libc.free.argtypes = [ctypes.c_void_p]
+
+USIZE = ctypes.sizeof(ctypes.c_size_t)
+
+# A refcount of 1 means the allocation is unique; 0 means it is read-only (e.g. a constant
+# in the Roc application's data section) and must never be freed.
+REFCOUNT_ONE = 1
+REFCOUNT_READONLY = 0
+
+# The high bit of a list or string length marks a seamless slice.
+SEAMLESS_SLICE_BIT = 1 << (USIZE * 8 - 1)
+
+
+def roc_alloc(size, alignment):
+    return _libc.malloc(size)
+
+
+def roc_dealloc(ptr, alignment):
+    _libc.free(ptr)
+
+
+def _refcount_ptr(data_ptr):
+    return ctypes.cast(data_ptr - USIZE, ctypes.POINTER(ctypes.c_ssize_t))
+
+
+def alloc_refcounted(size, alignment):
+    """Allocate `size` bytes preceded by a refcount of 1, returning a pointer to the data."""
+    prefix = 16 if alignment > 8 else 8
+    alloc_ptr = roc_alloc(size + prefix, max(alignment, USIZE))
+    data_ptr = alloc_ptr + prefix
+    _refcount_ptr(data_ptr)[0] = REFCOUNT_ONE
+
+    return data_ptr
+
+
+def increment_refcount(data_ptr):
+    rc = _refcount_ptr(data_ptr)
+
+    if rc[0] != REFCOUNT_READONLY:
+        rc[0] += 1
+
+
+def decrement_refcount(data_ptr, alloc_offset, alignment):
+    """Decrement a refcount, freeing the allocation when it reaches zero.
+
+    Returns True if the allocation was freed.
+    """
+    rc = _refcount_ptr(data_ptr)
+
+    if rc[0] == REFCOUNT_READONLY:
+        return False
+    elif rc[0] == REFCOUNT_ONE:
+        roc_dealloc(data_ptr - alloc_offset, alignment)
+        return True
+    else:
+        rc[0] -= 1
+        return False
+
+
+class RocStr(ctypes.Structure):
+    """A Roc `Str`.
+
+    Strings of up to `SMALL_STRING_CAPACITY` bytes are stored inline, with the length
+    in the last byte and its high bit set; longer strings live on the heap.
+    """
+
+    _fields_ = [
+        ("_bytes", ctypes.c_size_t),
+        ("_length", ctypes.c_size_t),
+        ("_capacity_or_alloc_ptr", ctypes.c_size_t),
+    ]
+
+    SMALL_STRING_CAPACITY = 3 * USIZE - 1
+
+    @classmethod
+    def from_str(cls, value):
+        return cls.from_bytes(value.encode("utf-8"))
+
+    @classmethod
+    def from_bytes(cls, data):
+        answer = cls()
+
+        if len(data) <= cls.SMALL_STRING_CAPACITY:
+            raw = (ctypes.c_uint8 * ctypes.sizeof(cls)).from_buffer(answer)
+            raw[: len(data)] = data
+            raw[-1] = 0x80 | len(data)
+        else:
+            data_ptr = alloc_refcounted(len(data), USIZE)
+            ctypes.memmove(data_ptr, data, len(data))
+            answer._bytes = data_ptr
+            answer._length = len(data)
+            answer._capacity_or_alloc_ptr = len(data)
+
+        return answer
+
+    def is_small_str(self):
+        raw = (ctypes.c_uint8 * ctypes.sizeof(self)).from_buffer(self)
+
+        return raw[-1] & 0x80 != 0
+
+    def is_seamless_slice(self):
+        return not self.is_small_str() and self._length & SEAMLESS_SLICE_BIT != 0
+
+    def __len__(self):
+        if self.is_small_str():
+            raw = (ctypes.c_uint8 * ctypes.sizeof(self)).from_buffer(self)
+
+            return raw[-1] & 0x7F
+
+        return self._length & ~SEAMLESS_SLICE_BIT
+
+    def as_bytes(self):
+        if self.is_small_str():
+            raw = (ctypes.c_uint8 * ctypes.sizeof(self)).from_buffer(self)
+
+            return bytes(raw[: len(self)])
+
+        return ctypes.string_at(self._bytes, len(self))
+
+    def __str__(self):
+        return self.as_bytes().decode("utf-8")
+
+    def __repr__(self):
+        return "RocStr(%r)" % str(self)
+
+    def __eq__(self, other):
+        if isinstance(other, RocStr):
+            return self.as_bytes() == other.as_bytes()
+        if isinstance(other, str):
+            return str(self) == other
+
+        return NotImplemented
+
+    def _refcounted_ptr(self):
+        if self.is_seamless_slice():
+            return self._capacity_or_alloc_ptr << 1
+
+        return self._bytes
+
+    def inc(self):
+        if not self.is_small_str() and len(self) > 0:
+            increment_refcount(self._refcounted_ptr())
+
+    def dec(self):
+        if not self.is_small_str() and len(self) > 0:
+            decrement_refcount(self._refcounted_ptr(), USIZE, USIZE)
+
+
+_list_types = {}
+
+
+def RocList(elem_type):
+    """Return the ctypes representation of a Roc `List` of `elem_type`."""
+    if elem_type in _list_types:
+        return _list_types[elem_type]
+
+    elem_is_refcounted = hasattr(elem_type, "dec")
+    alignment = max(ctypes.alignment(elem_type), USIZE)
+    # Lists of refcounted elements store their element count before the refcount,
+    # so the allocation has room for two words.
+    alloc_offset = max(alignment, 2 * USIZE if elem_is_refcounted else USIZE)
+
+    class _RocList(ctypes.Structure):
+        _fields_ = [
+            ("_elements", ctypes.c_size_t),
+            ("_length", ctypes.c_size_t),
+            ("_capacity_or_alloc_ptr", ctypes.c_size_t),
+        ]
+
+        ELEM_TYPE = elem_type
+
+        @classmethod
+        def from_list(cls, values):
+            answer = cls()
+
+            if not values:
+                return answer
+
+            size = ctypes.sizeof(elem_type) * len(values)
+            alloc_ptr = roc_alloc(alloc_offset + size, alignment)
+            data_ptr = alloc_ptr + alloc_offset
+            _refcount_ptr(data_ptr)[0] = REFCOUNT_ONE
+
+            if elem_is_refcounted:
+                ctypes.cast(data_ptr - 2 * USIZE, ctypes.POINTER(ctypes.c_size_t))[0] = len(values)
+
+            array = (elem_type * len(values)).from_address(data_ptr)
+            for index, value in enumerate(values):
+                array[index] = value
+
+            answer._elements = data_ptr
+            answer._length = len(values)
+            answer._capacity_or_alloc_ptr = len(values)
+
+            return answer
+
+        def is_seamless_slice(self):
+            return self._length & SEAMLESS_SLICE_BIT != 0
+
+        def __len__(self):
+            return self._length & ~SEAMLESS_SLICE_BIT
+
+        def __getitem__(self, index):
+            if not 0 <= index < len(self):
+                raise IndexError("RocList index out of range")
+
+            return elem_type.from_address(self._elements + index * ctypes.sizeof(elem_type))
+
+        def __iter__(self):
+            for index in range(len(self)):
+                yield self[index]
+
+        def to_list(self):
+            return list(self)
+
+        def __repr__(self):
+            return "RocList(%r)" % self.to_list()
+
+        def _refcounted_ptr(self):
+            if self.is_seamless_slice():
+                return self._capacity_or_alloc_ptr << 1
+
+            return self._elements
+
+        def inc(self):
+            if self._elements:
+                increment_refcount(self._refcounted_ptr())
+
+        def dec(self):
+            if not self._elements:
+                return
+
+            data_ptr = self._refcounted_ptr()
+            rc = _refcount_ptr(data_ptr)
+
+            if elem_is_refcounted and rc[0] == REFCOUNT_ONE:
+                # This is the last reference, so the elements must be released too.
+                count = ctypes.cast(data_ptr - 2 * USIZE, ctypes.POINTER(ctypes.c_size_t))[0]
+                array = (elem_type * count).from_address(data_ptr)
+                for elem in array:
+                    elem.dec()
+
+            decrement_refcount(data_ptr, alloc_offset, alignment)
+
+    _RocList.__name__ = "RocList_" + elem_type.__name__
+    _list_types[elem_type] = _RocList
+
+    return _RocList
+
+
+_box_types = {}
+
+
+def RocBox(contents_type):
+    """Return the ctypes representation of a Roc `Box` of `contents_type`."""
+    if contents_type in _box_types:
+        return _box_types[contents_type]
+
+    alignment = max(ctypes.alignment(contents_type), USIZE)
+    alloc_offset = 16 if alignment > 8 else 8
+
+    class _RocBox(ctypes.Structure):
+        _fields_ = [("_contents", ctypes.c_size_t)]
+
+        CONTENTS_TYPE = contents_type
+
+        @classmethod
+        def new(cls, value):
+            answer = cls()
+            answer._contents = alloc_refcounted(ctypes.sizeof(contents_type), alignment)
+            ctypes.memmove(answer._contents, ctypes.addressof(value), ctypes.sizeof(contents_type))
+
+            return answer
+
+        def get(self):
+            return contents_type.from_address(self._contents)
+
+        def __repr__(self):
+            return "RocBox(%r)" % self.get()
+
+        def inc(self):
+            increment_refcount(self._contents)
+
+        def dec(self):
+            rc = _refcount_ptr(self._contents)
+
+            if rc[0] == REFCOUNT_ONE and hasattr(contents_type, "dec"):
+                self.get().dec()
+
+            decrement_refcount(self._contents, alloc_offset, alignment)
+
+    _RocBox.__name__ = "RocBox_" + contents_type.__name__
+    _box_types[contents_type] = _RocBox
+
+    return _RocBox
+
+
+_result_types = {}
+
+
+def RocResult(ok_type, err_type):
+    """Return the ctypes representation of a Roc `Result` of `ok_type` and `err_type`."""
+    key = (ok_type, err_type)
+
+    if key in _result_types:
+        return _result_types[key]
+
+    class _Payload(ctypes.Union):
+        _fields_ = [("ok", ok_type), ("err", err_type)]
+
+    class _RocResult(ctypes.Structure):
+        _fields_ = [("_payload", _Payload), ("_tag", ctypes.c_uint8)]
+
+        def is_ok(self):
+            return self._tag == 1
+
+        def is_err(self):
+            return self._tag == 0
+
+        def unwrap(self):
+            if not self.is_ok():
+                raise ValueError("called unwrap on a Roc Err: %r" % self._payload.err)
+
+            return self._payload.ok
+
+        def unwrap_err(self):
+            if not self.is_err():
+                raise ValueError("called unwrap_err on a Roc Ok: %r" % self._payload.ok)
+
+            return self._payload.err
+
+        def __repr__(self):
+            if self.is_ok():
+                return "RocOk(%r)" % self._payload.ok
+
+            return "RocErr(%r)" % self._payload.err
+
+        def inc(self):
+            payload = self._payload.ok if self.is_ok() else self._payload.err
+
+            if hasattr(payload, "inc"):
+                payload.inc()
+
+        def dec(self):
+            payload = self._payload.ok if self.is_ok() else self._payload.err
+
+            if hasattr(payload, "dec"):
+                payload.dec()
+
+    _result_types[key] = _RocResult
+
+    return _RocResult
+
+
+class _Int128(ctypes.Union):
+    """16 little-endian bytes, aligned like Roc's 128-bit integers.
+
+    ctypes has no 128-bit integer type, so this borrows the 16-byte alignment of
+    `long double` on x86_64 and aarch64 Linux. Python 3.13+ also honors `_align_`.
+    Without it, a struct like `{ a : U64, b : U128 }` would be smaller in Python
+    than in Roc, and Roc would write past the end of it.
+    """
+
+    _align_ = 16
+    _fields_ = [("_bytes", ctypes.c_uint8 * 16), ("_alignment", ctypes.c_longdouble)]
+
+    SIGNED = False
+
+    @classmethod
+    def from_int(cls, value):
+        answer = cls()
+        answer._bytes[:] = value.to_bytes(16, "little", signed=cls.SIGNED)
+
+        return answer
+
+    def __int__(self):
+        return int.from_bytes(bytes(self._bytes), "little", signed=self.SIGNED)
+
+    def __repr__(self):
+        return "%s(%d)" % (type(self).__name__, int(self))
+
+
+class RocI128(_Int128):
+    """A Roc `I128`."""
+
+    SIGNED = True
+
+
+class RocU128(_Int128):
+    """A Roc `U128`."""
+
+
+class RocDec(RocI128):
+    """A Roc `Dec`: a 128-bit fixed-point number with 18 decimal places."""
+
+    DECIMAL_PLACES = 18
+
+    def __float__(self):
+        return int(self) / 10**self.DECIMAL_PLACES
+
+
+class RocUnit(ctypes.Structure):
+    """A zero-sized Roc value, such as `{}`."""
+
+    _fields_ = []
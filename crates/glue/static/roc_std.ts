@@ -0,0 +1,284 @@
+// This file is copied into the output directory by `roc glue` when using TypeScriptGlue.roc.
+//
+// Helpers for reading and writing Roc values in the linear memory of a wasm32 Roc application.
+// All offsets and sizes here follow the wasm32 layouts, where a pointer (and `usize`) is 4 bytes.
+//
+// This only uses TypeScript syntax that can be erased, so Node.js can run it as-is.
+
+export const USIZE = 4;
+
+// A refcount of 1 means the allocation is unique; 0 means it is read-only (e.g. a constant
+// in the Roc application's data section) and must never be freed.
+const REFCOUNT_ONE = 1;
+const REFCOUNT_READONLY = 0;
+
+// The high bit of a list or string length marks a seamless slice.
+const SEAMLESS_SLICE_BIT = 0x8000_0000;
+
+const STR_SIZE = 3 * USIZE;
+const SMALL_STRING_CAPACITY = STR_SIZE - 1;
+
+/**
+ * The allocator the wasm module uses for `roc_alloc` and `roc_dealloc`.
+ * Memory handed to Roc must come from here, so that Roc can free it.
+ */
+export interface RocAllocator {
+  alloc(size: number, alignment: number): number;
+  dealloc(ptr: number, alignment: number): void;
+}
+
+export type RocResult<T, E> = { tag: "Ok"; value: T } | { tag: "Err"; value: E };
+
+export class RocMemory {
+  readonly memory: WebAssembly.Memory;
+  readonly allocator: RocAllocator;
+
+  private readonly encoder = new TextEncoder();
+  private readonly decoder = new TextDecoder("utf-8");
+
+  constructor(memory: WebAssembly.Memory, allocator: RocAllocator) {
+    this.memory = memory;
+    this.allocator = allocator;
+  }
+
+  // The buffer is replaced whenever memory grows, so always get a fresh view.
+  get view(): DataView {
+    return new DataView(this.memory.buffer);
+  }
+
+  get bytes(): Uint8Array {
+    return new Uint8Array(this.memory.buffer);
+  }
+
+  readBool(ptr: number): boolean {
+    return this.view.getUint8(ptr) !== 0;
+  }
+
+  writeBool(ptr: number, value: boolean): void {
+    this.view.setUint8(ptr, value ? 1 : 0);
+  }
+
+  readU128(ptr: number): bigint {
+    const lo = this.view.getBigUint64(ptr, true);
+    const hi = this.view.getBigUint64(ptr + 8, true);
+
+    return (hi << 64n) | lo;
+  }
+
+  writeU128(ptr: number, value: bigint): void {
+    this.view.setBigUint64(ptr, BigInt.asUintN(64, value), true);
+    this.view.setBigUint64(ptr + 8, BigInt.asUintN(64, value >> 64n), true);
+  }
+
+  readI128(ptr: number): bigint {
+    return BigInt.asIntN(128, this.readU128(ptr));
+  }
+
+  writeI128(ptr: number, value: bigint): void {
+    this.writeU128(ptr, BigInt.asUintN(128, value));
+  }
+
+  // A `Dec` is a 128-bit fixed-point number with 18 decimal places;
+  // this returns the raw scaled integer.
+  readDec(ptr: number): bigint {
+    return this.readI128(ptr);
+  }
+
+  writeDec(ptr: number, value: bigint): void {
+    this.writeI128(ptr, value);
+  }
+
+  /** Allocate `size` bytes preceded by a refcount of 1, returning a pointer to the data. */
+  allocRefcounted(size: number, alignment: number): number {
+    const prefix = alignment > 8 ? 16 : 8;
+    const allocPtr = this.allocator.alloc(size + prefix, Math.max(alignment, USIZE));
+    const dataPtr = allocPtr + prefix;
+    this.view.setInt32(dataPtr - USIZE, REFCOUNT_ONE, true);
+
+    return dataPtr;
+  }
+
+  increment(dataPtr: number): void {
+    const rc = this.view.getInt32(dataPtr - USIZE, true);
+
+    if (rc !== REFCOUNT_READONLY) {
+      this.view.setInt32(dataPtr - USIZE, rc + 1, true);
+    }
+  }
+
+  /** Decrement a refcount, freeing the allocation once it reaches zero. Returns true if it was freed. */
+  decrement(dataPtr: number, allocOffset: number, alignment: number): boolean {
+    const rc = this.view.getInt32(dataPtr - USIZE, true);
+
+    if (rc === REFCOUNT_READONLY) {
+      return false;
+    } else if (rc === REFCOUNT_ONE) {
+      this.allocator.dealloc(dataPtr - allocOffset, alignment);
+      return true;
+    } else {
+      this.view.setInt32(dataPtr - USIZE, rc - 1, true);
+      return false;
+    }
+  }
+
+  readStr(ptr: number): string {
+    const lastByte = this.view.getUint8(ptr + STR_SIZE - 1);
+
+    if ((lastByte & 0x80) !== 0) {
+      // Small strings are stored inline, with their length in the last byte.
+      const len = lastByte & 0x7f;
+
+      return this.decoder.decode(this.bytes.slice(ptr, ptr + len));
+    }
+
+    const elements = this.view.getUint32(ptr, true);
+    const len = this.view.getUint32(ptr + USIZE, true) & ~SEAMLESS_SLICE_BIT;
+
+    return this.decoder.decode(this.bytes.slice(elements, elements + len));
+  }
+
+  writeStr(ptr: number, value: string): void {
+    const utf8 = this.encoder.encode(value);
+
+    if (utf8.length <= SMALL_STRING_CAPACITY) {
+      this.bytes.fill(0, ptr, ptr + STR_SIZE);
+      this.bytes.set(utf8, ptr);
+      this.view.setUint8(ptr + STR_SIZE - 1, 0x80 | utf8.length);
+    } else {
+      const elements = this.allocRefcounted(utf8.length, USIZE);
+      this.bytes.set(utf8, elements);
+      this.view.setUint32(ptr, elements, true);
+      this.view.setUint32(ptr + USIZE, utf8.length, true);
+      this.view.setUint32(ptr + 2 * USIZE, utf8.length, true);
+    }
+  }
+
+  decrementStr(ptr: number): void {
+    const lastByte = this.view.getUint8(ptr + STR_SIZE - 1);
+
+    if ((lastByte & 0x80) === 0) {
+      this.decrementList(ptr, USIZE, false);
+    }
+  }
+
+  readList<T>(ptr: number, elemSize: number, readElem: (ptr: number) => T): T[] {
+    const elements = this.view.getUint32(ptr, true);
+    const len = this.view.getUint32(ptr + USIZE, true) & ~SEAMLESS_SLICE_BIT;
+    const answer: T[] = [];
+
+    for (let index = 0; index < len; index++) {
+      answer.push(readElem(elements + index * elemSize));
+    }
+
+    return answer;
+  }
+
+  writeList<T>(
+    ptr: number,
+    values: T[],
+    elemSize: number,
+    elemAlignment: number,
+    elemIsRefcounted: boolean,
+    writeElem: (ptr: number, value: T) => void,
+  ): void {
+    if (values.length === 0) {
+      this.bytes.fill(0, ptr, ptr + 3 * USIZE);
+      return;
+    }
+
+    const alignment = Math.max(elemAlignment, USIZE);
+    const allocOffset = listAllocOffset(elemAlignment, elemIsRefcounted);
+    const allocPtr = this.allocator.alloc(allocOffset + values.length * elemSize, alignment);
+    const elements = allocPtr + allocOffset;
+    this.view.setInt32(elements - USIZE, REFCOUNT_ONE, true);
+
+    if (elemIsRefcounted) {
+      // Lists of refcounted elements store their element count before the refcount.
+      this.view.setUint32(elements - 2 * USIZE, values.length, true);
+    }
+
+    values.forEach((value, index) => writeElem(elements + index * elemSize, value));
+
+    this.view.setUint32(ptr, elements, true);
+    this.view.setUint32(ptr + USIZE, values.length, true);
+    this.view.setUint32(ptr + 2 * USIZE, values.length, true);
+  }
+
+  decrementList(
+    ptr: number,
+    elemAlignment: number,
+    elemIsRefcounted: boolean,
+    elemSize = 0,
+    decrementElem?: (ptr: number) => void,
+  ): void {
+    const elements = this.view.getUint32(ptr, true);
+    const length = this.view.getUint32(ptr + USIZE, true);
+
+    if (elements === 0) {
+      return;
+    }
+
+    const dataPtr =
+      (length & SEAMLESS_SLICE_BIT) !== 0 ? this.view.getUint32(ptr + 2 * USIZE, true) << 1 : elements;
+
+    if (decrementElem && this.view.getInt32(dataPtr - USIZE, true) === REFCOUNT_ONE) {
+      // This is the last reference, so the elements must be released too.
+      const count = this.view.getUint32(dataPtr - 2 * USIZE, true);
+
+      for (let index = 0; index < count; index++) {
+        decrementElem(dataPtr + index * elemSize);
+      }
+    }
+
+    this.decrement(dataPtr, listAllocOffset(elemAlignment, elemIsRefcounted), Math.max(elemAlignment, USIZE));
+  }
+
+  readBox<T>(ptr: number, readContents: (ptr: number) => T): T {
+    return readContents(this.view.getUint32(ptr, true));
+  }
+
+  writeBox<T>(
+    ptr: number,
+    value: T,
+    contentsSize: number,
+    contentsAlignment: number,
+    writeContents: (ptr: number, value: T) => void,
+  ): void {
+    const contents = this.allocRefcounted(contentsSize, contentsAlignment);
+    writeContents(contents, value);
+    this.view.setUint32(ptr, contents, true);
+  }
+
+  readResult<T, E>(
+    ptr: number,
+    tagOffset: number,
+    readOk: (ptr: number) => T,
+    readErr: (ptr: number) => E,
+  ): RocResult<T, E> {
+    if (this.view.getUint8(ptr + tagOffset) === 1) {
+      return { tag: "Ok", value: readOk(ptr) };
+    }
+
+    return { tag: "Err", value: readErr(ptr) };
+  }
+
+  writeResult<T, E>(
+    ptr: number,
+    tagOffset: number,
+    value: RocResult<T, E>,
+    writeOk: (ptr: number, value: T) => void,
+    writeErr: (ptr: number, value: E) => void,
+  ): void {
+    if (value.tag === "Ok") {
+      writeOk(ptr, value.value);
+      this.view.setUint8(ptr + tagOffset, 1);
+    } else {
+      writeErr(ptr, value.value);
+      this.view.setUint8(ptr + tagOffset, 0);
+    }
+  }
+}
+
+function listAllocOffset(elemAlignment: number, elemIsRefcounted: boolean): number {
+  return Math.max(elemAlignment, USIZE, elemIsRefcounted ? 2 * USIZE : USIZE);
+}
@@ -0,0 +1,24 @@
+# ⚠️ READ THIS BEFORE MODIFYING THIS FILE! ⚠️
+#
+# This file is a fixture template. If the file you're looking at is
+# in the fixture-templates/ directory, then you're all set - go ahead
+# and modify it, and it will modify all the fixture tests.
+#
+# If this file is in the fixtures/ directory, on the other hand, then
+# it is gitignored and will be overwritten the next time tests run.
+# So you probably don't want to modify it by hand! Instead, modify the
+# file with the same name in the fixture-templates/ directory.
+
+import ctypes
+import sys
+
+# `roc glue` puts the roc_app package in test_glue/
+sys.path.insert(0, "test_glue")
+
+
+def load_roc_app():
+    # The app's shared library expects roc_alloc and friends to already be
+    # loaded, so libhost.so has to be loaded globally first.
+    ctypes.CDLL("./libhost.so", mode=ctypes.RTLD_GLOBAL)
+
+    return ctypes.CDLL("./app.so")
@@ -0,0 +1,30 @@
+// ⚠️ READ THIS BEFORE MODIFYING THIS FILE! ⚠️
+//
+// This file is a fixture template. If the file you're looking at is
+// in the fixture-templates/ directory, then you're all set - go ahead
+// and modify it, and it will modify all the fixture tests.
+//
+// If this file is in the fixtures/ directory, on the other hand, then
+// it is gitignored and will be overwritten the next time tests run.
+// So you probably don't want to modify it by hand! Instead, modify the
+// file with the same name in the fixture-templates/ directory.
+
+import { readFileSync } from "node:fs";
+import { WASI } from "node:wasi";
+
+import { RocMemory } from "./test_glue/roc_std.ts";
+
+/** Instantiates app.wasm, without running its `main`. */
+export function loadRocApp(): { exports: WebAssembly.Exports; mem: RocMemory } {
+  const wasi = new WASI({ version: "preview1" });
+  const module = new WebAssembly.Module(readFileSync("app.wasm"));
+  const instance = new WebAssembly.Instance(module, wasi.getImportObject() as WebAssembly.Imports);
+  const exports = instance.exports;
+
+  const mem = new RocMemory(exports.memory as WebAssembly.Memory, {
+    alloc: exports.roc_alloc as (size: number, alignment: number) => number,
+    dealloc: exports.roc_dealloc as (ptr: number, alignment: number) => void,
+  });
+
+  return { exports, mem };
+}
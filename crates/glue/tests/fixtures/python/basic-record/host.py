@@ -0,0 +1,12 @@
+from load import load_roc_app
+
+import roc_app
+
+lib = load_roc_app()
+
+record = roc_app.main_for_host(lib)
+
+assert record.a == 1995
+assert int(record.b) == 42
+
+print("Record was: MyRcd { a: %d, b: %d }" % (record.a, int(record.b)))
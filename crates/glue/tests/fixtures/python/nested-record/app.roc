@@ -0,0 +1,3 @@
+app [main] { pf: platform "platform.roc" }
+
+main = { x: { a: 5, b: 24 }, y: "foo", z: [1, 2] }
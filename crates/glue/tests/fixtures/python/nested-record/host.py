@@ -0,0 +1,17 @@
+from load import load_roc_app
+
+import roc_app
+
+lib = load_roc_app()
+
+outer = roc_app.main_for_host(lib)
+z = [elem.value for elem in outer.z]
+
+assert outer.x.a == 5
+assert outer.x.b == 24.0
+assert str(outer.y) == "foo"
+assert z == [1, 2]
+
+print("Record was: Outer { x: Inner { a: %d, b: %r }, y: %r, z: %r }" % (outer.x.a, outer.x.b, str(outer.y), z))
+
+outer.dec()
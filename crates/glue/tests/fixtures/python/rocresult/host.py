@@ -0,0 +1,16 @@
+from load import load_roc_app
+
+import roc_app
+
+lib = load_roc_app()
+
+ok = roc_app.main_for_host(lib, True)
+err = roc_app.main_for_host(lib, False)
+
+assert ok.is_ok()
+assert err.is_err()
+
+print("Answer was: %r" % ok)
+print("Answer was: %r" % err)
+
+ok.dec()
@@ -0,0 +1,4 @@
+app [main] { pf: platform "platform.roc" }
+
+main : Str -> Str
+main = \name -> "Hello, ${name}! This string is too long to be a small string."
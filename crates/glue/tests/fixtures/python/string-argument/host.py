@@ -0,0 +1,16 @@
+from load import load_roc_app
+
+import roc_app
+from roc_app.roc_std import RocStr
+
+lib = load_roc_app()
+
+# Roc takes ownership of its arguments, so there's nothing to release afterwards.
+small = roc_app.main_for_host(lib, RocStr.from_str("Roc"))
+big = roc_app.main_for_host(lib, RocStr.from_str("a name which is long enough to be on the heap"))
+
+print("Answer was: %r" % str(small))
+print("Answer was: %r" % str(big))
+
+small.dec()
+big.dec()
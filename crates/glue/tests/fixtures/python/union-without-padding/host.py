@@ -0,0 +1,29 @@
+from load import load_roc_app
+
+import roc_app
+from roc_app.roc_std import RocStr
+
+lib = load_roc_app()
+
+tag_union = roc_app.main_for_host(lib)
+
+assert tag_union.tag() == roc_app.NonRecursive_Discriminant.Foo
+
+print("tag_union was: NonRecursive.Foo(%r)" % str(tag_union.payload.Foo))
+
+tag_union.dec()
+
+# Build some unions in Python, to check that they agree with Roc's layout
+foo = roc_app.NonRecursive.Foo(RocStr.from_str("A long enough string to not be small"))
+bar = roc_app.NonRecursive.Bar(123)
+baz = roc_app.NonRecursive.Baz()
+
+assert foo.tag() == roc_app.NonRecursive_Discriminant.Foo
+assert bar.tag() == roc_app.NonRecursive_Discriminant.Bar
+assert baz.tag() == roc_app.NonRecursive_Discriminant.Baz
+
+print("`Foo \"A long enough string to not be small\"` is: NonRecursive.Foo(%r)" % str(foo.payload.Foo))
+print("`Bar 123` is: NonRecursive.Bar(%d)" % bar.payload.Bar)
+print("`Baz` is: NonRecursive.Baz")
+
+foo.dec()
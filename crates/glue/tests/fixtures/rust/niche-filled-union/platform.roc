@@ -0,0 +1,11 @@
+platform "test-platform"
+    requires {} { main : _ }
+    exposes []
+    packages {}
+    imports []
+    provides [main_for_host]
+
+MaybeBool : [Nothing, Just Bool]
+
+main_for_host : MaybeBool
+main_for_host = main
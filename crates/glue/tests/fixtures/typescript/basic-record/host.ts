@@ -0,0 +1,13 @@
+import assert from "node:assert/strict";
+
+import { loadRocApp } from "./load.ts";
+import { main_for_host } from "./test_glue/roc_app.ts";
+
+const { exports, mem } = loadRocApp();
+
+const record = main_for_host(exports, mem);
+
+assert.equal(record.a, 1995n);
+assert.equal(record.b, 42n);
+
+console.log(`Record was: MyRcd { a: ${record.a}, b: ${record.b} }`);
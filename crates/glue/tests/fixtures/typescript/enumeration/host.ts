@@ -0,0 +1,12 @@
+import assert from "node:assert/strict";
+
+import { loadRocApp } from "./load.ts";
+import { MyEnum, main_for_host } from "./test_glue/roc_app.ts";
+
+const { exports, mem } = loadRocApp();
+
+const tagUnion = main_for_host(exports, mem);
+
+assert.equal(tagUnion, MyEnum.Foo);
+
+console.log(`tag_union was: MyEnum.Foo (${tagUnion}), Bar is: ${MyEnum.Bar}, Baz is: ${MyEnum.Baz}`);
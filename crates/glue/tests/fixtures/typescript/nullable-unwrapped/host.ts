@@ -0,0 +1,27 @@
+import assert from "node:assert/strict";
+
+import { loadRocApp } from "./load.ts";
+import { type StrConsList, main_for_host, readStrConsList, writeStrConsList } from "./test_glue/roc_app.ts";
+
+function show(list: StrConsList): string {
+  if (list.tag === "Nil") {
+    return "Nil";
+  }
+
+  return `Cons(${JSON.stringify(list.payload.f0)}, ${show(list.payload.f1)})`;
+}
+
+const { exports, mem } = loadRocApp();
+
+const tagUnion = main_for_host(exports, mem);
+
+assert.equal(tagUnion.tag, "Cons");
+
+console.log(`tag_union was: ${show(tagUnion)}`);
+
+// Write a list into Roc's memory and read it back, to check that both agree on the layout
+const value: StrConsList = { tag: "Cons", payload: { f0: "small str", f1: { tag: "Nil" } } };
+const ptr = mem.allocator.alloc(4, 4);
+writeStrConsList(mem, ptr, value);
+
+console.log(`\`Cons "small str" Nil\` is: ${show(readStrConsList(mem, ptr))}`);
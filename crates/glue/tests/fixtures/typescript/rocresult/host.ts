@@ -0,0 +1,10 @@
+import { loadRocApp } from "./load.ts";
+import { main_for_host } from "./test_glue/roc_app.ts";
+
+const { exports, mem } = loadRocApp();
+
+const ok = main_for_host(exports, mem, true);
+const err = main_for_host(exports, mem, false);
+
+console.log(`Answer was: ${JSON.stringify(ok)}`);
+console.log(`Answer was: ${JSON.stringify(err)}`);
@@ -0,0 +1,10 @@
+import { loadRocApp } from "./load.ts";
+import { main_for_host } from "./test_glue/roc_app.ts";
+
+const { exports, mem } = loadRocApp();
+
+const small = main_for_host(exports, mem, "Roc");
+const big = main_for_host(exports, mem, "a name which is long enough to be on the heap");
+
+console.log(`Answer was: ${JSON.stringify(small)}`);
+console.log(`Answer was: ${JSON.stringify(big)}`);
@@ -0,0 +1,9 @@
+platform "test-platform"
+    requires {} { main : Str -> Str }
+    exposes []
+    packages {}
+    imports []
+    provides [main_for_host]
+
+main_for_host : Str -> Str
+main_for_host = \name -> main(name)
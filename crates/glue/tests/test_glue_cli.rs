@@ -11,10 +11,14 @@ mod helpers;
 
 #[cfg(test)]
 mod glue_cli_tests {
-    use cli_test_utils::{command::CmdOut, exec_cli::ExecCli};
+    use cli_test_utils::{
+        command::{run_command, CmdOut},
+        exec_cli::ExecCli,
+    };
 
     use crate::helpers::fixtures_dir;
-    use std::path::{Path, PathBuf};
+    use std::path::Path;
+    use std::process::Command;
 
     #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
     const TEST_LEGACY_LINKER: bool = true;
@@ -48,9 +52,7 @@ mod glue_cli_tests {
 
                     generate_glue_for(&dir, std::iter::empty());
 
-                    fn validate<'a, I: IntoIterator<Item = &'a str> + std::fmt::Debug>(dir: PathBuf, args: I) {
-                        let out = run_app(&dir.join("app.roc"), args);
-
+                    fn validate(out: CmdOut) {
                         assert!(out.status.success());
                         let ignorable = "🔨 Building host ...\n";
                         let stderr = out.stderr.replacen(ignorable, "", 1);
@@ -67,15 +69,30 @@ mod glue_cli_tests {
                     let test_name_str = stringify!($test_name);
 
                     // TODO after #5924 is fixed; remove this
-                    let skip_on_linux_surgical_linker = ["rust_closures", "rust_option", "rust_nullable_wrapped", "rust_nullable_unwrapped", "rust_nonnullable_unwrapped", "rust_enumeration", "rust_nested_record", "rust_advanced_recursive_union"];
-
-                    // Validate linux with the default linker.
-                    if !(cfg!(target_os = "linux") && (skip_on_linux_surgical_linker.contains(&test_name_str))) {
-                        validate(dir.clone(), ["--build-host", "--suppress-build-host-warning"]);
-                    }
-
-                    if TEST_LEGACY_LINKER {
-                        validate(dir, ["--build-host", "--suppress-build-host-warning", "--linker=legacy"]);
+                    let skip_on_linux_surgical_linker = ["rust_closures", "rust_option", "rust_nullable_wrapped", "rust_nullable_unwrapped", "rust_nonnullable_unwrapped", "rust_enumeration", "rust_niche_filled_union", "rust_nested_record", "rust_advanced_recursive_union"];
+
+                    match $fixture_dir.split('/').next().unwrap() {
+                        // These hosts load the Roc app themselves, so there's no linker to choose.
+                        "python" => {
+                            // The app's shared library leaves roc_alloc and friends undefined,
+                            // for libhost.so to provide, and only Linux allows linking it that way.
+                            if cfg!(target_os = "linux") {
+                                validate(run_python_host(&dir));
+                            }
+                        }
+                        "typescript" => validate(run_typescript_host(&dir)),
+                        _ => {
+                            let app = dir.join("app.roc");
+
+                            // Validate linux with the default linker.
+                            if !(cfg!(target_os = "linux") && (skip_on_linux_surgical_linker.contains(&test_name_str))) {
+                                validate(run_app(&app, ["--build-host", "--suppress-build-host-warning"]));
+                            }
+
+                            if TEST_LEGACY_LINKER {
+                                validate(run_app(&app, ["--build-host", "--suppress-build-host-warning", "--linker=legacy"]));
+                            }
+                        }
                     }
                 }
             )*
@@ -100,6 +117,7 @@ mod glue_cli_tests {
         rust_basic_record:"rust/basic-record" => "Record was: MyRcd { b: 42, a: 1995 }\n",
         rust_nested_record:"rust/nested-record" => "Record was: Outer { y: \"foo\", z: [1, 2], x: Inner { b: 24.0, a: 5 } }\n",
         rust_enumeration:"rust/enumeration" => "tag_union was: MyEnum::Foo, Bar is: MyEnum::Bar, Baz is: MyEnum::Baz\n",
+        rust_niche_filled_union:"rust/niche-filled-union" => "tag_union was: MaybeBool::Just_true, Nothing is: MaybeBool::Nothing\n",
         rust_single_tag_union:"rust/single-tag-union" => indoc!(r#"
             tag_union was: SingleTagUnion::OneTag
         "#),
@@ -169,6 +187,61 @@ mod glue_cli_tests {
         c_hello_world:"c/hello-world" => indoc!(r#"
             main_for_host = 42
         "#),
+        python_basic_record:"python/basic-record" => "Record was: MyRcd { a: 1995, b: 42 }\n",
+        python_nested_record:"python/nested-record" => "Record was: Outer { x: Inner { a: 5, b: 24.0 }, y: 'foo', z: [1, 2] }\n",
+        python_union_without_padding:"python/union-without-padding" => indoc!(r#"
+            tag_union was: NonRecursive.Foo('This is a test')
+            `Foo "A long enough string to not be small"` is: NonRecursive.Foo('A long enough string to not be small')
+            `Bar 123` is: NonRecursive.Bar(123)
+            `Baz` is: NonRecursive.Baz
+        "#),
+        python_rocresult:"python/rocresult" => indoc!(r#"
+            Answer was: RocOk(RocStr('Hello World!'))
+            Answer was: RocErr(42)
+        "#),
+        python_string_argument:"python/string-argument" => indoc!(r#"
+            Answer was: 'Hello, Roc! This string is too long to be a small string.'
+            Answer was: 'Hello, a name which is long enough to be on the heap! This string is too long to be a small string.'
+        "#),
+        typescript_basic_record:"typescript/basic-record" => "Record was: MyRcd { a: 1995, b: 42 }\n",
+        typescript_enumeration:"typescript/enumeration" => "tag_union was: MyEnum.Foo (2), Bar is: 0, Baz is: 1\n",
+        typescript_nullable_unwrapped:"typescript/nullable-unwrapped" => indoc!(r#"
+            tag_union was: Cons("World!", Cons("Hello ", Nil))
+            `Cons "small str" Nil` is: Cons("small str", Nil)
+        "#),
+        typescript_rocresult:"typescript/rocresult" => indoc!(r#"
+            Answer was: {"tag":"Ok","value":"Hello World!"}
+            Answer was: {"tag":"Err","value":42}
+        "#),
+        typescript_string_argument:"typescript/string-argument" => indoc!(r#"
+            Answer was: "Hello, Roc! This string is too long to be a small string."
+            Answer was: "Hello, a name which is long enough to be on the heap! This string is too long to be a small string."
+        "#),
+    }
+
+    #[test]
+    fn python_glue_rejects_recursive_unions() {
+        let out = generate_glue_expecting_failure("PythonGlue.roc", "rust/basic-recursive-union");
+
+        assert!(
+            out.stderr.contains(
+                "Glue generation failed: PythonGlue does not support recursive tag unions like `Expr` yet."
+            ),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn typescript_glue_rejects_recursive_unions() {
+        let out =
+            generate_glue_expecting_failure("TypeScriptGlue.roc", "rust/basic-recursive-union");
+
+        assert!(
+            out.stderr.contains(
+                "Glue generation failed: TypeScriptGlue does not support recursive tag unions like `Expr` yet."
+            ),
+            "{out}"
+        );
     }
 
     fn check_for_tests(all_fixtures: &mut roc_collections::VecSet<String>) {
@@ -240,6 +313,8 @@ mod glue_cli_tests {
             "rust" => "RustGlue.roc",
             "zig" => "ZigGlue.roc",
             "c" => "CGlue.roc",
+            "python" => "PythonGlue.roc",
+            "typescript" => "TypeScriptGlue.roc",
             unknown_subfolder => panic!("I don't know which glue file to use for tests in the `{}` subfolder! Please add one here!", unknown_subfolder),
         };
 
@@ -267,6 +342,75 @@ mod glue_cli_tests {
         glue_cmd_out
     }
 
+    /// Runs `roc glue` with the given spec on a fixture's platform, expecting it to fail.
+    fn generate_glue_expecting_failure(glue_spec_filename: &str, fixture_dir: &str) -> CmdOut {
+        let glue_spec = fixtures_dir("")
+            .parent()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .join("src")
+            .join(glue_spec_filename);
+        let glue_dir = tempfile::tempdir().unwrap();
+        let platform_module_path = fixtures_dir(fixture_dir).join("platform.roc");
+
+        let glue_cmd_out = ExecCli::new("glue", glue_spec)
+            .add_args([glue_dir.path(), platform_module_path.as_path()])
+            .run();
+
+        glue_cmd_out.assert_nonzero_exit();
+
+        glue_cmd_out
+    }
+
+    /// Builds the app as a shared library, and runs the fixture's host.py on it.
+    /// This needs `python3` and `cc` on the PATH.
+    fn run_python_host(fixture_dir: &Path) -> CmdOut {
+        let build_cmd_out = ExecCli::new(
+            "build", // can't import CMD_BUILD from roc_cli, that would create a cycle
+            fixture_dir.join("app.roc"),
+        )
+        .arg("--lib")
+        .run();
+
+        build_cmd_out.assert_clean_success();
+
+        let mut cc_cmd = Command::new("cc");
+        cc_cmd
+            .current_dir(fixture_dir)
+            .args(["-shared", "-fPIC", "host.c", "-o", "libhost.so"]);
+
+        run_command(cc_cmd, None).assert_clean_success();
+
+        let mut python_cmd = Command::new("python3");
+        python_cmd.current_dir(fixture_dir).arg("host.py");
+
+        run_command(python_cmd, None)
+    }
+
+    /// Builds the app for wasm32, and runs the fixture's host.ts on it.
+    /// This needs a `node` which can strip TypeScript types (v22.6 or later).
+    fn run_typescript_host(fixture_dir: &Path) -> CmdOut {
+        let build_cmd_out = ExecCli::new(
+            "build", // can't import CMD_BUILD from roc_cli, that would create a cycle
+            fixture_dir.join("app.roc"),
+        )
+        .add_args(["--target=wasm32", "--optimize", "--linker=legacy"])
+        .run();
+
+        build_cmd_out.assert_clean_success();
+
+        let mut node_cmd = Command::new("node");
+        node_cmd.current_dir(fixture_dir).args([
+            "--experimental-strip-types",
+            // type stripping prints an ExperimentalWarning to stderr
+            "--no-warnings",
+            "host.ts",
+        ]);
+
+        run_command(node_cmd, None)
+    }
+
     fn run_app<'a, 'b, I: IntoIterator<Item = &'a str> + std::fmt::Debug>(
         app_file_path: &'b Path,
         args: I,
@@ -25,7 +25,6 @@ mod completion;
 mod parse_ast;
 mod semantic_tokens;
 mod tokens;
-mod utils;
 
 use crate::convert::diag::{IntoLspDiagnostic, ProblemFmt};
 
@@ -1,6 +1,7 @@
 use log::{debug, info};
 
-use roc_cli::{annotation_edit, annotation_edits};
+use roc_cli::{annotation_edit, annotation_edits, format_src, format_src_lines, FormatConfig};
+use roc_completion::{format_var_type, word_start};
 use roc_fmt::MigrationFlags;
 use std::collections::HashMap;
 
@@ -28,7 +29,6 @@ use super::{
     annotation_visitor::{find_declaration_at, FoundDeclaration, NotFound},
     parse_ast::Ast,
     semantic_tokens::arrange_semantic_tokens,
-    utils::{format_var_type, is_roc_identifier_char},
     AnalysisResult, AnalyzedModule,
 };
 
@@ -81,38 +81,84 @@ impl DocInfo {
     pub fn get_prefix_at_position(&self, position: Position) -> String {
         let position = position.to_roc_position(&self.line_info);
         let offset = position.offset as usize;
-        let source = &self.source.as_bytes()[..offset];
-        let symbol_len = source
-            .iter()
-            .rev()
-            .take_while(|&a| is_roc_identifier_char(&(*a as char)))
-            .count();
-
-        if symbol_len == 0 {
-            return String::from("");
-        }
 
-        let symbol = &self.source[offset - symbol_len..offset];
+        let start = word_start(&self.source, offset);
 
-        String::from(symbol)
+        self.source
+            .get(start..offset)
+            .unwrap_or_default()
+            .to_string()
     }
 
     pub fn format(&self) -> Option<Vec<TextEdit>> {
         let source = &self.source;
         let arena = &Bump::new();
 
-        let ast = Ast::parse(arena, source).ok()?;
+        // Formatting can only work with a document that parses.
+        Ast::parse(arena, source).ok()?;
+
+        let flags = MigrationFlags {
+            snakify: false,
+            parens_and_commas: false,
+        };
+        let formatted = format_src(arena, source, flags, self.format_config()).ok()?;
+
+        if *source == formatted {
+            None
+        } else {
+            let range = self.whole_document_range();
+            let text_edit = TextEdit::new(range, formatted);
+            Some(vec![text_edit])
+        }
+    }
+
+    /// The `roc-format.toml` settings for this document, so editors format it like `roc format`
+    fn format_config(&self) -> FormatConfig {
+        self.url
+            .to_file_path()
+            .ok()
+            .and_then(|path| FormatConfig::find(&path).ok())
+            .unwrap_or_default()
+    }
+
+    /// Format the lines `range` is on, along with the rest of any top-level defs they're in
+    pub fn format_range(&self, range: Range) -> Option<Vec<TextEdit>> {
+        // A range that ends at the start of a line doesn't include anything on that line.
+        let end_line = if range.end.character == 0 && range.end.line > range.start.line {
+            range.end.line
+        } else {
+            range.end.line + 1
+        };
+
+        self.format_lines(range.start.line..end_line)
+    }
+
+    /// Format the line that was just finished by typing a newline at `position`
+    pub fn format_on_type(&self, position: Position) -> Option<Vec<TextEdit>> {
+        let line = position.line.checked_sub(1)?;
+
+        self.format_lines(line..line + 1)
+    }
+
+    fn format_lines(&self, lines: std::ops::Range<u32>) -> Option<Vec<TextEdit>> {
+        let source = &self.source;
+        let arena = &Bump::new();
+
+        // Formatting can only work with a document that parses.
+        Ast::parse(arena, source).ok()?;
+
         let flags = MigrationFlags {
             snakify: false,
             parens_and_commas: false,
         };
-        let fmt = ast.fmt(flags);
+        let formatted =
+            format_src_lines(arena, source, &[lines], flags, self.format_config()).ok()?;
 
-        if source == fmt.as_str() {
+        if *source == formatted {
             None
         } else {
             let range = self.whole_document_range();
-            let text_edit = TextEdit::new(range, fmt.to_string().to_string());
+            let text_edit = TextEdit::new(range, formatted);
             Some(vec![text_edit])
         }
     }
@@ -201,7 +247,7 @@ impl AnalyzedDocument {
                 .get_doc_for_symbol(&symbol)
         });
 
-        let type_str = format_var_type(var, &mut subs.clone(), module_id, interns);
+        let type_str = format_var_type(var, &mut subs.clone(), *module_id, interns);
 
         let range = region.to_range(self.line_info());
 
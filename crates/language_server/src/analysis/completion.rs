@@ -4,18 +4,19 @@ use log::{debug, warn};
 
 use roc_can::{expr::Declarations, traverse::Visitor};
 use roc_collections::MutMap;
+use roc_completion::{format_var_type, FieldPath};
 use roc_load::docs::{DocDef, ModuleDocumentation};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_region::all::Position;
 use roc_types::{
-    subs::{Subs, Variable},
+    subs::{Content, Subs, Variable},
     types::Alias,
 };
 use tower_lsp::lsp_types::{self, CompletionItem, CompletionItemKind};
 
 use self::visitor::CompletionVisitor;
 
-use super::{utils::format_var_type, ModulesInfo};
+use super::ModulesInfo;
 mod formatting;
 mod visitor;
 
@@ -269,7 +270,7 @@ fn make_completion_item(
     symbol_str: String,
     var: Variable,
 ) -> CompletionItem {
-    let type_str = format_var_type(var, subs, module_id, interns);
+    let type_str = format_var_type(var, subs, *module_id, interns);
     let typ = match subs.get(var).content {
         roc_types::subs::Content::Structure(var) => match var {
             roc_types::subs::FlatType::Apply(_, _) => CompletionItemKind::FUNCTION,
@@ -301,84 +302,6 @@ fn make_completion_item(
     }
 }
 
-/// E.g. a.b.c.d->{variable_name:"a", field:"d", middle_fields:["b","c"]}
-struct RecFieldCompletion {
-    /// name of variable that is a record
-    variable_name: String,
-    field: String,
-    middle_fields: Vec<String>,
-}
-
-/// Finds the types of and names of all the fields of a record.
-/// `var` should be a `Variable` that you know is of type record or else it will return an empty list.
-fn find_record_fields(var: Variable, subs: &mut Subs) -> Vec<(String, Variable)> {
-    let content = subs.get(var);
-    match content.content {
-        roc_types::subs::Content::Structure(typ) => match typ {
-            roc_types::subs::FlatType::Record(fields, ext) => {
-                let field_types = fields.unsorted_iterator(subs, ext);
-
-                match field_types {
-                    Ok(field) => field
-                        .map(|a| (a.0.clone().into(), a.1.into_inner()))
-                        .collect::<Vec<_>>(),
-                    Err(err) => {
-                        warn!("Error getting record field types for completion: {:?}", err);
-                        vec![]
-                    }
-                }
-            }
-            roc_types::subs::FlatType::Tuple(elems, ext) => {
-                let elems = elems.unsorted_iterator(subs, ext);
-
-                match elems {
-                    Ok(elem) => elem.map(|(num, var)| (num.to_string(), var)).collect(),
-                    Err(err) => {
-                        warn!("Error getting tuple elems for completion: {:?}", err);
-                        vec![]
-                    }
-                }
-            }
-
-            _ => {
-                warn!(
-                    "Trying to get field completion for a type that is not a record: {:?}",
-                    typ
-                );
-                vec![]
-            }
-        },
-        roc_types::subs::Content::Error => {
-            //This is caused by typechecking our partially typed variable name causing the typechecking to be confused as the type of the parent variable
-            //TODO! ideally i could recover using some previous typecheck result that isn't broken
-            warn!("Variable type of record was of type 'error', cannot access field",);
-            vec![]
-        }
-        _ => {
-            warn!(
-                "Variable before field was unsupported type: {:?}",
-                subs.dbg(var)
-            );
-            vec![]
-        }
-    }
-}
-
-/// Splits a completion prefix for a field into its components.
-/// E.g. a.b.c.d->{variable_name:"a",middle_fields:["b","c"],field:"d"}
-fn get_field_completion_parts(symbol_prefix: &str) -> Option<RecFieldCompletion> {
-    let mut parts = symbol_prefix.split('.').collect::<Vec<_>>();
-    let field = parts.pop().unwrap_or("").to_string();
-    let variable_name = parts.remove(0).to_string();
-    // Now that we have the head and tail removed this is all the intermediate fields.
-    let middle_fields = parts.into_iter().map(ToString::to_string).collect();
-
-    Some(RecFieldCompletion {
-        variable_name,
-        field,
-        middle_fields,
-    })
-}
 pub fn field_completion(
     position: Position,
     symbol_prefix: String,
@@ -387,42 +310,29 @@ pub fn field_completion(
     subs: &mut Subs,
     module_id: &ModuleId,
 ) -> Option<Vec<CompletionItem>> {
-    let RecFieldCompletion {
-        variable_name,
-        field,
-        middle_fields,
-    } = get_field_completion_parts(&symbol_prefix)?;
+    let path = FieldPath::parse(&symbol_prefix)?;
 
-    debug!(
-        "Getting record field completions: variable: {:?} field: {:?} middle: {:?} ",
-        variable_name, field, middle_fields
-    );
+    debug!("Getting record field completions: {:?}", path);
 
     // We get completions here, but all we really want is the info about the variable that
     // is the first part of our record completion.
     // We are completing the full name of the variable so we should only have one match.
-    let completion = get_completions(position, declarations, variable_name, interns)
-        .into_iter()
-        .map(|(symbol, var)| (symbol.as_str(interns).to_string(), var))
-        .next()?;
-
-    // If we have a type that has nested records we could have a completion prefix like: "var.field1.field2.fi".
-    // If the document isn't fully typechecked we won't know what the type of field2 is for us to offer
-    // completions based on it's fields. Instead we get the type of "var" and then the type of "field1" within
-    // var's type and then "field2" within field1's type etc etc, until we have the type of the record we are
-    // actually looking for field completions for.
-    let completion_record = middle_fields.iter().fold(completion, |state, chain_field| {
-        let fields_vars = find_record_fields(state.1, subs);
-        fields_vars
-            .into_iter()
-            .find(|type_field| chain_field == &type_field.0)
-            .unwrap_or(state)
-    });
+    let (_, var) = get_completions(
+        position,
+        declarations,
+        path.variable_name.to_string(),
+        interns,
+    )
+    .into_iter()
+    .next()?;
+
+    if let Content::Error = subs.get_content_without_compacting(var) {
+        // This is caused by typechecking our partially typed variable name causing the typechecking to be confused as the type of the parent variable
+        //TODO! ideally i could recover using some previous typecheck result that isn't broken
+        warn!("Variable type of record was of type 'error', cannot access field");
+    }
 
-    let field_completions: Vec<_> = find_record_fields(completion_record.1, subs)
-        .into_iter()
-        .filter(|(str, _)| str.starts_with(&field.to_string()))
-        .collect();
+    let field_completions = path.completions(var, subs);
 
     let field_completions =
         make_completion_items_string(subs, module_id, interns, field_completions);
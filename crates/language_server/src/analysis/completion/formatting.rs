@@ -1,10 +1,11 @@
+use roc_completion::format_var_type;
 use roc_load::docs::ModuleDocumentation;
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 
 use roc_types::subs::Variable;
 use tower_lsp::lsp_types::{Documentation, MarkupContent, MarkupKind};
 
-use crate::analysis::{utils::format_var_type, ModulesInfo};
+use crate::analysis::ModulesInfo;
 
 fn get_module_exposed_list(
     module_id: &ModuleId,
@@ -16,7 +17,7 @@ fn get_module_exposed_list(
         let items = exposed
             .iter()
             .map(|(symbol, var)| {
-                let var_str = format_var_type(*var, subs, module_id, interns);
+                let var_str = format_var_type(*var, subs, *module_id, interns);
                 format!("{0}: {1}", symbol.as_str(interns), var_str)
             })
             .collect::<Vec<_>>();
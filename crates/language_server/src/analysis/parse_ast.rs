@@ -1,5 +1,4 @@
 use bumpalo::Bump;
-use roc_fmt::{Buf, MigrationFlags};
 use roc_parse::{
     ast::{Defs, Header, SpacesBefore},
     header::parse_module_defs,
@@ -7,12 +6,8 @@ use roc_parse::{
 };
 use roc_region::all::Loc;
 
-use self::format::FormattedAst;
-
 use super::tokens::{IterTokens, Token};
 
-mod format;
-
 pub struct Ast<'a> {
     arena: &'a Bump,
     module: SpacesBefore<'a, Header<'a>>,
@@ -40,18 +35,6 @@ impl<'a> Ast<'a> {
         })
     }
 
-    pub fn fmt(&self, flags: MigrationFlags) -> FormattedAst<'a> {
-        let mut buf = Buf::new_in(self.arena, flags);
-
-        roc_fmt::header::fmt_header(&mut buf, &self.module);
-
-        roc_fmt::def::fmt_defs(&mut buf, &self.defs, 0);
-
-        buf.fmt_end_of_file();
-
-        FormattedAst::new(buf)
-    }
-
     pub fn semantic_tokens(&self) -> impl IntoIterator<Item = Loc<Token>> + '_ {
         let header_tokens = self.module.item.iter_tokens(self.arena);
         let body_tokens = self.defs.iter_tokens(self.arena);
@@ -192,6 +192,16 @@ impl Registry {
         document.format()
     }
 
+    pub async fn range_formatting(&self, url: &Url, range: Range) -> Option<Vec<TextEdit>> {
+        let document = self.document_info_by_url(url).await?;
+        document.format_range(range)
+    }
+
+    pub async fn on_type_formatting(&self, url: &Url, position: Position) -> Option<Vec<TextEdit>> {
+        let document = self.document_info_by_url(url).await?;
+        document.format_on_type(position)
+    }
+
     pub async fn semantic_tokens(&self, url: &Url) -> Option<SemanticTokensResult> {
         let document = self.document_info_by_url(url).await?;
         document.semantic_tokens()
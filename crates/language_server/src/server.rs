@@ -83,6 +83,15 @@ impl RocServer {
                 work_done_progress: None,
             },
         };
+        let document_range_formatting_provider = DocumentRangeFormattingOptions {
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        };
+        let document_on_type_formatting_provider = DocumentOnTypeFormattingOptions {
+            first_trigger_character: "\n".to_string(),
+            more_trigger_character: None,
+        };
         let semantic_tokens_provider =
             SemanticTokensServerCapabilities::SemanticTokensOptions(SemanticTokensOptions {
                 work_done_progress_options: WorkDoneProgressOptions {
@@ -109,6 +118,10 @@ impl RocServer {
             hover_provider: Some(hover_provider),
             definition_provider: Some(OneOf::Right(definition_provider)),
             document_formatting_provider: Some(OneOf::Right(document_formatting_provider)),
+            document_range_formatting_provider: Some(OneOf::Right(
+                document_range_formatting_provider,
+            )),
+            document_on_type_formatting_provider: Some(document_on_type_formatting_provider),
             semantic_tokens_provider: Some(semantic_tokens_provider),
             completion_provider: Some(completion_provider),
             code_action_provider: Some(code_action_provider),
@@ -316,6 +329,47 @@ impl LanguageServer for RocServer {
         unwind_async(self.state.registry.formatting(&text_document.uri)).await
     }
 
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let DocumentRangeFormattingParams {
+            text_document,
+            range,
+            options: _,
+            work_done_progress_params: _,
+        } = params;
+
+        unwind_async(
+            self.state
+                .registry
+                .range_formatting(&text_document.uri, range),
+        )
+        .await
+    }
+
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let DocumentOnTypeFormattingParams {
+            text_document_position:
+                TextDocumentPositionParams {
+                    text_document,
+                    position,
+                },
+            ch: _,
+            options: _,
+        } = params;
+
+        unwind_async(
+            self.state
+                .registry
+                .on_type_formatting(&text_document.uri, position),
+        )
+        .await
+    }
+
     async fn semantic_tokens_full(
         &self,
         params: SemanticTokensParams,
@@ -801,4 +855,36 @@ mod tests {
         "#]]
         .assert_debug_eq(&edit);
     }
+
+    #[tokio::test]
+    async fn test_range_formatting() {
+        let doc = indoc! {r#"
+            module []
+
+            a =
+                    1
+
+            b =
+                    2
+        "#};
+        let (inner, url) = test_setup(doc.to_string()).await;
+
+        // The range is only part of `a`, but all of `a` gets formatted, and nothing else.
+        let range = Range::new(Position::new(3, 0), Position::new(3, 9));
+        let edits = inner.registry.range_formatting(&url, range).await.unwrap();
+
+        assert_eq!(edits.len(), 1);
+        assert_eq!(
+            edits[0].new_text,
+            indoc! {r#"
+                module []
+
+                a =
+                    1
+
+                b =
+                        2
+            "#}
+        );
+    }
 }
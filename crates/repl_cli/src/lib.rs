@@ -1,30 +1,41 @@
 //! Command Line Interface (CLI) functionality for the Read-Evaluate-Print-Loop (REPL).
 mod cli_gen;
+mod platform;
 
 use bumpalo::Bump;
 use const_format::concatcp;
 use roc_load::MonomorphizedModule;
 use roc_mono::ir::OptLevel;
-use roc_repl_eval::gen::Problems;
+use roc_repl_eval::gen::{Problems, ReplPlatform};
 use roc_repl_ui::colors::{CYAN, END_COL};
+use roc_repl_ui::completion::{Completion, CompletionContext};
 use roc_repl_ui::repl_state::{ReplAction, ReplState};
-use roc_repl_ui::{format_output, is_incomplete, CONT_PROMPT, PROMPT, SHORT_INSTRUCTIONS, TIPS};
+use roc_repl_ui::{
+    format_defs, format_output, format_timings, is_incomplete, CONT_PROMPT, PROMPT,
+    SHORT_INSTRUCTIONS, TIPS,
+};
 use roc_reporting::report::{
     strip_colors, to_file_problem_report_string, ANSI_STYLE_CODES, DEFAULT_PALETTE,
 };
 use roc_target::Target;
+use rustyline::completion::{Completer, Pair};
 use rustyline::highlight::{Highlighter, PromptInfo};
+use rustyline::hint::{Hint, Hinter};
 use rustyline::validate::{self, ValidationContext, ValidationResult, Validator};
-use rustyline::Config;
-use rustyline_derive::{Completer, Helper, Hinter};
+use rustyline::{Config, Context};
+use rustyline_derive::Helper;
 use std::any::Any;
 use std::backtrace::Backtrace;
 use std::borrow::Cow;
+use std::cell::RefCell;
 use std::panic::{AssertUnwindSafe, PanicInfo};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
 use target_lexicon::Triple;
 
 use crate::cli_gen::eval_llvm;
+use crate::platform::run_on_platform;
 
 pub const WELCOME_MESSAGE: &str = concatcp!(
     "\n  The rockin' ",
@@ -35,10 +46,16 @@ pub const WELCOME_MESSAGE: &str = concatcp!(
     "\n\n"
 );
 
-#[derive(Completer, Helper, Hinter, Default)]
+/// Grey, for the type hints shown after the cursor
+const HINT_COLOR: &str = "\u{001b}[90m";
+
+#[derive(Helper, Default)]
 pub struct ReplHelper {
     validator: InputValidator,
     state: ReplState,
+    /// Type checking the past defs for completions takes a while, so it's done once after each
+    /// step rather than on every keypress.
+    completion_context: RefCell<Option<CompletionContext>>,
 }
 
 static BACKTRACE: OnceLock<Arc<Mutex<Option<String>>>> = OnceLock::new();
@@ -55,7 +72,9 @@ fn panic_hook(_: &PanicInfo) {
     }
 }
 
-pub fn main(has_color: bool, has_header: bool) -> i32 {
+/// Run the REPL. With a platform, expressions the platform can run (like `Stdout.line! "Hi"`)
+/// are built into an app for it and run, rather than evaluated.
+pub fn main(has_color: bool, has_header: bool, opt_platform: Option<PathBuf>) -> i32 {
     use rustyline::error::ReadlineError;
     use rustyline::Editor;
 
@@ -88,7 +107,18 @@ pub fn main(has_color: bool, has_header: bool) -> i32 {
     };
     let mut editor =
         Editor::<ReplHelper>::with_config(Config::builder().color_mode(editor_color_mode).build());
-    let repl_helper = ReplHelper::default();
+    let mut repl_helper = ReplHelper::default();
+
+    if let Some(platform_path) = opt_platform {
+        match ReplPlatform::new(platform_path) {
+            Ok(platform) => repl_helper.state = ReplState::with_platform(platform),
+            Err(message) => {
+                eprintln!("{message}");
+                return 1;
+            }
+        }
+    }
+
     editor.set_helper(Some(repl_helper));
     let target = Triple::host().into();
     let mut arena = Bump::new();
@@ -100,26 +130,70 @@ pub fn main(has_color: bool, has_header: bool) -> i32 {
 
                 editor.add_history_entry(line);
 
-                let repl_state = &mut editor
-                    .helper_mut()
-                    .expect("Editor helper was not set")
-                    .state;
+                let repl_helper = editor.helper_mut().expect("Editor helper was not set");
+
+                // The past defs may be about to change, so the completions for them will too.
+                repl_helper.completion_context.take();
+
+                let repl_state = &mut repl_helper.state;
 
                 arena.reset();
 
+                let compile_start = Instant::now();
                 let action = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     repl_state.step(&arena, line, target, DEFAULT_PALETTE)
                 }))
                 .unwrap_or_else(|e| notify_repl_panic(target, e));
+                let compile_time = compile_start.elapsed();
 
                 match action {
                     ReplAction::Eval { opt_mono, problems } => {
+                        let eval_start = Instant::now();
                         let output = evaluate(opt_mono, problems, target);
+                        let eval_time = eval_start.elapsed();
+
                         // If there was no output, don't print a blank line!
                         // (This happens for something like a type annotation.)
                         if !output.is_empty() {
                             println!("{}", strip_colors_if_necessary(&output));
                         }
+
+                        if repl_state.is_timing() {
+                            println!("{}", format_timings(compile_time, eval_time));
+                        }
+                    }
+                    ReplAction::Run { app_src, problems } => {
+                        let output = format_output(ANSI_STYLE_CODES, None, problems);
+
+                        if !output.is_empty() {
+                            println!("{}", strip_colors_if_necessary(&output));
+                        }
+
+                        let run_start = Instant::now();
+
+                        match run_on_platform(app_src, target) {
+                            Ok(0) => {}
+                            Ok(exit_code) => println!("The app exited with code {exit_code}."),
+                            Err(message) => println!("{}", strip_colors_if_necessary(&message)),
+                        }
+
+                        if repl_state.is_timing() {
+                            println!("{}", format_timings(compile_time, run_start.elapsed()));
+                        }
+                    }
+                    ReplAction::Type {
+                        opt_output,
+                        problems,
+                    } => {
+                        let output = format_output(ANSI_STYLE_CODES, opt_output, problems);
+                        println!("{}", strip_colors_if_necessary(&output));
+                    }
+                    ReplAction::Defs(defs) => {
+                        let output = format_defs(ANSI_STYLE_CODES, &defs);
+                        println!("{}", strip_colors_if_necessary(&output));
+                    }
+                    ReplAction::Message(message) => {
+                        println!("{message}");
                     }
                     ReplAction::Exit => {
                         return 0;
@@ -209,11 +283,98 @@ impl Validator for InputValidator {
     }
 }
 
+impl ReplHelper {
+    fn complete_word(&self, line: &str, pos: usize) -> Option<(usize, Vec<Completion>)> {
+        let mut opt_context = self.completion_context.borrow_mut();
+
+        if opt_context.is_none() {
+            *opt_context = self.state.completion_context(Triple::host().into());
+        }
+
+        opt_context
+            .as_mut()
+            .map(|context| context.complete(line, pos))
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let (start, completions) = self.complete_word(line, pos).unwrap_or_default();
+        let candidates = completions
+            .into_iter()
+            .map(|Completion { name, type_str }| Pair {
+                display: if type_str.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{name} : {type_str}")
+                },
+                replacement: name,
+            })
+            .collect();
+
+        Ok((start, candidates))
+    }
+}
+
+/// When there's only one way to complete the word at the end of the line, the rest of it
+/// along with its type, like ` : List a, (a -> b) -> List b` after `List.map`.
+pub struct TypeHint {
+    display: String,
+    rest_of_word: String,
+}
+
+impl Hint for TypeHint {
+    fn display(&self) -> &str {
+        &self.display
+    }
+
+    fn completion(&self) -> Option<&str> {
+        (!self.rest_of_word.is_empty()).then_some(self.rest_of_word.as_str())
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = TypeHint;
+
+    fn hint(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> Option<TypeHint> {
+        if pos < line.len() || line.ends_with(char::is_whitespace) {
+            return None;
+        }
+
+        let (start, completions) = self.complete_word(line, pos)?;
+        let [Completion { name, type_str }] = completions.as_slice() else {
+            return None;
+        };
+
+        if type_str.is_empty() {
+            return None;
+        }
+
+        let rest_of_word = name.strip_prefix(&line[start..pos])?.to_string();
+
+        Some(TypeHint {
+            display: format!("{rest_of_word} : {type_str}"),
+            rest_of_word,
+        })
+    }
+}
+
 impl Highlighter for ReplHelper {
     fn has_continuation_prompt(&self) -> bool {
         true
     }
 
+    fn highlight_hint<'h>(&self, hint: &'h str) -> Cow<'h, str> {
+        format!("{HINT_COLOR}{hint}{END_COL}").into()
+    }
+
     fn highlight_prompt<'b, 's: 'b, 'p: 'b>(
         &'s self,
         prompt: &'p str,
@@ -0,0 +1,155 @@
+//! Running expressions on a platform, for `roc repl --platform`.
+use bumpalo::Bump;
+use roc_build::link::{LinkType, LinkingStrategy};
+use roc_build::program::{
+    build_str, standard_load_config, BuildFileError, BuildOrdering, BuiltFile, CodeGenBackend,
+    CodeGenOptions,
+};
+use roc_gen_llvm::llvm::build::LlvmBackendMode;
+use roc_linker::SupportLevel;
+use roc_load::{LoadingProblem, Threading};
+use roc_mono::ir::OptLevel;
+use roc_packaging::cache::{self, RocCacheDir};
+use roc_target::Target;
+use std::path::PathBuf;
+
+/// Build the app the REPL generated for an expression into an executable for its platform,
+/// and run it. The app writes to the terminal directly; like `roc dev`, we render any
+/// `expect`s that fail while it runs. Returns the app's exit code.
+pub fn run_on_platform(app_src: &str, target: Target) -> Result<i32, String> {
+    let arena = Bump::new();
+    let out_dir = tempfile::tempdir()
+        .map_err(|err| format!("I couldn't create a directory to build the app in: {err}"))?;
+
+    let code_gen_options = CodeGenOptions {
+        backend: CodeGenBackend::Llvm(LlvmBackendMode::BinaryWithExpect),
+        opt_level: OptLevel::Normal,
+        emit_debug_info: false,
+        emit_llvm_ir: false,
+        fuzz: false,
+        trace_clones: false,
+        profile_alloc: false,
+        check_refcounts: false,
+        sanitizer: None,
+    };
+
+    let linking_strategy = match roc_linker::support_level(LinkType::Executable, target) {
+        SupportLevel::Full => LinkingStrategy::Surgical,
+        SupportLevel::Wip | SupportLevel::None => LinkingStrategy::Legacy,
+    };
+
+    let load_config = standard_load_config(
+        target,
+        BuildOrdering::BuildIfChecks,
+        Threading::AllAvailable,
+        code_gen_options.opt_level,
+    );
+
+    let built = build_str(
+        &arena,
+        target,
+        PathBuf::from("replfile.roc"),
+        arena.alloc_str(app_src),
+        PathBuf::from("."),
+        code_gen_options,
+        LinkType::Executable,
+        linking_strategy,
+        false,
+        false,
+        RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
+        load_config,
+        Some(&out_dir.path().join("repl_app")),
+    );
+
+    match built {
+        Ok(BuiltFile {
+            binary_path,
+            expect_metadata,
+            ..
+        }) => run_with_expects(&arena, binary_path, expect_metadata),
+        Err(BuildFileError::LoadingProblem(LoadingProblem::FormattedReport(report, _))) => {
+            Err(report)
+        }
+        Err(BuildFileError::LoadingProblem(problem)) => Err(format!(
+            "I couldn't build the app for the platform: {problem:?}"
+        )),
+        Err(BuildFileError::ErrorModule { .. }) => {
+            Err("I couldn't build the app for the platform, because it has errors.".to_string())
+        }
+    }
+}
+
+#[cfg(target_family = "unix")]
+fn run_with_expects(
+    arena: &Bump,
+    binary_path: PathBuf,
+    expect_metadata: roc_load::ExpectMetadata,
+) -> Result<i32, String> {
+    use roc_repl_expect::run::{ChildProcessMsg, ExpectMemory};
+    use std::sync::{atomic::AtomicBool, Arc};
+
+    let roc_load::ExpectMetadata {
+        mut expectations,
+        interns,
+        layout_interner,
+    } = expect_metadata;
+
+    // The app finds this buffer by its parent's process id, which is ours.
+    let shm_name = format!("/roc_expect_buffer_{}", std::process::id());
+    let mut memory = ExpectMemory::create_or_reuse_mmap(&shm_name);
+
+    let layout_interner = layout_interner.into_global();
+
+    let sigchld = Arc::new(AtomicBool::new(false));
+    let signal_id = signal_hook::flag::register(signal_hook::consts::SIGCHLD, Arc::clone(&sigchld))
+        .map_err(|err| format!("I couldn't listen for the app exiting: {err}"))?;
+
+    let result = std::process::Command::new(&binary_path)
+        .spawn()
+        .map_err(|err| format!("I couldn't run the app: {err}"))
+        .and_then(|mut child| loop {
+            match memory.wait_for_child(sigchld.clone()) {
+                ChildProcessMsg::Terminate => {
+                    let status = child
+                        .wait()
+                        .map_err(|err| format!("I couldn't wait for the app to exit: {err}"))?;
+
+                    // Without an exit code, the app was killed by a signal.
+                    break Ok(status.code().unwrap_or(1));
+                }
+                ChildProcessMsg::Expect => {
+                    let mut writer = std::io::stdout();
+
+                    roc_repl_expect::run::render_expects_in_memory(
+                        &mut writer,
+                        arena,
+                        &mut expectations,
+                        &interns,
+                        &layout_interner,
+                        &memory,
+                    )
+                    .map_err(|err| format!("I couldn't report a failed expect: {err}"))?;
+
+                    memory.reset();
+                }
+            }
+        });
+
+    signal_hook::low_level::unregister(signal_id);
+
+    result
+}
+
+#[cfg(not(target_family = "unix"))]
+fn run_with_expects(
+    _arena: &Bump,
+    binary_path: PathBuf,
+    _expect_metadata: roc_load::ExpectMetadata,
+) -> Result<i32, String> {
+    // Failed expects are reported through shared memory, which we only set up on unix.
+    let status = std::process::Command::new(&binary_path)
+        .status()
+        .map_err(|err| format!("I couldn't run the app: {err}"))?;
+
+    Ok(status.code().unwrap_or(1))
+}
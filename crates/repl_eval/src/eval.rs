@@ -1423,7 +1423,7 @@ fn byte_to_ast<'a>(env: &mut Env<'a, '_>, value: u8, content: &Content) -> Expr<
                 }
                 FlatType::TagUnion(tags, _) => {
                     // anything with fewer tags is not a byte
-                    debug_assert!(tags.len() > 2);
+                    debug_assert!(tags.len() > 1);
 
                     let tags_vec: std::vec::Vec<(TagName, std::vec::Vec<Variable>)> = tags
                         .unsorted_iterator(env.subs, TagExt::Any(Variable::EMPTY_TAG_UNION))
@@ -1436,7 +1436,7 @@ fn byte_to_ast<'a>(env: &mut Env<'a, '_>, value: u8, content: &Content) -> Expr<
                             env.subs,
                             env.arena,
                         );
-                        union_sorted_tags_pub(&mut layout_env, tags_vec, None)
+                        union_sorted_tags_pub(&mut layout_env, tags_vec.clone(), None)
                     };
 
                     match union_variant {
@@ -1446,6 +1446,41 @@ fn byte_to_ast<'a>(env: &mut Env<'a, '_>, value: u8, content: &Content) -> Expr<
                             let loc_tag_expr = Loc::at_zero(tag_expr);
                             Expr::Apply(env.arena.alloc(loc_tag_expr), &[], CalledVia::Space)
                         }
+                        UnionVariant::NicheFilled { tag_names, niche } => {
+                            let tag_id = niche.tag_id_of(value);
+                            let tag_name = tag_names[tag_id as usize].expect_tag_ref();
+                            let tag_expr = tag_name_to_expr(env, tag_name);
+                            let loc_tag_expr = &*arena.alloc(Loc::at_zero(tag_expr));
+
+                            match niche.payload(tag_id) {
+                                None => Expr::Apply(loc_tag_expr, &[], CalledVia::Space),
+                                Some(payload_layout) => {
+                                    // the payload is stored as an offset from the tag's first value
+                                    let payload_value = value - niche.start(tag_id);
+
+                                    let (_, payload_vars) =
+                                        tags_vec.iter().find(|(name, _)| name == tag_name).unwrap();
+                                    let content =
+                                        env.subs.get_content_without_compacting(payload_vars[0]);
+
+                                    let payload_expr =
+                                        match env.layout_cache.get_repr(payload_layout) {
+                                            LayoutRepr::Builtin(Builtin::Bool) => {
+                                                bool_to_ast(env, payload_value != 0, content)
+                                            }
+                                            _ => byte_to_ast(env, payload_value, content),
+                                        };
+
+                                    let payload = &*arena.alloc(Loc::at_zero(payload_expr));
+
+                                    Expr::Apply(
+                                        loc_tag_expr,
+                                        arena.alloc([payload]),
+                                        CalledVia::Space,
+                                    )
+                                }
+                            }
+                        }
                         _ => unreachable!("invalid union variant for a Byte!"),
                     }
                 }
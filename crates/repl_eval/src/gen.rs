@@ -5,13 +5,21 @@ use roc_problem::Severity;
 use roc_reporting::report::Palette;
 use std::path::PathBuf;
 
+use roc_collections::MutMap;
 use roc_fmt::annotation::Formattable;
 use roc_fmt::annotation::{Newlines, Parens};
-use roc_load::{LoadingProblem, MonomorphizedModule};
-use roc_parse::ast::Expr;
+use roc_load::{LoadedModule, LoadingProblem, MonomorphizedModule};
+use roc_module::symbol::{Interns, ModuleId};
+use roc_mono::ir::OptLevel;
+use roc_parse::ast::{Expr, ExtractSpaces, Header, SpacesBefore, TypeAnnotation};
+use roc_parse::header::parse_header;
+use roc_parse::state::State;
 use roc_region::all::LineInfo;
-use roc_reporting::report::{can_problem, type_problem, RocDocAllocator};
+use roc_reporting::report::{
+    can_problem, to_file_problem_report_string, type_problem, RocDocAllocator,
+};
 use roc_solve::FunctionKind;
+use roc_solve_problem::TypeError;
 use roc_target::Target;
 
 #[derive(Debug)]
@@ -75,28 +83,27 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
             palette,
             threading: Threading::Single,
             exec_mode: ExecutionMode::Executable,
+            opt_level: Some(OptLevel::Normal),
         },
     );
 
     let mut loaded = match loaded {
         Ok(v) => v,
-        Err(LoadMonomorphizedError::ErrorModule(m)) => {
-            todo!(
-                "error while loading module: {:?}",
-                (m.can_problems, m.type_problems)
-            );
-        }
-        Err(LoadMonomorphizedError::LoadingProblem(LoadingProblem::FormattedReport(report, _))) => {
-            return (
-                None,
-                Problems {
-                    errors: vec![report],
-                    warnings: Vec::new(),
-                },
+        Err(LoadMonomorphizedError::ErrorModule(mut module)) => {
+            let problems = collect_problems(
+                &module.interns,
+                &module.sources,
+                &mut module.can_problems,
+                &mut module.type_problems,
+                module_src,
+                bytes_before_expr,
+                palette,
             );
+
+            return (None, problems);
         }
-        Err(e) => {
-            todo!("error while loading module: {:?}", e)
+        Err(LoadMonomorphizedError::LoadingProblem(problem)) => {
+            return (None, loading_problem(problem));
         }
     };
 
@@ -108,6 +115,205 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
         ..
     } = &mut loaded;
 
+    let problems = collect_problems(
+        interns,
+        sources,
+        can_problems,
+        type_problems,
+        module_src,
+        bytes_before_expr,
+        palette,
+    );
+
+    (Some(loaded), problems)
+}
+
+/// Like [compile_to_mono], but stops after type checking. This is enough to find out the
+/// types of the defs and the expr, without generating any code for them.
+pub fn typecheck<'a, 'i, I: Iterator<Item = &'i str>>(
+    arena: &'a Bump,
+    defs: I,
+    expr: &str,
+    target: Target,
+    palette: Palette,
+) -> (Option<LoadedModule>, Problems) {
+    let (bytes_before_expr, module_src) = promote_expr_to_module(arena, defs, expr);
+
+    typecheck_module(arena, bytes_before_expr, module_src, target, palette)
+}
+
+/// A platform for the REPL to run effectful expressions on, as in `roc repl --platform`.
+#[derive(Debug, Clone)]
+pub struct ReplPlatform {
+    /// The platform's main .roc file
+    pub path: PathBuf,
+    /// The one value the platform requires apps to provide, e.g. `main!`
+    pub main: String,
+    /// How many arguments `main` takes, if the platform requires it to be a function
+    pub main_arity: Option<usize>,
+}
+
+impl ReplPlatform {
+    pub fn new(path: PathBuf) -> Result<Self, String> {
+        let src = std::fs::read_to_string(&path)
+            .map_err(|err| format!("I couldn't read the platform {}: {err}", path.display()))?;
+        let path = path.canonicalize().unwrap_or(path);
+        let arena = Bump::new();
+
+        let header = match parse_header(&arena, State::new(src.as_bytes())) {
+            Ok((SpacesBefore { item, .. }, _)) => item,
+            Err(_) => {
+                return Err(format!(
+                    "{} doesn't start with a valid header.",
+                    path.display()
+                ));
+            }
+        };
+
+        let Header::Platform(header) = header else {
+            return Err(format!(
+                "{} isn't a platform; its header should start with `platform`.",
+                path.display()
+            ));
+        };
+
+        match header.requires.item.signatures.items {
+            [signature] => {
+                let typed_ident = signature.value.item();
+                let main_arity = match typed_ident.ann.value.extract_spaces().item {
+                    TypeAnnotation::Function(args, _, _) => Some(args.len()),
+                    _ => None,
+                };
+
+                Ok(Self {
+                    path,
+                    main: typed_ident.ident.value.to_string(),
+                    main_arity,
+                })
+            }
+            _ => Err(format!(
+                "The REPL can only run platforms that require exactly one value from apps, \
+                but {} requires {}.",
+                path.display(),
+                header.requires.item.signatures.items.len()
+            )),
+        }
+    }
+}
+
+/// What the platform's main value should be in the module we type check against a platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlatformMain {
+    /// The expr itself, to find out whether it's something the platform can run.
+    Expr,
+    /// A placeholder, to type check the defs and the expr no matter what they are.
+    Placeholder,
+}
+
+/// Like [typecheck], but with the defs and expr in an app for the given platform,
+/// so that they can import and use the platform's modules.
+pub fn typecheck_on_platform<'a, 'i, I: Iterator<Item = &'i str>>(
+    arena: &'a Bump,
+    platform: &ReplPlatform,
+    defs: I,
+    expr: &str,
+    main: PlatformMain,
+    target: Target,
+    palette: Palette,
+) -> (Option<LoadedModule>, Problems) {
+    let (bytes_before_expr, module_src) =
+        promote_expr_to_platform_app(arena, platform, defs, expr, main);
+
+    typecheck_module(arena, bytes_before_expr, module_src, target, palette)
+}
+
+/// The source of an app that runs `expr` on the given platform, for building into an executable.
+pub fn platform_app_src<'a, 'i, I: Iterator<Item = &'i str>>(
+    arena: &'a Bump,
+    platform: &ReplPlatform,
+    defs: I,
+    expr: &str,
+) -> &'a str {
+    let (_, module_src) =
+        promote_expr_to_platform_app(arena, platform, defs, expr, PlatformMain::Expr);
+
+    module_src
+}
+
+fn typecheck_module<'a>(
+    arena: &'a Bump,
+    bytes_before_expr: usize,
+    module_src: &'a str,
+    target: Target,
+    palette: Palette,
+) -> (Option<LoadedModule>, Problems) {
+    let filename = PathBuf::from("replfile.roc");
+    let src_dir = PathBuf::from(".");
+    let loaded = roc_load::load_and_typecheck_str(
+        arena,
+        filename,
+        module_src,
+        src_dir,
+        None,
+        target,
+        FunctionKind::LambdaSet,
+        roc_reporting::report::RenderTarget::ColorTerminal,
+        RocCacheDir::Persistent(cache::roc_cache_packages_dir().as_path()),
+        palette,
+    );
+
+    let mut loaded = match loaded {
+        Ok(v) => v,
+        Err(problem) => return (None, loading_problem(problem)),
+    };
+
+    let LoadedModule {
+        interns,
+        sources,
+        can_problems,
+        type_problems,
+        ..
+    } = &mut loaded;
+
+    let problems = collect_problems(
+        interns,
+        sources,
+        can_problems,
+        type_problems,
+        module_src,
+        bytes_before_expr,
+        palette,
+    );
+
+    (Some(loaded), problems)
+}
+
+/// Report a module that couldn't be loaded at all, e.g. because a `--platform` file went missing.
+fn loading_problem(problem: LoadingProblem) -> Problems {
+    let report = match problem {
+        LoadingProblem::FormattedReport(report, _) => report,
+        LoadingProblem::FileProblem { filename, error } => {
+            to_file_problem_report_string(filename, error, true)
+        }
+        // load turns everything else into a formatted report before it gets here
+        problem => format!("I couldn't load this module: {problem:?}"),
+    };
+
+    Problems {
+        errors: vec![report],
+        warnings: Vec::new(),
+    }
+}
+
+fn collect_problems(
+    interns: &Interns,
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    module_src: &str,
+    bytes_before_expr: usize,
+    palette: Palette,
+) -> Problems {
     let mut problems = Problems::default();
 
     let errors = &mut problems.errors;
@@ -153,6 +359,16 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
         }
 
         for problem in type_probs {
+            // Like above, but only for warnings about whether a def's name should end in `!`.
+            // Those can be about the def the REPL puts the expr in, which the user never wrote.
+            if let TypeError::UnsuffixedEffectfulFunction(region, _)
+            | TypeError::SuffixedPureFunction(region, _) = problem
+            {
+                if (region.end().offset as usize) < bytes_before_expr {
+                    continue;
+                }
+            }
+
             if let Some(report) = type_problem(&alloc, &line_info, module_path.clone(), problem) {
                 let severity = report.severity;
                 let mut buf = String::new();
@@ -171,7 +387,7 @@ pub fn compile_to_mono<'a, 'i, I: Iterator<Item = &'i str>>(
         }
     }
 
-    (Some(loaded), problems)
+    problems
 }
 
 fn promote_expr_to_module<'a, 'i, I: Iterator<Item = &'i str>>(
@@ -204,3 +420,62 @@ fn promote_expr_to_module<'a, 'i, I: Iterator<Item = &'i str>>(
 
     (bytes_before_expr, buffer.into_bump_str())
 }
+
+fn promote_expr_to_platform_app<'a, 'i, I: Iterator<Item = &'i str>>(
+    arena: &'a Bump,
+    platform: &ReplPlatform,
+    defs: I,
+    expr: &str,
+    main: PlatformMain,
+) -> (usize, &'a str) {
+    const INDENT: &str = "    ";
+
+    let mut buffer = bumpalo::collections::string::String::from_str_in(
+        &format!(
+            "app [{}] {{ pf: platform \"{}\" }}\n\n",
+            platform.main,
+            platform.path.display()
+        ),
+        arena,
+    );
+
+    for line in defs {
+        // don't indent the defs
+        buffer.push_str(line);
+        buffer.push_str("\n\n");
+    }
+
+    match (main, platform.main_arity) {
+        (PlatformMain::Expr, Some(arity)) => {
+            // When the platform wants a function (like `main! : {} => {}`), the expr is its body,
+            // so that you can enter something like `Stdout.line! "Hi!"` and have it run.
+            buffer.push_str("repl_output! = |");
+            buffer.push_str(&vec!["_"; arity].join(", "));
+            buffer.push_str("|\n");
+        }
+        (PlatformMain::Expr, None) | (PlatformMain::Placeholder, _) => {
+            buffer.push_str("repl_output =\n");
+        }
+    }
+
+    let bytes_before_expr = buffer.len();
+
+    for line in expr.lines() {
+        // indent the expr!
+        buffer.push_str(INDENT);
+        buffer.push_str(line);
+        buffer.push('\n');
+    }
+
+    buffer.push('\n');
+    buffer.push_str(&platform.main);
+
+    match (main, platform.main_arity) {
+        (PlatformMain::Expr, Some(_)) => buffer.push_str(" = repl_output!\n"),
+        (PlatformMain::Expr, None) => buffer.push_str(" = repl_output\n"),
+        // `crash` has whatever type the platform requires, so this never causes type errors.
+        (PlatformMain::Placeholder, _) => buffer.push_str(" = crash \"unreachable\"\n"),
+    }
+
+    (bytes_before_expr, buffer.into_bump_str())
+}
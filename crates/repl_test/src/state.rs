@@ -1,9 +1,11 @@
 use bumpalo::Bump;
 use indoc::indoc;
 use roc_repl_cli::{evaluate, ReplHelper};
-use roc_repl_ui::is_incomplete;
+use roc_repl_eval::gen::ReplPlatform;
+use roc_repl_ui::completion::Completion;
 use roc_repl_ui::repl_state::{ReplAction, ReplState};
-use roc_reporting::report::DEFAULT_PALETTE;
+use roc_repl_ui::{format_defs, format_output, is_incomplete};
+use roc_reporting::report::{ANSI_STYLE_CODES, DEFAULT_PALETTE};
 use rustyline::Editor;
 use target_lexicon::Triple;
 
@@ -61,7 +63,7 @@ fn exhaustiveness_problem() {
 
         let expected_error: &str = indoc!(
             r#"
-            ── UNSAFE PATTERN ──────────────────────────────────────────────────────────────
+            ── UNSAFE PATTERN [E0319] ──────────────────────────────────────────────────────
 
             This when does not cover all the possibilities:
 
@@ -107,7 +109,7 @@ fn partial_record_definition() {
         input.push('\n');
         let expected_error: &str = indoc!(
             r#"
-            ── RECORD PARSE PROBLEM ────────────────────────────────────────────────────────
+            ── RECORD PARSE PROBLEM [E0113] ────────────────────────────────────────────────
 
             I am partway through parsing a record, but I got stuck here:
 
@@ -145,6 +147,174 @@ fn standalone_annotation() {
     assert!(matches!(action, ReplAction::Nothing));
 }
 
+#[test]
+fn type_command() {
+    let mut state = ReplState::new();
+
+    complete("x = 5", &mut state, "5 : Num *");
+    command(":type x + 1", &mut state, "x + 1 : Num *");
+    command(":t \\a -> [a]", &mut state, "\\a -> [a] : a -> List a");
+}
+
+#[test]
+fn defs_command() {
+    let mut state = ReplState::new();
+
+    complete("x = 5", &mut state, "5 : Num *");
+    complete("f = \\a -> a", &mut state, "<function> : a -> a");
+    complete("x = \"five\"", &mut state, "\"five\" : Str");
+    command(":defs", &mut state, "x : Str\nf : a -> a");
+}
+
+#[test]
+fn reset_command() {
+    let mut state = ReplState::new();
+    let arena = Bump::new();
+    let target = Triple::host().into();
+
+    complete("x = 5", &mut state, "5 : Num *");
+
+    let action = state.step(&arena, ":reset", target, DEFAULT_PALETTE);
+    assert!(matches!(action, ReplAction::Message(_)));
+
+    let action = state.step(&arena, ":defs", target, DEFAULT_PALETTE);
+    assert!(matches!(action, ReplAction::Message(_)));
+}
+
+#[test]
+fn time_command() {
+    let mut state = ReplState::new();
+    let arena = Bump::new();
+    let target = Triple::host().into();
+
+    assert!(!state.is_timing());
+    state.step(&arena, ":time", target, DEFAULT_PALETTE);
+    assert!(state.is_timing());
+    state.step(&arena, ":time", target, DEFAULT_PALETTE);
+    assert!(!state.is_timing());
+}
+
+#[test]
+fn unknown_command() {
+    let arena = Bump::new();
+    let target = Triple::host().into();
+    let action = ReplState::new().step(&arena, ":frobnicate", target, DEFAULT_PALETTE);
+
+    assert!(matches!(action, ReplAction::Message(message) if message.contains(":frobnicate")));
+}
+
+#[test]
+fn completions() {
+    let mut state = ReplState::new();
+    let arena = Bump::new();
+    let target = Triple::host().into();
+
+    let def = "person = { name: \"Sam\", address: { city: \"Paris\" } }";
+    let action = state.step(&arena, def, target, DEFAULT_PALETTE);
+    assert!(matches!(action, ReplAction::Eval { .. }));
+
+    let mut context = state.completion_context(target).unwrap();
+    let names = |(_, completions): (usize, Vec<Completion>)| {
+        completions
+            .into_iter()
+            .map(|completion| completion.name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(names(context.complete("pers", 4)), ["person"]);
+    assert_eq!(names(context.complete("person.", 7)), ["address", "name"]);
+    assert_eq!(names(context.complete("person.address.c", 16)), ["city"]);
+    assert_eq!(
+        context.complete("Str.is_e", 8),
+        (
+            4,
+            vec![Completion {
+                name: "is_empty".to_string(),
+                type_str: "Str -> Bool".to_string(),
+            }]
+        )
+    );
+}
+
+#[test]
+fn platform_effects() {
+    let platform_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../cli/tests/test-projects/test-platform-effects-zig/main.roc");
+    let platform = ReplPlatform::new(platform_path).unwrap();
+    let mut state = ReplState::with_platform(platform);
+    let arena = Bump::new();
+    let target = Triple::host().into();
+
+    // Pure expressions are still evaluated as usual
+    complete("1 + 1", &mut state, "2 : Num *");
+
+    let action = state.step(&arena, "import pf.Effect", target, DEFAULT_PALETTE);
+    assert!(matches!(action, ReplAction::Nothing));
+
+    // Defs that need the platform aren't evaluated, but we know their types
+    command(
+        "greet! = \\name -> Effect.put_line!(\"Hi, $(name)!\")",
+        &mut state,
+        "greet! : Str => {}",
+    );
+
+    let action = state.step(&arena, "greet!(\"Sam\")", target, DEFAULT_PALETTE);
+    assert!(matches!(action, ReplAction::Run { .. }));
+}
+
+#[test]
+fn platform_missing() {
+    // as if the platform was moved after the REPL started
+    let platform = ReplPlatform {
+        path: std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("missing-platform/main.roc"),
+        main: "main".to_string(),
+        main_arity: None,
+    };
+    let mut state = ReplState::with_platform(platform);
+    let arena = Bump::new();
+    let target = Triple::host().into();
+
+    // Pure expressions don't need the platform
+    complete("1 + 1", &mut state, "2 : Num *");
+
+    match state.step(&arena, "Stdout.line!(\"Hi!\")", target, DEFAULT_PALETTE) {
+        ReplAction::Eval {
+            opt_mono: None,
+            problems,
+        } => {
+            let report =
+                String::from_utf8(strip_ansi_escapes::strip(&problems.errors[0]).unwrap()).unwrap();
+
+            assert!(report.contains("FILE NOT FOUND"), "{report}");
+            assert!(report.contains("missing-platform"), "{report}");
+        }
+        action => panic!("Unexpected action: {:?}", action),
+    }
+}
+
+/// step the given `:type` or `:defs` command (or a def that needs the platform),
+/// then check the output
+/// with ANSI escape codes stripped.
+fn command(input: &str, state: &mut ReplState, expected: &str) {
+    assert!(!is_incomplete(input));
+    let arena = Bump::new();
+    let target = Triple::host().into();
+
+    let output = match state.step(&arena, input, target, DEFAULT_PALETTE) {
+        ReplAction::Type {
+            opt_output,
+            problems,
+        } => format_output(ANSI_STYLE_CODES, opt_output, problems),
+        ReplAction::Defs(defs) => format_defs(ANSI_STYLE_CODES, &defs),
+        action => panic!("Unexpected action: {:?}", action),
+    };
+
+    let escaped =
+        std::string::String::from_utf8(strip_ansi_escapes::strip(output.trim()).unwrap()).unwrap();
+
+    assert_eq!(expected, escaped);
+}
+
 /// validate and step the given input, then check the Result vs the output
 /// with ANSI escape codes stripped.
 fn complete(input: &str, state: &mut ReplState, expected_start: &str) {
@@ -659,7 +659,7 @@ fn too_few_args() {
         "Num.add 2",
         indoc!(
             r"
-                ── TOO FEW ARGS ────────────────────────────────────────────────────────────────
+                ── TOO FEW ARGS [E0316] ────────────────────────────────────────────────────────
 
                 The add function expects 2 arguments, but it got only 1:
 
@@ -680,7 +680,7 @@ fn type_problem_function() {
         "Num.add 1 \"not a num\"",
         indoc!(
             r#"
-                ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+                ── TYPE MISMATCH [E0301] ───────────────────────────────────────────────────────
 
                 This 2nd argument to add has an unexpected type:
 
@@ -706,7 +706,7 @@ fn type_problem_binary_operator() {
         "1 + \"\"",
         indoc!(
             r#"
-                ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+                ── TYPE MISMATCH [E0301] ───────────────────────────────────────────────────────
 
                 This 2nd argument to + has an unexpected type:
 
@@ -732,7 +732,7 @@ fn type_problem_unary_operator() {
         "!\"not a bool\"",
         indoc!(
             r#"
-                ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+                ── TYPE MISMATCH [E0301] ───────────────────────────────────────────────────────
 
                 This 1st argument to ! has an unexpected type:
 
@@ -758,7 +758,7 @@ fn type_problem_string_interpolation() {
         "\"This is not a string -> ${1}\"",
         indoc!(
             r#"
-                ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+                ── TYPE MISMATCH [E0301] ───────────────────────────────────────────────────────
 
                 This argument to this string interpolation has an unexpected type:
 
@@ -784,7 +784,7 @@ fn list_drop_at_negative_index() {
         "List.drop_at [1, 2, 3] -1",
         indoc!(
             r#"
-            ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+            ── TYPE MISMATCH [E0301] ───────────────────────────────────────────────────────
 
             This 2nd argument to drop_at has an unexpected type:
 
@@ -810,7 +810,7 @@ fn list_get_negative_index() {
         "List.get [1, 2, 3] -1",
         indoc!(
             r#"
-            ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+            ── TYPE MISMATCH [E0301] ───────────────────────────────────────────────────────
 
             This 2nd argument to get has an unexpected type:
 
@@ -836,7 +836,7 @@ fn invalid_string_interpolation() {
         "\"${123}\"",
         indoc!(
             r#"
-            ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+            ── TYPE MISMATCH [E0301] ───────────────────────────────────────────────────────
 
             This argument to this string interpolation has an unexpected type:
 
@@ -1135,7 +1135,7 @@ fn parse_problem() {
         "add m n = m + n",
         indoc!(
             r#"
-                ── ARGUMENTS BEFORE EQUALS ─────────────────────────────────────────────────────
+                ── ARGUMENTS BEFORE EQUALS [E0103] ─────────────────────────────────────────────
 
                 I am partway through parsing a definition, but I got stuck here:
 
@@ -1169,7 +1169,7 @@ fn issue_2343_complete_mono_with_shadowed_vars() {
         ),
         indoc!(
             r"
-                ── DUPLICATE NAME ──────────────────────────────────────────────────────────────
+                ── DUPLICATE NAME [E0217] ──────────────────────────────────────────────────────
 
                 The b name is first defined here:
 
@@ -1581,7 +1581,7 @@ fn interpolation_with_nested_interpolation() {
         ),
         indoc!(
             r#"
-                ── SYNTAX PROBLEM ──────────────────────────────────────────────────────────────
+                ── SYNTAX PROBLEM [E0108] ──────────────────────────────────────────────────────
 
                 This string interpolation is invalid:
 
@@ -1597,8 +1597,15 @@ fn interpolation_with_nested_interpolation() {
                 Enter an expression to evaluate, or a definition (like x = 1) to use later.
 
                   - ctrl-v + ctrl-j makes a newline
+                  - tab completes names, like List.ma or a record's fields
                   - :q quits
                   - :help shows this text again
+                  - :type <expr> shows the type of an expression without evaluating it
+                  - :load <file.roc> imports a module from a file, exposing everything it exposes
+                  - :import <module> imports a module from the current directory
+                  - :defs lists the definitions so far, with their types
+                  - :reset clears all definitions
+                  - :time turns on (or off) reporting how long each evaluation takes
             "#
         ),
         // TODO figure out why the tests prints the repl help text at the end, but only after syntax errors or something?
@@ -0,0 +1,166 @@
+//! Completion of the names in scope in the REPL: past defs, the modules they can use (and what
+//! those modules expose), and the fields of records. This follows what the language server
+//! offers in `analysis/completion`, with the REPL's past defs as the document, and shares the
+//! rest of its logic with it through `roc_completion`.
+use roc_can::expr::Declarations;
+use roc_completion::{format_var_type, word_start, FieldPath};
+use roc_load::LoadedModule;
+use roc_module::symbol::Interns;
+use roc_types::subs::Subs;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    pub name: String,
+    pub type_str: String,
+}
+
+/// The past defs, type checked, along with the modules they can see.
+pub struct CompletionContext {
+    loaded: LoadedModule,
+}
+
+impl CompletionContext {
+    pub(crate) fn new(loaded: LoadedModule) -> Self {
+        Self { loaded }
+    }
+
+    /// Completions for the word that ends at `pos` in `line`. Returns where the part of the word
+    /// being completed starts (after its last `.`, if any), and the candidates for that part.
+    pub fn complete(&mut self, line: &str, pos: usize) -> (usize, Vec<Completion>) {
+        let start = word_start(line, pos);
+        let word = &line[start..pos];
+        // After a module name, complete what the module exposes, and otherwise the fields of the
+        // record the word names (like `person.address` in `person.address.ci`).
+        let mut completions = match word.rsplit_once('.') {
+            Some((module_name, prefix)) if module_name.starts_with(char::is_uppercase) => {
+                self.module_completions(module_name, prefix)
+            }
+            Some(_) => FieldPath::parse(word)
+                .map(|path| self.field_completions(&path))
+                .unwrap_or_default(),
+            None => self.unqualified_completions(word),
+        };
+
+        completions.sort_by(|a, b| a.name.cmp(&b.name));
+        completions.dedup_by(|a, b| a.name == b.name);
+
+        let field_start = word.rfind('.').map_or(start, |index| start + index + 1);
+
+        (field_start, completions)
+    }
+
+    /// The past defs and the modules in scope whose names start with `prefix`
+    fn unqualified_completions(&mut self, prefix: &str) -> Vec<Completion> {
+        let home = self.loaded.module_id;
+        let mut completions = Vec::new();
+
+        if let Some((declarations, subs, interns)) = home_decls(&mut self.loaded) {
+            for (loc_symbol, var) in declarations.symbols.iter().zip(&declarations.variables) {
+                let name = loc_symbol.value.as_str(interns);
+
+                if name.starts_with(prefix) && name != "repl_output" {
+                    completions.push(Completion {
+                        name: name.to_string(),
+                        type_str: format_var_type(*var, subs, home, interns),
+                    });
+                }
+            }
+        }
+
+        for module_id in self.loaded.exposes.keys() {
+            let name = module_id.to_ident_str(&self.loaded.interns).as_str();
+
+            if *module_id != home && name.starts_with(prefix) {
+                completions.push(Completion {
+                    name: name.to_string(),
+                    type_str: String::new(),
+                });
+            }
+        }
+
+        completions
+    }
+
+    fn module_completions(&mut self, module_name: &str, prefix: &str) -> Vec<Completion> {
+        let LoadedModule {
+            interns,
+            exposes,
+            typechecked,
+            ..
+        } = &mut self.loaded;
+
+        let Some((module_id, exposed_symbols)) = exposes
+            .iter()
+            .find(|(module_id, _)| module_id.to_ident_str(interns).as_str() == module_name)
+        else {
+            return Vec::new();
+        };
+
+        // We need the subs of the module exposing the symbols, because that's where their
+        // types are.
+        let Some(checked) = typechecked.get_mut(module_id) else {
+            return Vec::new();
+        };
+        let subs = checked.solved_subs.inner_mut();
+
+        exposed_symbols
+            .iter()
+            .filter(|(symbol, _)| symbol.as_str(interns).starts_with(prefix))
+            .map(|(symbol, var)| Completion {
+                name: symbol.as_str(interns).to_string(),
+                type_str: format_var_type(*var, subs, *module_id, interns),
+            })
+            .collect()
+    }
+
+    fn field_completions(&mut self, path: &FieldPath) -> Vec<Completion> {
+        let home = self.loaded.module_id;
+
+        let Some((declarations, subs, interns)) = home_decls(&mut self.loaded) else {
+            return Vec::new();
+        };
+
+        let Some(var) = declarations
+            .symbols
+            .iter()
+            .zip(&declarations.variables)
+            .find_map(|(loc_symbol, var)| {
+                (loc_symbol.value.as_str(interns) == path.variable_name).then_some(*var)
+            })
+        else {
+            return Vec::new();
+        };
+
+        path.completions(var, subs)
+            .into_iter()
+            .map(|(name, field_var)| Completion {
+                name,
+                type_str: format_var_type(field_var, subs, home, interns),
+            })
+            .collect()
+    }
+}
+
+/// The declarations of the REPL's module, the subs their variables are in, and the interns
+/// for printing them
+pub(crate) fn home_decls(
+    loaded: &mut LoadedModule,
+) -> Option<(&Declarations, &mut Subs, &Interns)> {
+    let LoadedModule {
+        module_id,
+        interns,
+        solved,
+        declarations_by_id,
+        typechecked,
+        ..
+    } = loaded;
+
+    // When there's a platform, type checking finishes with the platform's module rather than
+    // ours, so ours is kept along with the other modules that were checked.
+    match typechecked.get_mut(module_id) {
+        Some(checked) => Some((&checked.decls, checked.solved_subs.inner_mut(), interns)),
+        None => declarations_by_id
+            .get(module_id)
+            .map(|declarations| (declarations, solved.inner_mut(), &*interns)),
+    }
+}
@@ -1,6 +1,7 @@
 //! UI functionality, shared between CLI and web, for the Read-Evaluate-Print-Loop (REPL).
 // We don't do anything here related to the terminal (doesn't exist on the web) or LLVM (too big for the web).
 pub mod colors;
+pub mod completion;
 pub mod repl_state;
 
 use bumpalo::Bump;
@@ -10,6 +11,7 @@ use repl_state::{parse_src, ParseOutcome};
 use roc_parse::ast::{Expr, ExtractSpaces, ValueDef};
 use roc_repl_eval::gen::{Problems, ReplOutput};
 use roc_reporting::report::StyleCodes;
+use std::time::Duration;
 
 // TODO add link to repl tutorial (does not yet exist).
 pub const TIPS: &str = concatcp!(
@@ -38,6 +40,17 @@ pub const TIPS: &str = concatcp!(
             "  - ",
             END_COL,
             GREEN,
+            "tab",
+            END_COL,
+            " completes names, like ",
+            GREEN,
+            "List.ma",
+            END_COL,
+            " or a record's fields\n",
+            CYAN,
+            "  - ",
+            END_COL,
+            GREEN,
             ":q",
             END_COL,
             " quits\n",
@@ -49,7 +62,51 @@ pub const TIPS: &str = concatcp!(
             END_COL,
             " shows this text again\n",
         )
-    }
+    },
+    concatcp!(
+        CYAN,
+        "  - ",
+        END_COL,
+        GREEN,
+        ":type <expr>",
+        END_COL,
+        " shows the type of an expression without evaluating it\n",
+        CYAN,
+        "  - ",
+        END_COL,
+        GREEN,
+        ":load <file.roc>",
+        END_COL,
+        " imports a module from a file, exposing everything it exposes\n",
+        CYAN,
+        "  - ",
+        END_COL,
+        GREEN,
+        ":import <module>",
+        END_COL,
+        " imports a module from the current directory\n",
+        CYAN,
+        "  - ",
+        END_COL,
+        GREEN,
+        ":defs",
+        END_COL,
+        " lists the definitions so far, with their types\n",
+        CYAN,
+        "  - ",
+        END_COL,
+        GREEN,
+        ":reset",
+        END_COL,
+        " clears all definitions\n",
+        CYAN,
+        "  - ",
+        END_COL,
+        GREEN,
+        ":time",
+        END_COL,
+        " turns on (or off) reporting how long each evaluation takes\n",
+    )
 );
 
 // For when nothing is entered in the repl
@@ -84,9 +141,17 @@ pub fn is_incomplete(input: &str) -> bool {
                 false
             }
         }
-        ParseOutcome::Empty | ParseOutcome::Help | ParseOutcome::Exit | ParseOutcome::SyntaxErr => {
-            false
-        }
+        ParseOutcome::Empty
+        | ParseOutcome::Help
+        | ParseOutcome::Exit
+        | ParseOutcome::SyntaxErr
+        | ParseOutcome::Type(_)
+        | ParseOutcome::Load(_)
+        | ParseOutcome::Import(_)
+        | ParseOutcome::Reset
+        | ParseOutcome::Defs
+        | ParseOutcome::Time
+        | ParseOutcome::UnknownCommand(_) => false,
     }
 }
 
@@ -130,3 +195,31 @@ pub fn format_output(
 
     buf
 }
+
+/// For `:defs`, one def per line. Values get their types; everything else is shown as written.
+pub fn format_defs(style_codes: StyleCodes, defs: &[ReplOutput]) -> String {
+    let mut buf = String::new();
+
+    for ReplOutput { expr, expr_type } in defs {
+        buf.push('\n');
+        buf.push_str(expr);
+
+        if !expr_type.is_empty() {
+            buf.push_str(style_codes.green);
+            buf.push_str(" : ");
+            buf.push_str(style_codes.reset);
+            buf.push_str(expr_type);
+        }
+    }
+
+    buf
+}
+
+/// For `:time`, how long an evaluation took to compile and then to run.
+pub fn format_timings(compile: Duration, eval: Duration) -> String {
+    format!(
+        "compiled in {:.2} ms, evaluated in {:.2} ms",
+        compile.as_secs_f64() * 1000.0,
+        eval.as_secs_f64() * 1000.0
+    )
+}
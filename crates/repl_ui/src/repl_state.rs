@@ -1,28 +1,47 @@
 use std::path::PathBuf;
 use std::{fs, io};
 
+use crate::completion::{home_decls, CompletionContext};
 use bumpalo::Bump;
-use roc_collections::MutSet;
-use roc_load::MonomorphizedModule;
-use roc_parse::ast::{Defs, Expr, Pattern, StrLiteral, TypeDef, TypeHeader, ValueDef};
+use roc_collections::{MutMap, MutSet};
+use roc_load::{LoadedModule, MonomorphizedModule};
+use roc_parse::ast::{
+    Defs, Expr, Header, Pattern, SpacesBefore, StrLiteral, TypeDef, TypeHeader, ValueDef,
+};
 use roc_parse::expr::parse_repl_defs_and_optional_expr;
+use roc_parse::header::parse_header;
 use roc_parse::parser::EWhen;
 use roc_parse::parser::{EClosure, EExpr, EPattern};
 use roc_parse::state::State;
 use roc_region::all::Loc;
-use roc_repl_eval::gen::{compile_to_mono, Problems};
-use roc_reporting::report::Palette;
+use roc_repl_eval::gen::{
+    compile_to_mono, platform_app_src, typecheck, typecheck_on_platform, PlatformMain, Problems,
+    ReplOutput, ReplPlatform,
+};
+use roc_reporting::report::{Palette, DEFAULT_PALETTE};
 use roc_target::Target;
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
 
+/// Past defs that only compile in an app for the platform (e.g. because they use its modules)
+/// have `needs_platform` set, so that we can leave them out when evaluating pure expressions.
 #[derive(Debug, Clone, PartialEq)]
 enum PastDef {
-    Def { ident: String, src: String },
-    Import(String),
+    Def {
+        ident: String,
+        src: String,
+        needs_platform: bool,
+    },
+    Import {
+        src: String,
+        needs_platform: bool,
+    },
 }
 
 pub struct ReplState {
     past_defs: Vec<PastDef>,
     past_def_idents: MutSet<String>,
+    timing: bool,
+    platform: Option<ReplPlatform>,
 }
 
 impl Default for ReplState {
@@ -38,12 +57,28 @@ pub enum ReplAction<'a> {
         opt_mono: Option<MonomorphizedModule<'a>>,
         problems: Problems,
     },
+    /// Build this app for the platform given to `roc repl --platform`, and run it.
+    /// This is what happens to expressions the platform can run, like `Stdout.line! "Hi"`.
+    Run {
+        app_src: &'a str,
+        problems: Problems,
+    },
+    /// The inferred type of an expression, for `:type`
+    Type {
+        opt_output: Option<ReplOutput>,
+        problems: Problems,
+    },
+    /// The defs entered so far, for `:defs`. Values come with their types; other defs
+    /// (like type aliases and imports) have an empty `expr_type` and their source as the `expr`.
+    Defs(Vec<ReplOutput>),
     Exit,
     Help,
     FileProblem {
         filename: PathBuf,
         error: io::ErrorKind,
     },
+    /// Something to tell the user, for commands like `:reset` that have no other output
+    Message(String),
     Nothing,
 }
 
@@ -52,9 +87,35 @@ impl ReplState {
         Self {
             past_defs: Default::default(),
             past_def_idents: Default::default(),
+            timing: false,
+            platform: None,
+        }
+    }
+
+    /// A REPL that runs the expressions it can on the given platform, and evaluates the rest
+    /// as usual. Past defs can import and use the platform's modules.
+    pub fn with_platform(platform: ReplPlatform) -> Self {
+        Self {
+            platform: Some(platform),
+            ..Self::new()
         }
     }
 
+    /// Whether `:time` has been turned on, meaning the REPL should report how long each
+    /// evaluation took to compile and to run.
+    pub fn is_timing(&self) -> bool {
+        self.timing
+    }
+
+    /// Type check the past defs, so the names in scope (and their types) can be completed.
+    /// This is `None` when the past defs can't be loaded.
+    pub fn completion_context(&self, target: Target) -> Option<CompletionContext> {
+        let arena = Bump::new();
+        let (opt_loaded, _problems) = self.typecheck(&arena, "{}", target, DEFAULT_PALETTE);
+
+        opt_loaded.map(CompletionContext::new)
+    }
+
     pub fn step<'a>(
         &mut self,
         arena: &'a Bump,
@@ -66,6 +127,36 @@ impl ReplState {
         let src: &str = match parse_src(arena, line) {
             ParseOutcome::Empty | ParseOutcome::Help => return ReplAction::Help,
             ParseOutcome::Exit => return ReplAction::Exit,
+            ParseOutcome::Type(expr) => return self.type_of(arena, expr, target, palette),
+            ParseOutcome::Load(filename) => {
+                return self.load(arena, filename, target, palette);
+            }
+            ParseOutcome::Import(module) => {
+                return self.step(arena, &format!("import {module}"), target, palette);
+            }
+            ParseOutcome::Reset => {
+                self.past_defs.clear();
+                self.past_def_idents.clear();
+
+                return ReplAction::Message("Cleared all definitions.".to_string());
+            }
+            ParseOutcome::Defs => return self.defs(arena, target, palette),
+            ParseOutcome::Time => {
+                self.timing = !self.timing;
+
+                let message = if self.timing {
+                    "Timing is on."
+                } else {
+                    "Timing is off."
+                };
+
+                return ReplAction::Message(message.to_string());
+            }
+            ParseOutcome::UnknownCommand(command) => {
+                return ReplAction::Message(format!(
+                    "There's no {command} command. Enter :help to see the commands there are."
+                ));
+            }
             ParseOutcome::Incomplete | ParseOutcome::SyntaxErr => {
                 pending_past_def = None;
 
@@ -117,6 +208,7 @@ impl ReplState {
                                     self.add_past_def(
                                         ident.trim_end().to_string(),
                                         line[td.byte_range()].to_string(),
+                                        false,
                                     );
 
                                     // Return early without running eval, since none of these
@@ -139,6 +231,7 @@ impl ReplState {
                                     self.add_past_def(
                                         ident.trim_end().to_string(),
                                         line[vd.byte_range()].to_string(),
+                                        false,
                                     );
 
                                     // Return early without running eval, since standalone annotations
@@ -197,6 +290,16 @@ impl ReplState {
                                     todo!("handle receiving an `expect` - what should the repl do for that?")
                                 }
                                 ValueDef::ModuleImport(import) => match import.name.value.package {
+                                    Some("pf") if self.platform.is_some() => {
+                                        // This is one of the platform's modules; we'll find out
+                                        // whether it exists when it's next type checked.
+                                        self.past_defs.push(PastDef::Import {
+                                            src: line[vd.byte_range()].to_string(),
+                                            needs_platform: true,
+                                        });
+
+                                        return ReplAction::Nothing;
+                                    }
                                     Some(_) => {
                                         todo!("handle importing a module from a package")
                                     }
@@ -219,9 +322,10 @@ impl ReplState {
                                             };
                                         }
 
-                                        self.past_defs.push(PastDef::Import(
-                                            line[vd.byte_range()].to_string(),
-                                        ));
+                                        self.past_defs.push(PastDef::Import {
+                                            src: line[vd.byte_range()].to_string(),
+                                            needs_platform: false,
+                                        });
 
                                         return ReplAction::Nothing;
                                     }
@@ -237,8 +341,10 @@ impl ReplState {
                                         }
                                     }
 
-                                    self.past_defs
-                                        .push(PastDef::Import(line[vd.byte_range()].to_string()));
+                                    self.past_defs.push(PastDef::Import {
+                                        src: line[vd.byte_range()].to_string(),
+                                        needs_platform: false,
+                                    });
 
                                     return ReplAction::Nothing;
                                 }
@@ -257,33 +363,346 @@ impl ReplState {
             }
         };
 
-        let (opt_mono, problems) = compile_to_mono(
+        if let Some(platform) = self.platform.clone() {
+            return self.step_with_platform(
+                arena,
+                &platform,
+                src,
+                pending_past_def,
+                target,
+                palette,
+            );
+        }
+
+        let (opt_mono, problems) =
+            compile_to_mono(arena, self.past_srcs(false), src, target, palette);
+
+        if let Some((ident, src)) = pending_past_def {
+            self.add_past_def(ident, src, false);
+        }
+
+        ReplAction::Eval { opt_mono, problems }
+    }
+
+    /// Evaluate `src` as usual if it doesn't need the platform. If it does, run it on the
+    /// platform if it's something the platform can run, and otherwise just show its type.
+    fn step_with_platform<'a>(
+        &mut self,
+        arena: &'a Bump,
+        platform: &ReplPlatform,
+        src: &str,
+        pending_past_def: Option<(String, String)>,
+        target: Target,
+        palette: Palette,
+    ) -> ReplAction<'a> {
+        // Most lines don't need the platform, and evaluate just like they would without one.
+        // Past defs that don't need the platform never have errors, so any errors here mean
+        // that this line needs the platform (or is wrong no matter what).
+        let (opt_mono, problems) =
+            compile_to_mono(arena, self.past_srcs(false), src, target, palette);
+
+        if opt_mono.is_some() && problems.errors.is_empty() {
+            if let Some((ident, src)) = pending_past_def {
+                self.add_past_def(ident, src, false);
+            }
+
+            return ReplAction::Eval { opt_mono, problems };
+        }
+
+        // Type check with everything in scope, so that the errors we report
+        // (and the types we show) are about all the defs there are.
+        let (opt_loaded, problems) = typecheck_on_platform(
             arena,
-            self.past_defs.iter().map(|past_def| match past_def {
-                PastDef::Def { ident: _, src } => src.as_str(),
-                PastDef::Import(src) => src.as_str(),
-            }),
+            platform,
+            self.past_srcs(true),
             src,
+            PlatformMain::Placeholder,
             target,
             palette,
         );
 
-        if let Some((ident, src)) = pending_past_def {
-            self.add_past_def(ident, src);
+        let Some(mut loaded) = opt_loaded.filter(|_| problems.errors.is_empty()) else {
+            // Unlike without a platform, we don't keep defs with errors around; otherwise
+            // we couldn't tell whether later defs need the platform.
+            return ReplAction::Eval {
+                opt_mono: None,
+                problems,
+            };
+        };
+
+        match pending_past_def {
+            Some((ident, def_src)) => {
+                // Defs that need the platform don't run until an expression uses them.
+                let opt_output = repl_output_type(&mut loaded).map(|expr_type| ReplOutput {
+                    expr: ident.clone(),
+                    expr_type,
+                });
+
+                self.add_past_def(ident, def_src, true);
+
+                ReplAction::Type {
+                    opt_output,
+                    problems,
+                }
+            }
+            None => {
+                let (opt_loaded, run_problems) = typecheck_on_platform(
+                    arena,
+                    platform,
+                    self.past_srcs(true),
+                    src,
+                    PlatformMain::Expr,
+                    target,
+                    palette,
+                );
+
+                if opt_loaded.is_some() && run_problems.errors.is_empty() {
+                    let app_src = platform_app_src(arena, platform, self.past_srcs(true), src);
+
+                    return ReplAction::Run {
+                        app_src,
+                        problems: run_problems,
+                    };
+                }
+
+                // It needs the platform, but isn't something the platform can run,
+                // like `Stdout.line!` on its own. All we can do is show its type.
+                let opt_output = repl_output_type(&mut loaded).map(|expr_type| ReplOutput {
+                    expr: src.to_string(),
+                    expr_type,
+                });
+
+                ReplAction::Type {
+                    opt_output,
+                    problems,
+                }
+            }
         }
+    }
 
-        ReplAction::Eval { opt_mono, problems }
+    /// The sources of the past defs, leaving out the ones that need the platform
+    /// unless `include_platform_defs` is set.
+    fn past_srcs(&self, include_platform_defs: bool) -> impl Iterator<Item = &str> {
+        self.past_defs
+            .iter()
+            .filter_map(move |past_def| match past_def {
+                PastDef::Def {
+                    src,
+                    needs_platform,
+                    ..
+                }
+                | PastDef::Import {
+                    src,
+                    needs_platform,
+                } => (include_platform_defs || !needs_platform).then_some(src.as_str()),
+            })
+    }
+
+    /// Type check `expr` along with all the past defs, on the platform if there is one.
+    fn typecheck(
+        &self,
+        arena: &Bump,
+        expr: &str,
+        target: Target,
+        palette: Palette,
+    ) -> (Option<LoadedModule>, Problems) {
+        match &self.platform {
+            Some(platform) => typecheck_on_platform(
+                arena,
+                platform,
+                self.past_srcs(true),
+                expr,
+                PlatformMain::Placeholder,
+                target,
+                palette,
+            ),
+            None => typecheck(arena, self.past_srcs(true), expr, target, palette),
+        }
+    }
+
+    /// Infer the type of `expr` without evaluating it.
+    fn type_of<'a>(
+        &self,
+        arena: &'a Bump,
+        expr: &str,
+        target: Target,
+        palette: Palette,
+    ) -> ReplAction<'a> {
+        let (opt_loaded, problems) = self.typecheck(arena, expr, target, palette);
+
+        let opt_output = opt_loaded.and_then(|mut loaded| {
+            Some(ReplOutput {
+                expr: expr.to_string(),
+                expr_type: repl_output_type(&mut loaded)?,
+            })
+        });
+
+        ReplAction::Type {
+            opt_output,
+            problems,
+        }
+    }
+
+    fn defs<'a>(&self, arena: &'a Bump, target: Target, palette: Palette) -> ReplAction<'a> {
+        if self.past_defs.is_empty() {
+            return ReplAction::Message("There are no definitions yet.".to_string());
+        }
+
+        // Type check the past defs on their own, to find out their types.
+        // Later defs shadow earlier ones with the same name, so those are the types we want.
+        let mut types_by_ident = MutMap::default();
+
+        if let (Some(mut loaded), _) = self.typecheck(arena, "{}", target, palette) {
+            types_by_ident.extend(home_value_types(&mut loaded));
+        }
+
+        let mut listed_idents = MutSet::default();
+        let mut defs = Vec::with_capacity(self.past_defs.len());
+
+        for past_def in self.past_defs.iter() {
+            match past_def {
+                PastDef::Def { ident, src, .. } => {
+                    if !listed_idents.insert(ident) {
+                        continue;
+                    }
+
+                    match types_by_ident.remove(ident) {
+                        Some(expr_type) => defs.push(ReplOutput {
+                            expr: ident.clone(),
+                            expr_type,
+                        }),
+                        None => defs.push(ReplOutput {
+                            expr: src.clone(),
+                            expr_type: String::new(),
+                        }),
+                    }
+                }
+                PastDef::Import { src, .. } => defs.push(ReplOutput {
+                    expr: src.clone(),
+                    expr_type: String::new(),
+                }),
+            }
+        }
+
+        ReplAction::Defs(defs)
+    }
+
+    /// Import the module in the given file, exposing everything it exposes.
+    fn load<'a>(
+        &mut self,
+        arena: &'a Bump,
+        filename: &str,
+        target: Target,
+        palette: Palette,
+    ) -> ReplAction<'a> {
+        let path = PathBuf::from(filename);
+
+        let src = match fs::read_to_string(&path) {
+            Ok(src) => arena.alloc_str(&src),
+            Err(err) => {
+                return ReplAction::FileProblem {
+                    filename: path,
+                    error: err.kind(),
+                }
+            }
+        };
+
+        let exposes = match parse_header(arena, State::new(src.as_bytes())) {
+            Ok((
+                SpacesBefore {
+                    item: Header::Module(header),
+                    ..
+                },
+                _,
+            )) => header.exposes.items,
+            Ok(_) | Err(_) => {
+                return ReplAction::Message(format!(
+                    "{filename} doesn't start with a `module` header, so there's nothing to import from it."
+                ));
+            }
+        };
+
+        // Imports are relative to the current directory, so that's where the module has to be.
+        let mut module_name = String::new();
+
+        for component in path.with_extension("").components() {
+            match component {
+                std::path::Component::Normal(part) => {
+                    if !module_name.is_empty() {
+                        module_name.push('.');
+                    }
+
+                    module_name.push_str(&part.to_string_lossy());
+                }
+                std::path::Component::CurDir => {}
+                _ => {
+                    return ReplAction::Message(format!(
+                        "{filename} isn't inside the current directory, so I can't import it."
+                    ));
+                }
+            }
+        }
+
+        let exposed_names: Vec<&str> = exposes
+            .iter()
+            .map(|exposed| exposed.value.item().as_str())
+            .collect();
+
+        let import = if exposed_names.is_empty() {
+            format!("import {module_name}")
+        } else {
+            format!(
+                "import {module_name} exposing [{}]",
+                exposed_names.join(", ")
+            )
+        };
+
+        match self.step(arena, &import, target, palette) {
+            ReplAction::Nothing => ReplAction::Message(import),
+            action => action,
+        }
     }
 
-    fn add_past_def(&mut self, ident: String, src: String) {
+    fn add_past_def(&mut self, ident: String, src: String, needs_platform: bool) {
         let existing_idents = &mut self.past_def_idents;
 
         existing_idents.insert(ident.clone());
 
-        self.past_defs.push(PastDef::Def { ident, src });
+        self.past_defs.push(PastDef::Def {
+            ident,
+            src,
+            needs_platform,
+        });
     }
 }
 
+/// The type of the `repl_output` def that the REPL puts the expr in
+fn repl_output_type(loaded: &mut LoadedModule) -> Option<String> {
+    home_value_types(loaded)
+        .into_iter()
+        .find_map(|(ident, expr_type)| (ident == "repl_output").then_some(expr_type))
+}
+
+/// The names and types of the values defined in the REPL's module, in order
+fn home_value_types(loaded: &mut LoadedModule) -> Vec<(String, String)> {
+    let home = loaded.module_id;
+
+    let Some((declarations, subs, interns)) = home_decls(loaded) else {
+        return Vec::new();
+    };
+
+    declarations
+        .symbols
+        .iter()
+        .zip(&declarations.variables)
+        .map(|(loc_symbol, var)| {
+            let expr_type = name_and_print_var(*var, subs, home, interns, DebugPrint::NOTHING);
+
+            (loc_symbol.value.as_str(interns).to_string(), expr_type)
+        })
+        .collect()
+}
+
 #[derive(Debug, PartialEq)]
 pub enum ParseOutcome<'a> {
     DefsAndExpr(Defs<'a>, Option<Loc<Expr<'a>>>),
@@ -292,6 +711,17 @@ pub enum ParseOutcome<'a> {
     Empty,
     Help,
     Exit,
+    /// `:type <expr>`
+    Type(&'a str),
+    /// `:load <file.roc>`
+    Load(&'a str),
+    /// `:import <module>`
+    Import(&'a str),
+    Reset,
+    Defs,
+    Time,
+    /// A line starting with `:` that isn't one of the commands above
+    UnknownCommand(&'a str),
 }
 
 /// Special case some syntax errors to allow for multi-line inputs
@@ -306,15 +736,41 @@ fn parse_outcome_for_error(e: EExpr<'_>) -> ParseOutcome<'_> {
     }
 }
 
+/// Roc code never starts with a `:`, so we use that for REPL commands like `:type`.
+fn parse_command(line: &str) -> Option<ParseOutcome<'_>> {
+    let after_colon = line.strip_prefix(':')?;
+    let (command, arg) = match after_colon.split_once(char::is_whitespace) {
+        Some((command, arg)) => (command, arg.trim()),
+        None => (after_colon, ""),
+    };
+
+    let outcome = match (command.to_lowercase().as_str(), arg) {
+        ("help", "") => ParseOutcome::Help,
+        ("exit" | "quit" | "q", "") => ParseOutcome::Exit,
+        ("type" | "t", expr) if !expr.is_empty() => ParseOutcome::Type(expr),
+        ("load" | "l", filename) if !filename.is_empty() => ParseOutcome::Load(filename),
+        ("import", module) if !module.is_empty() => ParseOutcome::Import(module),
+        ("reset", "") => ParseOutcome::Reset,
+        ("defs", "") => ParseOutcome::Defs,
+        ("time", "") => ParseOutcome::Time,
+        _ => ParseOutcome::UnknownCommand(line),
+    };
+
+    Some(outcome)
+}
+
 pub fn parse_src<'a>(arena: &'a Bump, line: &'a str) -> ParseOutcome<'a> {
+    if let Some(outcome) = parse_command(line.trim()) {
+        return outcome;
+    }
+
     match line.trim().to_lowercase().as_str() {
         "" => ParseOutcome::Empty,
-        ":help" => ParseOutcome::Help,
         // These are all common things beginners try.
         // Let people exit the repl easily!
         // If you really need to evaluate `exit` for some reason,
         // you can do `foo = exit` and then evaluate `foo` instead.
-        ":exit" | ":quit" | ":q" | "exit" | "quit" | "exit()" | "quit()" => ParseOutcome::Exit,
+        "exit" | "quit" | "exit()" | "quit()" => ParseOutcome::Exit,
         _ => {
             let src_bytes = line.as_bytes();
 
@@ -26,6 +26,11 @@ macro_rules! console_log {
     ($($t:tt)*) => (log(&format_args!($($t)*).to_string()))
 }
 
+/// Milliseconds since the epoch, for timing evaluations with `:time`
+pub fn js_now_ms() -> f64 {
+    js_sys::Date::now()
+}
+
 /// Async entrypoint for the browser
 /// The browser only has an async API to generate a Wasm module from bytes
 /// wasm_bindgen manages the interaction between Rust Futures and JS Promises
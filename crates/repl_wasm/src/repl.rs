@@ -12,14 +12,15 @@ use roc_repl_eval::{
     ReplApp, ReplAppMemory,
 };
 use roc_repl_ui::{
-    format_output,
+    format_defs, format_output, format_timings,
     repl_state::{ReplAction, ReplState},
     TIPS,
 };
 use roc_target::Target;
 use roc_types::pretty_print::{name_and_print_var, DebugPrint};
 
-use crate::{js_create_app, js_get_result_and_memory, js_run_app};
+use crate::{js_create_app, js_get_result_and_memory, js_now_ms, js_run_app};
+use std::time::Duration;
 
 const WRAPPER_NAME: &str = "wrapper";
 
@@ -192,10 +193,14 @@ pub async fn entrypoint_from_js(src: String) -> String {
     let target = Target::Wasm32;
 
     // Advance the REPL state machine
-    let action = REPL_STATE.with(|repl_state_cell| {
+    let compile_start = js_now_ms();
+    let (action, is_timing) = REPL_STATE.with(|repl_state_cell| {
         let mut repl_state = repl_state_cell.borrow_mut();
-        repl_state.step(arena, &src, target, DEFAULT_PALETTE_HTML)
+        let action = repl_state.step(arena, &src, target, DEFAULT_PALETTE_HTML);
+
+        (action, repl_state.is_timing())
     });
+    let compile_ms = js_now_ms() - compile_start;
 
     // Perform the action the state machine asked for, and return the appropriate output string
     match action {
@@ -207,17 +212,44 @@ pub async fn entrypoint_from_js(src: String) -> String {
             "The web version of the REPL cannot import files... for now!".to_string()
         }
         ReplAction::Nothing => String::new(),
+        ReplAction::Message(message) => message,
+        ReplAction::Run { .. } => {
+            "The web version of the REPL cannot run code on a platform.".to_string()
+        }
+        ReplAction::Type {
+            opt_output,
+            problems,
+        } => format_output(HTML_STYLE_CODES, opt_output, problems),
+        ReplAction::Defs(defs) => format_defs(HTML_STYLE_CODES, &defs),
         ReplAction::Eval { opt_mono, problems } => {
+            let eval_start = js_now_ms();
             let opt_output = match opt_mono {
                 Some(mono) => eval_wasm(arena, target, mono).await,
                 None => None,
             };
+            let eval_ms = js_now_ms() - eval_start;
+
+            let mut output = format_output(HTML_STYLE_CODES, opt_output, problems);
 
-            format_output(HTML_STYLE_CODES, opt_output, problems)
+            if is_timing {
+                output.push('\n');
+                output.push_str(&format_timings(
+                    ms_to_duration(compile_ms),
+                    ms_to_duration(eval_ms),
+                ));
+            }
+
+            output
         }
     }
 }
 
+/// The browser's clock can be coarsened or go backwards, so the difference between two of its
+/// readings can be negative (or NaN). That's counted as no time at all, rather than panicking.
+fn ms_to_duration(ms: f64) -> Duration {
+    Duration::try_from_secs_f64(ms / 1000.0).unwrap_or(Duration::ZERO)
+}
+
 async fn eval_wasm<'a>(
     arena: &'a Bump,
     target: Target,
@@ -1,12 +1,15 @@
+use std::ops::Range;
 use std::path::PathBuf;
 
 use roc_collections::MutMap;
 use roc_module::symbol::{Interns, ModuleId};
 use roc_problem::can::Problem;
-use roc_region::all::LineInfo;
+use roc_problem::lint::LintProblem;
+use roc_problem::Severity;
+use roc_region::all::{LineInfo, Region};
 use roc_solve_problem::TypeError;
 
-use crate::report::ANSI_STYLE_CODES;
+use crate::report::{RenderTarget, Report, RocDocAllocator, ANSI_STYLE_CODES};
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct Problems {
@@ -60,13 +63,184 @@ impl Problems {
     }
 }
 
+/// How to report each kind of warning, as set by `--deny`, `--allow` and `--warnings-as-errors`.
+/// Kinds are identified by their error code; see [`warning_kind`].
+#[derive(Clone, Debug, Default)]
+pub struct WarningLevels {
+    /// Warnings of these kinds are reported as errors
+    pub deny: Vec<&'static str>,
+    /// Warnings of these kinds aren't reported at all
+    pub allow: Vec<&'static str>,
+    /// Report every warning that isn't allowed as an error
+    pub warnings_as_errors: bool,
+}
+
+impl WarningLevels {
+    /// The severity to report a problem with, or `None` if it's a warning that shouldn't be
+    /// reported. `allowed_here` are the kinds allowed by `# roc:allow` where the problem is,
+    /// which take precedence over the command line, like `#[allow]` does in Rust.
+    /// Only warnings are affected; errors are always reported as they are.
+    fn severity(
+        &self,
+        code: Option<&str>,
+        severity: Severity,
+        allowed_here: &[&str],
+    ) -> Option<Severity> {
+        if severity != Severity::Warning {
+            return Some(severity);
+        }
+
+        match code {
+            Some(code) if allowed_here.contains(&code) => None,
+            Some(code) if self.deny.contains(&code) => Some(Severity::RuntimeError),
+            Some(code) if self.allow.contains(&code) => None,
+            _ if self.warnings_as_errors => Some(Severity::RuntimeError),
+            _ => Some(Severity::Warning),
+        }
+    }
+}
+
+/// The error code for a kind of warning, given either as the code itself, like `E0204`, or as
+/// its title in kebab case, like `unused-import`
+pub fn warning_kind(kind: &str) -> Option<&'static str> {
+    let kind = kind.trim();
+
+    crate::error_code::all()
+        .find(|error_code| {
+            error_code.code.eq_ignore_ascii_case(kind)
+                || error_code
+                    .title
+                    .replace(' ', "-")
+                    .eq_ignore_ascii_case(kind)
+        })
+        .map(|error_code| error_code.code)
+}
+
+/// The lines covered by each `# roc:allow` comment in a module, along with the kinds of warning
+/// it allows. A comment like `# roc:allow unused-argument, unused-definition` directly above a
+/// def (or its annotation) covers the whole def: every line until the next one that's indented
+/// no further than the def itself.
+fn allowed_in_source(src_lines: &[&str]) -> Vec<(Range<u32>, Vec<&'static str>)> {
+    fn indentation(line: &str) -> usize {
+        line.len() - line.trim_start().len()
+    }
+
+    fn is_blank_or_comment(line: &str) -> bool {
+        let trimmed = line.trim_start();
+
+        trimmed.is_empty() || trimmed.starts_with('#')
+    }
+
+    let mut allowed = Vec::new();
+
+    for (index, line) in src_lines.iter().enumerate() {
+        let Some(kinds) = line
+            .trim_start()
+            .strip_prefix('#')
+            .and_then(|comment| comment.trim_start().strip_prefix("roc:allow"))
+        else {
+            continue;
+        };
+
+        let kinds: Vec<&'static str> = kinds
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter_map(warning_kind)
+            .collect();
+
+        let Some(def_start) =
+            (index + 1..src_lines.len()).find(|&i| !is_blank_or_comment(src_lines[i]))
+        else {
+            continue;
+        };
+
+        let def_line = src_lines[def_start];
+        let def_indent = indentation(def_line);
+
+        // An annotation like `name : Str` is followed by its def, `name = ...`, at the same
+        // indentation.
+        let mut annotated_name = def_line
+            .trim_start()
+            .split_once(':')
+            .map(|(name, _)| name.trim())
+            .filter(|name| !name.is_empty() && !name.contains(|c: char| c.is_whitespace()));
+
+        let mut def_end = def_start + 1;
+
+        while let Some(line) = src_lines.get(def_end) {
+            let trimmed = line.trim_start();
+            let continues_def = is_blank_or_comment(line)
+                || indentation(line) > def_indent
+                || trimmed.starts_with([')', ']', '}'])
+                || annotated_name.take().map_or(false, |name| {
+                    trimmed
+                        .strip_prefix(name)
+                        .map_or(false, |rest| rest.trim_start().starts_with('='))
+                });
+
+            if !continues_def {
+                break;
+            }
+
+            def_end += 1;
+        }
+
+        allowed.push((def_start as u32..def_end as u32, kinds));
+    }
+
+    allowed
+}
+
+/// The kinds of warning allowed by `# roc:allow` comments covering the start of this region
+fn allowed_at<'a>(
+    allowed: &'a [(Range<u32>, Vec<&'static str>)],
+    lines: &LineInfo,
+    region: Option<Region>,
+) -> Vec<&'a str> {
+    let Some(region) = region else {
+        return Vec::new();
+    };
+
+    let line = lines.convert_pos(region.start()).line;
+
+    allowed
+        .iter()
+        .filter(|(covered, _)| covered.contains(&line))
+        .flat_map(|(_, kinds)| kinds.iter().copied())
+        .collect()
+}
+
 pub fn report_problems(
     sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
     interns: &Interns,
     can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    render: RenderTarget,
+    warning_levels: &WarningLevels,
 ) -> Problems {
-    use crate::report::{can_problem, type_problem, Report, RocDocAllocator, DEFAULT_PALETTE};
+    report_problems_and_lints(
+        sources,
+        interns,
+        can_problems,
+        type_problems,
+        &mut MutMap::default(),
+        render,
+        warning_levels,
+    )
+}
+
+/// Like [report_problems], but also reports the problems found by `roc lint`. Afterwards,
+/// `lint_problems` only has the ones that were reported, rather than allowed, so that their
+/// fixes can be applied.
+pub fn report_problems_and_lints(
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    interns: &Interns,
+    can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    lint_problems: &mut MutMap<ModuleId, Vec<LintProblem>>,
+    render: RenderTarget,
+    warning_levels: &WarningLevels,
+) -> Problems {
+    use crate::report::{can_problem, lint_problem, type_problem, DEFAULT_PALETTE};
     use roc_problem::Severity::*;
 
     let palette = DEFAULT_PALETTE;
@@ -80,11 +254,20 @@ pub fn report_problems(
         total_problems += problems.len();
     }
 
+    for problems in lint_problems.values() {
+        total_problems += problems.len();
+    }
+
     // This will often over-allocate total memory, but it means we definitely
     // never need to re-allocate either the warnings or the errors vec!
     let mut warnings = Vec::with_capacity(total_problems);
     let mut errors = Vec::with_capacity(total_problems);
     let mut fatally_errored = false;
+    // Warnings that were allowed, and so not reported
+    let mut allowed = 0;
+    // Machine-readable output includes every report, warnings and errors alike
+    let mut sarif_results = Vec::new();
+    let mut reported_lints = MutMap::default();
 
     for (home, (module_path, src)) in sources.iter() {
         let mut src_lines: Vec<&str> = Vec::new();
@@ -95,15 +278,24 @@ pub fn report_problems(
 
         // Report parsing and canonicalization problems
         let alloc = RocDocAllocator::new(&src_lines, *home, interns);
+        let allowed_in_module = allowed_in_source(&src_lines);
 
         let problems = type_problems.remove(home).unwrap_or_default();
 
         for problem in problems {
-            if let Some(report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
-                let severity = report.severity;
+            let allowed_here = allowed_at(&allowed_in_module, &lines, problem.region());
+
+            if let Some(mut report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
+                let Some(severity) =
+                    warning_levels.severity(report.code, report.severity, &allowed_here)
+                else {
+                    allowed += 1;
+                    continue;
+                };
+                report.severity = severity;
                 let mut buf = String::new();
 
-                report.render_color_terminal(&mut buf, &alloc, &palette);
+                render_report(report, render, &mut buf, &alloc, &mut sarif_results);
 
                 match severity {
                     Warning => {
@@ -137,11 +329,18 @@ pub fn report_problems(
         ordered.extend(shadowing_errs);
 
         for problem in ordered.into_iter() {
-            let report = can_problem(&alloc, &lines, module_path.clone(), problem);
-            let severity = report.severity;
+            let allowed_here = allowed_at(&allowed_in_module, &lines, problem.region());
+            let mut report = can_problem(&alloc, &lines, module_path.clone(), problem);
+            let Some(severity) =
+                warning_levels.severity(report.code, report.severity, &allowed_here)
+            else {
+                allowed += 1;
+                continue;
+            };
+            report.severity = severity;
             let mut buf = String::new();
 
-            report.render_color_terminal(&mut buf, &alloc, &palette);
+            render_report(report, render, &mut buf, &alloc, &mut sarif_results);
 
             match severity {
                 Warning => {
@@ -156,10 +355,61 @@ pub fn report_problems(
                 }
             }
         }
+
+        let problems = lint_problems.remove(home).unwrap_or_default();
+        let mut reported = Vec::with_capacity(problems.len());
+
+        for problem in problems {
+            let allowed_here = allowed_at(&allowed_in_module, &lines, Some(problem.region()));
+            let mut report = lint_problem(&alloc, &lines, module_path.clone(), problem.clone());
+            let Some(severity) =
+                warning_levels.severity(report.code, report.severity, &allowed_here)
+            else {
+                allowed += 1;
+                continue;
+            };
+            report.severity = severity;
+            reported.push(problem);
+            let mut buf = String::new();
+
+            render_report(report, render, &mut buf, &alloc, &mut sarif_results);
+
+            match severity {
+                Warning => {
+                    warnings.push(buf);
+                }
+                RuntimeError | Fatal => {
+                    errors.push(buf);
+                }
+            }
+        }
+
+        if !reported.is_empty() {
+            reported_lints.insert(*home, reported);
+        }
     }
 
-    debug_assert!(can_problems.is_empty() && type_problems.is_empty(), "After reporting problems, there were {:?} can_problems and {:?} type_problems that could not be reported because they did not have corresponding entries in `sources`.", can_problems.len(), type_problems.len());
-    debug_assert_eq!(errors.len() + warnings.len(), total_problems);
+    debug_assert!(can_problems.is_empty() && type_problems.is_empty() && lint_problems.is_empty(), "After reporting problems, there were {:?} can_problems, {:?} type_problems and {:?} lint_problems that could not be reported because they did not have corresponding entries in `sources`.", can_problems.len(), type_problems.len(), lint_problems.len());
+    debug_assert_eq!(errors.len() + warnings.len() + allowed, total_problems);
+
+    *lint_problems = reported_lints;
+
+    if render.is_machine_readable() {
+        match render {
+            RenderTarget::Sarif => println!("{}", Report::sarif_log(sarif_results)),
+            _ => {
+                for report in errors.iter().chain(warnings.iter()) {
+                    print!("{report}");
+                }
+            }
+        }
+
+        return Problems {
+            fatally_errored,
+            errors: errors.len(),
+            warnings: warnings.len(),
+        };
+    }
 
     let problems_reported;
 
@@ -194,3 +444,26 @@ pub fn report_problems(
         warnings: warnings.len(),
     }
 }
+
+/// Render a report the way [`report_problems`] prints it. SARIF results all go in one log,
+/// so they're collected in `sarif_results` instead.
+fn render_report<'b>(
+    report: Report<'b>,
+    render: RenderTarget,
+    buf: &mut String,
+    alloc: &'b RocDocAllocator<'b>,
+    sarif_results: &mut Vec<serde_json::Value>,
+) {
+    match render {
+        RenderTarget::Sarif => sarif_results.push(report.to_sarif_result()),
+        RenderTarget::Json => {
+            buf.push_str(&report.to_json().to_string());
+            buf.push('\n');
+        }
+        RenderTarget::ColorTerminal => {
+            report.render_color_terminal(buf, alloc, &crate::report::DEFAULT_PALETTE)
+        }
+        RenderTarget::Generic => report.render_ci(buf, alloc),
+        RenderTarget::LanguageServer => report.render_language_server(buf, alloc),
+    }
+}
@@ -68,6 +68,8 @@ const UNNECESSARY_EXCLAMATION: &str = "UNNECESSARY EXCLAMATION";
 const EMPTY_TUPLE_TYPE: &str = "EMPTY TUPLE TYPE";
 const UNBOUND_TYPE_VARS_IN_AS: &str = "UNBOUND TYPE VARIABLES IN AS";
 const INTERPOLATED_STRING_NOT_ALLOWED: &str = "INTERPOLATED STRING NOT ALLOWED";
+const CONSTANT_CRASHED: &str = "CONSTANT CRASHED";
+const INVALID_DOC_EXAMPLE: &str = "INVALID DOC EXAMPLE";
 
 pub fn can_problem<'b>(
     alloc: &'b RocDocAllocator<'b>,
@@ -77,6 +79,7 @@ pub fn can_problem<'b>(
 ) -> Report<'b> {
     let doc;
     let title;
+    let code;
     let severity = problem.severity();
 
     match problem {
@@ -96,6 +99,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_DEF.to_string();
+            code = "E0203";
         }
         Problem::UnusedImport(symbol, region) => {
             doc = alloc.stack([
@@ -112,6 +116,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_IMPORT.to_string();
+            code = "E0204";
         }
         Problem::UnusedModuleImport(module_id, region) => {
             doc = alloc.stack([
@@ -128,6 +133,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_IMPORT.to_string();
+            code = "E0204";
         }
         Problem::ImportNameConflict {
             name,
@@ -188,6 +194,7 @@ pub fn can_problem<'b>(
                 },
             ]);
             title = IMPORT_NAME_CONFLICT.to_string();
+            code = "E0205";
         }
 
         Problem::ExplicitBuiltinImport(module_id, region) => {
@@ -203,6 +210,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = EXPLICIT_BUILTIN_IMPORT.to_string();
+            code = "E0206";
         }
 
         Problem::ExplicitBuiltinTypeImport(symbol, region) => {
@@ -221,6 +229,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = EXPLICIT_BUILTIN_IMPORT.to_string();
+            code = "E0206";
         }
 
         Problem::ImportShadowsSymbol {
@@ -248,6 +257,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_NAME.to_string();
+            code = "E0217";
         }
 
         Problem::DefsOnlyUsedInRecursion(1, region) => {
@@ -260,6 +270,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = "DEFINITION ONLY USED IN RECURSION".to_string();
+            code = "E0253";
         }
         Problem::DefsOnlyUsedInRecursion(n, region) => {
             doc = alloc.stack([
@@ -275,6 +286,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = "DEFINITIONS ONLY USED IN RECURSION".to_string();
+            code = "E0254";
         }
         Problem::ExposedButNotDefined(symbol) => {
             doc = alloc.stack([
@@ -290,6 +302,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = MISSING_DEFINITION.to_string();
+            code = "E0212";
         }
         Problem::UnusedArgument(closure_symbol, is_anonymous, argument_symbol, region) => {
             let line = "\". Adding an underscore at the start of a variable name is a way of saying that the variable is not used.";
@@ -324,6 +337,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_ARG.to_string();
+            code = "E0211";
         }
         Problem::UnusedBranchDef(symbol, region) => {
             doc = alloc.stack([
@@ -344,6 +358,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_DEF.to_string();
+            code = "E0203";
         }
         Problem::PrecedenceProblem(BothNonAssociative(region, left_bin_op, right_bin_op)) => {
             doc = alloc.stack([
@@ -372,6 +387,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            code = "E0108";
         }
         Problem::UnsupportedPattern(BadPattern::Unsupported(pattern_type), region) => {
             use roc_parse::pattern::PatternType::*;
@@ -403,6 +419,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            code = "E0108";
         }
         Problem::Shadowing {
             original_region,
@@ -414,6 +431,7 @@ pub fn can_problem<'b>(
 
             doc = res_doc;
             title = res_title.to_string();
+            code = "E0217";
         }
         Problem::CyclicAlias(symbol, region, others, alias_kind) => {
             let answer = crate::error::r#type::cyclic_alias(
@@ -422,6 +440,7 @@ pub fn can_problem<'b>(
 
             doc = answer.0;
             title = answer.1;
+            code = "E0314";
         }
         Problem::PhantomTypeArgument {
             typ: alias,
@@ -449,6 +468,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_ALIAS_PARAM.to_string();
+            code = "E0207";
         }
         Problem::WildcardNotAllowed {
             typ: alias,
@@ -489,6 +509,7 @@ pub fn can_problem<'b>(
             doc = alloc.stack(stack);
 
             title = WILDCARD_NOT_ALLOWED.to_string();
+            code = "E0209";
         }
         Problem::UnderscoreNotAllowed {
             typ: alias,
@@ -530,6 +551,7 @@ pub fn can_problem<'b>(
             doc = alloc.stack(stack);
 
             title = UNDERSCORE_NOT_ALLOWED.to_string();
+            code = "E0210";
         }
         Problem::UndeclaredTypeVar {
             typ: alias,
@@ -576,10 +598,12 @@ pub fn can_problem<'b>(
             doc = alloc.stack(stack);
 
             title = UNDECLARED_TYPE_VARIABLE.to_string();
+            code = "E0208";
         }
         Problem::BadRecursion(entries) => {
             doc = to_circular_def_doc(alloc, lines, &entries, severity);
             title = CIRCULAR_DEF.to_string();
+            code = "E0216";
         }
         Problem::DuplicateRecordFieldValue {
             field_name,
@@ -614,6 +638,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_FIELD_NAME.to_string();
+            code = "E0213";
         }
         Problem::InvalidOptionalValue {
             field_name,
@@ -676,6 +701,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_FIELD_NAME.to_string();
+            code = "E0213";
         }
         Problem::DuplicateTag {
             tag_name,
@@ -710,6 +736,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_TAG_NAME.to_string();
+            code = "E0214";
         }
         Problem::SignatureDefMismatch {
             ref annotation_pattern,
@@ -727,6 +754,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = NAMING_PROBLEM.to_string();
+            code = "E0201";
         }
         Problem::InvalidAliasRigid {
             alias_name: type_name,
@@ -749,6 +777,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            code = "E0108";
         }
         Problem::InvalidHexadecimal(region) => {
             doc = alloc.stack([
@@ -765,6 +794,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = INVALID_UNICODE.to_string();
+            code = "E0215";
         }
         Problem::InvalidUnicodeCodePt(region) => {
             doc = alloc.stack([
@@ -774,6 +804,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = INVALID_UNICODE.to_string();
+            code = "E0215";
         }
         Problem::InvalidInterpolation(region) => {
             doc = alloc.stack([
@@ -784,12 +815,14 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            code = "E0108";
         }
         Problem::RuntimeError(runtime_error) => {
             let answer = pretty_runtime_error(alloc, lines, runtime_error);
 
             doc = answer.0;
             title = answer.1.to_string();
+            code = answer.2;
         }
         Problem::NestedDatatype {
             alias,
@@ -817,6 +850,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = NESTED_DATATYPE.to_string();
+            code = "E0221";
         }
 
         Problem::InvalidExtensionType { region, kind } => {
@@ -844,6 +878,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = INVALID_EXTENSION_TYPE.to_string();
+            code = "E0229";
         }
 
         Problem::AbilityHasTypeVariables {
@@ -862,6 +897,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = ABILITY_HAS_TYPE_VARIABLES.to_string();
+            code = "E0230";
         }
 
         Problem::ImplementsClauseIsNotAbility {
@@ -874,6 +910,7 @@ pub fn can_problem<'b>(
                 alloc.region(lines.convert_region(clause_region), severity),
             ]);
             title = IMPLEMENTS_CLAUSE_IS_NOT_AN_ABILITY.to_string();
+            code = "E0231";
         }
 
         Problem::IllegalImplementsClause { region } => {
@@ -892,6 +929,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = ILLEGAL_IMPLEMENTS_CLAUSE.to_string();
+            code = "E0232";
         }
 
         Problem::DuplicateImplementsAbility { ability, region } => {
@@ -909,6 +947,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "DUPLICATE BOUND ABILITY".to_string();
+            code = "E0255";
         }
 
         Problem::AbilityMemberMissingImplementsClause {
@@ -943,6 +982,7 @@ pub fn can_problem<'b>(
                     .reflow("Otherwise, the function does not need to be part of the ability!")]),
             ]);
             title = ABILITY_MEMBER_MISSING_IMPLEMENTS_CLAUSE.to_string();
+            code = "E0233";
         }
 
         Problem::AbilityMemberMultipleBoundVars {
@@ -970,6 +1010,7 @@ pub fn can_problem<'b>(
                 ])
             ]);
             title = ABILITY_MEMBER_BINDS_MULTIPLE_VARIABLES.to_string();
+            code = "E0234";
         }
 
         Problem::AbilityNotOnToplevel { region } => {
@@ -981,6 +1022,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Abilities can only be defined on the top-level of a Roc module."),
             ]);
             title = ABILITY_NOT_ON_TOPLEVEL.to_string();
+            code = "E0235";
         }
 
         Problem::AbilityUsedAsType(suggested_var_name, ability, region) => {
@@ -1008,6 +1050,7 @@ pub fn can_problem<'b>(
                 ])),
             ]);
             title = ABILITY_USED_AS_TYPE.to_string();
+            code = "E0237";
         }
         Problem::NestedSpecialization(member, region) => {
             doc = alloc.stack([
@@ -1020,6 +1063,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Specializations can only be defined on the top-level of a module."),
             ]);
             title = SPECIALIZATION_NOT_ON_TOPLEVEL.to_string();
+            code = "E0236";
         }
         Problem::IllegalDerivedAbility(region) => {
             doc = alloc.stack([
@@ -1031,6 +1075,7 @@ pub fn can_problem<'b>(
                     .append(list_builtin_abilities(alloc)),
             ]);
             title = ILLEGAL_DERIVE.to_string();
+            code = "E0238";
         }
         Problem::NotAnAbility(region) => {
             doc = alloc.stack([
@@ -1039,6 +1084,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Only abilities can be implemented."),
             ]);
             title = NOT_AN_ABILITY.to_string();
+            code = "E0241";
         }
         Problem::NotAnAbilityMember {
             ability,
@@ -1053,6 +1099,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Only implementations for members an ability has can be specified in this location.")
             ]);
             title = NOT_AN_ABILITY_MEMBER.to_string();
+            code = "E0240";
         }
         Problem::ImplementationNotFound { member, region } => {
             let member_str = member.as_str(alloc.interns);
@@ -1064,6 +1111,7 @@ pub fn can_problem<'b>(
                 alloc.tip().append(alloc.concat([alloc.reflow("consider adding a value of name "), alloc.symbol_unqualified(member), alloc.reflow(" in this scope, or using another variable that implements this ability member, like "), alloc.type_str(&format!("{{ {member_str}: my{member_str} }}"))]))
             ]);
             title = IMPLEMENTATION_NOT_FOUND.to_string();
+            code = "E0239";
         }
         Problem::OptionalAbilityImpl { ability, region } => {
             let hint = if ability.is_builtin() {
@@ -1082,6 +1130,7 @@ pub fn can_problem<'b>(
                 hint,
             ]);
             title = OPTIONAL_ABILITY_IMPLEMENTATION.to_string();
+            code = "E0242";
         }
         Problem::QualifiedAbilityImpl { region } => {
             doc = alloc.stack([
@@ -1092,6 +1141,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = QUALIFIED_ABILITY_IMPLEMENTATION.to_string();
+            code = "E0243";
         }
         Problem::AbilityImplNotIdent { region } => {
             doc = alloc.stack([
@@ -1103,6 +1153,7 @@ pub fn can_problem<'b>(
                 alloc.tip().append(alloc.reflow("consider defining this expression as a variable."))
             ]);
             title = ABILITY_IMPLEMENTATION_NOT_IDENTIFIER.to_string();
+            code = "E0244";
         }
         Problem::DuplicateImpl {
             original,
@@ -1117,6 +1168,7 @@ pub fn can_problem<'b>(
                     .reflow("Only one custom implementation can be defined for an ability member."),
             ]);
             title = DUPLICATE_IMPLEMENTATION.to_string();
+            code = "E0245";
         }
         Problem::ImplementsNonRequired {
             region,
@@ -1141,6 +1193,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = UNNECESSARY_IMPLEMENTATIONS.to_string();
+            code = "E0246";
         }
         Problem::DoesNotImplementAbility {
             region,
@@ -1165,6 +1218,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = INCOMPLETE_ABILITY_IMPLEMENTATION.to_string();
+            code = "E0247";
         }
         Problem::NotBoundInAllPatterns {
             unbound_symbol,
@@ -1185,6 +1239,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "NAME NOT BOUND IN ALL PATTERNS".to_string();
+            code = "E0256";
         }
         Problem::NoIdentifiersIntroduced(region) => {
             doc = alloc.stack([
@@ -1193,6 +1248,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("If you don't need to use the value on the right-hand side of this assignment, consider removing the assignment. Since effects are not allowed at the top-level, assignments that don't introduce variables cannot affect a program's behavior"),
             ]);
             title = "UNNECESSARY DEFINITION".to_string();
+            code = "E0257";
         }
         Problem::OverloadedSpecialization {
             ability_member,
@@ -1212,6 +1268,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Ability specializations can only provide implementations for one opaque type, since all opaque types are different!"),
             ]);
             title = "OVERLOADED SPECIALIZATION".to_string();
+            code = "E0258";
         }
         Problem::UnnecessaryOutputWildcard { region } => {
             doc = alloc.stack([
@@ -1231,6 +1288,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("You can safely remove this to make the code more concise without changing what it means."),
             ]);
             title = "UNNECESSARY WILDCARD".to_string();
+            code = "E0259";
         }
         Problem::MultipleListRestPattern { region } => {
             doc = alloc.stack([
@@ -1243,6 +1301,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "MULTIPLE LIST REST PATTERNS".to_string();
+            code = "E0260";
         }
         Problem::BadTypeArguments {
             symbol,
@@ -1277,10 +1336,10 @@ pub fn can_problem<'b>(
                 alloc.reflow("Are there missing parentheses?"),
             ]);
 
-            title = if type_got > alias_needs {
-                "TOO MANY TYPE ARGUMENTS".to_string()
+            (title, code) = if type_got > alias_needs {
+                ("TOO MANY TYPE ARGUMENTS".to_string(), "E0261")
             } else {
-                "TOO FEW TYPE ARGUMENTS".to_string()
+                ("TOO FEW TYPE ARGUMENTS".to_string(), "E0262")
             };
         }
         Problem::UnappliedCrash { region } => {
@@ -1295,6 +1354,7 @@ pub fn can_problem<'b>(
                 ])
             ]);
             title = "UNAPPLIED CRASH".to_string();
+            code = "E0263";
         }
         Problem::OverAppliedCrash { region } => {
             doc = alloc.stack([
@@ -1310,6 +1370,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "OVERAPPLIED CRASH".to_string();
+            code = "E0264";
         }
         Problem::UnappliedDbg { region } => {
             doc = alloc.stack([
@@ -1323,6 +1384,7 @@ pub fn can_problem<'b>(
                 ])
             ]);
             title = "UNAPPLIED DBG".to_string();
+            code = "E0265";
         }
         Problem::OverAppliedDbg { region } => {
             doc = alloc.stack([
@@ -1338,6 +1400,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "OVERAPPLIED DBG".to_string();
+            code = "E0266";
         }
         Problem::UnderAppliedTry { region } => {
             doc = alloc.stack([
@@ -1353,11 +1416,13 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "UNDERAPPLIED TRY".to_string();
+            code = "E0267";
         }
         Problem::FileProblem { filename, error } => {
             let report = to_file_problem_report(alloc, filename, error);
             doc = report.doc;
             title = report.title;
+            code = report.code.unwrap_or_default();
         }
 
         Problem::ReturnOutsideOfFunction {
@@ -1365,9 +1430,11 @@ pub fn can_problem<'b>(
             return_kind,
         } => {
             let return_keyword;
-            (title, return_keyword) = match return_kind {
-                EarlyReturnKind::Return => ("RETURN OUTSIDE OF FUNCTION".to_string(), "return"),
-                EarlyReturnKind::Try => ("TRY OUTSIDE OF FUNCTION".to_string(), "try"),
+            (title, code, return_keyword) = match return_kind {
+                EarlyReturnKind::Return => {
+                    ("RETURN OUTSIDE OF FUNCTION".to_string(), "E0268", "return")
+                }
+                EarlyReturnKind::Try => ("TRY OUTSIDE OF FUNCTION".to_string(), "E0269", "try"),
             };
 
             doc = alloc.stack([
@@ -1399,6 +1466,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = "UNREACHABLE CODE".to_string();
+            code = "E0270";
         }
 
         Problem::ReturnAtEndOfFunction { region } => {
@@ -1419,6 +1487,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = "UNNECESSARY RETURN".to_string();
+            code = "E0271";
         }
 
         Problem::UnsuffixedEffectfulRecordField(region) => {
@@ -1435,6 +1504,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = MISSING_EXCLAMATION.to_string();
+            code = "E0248";
         }
 
         Problem::SuffixedPureRecordField(region) => {
@@ -1455,6 +1525,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNNECESSARY_EXCLAMATION.to_string();
+            code = "E0249";
         }
 
         Problem::EmptyTupleType(region) => {
@@ -1465,6 +1536,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = EMPTY_TUPLE_TYPE.to_string();
+            code = "E0250";
         }
         Problem::UnboundTypeVarsInAs(region) => {
             // NOTE for the enterprising contributor:
@@ -1478,6 +1550,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNBOUND_TYPE_VARS_IN_AS.to_string();
+            code = "E0251";
         }
         Problem::InterpolatedStringNotAllowed(region) => {
             doc = alloc.stack([
@@ -1487,11 +1560,55 @@ pub fn can_problem<'b>(
             ]);
 
             title = INTERPOLATED_STRING_NOT_ALLOWED.to_string();
+            code = "E0252";
+        }
+        Problem::ConstantCrashed {
+            symbol,
+            region,
+            message,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("Evaluating "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" at compile time crashed:"),
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.reflow("It crashed with this message:"),
+                alloc.string(message).indent(4),
+                alloc.concat([
+                    alloc.reflow("Every program that uses "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" will crash the same way when it runs."),
+                ]),
+            ]);
+
+            title = CONSTANT_CRASHED.to_string();
+            code = "E0427";
+        }
+        Problem::InvalidDocExample(region) => {
+            doc = alloc.stack([
+                alloc.reflow("I could not parse this doc example:"),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.concat([
+                    alloc.reflow("Examples in "),
+                    alloc.parser_suggestion("```roc"),
+                    alloc.reflow(" code blocks get checked by "),
+                    alloc.parser_suggestion("roc test"),
+                    alloc.reflow(". If this one isn't meant to be, tag its code block "),
+                    alloc.parser_suggestion("```roc,unchecked"),
+                    alloc.reflow(" instead."),
+                ]),
+            ]);
+
+            title = INVALID_DOC_EXAMPLE.to_string();
+            code = "E0428";
         }
     };
 
     Report {
         title,
+        code: Some(code),
         filename,
         doc,
         severity,
@@ -1525,6 +1642,7 @@ fn to_invalid_optional_value_report<'b>(
 
     Report {
         title: "BAD OPTIONAL VALUE".to_string(),
+        code: Some("E0272"),
         filename,
         doc,
         severity: Severity::RuntimeError,
@@ -1570,6 +1688,7 @@ fn to_invalid_ignored_value_report<'b>(
 
     Report {
         title: "BAD IGNORED VALUE".to_string(),
+        code: Some("E0273"),
         filename,
         doc,
         severity: Severity::RuntimeError,
@@ -2090,9 +2209,10 @@ fn pretty_runtime_error<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
     runtime_error: RuntimeError,
-) -> (RocDocBuilder<'b>, &'static str) {
+) -> (RocDocBuilder<'b>, &'static str, &'static str) {
     let doc;
     let title;
+    let code;
 
     let severity = Severity::RuntimeError;
 
@@ -2114,6 +2234,7 @@ fn pretty_runtime_error<'b>(
             kind,
         } => {
             (title, doc) = report_shadowing(alloc, lines, original_region, shadow, kind, severity);
+            code = "E0217";
         }
 
         RuntimeError::LookupNotInScope {
@@ -2131,10 +2252,12 @@ fn pretty_runtime_error<'b>(
                 severity,
             );
             title = UNRECOGNIZED_NAME;
+            code = "E0202";
         }
         RuntimeError::CircularDef(entries) => {
             doc = to_circular_def_doc(alloc, lines, &entries, severity);
             title = CIRCULAR_DEF;
+            code = "E0216";
         }
         RuntimeError::MalformedPattern(problem, region) => {
             use roc_parse::ast::Base;
@@ -2149,9 +2272,10 @@ fn pretty_runtime_error<'b>(
                 MalformedBase(Base::Decimal) => " integer ",
                 BadIdent(bad_ident) => {
                     title = NAMING_PROBLEM;
+                    code = "E0201";
                     doc = to_bad_ident_pattern_report(alloc, lines, bad_ident, region, severity);
 
-                    return (doc, title);
+                    return (doc, title, code);
                 }
                 Unknown | CantApplyPattern => " ",
                 QualifiedIdentifier => " qualified ",
@@ -2188,6 +2312,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::UnsupportedPattern(_) => {
             todo!("unsupported patterns are currently not parsed!")
@@ -2229,6 +2354,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = VALUE_NOT_EXPOSED;
+            code = "E0218";
         }
 
         RuntimeError::ModuleNotImported {
@@ -2248,6 +2374,7 @@ fn pretty_runtime_error<'b>(
             );
 
             title = MODULE_NOT_IMPORTED;
+            code = "E0219";
         }
         RuntimeError::ReadIngestedFileError {
             filename,
@@ -2258,6 +2385,7 @@ fn pretty_runtime_error<'b>(
 
             doc = report.doc;
             title = INGESTED_FILE_ERROR;
+            code = "E0220";
         }
         RuntimeError::IngestedFilePathError(region) => {
             doc = alloc.stack([
@@ -2266,6 +2394,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = INGESTED_FILE_ERROR;
+            code = "E0220";
         }
         RuntimeError::InvalidPrecedence(_, _) => {
             // do nothing, reported with PrecedenceProblem
@@ -2275,6 +2404,7 @@ fn pretty_runtime_error<'b>(
             doc = to_bad_ident_expr_report(alloc, lines, bad_ident, surroundings, severity);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::MalformedTypeName(_box_str, surroundings) => {
             doc = alloc.stack([
@@ -2291,6 +2421,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::InvalidFloat(sign @ FloatErrorKind::PositiveInfinity, region, _raw_str)
         | RuntimeError::InvalidFloat(sign @ FloatErrorKind::NegativeInfinity, region, _raw_str) => {
@@ -2322,6 +2453,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::InvalidFloat(FloatErrorKind::Error, region, _raw_str) => {
             let tip = alloc
@@ -2340,6 +2472,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::InvalidFloat(FloatErrorKind::IntSuffix, region, _raw_str) => {
             doc = alloc.stack([
@@ -2350,6 +2483,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = CONFLICTING_NUMBER_SUFFIX;
+            code = "E0222";
         }
         RuntimeError::InvalidInt(error @ IntErrorKind::InvalidDigit, base, region, _raw_str)
         | RuntimeError::InvalidInt(error @ IntErrorKind::Empty, base, region, _raw_str) => {
@@ -2411,6 +2545,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::InvalidInt(error_kind @ IntErrorKind::Underflow, _base, region, _raw_str)
         | RuntimeError::InvalidInt(error_kind @ IntErrorKind::Overflow, _base, region, _raw_str) => {
@@ -2454,6 +2589,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::InvalidInt(IntErrorKind::FloatSuffix, _base, region, _raw_str) => {
             doc = alloc.stack([
@@ -2464,6 +2600,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = CONFLICTING_NUMBER_SUFFIX;
+            code = "E0222";
         }
         RuntimeError::InvalidInt(
             IntErrorKind::OverflowsSuffix {
@@ -2488,6 +2625,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = NUMBER_OVERFLOWS_SUFFIX;
+            code = "E0223";
         }
         RuntimeError::InvalidInt(
             IntErrorKind::UnderflowsSuffix {
@@ -2512,6 +2650,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = NUMBER_UNDERFLOWS_SUFFIX;
+            code = "E0224";
         }
         RuntimeError::InvalidTupleIndex(region) => {
             doc = alloc.stack([
@@ -2526,6 +2665,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = NUMBER_UNDERFLOWS_SUFFIX;
+            code = "E0224";
         }
         RuntimeError::InvalidOptionalValue {
             field_name,
@@ -2541,6 +2681,7 @@ fn pretty_runtime_error<'b>(
             );
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::InvalidIgnoredValue {
             field_name,
@@ -2556,6 +2697,7 @@ fn pretty_runtime_error<'b>(
             );
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::InvalidRecordUpdate { region } => {
             doc = alloc.stack([
@@ -2568,6 +2710,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::InvalidHexadecimal(region) => {
             todo!(
@@ -2601,6 +2744,7 @@ fn pretty_runtime_error<'b>(
                 .append(alloc.reflow(", but it was not defined anywhere in that module."))]);
 
             title = MISSING_DEFINITION;
+            code = "E0212";
         }
         RuntimeError::EmptySingleQuote(region) => {
             let tip = alloc
@@ -2614,6 +2758,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::MultipleCharsInSingleQuote(region) => {
             let tip = alloc
@@ -2630,6 +2775,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            code = "E0108";
         }
         RuntimeError::OpaqueNotDefined {
             usage:
@@ -2680,6 +2826,7 @@ fn pretty_runtime_error<'b>(
             doc = alloc.stack(stack);
 
             title = OPAQUE_NOT_DEFINED;
+            code = "E0225";
         }
         RuntimeError::OpaqueOutsideScope {
             opaque,
@@ -2701,6 +2848,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = OPAQUE_DECLARED_OUTSIDE_SCOPE;
+            code = "E0226";
         }
         RuntimeError::OpaqueNotApplied(loc_ident) => {
             doc = alloc.stack([
@@ -2710,6 +2858,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = OPAQUE_NOT_APPLIED;
+            code = "E0227";
         }
         RuntimeError::OpaqueAppliedToMultipleArgs(region) => {
             doc = alloc.stack([
@@ -2719,6 +2868,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = OPAQUE_OVER_APPLIED;
+            code = "E0228";
         }
         RuntimeError::DegenerateBranch(region) => {
             doc = alloc.stack([
@@ -2727,6 +2877,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = "DEGENERATE BRANCH";
+            code = "E0274";
         }
         RuntimeError::EmptyRecordBuilder(region) => {
             doc = alloc.stack([
@@ -2736,6 +2887,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = "EMPTY RECORD BUILDER";
+            code = "E0275";
         }
         RuntimeError::SingleFieldRecordBuilder(region) => {
             doc = alloc.stack([
@@ -2745,6 +2897,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = "NOT ENOUGH FIELDS IN RECORD BUILDER";
+            code = "E0276";
         }
         RuntimeError::OptionalFieldInRecordBuilder {
             record: record_region,
@@ -2761,6 +2914,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = "OPTIONAL FIELD IN RECORD BUILDER";
+            code = "E0277";
         }
         RuntimeError::NonFunctionHostedAnnotation(region) => {
             doc = alloc.stack([
@@ -2770,10 +2924,11 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = "NON-FUNCTION HOSTED ANNOTATION";
+            code = "E0278";
         }
     }
 
-    (doc, title)
+    (doc, title, code)
 }
 
 pub fn to_circular_def_doc<'b>(
@@ -2859,18 +3014,21 @@ fn not_found<'b>(
         None => alloc.reflow("Did you mean one of these?"),
     };
 
-    let to_details = |no_suggestion_details, yes_suggestion_details| {
-        if suggestions.is_empty() {
-            no_suggestion_details
-        } else {
-            alloc.stack([
-                yes_suggestion_details,
-                alloc
-                    .vcat(suggestions.into_iter().map(|v| alloc.string(v.to_string())))
-                    .indent(4),
-            ])
-        }
-    };
+    let to_details =
+        |no_suggestion_details, yes_suggestion_details| {
+            if suggestions.is_empty() {
+                no_suggestion_details
+            } else {
+                alloc.stack([
+                    yes_suggestion_details,
+                    alloc
+                        .vcat(suggestions.into_iter().map(|v| {
+                            alloc.replacement(lines.convert_region(region), v.to_string())
+                        }))
+                        .indent(4),
+                ])
+            }
+        };
 
     alloc.stack([
         alloc.concat([
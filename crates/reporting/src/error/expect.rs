@@ -169,6 +169,7 @@ impl<'a> Renderer<'a> {
 
         let report = Report {
             title: "EXPECT FAILED".into(),
+            code: Some("E0424"),
             doc,
             filename: self.filename.clone(),
             severity,
@@ -247,6 +248,7 @@ impl<'a> Renderer<'a> {
 
         let report = Report {
             title: "EXPECT PANICKED".into(),
+            code: Some("E0425"),
             doc,
             filename: self.filename.clone(),
             severity,
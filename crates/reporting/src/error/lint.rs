@@ -0,0 +1,150 @@
+use roc_problem::lint::LintProblem;
+use roc_region::all::{LineInfo, Region};
+use std::path::PathBuf;
+
+use crate::report::{Report, RocDocAllocator};
+use ven_pretty::DocAllocator;
+
+const REDUNDANT_WHEN_BRANCH: &str = "REDUNDANT WHEN BRANCH";
+const CRASH_ON_LIST_GET: &str = "CRASH ON LIST GET";
+const CONFUSABLE_NAMES: &str = "CONFUSABLE NAMES";
+const MANUAL_TRY: &str = "MANUAL TRY";
+const LEFTOVER_DBG: &str = "LEFTOVER DBG";
+
+pub fn lint_problem<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    problem: LintProblem,
+) -> Report<'b> {
+    let doc;
+    let title;
+    let code;
+    let severity = problem.severity();
+
+    match problem {
+        LintProblem::RedundantWhenBranch {
+            region,
+            previous_branch,
+            fix,
+        } => {
+            let combine = match fix {
+                Some(fix) => alloc.stack([
+                    alloc.reflow("Their patterns can be combined with `|` instead:"),
+                    alloc
+                        .replacement(lines.convert_region(fix.region), fix.replacement)
+                        .indent(4),
+                ]),
+                None => alloc.reflow(
+                    "If they're meant to do the same thing, their patterns can be combined with `|`.",
+                ),
+            };
+
+            doc = alloc.stack([
+                alloc.reflow("This branch does the same thing as the one before it:"),
+                alloc.region_with_subregion(
+                    lines.convert_region(Region::span_across(&previous_branch, &region)),
+                    lines.convert_region(region),
+                    severity,
+                ),
+                combine,
+            ]);
+
+            title = REDUNDANT_WHEN_BRANCH.to_string();
+            code = "E0501";
+        }
+        LintProblem::CrashOnListGet { region, crash } => {
+            doc = alloc.stack([
+                alloc.reflow("This `when` crashes if the index is out of bounds:"),
+                alloc.region_with_subregion(
+                    lines.convert_region(region),
+                    lines.convert_region(crash),
+                    severity,
+                ),
+                alloc.concat([
+                    alloc.reflow("Crashing ends the whole program. Consider handling the "),
+                    alloc.tag("OutOfBounds".into()),
+                    alloc.reflow(" error instead, by returning it with `?` or by using "),
+                    alloc.keyword("Result.with_default"),
+                    alloc.reflow(" to fall back to a default element."),
+                ]),
+            ]);
+
+            title = CRASH_ON_LIST_GET.to_string();
+            code = "E0502";
+        }
+        LintProblem::ConfusableNames {
+            original,
+            confusable,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("The name "),
+                    alloc.symbol_unqualified(confusable.value),
+                    alloc.reflow(" is easy to confuse with "),
+                    alloc.symbol_unqualified(original.value),
+                    alloc.reflow(", which is defined nearby:"),
+                ]),
+                alloc.region(lines.convert_region(original.region), severity),
+                alloc.reflow("And here it is:"),
+                alloc.region(lines.convert_region(confusable.region), severity),
+                alloc.reflow(
+                    "Names that only differ by underscores or a number at the end are easy to mix up. Consider a name that says how the two values are different.",
+                ),
+            ]);
+
+            title = CONFUSABLE_NAMES.to_string();
+            code = "E0503";
+        }
+        LintProblem::ManualTry { region, fix } => {
+            doc = alloc.stack([
+                alloc.reflow(
+                    "This `when` unwraps an `Ok` and returns an `Err` early, which is what `?` does:",
+                ),
+                alloc.region(lines.convert_region(region), severity),
+                alloc.reflow("It can be written like this instead:"),
+                alloc
+                    .replacement(lines.convert_region(fix.region), fix.replacement)
+                    .indent(4),
+            ]);
+
+            title = MANUAL_TRY.to_string();
+            code = "E0504";
+        }
+        LintProblem::LeftoverDbg { region, fix } => {
+            // Removing a `dbg` statement leaves nothing to show
+            let remove = match fix.filter(|fix| !fix.replacement.is_empty()) {
+                Some(fix) => alloc.stack([
+                    alloc.reflow("If you're done debugging, replace it with the value itself:"),
+                    alloc
+                        .replacement(lines.convert_region(fix.region), fix.replacement)
+                        .indent(4),
+                ]),
+                None => alloc.reflow("If you're done debugging, remove it."),
+            };
+
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.keyword("dbg"),
+                    alloc.reflow(" is outside of an "),
+                    alloc.keyword("expect"),
+                    alloc.reflow(":"),
+                ]),
+                alloc.region(lines.convert_region(region), severity),
+                remove,
+            ]);
+
+            title = LEFTOVER_DBG.to_string();
+            code = "E0505";
+        }
+    }
+
+    Report {
+        title,
+        code: Some(code),
+        filename,
+        doc,
+        severity,
+    }
+}
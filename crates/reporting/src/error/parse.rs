@@ -75,6 +75,7 @@ fn to_syntax_report<'a>(
         filename: filename.clone(),
         doc,
         title: "PARSE PROBLEM".to_string(),
+        code: Some("E0101"),
         severity,
     };
 
@@ -89,6 +90,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                code: Some("E0101"),
                 severity,
             }
         }
@@ -121,6 +123,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "NOT END OF FILE".to_string(),
+                code: Some("E0102"),
                 severity,
             }
         }
@@ -134,6 +137,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                code: Some("E0101"),
                 severity,
             }
         }
@@ -144,6 +148,7 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                code: Some("E0101"),
                 severity,
             }
         }
@@ -236,6 +241,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
+                code: Some("E0103"),
                 severity,
             }
         }
@@ -352,6 +358,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "UNKNOWN OPERATOR".to_string(),
+                code: Some("E0104"),
                 severity,
             }
         }
@@ -379,14 +386,16 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IDENTIFIER".to_string(),
+                code: Some("E0105"),
                 severity,
             }
         }
 
         EExpr::Start(pos) | EExpr::IndentStart(pos) => {
-            let (title, expecting) = match &context {
+            let (title, code, expecting) = match &context {
                 Context::InNode { .. } | Context::InDef { .. } => (
                     "MISSING EXPRESSION",
+                    "E0106",
                     alloc.concat([
                         alloc.reflow("I was expecting to see an expression like "),
                         alloc.parser_suggestion("42"),
@@ -397,6 +406,7 @@ fn to_expr_report<'a>(
                 ),
                 Context::InDefFinalExpr { .. } => (
                     "MISSING FINAL EXPRESSION",
+                    "E0107",
                     alloc.stack([
                         alloc.concat([
                             alloc.reflow("This definition is missing a final expression."),
@@ -462,6 +472,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: title.to_string(),
+                code: Some(code),
                 severity,
             }
         }
@@ -490,6 +501,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "MISSING FINAL EXPRESSION".to_string(),
+                code: Some("E0107"),
                 severity,
             }
         }
@@ -521,6 +533,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "SYNTAX PROBLEM".to_string(),
+                code: Some("E0108"),
                 severity,
             }
         }
@@ -544,6 +557,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
+                code: Some("E0103"),
                 severity,
             }
         }
@@ -602,6 +616,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "INDENT ENDS AFTER EXPRESSION".to_string(),
+                code: Some("E0109"),
                 severity,
             }
         }
@@ -629,6 +644,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "TRAILING OPERATOR".to_string(),
+                code: Some("E0110"),
                 severity,
             }
         }
@@ -646,6 +662,7 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "UNEXPECTED COMMA".to_string(),
+                code: Some("E0111"),
                 severity,
             }
         }
@@ -734,6 +751,7 @@ fn to_unhandled_parse_error_report<'a>(
         filename,
         doc,
         title: "UNHANDLED PARSE ERROR".to_string(),
+        code: Some("E0112"),
         severity,
     }
 }
@@ -760,6 +778,7 @@ fn to_record_report<'a>(
         filename,
         doc,
         title: "RECORD PARSE PROBLEM".to_string(),
+        code: Some("E0113"),
         severity,
     }
 }
@@ -794,6 +813,7 @@ fn to_lambda_report<'a>(
                 filename,
                 doc,
                 title: "MALFORMED ARGS LIST".to_string(),
+                code: Some("E0114"),
                 severity,
             }
         }
@@ -817,6 +837,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD ARROW".to_string(),
+                    code: Some("E0115"),
                     severity,
                 }
             }
@@ -839,6 +860,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    code: Some("E0116"),
                     severity,
                 }
             }
@@ -864,6 +886,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD ARROW".to_string(),
+                    code: Some("E0115"),
                     severity,
                 }
             }
@@ -886,6 +909,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    code: Some("E0116"),
                     severity,
                 }
             }
@@ -910,6 +934,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED ARGUMENT LIST".to_string(),
+                    code: Some("E0117"),
                     severity,
                 }
             }
@@ -931,6 +956,7 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    code: Some("E0116"),
                     severity,
                 }
             }
@@ -1013,6 +1039,7 @@ fn to_unfinished_lambda_report<'a>(
         filename,
         doc,
         title: "UNFINISHED FUNCTION".to_string(),
+        code: Some("E0118"),
         severity,
     }
 }
@@ -1078,6 +1105,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD ESCAPE".to_string(),
+                code: Some("E0119"),
                 severity,
             }
         }
@@ -1104,6 +1132,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD CODE POINT".to_string(),
+                code: Some("E0120"),
                 severity,
             }
         }
@@ -1125,6 +1154,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS FORMAT".to_string(),
+                code: Some("E0121"),
                 severity,
             }
         }
@@ -1148,6 +1178,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS SCALAR".to_string(),
+                code: Some("E0122"),
                 severity,
             }
         }
@@ -1214,6 +1245,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "INVALID SCALAR".to_string(),
+                code: Some("E0123"),
                 severity,
             }
         }
@@ -1237,6 +1269,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS STRING".to_string(),
+                code: Some("E0124"),
                 severity,
             }
         }
@@ -1261,6 +1294,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "EXPECTED STRING".to_string(),
+                code: Some("E0125"),
                 severity,
             }
         }
@@ -1284,6 +1318,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS STRING".to_string(),
+                code: Some("E0124"),
                 severity,
             }
         }
@@ -1305,6 +1340,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "INSUFFICIENT INDENT IN MULTI-LINE STRING".to_string(),
+                code: Some("E0126"),
                 severity,
             }
         }
@@ -1330,6 +1366,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "INVALID UNICODE CODE POINT".to_string(),
+                code: Some("E0127"),
                 severity,
             }
         }
@@ -1351,6 +1388,7 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "UNICODE CODE POINT TOO LARGE".to_string(),
+                code: Some("E0128"),
                 severity,
             }
         }
@@ -1393,6 +1431,7 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
+                code: Some("E0129"),
                 severity,
             }
         }
@@ -1417,6 +1456,7 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some("E0130"),
                 severity,
             }
         }
@@ -1442,6 +1482,7 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some("E0130"),
                 severity,
             }
         }
@@ -1496,6 +1537,7 @@ fn to_list_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED LIST".to_string(),
+                        code: Some("E0131"),
                         severity,
                     }
                 }
@@ -1533,6 +1575,7 @@ fn to_list_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED LIST".to_string(),
+                        code: Some("E0131"),
                         severity,
                     }
                 }
@@ -1659,6 +1702,7 @@ fn to_import_report<'a>(
                 filename,
                 doc,
                 title: "IGNORED RECORD FIELD IN MODULE PARAMS".to_string(),
+                code: Some("E0132"),
                 severity,
             }
         }
@@ -1676,6 +1720,7 @@ fn to_import_report<'a>(
                 filename,
                 doc,
                 title: "RECORD UPDATE IN MODULE PARAMS".to_string(),
+                code: Some("E0133"),
                 severity,
             }
         }
@@ -1693,6 +1738,7 @@ fn to_import_report<'a>(
                 filename,
                 doc,
                 title: "RECORD BUILDER IN MODULE PARAMS".to_string(),
+                code: Some("E0134"),
                 severity,
             }
         }
@@ -1722,6 +1768,7 @@ fn to_import_report<'a>(
                 filename,
                 doc,
                 title: "LOWERCASE ALIAS".to_string(),
+                code: Some("E0135"),
                 severity,
             }
         }
@@ -1757,6 +1804,7 @@ fn to_import_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSING".to_string(),
+                code: Some("E0136"),
                 severity,
             }
         }
@@ -1819,6 +1867,7 @@ fn to_unfinished_import_report<'a>(
         filename,
         doc,
         title: "UNFINISHED IMPORT".to_string(),
+        code: Some("E0137"),
         severity,
     }
 }
@@ -1932,6 +1981,7 @@ fn to_unfinished_if_report<'a>(
         filename,
         doc,
         title: "UNFINISHED IF".to_string(),
+        code: Some("E0138"),
         severity,
     }
 }
@@ -1969,6 +2019,7 @@ fn to_when_report<'a>(
                         filename,
                         doc,
                         title: "IF GUARD NO CONDITION".to_string(),
+                        code: Some("E0139"),
                         severity,
                     }
                 }
@@ -2001,6 +2052,7 @@ fn to_when_report<'a>(
                 filename,
                 doc,
                 title: "MISSING ARROW".to_string(),
+                code: Some("E0116"),
                 severity,
             }
         }
@@ -2156,6 +2208,7 @@ fn to_unfinished_when_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED WHEN".to_string(),
+                code: Some("E0140"),
                 severity,
             }
         }
@@ -2198,6 +2251,7 @@ fn to_unexpected_arrow_report<'a>(
         filename,
         doc,
         title: "UNEXPECTED ARROW".to_string(),
+        code: Some("E0141"),
         severity,
     }
 }
@@ -2275,6 +2329,7 @@ fn to_pattern_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PATTERN".to_string(),
+                code: Some("E0142"),
                 severity,
             }
         }
@@ -2343,6 +2398,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    code: Some("E0143"),
                     severity,
                 }
             }
@@ -2364,6 +2420,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    code: Some("E0143"),
                     severity,
                 }
             }
@@ -2389,6 +2446,7 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
+                        code: Some("E0143"),
                         severity,
                     }
                 }
@@ -2409,6 +2467,7 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
+                        code: Some("E0143"),
                         severity,
                     }
                 }
@@ -2434,6 +2493,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    code: Some("E0143"),
                     severity,
                 }
             }
@@ -2459,6 +2519,7 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "PROBLEM IN RECORD PATTERN".to_string(),
+                    code: Some("E0144"),
                     severity,
                 }
             }
@@ -2509,6 +2570,7 @@ fn to_plist_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED LIST PATTERN".to_string(),
+                code: Some("E0145"),
                 severity,
             }
         }
@@ -2531,6 +2593,7 @@ fn to_plist_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED LIST PATTERN".to_string(),
+                code: Some("E0145"),
                 severity,
             }
         }
@@ -2553,6 +2616,7 @@ fn to_plist_report<'a>(
                 filename,
                 doc,
                 title: "INCORRECT REST PATTERN".to_string(),
+                code: Some("E0146"),
                 severity,
             }
         }
@@ -2598,6 +2662,7 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some("E0130"),
                 severity,
             }
         }
@@ -2620,6 +2685,7 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
+                code: Some("E0129"),
                 severity,
             }
         }
@@ -2644,6 +2710,7 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some("E0130"),
                 severity,
             }
         }
@@ -2673,6 +2740,7 @@ fn to_malformed_number_literal_report<'a>(
         filename,
         doc,
         title: "INVALID NUMBER LITERAL".to_string(),
+        code: Some("E0147"),
         severity,
     }
 }
@@ -2714,6 +2782,7 @@ fn to_type_report<'a>(
                         filename,
                         doc,
                         title: "DOUBLE COMMA".to_string(),
+                        code: Some("E0148"),
                         severity,
                     }
                 }
@@ -2741,6 +2810,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                code: Some("E0149"),
                 severity,
             }
         }
@@ -2759,6 +2829,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                code: Some("E0149"),
                 severity,
             }
         }
@@ -2777,6 +2848,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                code: Some("E0149"),
                 severity,
             }
         }
@@ -2795,6 +2867,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED INLINE ALIAS".to_string(),
+                code: Some("E0150"),
                 severity,
             }
         }
@@ -2812,6 +2885,7 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "BAD TYPE VARIABLE".to_string(),
+                code: Some("E0151"),
                 severity,
             }
         }
@@ -2866,6 +2940,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    code: Some("E0152"),
                     severity,
                 }
             }
@@ -2891,6 +2966,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    code: Some("E0152"),
                     severity,
                 }
             }
@@ -2916,6 +2992,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        code: Some("E0152"),
                         severity,
                     }
                 }
@@ -2936,6 +3013,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        code: Some("E0152"),
                         severity,
                     }
                 }
@@ -2961,6 +3039,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    code: Some("E0152"),
                     severity,
                 }
             }
@@ -2986,6 +3065,7 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "PROBLEM IN RECORD TYPE".to_string(),
+                    code: Some("E0153"),
                     severity,
                 }
             }
@@ -3019,6 +3099,7 @@ fn to_trecord_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED RECORD TYPE".to_string(),
+                code: Some("E0152"),
                 severity,
             }
         }
@@ -3043,6 +3124,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        code: Some("E0154"),
                         severity,
                     }
                 }
@@ -3072,6 +3154,7 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        code: Some("E0152"),
                         severity,
                     }
                 }
@@ -3121,6 +3204,7 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
+                    code: Some("E0155"),
                     severity,
                 }
             }
@@ -3147,6 +3231,7 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD TAG NAME".to_string(),
+                    code: Some("E0156"),
                     severity,
                 }
             }
@@ -3172,6 +3257,7 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
+                    code: Some("E0155"),
                     severity,
                 }
             }
@@ -3202,6 +3288,7 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        code: Some("E0156"),
                         severity,
                     }
                 }
@@ -3222,6 +3309,7 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED TAG UNION TYPE".to_string(),
+                        code: Some("E0155"),
                         severity,
                     }
                 }
@@ -3267,6 +3355,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: Some("E0130"),
                         severity,
                     }
                 }
@@ -3289,6 +3378,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        code: Some("E0156"),
                         severity,
                     }
                 }
@@ -3316,6 +3406,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: Some("E0130"),
                         severity,
                     }
                 }
@@ -3339,6 +3430,7 @@ fn to_tinparens_report<'a>(
                 filename,
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
+                code: Some("E0129"),
                 severity,
             }
         }
@@ -3365,6 +3457,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        code: Some("E0156"),
                         severity,
                     }
                 }
@@ -3385,6 +3478,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: Some("E0130"),
                         severity,
                     }
                 }
@@ -3413,6 +3507,7 @@ fn to_tinparens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                code: Some("E0130"),
                 severity,
             }
         }
@@ -3437,6 +3532,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        code: Some("E0154"),
                         severity,
                     }
                 }
@@ -3462,6 +3558,7 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        code: Some("E0130"),
                         severity,
                     }
                 }
@@ -3496,6 +3593,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "DOUBLE DOT".to_string(),
+                code: Some("E0157"),
                 severity,
             }
         }
@@ -3518,6 +3616,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "TRAILING DOT".to_string(),
+                code: Some("E0158"),
                 severity,
             }
         }
@@ -3540,6 +3639,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
+                code: Some("E0159"),
                 severity,
             }
         }
@@ -3562,6 +3662,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
+                code: Some("E0159"),
                 severity,
             }
         }
@@ -3580,6 +3681,7 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "END OF FILE".to_string(),
+                code: Some("E0160"),
                 severity,
             }
         }
@@ -3621,6 +3723,7 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "NOT AN INLINE ALIAS".to_string(),
+                code: Some("E0161"),
                 severity,
             }
         }
@@ -3637,6 +3740,7 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "QUALIFIED ALIAS NAME".to_string(),
+                code: Some("E0162"),
                 severity,
             }
         }
@@ -3653,6 +3757,7 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "TYPE ARGUMENT NOT LOWERCASE".to_string(),
+                code: Some("E0163"),
                 severity,
             }
         }
@@ -3703,6 +3808,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INCOMPLETE HEADER".to_string(),
+                code: Some("E0164"),
                 severity,
             }
         }
@@ -3745,6 +3851,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "MISSING HEADER".to_string(),
+                code: Some("E0165"),
                 severity,
             }
         }
@@ -3769,6 +3876,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                code: Some("E0166"),
                 severity,
             }
         }
@@ -3796,6 +3904,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                code: Some("E0166"),
                 severity,
             }
         }
@@ -3820,6 +3929,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD APP NAME".to_string(),
+                code: Some("E0167"),
                 severity,
             }
         }
@@ -3842,6 +3952,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INVALID PACKAGE NAME".to_string(),
+                code: Some("E0168"),
                 severity,
             }
         }
@@ -3865,6 +3976,7 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INVALID PLATFORM NAME".to_string(),
+                code: Some("E0169"),
                 severity,
             }
         }
@@ -3906,6 +4018,7 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                code: Some("E0170"),
                 severity,
             }
         }
@@ -3931,6 +4044,7 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                code: Some("E0170"),
                 severity,
             }
         }
@@ -3958,6 +4072,7 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                code: Some("E0170"),
                 severity,
             }
         }
@@ -3979,6 +4094,7 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                code: Some("E0170"),
                 severity,
             }
         }
@@ -4029,6 +4145,7 @@ fn to_params_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE PARAMS".to_string(),
+                code: Some("E0171"),
                 severity,
             }
         }
@@ -4073,6 +4190,7 @@ fn to_exposes_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
+                code: Some("E0172"),
                 severity,
             }
         }
@@ -4096,6 +4214,7 @@ fn to_exposes_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
+                code: Some("E0172"),
                 severity,
             }
         }
@@ -4133,6 +4252,7 @@ fn to_exposes_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
+                code: Some("E0172"),
                 severity,
             }
         }
@@ -4183,6 +4303,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                code: Some("E0173"),
                 severity,
             }
         }
@@ -4208,6 +4329,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                code: Some("E0173"),
                 severity,
             }
         }
@@ -4234,6 +4356,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                code: Some("E0166"),
                 severity,
             }
         }
@@ -4253,6 +4376,7 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                code: Some("E0173"),
                 severity,
             }
         }
@@ -4314,6 +4438,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "MISSING REQUIRES".to_string(),
+                code: Some("E0174"),
                 severity,
             }
         }
@@ -4341,6 +4466,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "MISSING REQUIRES".to_string(),
+                code: Some("E0174"),
                 severity,
             }
         }
@@ -4370,6 +4496,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "BAD REQUIRES RIGIDS".to_string(),
+                code: Some("E0175"),
                 severity,
             }
         }
@@ -4399,6 +4526,7 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "BAD REQUIRES".to_string(),
+                code: Some("E0176"),
                 severity,
             }
         }
@@ -4448,6 +4576,7 @@ fn to_packages_report<'a>(
                 filename,
                 doc,
                 title: "MISSING PACKAGES".to_string(),
+                code: Some("E0177"),
                 severity,
             }
         }
@@ -4470,6 +4599,7 @@ fn to_packages_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PACKAGES LIST".to_string(),
+                code: Some("E0178"),
                 severity,
             }
         }
@@ -4521,6 +4651,7 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "TAB CHARACTER".to_string(),
+                code: Some("E0179"),
                 severity,
             }
         }
@@ -4538,6 +4669,7 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "ASCII CONTROL CHARACTER".to_string(),
+                code: Some("E0180"),
                 severity,
             }
         }
@@ -4555,6 +4687,7 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "MISPLACED CARRIAGE RETURN".to_string(),
+                code: Some("E0181"),
                 severity,
             }
         }
@@ -4646,6 +4779,7 @@ fn to_unfinished_ability_report<'a>(
         filename,
         doc,
         title: "UNFINISHED ABILITY".to_string(),
+        code: Some("E0182"),
         severity,
     }
 }
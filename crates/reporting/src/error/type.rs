@@ -17,7 +17,8 @@ use roc_module::symbol::Symbol;
 use roc_problem::Severity;
 use roc_region::all::{LineInfo, Region};
 use roc_solve_problem::{
-    NotDerivableContext, NotDerivableEq, TypeError, UnderivableReason, Unfulfilled,
+    NotDerivableContext, NotDerivableEq, NotDerivableSort, TypeError, UnderivableReason,
+    Unfulfilled,
 };
 use roc_std::RocDec;
 use roc_types::pretty_print::{Parens, WILDCARD};
@@ -46,15 +47,15 @@ pub fn type_problem<'b>(
 
     let severity = problem.severity();
 
-    let report =
-        move |title: String, doc: RocDocBuilder<'b>, filename: PathBuf| -> Option<Report<'b>> {
-            Some(Report {
-                title,
-                filename,
-                doc,
-                severity,
-            })
-        };
+    let report = move |title: String, code, doc, filename| -> Option<Report<'b>> {
+        Some(Report {
+            title,
+            code: Some(code),
+            filename,
+            doc,
+            severity,
+        })
+    };
 
     match problem {
         BadExpr(region, category, found, expected) => Some(to_expr_report(
@@ -74,6 +75,7 @@ pub fn type_problem<'b>(
         )),
         UnexposedLookup(_, symbol) => {
             let title = "UNRECOGNIZED NAME".to_string();
+            let code = "E0202";
             let doc = alloc
                 .stack(vec![alloc
                     .reflow("The ")
@@ -82,14 +84,15 @@ pub fn type_problem<'b>(
                     .append(alloc.symbol_unqualified(symbol))])
                 .append(alloc.reflow("."));
 
-            report(title, doc, filename)
+            report(title, code, doc, filename)
         }
         UnfulfilledAbility(incomplete) => {
             let title = "INCOMPLETE ABILITY IMPLEMENTATION".to_string();
+            let code = "E0247";
 
             let doc = report_unfulfilled_ability(alloc, lines, incomplete, severity);
 
-            report(title, doc, filename)
+            report(title, code, doc, filename)
         }
         BadExprMissingAbility(region, _category, _found, incomplete) => {
             if region == roc_can::DERIVED_REGION {
@@ -111,6 +114,7 @@ pub fn type_problem<'b>(
 
             let report = Report {
                 title: "TYPE MISMATCH".to_string(),
+                code: Some("E0301"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -133,6 +137,7 @@ pub fn type_problem<'b>(
 
             let report = Report {
                 title: "TYPE MISMATCH".to_string(),
+                code: Some("E0301"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -143,9 +148,11 @@ pub fn type_problem<'b>(
         CircularDef(entries) => {
             let doc = to_circular_def_doc(alloc, lines, &entries, severity);
             let title = CIRCULAR_DEF.to_string();
+            let code = "E0216";
 
             Some(Report {
                 title,
+                code: Some(code),
                 filename,
                 doc,
                 severity,
@@ -176,6 +183,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "ILLEGAL SPECIALIZATION".to_string(),
+                code: Some("E0302"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -205,6 +213,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "WRONG SPECIALIZATION TYPE".to_string(),
+                code: Some("E0303"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -221,6 +230,7 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "INVALID UTF-8".to_string(),
+                code: Some("E0304"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -244,6 +254,7 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "INVALID TYPE FOR INGESTED FILE".to_string(),
+                code: Some("E0305"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -264,6 +275,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "UNEXPECTED MODULE PARAMS".to_string(),
+                code: Some("E0306"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -286,6 +298,7 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "MISSING MODULE PARAMS".to_string(),
+                code: Some("E0307"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -311,6 +324,7 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "MODULE PARAMS MISMATCH".to_string(),
+                code: Some("E0308"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -345,6 +359,7 @@ pub fn type_problem<'b>(
             Some(Report {
                 filename,
                 title: "EFFECT IN PURE FUNCTION".to_string(),
+                code: Some("E0309"),
                 doc: alloc.stack(lines),
                 severity,
             })
@@ -365,6 +380,7 @@ pub fn type_problem<'b>(
             Some(Report {
                 filename,
                 title: "EFFECT IN TOP-LEVEL".to_string(),
+                code: Some("E0310"),
                 doc: alloc.stack(lines),
                 severity,
             })
@@ -380,6 +396,7 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "LEFTOVER STATEMENT".to_string(),
+                code: Some("E0311"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -394,6 +411,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "UNNECESSARY DEFINITION".to_string(),
+                code: Some("E0257"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -423,6 +441,7 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "MISSING EXCLAMATION".to_string(),
+                code: Some("E0248"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -442,6 +461,7 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "MISSING EXCLAMATION".to_string(),
+                code: Some("E0248"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -468,6 +488,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "UNNECESSARY EXCLAMATION".to_string(),
+                code: Some("E0249"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -508,6 +529,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "INVALID TRY TARGET".to_string(),
+                code: Some("E0312"),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
@@ -540,6 +562,7 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "TYPE VARIABLE IS NOT GENERIC".to_string(),
+                code: Some("E0313"),
                 filename,
                 doc,
                 severity,
@@ -735,6 +758,18 @@ fn underivable_hint<'b>(
                 ])))
             }
         },
+        NotDerivableContext::Sort(reason) => match reason {
+            NotDerivableSort::FloatingPoint => {
+                Some(alloc.note("").append(alloc.concat([
+                    alloc.reflow("I can't derive "),
+                    alloc.symbol_qualified(Symbol::SORT_COMPARE),
+                    alloc.reflow(" for floating-point types. That's because Roc's floating-point numbers cannot be put in a total order - in Roc, `NaN` is neither less than, greater than, nor equal to any number."),
+                    alloc.reflow(" If a type can't be put in a total order, it cannot support the "),
+                    alloc.symbol_unqualified(Symbol::SORT_SORT_ABILITY),
+                    alloc.reflow(" ability!"),
+                ])))
+            }
+        },
     }
 }
 
@@ -836,6 +871,7 @@ fn report_mismatch<'b>(
 
     Report {
         title: "TYPE MISMATCH".to_string(),
+        code: Some("E0301"),
         filename,
         doc: alloc.stack(lines),
         severity,
@@ -880,6 +916,7 @@ fn report_bad_type<'b>(
 
     Report {
         title: "TYPE MISMATCH".to_string(),
+        code: Some("E0301"),
         filename,
         doc: alloc.stack(lines),
         severity,
@@ -964,6 +1001,7 @@ fn to_expr_report<'b>(
             Report {
                 filename,
                 title: "TYPE MISMATCH".to_string(),
+                code: Some("E0301"),
                 doc: alloc.stack([
                     alloc.text("This expression is used in an unexpected way:"),
                     alloc.region(lines.convert_region(expr_region), severity),
@@ -1105,6 +1143,7 @@ fn to_expr_report<'b>(
 
             Report {
                 title: "TYPE MISMATCH".to_string(),
+                code: Some("E0301"),
                 filename,
                 doc: alloc.stack([
                     alloc.text("Something is off with the ").append(thing),
@@ -1503,6 +1542,7 @@ fn to_expr_report<'b>(
                     Report {
                         filename,
                         title: "TOO MANY ARGS".to_string(),
+                        code: Some("E0315"),
                         doc,
                         severity,
                     }
@@ -1538,6 +1578,7 @@ fn to_expr_report<'b>(
                         Report {
                             filename,
                             title: "TOO MANY ARGS".to_string(),
+                            code: Some("E0315"),
                             doc: alloc.stack(lines),
                             severity,
                         }
@@ -1565,6 +1606,7 @@ fn to_expr_report<'b>(
                         Report {
                             filename,
                             title: "TOO FEW ARGS".to_string(),
+                            code: Some("E0316"),
                             doc: alloc.stack(lines),
                             severity,
                         }
@@ -1766,6 +1808,7 @@ fn to_expr_report<'b>(
 
                 Report {
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some("E0301"),
                     filename,
                     doc: alloc.stack(lines),
                     severity,
@@ -1804,6 +1847,7 @@ fn to_expr_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some("E0301"),
                     doc,
                     severity,
                 }
@@ -1840,6 +1884,7 @@ fn to_expr_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some("E0301"),
                     doc: alloc.stack(lines),
                     severity,
                 }
@@ -1895,6 +1940,7 @@ fn to_expr_report<'b>(
 
                 Report {
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some("E0301"),
                     filename,
                     doc: alloc.stack([
                         problem,
@@ -1944,6 +1990,7 @@ fn to_expr_report<'b>(
                 Report {
                     filename,
                     title: "IGNORED RESULT".to_string(),
+                    code: Some("E0317"),
                     doc: alloc.stack(lines),
                     severity,
                 }
@@ -1974,6 +2021,7 @@ fn to_expr_report<'b>(
 
                 Report {
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some("E0301"),
                     filename,
                     doc: alloc.stack([
                         problem,
@@ -2391,6 +2439,7 @@ fn to_pattern_report<'b>(
             Report {
                 filename,
                 title: "TYPE MISMATCH".to_string(),
+                code: Some("E0301"),
                 doc,
                 severity,
             }
@@ -2434,6 +2483,7 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some("E0301"),
                     doc,
                     severity,
                 }
@@ -2516,6 +2566,7 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some("E0301"),
                     doc,
                     severity,
                 }
@@ -2543,6 +2594,7 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    code: Some("E0301"),
                     doc,
                     severity,
                 }
@@ -2627,6 +2679,7 @@ fn to_circular_report<'b>(
 ) -> Report<'b> {
     Report {
         title: "CIRCULAR TYPE".to_string(),
+        code: Some("E0318"),
         filename,
         doc: {
             alloc.stack([
@@ -5332,6 +5385,7 @@ fn report_record_field_typo<'b>(
     Report {
         filename,
         title: "TYPE MISMATCH".to_string(),
+        code: Some("E0301"),
         doc,
         severity,
     }
@@ -5369,6 +5423,7 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    code: Some("E0319"),
                     doc,
                     severity,
                 }
@@ -5393,6 +5448,7 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    code: Some("E0319"),
                     doc,
                     severity,
                 }
@@ -5417,6 +5473,7 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    code: Some("E0319"),
                     doc,
                     severity,
                 }
@@ -5447,6 +5504,7 @@ fn exhaustive_problem<'a>(
             Report {
                 filename,
                 title: "REDUNDANT PATTERN".to_string(),
+                code: Some("E0320"),
                 doc,
                 severity,
             }
@@ -5476,6 +5534,7 @@ fn exhaustive_problem<'a>(
             Report {
                 filename,
                 title: "UNMATCHABLE PATTERN".to_string(),
+                code: Some("E0321"),
                 doc,
                 severity,
             }
@@ -5526,9 +5585,10 @@ fn pattern_to_doc_help<'b>(
             Bit(true) => alloc.text("Bool.true"),
             Bit(false) => alloc.text("Bool.false"),
             Byte(b) => alloc.text(b.to_string()),
-            Float(f) => alloc.text(f.to_string()),
+            Float(f) => alloc.text(format!("{:?}", f64::from_bits(f))),
             Decimal(d) => alloc.text(RocDec::from_ne_bytes(d).to_string()),
-            Str(s) => alloc.string(s.into()),
+            Str(s) => alloc.string(str_literal_to_source(&s)),
+            Malformed => alloc.text("_"),
         },
         List(arity, patterns) => {
             let inner = match arity {
@@ -5659,6 +5719,27 @@ fn pattern_to_doc_help<'b>(
     }
 }
 
+/// Renders a string as a Roc string literal, so that it can be pasted into a pattern.
+fn str_literal_to_source(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len() + 2);
+
+    buf.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '$' => buf.push_str("\\$"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            _ => buf.push(c),
+        }
+    }
+    buf.push('"');
+
+    buf
+}
+
 fn describe_fx_call_kind<'b>(
     alloc: &'b RocDocAllocator<'b>,
     kind: FxCallKind,
@@ -0,0 +1,43 @@
+//! Stable codes for each kind of report, shown in report headers, and the longer explanations
+//! that `roc explain` prints for them.
+//!
+//! The codes and explanations live in `error_codes.md`, with one `## <CODE> <TITLE>` section per
+//! kind of report. Parse problems are `E01xx`, naming problems `E02xx`, type problems `E03xx`, and
+//! problems with files, packages, modules and expects `E04xx`, and the warnings from `roc lint`
+//! `E05xx`. A code is never reused or changed once assigned, so a new kind of report gets the next
+//! free code in its group.
+//!
+//! Each report sets its own [`code`](crate::report::Report::code) where it's built; this file is
+//! only used to list and explain the codes.
+
+const ERROR_CODES: &str = include_str!("error_codes.md");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCode {
+    pub code: &'static str,
+    pub title: &'static str,
+    pub explanation: &'static str,
+}
+
+/// Every error code, in order
+pub fn all() -> impl Iterator<Item = ErrorCode> {
+    ERROR_CODES
+        .strip_prefix("## ")
+        .unwrap_or(ERROR_CODES)
+        .split("\n## ")
+        .map(|section| {
+            let (heading, explanation) = section.split_once('\n').unwrap_or((section, ""));
+            let (code, title) = heading.split_once(' ').unwrap_or((heading, ""));
+
+            ErrorCode {
+                code,
+                title,
+                explanation: explanation.trim(),
+            }
+        })
+}
+
+/// Look up a code like `E0301`, ignoring case
+pub fn lookup(code: &str) -> Option<ErrorCode> {
+    all().find(|error_code| error_code.code.eq_ignore_ascii_case(code.trim()))
+}
@@ -13,6 +13,7 @@ use byte_unit::Byte;
 use roc_packaging::https::Problem;
 
 pub use crate::error::canonicalize::can_problem;
+pub use crate::error::lint::lint_problem;
 pub use crate::error::parse::parse_problem;
 pub use crate::error::r#type::type_problem;
 
@@ -67,9 +68,21 @@ pub fn cycle<'b>(
 
 const HEADER_WIDTH: usize = 80;
 
+/// The title followed by its error code, like `TYPE MISMATCH [E0301]`, if it has one
+pub fn title_with_code(title: &str, code: Option<&str>) -> String {
+    match code {
+        Some(code) => format!("{title} [{code}]"),
+        None => title.to_string(),
+    }
+}
+
 pub fn pretty_header(title: &str) -> String {
-    let title_width = title.len() + 4;
-    let header = format!("── {} {}", title, "─".repeat(HEADER_WIDTH - title_width));
+    let title_width = title.chars().count() + 4;
+    let header = format!(
+        "── {} {}",
+        title,
+        "─".repeat(HEADER_WIDTH.saturating_sub(title_width))
+    );
     header
 }
 
@@ -84,9 +97,11 @@ pub fn pretty_header_with_path(title: &str, path: &Path) -> String {
 
     let additional_path_display = "in";
     let additional_path_display_width = additional_path_display.len() + 1;
-    let title_width = title.len() + 4;
+    let title_width = title.chars().count() + 4;
     let relative_path_width = relative_path.len() + 1;
-    let available_path_width = HEADER_WIDTH - title_width - additional_path_display_width - 1;
+    let available_path_width = HEADER_WIDTH
+        .saturating_sub(title_width + additional_path_display_width + 1)
+        .max(4);
 
     // If path is too long to fit in 80 characters with everything else then truncate it
     let path_width = relative_path_width.min(available_path_width);
@@ -105,7 +120,9 @@ pub fn pretty_header_with_path(title: &str, path: &Path) -> String {
         title,
         additional_path_display,
         path,
-        "─".repeat(HEADER_WIDTH - (title_width + path_width + additional_path_display_width))
+        "─".repeat(
+            HEADER_WIDTH.saturating_sub(title_width + path_width + additional_path_display_width)
+        )
     );
 
     header
@@ -116,11 +133,25 @@ pub enum RenderTarget {
     ColorTerminal,
     Generic,
     LanguageServer,
+    /// One JSON object per report, each on its own line (see [`Report::to_json`])
+    Json,
+    /// A SARIF 2.1.0 log, for code scanning tools (see [`Report::to_sarif_result`])
+    Sarif,
+}
+
+impl RenderTarget {
+    /// Whether reports rendered to this target are meant to be read by another program,
+    /// so nothing else should be printed alongside them.
+    pub fn is_machine_readable(&self) -> bool {
+        matches!(self, RenderTarget::Json | RenderTarget::Sarif)
+    }
 }
 
 /// A textual report.
 pub struct Report<'b> {
     pub title: String,
+    /// The stable code for this kind of report, like `E0301`; see [`crate::error_code`]
+    pub code: Option<&'static str>,
     pub filename: PathBuf,
     pub doc: RocDocBuilder<'b>,
     pub severity: Severity,
@@ -138,6 +169,16 @@ impl<'b> Report<'b> {
             RenderTarget::Generic => self.render_ci(buf, alloc),
             RenderTarget::ColorTerminal => self.render_color_terminal(buf, alloc, palette),
             RenderTarget::LanguageServer => self.render_language_server(buf, alloc),
+            RenderTarget::Json => {
+                buf.push_str(&self.to_json().to_string());
+                buf.push('\n');
+            }
+            RenderTarget::Sarif => {
+                let log = Report::sarif_log(vec![self.to_sarif_result()]);
+
+                buf.push_str(&log.to_string());
+                buf.push('\n');
+            }
         }
     }
 
@@ -171,10 +212,11 @@ impl<'b> Report<'b> {
         if self.title.is_empty() {
             self.doc
         } else {
+            let title = title_with_code(&self.title, self.code);
             let header = if self.filename == PathBuf::from("replfile.roc") {
-                crate::report::pretty_header(&self.title)
+                crate::report::pretty_header(&title)
             } else {
-                crate::report::pretty_header_with_path(&self.title, &self.filename)
+                crate::report::pretty_header_with_path(&title, &self.filename)
             };
 
             alloc.stack([alloc.text(header).annotate(Annotation::Header), self.doc])
@@ -196,6 +238,136 @@ impl<'b> Report<'b> {
     pub fn horizontal_rule(palette: &'b Palette) -> String {
         format!("{}{}", palette.header, "─".repeat(80))
     }
+
+    /// The report as a JSON object with its title, error code, severity, file, the region it's
+    /// about and any other regions it shows, the plain-text message, and suggested replacements.
+    /// Lines and columns start at 1.
+    pub fn to_json(self) -> serde_json::Value {
+        let structured = self.structured();
+        let primary = structured.regions.first().copied();
+
+        serde_json::json!({
+            "title": structured.title,
+            "code": structured.code,
+            "severity": severity_name(structured.severity),
+            "file": structured.filename,
+            "region": primary.map(region_json),
+            "secondary_regions": structured.regions.iter().skip(1).copied().map(region_json).collect::<Vec<_>>(),
+            "message": structured.message,
+            "suggestions": structured.replacements.iter().map(|(region, replacement)| serde_json::json!({
+                "region": region_json(*region),
+                "replacement": replacement,
+            })).collect::<Vec<_>>(),
+        })
+    }
+
+    /// The report as a SARIF `result`; put these in a log with [`Report::sarif_log`].
+    pub fn to_sarif_result(self) -> serde_json::Value {
+        let structured = self.structured();
+        let uri = structured.filename.to_string_lossy().replace('\\', "/");
+        let location = |region: LineColumnRegion| {
+            serde_json::json!({
+                "physicalLocation": {
+                    "artifactLocation": { "uri": uri },
+                    "region": sarif_region(region),
+                }
+            })
+        };
+        let primary = structured.regions.first().copied();
+
+        serde_json::json!({
+            "ruleId": structured.code.unwrap_or(&structured.title),
+            "level": severity_name(structured.severity),
+            "message": { "text": structured.message },
+            "locations": primary.map(location).into_iter().collect::<Vec<_>>(),
+            "relatedLocations": structured.regions.iter().skip(1).copied().map(location).collect::<Vec<_>>(),
+            "fixes": structured.replacements.iter().map(|(region, replacement)| serde_json::json!({
+                "description": { "text": format!("Replace with `{replacement}`") },
+                "artifactChanges": [{
+                    "artifactLocation": { "uri": uri },
+                    "replacements": [{
+                        "deletedRegion": sarif_region(*region),
+                        "insertedContent": { "text": replacement },
+                    }],
+                }],
+            })).collect::<Vec<_>>(),
+        })
+    }
+
+    /// A SARIF 2.1.0 log holding the given results from [`Report::to_sarif_result`]
+    pub fn sarif_log(results: Vec<serde_json::Value>) -> serde_json::Value {
+        serde_json::json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "roc",
+                        "informationUri": "https://www.roc-lang.org",
+                    }
+                },
+                "results": results,
+            }],
+        })
+    }
+
+    fn structured(self) -> StructuredReport {
+        let err_msg = "<buffer is not a utf-8 encoded string>";
+        let mut message = String::new();
+        let mut writer = StructuredWrite::new(&mut message);
+
+        self.doc.1.render_raw(70, &mut writer).expect(err_msg);
+
+        let StructuredWrite {
+            regions,
+            replacements,
+            ..
+        } = writer;
+
+        StructuredReport {
+            title: self.title,
+            code: self.code,
+            filename: self.filename,
+            severity: self.severity,
+            message,
+            regions,
+            replacements,
+        }
+    }
+}
+
+/// What the machine-readable renderings of a [`Report`] are made from
+struct StructuredReport {
+    title: String,
+    code: Option<&'static str>,
+    filename: PathBuf,
+    severity: Severity,
+    message: String,
+    regions: Vec<LineColumnRegion>,
+    replacements: Vec<(LineColumnRegion, String)>,
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::RuntimeError | Severity::Fatal => "error",
+        Severity::Warning => "warning",
+    }
+}
+
+fn region_json(region: LineColumnRegion) -> serde_json::Value {
+    serde_json::json!({
+        "start": { "line": region.start().line + 1, "column": region.start().column + 1 },
+        "end": { "line": region.end().line + 1, "column": region.end().column + 1 },
+    })
+}
+
+fn sarif_region(region: LineColumnRegion) -> serde_json::Value {
+    serde_json::json!({
+        "startLine": region.start().line + 1,
+        "startColumn": region.start().column + 1,
+        "endLine": region.end().line + 1,
+        "endColumn": region.end().column + 1,
+    })
 }
 
 /// This struct is a combination of several things
@@ -724,7 +896,10 @@ impl<'a> RocDocAllocator<'a> {
             result = result.append(highlight_line);
         }
 
-        result.annotate(Annotation::CodeBlock)
+        result
+            .annotate(Annotation::CodeBlock)
+            .annotate(Annotation::SourceRegion(sub_region2))
+            .annotate(Annotation::SourceRegion(sub_region1))
     }
 
     pub fn region_with_subregion(
@@ -837,7 +1012,7 @@ impl<'a> RocDocAllocator<'a> {
             result = result.append(highlight_line);
         }
 
-        result
+        result.annotate(Annotation::SourceRegion(sub_region))
     }
 
     pub fn region(
@@ -848,6 +1023,17 @@ impl<'a> RocDocAllocator<'a> {
         self.region_with_subregion(region, region, severity)
     }
 
+    /// Text the code in `region` could be replaced with, which tools reading the report
+    /// can offer to apply
+    pub fn replacement(
+        &'a self,
+        region: LineColumnRegion,
+        replacement: String,
+    ) -> DocBuilder<'a, Self, Annotation> {
+        self.string(replacement)
+            .annotate(Annotation::Replacement(region))
+    }
+
     pub fn region_without_error(
         &'a self,
         region: LineColumnRegion,
@@ -960,6 +1146,11 @@ pub enum Annotation {
     Header,
     ParserSuggestion,
     Warning,
+    /// A snippet of source code showing this region, which is what the report is about
+    /// if it's the first one in the report
+    SourceRegion(LineColumnRegion),
+    /// Text that could replace this region to fix the problem
+    Replacement(LineColumnRegion),
 }
 
 /// Render with minimal formatting
@@ -1085,6 +1276,77 @@ where
     }
 }
 
+/// Render like [`CiWrite`], remembering the source regions shown and the suggested
+/// replacements along the way
+struct StructuredWrite<W> {
+    upstream: CiWrite<W>,
+    regions: Vec<LineColumnRegion>,
+    replacements: Vec<(LineColumnRegion, String)>,
+    in_replacement: bool,
+}
+
+impl<W> StructuredWrite<W> {
+    fn new(upstream: W) -> StructuredWrite<W> {
+        StructuredWrite {
+            upstream: CiWrite::new(upstream),
+            regions: vec![],
+            replacements: vec![],
+            in_replacement: false,
+        }
+    }
+}
+
+impl<W> Render for StructuredWrite<W>
+where
+    W: fmt::Write,
+{
+    type Error = fmt::Error;
+
+    fn write_str(&mut self, s: &str) -> Result<usize, fmt::Error> {
+        self.write_str_all(s).map(|_| s.len())
+    }
+
+    fn write_str_all(&mut self, s: &str) -> fmt::Result {
+        if self.in_replacement {
+            if let Some((_, replacement)) = self.replacements.last_mut() {
+                replacement.push_str(s);
+            }
+        }
+
+        self.upstream.write_str_all(s)
+    }
+}
+
+impl<W> RenderAnnotated<Annotation> for StructuredWrite<W>
+where
+    W: fmt::Write,
+{
+    fn push_annotation(&mut self, annotation: &Annotation) -> Result<(), Self::Error> {
+        match annotation {
+            Annotation::SourceRegion(region) => {
+                if !self.regions.contains(region) {
+                    self.regions.push(*region);
+                }
+            }
+            Annotation::Replacement(region) => {
+                self.replacements.push((*region, String::new()));
+                self.in_replacement = true;
+            }
+            _ => {}
+        }
+
+        self.upstream.push_annotation(annotation)
+    }
+
+    fn pop_annotation(&mut self) -> Result<(), Self::Error> {
+        if let Some(Annotation::Replacement(_)) = self.upstream.style_stack.last() {
+            self.in_replacement = false;
+        }
+
+        self.upstream.pop_annotation()
+    }
+}
+
 impl<'a, W> Render for ColorWrite<'a, W>
 where
     W: fmt::Write,
@@ -1176,8 +1438,8 @@ where
             Warning => {
                 self.write_str(self.palette.warning)?;
             }
-            TypeBlock | InlineTypeBlock | Tag | RecordField | RecordUpdater | TupleElem => { /* nothing yet */
-            }
+            TypeBlock | InlineTypeBlock | Tag | RecordField | RecordUpdater | TupleElem
+            | SourceRegion(_) | Replacement(_) => { /* nothing yet */ }
         }
         self.style_stack.push(*annotation);
         Ok(())
@@ -1197,7 +1459,7 @@ where
                 }
 
                 TypeBlock | InlineTypeBlock | Tag | Opaque | RecordField | RecordUpdater
-                | TupleElem => { /* nothing yet */ }
+                | TupleElem | SourceRegion(_) | Replacement(_) => { /* nothing yet */ }
             },
         }
         Ok(())
@@ -1266,6 +1528,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "UNSUPPORTED ENCODING".to_string(),
+                code: Some("E0401"),
                 severity: Severity::Fatal,
             }
         }
@@ -1301,6 +1564,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "MULTIPLE ENCODINGS".to_string(),
+                code: Some("E0402"),
                 severity: Severity::Fatal,
             }
         }
@@ -1336,6 +1600,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "INVALID CONTENT HASH".to_string(),
+                code: Some("E0403"),
                 severity: Severity::Fatal,
             }
         }
@@ -1353,6 +1618,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "NOTFOUND".to_string(),
+                code: Some("E0404"),
                 severity: Severity::Fatal,
             }
         }
@@ -1380,6 +1646,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "IO ERROR".to_string(),
+                code: Some("E0405"),
                 severity: Severity::Fatal,
             }
         }
@@ -1407,6 +1674,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "IO ERROR".to_string(),
+                code: Some("E0405"),
                 severity: Severity::Fatal,
             }
         }
@@ -1436,6 +1704,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "HTTP ERROR".to_string(),
+                code: Some("E0406"),
                 severity: Severity::Fatal,
             }
         }
@@ -1477,6 +1746,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "INVALID EXTENSION SUFFIX".to_string(),
+                code: Some("E0407"),
                 severity: Severity::Fatal,
             }
         }
@@ -1510,6 +1780,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "INVALID EXTENSION".to_string(),
+                code: Some("E0408"),
                 severity: Severity::Fatal,
             }
         }
@@ -1548,6 +1819,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "INVALID FRAGMENT".to_string(),
+                code: Some("E0409"),
                 severity: Severity::Fatal,
             }
         }
@@ -1586,6 +1858,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "MISSING PACKAGE HASH".to_string(),
+                code: Some("E0410"),
                 severity: Severity::Fatal,
             }
         }
@@ -1612,6 +1885,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "HTTPS MANDATORY".to_string(),
+                code: Some("E0411"),
                 severity: Severity::Fatal,
             }
         }
@@ -1655,6 +1929,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "MISLEADING CHARACTERS".to_string(),
+                code: Some("E0412"),
                 severity: Severity::Fatal,
             }
         }
@@ -1685,6 +1960,7 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "FILE TOO LARGE".to_string(),
+                code: Some("E0413"),
                 severity: Severity::Fatal,
             }
         }
@@ -1740,6 +2016,7 @@ pub fn to_file_problem_report<'b>(
                 filename,
                 doc,
                 title: "FILE NOT FOUND".to_string(),
+                code: Some("E0414"),
                 severity: Severity::Fatal,
             }
         }
@@ -1758,6 +2035,7 @@ pub fn to_file_problem_report<'b>(
                 filename,
                 doc,
                 title: "FILE PERMISSION DENIED".to_string(),
+                code: Some("E0415"),
                 severity: Severity::Fatal,
             }
         }
@@ -1794,6 +2072,7 @@ pub fn to_file_problem_report<'b>(
                 filename,
                 doc,
                 title: "NOT A ROC FILE".to_string(),
+                code: Some("E0416"),
                 severity: Severity::Fatal,
             }
         }
@@ -1814,6 +2093,7 @@ pub fn to_file_problem_report<'b>(
                 filename,
                 doc,
                 title: "FILE PROBLEM".to_string(),
+                code: Some("E0417"),
                 severity: Severity::Fatal,
             }
         }
@@ -0,0 +1,187 @@
+//! The error code table in `error_codes.md`, the codes reports are built with, and how a code is
+//! shown next to a report's title.
+use roc_reporting::error_code::{self, ErrorCode};
+use roc_reporting::report::{pretty_header, pretty_header_with_path, title_with_code};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The sources that build reports, and so set their codes
+const REPORT_SOURCES: &[&str] = &[
+    "reporting/src",
+    "compiler/load_internal/src/file.rs",
+    "docs/src/lib.rs",
+];
+
+fn rust_files(path: &Path, files: &mut Vec<PathBuf>) {
+    if path.is_dir() {
+        for entry in fs::read_dir(path).unwrap() {
+            rust_files(&entry.unwrap().path(), files);
+        }
+    } else if path.extension().is_some_and(|ext| ext == "rs") {
+        files.push(path.to_path_buf());
+    }
+}
+
+/// The contents of every source file that builds reports
+fn report_sources() -> Vec<(PathBuf, String)> {
+    let crates_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut files = Vec::new();
+
+    for source in REPORT_SOURCES {
+        rust_files(&crates_dir.join(source), &mut files);
+    }
+
+    files
+        .into_iter()
+        .map(|file| {
+            let src = fs::read_to_string(&file).unwrap();
+
+            (file, src)
+        })
+        .collect()
+}
+
+/// Every string literal like `"E0301"` in the sources that build reports
+fn codes_in_report_sources() -> Vec<(String, PathBuf)> {
+    let mut codes = Vec::new();
+
+    for (file, src) in report_sources() {
+        for (index, _) in src.match_indices("\"E") {
+            let literal = &src[index + 1..];
+
+            if let Some(code) = literal
+                .get(..6)
+                .filter(|code| code.ends_with('"') && is_well_formed(&code[..5]))
+            {
+                codes.push((code[..5].to_string(), file.clone()));
+            }
+        }
+    }
+
+    codes
+}
+
+fn is_well_formed(code: &str) -> bool {
+    code.len() == 5 && code.starts_with('E') && code[1..].bytes().all(|b| b.is_ascii_digit())
+}
+
+#[test]
+fn error_codes_are_unique() {
+    let mut codes = HashSet::new();
+    let mut titles = HashSet::new();
+
+    for error_code in error_code::all() {
+        assert!(
+            is_well_formed(error_code.code),
+            "malformed error code {:?}",
+            error_code.code
+        );
+        assert!(!error_code.title.is_empty(), "{}", error_code.code);
+        assert!(!error_code.explanation.is_empty(), "{}", error_code.code);
+        assert!(codes.insert(error_code.code), "{}", error_code.code);
+        assert!(titles.insert(error_code.title), "{}", error_code.title);
+    }
+}
+
+#[test]
+fn error_codes_are_in_order() {
+    let codes: Vec<_> = error_code::all()
+        .map(|error_code| error_code.code)
+        .collect();
+    let mut sorted = codes.clone();
+    sorted.sort();
+
+    assert_eq!(codes, sorted);
+}
+
+#[test]
+fn every_report_code_is_documented() {
+    for (code, file) in codes_in_report_sources() {
+        assert!(
+            error_code::lookup(&code).is_some(),
+            "{} sets the code {code}, which isn't in error_codes.md",
+            file.display()
+        );
+    }
+}
+
+#[test]
+fn every_documented_code_is_used() {
+    let used: HashSet<_> = codes_in_report_sources()
+        .into_iter()
+        .map(|(code, _)| code)
+        .collect();
+
+    for ErrorCode { code, title, .. } in error_code::all() {
+        assert!(
+            used.contains(code),
+            "{code} {title} is in error_codes.md, but no report sets it"
+        );
+    }
+}
+
+#[test]
+fn every_documented_title_is_used() {
+    let sources = report_sources();
+
+    for ErrorCode { code, title, .. } in error_code::all() {
+        let literal = format!("\"{title}\"");
+
+        assert!(
+            sources.iter().any(|(_, src)| src.contains(&literal)),
+            "{code} {title} is in error_codes.md, but no report has that title"
+        );
+    }
+}
+
+#[test]
+fn explain_error_code() {
+    let error_code = error_code::lookup("e0301").unwrap();
+
+    assert_eq!(error_code.code, "E0301");
+    assert_eq!(error_code.title, "TYPE MISMATCH");
+    assert!(error_code.explanation.contains("if \"foo\" then 2 else 3"));
+    assert_eq!(error_code::lookup(" E0301 "), Some(error_code));
+    assert_eq!(error_code::lookup("E9999"), None);
+}
+
+#[test]
+fn title_with_error_code() {
+    assert_eq!(
+        title_with_code("TYPE MISMATCH", Some("E0301")),
+        "TYPE MISMATCH [E0301]"
+    );
+    assert_eq!(title_with_code("TYPE MISMATCH", None), "TYPE MISMATCH");
+}
+
+#[test]
+fn header_with_error_code() {
+    let title = title_with_code("TYPE MISMATCH", Some("E0301"));
+    let header = pretty_header(&title);
+
+    assert!(header.starts_with("── TYPE MISMATCH [E0301] ─"), "{header}");
+    assert_eq!(header.chars().count(), 80);
+
+    let header = pretty_header_with_path(&title, Path::new("/code/proj/Main.roc"));
+
+    assert!(
+        header.starts_with("── TYPE MISMATCH [E0301] in /code/proj/Main.roc ─"),
+        "{header}"
+    );
+    assert_eq!(header.chars().count(), 80);
+}
+
+#[test]
+fn long_path_header_with_error_code() {
+    let title = title_with_code("TYPE MISMATCH", Some("E0301"));
+    let path = Path::new("/code/a/very/long/path/to/a/project/with/a/deeply/nested/Module.roc");
+    let header = pretty_header_with_path(&title, path);
+
+    assert!(
+        header.starts_with("── TYPE MISMATCH [E0301] in ..."),
+        "{header}"
+    );
+    assert!(header.contains("/nested/Module.roc ─"), "{header}");
+    assert_eq!(header.chars().count(), 80);
+}
@@ -12,13 +12,22 @@ use core::ops::Drop;
 use core::str;
 use std::convert::Infallible;
 
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 mod roc_box;
+mod roc_dict;
 mod roc_list;
+mod roc_set;
 mod roc_str;
 mod storage;
 
 pub use roc_box::RocBox;
+pub use roc_dict::RocDict;
 pub use roc_list::{ReadOnlyRocList, RocList, SendSafeRocList};
+pub use roc_set::RocSet;
 pub use roc_str::{InteriorNulError, ReadOnlyRocStr, RocStr, SendSafeRocStr};
 pub use storage::Storage;
 
@@ -187,6 +196,26 @@ impl<T, E> RocResult<T, E> {
         matches!(self.tag, RocResultTag::RocErr)
     }
 
+    /// Like [`Result::as_ref`]
+    pub fn as_ref(&self) -> Result<&T, &E> {
+        match self.as_result_of_refs() {
+            Ok(payload) => Ok(payload),
+            Err(payload) => Err(payload),
+        }
+    }
+
+    /// Like [`Result::as_mut`]
+    pub fn as_mut(&mut self) -> Result<&mut T, &mut E> {
+        use RocResultTag::*;
+
+        unsafe {
+            match self.tag {
+                RocOk => Ok(&mut self.payload.ok),
+                RocErr => Err(&mut self.payload.err),
+            }
+        }
+    }
+
     fn into_payload(self) -> RocResultPayload<T, E> {
         let mut value = MaybeUninit::uninit();
 
@@ -265,6 +294,50 @@ impl<T, E> From<Result<T, E>> for RocResult<T, E> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T, E> Serialize for RocResult<T, E>
+where
+    T: Serialize,
+    E: Serialize,
+{
+    /// Serializes the same way as a Rust `Result`
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, E> Deserialize<'de> for RocResult<T, E>
+where
+    T: Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Result::deserialize(deserializer).map(RocResult::from)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T, E> Arbitrary<'a> for RocResult<T, E>
+where
+    T: Arbitrary<'a>,
+    E: Arbitrary<'a>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Result::arbitrary(u).map(RocResult::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Result<T, E>>::size_hint(depth)
+    }
+}
+
 #[repr(u8)]
 #[derive(Clone, Copy)]
 enum RocResultTag {
@@ -472,6 +545,42 @@ impl From<i32> for RocDec {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for RocDec {
+    /// Serializes to a string, because most formats can't represent every `Dec` as a number
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.to_str_helper(&mut ArrayString::new()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for RocDec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+
+        RocDec::from_str(&string).ok_or_else(|| {
+            serde::de::Error::invalid_value(serde::de::Unexpected::Str(&string), &"a Dec")
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for RocDec {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RocDec::from_ne_bytes(u.arbitrary()?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 16]>::size_hint(depth)
+    }
+}
+
 impl fmt::Display for RocDec {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str(self.to_str_helper(&mut ArrayString::new()))
@@ -536,6 +645,37 @@ impl Hash for I128 {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for I128 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        i128::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for I128 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        i128::deserialize(deserializer).map(I128::from)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for I128 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        i128::arbitrary(u).map(I128::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        i128::size_hint(depth)
+    }
+}
+
 #[repr(C, align(16))]
 #[derive(Clone, Copy, Eq, Default, PartialEq)]
 pub struct U128([u8; 16]);
@@ -582,6 +722,37 @@ impl Hash for U128 {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for U128 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        u128::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for U128 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u128::deserialize(deserializer).map(U128::from)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for U128 {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        u128::arbitrary(u).map(U128::from)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u128::size_hint(depth)
+    }
+}
+
 pub const ROC_REFCOUNT_CONSTANT: usize = 0;
 // The top bit indicates if refcounts should be atomic.
 pub const ROC_REFCOUNT_IS_ATOMIC: usize = isize::MIN as usize;
@@ -12,6 +12,11 @@ use core::{
 
 use std::os::raw::c_void;
 
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 #[repr(C)]
 pub struct RocBox<T>
 where
@@ -231,3 +236,43 @@ where
         true
     }
 }
+
+#[cfg(feature = "serde")]
+impl<T> Serialize for RocBox<T>
+where
+    T: Serialize + RocRefcounted,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deref().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for RocBox<T>
+where
+    T: Deserialize<'de> + RocRefcounted,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(RocBox::new)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T> Arbitrary<'a> for RocBox<T>
+where
+    T: Arbitrary<'a> + RocRefcounted,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary(u).map(RocBox::new)
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        T::size_hint(depth)
+    }
+}
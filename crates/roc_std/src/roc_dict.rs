@@ -0,0 +1,467 @@
+#![deny(unsafe_op_in_unsafe_fn)]
+
+use core::{
+    fmt::{self, Debug},
+    hash::Hash,
+    iter::FromIterator,
+    mem::{self, ManuallyDrop},
+};
+use std::collections::{hash_map::Entry, HashMap};
+
+use crate::{RocList, RocRefcounted};
+
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// The same as `default_max_load_factor` in Dict.roc
+const DEFAULT_MAX_LOAD_FACTOR: f32 = 0.8;
+/// The same as `initial_shifts` in Dict.roc
+const INITIAL_SHIFTS: u8 = 64 - 3;
+/// The same as `max_bucket_count` in Dict.roc
+const MAX_BUCKET_COUNT: u64 = 1 << 32;
+
+/// A Roc `Dict`, laid out the same way as the record behind the opaque type in Dict.roc.
+///
+/// Roc hashes keys with a seed that is private to the application, so a host has no way to
+/// fill in the hash buckets. A `RocDict` built in Rust only contains its entries, in insertion
+/// order; Roc indexes it the first time it needs the buckets. For the same reason, lookups on
+/// the Rust side are a linear scan over the entries.
+#[repr(C)]
+pub struct RocDict<K, V>
+where
+    K: RocRefcounted,
+    V: RocRefcounted,
+{
+    // Roc sorts record fields by alignment, and a U64 is more aligned than a List on 32-bit targets.
+    #[cfg(target_pointer_width = "32")]
+    max_bucket_capacity: u64,
+    buckets: RocList<Bucket>,
+    data: RocList<RocDictItem<K, V>>,
+    #[cfg(not(target_pointer_width = "32"))]
+    max_bucket_capacity: u64,
+    max_load_factor: f32,
+    shifts: u8,
+}
+
+impl<K, V> RocDict<K, V>
+where
+    K: RocRefcounted,
+    V: RocRefcounted,
+{
+    pub fn empty() -> Self {
+        Self::from_data(RocList::empty())
+    }
+
+    fn from_data(data: RocList<RocDictItem<K, V>>) -> Self {
+        Self {
+            buckets: RocList::empty(),
+            max_bucket_capacity: 0,
+            max_load_factor: DEFAULT_MAX_LOAD_FACTOR,
+            shifts: shifts_for_len(data.len()),
+            data,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Iterates over the entries in insertion order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            items: self.data.as_slice().iter(),
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.data.iter().map(RocDictItem::key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.data.iter().map(RocDictItem::value)
+    }
+}
+
+impl<K, V> RocDict<K, V>
+where
+    K: PartialEq + RocRefcounted,
+    V: RocRefcounted,
+{
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.keys().any(|k| k == key)
+    }
+}
+
+/// The same as `calc_shifts_for_size` in Dict.roc
+fn shifts_for_len(len: usize) -> u8 {
+    let mut shifts = INITIAL_SHIFTS;
+
+    while shifts > 0 && max_bucket_capacity(shifts) < len as u64 {
+        shifts -= 1;
+    }
+
+    shifts
+}
+
+fn max_bucket_capacity(shifts: u8) -> u64 {
+    let bucket_count = (1u64 << (64 - shifts)).min(MAX_BUCKET_COUNT);
+
+    (bucket_count as f32 * DEFAULT_MAX_LOAD_FACTOR).floor() as u64
+}
+
+impl<K, V> Default for RocDict<K, V>
+where
+    K: RocRefcounted,
+    V: RocRefcounted,
+{
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for RocDict<K, V>
+where
+    K: Hash + Eq + RocRefcounted,
+    V: RocRefcounted,
+{
+    /// Like repeated calls to `Dict.insert`: a key that appears more than once keeps the
+    /// position of its first occurrence and the value of its last.
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(into: T) -> Self {
+        let mut entries: Vec<Option<(K, V)>> = into.into_iter().map(Some).collect();
+
+        let order = {
+            let mut positions = HashMap::with_capacity(entries.len());
+            let mut order = Vec::with_capacity(entries.len());
+
+            for (index, entry) in entries.iter().enumerate() {
+                let (key, _) = entry.as_ref().unwrap();
+
+                match positions.entry(key) {
+                    Entry::Occupied(position) => order[*position.get()] = index,
+                    Entry::Vacant(position) => {
+                        position.insert(order.len());
+                        order.push(index);
+                    }
+                }
+            }
+
+            order
+        };
+
+        let data = order
+            .into_iter()
+            .map(|index| {
+                let (key, value) = entries[index].take().unwrap();
+
+                RocDictItem::new(key, value)
+            })
+            .collect();
+
+        Self::from_data(data)
+    }
+}
+
+impl<K, V> PartialEq for RocDict<K, V>
+where
+    K: PartialEq + RocRefcounted,
+    V: PartialEq + RocRefcounted,
+{
+    /// Like `Dict.is_eq`, this ignores the order of the entries.
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K, V> Eq for RocDict<K, V>
+where
+    K: Eq + RocRefcounted,
+    V: Eq + RocRefcounted,
+{
+}
+
+impl<K, V> Debug for RocDict<K, V>
+where
+    K: Debug + RocRefcounted,
+    V: Debug + RocRefcounted,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RocDict ")?;
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V> Clone for RocDict<K, V>
+where
+    K: RocRefcounted,
+    V: RocRefcounted,
+{
+    fn clone(&self) -> Self {
+        Self {
+            buckets: self.buckets.clone(),
+            data: self.data.clone(),
+            max_bucket_capacity: self.max_bucket_capacity,
+            max_load_factor: self.max_load_factor,
+            shifts: self.shifts,
+        }
+    }
+}
+
+impl<K, V> RocRefcounted for RocDict<K, V>
+where
+    K: RocRefcounted,
+    V: RocRefcounted,
+{
+    fn inc(&mut self) {
+        self.buckets.inc();
+        self.data.inc();
+    }
+
+    fn dec(&mut self) {
+        self.buckets.dec();
+        self.data.dec();
+    }
+
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a RocDict<K, V>
+where
+    K: RocRefcounted,
+    V: RocRefcounted,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a [`RocDict`], in insertion order.
+pub struct Iter<'a, K, V> {
+    items: core::slice::Iter<'a, RocDictItem<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next().map(|item| (item.key(), item.value()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+#[cfg(feature = "serde")]
+impl<K, V> Serialize for RocDict<K, V>
+where
+    K: Serialize + RocRefcounted,
+    V: Serialize + RocRefcounted,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (key, value) in self.iter() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> Deserialize<'de> for RocDict<K, V>
+where
+    K: Deserialize<'de> + Hash + Eq + RocRefcounted,
+    V: Deserialize<'de> + RocRefcounted,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(RocDictVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+struct RocDictVisitor<K, V> {
+    marker: PhantomData<(K, V)>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> Visitor<'de> for RocDictVisitor<K, V>
+where
+    K: Deserialize<'de> + Hash + Eq + RocRefcounted,
+    V: Deserialize<'de> + RocRefcounted,
+{
+    type Value = RocDict<K, V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a map")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0));
+
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(entries.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, K, V> Arbitrary<'a> for RocDict<K, V>
+where
+    K: Arbitrary<'a> + Hash + Eq + RocRefcounted,
+    V: Arbitrary<'a> + RocRefcounted,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+/// The same as `Bucket` in Dict.roc
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct Bucket {
+    data_index: u32,
+    dist_and_fingerprint: u32,
+}
+
+crate::roc_refcounted_noop_impl!(Bucket);
+
+/// A `(k, v)` tuple. Roc sorts tuple elements by alignment, so the value comes first
+/// if it is more aligned than the key. Both orders always have the same size.
+#[repr(C)]
+union RocDictItem<K, V> {
+    key_first: ManuallyDrop<KeyFirst<K, V>>,
+    value_first: ManuallyDrop<ValueFirst<K, V>>,
+}
+
+#[repr(C)]
+struct KeyFirst<K, V> {
+    key: K,
+    value: V,
+}
+
+#[repr(C)]
+struct ValueFirst<K, V> {
+    value: V,
+    key: K,
+}
+
+impl<K, V> RocDictItem<K, V> {
+    fn value_first() -> bool {
+        mem::align_of::<V>() > mem::align_of::<K>()
+    }
+
+    fn new(key: K, value: V) -> Self {
+        if Self::value_first() {
+            Self {
+                value_first: ManuallyDrop::new(ValueFirst { value, key }),
+            }
+        } else {
+            Self {
+                key_first: ManuallyDrop::new(KeyFirst { key, value }),
+            }
+        }
+    }
+
+    fn key(&self) -> &K {
+        unsafe {
+            if Self::value_first() {
+                &self.value_first.key
+            } else {
+                &self.key_first.key
+            }
+        }
+    }
+
+    fn value(&self) -> &V {
+        unsafe {
+            if Self::value_first() {
+                &self.value_first.value
+            } else {
+                &self.key_first.value
+            }
+        }
+    }
+
+    fn key_mut(&mut self) -> &mut K {
+        unsafe {
+            if Self::value_first() {
+                &mut self.value_first.key
+            } else {
+                &mut self.key_first.key
+            }
+        }
+    }
+
+    fn value_mut(&mut self) -> &mut V {
+        unsafe {
+            if Self::value_first() {
+                &mut self.value_first.value
+            } else {
+                &mut self.key_first.value
+            }
+        }
+    }
+}
+
+impl<K, V> RocRefcounted for RocDictItem<K, V>
+where
+    K: RocRefcounted,
+    V: RocRefcounted,
+{
+    fn inc(&mut self) {
+        self.key_mut().inc();
+        self.value_mut().inc();
+    }
+
+    fn dec(&mut self) {
+        self.key_mut().dec();
+        self.value_mut().dec();
+    }
+
+    fn is_refcounted() -> bool {
+        K::is_refcounted() || V::is_refcounted()
+    }
+}
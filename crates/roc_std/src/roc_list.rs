@@ -18,6 +18,8 @@ use crate::{
     roc_alloc, roc_dealloc, roc_realloc, storage::Storage, RocRefcounted, ROC_REFCOUNT_CONSTANT,
 };
 
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
 #[cfg(feature = "serde")]
 use core::marker::PhantomData;
 #[cfg(feature = "serde")]
@@ -458,6 +460,7 @@ where
 
                         if new_alloc == old_alloc {
                             // We successfully reallocated in-place; we're done!
+                            self.capacity_or_ref_ptr = new_len;
                             return;
                         } else {
                             // We got back a different allocation; copy the existing elements
@@ -812,7 +815,7 @@ where
             // If the size_hint didn't give us a max, we may need to grow. 1.5x seems to be good, based on:
             // https://archive.ph/Z2R8w and https://github.com/facebook/folly/blob/1f2706/folly/docs/FBVector.md
             if list.length == list.capacity() {
-                list.reserve(list.capacity() / 2);
+                list.reserve(max(list.capacity() / 2, 1));
                 elements = list.elements.unwrap().as_ptr();
             }
 
@@ -860,6 +863,24 @@ where
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a, T> Arbitrary<'a> for RocList<T>
+where
+    T: Arbitrary<'a> + RocRefcounted,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
 // This is a RocList that is checked to ensure it is unique or readonly such that it can be sent between threads safely.
 #[repr(transparent)]
 pub struct SendSafeRocList<T>(RocList<T>)
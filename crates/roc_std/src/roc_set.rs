@@ -0,0 +1,231 @@
+use core::{
+    fmt::{self, Debug},
+    hash::Hash,
+    iter::FromIterator,
+};
+
+use crate::{roc_dict, RocDict, RocRefcounted};
+
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// A Roc `Set`, which is a `Dict` whose values are all `{}`.
+///
+/// See [`RocDict`] for how sets built on the Rust side are handed to Roc.
+#[repr(transparent)]
+pub struct RocSet<T>(RocDict<T, ()>)
+where
+    T: RocRefcounted;
+
+impl<T> RocSet<T>
+where
+    T: RocRefcounted,
+{
+    pub fn empty() -> Self {
+        Self(RocDict::empty())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterates over the elements in insertion order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter(self.0.iter())
+    }
+}
+
+impl<T> RocSet<T>
+where
+    T: PartialEq + RocRefcounted,
+{
+    pub fn contains(&self, value: &T) -> bool {
+        self.0.contains_key(value)
+    }
+}
+
+impl<T> Default for RocSet<T>
+where
+    T: RocRefcounted,
+{
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<T> FromIterator<T> for RocSet<T>
+where
+    T: Hash + Eq + RocRefcounted,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(into: I) -> Self {
+        Self(into.into_iter().map(|value| (value, ())).collect())
+    }
+}
+
+impl<T> PartialEq for RocSet<T>
+where
+    T: PartialEq + RocRefcounted,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T> Eq for RocSet<T> where T: Eq + RocRefcounted {}
+
+impl<T> Debug for RocSet<T>
+where
+    T: Debug + RocRefcounted,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RocSet ")?;
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T> Clone for RocSet<T>
+where
+    T: RocRefcounted,
+{
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> RocRefcounted for RocSet<T>
+where
+    T: RocRefcounted,
+{
+    fn inc(&mut self) {
+        self.0.inc();
+    }
+
+    fn dec(&mut self) {
+        self.0.dec();
+    }
+
+    fn is_refcounted() -> bool {
+        true
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RocSet<T>
+where
+    T: RocRefcounted,
+{
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a [`RocSet`], in insertion order.
+pub struct Iter<'a, T>(roc_dict::Iter<'a, T, ()>);
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(value, ())| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+#[cfg(feature = "serde")]
+impl<T> Serialize for RocSet<T>
+where
+    T: Serialize + RocRefcounted,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for value in self {
+            seq.serialize_element(value)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for RocSet<T>
+where
+    T: Deserialize<'de> + Hash + Eq + RocRefcounted,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(RocSetVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+struct RocSetVisitor<T> {
+    marker: PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Visitor<'de> for RocSetVisitor<T>
+where
+    T: Deserialize<'de> + Hash + Eq + RocRefcounted,
+{
+    type Value = RocSet<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+
+        Ok(values.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T> Arbitrary<'a> for RocSet<T>
+where
+    T: Arbitrary<'a> + Hash + Eq + RocRefcounted,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
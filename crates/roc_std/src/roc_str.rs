@@ -23,6 +23,9 @@ use std::{ops::Range, ptr::NonNull};
 
 use crate::{roc_realloc, RocList, RocRefcounted, ROC_REFCOUNT_CONSTANT};
 
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+
 #[repr(transparent)]
 pub struct RocStr(RocStrInner);
 
@@ -1193,3 +1196,14 @@ impl<'de> Visitor<'de> for RocStrVisitor {
         Ok(RocStr::from(value))
     }
 }
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for RocStr {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(RocStr::from(<&str>::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&str>::size_hint(depth)
+    }
+}
@@ -54,7 +54,7 @@ pub unsafe extern "C" fn roc_memset(dst: *mut c_void, c: i32, n: usize) -> *mut
 
 #[cfg(test)]
 mod test_roc_std {
-    use roc_std::{RocBox, RocDec, RocList, RocResult, RocStr, SendSafeRocStr};
+    use roc_std::{RocBox, RocDec, RocDict, RocList, RocResult, RocSet, RocStr, SendSafeRocStr};
 
     fn roc_str_byte_representation(string: &RocStr) -> [u8; RocStr::SIZE] {
         unsafe { core::mem::transmute_copy(string) }
@@ -174,6 +174,19 @@ mod test_roc_std {
         assert_eq!(roc_list.capacity(), 5000);
     }
 
+    #[test]
+    fn reserve_list_reallocated_in_place() {
+        let mut roc_list = RocList::<u64>::with_capacity(1000);
+        roc_list.extend_from_slice(&[1, 2, 3]);
+
+        // The allocator can shrink this allocation in place,
+        // and the capacity has to follow it rather than keep its old value.
+        roc_list.reserve(1);
+
+        assert_eq!(roc_list.capacity(), 4);
+        assert_eq!(roc_list.as_slice(), &[1, 2, 3]);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn short_list_roundtrip() {
@@ -208,6 +221,19 @@ mod test_roc_std {
         assert_eq!(from_iter.capacity(), from_slice.capacity());
     }
 
+    #[test]
+    fn list_from_iter_without_size_hint() {
+        // from_fn has no size hint, so the list starts out with no capacity and has to grow.
+        let mut next = 0;
+        let from_iter: RocList<i64> = std::iter::from_fn(|| {
+            next += 1;
+            (next <= 5).then_some(next)
+        })
+        .collect();
+
+        assert_eq!(from_iter.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
     #[test]
     fn list_from_iter_zero_size() {
         let elems: [(); 5] = [(), (), (), (), ()];
@@ -267,6 +293,146 @@ mod test_roc_std {
         assert!(roc_result.is_err());
     }
 
+    #[test]
+    fn roc_result_from_rust_result() {
+        let mut roc_result: RocResult<u64, RocStr> = Ok(42).into();
+
+        assert_eq!(roc_result.as_ref(), Ok(&42));
+
+        if let Ok(answer) = roc_result.as_mut() {
+            *answer += 1;
+        }
+
+        assert_eq!(Result::from(roc_result), Ok(43));
+
+        let roc_result: RocResult<u64, RocStr> = Err(RocStr::from("nope")).into();
+
+        assert_eq!(roc_result.as_ref(), Err(&RocStr::from("nope")));
+    }
+
+    #[test]
+    fn roc_dict_layout() {
+        // buckets, data, max_bucket_capacity, max_load_factor, shifts
+        assert_eq!(
+            core::mem::size_of::<RocDict<u8, u8>>(),
+            2 * core::mem::size_of::<RocList<u8>>() + 8 + 4 + 4
+        );
+        assert_eq!(
+            core::mem::size_of::<RocSet<u8>>(),
+            core::mem::size_of::<RocDict<u8, ()>>()
+        );
+    }
+
+    #[test]
+    fn roc_dict_from_iter() {
+        let dict: RocDict<RocStr, u64> = [("one", 1), ("two", 2), ("three", 3)]
+            .into_iter()
+            .map(|(k, v)| (RocStr::from(k), v))
+            .collect();
+
+        assert_eq!(dict.len(), 3);
+        assert_eq!(dict.get(&RocStr::from("two")), Some(&2));
+        assert_eq!(dict.get(&RocStr::from("four")), None);
+        assert!(dict.contains_key(&RocStr::from("three")));
+        assert_eq!(dict.values().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn roc_dict_duplicate_keys() {
+        let dict: RocDict<u8, u64> = [(1, 10), (2, 20), (1, 11)].into_iter().collect();
+
+        // Like Dict.insert, the key keeps its position and gets the latest value.
+        assert_eq!(
+            dict.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+            [(1, 11), (2, 20)]
+        );
+    }
+
+    #[test]
+    fn roc_dict_value_more_aligned_than_key() {
+        let dict: RocDict<u8, u64> = [(1, u64::MAX), (2, 0)].into_iter().collect();
+
+        assert_eq!(dict.get(&1), Some(&u64::MAX));
+        assert_eq!(dict.keys().copied().collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn roc_dict_eq_ignores_order() {
+        let a: RocDict<u8, u8> = [(1, 1), (2, 2)].into_iter().collect();
+        let b: RocDict<u8, u8> = [(2, 2), (1, 1)].into_iter().collect();
+        let c: RocDict<u8, u8> = [(2, 2), (1, 3)].into_iter().collect();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(RocDict::<u8, u8>::empty(), RocDict::default());
+    }
+
+    #[test]
+    fn roc_dict_clone_shares_data() {
+        let long = "a string that is too long to be a small string";
+        let dict: RocDict<RocStr, RocStr> = [(RocStr::from("key"), RocStr::from(long))]
+            .into_iter()
+            .collect();
+        let clone = dict.clone();
+
+        drop(dict);
+
+        assert_eq!(
+            clone.get(&RocStr::from("key")).map(RocStr::as_str),
+            Some(long)
+        );
+    }
+
+    #[test]
+    fn roc_dict_debug() {
+        let dict: RocDict<u8, bool> = [(1, true)].into_iter().collect();
+
+        assert_eq!(format!("{dict:?}"), "RocDict {1: true}");
+    }
+
+    #[test]
+    fn roc_set_from_iter() {
+        let set: RocSet<RocStr> = ["a", "b", "a", "c"].into_iter().map(RocStr::from).collect();
+
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&RocStr::from("c")));
+        assert!(!set.contains(&RocStr::from("d")));
+        assert_eq!(
+            set.iter().map(RocStr::as_str).collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+        assert_eq!(format!("{set:?}"), r#"RocSet {"a", "b", "c"}"#);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn dict_serde_roundtrip() {
+        let orig: RocDict<RocStr, RocList<u8>> = [
+            (RocStr::from("a"), RocList::from([1, 2])),
+            (RocStr::from("b"), RocList::empty()),
+        ]
+        .into_iter()
+        .collect();
+
+        let serialized = serde_json::to_string(&orig).expect("failed to serialize dict");
+        let deserialized = serde_json::from_str(&serialized).expect("failed to deserialize dict");
+
+        assert_eq!(serialized, r#"{"a":[1,2],"b":[]}"#);
+        assert_eq!(orig, deserialized);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn set_serde_roundtrip() {
+        let orig: RocSet<u32> = [3, 1, 2].into_iter().collect();
+
+        let serialized = serde_json::to_string(&orig).expect("failed to serialize set");
+        let deserialized = serde_json::from_str(&serialized).expect("failed to deserialize set");
+
+        assert_eq!(serialized, "[3,1,2]");
+        assert_eq!(orig, deserialized);
+    }
+
     #[test]
     fn create_roc_box() {
         let contents = 42i32;
@@ -576,3 +742,155 @@ mod with_terminator {
         // verify_temp_c(&string_for_len(65), 64);
     }
 }
+
+/// Values coming out of a deserializer are freshly allocated, so they must be unique, and
+/// serializing a value must not touch its refcount. See `Storage` for the refcount states.
+#[cfg(all(test, feature = "serde"))]
+mod serde_roundtrip {
+    use roc_std::{RocBox, RocDec, RocDict, RocList, RocResult, RocStr, I128, U128};
+
+    const LONG: &str = "a string that is too long to be a small string";
+
+    fn roundtrip<T>(value: &T) -> T
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let serialized = serde_json::to_string(value).expect("failed to serialize");
+
+        serde_json::from_str(&serialized).expect("failed to deserialize")
+    }
+
+    #[test]
+    fn box_roundtrip() {
+        let orig = RocBox::new(42u64);
+
+        assert_eq!(serde_json::to_string(&orig).unwrap(), "42");
+        assert_eq!(roundtrip(&orig), orig);
+    }
+
+    #[test]
+    fn result_roundtrip() {
+        let ok: RocResult<RocStr, u8> = RocResult::ok(RocStr::from(LONG));
+        let err: RocResult<RocStr, u8> = RocResult::err(7);
+
+        assert_eq!(serde_json::to_string(&err).unwrap(), r#"{"Err":7}"#);
+        assert_eq!(roundtrip(&ok), ok);
+        assert_eq!(roundtrip(&err), err);
+    }
+
+    #[test]
+    fn dec_roundtrip() {
+        let orig = RocDec::from_str("-1234.5678").unwrap();
+
+        assert_eq!(serde_json::to_string(&orig).unwrap(), r#""-1234.5678""#);
+        assert_eq!(roundtrip(&orig), orig);
+        assert!(serde_json::from_str::<RocDec>(r#""one""#).is_err());
+    }
+
+    #[test]
+    fn int128_roundtrip() {
+        let signed = I128::from(i128::MIN);
+        let unsigned = U128::from(u128::MAX);
+
+        assert_eq!(roundtrip(&signed), signed);
+        assert_eq!(roundtrip(&unsigned), unsigned);
+    }
+
+    #[test]
+    fn deserialized_values_are_unique() {
+        let list: RocList<RocStr> = roundtrip(&RocList::from([RocStr::from(LONG)]));
+        let dict: RocDict<RocStr, RocStr> = roundtrip(
+            &[(RocStr::from(LONG), RocStr::from(LONG))]
+                .into_iter()
+                .collect(),
+        );
+
+        assert!(list.is_unique());
+        assert!(list[0].is_unique());
+        assert!(dict.keys().chain(dict.values()).all(RocStr::is_unique));
+    }
+
+    #[test]
+    fn serializing_does_not_change_refcount() {
+        let list = RocList::from([RocStr::from(LONG)]);
+        let shared = list.clone();
+
+        assert!(!list.is_unique());
+
+        let deserialized = roundtrip(&shared);
+        drop(shared);
+
+        assert!(list.is_unique());
+        assert!(list[0].is_unique());
+        assert_eq!(deserialized, list);
+    }
+
+    #[test]
+    fn readonly_roundtrip() {
+        let mut list = RocList::from([1u8, 2, 3]);
+        unsafe { list.set_readonly() };
+
+        let deserialized = roundtrip(&list);
+
+        assert!(list.is_readonly());
+        assert!(!deserialized.is_readonly());
+        assert!(deserialized.is_unique());
+        assert_eq!(deserialized, list);
+    }
+}
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod arbitrary_values {
+    use arbitrary::{Arbitrary, Unstructured};
+    use quickcheck_macros::quickcheck;
+    use roc_std::{RocDict, RocList, RocResult, RocSet, RocStr};
+
+    fn arbitrary<'a, T: Arbitrary<'a>>(bytes: &'a [u8]) -> Option<T> {
+        T::arbitrary(&mut Unstructured::new(bytes)).ok()
+    }
+
+    #[quickcheck]
+    fn str_matches_std(bytes: Vec<u8>) -> bool {
+        let roc_str: Option<RocStr> = arbitrary(&bytes);
+        let std_str: Option<&str> = arbitrary(&bytes);
+
+        roc_str.as_ref().map(RocStr::as_str) == std_str
+            && roc_str.map(|roc_str| roc_str.is_unique()) != Some(false)
+    }
+
+    #[quickcheck]
+    fn list_refcount(bytes: Vec<u8>) -> bool {
+        let Some(list) = arbitrary::<RocList<RocStr>>(&bytes) else {
+            return true;
+        };
+        let shared = list.clone();
+        let was_shared = !list.is_unique();
+        drop(shared);
+
+        was_shared && list.is_unique() && list.iter().all(RocStr::is_unique)
+    }
+
+    #[quickcheck]
+    fn dict_keys_are_distinct(bytes: Vec<u8>) -> bool {
+        let Some(dict) = arbitrary::<RocDict<u8, RocResult<u32, RocStr>>>(&bytes) else {
+            return true;
+        };
+        let keys: RocSet<u8> = dict.keys().copied().collect();
+
+        keys.len() == dict.len() && keys.iter().all(|key| dict.contains_key(key))
+    }
+
+    #[quickcheck]
+    #[cfg(feature = "serde")]
+    fn serde_roundtrip(bytes: Vec<u8>) -> bool {
+        let Some(dict) = arbitrary::<RocDict<RocStr, RocList<RocResult<u32, RocStr>>>>(&bytes)
+        else {
+            return true;
+        };
+        let serialized = serde_json::to_string(&dict).expect("failed to serialize");
+        let deserialized: RocDict<_, _> =
+            serde_json::from_str(&serialized).expect("failed to deserialize");
+
+        deserialized == dict
+    }
+}